@@ -22,6 +22,49 @@ mod config {
         pub format: Option<String>,
         pub show_events: Option<bool>,
     }
+
+    #[derive(Clone, Copy, clap::ValueEnum)]
+    pub enum NetworkLimitsProfile {
+        Testnet,
+        Mainnet,
+        Custom,
+    }
+
+    #[derive(Clone, Copy, clap::ValueEnum)]
+    pub enum ThemeName {
+        Dark,
+        Light,
+        /// No RGB colors, for limited terminals and accessibility
+        Mono,
+    }
+}
+
+#[allow(dead_code)]
+mod debugger {
+    pub mod breakpoint {
+        pub struct BreakpointSpec {
+            pub id: String,
+            pub function: String,
+            pub condition: Option<String>,
+            pub hit_condition: Option<String>,
+            pub log_message: Option<String>,
+        }
+    }
+
+    pub mod state {
+        pub const DEFAULT_STORAGE_HISTORY: usize = 64;
+    }
+}
+
+mod inspector {
+    pub mod storage {
+        #[derive(Clone, Copy, clap::ValueEnum)]
+        pub enum StorageDurability {
+            Instance,
+            Persistent,
+            Temporary,
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -1,4 +1,8 @@
-use soroban_sdk::{Env, Val};
+use crate::json::json_to_val;
+use serde_json::Value;
+use soroban_sdk::testutils::Events as _;
+use soroban_sdk::xdr::ScVal;
+use soroban_sdk::{Address, Env, TryFromVal, Val};
 
 pub struct StorageAssertions<'a> {
     _env: &'a Env,
@@ -22,3 +26,151 @@ impl<'a> StorageAssertions<'a> {
         assert_eq!(actual.get_payload(), expected.get_payload());
     }
 }
+
+/// Assert that the storage of `contract_id` maps `key_json` to `expected_json`.
+///
+/// Key and value are written in the JSON format of soroban-debug's `--args`
+/// (see [`crate::json`]). Instance, persistent and temporary storage are
+/// searched in that order.
+pub fn assert_storage_eq(
+    env: &Env,
+    contract_id: &Address,
+    key_json: &Value,
+    expected_json: &Value,
+) {
+    let key = json_val(env, key_json, "key");
+    let expected = to_sc_val(env, json_val(env, expected_json, "expected value"));
+
+    let found = env.as_contract(contract_id, || {
+        let storage = env.storage();
+        storage
+            .instance()
+            .get::<Val, Val>(&key)
+            .map(|v| ("instance", v))
+            .or_else(|| {
+                storage
+                    .persistent()
+                    .get::<Val, Val>(&key)
+                    .map(|v| ("persistent", v))
+            })
+            .or_else(|| {
+                storage
+                    .temporary()
+                    .get::<Val, Val>(&key)
+                    .map(|v| ("temporary", v))
+            })
+    });
+
+    match found {
+        Some((durability, actual)) => {
+            let actual = to_sc_val(env, actual);
+            assert!(
+                actual == expected,
+                "storage mismatch for key {key_json} ({durability}): expected {expected:?}, found {actual:?}"
+            );
+        }
+        None => panic!("storage key {key_json} not found; expected {expected:?}"),
+    }
+}
+
+/// Assert that `contract_id` emitted an event with `topic` among its topics
+/// and data equal to `data_json`, both written as soroban-debug `--args` JSON.
+pub fn assert_event_emitted(env: &Env, contract_id: &Address, topic: &Value, data_json: &Value) {
+    let topic_val = to_sc_val(env, json_val(env, topic, "topic"));
+    let data = to_sc_val(env, json_val(env, data_json, "event data"));
+
+    let emitted: Vec<(Vec<ScVal>, ScVal)> = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(address, _, _)| address == contract_id)
+        .map(|(_, topics, data)| {
+            (
+                topics.iter().map(|t| to_sc_val(env, t)).collect(),
+                to_sc_val(env, data),
+            )
+        })
+        .collect();
+
+    let matched = emitted
+        .iter()
+        .any(|(topics, event_data)| topics.contains(&topic_val) && *event_data == data);
+    assert!(
+        matched,
+        "no event with topic {topic_val:?} and data {data:?}; events found: {emitted:?}"
+    );
+}
+
+fn json_val(env: &Env, json: &Value, what: &str) -> Val {
+    json_to_val(env, json).unwrap_or_else(|e| panic!("invalid {what} {json}: {e}"))
+}
+
+fn to_sc_val(env: &Env, val: Val) -> ScVal {
+    ScVal::try_from_val(env, &val).unwrap_or_else(|e| panic!("cannot convert {val:?}: {e:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use soroban_sdk::{contract, contractimpl, symbol_short};
+
+    #[contract]
+    pub struct Recorder;
+
+    #[contractimpl]
+    impl Recorder {
+        pub fn record(env: Env, amount: i128) {
+            env.storage()
+                .persistent()
+                .set(&symbol_short!("total"), &amount);
+            env.events().publish((symbol_short!("recorded"),), amount);
+        }
+    }
+
+    fn recorded(amount: i128) -> (Env, Address) {
+        let env = Env::default();
+        let contract_id = env.register(Recorder, ());
+        RecorderClient::new(&env, &contract_id).record(&amount);
+        (env, contract_id)
+    }
+
+    #[test]
+    fn storage_assertion_matches_typed_value() {
+        let (env, contract_id) = recorded(500);
+        assert_storage_eq(&env, &contract_id, &json!("total"), &json!(500));
+        assert_storage_eq(
+            &env,
+            &contract_id,
+            &json!({"type": "symbol", "value": "total"}),
+            &json!({"type": "i128", "value": 500}),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "found I128(")]
+    fn storage_assertion_reports_actual_value_on_mismatch() {
+        let (env, contract_id) = recorded(500);
+        assert_storage_eq(&env, &contract_id, &json!("total"), &json!(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "not found")]
+    fn storage_assertion_fails_for_missing_key() {
+        let (env, contract_id) = recorded(500);
+        assert_storage_eq(&env, &contract_id, &json!("missing"), &json!(7));
+    }
+
+    #[test]
+    fn event_assertion_matches_topic_and_data() {
+        let (env, contract_id) = recorded(42);
+        assert_event_emitted(&env, &contract_id, &json!("recorded"), &json!(42));
+    }
+
+    #[test]
+    #[should_panic(expected = "events found")]
+    fn event_assertion_fails_for_other_data() {
+        let (env, contract_id) = recorded(42);
+        assert_event_emitted(&env, &contract_id, &json!("recorded"), &json!(43));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::mock::MockRegistry;
-use crate::storage::StorageHelper;
+use crate::storage::{StorageDurability, StorageHelper};
 use serde_json::Value;
+use soroban_sdk::testutils::Ledger as _;
 use soroban_sdk::{Address, Env, Val};
 
 pub struct MockEnvBuilder {
@@ -33,6 +34,21 @@ impl MockEnvBuilder {
         self
     }
 
+    /// Write `key` => `value` to the `durability` storage of the contract at
+    /// `address`, keeping the value's type as given.
+    pub fn with_contract_entry(
+        self,
+        address: &Address,
+        durability: StorageDurability,
+        key: &Val,
+        value: &Val,
+    ) -> Self {
+        self.env.as_contract(address, || {
+            StorageHelper::set_entry(&self.env, durability, key, value);
+        });
+        self
+    }
+
     pub fn with_storage_json(self, json: &Value) -> Self {
         // Default to a temporary contract if no address is provided?
         // Or just document that it uses the current context.
@@ -41,6 +57,34 @@ impl MockEnvBuilder {
         self
     }
 
+    /// Set the ledger close time returned by `env.ledger().timestamp()`.
+    ///
+    /// The ledger setters write straight into the `Env`'s ledger info when
+    /// called. Fields left alone keep the values `Env::default()` starts with
+    /// (or whatever the env passed to [`Self::from_env`] already had):
+    /// timestamp 0, sequence 0 and a maximum entry TTL of 6,312,000 ledgers.
+    pub fn with_ledger_timestamp(self, timestamp: u64) -> Self {
+        self.env.ledger().with_mut(|li| li.timestamp = timestamp);
+        self
+    }
+
+    /// Set the ledger sequence number returned by `env.ledger().sequence()`.
+    pub fn with_ledger_sequence(self, sequence: u32) -> Self {
+        self.env
+            .ledger()
+            .with_mut(|li| li.sequence_number = sequence);
+        self
+    }
+
+    /// Set the maximum TTL, in ledgers, that an entry can be extended to.
+    ///
+    /// Call this before seeding storage if the value is below the default
+    /// minimum entry TTLs, since new entries start at the minimum.
+    pub fn with_ledger_max_ttl(self, max_ttl: u32) -> Self {
+        self.env.ledger().with_mut(|li| li.max_entry_ttl = max_ttl);
+        self
+    }
+
     pub fn with_mock_call(mut self, contract_id: &str, function: &str, return_value: Val) -> Self {
         self.mock_registry
             .register(contract_id, function, return_value);
@@ -52,3 +96,60 @@ impl MockEnvBuilder {
         self.env
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{IntoVal, Symbol};
+
+    #[test]
+    fn ledger_setters_configure_env() {
+        let env = MockEnvBuilder::new()
+            .with_ledger_timestamp(1_700_000_000)
+            .with_ledger_sequence(42)
+            .with_ledger_max_ttl(100_000)
+            .build();
+
+        assert_eq!(env.ledger().timestamp(), 1_700_000_000);
+        assert_eq!(env.ledger().sequence(), 42);
+        assert_eq!(env.ledger().get().max_entry_ttl, 100_000);
+    }
+
+    #[test]
+    fn with_contract_entry_writes_each_durability() {
+        let env = Env::default();
+        let address = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let key = |name: &str| Symbol::new(&env, name).into_val(&env);
+        let env = MockEnvBuilder::from_env(env.clone())
+            .with_contract_entry(
+                &address,
+                StorageDurability::Instance,
+                &key("i"),
+                &1u32.into_val(&env),
+            )
+            .with_contract_entry(
+                &address,
+                StorageDurability::Persistent,
+                &key("p"),
+                &2i128.into_val(&env),
+            )
+            .with_contract_entry(
+                &address,
+                StorageDurability::Temporary,
+                &key("t"),
+                &true.into_val(&env),
+            )
+            .build();
+
+        env.as_contract(&address, || {
+            let storage = env.storage();
+            assert_eq!(storage.instance().get::<_, u32>(&key("i")), Some(1));
+            assert_eq!(storage.persistent().get::<_, i128>(&key("p")), Some(2));
+            assert_eq!(storage.temporary().get::<_, bool>(&key("t")), Some(true));
+            assert!(!storage.persistent().has(&key("i")));
+        });
+    }
+}
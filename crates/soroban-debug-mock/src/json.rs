@@ -0,0 +1,160 @@
+//! JSON to `Val` conversion following the rules of soroban-debug's `--args`
+//! parser, so values in assertions are written the same way as on the CLI:
+//!
+//! - bare numbers become `i128`, bare booleans `bool` and `null` void;
+//! - bare strings become an `Address` when they are a G.../C... strkey,
+//!   otherwise a `Symbol`, falling back to a `String`;
+//! - arrays become a `Vec` and plain objects a `Map` with symbol keys;
+//! - `{"type": "u32", "value": 10}` picks the type explicitly (`u32`, `i32`,
+//!   `u64`, `i64`, `u128`, `i128`, `bool`, `string`, `symbol`, `address`,
+//!   `option`, `vec`, `bytes`).
+
+use serde_json::Value;
+use soroban_sdk::{
+    Address, Bytes, Env, Map, String as SorobanString, Symbol, TryFromVal, Val, Vec,
+};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct JsonConversionError(String);
+
+/// Convert `value` to a `Val` in `env`.
+pub fn json_to_val(env: &Env, value: &Value) -> Result<Val, JsonConversionError> {
+    match value {
+        Value::Null => to_val(env, &()),
+        Value::Bool(b) => to_val(env, b),
+        Value::Number(_) => to_val(env, &as_i128(value)?),
+        Value::String(s) => string_to_val(env, s),
+        Value::Array(items) => {
+            let mut vec = Vec::<Val>::new(env);
+            for item in items {
+                vec.push_back(json_to_val(env, item)?);
+            }
+            to_val(env, &vec)
+        }
+        Value::Object(obj) if is_typed(obj) => typed_to_val(env, obj),
+        Value::Object(obj) => {
+            let mut map = Map::<Symbol, Val>::new(env);
+            for (key, item) in obj {
+                map.set(symbol(env, key)?, json_to_val(env, item)?);
+            }
+            to_val(env, &map)
+        }
+    }
+}
+
+fn is_typed(obj: &serde_json::Map<String, Value>) -> bool {
+    obj.get("type").is_some_and(Value::is_string)
+        && obj.contains_key("value")
+        && obj.keys().all(|k| k == "type" || k == "value")
+}
+
+fn typed_to_val(
+    env: &Env,
+    obj: &serde_json::Map<String, Value>,
+) -> Result<Val, JsonConversionError> {
+    let value = &obj["value"];
+    match obj["type"].as_str().unwrap_or_default() {
+        "u32" => to_val(env, &narrow::<u32>(as_i128(value)?, "u32")?),
+        "i32" => to_val(env, &narrow::<i32>(as_i128(value)?, "i32")?),
+        "u64" => to_val(env, &narrow::<u64>(as_i128(value)?, "u64")?),
+        "i64" => to_val(env, &narrow::<i64>(as_i128(value)?, "i64")?),
+        "u128" => to_val(env, &narrow::<u128>(as_i128(value)?, "u128")?),
+        "i128" => to_val(env, &as_i128(value)?),
+        "bool" => to_val(
+            env,
+            &value.as_bool().ok_or_else(|| mismatch("bool", value))?,
+        ),
+        "string" => {
+            let s = value.as_str().ok_or_else(|| mismatch("string", value))?;
+            to_val(env, &SorobanString::from_str(env, s))
+        }
+        "symbol" => {
+            let s = value.as_str().ok_or_else(|| mismatch("symbol", value))?;
+            to_val(env, &symbol(env, s)?)
+        }
+        "address" => {
+            let s = value.as_str().ok_or_else(|| mismatch("address", value))?;
+            to_val(env, &address(env, s)?)
+        }
+        "option" => json_to_val(env, value),
+        "vec" => {
+            if !value.is_array() {
+                return Err(mismatch("vec", value));
+            }
+            json_to_val(env, value)
+        }
+        "bytes" => {
+            let s = value.as_str().ok_or_else(|| mismatch("bytes", value))?;
+            to_val(env, &Bytes::from_slice(env, &decode_hex(s)?))
+        }
+        other => Err(JsonConversionError(format!("Unsupported type '{other}'"))),
+    }
+}
+
+fn string_to_val(env: &Env, s: &str) -> Result<Val, JsonConversionError> {
+    if s.len() == 56 && (s.starts_with('G') || s.starts_with('C')) {
+        if let Ok(address) = address(env, s) {
+            return to_val(env, &address);
+        }
+    }
+    match symbol(env, s) {
+        Ok(symbol) => to_val(env, &symbol),
+        Err(_) => to_val(env, &SorobanString::from_str(env, s)),
+    }
+}
+
+fn symbol(env: &Env, s: &str) -> Result<Symbol, JsonConversionError> {
+    catch_unwind(AssertUnwindSafe(|| Symbol::new(env, s)))
+        .map_err(|_| JsonConversionError(format!("'{s}' is not a valid symbol")))
+}
+
+fn address(env: &Env, s: &str) -> Result<Address, JsonConversionError> {
+    catch_unwind(AssertUnwindSafe(|| Address::from_str(env, s)))
+        .map_err(|_| JsonConversionError(format!("'{s}' is not a valid address")))
+}
+
+fn as_i128(value: &Value) -> Result<i128, JsonConversionError> {
+    match value {
+        Value::Number(n) => n
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from))
+            .ok_or_else(|| mismatch("integer", value)),
+        // Large 128-bit values may be written as strings.
+        Value::String(s) => s.parse().map_err(|_| mismatch("integer", value)),
+        _ => Err(mismatch("integer", value)),
+    }
+}
+
+fn narrow<T: TryFrom<i128>>(n: i128, type_name: &str) -> Result<T, JsonConversionError> {
+    T::try_from(n).map_err(|_| JsonConversionError(format!("{n} is out of range for {type_name}")))
+}
+
+fn decode_hex(s: &str) -> Result<std::vec::Vec<u8>, JsonConversionError> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return Err(JsonConversionError(format!("'{s}' is not valid hex")));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&s[i..i + 2], 16)
+                .map_err(|_| JsonConversionError(format!("'{s}' is not valid hex")))
+        })
+        .collect()
+}
+
+fn mismatch(expected: &str, value: &Value) -> JsonConversionError {
+    JsonConversionError(format!("Expected {expected}, got {value}"))
+}
+
+fn to_val<T>(env: &Env, value: &T) -> Result<Val, JsonConversionError>
+where
+    Val: TryFromVal<Env, T>,
+    <Val as TryFromVal<Env, T>>::Error: std::fmt::Debug,
+{
+    Val::try_from_val(env, value)
+        .map_err(|e| JsonConversionError(format!("Failed to convert to Val: {e:?}")))
+}
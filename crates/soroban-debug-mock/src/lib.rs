@@ -1,6 +1,9 @@
 pub mod assertions;
 pub mod builder;
+pub mod json;
 pub mod mock;
 pub mod storage;
 
+pub use assertions::{assert_event_emitted, assert_storage_eq};
 pub use builder::MockEnvBuilder;
+pub use storage::StorageDurability;
@@ -1,9 +1,27 @@
 use serde_json::Value;
-use soroban_sdk::{Env, Symbol};
+use soroban_sdk::{Env, Symbol, Val};
+
+/// Contract storage an entry is written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageDurability {
+    Instance,
+    Persistent,
+    Temporary,
+}
 
 pub struct StorageHelper;
 
 impl StorageHelper {
+    /// Write one entry to the current contract's `durability` storage.
+    /// Must run inside `env.as_contract`.
+    pub fn set_entry(env: &Env, durability: StorageDurability, key: &Val, value: &Val) {
+        match durability {
+            StorageDurability::Instance => env.storage().instance().set(key, value),
+            StorageDurability::Persistent => env.storage().persistent().set(key, value),
+            StorageDurability::Temporary => env.storage().temporary().set(key, value),
+        }
+    }
+
     pub fn populate_from_json(env: &Env, json: &Value) {
         if let Some(storage) = json.as_object() {
             for (key_str, value) in storage {
@@ -260,6 +260,26 @@ mod test {
         assert_eq!(status, EscrowStatus::Released);
     }
 
+    #[test]
+    fn test_release_with_mock_env_builder_timestamp() {
+        let env = soroban_debug_mock::MockEnvBuilder::new()
+            .with_ledger_timestamp(150)
+            .build();
+        env.mock_all_auths();
+
+        let contract_id = env.register(EscrowContract, ());
+        let client = EscrowContractClient::new(&env, &contract_id);
+
+        let depositor = Address::generate(&env);
+        let beneficiary = Address::generate(&env);
+
+        client.deposit(&depositor, &beneficiary, &1000, &100);
+        client.release();
+
+        let (_dep, _ben, _amt, _time, status) = client.get_status();
+        assert_eq!(status, EscrowStatus::Released);
+    }
+
     #[test]
     fn test_release_before_unlock_time_fails() {
         let env = Env::default();
@@ -132,11 +132,20 @@ impl InspectorPlugin for ExampleLoggerPlugin {
                     instruction
                 ));
             }
-            ExecutionEvent::BreakpointHit { function, condition } => {
+            ExecutionEvent::BreakpointHit { function, condition, hit_count } => {
                 self.log_event(&format!(
-                    "BREAKPOINT: {} (condition: {:?})",
+                    "BREAKPOINT: {} (condition: {:?}, hit: {})",
                     function,
-                    condition
+                    condition,
+                    hit_count
+                ));
+            }
+            ExecutionEvent::WatchpointHit { key, before, after } => {
+                self.log_event(&format!(
+                    "WATCHPOINT: {} ({:?} -> {:?})",
+                    key,
+                    before,
+                    after
                 ));
             }
             ExecutionEvent::ExecutionPaused { reason } => {
@@ -1058,10 +1058,14 @@ export class SorobanDebugSession extends DebugSession {
     this.state.storage = storage;
   }
 
-  private mapPauseReason(reason?: string): 'breakpoint' | 'step' | 'pause' | 'exception' | undefined {
+  private mapPauseReason(
+    reason?: string
+  ): 'breakpoint' | 'data breakpoint' | 'step' | 'pause' | 'exception' | undefined {
     switch (reason) {
       case 'breakpoint':
         return 'breakpoint';
+      case 'watchpoint':
+        return 'data breakpoint';
       case 'step_boundary':
         return 'step';
       case 'user_interrupt':
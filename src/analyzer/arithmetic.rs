@@ -0,0 +1,1494 @@
+//! Concrete integer model of a contract, used by the symbolic analyzer to
+//! find arithmetic faults on an explored path.
+//!
+//! The model interprets the exported function with a path's concrete
+//! arguments: branches, calls into the module's own functions, globals and
+//! linear memory, holding every integer as an `i128`. Arguments are encoded
+//! the way the host passes them: small integers become tagged `Val`s
+//! (`value << 8 | tag`) and wider ones host objects, which the SDK reads and
+//! builds through the int conversion imports (`obj_to_i128_lo64`,
+//! `obj_from_i128_pieces`, ...). Evaluation stops quietly at any other
+//! import, at float code and at indirect calls.
+//!
+//! Faults are found in three ways:
+//! - A division instruction with a zero divisor.
+//! - Without overflow checks an unchecked `a * b` wraps silently, so the run
+//!   "succeeds". The first add, subtract or multiply in the exported function
+//!   itself whose exact result fits neither the signed nor the unsigned range
+//!   of the instruction is reported. Helpers such as compiler-builtins'
+//!   `__multi3` wrap by design and are not.
+//! - With overflow checks on, Rust's overflow and division panics compile to a
+//!   branch into `unreachable`. When a path traps, the last branch taken is
+//!   classified: an overflow if its condition was computed from an operation
+//!   whose exact result left the instruction's range, a division by zero if
+//!   the code it skipped divides.
+
+use crate::utils::wasm::sdk_host_function_name;
+use crate::{DebuggerError, Result};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use wasmparser::{
+    BlockType, ConstExpr, DataKind, ExternalKind, FuncType, Operator, Parser, Payload, TypeRef,
+    ValType,
+};
+
+/// Instructions evaluated before a path is given up on.
+const FUEL: u64 = 2_000_000;
+/// Nested calls evaluated before a path is given up on.
+const MAX_CALL_DEPTH: usize = 128;
+/// Instructions scanned past a panic guard when looking for the division it
+/// protects. Covers the `MIN / -1` check Rust emits between the two.
+const DIVISION_LOOKAHEAD: usize = 32;
+/// Largest `memory.copy` / `memory.fill` modelled.
+const MAX_BULK_MEMORY: u32 = 1 << 20;
+
+const TAG_U64_OBJECT: i64 = 64;
+const TAG_I64_OBJECT: i64 = 65;
+const TAG_U128_OBJECT: i64 = 68;
+const TAG_I128_OBJECT: i64 = 69;
+
+/// The kind of arithmetic fault found on a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArithmeticFaultKind {
+    MulOverflow,
+    AddOverflow,
+    SubOverflow,
+    DivisionByZero,
+}
+
+impl ArithmeticFaultKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::MulOverflow => "multiply overflow",
+            Self::AddOverflow => "add overflow",
+            Self::SubOverflow => "subtract overflow",
+            Self::DivisionByZero => "division by zero",
+        }
+    }
+
+    /// Recognise Rust's checked-arithmetic panic messages and the VM's
+    /// division trap in an execution error.
+    pub fn from_error_message(message: &str) -> Option<Self> {
+        let message = message.to_ascii_lowercase();
+        if message.contains("multiply with overflow") {
+            Some(Self::MulOverflow)
+        } else if message.contains("add with overflow") {
+            Some(Self::AddOverflow)
+        } else if message.contains("subtract with overflow") {
+            Some(Self::SubOverflow)
+        } else if message.contains("divide by zero")
+            || message.contains("division by zero")
+            || message.contains("divisor of zero")
+        {
+            Some(Self::DivisionByZero)
+        } else {
+            None
+        }
+    }
+}
+
+/// A fault found by [`ArithmeticModel::evaluate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArithmeticFault {
+    pub kind: ArithmeticFaultKind,
+    /// Module offset of the faulting instruction.
+    pub offset: usize,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Width {
+    W32,
+    W64,
+}
+
+impl Width {
+    fn bits(self) -> u32 {
+        match self {
+            Self::W32 => 32,
+            Self::W64 => 64,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::W32 => "i32",
+            Self::W64 => "i64",
+        }
+    }
+
+    /// Truncate to the width and sign-extend back.
+    fn wrap(self, value: i128) -> i128 {
+        match self {
+            Self::W32 => value as i32 as i128,
+            Self::W64 => value as i64 as i128,
+        }
+    }
+
+    fn unsigned(self, value: i128) -> u128 {
+        match self {
+            Self::W32 => value as u32 as u128,
+            Self::W64 => value as u64 as u128,
+        }
+    }
+
+    fn unsigned_max(self) -> u128 {
+        match self {
+            Self::W32 => u32::MAX as u128,
+            Self::W64 => u64::MAX as u128,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cmp {
+    Eq,
+    Ne,
+    LtS,
+    LtU,
+    GtS,
+    GtU,
+    LeS,
+    LeU,
+    GeS,
+    GeU,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Block {
+        results: usize,
+        end: usize,
+    },
+    Loop {
+        params: usize,
+    },
+    If {
+        results: usize,
+        else_: Option<usize>,
+        end: usize,
+    },
+    Else {
+        end: usize,
+    },
+    End,
+    Br(u32),
+    BrIf(u32),
+    /// Index into [`Body::tables`]; the last target is the default.
+    BrTable(usize),
+    Return,
+    Unreachable,
+    Call(u32),
+    Drop,
+    Select,
+    Nop,
+    LocalGet(u32),
+    LocalSet(u32),
+    LocalTee(u32),
+    GlobalGet(u32),
+    GlobalSet(u32),
+    Const(i128),
+    Load {
+        width: Width,
+        bytes: u32,
+        signed: bool,
+        offset: u32,
+    },
+    Store {
+        bytes: u32,
+        offset: u32,
+    },
+    MemorySize,
+    MemoryGrow,
+    MemoryCopy,
+    MemoryFill,
+    Add(Width),
+    Sub(Width),
+    Mul(Width),
+    DivS(Width),
+    DivU(Width),
+    RemS(Width),
+    RemU(Width),
+    And(Width),
+    Or(Width),
+    Xor(Width),
+    Shl(Width),
+    ShrS(Width),
+    ShrU(Width),
+    Rotl(Width),
+    Rotr(Width),
+    Clz(Width),
+    Ctz(Width),
+    Popcnt(Width),
+    Eqz(Width),
+    Compare(Cmp, Width),
+    WrapI64,
+    ExtendI32S,
+    ExtendI32U,
+    /// Sign-extend the low `u32` bits.
+    ExtendS(Width, u32),
+    Unmodelled,
+}
+
+impl Op {
+    fn is_division(self) -> bool {
+        matches!(
+            self,
+            Self::DivS(_) | Self::DivU(_) | Self::RemS(_) | Self::RemU(_)
+        )
+    }
+
+    fn from_operator(op: &Operator) -> Self {
+        use Width::*;
+        let load = |width, bytes, signed, memarg: &wasmparser::MemArg| Self::Load {
+            width,
+            bytes,
+            signed,
+            offset: memarg.offset as u32,
+        };
+        let store = |bytes, memarg: &wasmparser::MemArg| Self::Store {
+            bytes,
+            offset: memarg.offset as u32,
+        };
+        match op {
+            Operator::Unreachable => Self::Unreachable,
+            Operator::Nop => Self::Nop,
+            Operator::Br { relative_depth } => Self::Br(*relative_depth),
+            Operator::BrIf { relative_depth } => Self::BrIf(*relative_depth),
+            Operator::Return => Self::Return,
+            Operator::Call { function_index } => Self::Call(*function_index),
+            Operator::Drop => Self::Drop,
+            Operator::Select | Operator::TypedSelect { .. } => Self::Select,
+            Operator::LocalGet { local_index } => Self::LocalGet(*local_index),
+            Operator::LocalSet { local_index } => Self::LocalSet(*local_index),
+            Operator::LocalTee { local_index } => Self::LocalTee(*local_index),
+            Operator::GlobalGet { global_index } => Self::GlobalGet(*global_index),
+            Operator::GlobalSet { global_index } => Self::GlobalSet(*global_index),
+            Operator::I32Load { memarg } => load(W32, 4, true, memarg),
+            Operator::I64Load { memarg } => load(W64, 8, true, memarg),
+            Operator::I32Load8S { memarg } => load(W32, 1, true, memarg),
+            Operator::I32Load8U { memarg } => load(W32, 1, false, memarg),
+            Operator::I32Load16S { memarg } => load(W32, 2, true, memarg),
+            Operator::I32Load16U { memarg } => load(W32, 2, false, memarg),
+            Operator::I64Load8S { memarg } => load(W64, 1, true, memarg),
+            Operator::I64Load8U { memarg } => load(W64, 1, false, memarg),
+            Operator::I64Load16S { memarg } => load(W64, 2, true, memarg),
+            Operator::I64Load16U { memarg } => load(W64, 2, false, memarg),
+            Operator::I64Load32S { memarg } => load(W64, 4, true, memarg),
+            Operator::I64Load32U { memarg } => load(W64, 4, false, memarg),
+            Operator::I32Store { memarg } => store(4, memarg),
+            Operator::I64Store { memarg } => store(8, memarg),
+            Operator::I32Store8 { memarg } | Operator::I64Store8 { memarg } => store(1, memarg),
+            Operator::I32Store16 { memarg } | Operator::I64Store16 { memarg } => store(2, memarg),
+            Operator::I64Store32 { memarg } => store(4, memarg),
+            Operator::MemorySize { .. } => Self::MemorySize,
+            Operator::MemoryGrow { .. } => Self::MemoryGrow,
+            Operator::MemoryCopy { .. } => Self::MemoryCopy,
+            Operator::MemoryFill { .. } => Self::MemoryFill,
+            Operator::I32Const { value } => Self::Const(*value as i128),
+            Operator::I64Const { value } => Self::Const(*value as i128),
+            Operator::I32Eqz => Self::Eqz(W32),
+            Operator::I64Eqz => Self::Eqz(W64),
+            Operator::I32Eq => Self::Compare(Cmp::Eq, W32),
+            Operator::I32Ne => Self::Compare(Cmp::Ne, W32),
+            Operator::I32LtS => Self::Compare(Cmp::LtS, W32),
+            Operator::I32LtU => Self::Compare(Cmp::LtU, W32),
+            Operator::I32GtS => Self::Compare(Cmp::GtS, W32),
+            Operator::I32GtU => Self::Compare(Cmp::GtU, W32),
+            Operator::I32LeS => Self::Compare(Cmp::LeS, W32),
+            Operator::I32LeU => Self::Compare(Cmp::LeU, W32),
+            Operator::I32GeS => Self::Compare(Cmp::GeS, W32),
+            Operator::I32GeU => Self::Compare(Cmp::GeU, W32),
+            Operator::I64Eq => Self::Compare(Cmp::Eq, W64),
+            Operator::I64Ne => Self::Compare(Cmp::Ne, W64),
+            Operator::I64LtS => Self::Compare(Cmp::LtS, W64),
+            Operator::I64LtU => Self::Compare(Cmp::LtU, W64),
+            Operator::I64GtS => Self::Compare(Cmp::GtS, W64),
+            Operator::I64GtU => Self::Compare(Cmp::GtU, W64),
+            Operator::I64LeS => Self::Compare(Cmp::LeS, W64),
+            Operator::I64LeU => Self::Compare(Cmp::LeU, W64),
+            Operator::I64GeS => Self::Compare(Cmp::GeS, W64),
+            Operator::I64GeU => Self::Compare(Cmp::GeU, W64),
+            Operator::I32Clz => Self::Clz(W32),
+            Operator::I64Clz => Self::Clz(W64),
+            Operator::I32Ctz => Self::Ctz(W32),
+            Operator::I64Ctz => Self::Ctz(W64),
+            Operator::I32Popcnt => Self::Popcnt(W32),
+            Operator::I64Popcnt => Self::Popcnt(W64),
+            Operator::I32Add => Self::Add(W32),
+            Operator::I64Add => Self::Add(W64),
+            Operator::I32Sub => Self::Sub(W32),
+            Operator::I64Sub => Self::Sub(W64),
+            Operator::I32Mul => Self::Mul(W32),
+            Operator::I64Mul => Self::Mul(W64),
+            Operator::I32DivS => Self::DivS(W32),
+            Operator::I64DivS => Self::DivS(W64),
+            Operator::I32DivU => Self::DivU(W32),
+            Operator::I64DivU => Self::DivU(W64),
+            Operator::I32RemS => Self::RemS(W32),
+            Operator::I64RemS => Self::RemS(W64),
+            Operator::I32RemU => Self::RemU(W32),
+            Operator::I64RemU => Self::RemU(W64),
+            Operator::I32And => Self::And(W32),
+            Operator::I64And => Self::And(W64),
+            Operator::I32Or => Self::Or(W32),
+            Operator::I64Or => Self::Or(W64),
+            Operator::I32Xor => Self::Xor(W32),
+            Operator::I64Xor => Self::Xor(W64),
+            Operator::I32Shl => Self::Shl(W32),
+            Operator::I64Shl => Self::Shl(W64),
+            Operator::I32ShrS => Self::ShrS(W32),
+            Operator::I64ShrS => Self::ShrS(W64),
+            Operator::I32ShrU => Self::ShrU(W32),
+            Operator::I64ShrU => Self::ShrU(W64),
+            Operator::I32Rotl => Self::Rotl(W32),
+            Operator::I64Rotl => Self::Rotl(W64),
+            Operator::I32Rotr => Self::Rotr(W32),
+            Operator::I64Rotr => Self::Rotr(W64),
+            Operator::I32WrapI64 => Self::WrapI64,
+            Operator::I64ExtendI32S => Self::ExtendI32S,
+            Operator::I64ExtendI32U => Self::ExtendI32U,
+            Operator::I32Extend8S => Self::ExtendS(W32, 8),
+            Operator::I32Extend16S => Self::ExtendS(W32, 16),
+            Operator::I64Extend8S => Self::ExtendS(W64, 8),
+            Operator::I64Extend16S => Self::ExtendS(W64, 16),
+            Operator::I64Extend32S => Self::ExtendS(W64, 32),
+            _ => Self::Unmodelled,
+        }
+    }
+}
+
+/// A decoded function body. Block, loop and if instructions carry the index
+/// of their matching `else`/`end`.
+#[derive(Debug, Clone)]
+struct Body {
+    locals: usize,
+    ops: Vec<(usize, Op)>,
+    tables: Vec<Vec<u32>>,
+}
+
+/// The decoded module, ready to evaluate the exported function.
+#[derive(Debug, Clone)]
+pub struct ArithmeticModel {
+    types: Vec<FuncType>,
+    /// Type index of every function, imports first.
+    function_types: Vec<u32>,
+    /// Host function name of every imported function, when known.
+    imports: Vec<Option<&'static str>>,
+    bodies: Vec<Body>,
+    globals: Vec<i128>,
+    data: Vec<(u32, Vec<u8>)>,
+    pages: u32,
+    export: u32,
+    params: usize,
+}
+
+impl ArithmeticModel {
+    /// Decode the module for evaluating the exported `function`. Returns
+    /// `None` when the export does not exist, is an import, or takes
+    /// non-integer parameters.
+    pub fn for_function(wasm_bytes: &[u8], function: &str) -> Result<Option<Self>> {
+        let parse_err = |e: wasmparser::BinaryReaderError| {
+            DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e))
+        };
+
+        let mut types = Vec::new();
+        let mut function_types = Vec::new();
+        let mut imports = Vec::new();
+        let mut bodies = Vec::new();
+        let mut globals = Vec::new();
+        let mut data = Vec::new();
+        let mut pages = 0u32;
+        let mut export = None;
+
+        for payload in Parser::new(0).parse_all(wasm_bytes) {
+            match payload.map_err(parse_err)? {
+                Payload::TypeSection(reader) => {
+                    for rec_group in reader {
+                        for ty in rec_group.map_err(parse_err)?.into_types() {
+                            if let wasmparser::CompositeType::Func(func_type) = ty.composite_type {
+                                types.push(func_type);
+                            }
+                        }
+                    }
+                }
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import.map_err(parse_err)?;
+                        if let TypeRef::Func(type_index) = import.ty {
+                            function_types.push(type_index);
+                            imports.push(sdk_host_function_name(import.module, import.name));
+                        }
+                    }
+                }
+                Payload::FunctionSection(reader) => {
+                    for type_index in reader {
+                        function_types.push(type_index.map_err(parse_err)?);
+                    }
+                }
+                Payload::MemorySection(reader) => {
+                    for memory in reader {
+                        pages = memory.map_err(parse_err)?.initial as u32;
+                    }
+                }
+                Payload::GlobalSection(reader) => {
+                    for global in reader {
+                        globals.push(const_value(&global.map_err(parse_err)?.init_expr));
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for item in reader {
+                        let item = item.map_err(parse_err)?;
+                        if item.kind == ExternalKind::Func && item.name == function {
+                            export = Some(item.index);
+                        }
+                    }
+                }
+                Payload::DataSection(reader) => {
+                    for segment in reader {
+                        let segment = segment.map_err(parse_err)?;
+                        if let DataKind::Active { offset_expr, .. } = segment.kind {
+                            data.push((const_value(&offset_expr) as u32, segment.data.to_vec()));
+                        }
+                    }
+                }
+                Payload::CodeSectionEntry(body) => {
+                    let mut locals = 0usize;
+                    for local in body.get_locals_reader().map_err(parse_err)? {
+                        locals += local.map_err(parse_err)?.0 as usize;
+                    }
+                    let mut reader = body.get_operators_reader().map_err(parse_err)?;
+                    let mut decoded = Body {
+                        locals,
+                        ops: Vec::new(),
+                        tables: Vec::new(),
+                    };
+                    let mut open = Vec::new();
+                    while !reader.eof() {
+                        let offset = reader.original_position();
+                        let operator = reader.read().map_err(parse_err)?;
+                        let index = decoded.ops.len();
+                        let op = match &operator {
+                            Operator::Block { blockty } => {
+                                open.push(index);
+                                Op::Block {
+                                    results: block_arity(&types, blockty).1,
+                                    end: 0,
+                                }
+                            }
+                            Operator::Loop { blockty } => {
+                                open.push(index);
+                                Op::Loop {
+                                    params: block_arity(&types, blockty).0,
+                                }
+                            }
+                            Operator::If { blockty } => {
+                                open.push(index);
+                                Op::If {
+                                    results: block_arity(&types, blockty).1,
+                                    else_: None,
+                                    end: 0,
+                                }
+                            }
+                            Operator::Else => {
+                                if let Some(&opener) = open.last() {
+                                    if let (_, Op::If { else_, .. }) = &mut decoded.ops[opener] {
+                                        *else_ = Some(index);
+                                    }
+                                }
+                                Op::Else { end: 0 }
+                            }
+                            Operator::End => {
+                                if let Some(opener) = open.pop() {
+                                    let mut else_index = None;
+                                    match &mut decoded.ops[opener].1 {
+                                        Op::Block { end, .. } => *end = index,
+                                        Op::If { else_, end, .. } => {
+                                            *end = index;
+                                            else_index = *else_;
+                                        }
+                                        _ => {}
+                                    }
+                                    if let Some(else_index) = else_index {
+                                        decoded.ops[else_index].1 = Op::Else { end: index };
+                                    }
+                                }
+                                Op::End
+                            }
+                            Operator::BrTable { targets } => {
+                                let mut table = targets
+                                    .targets()
+                                    .collect::<std::result::Result<Vec<_>, _>>()
+                                    .map_err(parse_err)?;
+                                table.push(targets.default());
+                                decoded.tables.push(table);
+                                Op::BrTable(decoded.tables.len() - 1)
+                            }
+                            other => Op::from_operator(other),
+                        };
+                        decoded.ops.push((offset, op));
+                    }
+                    bodies.push(decoded);
+                }
+                _ => {}
+            }
+        }
+
+        let Some(export) = export.filter(|index| *index as usize >= imports.len()) else {
+            return Ok(None);
+        };
+        let Some(func_type) = function_types
+            .get(export as usize)
+            .and_then(|type_index| types.get(*type_index as usize))
+        else {
+            return Ok(None);
+        };
+        if !func_type
+            .params()
+            .iter()
+            .all(|ty| matches!(ty, ValType::I32 | ValType::I64))
+        {
+            return Ok(None);
+        }
+        let params = func_type.params().len();
+
+        Ok(Some(Self {
+            types,
+            function_types,
+            imports,
+            bodies,
+            globals,
+            data,
+            pages,
+            export,
+            params,
+        }))
+    }
+
+    /// Run the exported function with the arguments in `args_json` and return
+    /// the fault found on that path, if any. Returns `None` as well when an
+    /// argument has no integer encoding or evaluation leaves the modelled
+    /// subset before a fault shows.
+    pub fn evaluate(&self, args_json: &str) -> Option<ArithmeticFault> {
+        let args = match serde_json::from_str::<JsonValue>(args_json).ok()? {
+            JsonValue::Array(items) => items,
+            single => vec![single],
+        };
+        if args.len() != self.params {
+            return None;
+        }
+
+        let mut machine = Machine::new(self);
+        let args = args
+            .iter()
+            .map(|arg| machine.encode_arg(arg))
+            .collect::<Option<Vec<_>>>()?;
+        match machine.invoke(self.export, args, 0) {
+            Err(Stop::Fault(fault)) => Some(fault),
+            Err(Stop::Trap) => machine.classify_trap().or_else(|| machine.wrapped()),
+            Ok(_) | Err(Stop::Unmodelled) => machine.wrapped(),
+        }
+    }
+
+    fn func_type(&self, func: u32) -> Option<&FuncType> {
+        self.types
+            .get(*self.function_types.get(func as usize)? as usize)
+    }
+
+    fn body(&self, func: u32) -> Option<&Body> {
+        self.bodies
+            .get((func as usize).checked_sub(self.imports.len())?)
+    }
+
+    /// Whether `func` divides, directly or through up to `depth` calls.
+    fn divides(&self, func: u32, depth: usize) -> bool {
+        let Some(body) = self.body(func) else {
+            return false;
+        };
+        body.ops.iter().any(|(_, op)| match *op {
+            Op::Call(callee) => depth > 0 && self.divides(callee, depth - 1),
+            op => op.is_division(),
+        })
+    }
+
+    /// Whether the code from `start` in `func` reaches a division within
+    /// [`DIVISION_LOOKAHEAD`] instructions, without leaving the function.
+    fn leads_to_division(&self, func: u32, start: usize) -> bool {
+        let Some(body) = self.body(func) else {
+            return false;
+        };
+        for &(_, op) in body.ops.iter().skip(start).take(DIVISION_LOOKAHEAD) {
+            match op {
+                op if op.is_division() => return true,
+                Op::Call(callee) if self.divides(callee, 2) => return true,
+                Op::Unreachable | Op::Return | Op::Br(_) | Op::BrTable(_) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+}
+
+/// Value of a constant initializer expression, 0 if it is not a constant.
+fn const_value(expr: &ConstExpr) -> i128 {
+    match expr.get_operators_reader().read() {
+        Ok(Operator::I32Const { value }) => value as i128,
+        Ok(Operator::I64Const { value }) => value as i128,
+        _ => 0,
+    }
+}
+
+/// Parameter and result counts of a block type.
+fn block_arity(types: &[FuncType], blockty: &BlockType) -> (usize, usize) {
+    match blockty {
+        BlockType::Empty => (0, 0),
+        BlockType::Type(_) => (0, 1),
+        BlockType::FuncType(index) => types
+            .get(*index as usize)
+            .map(|ty| (ty.params().len(), ty.results().len()))
+            .unwrap_or((0, 0)),
+    }
+}
+
+/// Why evaluation of a path ended early.
+#[derive(Debug)]
+enum Stop {
+    Fault(ArithmeticFault),
+    /// The contract executed `unreachable`, i.e. panicked.
+    Trap,
+    Unmodelled,
+}
+
+/// An integer on the stack, in a local, global or memory, with the overflow
+/// it was computed from, if any.
+#[derive(Debug, Clone, Copy)]
+struct Value {
+    bits: i128,
+    origin: Option<usize>,
+}
+
+impl Value {
+    fn plain(bits: i128) -> Self {
+        Self { bits, origin: None }
+    }
+}
+
+/// An add, subtract or multiply whose exact result left the instruction's
+/// signed or unsigned range.
+#[derive(Debug, Clone, Copy)]
+struct Overflow {
+    kind: ArithmeticFaultKind,
+    offset: usize,
+    width: Width,
+    lhs: i128,
+    rhs: i128,
+    exact: i128,
+}
+
+impl Overflow {
+    fn fault(&self) -> ArithmeticFault {
+        let symbol = match self.kind {
+            ArithmeticFaultKind::AddOverflow => "+",
+            ArithmeticFaultKind::SubOverflow => "-",
+            _ => "*",
+        };
+        ArithmeticFault {
+            kind: self.kind,
+            offset: self.offset,
+            detail: format!(
+                "{} {} {} = {} does not fit in {}",
+                self.lhs,
+                symbol,
+                self.rhs,
+                self.exact,
+                self.width.name()
+            ),
+        }
+    }
+}
+
+/// The last conditional branch evaluated.
+#[derive(Debug, Clone, Copy)]
+struct Branch {
+    func: u32,
+    offset: usize,
+    /// First instruction of the direction not taken, if it is in `func`.
+    other: Option<usize>,
+    origin: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Label {
+    continuation: usize,
+    arity: usize,
+    height: usize,
+    is_loop: bool,
+}
+
+/// Evaluation state of one path.
+struct Machine<'m> {
+    model: &'m ArithmeticModel,
+    memory: HashMap<u32, (u8, Option<usize>)>,
+    pages: u32,
+    globals: Vec<Value>,
+    /// Host objects by handle, as raw 128-bit integers.
+    objects: Vec<u128>,
+    fuel: u64,
+    overflows: Vec<Overflow>,
+    /// First overflow in the exported function that fits neither range.
+    wrapped: Option<usize>,
+    last_branch: Option<Branch>,
+}
+
+impl<'m> Machine<'m> {
+    fn new(model: &'m ArithmeticModel) -> Self {
+        let mut memory = HashMap::new();
+        for (base, bytes) in &model.data {
+            for (i, byte) in bytes.iter().enumerate() {
+                memory.insert(base.wrapping_add(i as u32), (*byte, None));
+            }
+        }
+        Self {
+            model,
+            memory,
+            pages: model.pages,
+            globals: model
+                .globals
+                .iter()
+                .map(|bits| Value::plain(*bits))
+                .collect(),
+            objects: Vec::new(),
+            fuel: FUEL,
+            overflows: Vec::new(),
+            wrapped: None,
+            last_branch: None,
+        }
+    }
+
+    fn wrapped(&self) -> Option<ArithmeticFault> {
+        self.wrapped.map(|index| self.overflows[index].fault())
+    }
+
+    /// Classify the panic that ended the path by the branch that led to it.
+    fn classify_trap(&self) -> Option<ArithmeticFault> {
+        let branch = self.last_branch?;
+        if let Some(origin) = branch.origin {
+            let mut fault = self.overflows[origin].fault();
+            fault.detail = format!("{}; the contract panicked", fault.detail);
+            return Some(fault);
+        }
+        let other = branch.other?;
+        self.model
+            .leads_to_division(branch.func, other)
+            .then(|| ArithmeticFault {
+                kind: ArithmeticFaultKind::DivisionByZero,
+                offset: branch.offset,
+                detail: "the contract panicked on a zero divisor before dividing".to_string(),
+            })
+    }
+
+    /// The origin to carry when combining two values: a multiply overflow
+    /// wins, since the adds that propagate its carries overflow as well;
+    /// otherwise the most recent.
+    fn merge(&self, a: Option<usize>, b: Option<usize>) -> Option<usize> {
+        match (a, b) {
+            (Some(a), Some(b)) => {
+                let is_mul = |i: usize| self.overflows[i].kind == ArithmeticFaultKind::MulOverflow;
+                Some(match (is_mul(a), is_mul(b)) {
+                    (true, false) => a,
+                    (false, true) => b,
+                    _ => a.max(b),
+                })
+            }
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn new_object(&mut self, tag: i64, value: u128) -> i128 {
+        self.objects.push(value);
+        ((((self.objects.len() - 1) as i64) << 32) | tag) as i128
+    }
+
+    fn object(&self, val: i128) -> std::result::Result<u128, Stop> {
+        let handle = ((val as u64) >> 32) as usize;
+        self.objects.get(handle).copied().ok_or(Stop::Unmodelled)
+    }
+
+    /// Encode a JSON argument as the host passes it: a small `Val` when it
+    /// fits, a host object for wider integers. `None` for non-integers.
+    fn encode_arg(&mut self, arg: &JsonValue) -> Option<Value> {
+        const SMALL: std::ops::Range<i128> = -(1 << 55)..(1 << 55);
+        const UNSIGNED_SMALL: std::ops::Range<i128> = 0..(1 << 56);
+        let tagged = |value: i128, tag: i64| Value::plain((((value as i64) << 8) | tag) as i128);
+
+        let (type_name, value) = match arg {
+            JsonValue::Bool(flag) => return Some(Value::plain(*flag as i128)),
+            JsonValue::Null => return Some(Value::plain(2)),
+            JsonValue::Number(_) => ("i128", json_integer(arg)?),
+            JsonValue::Object(obj) => {
+                (obj.get("type")?.as_str()?, json_integer(obj.get("value")?)?)
+            }
+            _ => return None,
+        };
+        Some(match type_name {
+            "u32" => Value::plain((((u32::try_from(value).ok()? as i64) << 32) | 4) as i128),
+            "i32" => {
+                Value::plain(((((i32::try_from(value).ok()? as u32) as i64) << 32) | 5) as i128)
+            }
+            "u64" if UNSIGNED_SMALL.contains(&value) => tagged(value, 6),
+            "u64" => {
+                Value::plain(self.new_object(TAG_U64_OBJECT, u64::try_from(value).ok()? as u128))
+            }
+            "i64" if SMALL.contains(&value) => tagged(value, 7),
+            "i64" => {
+                Value::plain(self.new_object(TAG_I64_OBJECT, i64::try_from(value).ok()? as u128))
+            }
+            "timepoint" if UNSIGNED_SMALL.contains(&value) => tagged(value, 8),
+            "duration" if UNSIGNED_SMALL.contains(&value) => tagged(value, 9),
+            "u128" if UNSIGNED_SMALL.contains(&value) => tagged(value, 10),
+            "u128" if value >= 0 => Value::plain(self.new_object(TAG_U128_OBJECT, value as u128)),
+            "i128" if SMALL.contains(&value) => tagged(value, 11),
+            "i128" => Value::plain(self.new_object(TAG_I128_OBJECT, value as u128)),
+            _ => return None,
+        })
+    }
+
+    fn host_call(&mut self, func: u32, args: &[Value]) -> std::result::Result<Vec<Value>, Stop> {
+        let name = self.model.imports[func as usize].ok_or(Stop::Unmodelled)?;
+        let arg = |i: usize| args.get(i).map(|v| v.bits).ok_or(Stop::Unmodelled);
+        let pieces = |hi: i128, lo: i128| ((hi as u64 as u128) << 64) | lo as u64 as u128;
+        let result = match name {
+            "obj_from_u64" => self.new_object(TAG_U64_OBJECT, arg(0)? as u64 as u128),
+            "obj_from_i64" => self.new_object(TAG_I64_OBJECT, arg(0)? as i64 as i128 as u128),
+            "obj_from_u128_pieces" => self.new_object(TAG_U128_OBJECT, pieces(arg(0)?, arg(1)?)),
+            "obj_from_i128_pieces" => self.new_object(TAG_I128_OBJECT, pieces(arg(0)?, arg(1)?)),
+            "obj_to_u64" | "obj_to_i64" | "obj_to_u128_lo64" | "obj_to_i128_lo64" => {
+                self.object(arg(0)?)? as i64 as i128
+            }
+            "obj_to_u128_hi64" | "obj_to_i128_hi64" => (self.object(arg(0)?)? >> 64) as i64 as i128,
+            _ => return Err(Stop::Unmodelled),
+        };
+        Ok(vec![Value::plain(result)])
+    }
+
+    fn load(&self, address: u32, bytes: u32) -> Value {
+        let mut bits = 0u128;
+        let mut origin = None;
+        for i in (0..bytes).rev() {
+            let (byte, byte_origin) = self
+                .memory
+                .get(&address.wrapping_add(i))
+                .copied()
+                .unwrap_or((0, None));
+            bits = (bits << 8) | byte as u128;
+            origin = self.merge(origin, byte_origin);
+        }
+        Value {
+            bits: bits as i128,
+            origin,
+        }
+    }
+
+    fn store(&mut self, address: u32, bytes: u32, value: Value) {
+        for i in 0..bytes {
+            let byte = (value.bits >> (8 * i)) as u8;
+            self.memory
+                .insert(address.wrapping_add(i), (byte, value.origin));
+        }
+    }
+
+    fn invoke(
+        &mut self,
+        func: u32,
+        mut locals: Vec<Value>,
+        depth: usize,
+    ) -> std::result::Result<Vec<Value>, Stop> {
+        let model = self.model;
+        if (func as usize) < model.imports.len() {
+            return self.host_call(func, &locals);
+        }
+        if depth > MAX_CALL_DEPTH {
+            return Err(Stop::Unmodelled);
+        }
+        let results = model
+            .func_type(func)
+            .ok_or(Stop::Unmodelled)?
+            .results()
+            .len();
+        let body = model.body(func).ok_or(Stop::Unmodelled)?;
+        locals.resize(locals.len() + body.locals, Value::plain(0));
+
+        let mut stack: Vec<Value> = Vec::new();
+        let mut labels: Vec<Label> = Vec::new();
+        let mut pc = 0usize;
+        macro_rules! pop {
+            () => {
+                stack.pop().ok_or(Stop::Unmodelled)?
+            };
+        }
+
+        // Jump to the continuation of label `depth`, or return.
+        let branch = |stack: &mut Vec<Value>, labels: &mut Vec<Label>, depth: u32| {
+            let index = labels.len().checked_sub(depth as usize + 1)?;
+            let label = labels[index];
+            let kept = stack.split_off(stack.len().saturating_sub(label.arity));
+            stack.truncate(label.height);
+            stack.extend(kept);
+            labels.truncate(if label.is_loop { index + 1 } else { index });
+            Some(label.continuation)
+        };
+        let continuation = |labels: &[Label], depth: u32| {
+            let index = labels.len().checked_sub(depth as usize + 1)?;
+            Some(labels[index].continuation)
+        };
+
+        loop {
+            let &(offset, op) = body.ops.get(pc).ok_or(Stop::Unmodelled)?;
+            self.fuel = self.fuel.checked_sub(1).ok_or(Stop::Unmodelled)?;
+            pc += 1;
+
+            match op {
+                Op::Block { results, end } => labels.push(Label {
+                    continuation: end + 1,
+                    arity: results,
+                    height: stack.len(),
+                    is_loop: false,
+                }),
+                Op::Loop { params } => labels.push(Label {
+                    continuation: pc,
+                    arity: params,
+                    height: stack.len().saturating_sub(params),
+                    is_loop: true,
+                }),
+                Op::If {
+                    results,
+                    else_,
+                    end,
+                } => {
+                    let condition = pop!();
+                    let taken = condition.bits != 0;
+                    let skipped = else_.map(|e| e + 1).unwrap_or(end + 1);
+                    self.last_branch = Some(Branch {
+                        func,
+                        offset,
+                        other: Some(if taken { skipped } else { pc }),
+                        origin: condition.origin,
+                    });
+                    labels.push(Label {
+                        continuation: end + 1,
+                        arity: results,
+                        height: stack.len(),
+                        is_loop: false,
+                    });
+                    if !taken {
+                        pc = else_.map(|e| e + 1).unwrap_or(end);
+                    }
+                }
+                Op::Else { end } => pc = end,
+                Op::End => {
+                    if labels.pop().is_none() {
+                        return Ok(stack.split_off(stack.len().saturating_sub(results)));
+                    }
+                }
+                Op::Br(depth) => match branch(&mut stack, &mut labels, depth) {
+                    Some(next) => pc = next,
+                    None => return Ok(stack.split_off(stack.len().saturating_sub(results))),
+                },
+                Op::BrIf(depth) => {
+                    let condition = pop!();
+                    let taken = condition.bits != 0;
+                    self.last_branch = Some(Branch {
+                        func,
+                        offset,
+                        other: if taken {
+                            Some(pc)
+                        } else {
+                            continuation(&labels, depth)
+                        },
+                        origin: condition.origin,
+                    });
+                    if taken {
+                        match branch(&mut stack, &mut labels, depth) {
+                            Some(next) => pc = next,
+                            None => return Ok(stack.split_off(stack.len().saturating_sub(results))),
+                        }
+                    }
+                }
+                Op::BrTable(table) => {
+                    let index = pop!();
+                    let targets = &body.tables[table];
+                    let depth = targets
+                        .get(index.bits as u32 as usize)
+                        .or(targets.last())
+                        .copied()
+                        .ok_or(Stop::Unmodelled)?;
+                    match branch(&mut stack, &mut labels, depth) {
+                        Some(next) => pc = next,
+                        None => return Ok(stack.split_off(stack.len().saturating_sub(results))),
+                    }
+                }
+                Op::Return => return Ok(stack.split_off(stack.len().saturating_sub(results))),
+                Op::Unreachable => return Err(Stop::Trap),
+                Op::Call(callee) => {
+                    let params = model
+                        .func_type(callee)
+                        .ok_or(Stop::Unmodelled)?
+                        .params()
+                        .len();
+                    let args =
+                        stack.split_off(stack.len().checked_sub(params).ok_or(Stop::Unmodelled)?);
+                    let returned = self.invoke(callee, args, depth + 1)?;
+                    stack.extend(returned);
+                }
+                Op::Drop => {
+                    pop!();
+                }
+                Op::Select => {
+                    let condition = pop!();
+                    let b = pop!();
+                    let a = pop!();
+                    stack.push(if condition.bits != 0 { a } else { b });
+                }
+                Op::Nop => {}
+                Op::LocalGet(index) => {
+                    stack.push(*locals.get(index as usize).ok_or(Stop::Unmodelled)?)
+                }
+                Op::LocalSet(index) => {
+                    let value = pop!();
+                    *locals.get_mut(index as usize).ok_or(Stop::Unmodelled)? = value;
+                }
+                Op::LocalTee(index) => {
+                    let value = *stack.last().ok_or(Stop::Unmodelled)?;
+                    *locals.get_mut(index as usize).ok_or(Stop::Unmodelled)? = value;
+                }
+                Op::GlobalGet(index) => {
+                    stack.push(*self.globals.get(index as usize).ok_or(Stop::Unmodelled)?)
+                }
+                Op::GlobalSet(index) => {
+                    let value = pop!();
+                    *self
+                        .globals
+                        .get_mut(index as usize)
+                        .ok_or(Stop::Unmodelled)? = value;
+                }
+                Op::Const(bits) => stack.push(Value::plain(bits)),
+                Op::Load {
+                    width,
+                    bytes,
+                    signed,
+                    offset: memarg_offset,
+                } => {
+                    let address = (pop!().bits as u32).wrapping_add(memarg_offset);
+                    let mut value = self.load(address, bytes);
+                    let shift = 128 - 8 * bytes;
+                    value.bits = if signed {
+                        (value.bits << shift) >> shift
+                    } else {
+                        value.bits
+                    };
+                    value.bits = width.wrap(value.bits);
+                    stack.push(value);
+                }
+                Op::Store {
+                    bytes,
+                    offset: memarg_offset,
+                } => {
+                    let value = pop!();
+                    let address = (pop!().bits as u32).wrapping_add(memarg_offset);
+                    self.store(address, bytes, value);
+                }
+                Op::MemorySize => stack.push(Value::plain(self.pages as i128)),
+                Op::MemoryGrow => {
+                    let delta = pop!().bits as u32;
+                    stack.push(Value::plain(self.pages as i128));
+                    self.pages = self.pages.saturating_add(delta);
+                }
+                Op::MemoryCopy => {
+                    let len = pop!().bits as u32;
+                    let src = pop!().bits as u32;
+                    let dst = pop!().bits as u32;
+                    if len > MAX_BULK_MEMORY {
+                        return Err(Stop::Unmodelled);
+                    }
+                    let bytes: Vec<_> = (0..len)
+                        .map(|i| {
+                            self.memory
+                                .get(&src.wrapping_add(i))
+                                .copied()
+                                .unwrap_or((0, None))
+                        })
+                        .collect();
+                    for (i, byte) in bytes.into_iter().enumerate() {
+                        self.memory.insert(dst.wrapping_add(i as u32), byte);
+                    }
+                }
+                Op::MemoryFill => {
+                    let len = pop!().bits as u32;
+                    let value = pop!();
+                    let dst = pop!().bits as u32;
+                    if len > MAX_BULK_MEMORY {
+                        return Err(Stop::Unmodelled);
+                    }
+                    for i in 0..len {
+                        self.memory
+                            .insert(dst.wrapping_add(i), (value.bits as u8, value.origin));
+                    }
+                }
+                Op::Add(w) | Op::Sub(w) | Op::Mul(w) => {
+                    let b = pop!();
+                    let a = pop!();
+                    let (ua, ub) = (w.unsigned(a.bits), w.unsigned(b.bits));
+                    let (exact, unsigned_exact, kind) = match op {
+                        Op::Add(_) => (
+                            a.bits + b.bits,
+                            ua as i128 + ub as i128,
+                            ArithmeticFaultKind::AddOverflow,
+                        ),
+                        Op::Sub(_) => (
+                            a.bits - b.bits,
+                            ua as i128 - ub as i128,
+                            ArithmeticFaultKind::SubOverflow,
+                        ),
+                        _ => (
+                            a.bits * b.bits,
+                            (ua * ub).min(i128::MAX as u128) as i128,
+                            ArithmeticFaultKind::MulOverflow,
+                        ),
+                    };
+                    let signed_fits = w.wrap(exact) == exact;
+                    let unsigned_fits = (0..=w.unsigned_max() as i128).contains(&unsigned_exact);
+                    let mut origin = self.merge(a.origin, b.origin);
+                    if !signed_fits || !unsigned_fits {
+                        self.overflows.push(if signed_fits {
+                            Overflow {
+                                kind,
+                                offset,
+                                width: w,
+                                lhs: ua as i128,
+                                rhs: ub as i128,
+                                exact: unsigned_exact,
+                            }
+                        } else {
+                            Overflow {
+                                kind,
+                                offset,
+                                width: w,
+                                lhs: a.bits,
+                                rhs: b.bits,
+                                exact,
+                            }
+                        });
+                        let index = self.overflows.len() - 1;
+                        if depth == 0 && !signed_fits && !unsigned_fits && self.wrapped.is_none() {
+                            self.wrapped = Some(index);
+                        }
+                        origin = self.merge(origin, Some(index));
+                    }
+                    stack.push(Value {
+                        bits: w.wrap(exact),
+                        origin,
+                    });
+                }
+                Op::DivS(w) | Op::DivU(w) | Op::RemS(w) | Op::RemU(w) => {
+                    let b = pop!();
+                    let a = pop!();
+                    if w.wrap(b.bits) == 0 {
+                        return Err(Stop::Fault(ArithmeticFault {
+                            kind: ArithmeticFaultKind::DivisionByZero,
+                            offset,
+                            detail: format!("{} divided by zero in {}", a.bits, w.name()),
+                        }));
+                    }
+                    let bits = match op {
+                        Op::DivS(_) => a.bits.checked_div(b.bits).ok_or(Stop::Unmodelled)?,
+                        Op::RemS(_) => a.bits.checked_rem(b.bits).ok_or(Stop::Unmodelled)?,
+                        Op::DivU(_) => (w.unsigned(a.bits) / w.unsigned(b.bits)) as i128,
+                        _ => (w.unsigned(a.bits) % w.unsigned(b.bits)) as i128,
+                    };
+                    stack.push(Value {
+                        bits: w.wrap(bits),
+                        origin: self.merge(a.origin, b.origin),
+                    });
+                }
+                Op::And(w)
+                | Op::Or(w)
+                | Op::Xor(w)
+                | Op::Shl(w)
+                | Op::ShrS(w)
+                | Op::ShrU(w)
+                | Op::Rotl(w)
+                | Op::Rotr(w) => {
+                    let b = pop!();
+                    let a = pop!();
+                    let shift = (w.unsigned(b.bits) % w.bits() as u128) as u32;
+                    let bits = match (op, w) {
+                        (Op::And(_), _) => a.bits & b.bits,
+                        (Op::Or(_), _) => a.bits | b.bits,
+                        (Op::Xor(_), _) => a.bits ^ b.bits,
+                        (Op::Shl(_), _) => a.bits << shift,
+                        (Op::ShrS(_), _) => a.bits >> shift,
+                        (Op::ShrU(_), _) => (w.unsigned(a.bits) >> shift) as i128,
+                        (Op::Rotl(_), Width::W32) => (a.bits as u32).rotate_left(shift) as i128,
+                        (Op::Rotl(_), Width::W64) => (a.bits as u64).rotate_left(shift) as i128,
+                        (_, Width::W32) => (a.bits as u32).rotate_right(shift) as i128,
+                        (_, Width::W64) => (a.bits as u64).rotate_right(shift) as i128,
+                    };
+                    stack.push(Value {
+                        bits: w.wrap(bits),
+                        origin: self.merge(a.origin, b.origin),
+                    });
+                }
+                Op::Clz(w) | Op::Ctz(w) | Op::Popcnt(w) | Op::Eqz(w) => {
+                    let a = pop!();
+                    let unsigned = w.unsigned(a.bits);
+                    let bits = match op {
+                        Op::Clz(_) => unsigned.leading_zeros() - (128 - w.bits()),
+                        Op::Ctz(_) => unsigned.trailing_zeros().min(w.bits()),
+                        Op::Popcnt(_) => unsigned.count_ones(),
+                        _ => (unsigned == 0) as u32,
+                    };
+                    stack.push(Value {
+                        bits: bits as i128,
+                        origin: a.origin,
+                    });
+                }
+                Op::Compare(cmp, w) => {
+                    let b = pop!();
+                    let a = pop!();
+                    let (sa, sb) = (w.wrap(a.bits), w.wrap(b.bits));
+                    let (ua, ub) = (w.unsigned(a.bits), w.unsigned(b.bits));
+                    let result = match cmp {
+                        Cmp::Eq => ua == ub,
+                        Cmp::Ne => ua != ub,
+                        Cmp::LtS => sa < sb,
+                        Cmp::LtU => ua < ub,
+                        Cmp::GtS => sa > sb,
+                        Cmp::GtU => ua > ub,
+                        Cmp::LeS => sa <= sb,
+                        Cmp::LeU => ua <= ub,
+                        Cmp::GeS => sa >= sb,
+                        Cmp::GeU => ua >= ub,
+                    };
+                    stack.push(Value {
+                        bits: result as i128,
+                        origin: self.merge(a.origin, b.origin),
+                    });
+                }
+                Op::WrapI64 | Op::ExtendI32S | Op::ExtendI32U | Op::ExtendS(..) => {
+                    let mut value = pop!();
+                    value.bits = match op {
+                        Op::WrapI64 | Op::ExtendI32S => Width::W32.wrap(value.bits),
+                        Op::ExtendI32U => Width::W32.unsigned(value.bits) as i128,
+                        Op::ExtendS(w, bits) => {
+                            let shift = 128 - bits;
+                            w.wrap((value.bits << shift) >> shift)
+                        }
+                        _ => unreachable!(),
+                    };
+                    stack.push(value);
+                }
+                Op::Unmodelled => return Err(Stop::Unmodelled),
+            }
+        }
+    }
+}
+
+/// A JSON integer, written as a number or a decimal string.
+fn json_integer(value: &JsonValue) -> Option<i128> {
+    match value {
+        JsonValue::Number(n) => n
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from)),
+        JsonValue::String(s) => s.trim().parse::<i128>().ok().or_else(|| {
+            // u128 values above i128::MAX keep their bit pattern.
+            s.trim().parse::<u128>().ok().map(|v| v as i128)
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Module exporting `op(a, b)`, which decodes two small-integer `Val`s,
+    /// applies `instruction` without any overflow check and re-encodes the
+    /// result as an `I128Small`.
+    fn binary_op_wasm(instruction: u8) -> Vec<u8> {
+        let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        // (type (func (param i64 i64) (result i64)))
+        wasm.extend_from_slice(&[0x01, 0x07, 0x01, 0x60, 0x02, 0x7e, 0x7e, 0x01, 0x7e]);
+        wasm.extend_from_slice(&[0x03, 0x02, 0x01, 0x00]);
+        wasm.extend_from_slice(&[0x07, 0x06, 0x01, 0x02, b'o', b'p', 0x00, 0x00]);
+        let mut body = vec![0x00]; // no locals
+        body.extend_from_slice(&[0x20, 0x00, 0x42, 0x08, 0x87]); // a >> 8
+        body.extend_from_slice(&[0x20, 0x01, 0x42, 0x08, 0x87]); // b >> 8
+        body.push(instruction);
+        body.extend_from_slice(&[0x42, 0x08, 0x86, 0x42, 0x0b, 0x84]); // << 8 | I128Small tag
+        body.push(0x0b);
+        wasm.extend_from_slice(&[0x0a, body.len() as u8 + 2, 0x01, body.len() as u8]);
+        wasm.extend_from_slice(&body);
+        wasm
+    }
+
+    /// Module laid out the way the SDK builds `op(a: i64, b: i64) -> i64`:
+    /// a stack-pointer global, each argument decoded from an `I64Small` or
+    /// through the `obj_to_i64` import, `check` between decoding and
+    /// re-encoding the result held in local 4.
+    fn sdk_op_wasm(check: &[u8]) -> Vec<u8> {
+        let section = |id: u8, contents: Vec<u8>| {
+            let mut section = vec![id, contents.len() as u8];
+            section.extend(contents);
+            section
+        };
+        let decode = |param: u8, local: u8| {
+            vec![
+                0x20, param, 0x42, 0xff, 0x01, 0x83, 0x42, 0x07, 0x51, // tag == I64Small
+                0x04, 0x7e, 0x20, param, 0x42, 0x08, 0x87, // then a >> 8
+                0x05, 0x20, param, 0x10, 0x00, 0x0b, // else obj_to_i64(a)
+                0x21, local,
+            ]
+        };
+
+        let mut code = vec![0x01, 0x03, 0x7e]; // three i64 locals
+        code.extend_from_slice(&[0x23, 0x00, 0x41, 0x10, 0x6b, 0x24, 0x00]); // sp -= 16
+        code.extend(decode(0, 2));
+        code.extend(decode(1, 3));
+        code.extend_from_slice(check);
+        code.extend_from_slice(&[0x23, 0x00, 0x41, 0x10, 0x6a, 0x24, 0x00]); // sp += 16
+        code.extend_from_slice(&[0x20, 0x04, 0x42, 0x08, 0x86, 0x42, 0x07, 0x84, 0x0b]);
+
+        let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        wasm.extend(section(
+            0x01,
+            vec![
+                0x02, 0x60, 0x02, 0x7e, 0x7e, 0x01, 0x7e, 0x60, 0x01, 0x7e, 0x01, 0x7e,
+            ],
+        ));
+        wasm.extend(section(
+            0x02,
+            vec![0x01, 0x01, b'i', 0x01, b'2', 0x00, 0x01],
+        ));
+        wasm.extend(section(0x03, vec![0x01, 0x00]));
+        wasm.extend(section(
+            0x06,
+            vec![0x01, 0x7f, 0x01, 0x41, 0x80, 0x08, 0x0b],
+        ));
+        wasm.extend(section(0x07, vec![0x01, 0x02, b'o', b'p', 0x00, 0x01]));
+        let mut body = vec![code.len() as u8];
+        body.extend(code);
+        wasm.extend(section(0x0a, [vec![0x01], body].concat()));
+        wasm
+    }
+
+    /// `a + b` followed by the overflow check Rust emits with
+    /// `overflow-checks` on: `(b < 0) != (a + b < a)` panics.
+    fn checked_add_wasm() -> Vec<u8> {
+        sdk_op_wasm(&[
+            0x02, 0x40, // block
+            0x20, 0x03, 0x42, 0x00, 0x53, // b < 0
+            0x20, 0x02, 0x20, 0x03, 0x7c, 0x22, 0x04, 0x20, 0x02, 0x53, // (a + b) < a
+            0x73, 0x45, 0x0d, 0x00, // br_if 0 when they agree
+            0x00, 0x0b, // unreachable, end
+        ])
+    }
+
+    /// `a / b` behind the zero-divisor guard Rust emits.
+    fn guarded_div_wasm() -> Vec<u8> {
+        sdk_op_wasm(&[
+            0x20, 0x03, 0x50, 0x04, 0x40, 0x00, 0x0b, // if b == 0 { unreachable }
+            0x20, 0x02, 0x20, 0x03, 0x7f, 0x21, 0x04, // a / b
+        ])
+    }
+
+    fn evaluate_wasm(wasm: &[u8], args: &str) -> Option<ArithmeticFault> {
+        ArithmeticModel::for_function(wasm, "op")
+            .unwrap()
+            .expect("op is exported")
+            .evaluate(args)
+    }
+
+    fn evaluate(instruction: u8, args: &str) -> Option<ArithmeticFault> {
+        evaluate_wasm(&binary_op_wasm(instruction), args)
+    }
+
+    #[test]
+    fn unchecked_multiply_overflow_is_found() {
+        let fault = evaluate(0x7e, "[36028797018963967, 36028797018963967]").unwrap();
+        assert_eq!(fault.kind, ArithmeticFaultKind::MulOverflow);
+        assert!(
+            fault.detail.contains("does not fit in i64"),
+            "{}",
+            fault.detail
+        );
+
+        assert_eq!(evaluate(0x7e, "[6, 7]"), None);
+        assert_eq!(evaluate(0x7e, "[-1, 42]"), None);
+    }
+
+    #[test]
+    fn division_by_zero_is_found() {
+        let fault = evaluate(0x7f, "[10, 0]").unwrap();
+        assert_eq!(fault.kind, ArithmeticFaultKind::DivisionByZero);
+        assert_eq!(evaluate(0x7f, "[10, 2]"), None);
+    }
+
+    #[test]
+    fn checked_overflow_panic_is_traced_to_the_add() {
+        let wasm = checked_add_wasm();
+        let fault = evaluate_wasm(
+            &wasm,
+            r#"[{"type": "i64", "value": "9223372036854775807"}, {"type": "i64", "value": 1}]"#,
+        )
+        .unwrap();
+        assert_eq!(fault.kind, ArithmeticFaultKind::AddOverflow);
+        assert!(fault.detail.contains("panicked"), "{}", fault.detail);
+
+        assert_eq!(
+            evaluate_wasm(
+                &wasm,
+                r#"[{"type": "i64", "value": 2}, {"type": "i64", "value": 3}]"#
+            ),
+            None
+        );
+        assert_eq!(evaluate_wasm(&wasm, r#"["abc", 2]"#), None);
+    }
+
+    #[test]
+    fn zero_divisor_guard_panic_is_classified() {
+        let wasm = guarded_div_wasm();
+        let fault = evaluate_wasm(
+            &wasm,
+            r#"[{"type": "i64", "value": 10}, {"type": "i64", "value": 0}]"#,
+        )
+        .unwrap();
+        assert_eq!(fault.kind, ArithmeticFaultKind::DivisionByZero);
+
+        assert_eq!(
+            evaluate_wasm(
+                &wasm,
+                r#"[{"type": "i64", "value": 10}, {"type": "i64", "value": 2}]"#
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic_panics_are_classified() {
+        assert_eq!(
+            ArithmeticFaultKind::from_error_message("attempt to multiply with overflow"),
+            Some(ArithmeticFaultKind::MulOverflow)
+        );
+        assert_eq!(
+            ArithmeticFaultKind::from_error_message("attempt to divide by zero"),
+            Some(ArithmeticFaultKind::DivisionByZero)
+        );
+        assert_eq!(
+            ArithmeticFaultKind::from_error_message("UnreachableCodeReached"),
+            None
+        );
+    }
+}
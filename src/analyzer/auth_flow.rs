@@ -0,0 +1,923 @@
+//! Static data flow from the parameters of exported functions to the storage
+//! they write and the addresses they authorize, used by the
+//! missing-authorization rule.
+//!
+//! Every export is interpreted once, in instruction order, over values that
+//! only record where they came from: which parameters, a storage read, an
+//! addition or a subtraction. Both arms of a branch are followed, loops run
+//! once and calls into the module's own functions are followed with the
+//! caller's argument values.
+//!
+//! Linear memory is tracked relative to the stack pointer (the first mutable
+//! `i32` global), so a value stored in a stack frame is found again when a
+//! pointer to it is passed on. That is how a key such as
+//! `DataKey::Balance(to)`, which the SDK lays out in the frame and turns into
+//! a `Vec` with `vec_new_from_linear_memory`, carries the `to` parameter. Host
+//! calls other than storage and `require_auth` return everything their
+//! arguments and the memory they point at came from.
+
+use std::collections::BTreeMap;
+use wasmparser::{BlockType, FuncType, Operator, Parser, Payload, TypeRef, ValType};
+
+/// Instructions interpreted per export before the flow is reported incomplete.
+const FUEL: usize = 500_000;
+/// Nested calls followed before a callee is treated like an unknown import.
+const MAX_CALL_DEPTH: usize = 32;
+/// Bytes read behind a pointer passed to a host function when the length is
+/// not a constant.
+const POINTER_SPAN: i64 = 64;
+/// Tag of an `Address` host object in the low byte of a `Val`.
+const TAG_ADDRESS_OBJECT: i64 = 77;
+/// Tag of a small `u32` in the low byte of a `Val`.
+const TAG_U32_VAL: i64 = 4;
+
+/// What an imported function does, as far as the data flow is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostCall {
+    /// `require_auth` / `require_auth_for_args`; the first argument is the
+    /// address.
+    RequireAuth,
+    /// Writes or deletes the entry keyed by the first argument, storing the
+    /// second when it writes.
+    StorageWrite {
+        has_value: bool,
+    },
+    /// Returns the entry keyed by the first argument.
+    StorageRead,
+    Other,
+}
+
+/// Where a value came from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Origins(u64);
+
+impl Origins {
+    /// Parameters tracked individually; later ones share the last bit.
+    const MAX_PARAMS: usize = 48;
+    const PARAMS: u64 = (1 << Self::MAX_PARAMS) - 1;
+    const READ: u64 = 1 << 48;
+    const FIXED_KEY_READ: u64 = 1 << 49;
+    const ADD: u64 = 1 << 50;
+    const SUB: u64 = 1 << 51;
+
+    fn param(index: usize) -> Self {
+        Self(1 << index.min(Self::MAX_PARAMS - 1))
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    fn with(self, bits: u64) -> Self {
+        Self(self.0 | bits)
+    }
+
+    /// Whether the value depends on parameter `index` of the export.
+    pub fn has_param(self, index: usize) -> bool {
+        self.0 & Self::param(index).0 != 0
+    }
+
+    fn has_any_param(self) -> bool {
+        self.0 & Self::PARAMS != 0
+    }
+
+    /// Whether the value was computed from something read from storage.
+    pub fn is_read(self) -> bool {
+        self.0 & Self::READ != 0
+    }
+
+    /// Whether an addition went into the value.
+    pub fn is_added(self) -> bool {
+        self.0 & Self::ADD != 0
+    }
+
+    /// Whether a subtraction went into the value.
+    pub fn is_subtracted(self) -> bool {
+        self.0 & Self::SUB != 0
+    }
+}
+
+/// A storage write reached from an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageWrite {
+    pub key: Origins,
+    /// `None` for a delete.
+    pub value: Option<Origins>,
+}
+
+impl StorageWrite {
+    /// Whether the write only adds to what was stored before, like a deposit
+    /// crediting a balance: the value is a stored value plus something, with
+    /// no subtraction.
+    ///
+    /// This is a heuristic on the shape of the arithmetic, not on what the key
+    /// means. Adding to a debt, a nonce or an allowance looks the same as
+    /// crediting a balance, so an unauthorized write of that kind is not
+    /// reported, and a credit computed with a subtraction (such as a fee
+    /// taken off the amount) is reported.
+    pub fn is_credit(&self) -> bool {
+        self.value
+            .is_some_and(|value| value.is_read() && value.is_added() && !value.is_subtracted())
+    }
+}
+
+/// Storage writes and authorizations reached from one exported function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportFlow {
+    pub function: String,
+    pub writes: Vec<StorageWrite>,
+    /// Origins of every address passed to `require_auth`.
+    pub authorized: Vec<Origins>,
+    /// Whether the export checks that a value read from storage under a key
+    /// built from none of its parameters is an `Address`, as when it loads an
+    /// admin.
+    pub reads_stored_address: bool,
+    /// `false` when interpretation stopped early, at an unmodelled
+    /// instruction or out of fuel, so the fields above may miss something.
+    pub complete: bool,
+}
+
+impl ExportFlow {
+    /// Whether parameter `index` reaches a `require_auth` call.
+    pub fn authorizes_param(&self, index: usize) -> bool {
+        self.authorized.iter().any(|auth| auth.has_param(index))
+    }
+}
+
+/// Interpret every exported function of `wasm_bytes`, classifying imports
+/// with `classify(module, name)`. Returns nothing when the module does not
+/// parse.
+pub fn export_flows(
+    wasm_bytes: &[u8],
+    classify: impl Fn(&str, &str) -> HostCall,
+) -> Vec<ExportFlow> {
+    let Some(module) = Module::parse(wasm_bytes, classify) else {
+        return Vec::new();
+    };
+    module
+        .exports
+        .iter()
+        .map(|(function, index)| {
+            let params = module
+                .func_type(*index)
+                .map(|ty| ty.params().len())
+                .unwrap_or(0);
+            let args = (0..params)
+                .map(|i| Value::from_origins(Origins::param(i)))
+                .collect();
+            let mut interpreter = Interpreter::new(&module);
+            let complete = interpreter.call(*index, args, 0).is_ok();
+            ExportFlow {
+                function: function.clone(),
+                writes: interpreter.writes,
+                authorized: interpreter.authorized,
+                reads_stored_address: interpreter.reads_stored_address,
+                complete,
+            }
+        })
+        .collect()
+}
+
+struct Module<'a> {
+    types: Vec<FuncType>,
+    /// Type index of every function, imports first.
+    function_types: Vec<u32>,
+    imports: Vec<HostCall>,
+    bodies: Vec<(usize, Vec<Operator<'a>>)>,
+    /// Initial values of the globals; the stack pointer starts at address 0.
+    globals: Vec<Value>,
+    exports: Vec<(String, u32)>,
+}
+
+impl<'a> Module<'a> {
+    fn parse(wasm_bytes: &'a [u8], classify: impl Fn(&str, &str) -> HostCall) -> Option<Self> {
+        let mut module = Module {
+            types: Vec::new(),
+            function_types: Vec::new(),
+            imports: Vec::new(),
+            bodies: Vec::new(),
+            globals: Vec::new(),
+            exports: Vec::new(),
+        };
+        let mut stack_pointer = None;
+
+        for payload in Parser::new(0).parse_all(wasm_bytes) {
+            match payload.ok()? {
+                Payload::TypeSection(reader) => {
+                    for rec_group in reader {
+                        for ty in rec_group.ok()?.into_types() {
+                            if let wasmparser::CompositeType::Func(func_type) = ty.composite_type {
+                                module.types.push(func_type);
+                            }
+                        }
+                    }
+                }
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import.ok()?;
+                        if let TypeRef::Func(type_index) = import.ty {
+                            module.function_types.push(type_index);
+                            module.imports.push(classify(import.module, import.name));
+                        }
+                    }
+                }
+                Payload::FunctionSection(reader) => {
+                    for type_index in reader {
+                        module.function_types.push(type_index.ok()?);
+                    }
+                }
+                Payload::GlobalSection(reader) => {
+                    for global in reader {
+                        let global = global.ok()?;
+                        let index = module.globals.len();
+                        let is_stack_pointer = stack_pointer.is_none()
+                            && global.ty.mutable
+                            && global.ty.content_type == ValType::I32;
+                        if is_stack_pointer {
+                            stack_pointer = Some(index);
+                        }
+                        module.globals.push(Value {
+                            slot: is_stack_pointer.then_some(0),
+                            konst: const_value(&global.init_expr),
+                            ..Value::default()
+                        });
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export.ok()?;
+                        if export.kind == wasmparser::ExternalKind::Func {
+                            module.exports.push((export.name.to_string(), export.index));
+                        }
+                    }
+                }
+                Payload::CodeSectionEntry(body) => {
+                    let mut locals = 0usize;
+                    for local in body.get_locals_reader().ok()? {
+                        locals += local.ok()?.0 as usize;
+                    }
+                    let mut ops = Vec::new();
+                    let mut reader = body.get_operators_reader().ok()?;
+                    while !reader.eof() {
+                        ops.push(reader.read().ok()?);
+                    }
+                    module.bodies.push((locals, ops));
+                }
+                _ => {}
+            }
+        }
+        Some(module)
+    }
+
+    fn func_type(&self, func: u32) -> Option<&FuncType> {
+        self.types
+            .get(*self.function_types.get(func as usize)? as usize)
+    }
+
+    /// Parameter and result counts of a block type.
+    fn block_arity(&self, blockty: &BlockType) -> (usize, usize) {
+        match blockty {
+            BlockType::Empty => (0, 0),
+            BlockType::Type(_) => (0, 1),
+            BlockType::FuncType(index) => self
+                .types
+                .get(*index as usize)
+                .map(|ty| (ty.params().len(), ty.results().len()))
+                .unwrap_or((0, 0)),
+        }
+    }
+}
+
+fn const_value(expr: &wasmparser::ConstExpr) -> Option<i64> {
+    match expr.get_operators_reader().read() {
+        Ok(Operator::I32Const { value }) => Some(value as i64),
+        Ok(Operator::I64Const { value }) => Some(value),
+        _ => None,
+    }
+}
+
+/// A value on the stack, in a local, global or memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Value {
+    origins: Origins,
+    /// The value, when it is a constant.
+    konst: Option<i64>,
+    /// The address it points at, relative to the initial stack pointer, when
+    /// it was computed from the stack pointer. Kept through the shifts and
+    /// ors that encode a pointer into a `U32Val`.
+    slot: Option<i64>,
+}
+
+impl Value {
+    fn from_origins(origins: Origins) -> Self {
+        Self {
+            origins,
+            ..Self::default()
+        }
+    }
+
+    fn constant(value: i64) -> Self {
+        Self {
+            konst: Some(value),
+            ..Self::default()
+        }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        Self {
+            origins: self.origins.union(other.origins),
+            konst: self.konst.filter(|_| self.konst == other.konst),
+            slot: self.slot.filter(|_| self.slot == other.slot),
+        }
+    }
+}
+
+/// The interpretation stopped early.
+struct Incomplete;
+
+/// An open block, loop or if.
+struct Control {
+    /// Stack height below the block's parameters.
+    height: usize,
+    results: usize,
+    is_loop: bool,
+    /// Results merged from the branches that left the block so far.
+    exits: Option<Vec<Value>>,
+}
+
+impl Control {
+    fn exit(&mut self, values: &[Value]) {
+        self.exits = Some(match self.exits.take() {
+            Some(exits) => exits.iter().zip(values).map(|(a, b)| a.merge(*b)).collect(),
+            None => values.to_vec(),
+        });
+    }
+}
+
+struct Interpreter<'m, 'a> {
+    module: &'m Module<'a>,
+    globals: Vec<Value>,
+    /// Stack memory by address relative to the initial stack pointer.
+    stack_memory: BTreeMap<i64, Origins>,
+    /// Everything stored at an address not derived from the stack pointer.
+    heap: Origins,
+    fuel: usize,
+    calls: Vec<u32>,
+    writes: Vec<StorageWrite>,
+    authorized: Vec<Origins>,
+    reads_stored_address: bool,
+}
+
+impl<'m, 'a> Interpreter<'m, 'a> {
+    fn new(module: &'m Module<'a>) -> Self {
+        Self {
+            module,
+            globals: module.globals.clone(),
+            stack_memory: BTreeMap::new(),
+            heap: Origins::default(),
+            fuel: FUEL,
+            calls: Vec::new(),
+            writes: Vec::new(),
+            authorized: Vec::new(),
+            reads_stored_address: false,
+        }
+    }
+
+    fn call(
+        &mut self,
+        func: u32,
+        args: Vec<Value>,
+        depth: usize,
+    ) -> Result<Vec<Value>, Incomplete> {
+        let results = self
+            .module
+            .func_type(func)
+            .map(|ty| ty.results().len())
+            .ok_or(Incomplete)?;
+        let imports = self.module.imports.len();
+        if let Some(&host) = self.module.imports.get(func as usize) {
+            return Ok(self.host_call(host, &args, results));
+        }
+        let (locals, ops) = self
+            .module
+            .bodies
+            .get(func as usize - imports)
+            .ok_or(Incomplete)?;
+        if depth >= MAX_CALL_DEPTH || self.calls.contains(&func) {
+            return Ok(vec![self.opaque_result(&args); results]);
+        }
+
+        self.calls.push(func);
+        let mut frame = args;
+        frame.resize(frame.len() + locals, Value::constant(0));
+        let outcome = self.run(ops, frame, results, depth);
+        self.calls.pop();
+        outcome
+    }
+
+    fn host_call(&mut self, host: HostCall, args: &[Value], results: usize) -> Vec<Value> {
+        let arg = |i: usize| args.get(i).copied().unwrap_or_default();
+        let result = match host {
+            HostCall::RequireAuth => {
+                self.authorized.push(arg(0).origins);
+                Value::default()
+            }
+            HostCall::StorageWrite { has_value } => {
+                self.writes.push(StorageWrite {
+                    key: arg(0).origins,
+                    value: has_value.then(|| arg(1).origins),
+                });
+                Value::default()
+            }
+            HostCall::StorageRead => {
+                let mut origins = Origins::default().with(Origins::READ);
+                if !arg(0).origins.has_any_param() {
+                    origins = origins.with(Origins::FIXED_KEY_READ);
+                }
+                Value::from_origins(origins)
+            }
+            HostCall::Other => Value::from_origins(self.opaque_result(args).origins),
+        };
+        vec![result; results]
+    }
+
+    /// What a call the interpretation does not follow returns: everything
+    /// its arguments, and the stack memory they point at, came from.
+    fn opaque_result(&self, args: &[Value]) -> Value {
+        // `(ptr, len)` pairs are passed as `U32Val`s: `value << 32 | 4`.
+        let length = args
+            .iter()
+            .filter(|arg| arg.slot.is_none())
+            .find_map(|arg| {
+                arg.konst
+                    .filter(|konst| konst & 0xff == TAG_U32_VAL)
+                    .map(|konst| (konst >> 32) * 8)
+            });
+        let mut origins = Origins::default();
+        for arg in args {
+            origins = origins.union(arg.origins);
+            if let Some(slot) = arg.slot {
+                let span = length.unwrap_or(POINTER_SPAN).clamp(1, POINTER_SPAN * 16);
+                for (_, stored) in self.stack_memory.range(slot..slot + span) {
+                    origins = origins.union(*stored);
+                }
+            }
+        }
+        Value::from_origins(origins)
+    }
+
+    fn load(&self, address: Value, offset: u64, bytes: i64) -> Value {
+        let origins = match address.slot {
+            Some(slot) => {
+                let start = slot + offset as i64;
+                self.stack_memory
+                    .range(start..start + bytes)
+                    .fold(Origins::default(), |acc, (_, stored)| acc.union(*stored))
+            }
+            None => self.heap,
+        };
+        Value::from_origins(origins)
+    }
+
+    fn store(&mut self, address: Value, offset: u64, value: Value) {
+        match address.slot {
+            Some(slot) => {
+                self.stack_memory
+                    .insert(slot + offset as i64, value.origins);
+            }
+            None => self.heap = self.heap.union(value.origins),
+        }
+    }
+
+    fn run(
+        &mut self,
+        ops: &[Operator<'a>],
+        mut locals: Vec<Value>,
+        results: usize,
+        depth: usize,
+    ) -> Result<Vec<Value>, Incomplete> {
+        let mut stack: Vec<Value> = Vec::new();
+        let mut controls = vec![Control {
+            height: 0,
+            results,
+            is_loop: false,
+            exits: None,
+        }];
+        // Inside code after a branch, trap or return: nesting depth of the
+        // blocks opened since, skipped until the enclosing `else` or `end`.
+        let mut dead: Option<usize> = None;
+
+        macro_rules! pop {
+            () => {
+                stack.pop().ok_or(Incomplete)?
+            };
+        }
+
+        for op in ops {
+            self.fuel = self.fuel.checked_sub(1).ok_or(Incomplete)?;
+
+            if let Some(nested) = dead.as_mut() {
+                match op {
+                    Operator::Block { .. } | Operator::Loop { .. } | Operator::If { .. } => {
+                        *nested += 1;
+                        continue;
+                    }
+                    Operator::Else | Operator::End if *nested > 0 => {
+                        if matches!(op, Operator::End) {
+                            *nested -= 1;
+                        }
+                        continue;
+                    }
+                    Operator::Else | Operator::End => {}
+                    _ => continue,
+                }
+            }
+
+            match op {
+                Operator::Nop => {}
+                Operator::Unreachable => {
+                    let height = controls.last().ok_or(Incomplete)?.height;
+                    stack.truncate(height);
+                    dead = Some(0);
+                }
+                Operator::Block { blockty } | Operator::Loop { blockty } => {
+                    let (params, results) = self.module.block_arity(blockty);
+                    controls.push(Control {
+                        height: stack.len().checked_sub(params).ok_or(Incomplete)?,
+                        results,
+                        is_loop: matches!(op, Operator::Loop { .. }),
+                        exits: None,
+                    });
+                }
+                Operator::If { blockty } => {
+                    pop!();
+                    let (params, results) = self.module.block_arity(blockty);
+                    controls.push(Control {
+                        height: stack.len().checked_sub(params).ok_or(Incomplete)?,
+                        results,
+                        is_loop: false,
+                        exits: None,
+                    });
+                }
+                Operator::Else => {
+                    let control = controls.last_mut().ok_or(Incomplete)?;
+                    if dead.is_none() {
+                        let from = stack.len().checked_sub(control.results).ok_or(Incomplete)?;
+                        control.exit(&stack[from..]);
+                    }
+                    stack.truncate(control.height);
+                    dead = None;
+                }
+                Operator::End => {
+                    let mut control = controls.pop().ok_or(Incomplete)?;
+                    if dead.is_none() {
+                        let from = stack.len().checked_sub(control.results).ok_or(Incomplete)?;
+                        control.exit(&stack[from..]);
+                    }
+                    stack.truncate(control.height);
+                    let exits = control
+                        .exits
+                        .unwrap_or_else(|| vec![Value::default(); control.results]);
+                    if controls.is_empty() {
+                        return Ok(exits);
+                    }
+                    stack.extend(exits);
+                    dead = None;
+                }
+                Operator::Br { relative_depth } => {
+                    self.branch(&mut controls, &stack, *relative_depth)?;
+                    let height = controls.last().ok_or(Incomplete)?.height;
+                    stack.truncate(height);
+                    dead = Some(0);
+                }
+                Operator::BrIf { relative_depth } => {
+                    pop!();
+                    self.branch(&mut controls, &stack, *relative_depth)?;
+                }
+                Operator::BrTable { targets } => {
+                    pop!();
+                    for target in targets.targets().chain(Some(Ok(targets.default()))) {
+                        let target = target.map_err(|_| Incomplete)?;
+                        self.branch(&mut controls, &stack, target)?;
+                    }
+                    let height = controls.last().ok_or(Incomplete)?.height;
+                    stack.truncate(height);
+                    dead = Some(0);
+                }
+                Operator::Return => {
+                    let depth = controls.len() as u32 - 1;
+                    self.branch(&mut controls, &stack, depth)?;
+                    let height = controls.last().ok_or(Incomplete)?.height;
+                    stack.truncate(height);
+                    dead = Some(0);
+                }
+                Operator::Call { function_index } => {
+                    let params = self
+                        .module
+                        .func_type(*function_index)
+                        .ok_or(Incomplete)?
+                        .params()
+                        .len();
+                    let from = stack.len().checked_sub(params).ok_or(Incomplete)?;
+                    let args = stack.split_off(from);
+                    let results = self.call(*function_index, args, depth + 1)?;
+                    stack.extend(results);
+                }
+                Operator::CallIndirect { type_index, .. } => {
+                    pop!();
+                    let ty = self
+                        .module
+                        .types
+                        .get(*type_index as usize)
+                        .ok_or(Incomplete)?;
+                    let from = stack
+                        .len()
+                        .checked_sub(ty.params().len())
+                        .ok_or(Incomplete)?;
+                    let args = stack.split_off(from);
+                    let result = self.opaque_result(&args);
+                    stack.extend(std::iter::repeat_n(result, ty.results().len()));
+                }
+                Operator::Drop => {
+                    pop!();
+                }
+                Operator::Select | Operator::TypedSelect { .. } => {
+                    pop!();
+                    let b = pop!();
+                    let a = pop!();
+                    stack.push(a.merge(b));
+                }
+                Operator::LocalGet { local_index } => {
+                    stack.push(*locals.get(*local_index as usize).ok_or(Incomplete)?);
+                }
+                Operator::LocalSet { local_index } => {
+                    let value = pop!();
+                    *locals.get_mut(*local_index as usize).ok_or(Incomplete)? = value;
+                }
+                Operator::LocalTee { local_index } => {
+                    let value = *stack.last().ok_or(Incomplete)?;
+                    *locals.get_mut(*local_index as usize).ok_or(Incomplete)? = value;
+                }
+                Operator::GlobalGet { global_index } => {
+                    stack.push(*self.globals.get(*global_index as usize).ok_or(Incomplete)?);
+                }
+                Operator::GlobalSet { global_index } => {
+                    let value = pop!();
+                    *self
+                        .globals
+                        .get_mut(*global_index as usize)
+                        .ok_or(Incomplete)? = value;
+                }
+                Operator::I32Const { value } => stack.push(Value::constant(*value as i64)),
+                Operator::I64Const { value } => stack.push(Value::constant(*value)),
+                Operator::MemorySize { .. } => stack.push(Value::default()),
+                Operator::MemoryGrow { .. } => {
+                    pop!();
+                    stack.push(Value::default());
+                }
+                Operator::MemoryCopy { .. } | Operator::MemoryFill { .. } => {
+                    pop!();
+                    pop!();
+                    pop!();
+                }
+                op => {
+                    if let Some((offset, bytes)) = load_access(op) {
+                        let address = pop!();
+                        stack.push(self.load(address, offset, bytes));
+                    } else if let Some(offset) = store_offset(op) {
+                        let value = pop!();
+                        let address = pop!();
+                        self.store(address, offset, value);
+                    } else if is_unary(op) {
+                        let value = pop!();
+                        stack.push(unary(op, value));
+                    } else if is_binary(op) {
+                        let rhs = pop!();
+                        let lhs = pop!();
+                        if matches!(
+                            op,
+                            Operator::I32Eq | Operator::I32Ne | Operator::I64Eq | Operator::I64Ne
+                        ) && self.is_stored_address_check(lhs, rhs)
+                        {
+                            self.reads_stored_address = true;
+                        }
+                        stack.push(binary(op, lhs, rhs));
+                    } else {
+                        return Err(Incomplete);
+                    }
+                }
+            }
+        }
+        Err(Incomplete)
+    }
+
+    /// Record a branch out of the block `relative_depth` levels up.
+    fn branch(
+        &self,
+        controls: &mut [Control],
+        stack: &[Value],
+        relative_depth: u32,
+    ) -> Result<(), Incomplete> {
+        let index = controls
+            .len()
+            .checked_sub(relative_depth as usize + 1)
+            .ok_or(Incomplete)?;
+        let target = &mut controls[index];
+        if !target.is_loop {
+            let from = stack.len().checked_sub(target.results).ok_or(Incomplete)?;
+            target.exit(&stack[from..]);
+        }
+        Ok(())
+    }
+
+    /// Whether one side is the `Address` tag and the other was read from
+    /// storage under a key built from no parameter.
+    fn is_stored_address_check(&self, lhs: Value, rhs: Value) -> bool {
+        let stored = |value: Value| value.origins.0 & Origins::FIXED_KEY_READ != 0;
+        (lhs.konst == Some(TAG_ADDRESS_OBJECT) && stored(rhs))
+            || (rhs.konst == Some(TAG_ADDRESS_OBJECT) && stored(lhs))
+    }
+}
+
+fn load_access(op: &Operator) -> Option<(u64, i64)> {
+    Some(match op {
+        Operator::I32Load { memarg } | Operator::I64Load32S { memarg } => (memarg.offset, 4),
+        Operator::I64Load32U { memarg } => (memarg.offset, 4),
+        Operator::I64Load { memarg } => (memarg.offset, 8),
+        Operator::I32Load8S { memarg }
+        | Operator::I32Load8U { memarg }
+        | Operator::I64Load8S { memarg }
+        | Operator::I64Load8U { memarg } => (memarg.offset, 1),
+        Operator::I32Load16S { memarg }
+        | Operator::I32Load16U { memarg }
+        | Operator::I64Load16S { memarg }
+        | Operator::I64Load16U { memarg } => (memarg.offset, 2),
+        _ => return None,
+    })
+}
+
+fn store_offset(op: &Operator) -> Option<u64> {
+    match op {
+        Operator::I32Store { memarg }
+        | Operator::I64Store { memarg }
+        | Operator::I32Store8 { memarg }
+        | Operator::I32Store16 { memarg }
+        | Operator::I64Store8 { memarg }
+        | Operator::I64Store16 { memarg }
+        | Operator::I64Store32 { memarg } => Some(memarg.offset),
+        _ => None,
+    }
+}
+
+fn is_unary(op: &Operator) -> bool {
+    matches!(
+        op,
+        Operator::I32Eqz
+            | Operator::I64Eqz
+            | Operator::I32Clz
+            | Operator::I64Clz
+            | Operator::I32Ctz
+            | Operator::I64Ctz
+            | Operator::I32Popcnt
+            | Operator::I64Popcnt
+            | Operator::I32WrapI64
+            | Operator::I64ExtendI32S
+            | Operator::I64ExtendI32U
+            | Operator::I32Extend8S
+            | Operator::I32Extend16S
+            | Operator::I64Extend8S
+            | Operator::I64Extend16S
+            | Operator::I64Extend32S
+    )
+}
+
+fn unary(op: &Operator, value: Value) -> Value {
+    match op {
+        // Conversions between widths keep the value and the pointer.
+        Operator::I32WrapI64 | Operator::I64ExtendI32S | Operator::I64ExtendI32U => value,
+        _ => Value::from_origins(value.origins),
+    }
+}
+
+fn is_binary(op: &Operator) -> bool {
+    matches!(
+        op,
+        Operator::I32Add
+            | Operator::I64Add
+            | Operator::I32Sub
+            | Operator::I64Sub
+            | Operator::I32Mul
+            | Operator::I64Mul
+            | Operator::I32DivS
+            | Operator::I64DivS
+            | Operator::I32DivU
+            | Operator::I64DivU
+            | Operator::I32RemS
+            | Operator::I64RemS
+            | Operator::I32RemU
+            | Operator::I64RemU
+            | Operator::I32And
+            | Operator::I64And
+            | Operator::I32Or
+            | Operator::I64Or
+            | Operator::I32Xor
+            | Operator::I64Xor
+            | Operator::I32Shl
+            | Operator::I64Shl
+            | Operator::I32ShrS
+            | Operator::I64ShrS
+            | Operator::I32ShrU
+            | Operator::I64ShrU
+            | Operator::I32Rotl
+            | Operator::I64Rotl
+            | Operator::I32Rotr
+            | Operator::I64Rotr
+            | Operator::I32Eq
+            | Operator::I64Eq
+            | Operator::I32Ne
+            | Operator::I64Ne
+            | Operator::I32LtS
+            | Operator::I64LtS
+            | Operator::I32LtU
+            | Operator::I64LtU
+            | Operator::I32GtS
+            | Operator::I64GtS
+            | Operator::I32GtU
+            | Operator::I64GtU
+            | Operator::I32LeS
+            | Operator::I64LeS
+            | Operator::I32LeU
+            | Operator::I64LeU
+            | Operator::I32GeS
+            | Operator::I64GeS
+            | Operator::I32GeU
+            | Operator::I64GeU
+    )
+}
+
+fn binary(op: &Operator, lhs: Value, rhs: Value) -> Value {
+    let origins = lhs.origins.union(rhs.origins);
+    let both = lhs.konst.zip(rhs.konst);
+    let pointer = lhs.slot.or(rhs.slot);
+    match op {
+        // Pointer arithmetic moves the pointer; arithmetic on data is
+        // recorded, as a credit and a debit differ only in it.
+        Operator::I32Add | Operator::I64Add | Operator::I32Sub | Operator::I64Sub => {
+            let is_sub = matches!(op, Operator::I32Sub | Operator::I64Sub);
+            let konst = both.map(|(a, b)| {
+                if is_sub {
+                    a.wrapping_sub(b)
+                } else {
+                    a.wrapping_add(b)
+                }
+            });
+            let slot = match (lhs.slot, rhs.slot, lhs.konst, rhs.konst) {
+                (Some(slot), None, _, Some(k)) => Some(if is_sub { slot - k } else { slot + k }),
+                (None, Some(slot), Some(k), _) if !is_sub => Some(slot + k),
+                _ => None,
+            };
+            let origins = match (pointer, matches!(op, Operator::I64Add | Operator::I64Sub)) {
+                (None, true) if is_sub => origins.with(Origins::SUB),
+                (None, true) => origins.with(Origins::ADD),
+                _ => origins,
+            };
+            Value {
+                origins,
+                konst,
+                slot,
+            }
+        }
+        // Encoding and decoding a `U32Val` keeps the pointer it carries.
+        Operator::I32Shl
+        | Operator::I64Shl
+        | Operator::I32ShrU
+        | Operator::I64ShrU
+        | Operator::I32Or
+        | Operator::I64Or
+        | Operator::I32And
+        | Operator::I64And => {
+            let konst = both.and_then(|(a, b)| match op {
+                Operator::I32Shl | Operator::I64Shl => a.checked_shl(b as u32 & 63),
+                Operator::I32ShrU | Operator::I64ShrU => {
+                    Some(((a as u64) >> (b as u32 & 63)) as i64)
+                }
+                Operator::I32Or | Operator::I64Or => Some(a | b),
+                _ => Some(a & b),
+            });
+            Value {
+                origins,
+                konst,
+                slot: pointer,
+            }
+        }
+        _ => Value {
+            origins,
+            konst: both.and_then(|(a, b)| match op {
+                Operator::I32Xor | Operator::I64Xor => Some(a ^ b),
+                Operator::I32Mul | Operator::I64Mul => Some(a.wrapping_mul(b)),
+                _ => None,
+            }),
+            slot: None,
+        },
+    }
+}
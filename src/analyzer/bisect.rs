@@ -0,0 +1,326 @@
+//! Binary search over an integer input range for the first failing value.
+//!
+//! A function that works for small inputs and fails for large ones (typically
+//! by exhausting the budget) has a boundary somewhere in between. `bisect`
+//! assumes failures are monotonic in the input and finds the smallest value in
+//! the range that fails, probing each candidate in a fresh executor.
+
+use crate::inspector::budget::BudgetInspector;
+use crate::runtime::executor::ContractExecutor;
+use crate::{DebuggerError, Result};
+use serde::Serialize;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Placeholder replaced by the probed value in an argument template.
+pub const VALUE_PLACEHOLDER: &str = "{n}";
+
+/// Inclusive integer range written as `LO..HI`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ParamRange {
+    pub start: i128,
+    pub end: i128,
+}
+
+impl ParamRange {
+    pub fn parse(spec: &str) -> Result<Self> {
+        let invalid = || {
+            DebuggerError::InvalidArguments(format!(
+                "Invalid --param-range '{}': expected LO..HI (e.g. 1..1000000)",
+                spec
+            ))
+        };
+        let (start, end) = spec.split_once("..").ok_or_else(invalid)?;
+        let start = start.trim().parse::<i128>().map_err(|_| invalid())?;
+        let end = end
+            .trim()
+            .trim_start_matches('=')
+            .parse::<i128>()
+            .map_err(|_| invalid())?;
+        if start > end {
+            return Err(DebuggerError::InvalidArguments(format!(
+                "Invalid --param-range '{}': start is greater than end",
+                spec
+            ))
+            .into());
+        }
+        Ok(Self { start, end })
+    }
+}
+
+/// Which outcomes count as a failure while searching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureCondition {
+    Any,
+    Budget,
+    Error,
+}
+
+/// Result of running the function with one candidate value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "outcome", content = "message", rename_all = "snake_case")]
+pub enum ProbeOutcome {
+    Passed,
+    BudgetExceeded(String),
+    Error(String),
+}
+
+impl ProbeOutcome {
+    pub fn is_failure(&self, condition: FailureCondition) -> bool {
+        matches!(
+            (self, condition),
+            (ProbeOutcome::BudgetExceeded(_), FailureCondition::Any)
+                | (ProbeOutcome::BudgetExceeded(_), FailureCondition::Budget)
+                | (ProbeOutcome::Error(_), FailureCondition::Any)
+                | (ProbeOutcome::Error(_), FailureCondition::Error)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Probe {
+    pub value: String,
+    #[serde(flatten)]
+    pub outcome: ProbeOutcome,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BisectReport {
+    pub function: String,
+    pub range: ParamRange,
+    pub fail_on: FailureCondition,
+    /// Smallest failing value, or `None` when the whole range passes.
+    pub boundary: Option<String>,
+    /// Outcome at the boundary, if one was found.
+    pub failure: Option<ProbeOutcome>,
+    pub probes: Vec<Probe>,
+}
+
+impl BisectReport {
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "Bisected {} over {}..{} ({} probes)\n",
+            self.function,
+            self.range.start,
+            self.range.end,
+            self.probes.len()
+        );
+        for probe in &self.probes {
+            let status = match &probe.outcome {
+                ProbeOutcome::Passed => "pass".to_string(),
+                ProbeOutcome::BudgetExceeded(msg) => format!("budget: {}", msg),
+                ProbeOutcome::Error(msg) => format!("error: {}", msg),
+            };
+            out.push_str(&format!("  {:>20}  {}\n", probe.value, status));
+        }
+        match &self.boundary {
+            Some(value) => out.push_str(&format!("First failing value: {}\n", value)),
+            None => out.push_str("No failing value in range\n"),
+        }
+        out
+    }
+}
+
+/// Find the smallest value in `range` for which `probe` reports a failure.
+///
+/// The upper end is probed first so a range without failures costs one run.
+pub fn search<F>(range: ParamRange, condition: FailureCondition, mut probe: F) -> BisectResult
+where
+    F: FnMut(i128) -> ProbeOutcome,
+{
+    let mut probes = Vec::new();
+    let mut run = |value: i128, probes: &mut Vec<(i128, ProbeOutcome)>| {
+        let outcome = probe(value);
+        let failed = outcome.is_failure(condition);
+        probes.push((value, outcome));
+        failed
+    };
+
+    if !run(range.end, &mut probes) {
+        return BisectResult {
+            boundary: None,
+            probes,
+        };
+    }
+    if range.start == range.end || run(range.start, &mut probes) {
+        return BisectResult {
+            boundary: Some(range.start),
+            probes,
+        };
+    }
+
+    // Invariant: `passing` passes and `failing` fails. The gap is measured as
+    // a u128, as it overflows i128 for ranges spanning more than half of it.
+    let (mut passing, mut failing) = (range.start, range.end);
+    while failing.abs_diff(passing) > 1 {
+        let mid = passing + (failing.abs_diff(passing) / 2) as i128;
+        if run(mid, &mut probes) {
+            failing = mid;
+        } else {
+            passing = mid;
+        }
+    }
+
+    BisectResult {
+        boundary: Some(failing),
+        probes,
+    }
+}
+
+/// Boundary and probe history returned by [`search`].
+#[derive(Debug, Clone)]
+pub struct BisectResult {
+    pub boundary: Option<i128>,
+    pub probes: Vec<(i128, ProbeOutcome)>,
+}
+
+/// Inputs needed to run the contract for one candidate value.
+pub struct BisectTarget<'a> {
+    pub wasm: &'a [u8],
+    pub function: &'a str,
+    /// JSON argument array containing [`VALUE_PLACEHOLDER`].
+    pub args_template: &'a str,
+    pub storage: Option<&'a str>,
+    pub timeout_secs: u64,
+}
+
+impl BisectTarget<'_> {
+    pub fn run(&self, range: ParamRange, condition: FailureCondition) -> Result<BisectReport> {
+        if !self.args_template.contains(VALUE_PLACEHOLDER) {
+            return Err(DebuggerError::InvalidArguments(format!(
+                "Argument template must contain the {} placeholder",
+                VALUE_PLACEHOLDER
+            ))
+            .into());
+        }
+
+        let result = search(range, condition, |value| self.probe(value));
+        let failure = result.boundary.and_then(|boundary| {
+            result
+                .probes
+                .iter()
+                .find(|(value, _)| *value == boundary)
+                .map(|(_, outcome)| outcome.clone())
+        });
+
+        Ok(BisectReport {
+            function: self.function.to_string(),
+            range,
+            fail_on: condition,
+            boundary: result.boundary.map(|b| b.to_string()),
+            failure,
+            probes: result
+                .probes
+                .into_iter()
+                .map(|(value, outcome)| Probe {
+                    value: value.to_string(),
+                    outcome,
+                })
+                .collect(),
+        })
+    }
+
+    /// Run the function once with `value` substituted into the arguments.
+    fn probe(&self, value: i128) -> ProbeOutcome {
+        let args = self
+            .args_template
+            .replace(VALUE_PLACEHOLDER, &value.to_string());
+
+        let mut executor = match ContractExecutor::new(self.wasm.to_vec()) {
+            Ok(executor) => executor,
+            Err(e) => return ProbeOutcome::Error(e.to_string()),
+        };
+        executor.set_timeout(self.timeout_secs);
+        if let Some(storage) = self.storage {
+            if let Err(e) = executor.set_initial_storage(storage.to_string()) {
+                return ProbeOutcome::Error(e.to_string());
+            }
+        }
+
+        let run = catch_unwind(AssertUnwindSafe(|| {
+            executor.execute(self.function, Some(&args))
+        }));
+        let message = match run {
+            Ok(Ok(_)) => return ProbeOutcome::Passed,
+            Ok(Err(e)) => e.to_string(),
+            Err(payload) => panic_message(payload),
+        };
+
+        // The host reports exhaustion as a generic abort, so look at the meter.
+        let budget = BudgetInspector::get_cpu_usage(executor.host());
+        let exhausted = (budget.cpu_limit > 0 && budget.cpu_instructions >= budget.cpu_limit)
+            || (budget.memory_limit > 0 && budget.memory_bytes >= budget.memory_limit)
+            || message.contains("ExceededLimit");
+        if exhausted {
+            ProbeOutcome::BudgetExceeded(message)
+        } else {
+            ProbeOutcome::Error(message)
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Contract execution panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_inclusive_ranges() {
+        assert_eq!(
+            ParamRange::parse("1..1000000").unwrap(),
+            ParamRange {
+                start: 1,
+                end: 1_000_000
+            }
+        );
+        assert_eq!(ParamRange::parse("-5..=5").unwrap().start, -5);
+        assert!(ParamRange::parse("10..1").is_err());
+        assert!(ParamRange::parse("10").is_err());
+    }
+
+    #[test]
+    fn search_finds_smallest_failing_value() {
+        let range = ParamRange::parse("1..1000000").unwrap();
+        let result = search(range, FailureCondition::Budget, |n| {
+            if n >= 4242 {
+                ProbeOutcome::BudgetExceeded("out of budget".to_string())
+            } else {
+                ProbeOutcome::Passed
+            }
+        });
+        assert_eq!(result.boundary, Some(4242));
+        assert!(result.probes.len() <= 22);
+
+        let none = search(range, FailureCondition::Budget, |_| {
+            ProbeOutcome::Error("trap".to_string())
+        });
+        assert_eq!(none.boundary, None);
+        assert_eq!(none.probes.len(), 1);
+    }
+
+    #[test]
+    fn search_handles_the_full_i128_range() {
+        let range = ParamRange {
+            start: i128::MIN,
+            end: i128::MAX,
+        };
+        let result = search(range, FailureCondition::Budget, |n| {
+            if n >= -7 {
+                ProbeOutcome::BudgetExceeded("out of budget".to_string())
+            } else {
+                ProbeOutcome::Passed
+            }
+        });
+        assert_eq!(result.boundary, Some(-7));
+        assert!(result.probes.len() <= 130);
+    }
+}
@@ -1,6 +1,7 @@
-use std::collections::BTreeSet;
+use crate::utils::wasm::CrossContractCall;
+use std::collections::{BTreeSet, HashMap};
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
 pub struct DependencyEdge {
     pub from: String,
     pub to: String,
@@ -17,6 +18,15 @@ impl DependencyGraph {
         Self::default()
     }
 
+    /// Graph with an edge from each calling function to its call target.
+    pub fn from_calls(calls: &[CrossContractCall]) -> Self {
+        let mut graph = Self::new();
+        for call in calls {
+            graph.add_edge(call.caller.as_str(), call.target.as_str());
+        }
+        graph
+    }
+
     pub fn add_node(&mut self, node: impl Into<String>) {
         self.nodes.insert(node.into());
     }
@@ -29,6 +39,23 @@ impl DependencyGraph {
         self.edges.insert(DependencyEdge { from, to });
     }
 
+    /// Copy of the graph with each node renamed through `labels`. Nodes
+    /// without an entry keep their raw identifier.
+    pub fn with_labels(&self, labels: &HashMap<String, String>) -> Self {
+        let label = |node: &String| labels.get(node).unwrap_or(node).clone();
+        Self {
+            nodes: self.nodes.iter().map(label).collect(),
+            edges: self
+                .edges
+                .iter()
+                .map(|edge| DependencyEdge {
+                    from: label(&edge.from),
+                    to: label(&edge.to),
+                })
+                .collect(),
+        }
+    }
+
     pub fn edges(&self) -> Vec<DependencyEdge> {
         self.edges.iter().cloned().collect()
     }
@@ -86,6 +113,14 @@ impl DependencyGraph {
 
         out
     }
+
+    /// `{"nodes": [...], "edges": [{"from": .., "to": ..}]}`, both sorted.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "nodes": self.nodes,
+            "edges": self.edges,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -111,4 +146,43 @@ mod tests {
         assert!(mermaid.starts_with("flowchart TD"));
         assert!(mermaid.contains("\"contract_a\" --> \"oracle_contract\""));
     }
+
+    #[test]
+    fn labels_rename_known_nodes_only() {
+        let mut graph = DependencyGraph::new();
+        graph.add_edge("call", "external_contract");
+        graph.add_edge("swap", "CAPOOL");
+
+        let labels = HashMap::from([("external_contract".to_string(), "token".to_string())]);
+        let labeled = graph.with_labels(&labels);
+
+        assert_eq!(
+            labeled.edges(),
+            vec![
+                DependencyEdge {
+                    from: "call".into(),
+                    to: "token".into()
+                },
+                DependencyEdge {
+                    from: "swap".into(),
+                    to: "CAPOOL".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn json_export_lists_nodes_and_edges() {
+        let mut graph = DependencyGraph::new();
+        graph.add_edge("contract_a", "oracle_contract");
+        graph.add_node("standalone");
+
+        assert_eq!(
+            graph.to_json(),
+            serde_json::json!({
+                "nodes": ["contract_a", "oracle_contract", "standalone"],
+                "edges": [{"from": "contract_a", "to": "oracle_contract"}],
+            })
+        );
+    }
 }
@@ -1,4 +1,8 @@
+pub mod arithmetic;
+pub mod auth_flow;
+pub mod bisect;
 pub mod graph;
 pub mod security;
+pub mod spec_diff;
 pub mod symbolic;
 pub mod upgrade;
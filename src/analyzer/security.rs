@@ -1,6 +1,9 @@
+use crate::analyzer::auth_flow::{self, HostCall};
 use crate::runtime::executor::ContractExecutor;
 use crate::server::protocol::{DynamicTraceEvent, DynamicTraceEventKind};
-use crate::utils::wasm::{parse_instructions, WasmInstruction};
+use crate::utils::wasm::{
+    parse_function_signatures, parse_instructions, sdk_host_function_name, WasmInstruction,
+};
 use crate::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -12,6 +15,10 @@ pub enum Severity {
     Low,
     Medium,
     High,
+    /// Reserved for issues that need fixing before any deployment. No
+    /// built-in rule reports it yet; it exists so `--fail-on critical` can
+    /// gate on future rules without failing on today's high findings.
+    Critical,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -126,11 +133,17 @@ impl SecurityAnalyzer {
                 Box::new(CrossContractImportRule),
                 Box::new(UnboundedIterationRule),
                 Box::new(StorageWritePressureRule),
+                Box::new(ChecksEffectsInteractionsRule),
             ],
             suppressions: Vec::new(),
         }
     }
 
+    /// Metadata for every registered rule, in evaluation order.
+    pub fn rule_metadata(&self) -> Vec<RuleMetadata> {
+        self.rules.iter().map(|rule| rule.metadata()).collect()
+    }
+
     pub fn with_suppressions(mut self, suppressions: Vec<AnalyzerSuppression>) -> Self {
         self.suppressions = suppressions;
         self
@@ -564,6 +577,110 @@ impl SecurityRule for AuthorizationCheckRule {
         Some("Identify all caller arguments and invoke `address.require_auth()` before persisting state changes.")
     }
 
+    /// Flags exported functions that take an `Address` and, tracing keys and
+    /// values with [`auth_flow`]:
+    /// - write storage under a key built from an `Address` parameter that
+    ///   never reaches `require_auth`, unless the write only adds to the stored
+    ///   value, as a deposit crediting a balance does; or
+    /// - check that a value stored under a fixed key is an `Address`, such as
+    ///   an admin, and write storage without calling `require_auth` at all.
+    ///
+    /// When the data flow cannot be traced through an export, any reachable
+    /// write without a `require_auth` call is reported at lower confidence.
+    fn analyze_static(&self, wasm_bytes: &[u8]) -> Result<Vec<SecurityFinding>> {
+        let Ok(signatures) = parse_function_signatures(wasm_bytes) else {
+            return Ok(Vec::new());
+        };
+        let address_params: HashMap<String, Vec<(usize, String)>> = signatures
+            .into_iter()
+            .map(|sig| {
+                let params = sig
+                    .params
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| p.type_name == "Address")
+                    .map(|(index, p)| (index, p.name.clone()))
+                    .collect::<Vec<_>>();
+                (sig.name, params)
+            })
+            .filter(|(_, params)| !params.is_empty())
+            .collect();
+        if address_params.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut findings = Vec::new();
+        for flow in auth_flow::export_flows(wasm_bytes, auth_flow_host_call) {
+            let Some(params) = address_params.get(&flow.function) else {
+                continue;
+            };
+            if flow.writes.is_empty() {
+                continue;
+            }
+
+            let (description, rationale, confidence) = if !flow.complete {
+                if !flow.authorized.is_empty() {
+                    continue;
+                }
+                (
+                    format!(
+                        "Missing authorization: '{}' takes an Address and writes storage but never calls require_auth.",
+                        flow.function
+                    ),
+                    "The data flow could not be traced through this export; a storage write is reachable and no require_auth call is.",
+                    0.5,
+                )
+            } else {
+                let unauthorized: Vec<String> = params
+                    .iter()
+                    .filter(|(index, _)| {
+                        !flow.authorizes_param(*index)
+                            && flow
+                                .writes
+                                .iter()
+                                .any(|write| write.key.has_param(*index) && !write.is_credit())
+                    })
+                    .map(|(_, name)| format!("'{}'", name))
+                    .collect();
+                if !unauthorized.is_empty() {
+                    (
+                        format!(
+                            "Missing authorization: '{}' writes storage keyed by its Address parameter {} without calling require_auth for it.",
+                            flow.function,
+                            unauthorized.join(", ")
+                        ),
+                        "A storage key built from the parameter reaches a storage write, and the parameter never reaches require_auth. Writes that only add to the stored value are not counted.",
+                        0.8,
+                    )
+                } else if flow.reads_stored_address && flow.authorized.is_empty() {
+                    (
+                        format!(
+                            "Missing authorization: '{}' loads an Address from storage, such as an admin, and writes storage but never calls require_auth.",
+                            flow.function
+                        ),
+                        "A value read under a fixed storage key is checked to be an Address, a storage write follows, and no require_auth call is reachable.",
+                        0.7,
+                    )
+                } else {
+                    continue;
+                }
+            };
+
+            findings.push(SecurityFinding {
+                rule_id: self.id().to_string(),
+                severity: Severity::High,
+                location: format!("function '{}'", flow.function),
+                description,
+                remediation: "Call `address.require_auth()` for the address whose state is modified before writing storage.".to_string(),
+                confidence: Some(confidence),
+                rationale: Some(rationale.to_string()),
+                fingerprint: format!("{}:static:{}", self.id(), flow.function),
+                suppressed: false,
+            });
+        }
+        Ok(findings)
+    }
+
     fn analyze_dynamic(
         &self,
         _executor: Option<&ContractExecutor>,
@@ -849,6 +966,9 @@ fn is_cross_contract_host_function_name(name: &str) -> bool {
 }
 
 fn is_cross_contract_host_import(module: &str, name: &str) -> bool {
+    if let Some(host_fn) = sdk_host_function_name(module, name) {
+        return matches!(host_fn, "call" | "try_call");
+    }
     is_env_like_module(module) && is_cross_contract_host_function_name(name)
 }
 
@@ -1079,7 +1199,39 @@ fn analyze_unbounded_iteration_static(wasm_bytes: &[u8]) -> UnboundedStaticSigna
     signal
 }
 
+/// How the missing-authorization data flow treats an import.
+fn auth_flow_host_call(module: &str, name: &str) -> HostCall {
+    if is_require_auth_import(module, name) {
+        HostCall::RequireAuth
+    } else if is_storage_write_import(module, name) {
+        let host_fn = sdk_host_function_name(module, name).unwrap_or(name);
+        HostCall::StorageWrite {
+            has_value: !canonicalize_ascii(host_fn).contains("del"),
+        }
+    } else if is_storage_read_import(module, name) {
+        HostCall::StorageRead
+    } else {
+        HostCall::Other
+    }
+}
+
+fn is_require_auth_import(module: &str, name: &str) -> bool {
+    if let Some(host_fn) = sdk_host_function_name(module, name) {
+        return matches!(host_fn, "require_auth" | "require_auth_for_args");
+    }
+
+    is_env_like_module(module)
+        && matches!(
+            canonicalize_ascii(name).as_str(),
+            "requireauth" | "requireauthforargs"
+        )
+}
+
 fn is_storage_read_import(module: &str, name: &str) -> bool {
+    if let Some(host_fn) = sdk_host_function_name(module, name) {
+        return is_storage_read_import("env", host_fn);
+    }
+
     const BASES: &[&str] = &[
         "storageget",
         "storagehas",
@@ -1123,6 +1275,10 @@ fn is_storage_read_import(module: &str, name: &str) -> bool {
 }
 
 fn is_storage_write_import(module: &str, name: &str) -> bool {
+    if let Some(host_fn) = sdk_host_function_name(module, name) {
+        return is_storage_write_import("env", host_fn);
+    }
+
     const BASES: &[&str] = &[
         "storageput",
         "storageset",
@@ -1265,6 +1421,145 @@ impl SecurityRule for StorageWritePressureRule {
     }
 }
 
+struct ChecksEffectsInteractionsRule;
+impl SecurityRule for ChecksEffectsInteractionsRule {
+    fn id(&self) -> &str {
+        "checks-effects-interactions"
+    }
+
+    fn name(&self) -> &str {
+        "Checks-Effects-Interactions detector"
+    }
+
+    fn description(&self) -> &str {
+        "Flags functions that write storage after making an external contract call."
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::High
+    }
+
+    fn rationale(&self) -> Option<&str> {
+        Some("State changes made after an external call can be observed or overwritten by a re-entrant callee.")
+    }
+
+    fn remediation(&self) -> Option<&str> {
+        Some("Apply all storage effects before invoking other contracts, or guard the function with a re-entrancy lock.")
+    }
+
+    fn analyze_static(&self, wasm_bytes: &[u8]) -> Result<Vec<SecurityFinding>> {
+        Ok(find_writes_after_external_calls(wasm_bytes)
+            .into_iter()
+            .map(|hazard| SecurityFinding {
+                rule_id: self.id().to_string(),
+                severity: Severity::High,
+                location: format!("{} @ 0x{:08x}", hazard.function, hazard.write_offset),
+                description: format!(
+                    "Function '{}' writes storage at 0x{:08x} after an external contract call at 0x{:08x}.",
+                    hazard.function, hazard.write_offset, hazard.call_offset
+                ),
+                remediation: self.remediation().unwrap_or_default().to_string(),
+                confidence: Some(0.8),
+                rationale: self.rationale().map(|r| r.to_string()),
+                fingerprint: format!("{}:{}:{}", self.id(), hazard.function, hazard.write_offset),
+                suppressed: false,
+            })
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PostCallWrite {
+    function: String,
+    call_offset: usize,
+    write_offset: usize,
+}
+
+/// Scan each function body for a storage-write host call that follows an
+/// external `invoke_contract`-style call. Reports the first such write per function.
+fn find_writes_after_external_calls(wasm_bytes: &[u8]) -> Vec<PostCallWrite> {
+    let mut external_call_indices = HashSet::new();
+    let mut storage_write_indices = HashSet::new();
+    let mut imported_func_count = 0u32;
+    let mut export_names: HashMap<u32, String> = HashMap::new();
+    let mut bodies = Vec::new();
+
+    for payload in Parser::new(0).parse_all(wasm_bytes) {
+        let Ok(payload) = payload else {
+            return Vec::new();
+        };
+
+        match payload {
+            Payload::ImportSection(reader) => {
+                for import in reader.into_iter().flatten() {
+                    if let wasmparser::TypeRef::Func(_) = import.ty {
+                        if is_cross_contract_host_import(import.module, import.name) {
+                            external_call_indices.insert(imported_func_count);
+                        }
+                        if is_storage_write_import(import.module, import.name) {
+                            storage_write_indices.insert(imported_func_count);
+                        }
+                        imported_func_count += 1;
+                    }
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader.into_iter().flatten() {
+                    if matches!(export.kind, wasmparser::ExternalKind::Func) {
+                        export_names.insert(export.index, export.name.to_string());
+                    }
+                }
+            }
+            Payload::CodeSectionEntry(body) => bodies.push(body),
+            _ => {}
+        }
+    }
+
+    if external_call_indices.is_empty() || storage_write_indices.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hazards = Vec::new();
+    for (local_index, body) in bodies.into_iter().enumerate() {
+        let caller_index = imported_func_count + local_index as u32;
+        let Ok(mut operators) = body.get_operators_reader() else {
+            continue;
+        };
+
+        let mut external_call_offset = None;
+        while !operators.eof() {
+            let offset = operators.original_position();
+            let Ok(op) = operators.read() else {
+                break;
+            };
+            let Operator::Call { function_index } = op else {
+                continue;
+            };
+
+            if external_call_indices.contains(&function_index) {
+                external_call_offset.get_or_insert(offset);
+                continue;
+            }
+            if !storage_write_indices.contains(&function_index) {
+                continue;
+            }
+            if let Some(call_offset) = external_call_offset {
+                hazards.push(PostCallWrite {
+                    function: export_names
+                        .get(&caller_index)
+                        .cloned()
+                        .unwrap_or_else(|| format!("func[{}]", caller_index)),
+                    call_offset,
+                    write_offset: offset,
+                });
+                break;
+            }
+        }
+    }
+
+    hazards
+}
+
 fn analyze_storage_write_pressure_static(wasm_bytes: &[u8]) -> StorageWriteStaticSignal {
     let mut storage_import_indices = HashSet::new();
     let mut imported_func_count = 0u32;
@@ -1678,6 +1973,16 @@ mod tests {
         out
     }
 
+    #[test]
+    fn rule_metadata_lists_every_rule_once() {
+        let rules = SecurityAnalyzer::new().rule_metadata();
+        let ids: HashSet<&str> = rules.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids.len(), rules.len());
+        assert!(ids.contains("hardcoded-address"));
+        assert!(ids.contains("checks-effects-interactions"));
+        assert!(rules.iter().all(|r| !r.description.is_empty()));
+    }
+
     // -----------------------------------------------------------------------
     // is_valid_strkey — unit tests
     // -----------------------------------------------------------------------
@@ -2603,4 +2908,95 @@ mod tests {
             .contains("without authorization for a relevant actor"));
         assert!(findings[0].description.contains("G_ALICE_ADDRESS"));
     }
+
+    /// Module importing `env.invoke_contract` (0) and `env.put_contract_data` (1)
+    /// with a single exported `withdraw` function whose body is `body`.
+    fn wasm_with_cei_body(body: &[u8]) -> Vec<u8> {
+        wasm_with_cei_imports(
+            [("env", "invoke_contract"), ("env", "put_contract_data")],
+            body,
+        )
+    }
+
+    fn wasm_with_cei_imports(imported: [(&str, &str); 2], body: &[u8]) -> Vec<u8> {
+        fn section(id: u8, content: Vec<u8>) -> Vec<u8> {
+            let mut out = vec![id, content.len() as u8];
+            out.extend(content);
+            out
+        }
+        fn import(module: &str, name: &str) -> Vec<u8> {
+            let mut out = vec![module.len() as u8];
+            out.extend_from_slice(module.as_bytes());
+            out.push(name.len() as u8);
+            out.extend_from_slice(name.as_bytes());
+            out.extend_from_slice(&[0x00, 0x00]);
+            out
+        }
+
+        let mut imports = vec![2];
+        for (module, name) in imported {
+            imports.extend(import(module, name));
+        }
+
+        let mut export = vec![1, 8];
+        export.extend_from_slice(b"withdraw");
+        export.extend_from_slice(&[0x00, 0x02]);
+
+        let mut code_body = vec![0x00];
+        code_body.extend_from_slice(body);
+        code_body.push(0x0b);
+        let mut code = vec![1, code_body.len() as u8];
+        code.extend(code_body);
+
+        let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        wasm.extend(section(1, vec![1, 0x60, 0, 0]));
+        wasm.extend(section(2, imports));
+        wasm.extend(section(3, vec![1, 0]));
+        wasm.extend(section(7, export));
+        wasm.extend(section(10, code));
+        wasm
+    }
+
+    #[test]
+    fn cei_rule_flags_storage_write_after_external_call() {
+        // call invoke_contract; call put_contract_data
+        let wasm = wasm_with_cei_body(&[0x10, 0x00, 0x10, 0x01]);
+        let findings = ChecksEffectsInteractionsRule.analyze_static(&wasm).unwrap();
+
+        assert_eq!(findings.len(), 1);
+        assert!(matches!(findings[0].severity, Severity::High));
+        let write_offset = wasm.len() - 3;
+        assert_eq!(
+            findings[0].location,
+            format!("withdraw @ 0x{:08x}", write_offset)
+        );
+    }
+
+    #[test]
+    fn cei_rule_recognises_sdk_short_import_names() {
+        // soroban-sdk builds import `call` as `d._` and `put_contract_data` as `l._`.
+        let imports = [("d", "_"), ("l", "_")];
+        let after = wasm_with_cei_imports(imports, &[0x10, 0x00, 0x10, 0x01]);
+        assert_eq!(
+            ChecksEffectsInteractionsRule
+                .analyze_static(&after)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let before = wasm_with_cei_imports(imports, &[0x10, 0x01, 0x10, 0x00]);
+        assert!(ChecksEffectsInteractionsRule
+            .analyze_static(&before)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn cei_rule_allows_effects_before_interactions() {
+        // call put_contract_data; call invoke_contract
+        let wasm = wasm_with_cei_body(&[0x10, 0x01, 0x10, 0x00]);
+        let findings = ChecksEffectsInteractionsRule.analyze_static(&wasm).unwrap();
+        assert!(findings.is_empty());
+    }
 }
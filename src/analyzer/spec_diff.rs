@@ -0,0 +1,410 @@
+//! Interface-level diff of two contracts' `contractspecv0` sections.
+//!
+//! Where `upgrade` answers "can this WASM replace that one?", this module answers
+//! "what changed for callers?": functions, parameter and return types, and the
+//! contract error variants a consumer may match on.
+
+use crate::utils::wasm::{ContractFunctionSignature, CustomError};
+use crate::Result;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A single interface change between two contract specs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type")]
+pub enum SpecChange {
+    FunctionAdded {
+        function: String,
+    },
+    FunctionRemoved {
+        function: String,
+    },
+    ParamsChanged {
+        function: String,
+        old_params: Vec<String>,
+        new_params: Vec<String>,
+    },
+    ParamTypeChanged {
+        function: String,
+        index: usize,
+        param: String,
+        old_type: String,
+        new_type: String,
+    },
+    ParamRenamed {
+        function: String,
+        index: usize,
+        old_name: String,
+        new_name: String,
+    },
+    ReturnTypeChanged {
+        function: String,
+        old_type: Option<String>,
+        new_type: Option<String>,
+    },
+    ErrorAdded {
+        name: String,
+        code: u32,
+    },
+    ErrorRemoved {
+        name: String,
+        code: u32,
+    },
+    ErrorCodeChanged {
+        name: String,
+        old_code: u32,
+        new_code: u32,
+    },
+}
+
+impl SpecChange {
+    /// Whether existing callers can break because of this change.
+    ///
+    /// Additions and parameter renames are safe for positional callers; everything
+    /// else changes what a caller sends or receives.
+    pub fn is_breaking(&self) -> bool {
+        !matches!(
+            self,
+            SpecChange::FunctionAdded { .. }
+                | SpecChange::ParamRenamed { .. }
+                | SpecChange::ErrorAdded { .. }
+        )
+    }
+}
+
+impl fmt::Display for SpecChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = |t: &Option<String>| t.clone().unwrap_or_else(|| "()".to_string());
+        match self {
+            SpecChange::FunctionAdded { function } => write!(f, "[FN_ADDED] {}", function),
+            SpecChange::FunctionRemoved { function } => write!(f, "[FN_REMOVED] {}", function),
+            SpecChange::ParamsChanged {
+                function,
+                old_params,
+                new_params,
+            } => write!(
+                f,
+                "[PARAMS] {}({}) -> {}({})",
+                function,
+                old_params.join(", "),
+                function,
+                new_params.join(", ")
+            ),
+            SpecChange::ParamTypeChanged {
+                function,
+                index,
+                param,
+                old_type,
+                new_type,
+            } => write!(
+                f,
+                "[PARAM_TYPE] {} param[{}] {}: {} -> {}",
+                function, index, param, old_type, new_type
+            ),
+            SpecChange::ParamRenamed {
+                function,
+                index,
+                old_name,
+                new_name,
+            } => write!(
+                f,
+                "[PARAM_RENAMED] {} param[{}]: {} -> {}",
+                function, index, old_name, new_name
+            ),
+            SpecChange::ReturnTypeChanged {
+                function,
+                old_type,
+                new_type,
+            } => write!(
+                f,
+                "[RETURN_TYPE] {}: {} -> {}",
+                function,
+                unit(old_type),
+                unit(new_type)
+            ),
+            SpecChange::ErrorAdded { name, code } => {
+                write!(f, "[ERROR_ADDED] {} = {}", name, code)
+            }
+            SpecChange::ErrorRemoved { name, code } => {
+                write!(f, "[ERROR_REMOVED] {} = {}", name, code)
+            }
+            SpecChange::ErrorCodeChanged {
+                name,
+                old_code,
+                new_code,
+            } => write!(f, "[ERROR_CODE] {}: {} -> {}", name, old_code, new_code),
+        }
+    }
+}
+
+/// Full interface diff between two contracts.
+#[derive(Debug, Clone, Serialize)]
+pub struct SpecDiffReport {
+    pub old_wasm_path: String,
+    pub new_wasm_path: String,
+    pub breaking: bool,
+    pub changes: Vec<SpecChange>,
+}
+
+impl SpecDiffReport {
+    /// Human-readable report, breaking changes first.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str("Contract Interface Diff\n");
+        out.push_str("=======================\n");
+        out.push_str(&format!("Old: {}\n", self.old_wasm_path));
+        out.push_str(&format!("New: {}\n\n", self.new_wasm_path));
+
+        if self.changes.is_empty() {
+            out.push_str("No interface changes.\n");
+            return out;
+        }
+
+        let (breaking, compatible): (Vec<_>, Vec<_>) =
+            self.changes.iter().partition(|c| c.is_breaking());
+        if !breaking.is_empty() {
+            out.push_str(&format!("Breaking changes ({}):\n", breaking.len()));
+            for change in breaking {
+                out.push_str(&format!("  {}\n", change));
+            }
+        }
+        if !compatible.is_empty() {
+            out.push_str(&format!("Compatible changes ({}):\n", compatible.len()));
+            for change in compatible {
+                out.push_str(&format!("  {}\n", change));
+            }
+        }
+        out
+    }
+}
+
+pub struct SpecDiffer;
+
+impl SpecDiffer {
+    /// Parse both contracts' specs and diff their interfaces.
+    pub fn diff_wasm(
+        old_wasm: &[u8],
+        new_wasm: &[u8],
+        old_path: &str,
+        new_path: &str,
+    ) -> Result<SpecDiffReport> {
+        let mut changes = Self::diff_functions(
+            &crate::utils::wasm::parse_function_signatures(old_wasm)?,
+            &crate::utils::wasm::parse_function_signatures(new_wasm)?,
+        );
+        changes.extend(Self::diff_errors(
+            &crate::utils::wasm::parse_custom_errors(old_wasm)?,
+            &crate::utils::wasm::parse_custom_errors(new_wasm)?,
+        ));
+
+        Ok(SpecDiffReport {
+            old_wasm_path: old_path.to_string(),
+            new_wasm_path: new_path.to_string(),
+            breaking: changes.iter().any(SpecChange::is_breaking),
+            changes,
+        })
+    }
+
+    fn diff_functions(
+        old: &[ContractFunctionSignature],
+        new: &[ContractFunctionSignature],
+    ) -> Vec<SpecChange> {
+        let old_by_name: BTreeMap<&str, &ContractFunctionSignature> =
+            old.iter().map(|sig| (sig.name.as_str(), sig)).collect();
+        let new_by_name: BTreeMap<&str, &ContractFunctionSignature> =
+            new.iter().map(|sig| (sig.name.as_str(), sig)).collect();
+
+        let mut changes = Vec::new();
+
+        for name in old_by_name.keys().filter(|n| !new_by_name.contains_key(*n)) {
+            changes.push(SpecChange::FunctionRemoved {
+                function: name.to_string(),
+            });
+        }
+        for name in new_by_name.keys().filter(|n| !old_by_name.contains_key(*n)) {
+            changes.push(SpecChange::FunctionAdded {
+                function: name.to_string(),
+            });
+        }
+
+        for (name, old_sig) in &old_by_name {
+            let Some(new_sig) = new_by_name.get(name) else {
+                continue;
+            };
+
+            if old_sig.params.len() != new_sig.params.len() {
+                let describe = |sig: &ContractFunctionSignature| {
+                    sig.params
+                        .iter()
+                        .map(|p| format!("{}: {}", p.name, p.type_name))
+                        .collect()
+                };
+                changes.push(SpecChange::ParamsChanged {
+                    function: name.to_string(),
+                    old_params: describe(old_sig),
+                    new_params: describe(new_sig),
+                });
+            } else {
+                for (index, (old_param, new_param)) in
+                    old_sig.params.iter().zip(&new_sig.params).enumerate()
+                {
+                    if old_param.type_name != new_param.type_name {
+                        changes.push(SpecChange::ParamTypeChanged {
+                            function: name.to_string(),
+                            index,
+                            param: new_param.name.clone(),
+                            old_type: old_param.type_name.clone(),
+                            new_type: new_param.type_name.clone(),
+                        });
+                    }
+                    if old_param.name != new_param.name {
+                        changes.push(SpecChange::ParamRenamed {
+                            function: name.to_string(),
+                            index,
+                            old_name: old_param.name.clone(),
+                            new_name: new_param.name.clone(),
+                        });
+                    }
+                }
+            }
+
+            if old_sig.return_type != new_sig.return_type {
+                changes.push(SpecChange::ReturnTypeChanged {
+                    function: name.to_string(),
+                    old_type: old_sig.return_type.clone(),
+                    new_type: new_sig.return_type.clone(),
+                });
+            }
+        }
+
+        changes
+    }
+
+    fn diff_errors(old: &[CustomError], new: &[CustomError]) -> Vec<SpecChange> {
+        let old_by_name: BTreeMap<&str, u32> =
+            old.iter().map(|e| (e.name.as_str(), e.code)).collect();
+        let new_by_name: BTreeMap<&str, u32> =
+            new.iter().map(|e| (e.name.as_str(), e.code)).collect();
+
+        let mut changes = Vec::new();
+        for (name, old_code) in &old_by_name {
+            match new_by_name.get(name) {
+                None => changes.push(SpecChange::ErrorRemoved {
+                    name: name.to_string(),
+                    code: *old_code,
+                }),
+                Some(new_code) if new_code != old_code => {
+                    changes.push(SpecChange::ErrorCodeChanged {
+                        name: name.to_string(),
+                        old_code: *old_code,
+                        new_code: *new_code,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        for (name, code) in &new_by_name {
+            if !old_by_name.contains_key(name) {
+                changes.push(SpecChange::ErrorAdded {
+                    name: name.to_string(),
+                    code: *code,
+                });
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::wasm::FunctionParam;
+
+    fn sig(name: &str, params: &[(&str, &str)], ret: Option<&str>) -> ContractFunctionSignature {
+        ContractFunctionSignature {
+            name: name.to_string(),
+            params: params
+                .iter()
+                .map(|(n, t)| FunctionParam {
+                    name: n.to_string(),
+                    type_name: t.to_string(),
+                })
+                .collect(),
+            return_type: ret.map(|r| r.to_string()),
+        }
+    }
+
+    fn error(name: &str, code: u32) -> CustomError {
+        CustomError {
+            code,
+            name: name.to_string(),
+            doc: String::new(),
+        }
+    }
+
+    #[test]
+    fn diff_functions_reports_interface_changes() {
+        let old = vec![
+            sig("transfer", &[("to", "Address"), ("amount", "i128")], None),
+            sig("balance", &[("id", "Address")], Some("i128")),
+            sig("legacy", &[], None),
+        ];
+        let new = vec![
+            sig("transfer", &[("to", "Address"), ("value", "u64")], None),
+            sig("balance", &[("id", "Address")], Some("u64")),
+            sig("burn", &[("amount", "i128")], None),
+        ];
+
+        let changes = SpecDiffer::diff_functions(&old, &new);
+        assert!(changes.contains(&SpecChange::FunctionRemoved {
+            function: "legacy".to_string()
+        }));
+        assert!(changes.contains(&SpecChange::FunctionAdded {
+            function: "burn".to_string()
+        }));
+        assert!(changes.contains(&SpecChange::ParamTypeChanged {
+            function: "transfer".to_string(),
+            index: 1,
+            param: "value".to_string(),
+            old_type: "i128".to_string(),
+            new_type: "u64".to_string(),
+        }));
+        assert!(changes.iter().any(|c| matches!(
+            c,
+            SpecChange::ParamRenamed { function, .. } if function == "transfer"
+        )));
+        assert!(changes.contains(&SpecChange::ReturnTypeChanged {
+            function: "balance".to_string(),
+            old_type: Some("i128".to_string()),
+            new_type: Some("u64".to_string()),
+        }));
+    }
+
+    #[test]
+    fn diff_errors_tracks_added_removed_and_renumbered_variants() {
+        let old = vec![error("NotFound", 1), error("Expired", 2)];
+        let new = vec![error("NotFound", 3), error("Paused", 4)];
+
+        let changes = SpecDiffer::diff_errors(&old, &new);
+        assert_eq!(
+            changes,
+            vec![
+                SpecChange::ErrorRemoved {
+                    name: "Expired".to_string(),
+                    code: 2
+                },
+                SpecChange::ErrorCodeChanged {
+                    name: "NotFound".to_string(),
+                    old_code: 1,
+                    new_code: 3
+                },
+                SpecChange::ErrorAdded {
+                    name: "Paused".to_string(),
+                    code: 4
+                },
+            ]
+        );
+        assert!(!changes[2].is_breaking());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::analyzer::arithmetic::{ArithmeticFaultKind, ArithmeticModel};
 use crate::output::*;
 use crate::runtime::executor::ContractExecutor;
 use crate::utils::wasm::{parse_function_signatures, ContractFunctionSignature};
@@ -23,9 +24,24 @@ pub struct SymbolicReport {
     pub paths_explored: usize,
     pub panics_found: usize,
     pub paths: Vec<PathResult>,
+    pub arithmetic_findings: Vec<ArithmeticFinding>,
     pub metadata: SymbolicReportMetadata,
 }
 
+/// A path whose inputs overflow an integer operation or divide by zero.
+#[derive(Debug, Clone, Serialize)]
+pub struct ArithmeticFinding {
+    pub kind: ArithmeticFaultKind,
+    pub inputs: String,
+    /// Module offset of the faulting instruction, when the arithmetic model
+    /// located it rather than the contract's own panic message.
+    pub offset: Option<usize>,
+    pub detail: String,
+    /// The contract's own failure on these inputs; `None` when the fault
+    /// wrapped silently and the call succeeded.
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct SymbolicConfig {
     pub max_paths: usize,
@@ -224,6 +240,33 @@ impl SymbolicAnalyzer {
         }
     }
 
+    /// Classify a path as an arithmetic fault by re-evaluating it on the
+    /// integer model, falling back to the error message of a failed run when
+    /// the model cannot follow the path.
+    fn arithmetic_finding(
+        model: Option<&ArithmeticModel>,
+        inputs: &str,
+        error: Option<String>,
+    ) -> Option<ArithmeticFinding> {
+        if let Some(fault) = model.and_then(|model| model.evaluate(inputs)) {
+            return Some(ArithmeticFinding {
+                kind: fault.kind,
+                inputs: inputs.to_string(),
+                offset: Some(fault.offset),
+                detail: fault.detail,
+                error,
+            });
+        }
+        let message = error?;
+        ArithmeticFaultKind::from_error_message(&message).map(|kind| ArithmeticFinding {
+            kind,
+            inputs: inputs.to_string(),
+            offset: None,
+            detail: message.clone(),
+            error: Some(message),
+        })
+    }
+
     pub fn analyze(&self, wasm: &[u8], function: &str) -> Result<SymbolicReport> {
         self.analyze_with_config(wasm, function, &SymbolicConfig::default())
     }
@@ -250,12 +293,14 @@ impl SymbolicAnalyzer {
             seeded_shuffle(&mut generated_inputs.combinations, seed);
         }
         let deadline = Instant::now();
+        let arithmetic = ArithmeticModel::for_function(wasm, function).ok().flatten();
 
         let mut report = SymbolicReport {
             function: function.to_string(),
             paths_explored: 0,
             panics_found: 0,
             paths: Vec::new(),
+            arithmetic_findings: Vec::new(),
             metadata: SymbolicReportMetadata {
                 config: config.clone(),
                 generated_input_combinations: generated_inputs.combinations.len(),
@@ -316,6 +361,12 @@ impl SymbolicAnalyzer {
                 .into()),
             };
 
+            if !seen_inputs.contains(args_json) {
+                let error = executor_res.as_ref().err().map(|e| e.to_string());
+                let finding = Self::arithmetic_finding(arithmetic.as_ref(), args_json, error);
+                report.arithmetic_findings.extend(finding);
+            }
+
             match executor_res {
                 Ok(val) => {
                     Self::record_outcome(&mut report, &mut seen_inputs, args_json, Ok(val), trace);
@@ -528,7 +579,24 @@ impl SymbolicAnalyzer {
         let limit = config.max_breadth;
 
         match type_name {
-            "U32" | "I32" | "U64" | "I64" | "U128" | "I128" | "Val" => {
+            "U64" | "I64" | "U128" | "I128" => {
+                let base = [
+                    "0",
+                    "1",
+                    "-1",
+                    // Largest small-value integer; squaring it overflows 64 bits.
+                    "36028797018963967",
+                    "42",
+                    "9223372036854775807",
+                    "-9223372036854775808",
+                    "18446744073709551615",
+                ];
+                base.into_iter()
+                    .take(limit)
+                    .map(|s| s.to_string())
+                    .collect()
+            }
+            "U32" | "I32" | "Val" => {
                 let base = [
                     "0",
                     "1",
@@ -657,7 +725,16 @@ impl SymbolicAnalyzer {
     }
 
     fn generate_input_combinations(&self, arg_count: usize, max_cases: usize) -> GeneratedInputs {
-        let numeric_seeds = ["0", "1", "-1", "42", "2147483647", "-2147483648"];
+        let numeric_seeds = [
+            "0",
+            "1",
+            "-1",
+            "42",
+            "2147483647",
+            "-2147483648",
+            // Largest small-value integer; squaring it overflows 64 bits.
+            "36028797018963967",
+        ];
 
         if max_cases == 0 {
             return GeneratedInputs {
@@ -718,7 +795,13 @@ impl SymbolicAnalyzer {
         writeln!(toml, "# Generated Symbolic Execution Scenarios").unwrap();
         writeln!(toml, "function = {}", toml_basic_string(&report.function)).unwrap();
         writeln!(toml, "paths_explored = {}", report.paths_explored).unwrap();
-        writeln!(toml, "panics_found = {}\n", report.panics_found).unwrap();
+        writeln!(toml, "panics_found = {}", report.panics_found).unwrap();
+        writeln!(
+            toml,
+            "arithmetic_findings = {}\n",
+            report.arithmetic_findings.len()
+        )
+        .unwrap();
         writeln!(toml, "[metadata]").unwrap();
         writeln!(toml, "max_paths = {}", report.metadata.config.max_paths).unwrap();
         writeln!(
@@ -800,10 +883,73 @@ impl SymbolicAnalyzer {
             writeln!(toml).unwrap();
         }
 
+        // Every panic path and every arithmetic finding also becomes a
+        // scenario step, so `soroban-debug scenario` replays the concrete
+        // inputs that reach it.
+        let mut step = 0;
+        for path in &report.paths {
+            let Some(ref panic) = path.panic else {
+                continue;
+            };
+            let label = report
+                .arithmetic_findings
+                .iter()
+                .find(|f| f.error.is_some() && f.inputs == path.inputs)
+                .map_or("panic", |f| f.kind.label());
+            step += 1;
+            write_scenario_step(&mut toml, &format!("{label} #{step}"), report, &path.inputs);
+            writeln!(
+                toml,
+                "expected_panic = {}",
+                toml_basic_string(panic_signature(panic))
+            )
+            .unwrap();
+            writeln!(toml, "notes = {}\n", toml_basic_string(panic)).unwrap();
+        }
+        // Findings on inputs not recorded as a panic path: silent wraps,
+        // where the call itself succeeds, and panics on a path already seen.
+        for finding in &report.arithmetic_findings {
+            let recorded = report
+                .paths
+                .iter()
+                .any(|p| p.panic.is_some() && p.inputs == finding.inputs);
+            if finding.error.is_some() && recorded {
+                continue;
+            }
+            step += 1;
+            let name = format!("{} #{}", finding.kind.label(), step);
+            write_scenario_step(&mut toml, &name, report, &finding.inputs);
+            if let Some(ref error) = finding.error {
+                writeln!(
+                    toml,
+                    "expected_panic = {}",
+                    toml_basic_string(panic_signature(error))
+                )
+                .unwrap();
+            }
+            writeln!(toml, "notes = {}\n", toml_basic_string(&finding.detail)).unwrap();
+        }
+
         toml
     }
 }
 
+fn write_scenario_step(toml: &mut String, name: &str, report: &SymbolicReport, inputs: &str) {
+    writeln!(toml, "[[steps]]").unwrap();
+    writeln!(toml, "name = {}", toml_basic_string(name)).unwrap();
+    writeln!(toml, "function = {}", toml_basic_string(&report.function)).unwrap();
+    writeln!(toml, "args = {}", toml_basic_string(inputs)).unwrap();
+}
+
+/// The stable tail of a panic message, used as the step's `expected_panic`
+/// substring so wrapper prefixes added by other commands still match.
+fn panic_signature(message: &str) -> &str {
+    match message.rsplit_once(": ") {
+        Some((_, tail)) if !tail.trim().is_empty() => tail.trim(),
+        _ => message.trim(),
+    }
+}
+
 fn toml_basic_string(value: &str) -> String {
     let escaped = value
         .replace('\\', "\\\\")
@@ -903,6 +1049,7 @@ mod tests {
             paths_explored: 0,
             panics_found: 0,
             paths: Vec::new(),
+            arithmetic_findings: Vec::new(),
             metadata: SymbolicReportMetadata {
                 config: SymbolicConfig::default(),
                 generated_input_combinations: 0,
@@ -947,6 +1094,7 @@ mod tests {
             paths_explored: 0,
             panics_found: 0,
             paths: Vec::new(),
+            arithmetic_findings: Vec::new(),
             metadata: SymbolicReportMetadata {
                 config: SymbolicConfig::default(),
                 generated_input_combinations: 0,
@@ -1125,6 +1273,7 @@ mod tests {
                 panic: None,
                 path_decisions: Vec::new(),
             }],
+            arithmetic_findings: Vec::new(),
             metadata: SymbolicReportMetadata {
                 config: SymbolicConfig::fast(),
                 generated_input_combinations: 10,
@@ -1148,6 +1297,156 @@ mod tests {
         assert!(toml.contains("truncated_by_input_cap = true"));
     }
 
+    /// `mul(a, b)` decoding both small-integer arguments and multiplying
+    /// them with an unchecked `i64.mul`.
+    fn unchecked_multiply_wasm() -> Vec<u8> {
+        let mut module = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        append_section(&mut module, 1, &[0x01, 0x60, 0x02, 0x7e, 0x7e, 0x01, 0x7e]);
+        append_section(&mut module, 3, &[0x01, 0x00]);
+        let mut exports = Vec::new();
+        push_u32_leb(1, &mut exports);
+        push_name("mul", &mut exports);
+        exports.extend_from_slice(&[0x00, 0x00]);
+        append_section(&mut module, 7, &exports);
+        let body = [
+            0x00, 0x20, 0x00, 0x42, 0x08, 0x87, 0x20, 0x01, 0x42, 0x08, 0x87, 0x7e, 0x42, 0x08,
+            0x86, 0x42, 0x0b, 0x84, 0x0b,
+        ];
+        let mut code = Vec::new();
+        push_u32_leb(1, &mut code);
+        push_u32_leb(body.len() as u32, &mut code);
+        code.extend_from_slice(&body);
+        append_section(&mut module, 10, &code);
+        module
+    }
+
+    #[test]
+    fn arithmetic_findings_come_from_model_and_panic_messages() {
+        let wasm = unchecked_multiply_wasm();
+        let model = ArithmeticModel::for_function(&wasm, "mul").unwrap();
+
+        let overflow = SymbolicAnalyzer::arithmetic_finding(
+            model.as_ref(),
+            "[36028797018963967, 36028797018963967]",
+            None,
+        )
+        .expect("unchecked multiply overflows");
+        assert_eq!(overflow.kind, ArithmeticFaultKind::MulOverflow);
+        assert!(overflow.offset.is_some());
+        assert_eq!(overflow.error, None);
+
+        assert!(SymbolicAnalyzer::arithmetic_finding(model.as_ref(), "[6, 7]", None).is_none());
+
+        let panicked = SymbolicAnalyzer::arithmetic_finding(
+            None,
+            "[1, 0]",
+            Some("panicked at 'attempt to divide by zero'".to_string()),
+        )
+        .expect("panic message is classified");
+        assert_eq!(panicked.kind, ArithmeticFaultKind::DivisionByZero);
+        assert_eq!(panicked.offset, None);
+        assert!(panicked.error.is_some());
+    }
+
+    #[test]
+    fn generate_scenario_toml_emits_replayable_arithmetic_steps() {
+        let analyzer = SymbolicAnalyzer::new();
+        let report = SymbolicReport {
+            function: "mul".to_string(),
+            paths_explored: 1,
+            panics_found: 0,
+            paths: Vec::new(),
+            arithmetic_findings: vec![ArithmeticFinding {
+                kind: ArithmeticFaultKind::MulOverflow,
+                inputs: "[36028797018963967, 36028797018963967]".to_string(),
+                offset: Some(42),
+                detail: "i64.mul overflows".to_string(),
+                error: None,
+            }],
+            metadata: SymbolicReportMetadata {
+                config: SymbolicConfig::fast(),
+                generated_input_combinations: 1,
+                attempted_input_combinations: 1,
+                distinct_paths_recorded: 1,
+                truncated_by_input_cap: false,
+                truncated_by_path_cap: false,
+                truncated_by_timeout: false,
+                truncation_reasons: Vec::new(),
+                seed: None,
+                coverage_fraction: 1.0,
+                uncovered_regions: Vec::new(),
+            },
+        };
+
+        let toml = analyzer.generate_scenario_toml(&report);
+        assert!(toml.contains("arithmetic_findings = 1"));
+
+        let scenario: crate::scenario::Scenario = toml::from_str(&toml).unwrap();
+        assert_eq!(scenario.steps.len(), 1);
+        assert_eq!(scenario.steps[0].function, "mul");
+        assert_eq!(
+            scenario.steps[0].name.as_deref(),
+            Some("multiply overflow #1")
+        );
+        assert_eq!(
+            scenario.steps[0].args.as_deref(),
+            Some("[36028797018963967, 36028797018963967]")
+        );
+    }
+
+    #[test]
+    fn generate_scenario_toml_emits_a_step_per_panic_path() {
+        let analyzer = SymbolicAnalyzer::new();
+        let mut report = SymbolicReport {
+            function: "withdraw".to_string(),
+            paths_explored: 2,
+            panics_found: 1,
+            paths: Vec::new(),
+            arithmetic_findings: Vec::new(),
+            metadata: SymbolicReportMetadata {
+                config: SymbolicConfig::fast(),
+                generated_input_combinations: 2,
+                attempted_input_combinations: 2,
+                distinct_paths_recorded: 2,
+                truncated_by_input_cap: false,
+                truncated_by_path_cap: false,
+                truncated_by_timeout: false,
+                truncation_reasons: Vec::new(),
+                seed: None,
+                coverage_fraction: 1.0,
+                uncovered_regions: Vec::new(),
+            },
+        };
+        let mut seen_inputs = HashSet::new();
+        SymbolicAnalyzer::record_outcome(
+            &mut report,
+            &mut seen_inputs,
+            "[1]",
+            Ok("Void".into()),
+            Vec::new(),
+        );
+        SymbolicAnalyzer::record_outcome(
+            &mut report,
+            &mut seen_inputs,
+            "[-1]",
+            Err("Execution error: Contract execution was aborted.".into()),
+            Vec::new(),
+        );
+
+        let toml = analyzer.generate_scenario_toml(&report);
+        let scenario: crate::scenario::Scenario = toml::from_str(&toml).unwrap();
+
+        assert_eq!(scenario.steps.len(), 1);
+        let step = &scenario.steps[0];
+        assert_eq!(step.name.as_deref(), Some("panic #1"));
+        assert_eq!(step.function, "withdraw");
+        assert_eq!(step.args.as_deref(), Some("[-1]"));
+        assert_eq!(
+            step.expected_panic.as_deref(),
+            Some("Contract execution was aborted.")
+        );
+    }
+
     #[test]
     fn test_generate_seeds_for_primitive_types() {
         let analyzer = SymbolicAnalyzer::new();
@@ -1156,6 +1455,9 @@ mod tests {
         let u32_seeds = analyzer.generate_seeds_for_type("U32", &config, 0);
         assert_eq!(u32_seeds, vec!["0", "1", "-1"]); // max_breadth = 3 in fast config
 
+        let i64_seeds = analyzer.generate_seeds_for_type("I64", &SymbolicConfig::default(), 0);
+        assert!(i64_seeds.contains(&"36028797018963967".to_string()));
+
         let bool_seeds = analyzer.generate_seeds_for_type("Bool", &config, 0);
         assert_eq!(bool_seeds, vec!["true", "false"]);
     }
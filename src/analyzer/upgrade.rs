@@ -75,6 +75,22 @@ pub enum BreakingChange {
         old_types: Vec<WasmType>,
         new_types: Vec<WasmType>,
     },
+    /// A storage key variant was removed; entries written under it become unreachable
+    StorageKeyRemoved {
+        key: String,
+    },
+    /// A storage key variant's payload changed, so existing keys no longer match
+    StorageKeyTypeChanged {
+        key: String,
+        old_types: Vec<String>,
+        new_types: Vec<String>,
+    },
+    /// The value stored under a key changed type, per the key's getter function
+    StorageValueTypeChanged {
+        key: String,
+        old_type: String,
+        new_type: String,
+    },
 }
 
 impl fmt::Display for BreakingChange {
@@ -121,6 +137,38 @@ impl fmt::Display for BreakingChange {
                     new.join(", ")
                 )
             }
+            BreakingChange::StorageKeyRemoved { key } => {
+                write!(
+                    f,
+                    "[STORAGE_KEY_REMOVED] {}: existing entries become unreachable",
+                    key
+                )
+            }
+            BreakingChange::StorageKeyTypeChanged {
+                key,
+                old_types,
+                new_types,
+            } => {
+                write!(
+                    f,
+                    "[STORAGE_KEY_TYPE] {}({}) -> {}({})",
+                    key,
+                    old_types.join(", "),
+                    key,
+                    new_types.join(", ")
+                )
+            }
+            BreakingChange::StorageValueTypeChanged {
+                key,
+                old_type,
+                new_type,
+            } => {
+                write!(
+                    f,
+                    "[STORAGE_VALUE_TYPE] {}: {} -> {}",
+                    key, old_type, new_type
+                )
+            }
         }
     }
 }
@@ -168,11 +216,113 @@ pub struct ExecutionDiff {
     pub outputs_match: bool,
 }
 
+/// Finding counts, so CI can gate on `summary.breaking_changes` without walking the lists
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeSummary {
+    pub breaking_changes: usize,
+    pub non_breaking_changes: usize,
+    pub execution_mismatches: usize,
+}
+
+impl UpgradeSummary {
+    pub fn from_findings(
+        breaking: &[BreakingChange],
+        non_breaking: &[NonBreakingChange],
+        execution_diffs: &[ExecutionDiff],
+    ) -> Self {
+        Self {
+            breaking_changes: breaking.len(),
+            non_breaking_changes: non_breaking.len(),
+            execution_mismatches: execution_diffs.iter().filter(|d| !d.outputs_match).count(),
+        }
+    }
+}
+
+/// Growth of the new build beyond this percentage of the old size is noted in the report
+pub const SIZE_GROWTH_THRESHOLD_PCT: f64 = 10.0;
+
+/// Size of one WASM section in both builds; 0 when the section is absent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionSize {
+    pub name: String,
+    pub old_size: usize,
+    pub new_size: usize,
+    pub delta: i64,
+}
+
+/// Old and new module sizes, since code size drives deployment cost and rent
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeComparison {
+    pub old_size: usize,
+    pub new_size: usize,
+    pub delta: i64,
+    pub sections: Vec<SectionSize>,
+}
+
+impl SizeComparison {
+    /// Compare module and per-section sizes. Individual function bodies are
+    /// counted once, as part of the code section.
+    pub fn compute(old_wasm: &[u8], new_wasm: &[u8]) -> Result<Self> {
+        let old_info = crate::utils::wasm::get_module_info(old_wasm)?;
+        let new_info = crate::utils::wasm::get_module_info(new_wasm)?;
+
+        let mut sections: Vec<SectionSize> = Vec::new();
+        let old_sections = old_info.sections.iter().map(|s| (s, true));
+        let new_sections = new_info.sections.iter().map(|s| (s, false));
+        for (section, is_old) in old_sections.chain(new_sections) {
+            if section.name == "Code (Entry)" {
+                continue;
+            }
+            let index = match sections.iter().position(|s| s.name == section.name) {
+                Some(index) => index,
+                None => {
+                    sections.push(SectionSize {
+                        name: section.name.clone(),
+                        old_size: 0,
+                        new_size: 0,
+                        delta: 0,
+                    });
+                    sections.len() - 1
+                }
+            };
+            if is_old {
+                sections[index].old_size += section.size;
+            } else {
+                sections[index].new_size += section.size;
+            }
+        }
+        for section in &mut sections {
+            section.delta = size_delta(section.old_size, section.new_size);
+        }
+
+        Ok(Self {
+            old_size: old_info.total_size,
+            new_size: new_info.total_size,
+            delta: size_delta(old_info.total_size, new_info.total_size),
+            sections,
+        })
+    }
+
+    /// Growth relative to the old size, in percent
+    pub fn growth_pct(&self) -> f64 {
+        if self.old_size == 0 {
+            return 0.0;
+        }
+        self.delta as f64 / self.old_size as f64 * 100.0
+    }
+}
+
+fn size_delta(old_size: usize, new_size: usize) -> i64 {
+    new_size as i64 - old_size as i64
+}
+
 /// The full compatibility report
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CompatibilityReport {
     pub is_compatible: bool,
     pub classification: UpgradeClass,
+    #[serde(default)]
+    pub summary: UpgradeSummary,
     pub old_wasm_path: String,
     pub new_wasm_path: String,
     pub breaking_changes: Vec<BreakingChange>,
@@ -180,6 +330,22 @@ pub struct CompatibilityReport {
     pub old_functions: Vec<crate::utils::wasm::ContractFunctionSignature>,
     pub new_functions: Vec<crate::utils::wasm::ContractFunctionSignature>,
     pub execution_diffs: Vec<ExecutionDiff>,
+    /// Informational notes, such as analysis passes that could not run
+    #[serde(default)]
+    pub notes: Vec<String>,
+    #[serde(default)]
+    pub size: SizeComparison,
+}
+
+/// Outcome of running a migration function on storage exported from the old contract
+#[derive(Debug, Serialize)]
+pub struct MigrationReport {
+    pub function: String,
+    pub seeded_entries: usize,
+    pub success: bool,
+    pub result: Option<String>,
+    pub error: Option<String>,
+    pub storage_diff: crate::inspector::storage::StorageDiff,
 }
 
 pub struct UpgradeAnalyzer;
@@ -196,15 +362,60 @@ impl UpgradeAnalyzer {
         let old_functions = crate::utils::wasm::parse_function_signatures(old_wasm)?;
         let new_functions = crate::utils::wasm::parse_function_signatures(new_wasm)?;
 
-        let (breaking_changes, non_breaking_changes) =
+        let (mut breaking_changes, non_breaking_changes) =
             Self::diff_signatures(&old_functions, &new_functions);
 
-        let has_execution_mismatches = execution_diffs.iter().any(|d| !d.outputs_match);
+        let mut notes = Vec::new();
+        if old_functions.is_empty() || new_functions.is_empty() {
+            let which = if old_functions.is_empty() {
+                "old"
+            } else {
+                "new"
+            };
+            notes.push(format!(
+                "Storage layout analysis skipped: the {} contract has no contract spec",
+                which
+            ));
+        } else {
+            let old_unions = crate::utils::wasm::parse_contract_unions(old_wasm)?;
+            let new_unions = crate::utils::wasm::parse_contract_unions(new_wasm)?;
+            if !old_unions.iter().any(is_storage_key_union) {
+                notes.push(
+                    "Storage layout analysis skipped: the old contract spec has no storage key enum"
+                        .to_string(),
+                );
+            }
+            breaking_changes.extend(Self::diff_storage_layout(
+                &old_unions,
+                &new_unions,
+                &old_functions,
+                &new_functions,
+            ));
+        }
+
+        let size = SizeComparison::compute(old_wasm, new_wasm)?;
+        if size.growth_pct() > SIZE_GROWTH_THRESHOLD_PCT {
+            notes.push(format!(
+                "Contract size grew by {} bytes ({:+.1}%, threshold {}%): {} -> {} bytes",
+                size.delta,
+                size.growth_pct(),
+                SIZE_GROWTH_THRESHOLD_PCT,
+                size.old_size,
+                size.new_size
+            ));
+        }
+
+        let summary = UpgradeSummary::from_findings(
+            &breaking_changes,
+            &non_breaking_changes,
+            &execution_diffs,
+        );
+        let has_execution_mismatches = summary.execution_mismatches > 0;
         let is_compatible = breaking_changes.is_empty() && !has_execution_mismatches;
 
         let classification = if !breaking_changes.is_empty() || has_execution_mismatches {
             UpgradeClass::Breaking
-        } else if !non_breaking_changes.is_empty() {
+        } else if !non_breaking_changes.is_empty() || !notes.is_empty() {
             UpgradeClass::Caution
         } else {
             UpgradeClass::Safe
@@ -213,6 +424,7 @@ impl UpgradeAnalyzer {
         Ok(CompatibilityReport {
             is_compatible,
             classification,
+            summary,
             old_wasm_path: old_path.to_string(),
             new_wasm_path: new_path.to_string(),
             breaking_changes,
@@ -220,9 +432,63 @@ impl UpgradeAnalyzer {
             old_functions,
             new_functions,
             execution_diffs,
+            notes,
+            size,
         })
     }
 
+    /// Compare the variants of storage key enums between two versions.
+    ///
+    /// Removed variants and variants whose payload types changed are breaking,
+    /// since existing entries can no longer be addressed. The value type of a
+    /// key is not part of the spec, so it is taken from the return type of a
+    /// getter named after the variant (`Balance` -> `balance` or `get_balance`);
+    /// keys without such a getter only get the key-level checks.
+    fn diff_storage_layout(
+        old_unions: &[crate::utils::wasm::ContractUnion],
+        new_unions: &[crate::utils::wasm::ContractUnion],
+        old_functions: &[crate::utils::wasm::ContractFunctionSignature],
+        new_functions: &[crate::utils::wasm::ContractFunctionSignature],
+    ) -> Vec<BreakingChange> {
+        let mut breaking = Vec::new();
+
+        for old_union in old_unions.iter().filter(|u| is_storage_key_union(u)) {
+            let new_union = new_unions.iter().find(|u| u.name == old_union.name);
+            for old_case in &old_union.cases {
+                let key = format!("{}::{}", old_union.name, old_case.name);
+                let Some(new_case) =
+                    new_union.and_then(|u| u.cases.iter().find(|c| c.name == old_case.name))
+                else {
+                    breaking.push(BreakingChange::StorageKeyRemoved { key });
+                    continue;
+                };
+
+                if old_case.types != new_case.types {
+                    breaking.push(BreakingChange::StorageKeyTypeChanged {
+                        key,
+                        old_types: old_case.types.clone(),
+                        new_types: new_case.types.clone(),
+                    });
+                    continue;
+                }
+
+                let old_value = getter_return_type(old_functions, &old_case.name);
+                let new_value = getter_return_type(new_functions, &old_case.name);
+                if let (Some(old_type), Some(new_type)) = (old_value, new_value) {
+                    if old_type != new_type {
+                        breaking.push(BreakingChange::StorageValueTypeChanged {
+                            key,
+                            old_type: old_type.to_string(),
+                            new_type: new_type.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        breaking
+    }
+
     /// Compute breaking and non-breaking changes between two sets of function signatures
     fn diff_signatures(
         old: &[crate::utils::wasm::ContractFunctionSignature],
@@ -302,6 +568,30 @@ impl UpgradeAnalyzer {
     }
 }
 
+/// Storage key enums are recognised by name: `DataKey`, `StorageKey` and the like.
+fn is_storage_key_union(union: &crate::utils::wasm::ContractUnion) -> bool {
+    union.name.ends_with("Key")
+}
+
+/// Return type of the getter for a storage key variant, if the contract has one.
+fn getter_return_type<'a>(
+    functions: &'a [crate::utils::wasm::ContractFunctionSignature],
+    variant: &str,
+) -> Option<&'a str> {
+    let mut snake = String::new();
+    for (i, c) in variant.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    let getter = format!("get_{}", snake);
+    functions
+        .iter()
+        .find(|f| f.name == snake || f.name == getter)
+        .and_then(|f| f.return_type.as_deref())
+}
+
 fn parse_contract_type_to_wasm_type(type_name: &str) -> WasmType {
     match type_name.trim().to_ascii_lowercase().as_str() {
         "i32" => WasmType::I32,
@@ -327,6 +617,98 @@ mod tests {
         }
     }
 
+    fn data_key(cases: &[(&str, &[&str])]) -> crate::utils::wasm::ContractUnion {
+        crate::utils::wasm::ContractUnion {
+            name: "DataKey".to_string(),
+            cases: cases
+                .iter()
+                .map(|(name, types)| crate::utils::wasm::ContractUnionCase {
+                    name: name.to_string(),
+                    types: types.iter().map(|t| t.to_string()).collect(),
+                })
+                .collect(),
+        }
+    }
+
+    fn getter(name: &str, return_type: &str) -> crate::utils::wasm::ContractFunctionSignature {
+        crate::utils::wasm::ContractFunctionSignature {
+            return_type: Some(return_type.to_string()),
+            ..sig(name)
+        }
+    }
+
+    #[test]
+    fn test_storage_layout_flags_value_type_change() {
+        let keys = data_key(&[("Admin", &[]), ("Balance", &["Address"])]);
+        let breaking = UpgradeAnalyzer::diff_storage_layout(
+            std::slice::from_ref(&keys),
+            std::slice::from_ref(&keys),
+            &[getter("balance", "I128")],
+            &[getter("balance", "U64")],
+        );
+
+        assert_eq!(breaking.len(), 1);
+        assert_eq!(
+            breaking[0].to_string(),
+            "[STORAGE_VALUE_TYPE] DataKey::Balance: I128 -> U64"
+        );
+    }
+
+    #[test]
+    fn test_storage_layout_flags_removed_and_retyped_keys() {
+        let old = data_key(&[("Admin", &[]), ("Balance", &["Address"])]);
+        let new = data_key(&[("Balance", &["U64"])]);
+        let breaking = UpgradeAnalyzer::diff_storage_layout(&[old], &[new], &[], &[]);
+
+        assert!(breaking.iter().any(|change| matches!(
+            change,
+            BreakingChange::StorageKeyRemoved { key } if key == "DataKey::Admin"
+        )));
+        assert!(breaking.iter().any(|change| matches!(
+            change,
+            BreakingChange::StorageKeyTypeChanged { key, .. } if key == "DataKey::Balance"
+        )));
+    }
+
+    #[test]
+    fn test_missing_spec_skips_layout_analysis_with_note() {
+        let wasm = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        let report =
+            UpgradeAnalyzer::analyze(&wasm, &wasm, "old.wasm", "new.wasm", Vec::new()).unwrap();
+
+        assert!(report.breaking_changes.is_empty());
+        assert_eq!(report.classification, UpgradeClass::Caution);
+        assert!(report.notes[0].starts_with("Storage layout analysis skipped"));
+    }
+
+    #[test]
+    fn test_size_comparison_notes_growth_beyond_threshold() {
+        let header = [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        // Custom section "pad" with 100 payload bytes.
+        let mut grown = header.to_vec();
+        grown.extend_from_slice(&[0x00, 104, 3, b'p', b'a', b'd']);
+        grown.extend_from_slice(&[0u8; 100]);
+
+        let report =
+            UpgradeAnalyzer::analyze(&header, &grown, "old.wasm", "new.wasm", Vec::new()).unwrap();
+
+        assert_eq!(report.size.old_size, 8);
+        assert_eq!(report.size.new_size, 114);
+        assert_eq!(report.size.delta, 106);
+        let pad = &report.size.sections[0];
+        assert_eq!(pad.name, "Custom (pad)");
+        assert_eq!((pad.old_size, pad.new_size), (0, 104));
+        assert!(report
+            .notes
+            .iter()
+            .any(|n| n.starts_with("Contract size grew by 106 bytes")));
+
+        let same =
+            UpgradeAnalyzer::analyze(&grown, &grown, "old.wasm", "new.wasm", Vec::new()).unwrap();
+        assert_eq!(same.size.delta, 0);
+        assert!(!same.notes.iter().any(|n| n.starts_with("Contract size")));
+    }
+
     #[test]
     fn test_diff_signatures_no_changes() {
         let sig = sig("test");
@@ -338,6 +720,31 @@ mod tests {
         assert!(non_breaking.is_empty());
     }
 
+    #[test]
+    fn test_summary_counts_findings() {
+        let (breaking, non_breaking) =
+            UpgradeAnalyzer::diff_signatures(&[sig("foo"), sig("baz")], &[sig("bar")]);
+        let diffs = vec![ExecutionDiff {
+            function: "bar".to_string(),
+            args: "[]".to_string(),
+            old_result: "Ok(1)".to_string(),
+            new_result: "Ok(2)".to_string(),
+            outputs_match: false,
+        }];
+
+        let summary = UpgradeSummary::from_findings(&breaking, &non_breaking, &diffs);
+        assert_eq!(
+            summary,
+            UpgradeSummary {
+                breaking_changes: 2,
+                non_breaking_changes: 1,
+                execution_mismatches: 1,
+            }
+        );
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["breaking_changes"], 2);
+    }
+
     #[test]
     fn test_diff_signatures_removed_and_added() {
         let sig1 = sig("foo");
@@ -1,12 +1,15 @@
 use crate::runtime::executor::ContractExecutor;
+use crate::utils::template;
 use crate::DebuggerError;
 use crate::Result;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread_local;
 use std::time::Instant;
@@ -25,8 +28,17 @@ pub struct BatchItem {
     /// When true, use exact string match; when false (default), use semantic comparison
     #[serde(default)]
     pub strict: bool,
+    /// Initial storage as a JSON object, applied to a fresh executor for this item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<String>,
+    /// Function to call instead of the batch-wide function
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
 }
 
+/// Columns accepted in a CSV batch file. Only `args` is required.
+const CSV_COLUMNS: &[&str] = &["args", "storage", "expected_result", "function", "label"];
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 enum BatchItemInput {
@@ -38,6 +50,10 @@ enum BatchItemInput {
         label: Option<String>,
         #[serde(default)]
         strict: bool,
+        #[serde(default)]
+        storage: Option<Value>,
+        #[serde(default)]
+        function: Option<String>,
     },
     RawArgs(Value),
 }
@@ -54,6 +70,9 @@ pub struct BatchResult {
     pub expected: Option<String>,
     pub passed: bool,
     pub duration_ms: u128,
+    /// True when the item was not run because fail-fast stopped the batch
+    #[serde(default)]
+    pub skipped: bool,
 }
 
 /// Summary of batch execution results
@@ -63,13 +82,29 @@ pub struct BatchSummary {
     pub passed: usize,
     pub failed: usize,
     pub errors: usize,
+    /// Items not run because fail-fast stopped the batch
+    pub skipped: usize,
     pub total_duration_ms: u128,
+    /// Items that ran and did not pass, in input order
+    pub failures: Vec<BatchFailure>,
+}
+
+/// An item that failed or errored, and why
+#[derive(Debug, Serialize)]
+pub struct BatchFailure {
+    pub index: usize,
+    pub label: Option<String>,
+    pub reason: String,
 }
 
 /// Batch executor for running multiple contract calls in parallel
 pub struct BatchExecutor {
     wasm_bytes: Arc<Vec<u8>>,
     function: String,
+    /// Maximum number of items run at once; `None` uses every core
+    concurrency: Option<usize>,
+    /// Skip the items not yet started once one item fails or errors
+    fail_fast: bool,
 }
 
 // Thread-local storage for executors to avoid re-initialization
@@ -83,11 +118,35 @@ impl BatchExecutor {
         Ok(Self {
             wasm_bytes: Arc::new(wasm_bytes),
             function,
+            concurrency: None,
+            fail_fast: false,
         })
     }
 
-    /// Load batch items from a JSON file
+    /// Run at most `n` items at once instead of one per core
+    pub fn with_concurrency(mut self, n: usize) -> Self {
+        self.concurrency = Some(n.max(1));
+        self
+    }
+
+    /// Stop starting new items after the first failure or error. Items already
+    /// running finish; the rest are reported as skipped.
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Load batch items from a JSON file, or a CSV file when the path ends in `.csv`
     pub fn load_batch_file<P: AsRef<Path>>(path: P) -> Result<Vec<BatchItem>> {
+        Self::load_batch_file_with_vars(path, &HashMap::new())
+    }
+
+    /// Load batch items from a JSON or CSV file, substituting `${name}`
+    /// placeholders from `vars` before the file is parsed.
+    pub fn load_batch_file_with_vars<P: AsRef<Path>>(
+        path: P,
+        vars: &HashMap<String, String>,
+    ) -> Result<Vec<BatchItem>> {
         let content = fs::read_to_string(path.as_ref()).map_err(|e| {
             DebuggerError::FileError(format!(
                 "Failed to read batch file {:?}: {}",
@@ -95,6 +154,22 @@ impl BatchExecutor {
                 e
             ))
         })?;
+        let content = template::substitute(&content, vars)?;
+
+        let is_csv = path
+            .as_ref()
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+        if is_csv {
+            return parse_csv_items(&content).map_err(|e| {
+                DebuggerError::FileError(format!(
+                    "Failed to parse batch CSV {:?}: {}",
+                    path.as_ref(),
+                    e
+                ))
+                .into()
+            });
+        }
 
         let parsed: Vec<BatchItemInput> = serde_json::from_str(&content).map_err(|e| {
             DebuggerError::FileError(format!(
@@ -114,11 +189,37 @@ impl BatchExecutor {
 
     /// Execute all batch items in parallel
     pub fn execute_batch(&self, items: Vec<BatchItem>) -> Result<Vec<BatchResult>> {
-        let results: Vec<BatchResult> = items
-            .par_iter()
-            .enumerate()
-            .map(|(index, item)| self.execute_single(index, item))
-            .collect();
+        let stop = AtomicBool::new(false);
+        let run = || -> Vec<BatchResult> {
+            items
+                .par_iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    if stop.load(Ordering::Relaxed) {
+                        return BatchResult::skipped(index, item);
+                    }
+                    let result = self.execute_single(index, item);
+                    if self.fail_fast && !result.passed {
+                        stop.store(true, Ordering::Relaxed);
+                    }
+                    result
+                })
+                .collect()
+        };
+
+        let results = match self.concurrency {
+            Some(n) => rayon::ThreadPoolBuilder::new()
+                .num_threads(n)
+                .build()
+                .map_err(|e| {
+                    DebuggerError::ExecutionError(format!(
+                        "Failed to start batch worker pool: {}",
+                        e
+                    ))
+                })?
+                .install(run),
+            None => run(),
+        };
 
         Ok(results)
     }
@@ -126,8 +227,38 @@ impl BatchExecutor {
     /// Execute a single batch item
     fn execute_single(&self, index: usize, item: &BatchItem) -> BatchResult {
         let start = Instant::now();
+        let function = item.function.as_deref().unwrap_or(&self.function);
 
-        let (result_str, success, error) = THREAD_EXECUTOR.with(|executor_cell| {
+        let (result_str, success, error) = match &item.storage {
+            Some(storage) => self.execute_with_storage(function, &item.args, storage),
+            None => self.execute_shared(function, &item.args),
+        };
+
+        let duration = start.elapsed().as_millis();
+
+        let passed = if let Some(expected) = &item.expected {
+            success && values_match(&result_str, expected, item.strict)
+        } else {
+            success
+        };
+
+        BatchResult {
+            index,
+            label: item.label.clone(),
+            args: item.args.clone(),
+            result: result_str,
+            success,
+            error,
+            expected: item.expected.clone(),
+            passed,
+            duration_ms: duration,
+            skipped: false,
+        }
+    }
+
+    /// Run an item without its own storage on this thread's reused executor
+    fn execute_shared(&self, function: &str, args: &str) -> (String, bool, Option<String>) {
+        THREAD_EXECUTOR.with(|executor_cell| {
             let mut executor_ref = executor_cell.borrow_mut();
 
             // Check if we need to create/recreate the executor
@@ -135,7 +266,7 @@ impl BatchExecutor {
                 if Arc::ptr_eq(wasm_bytes, &self.wasm_bytes) {
                     // Reuse existing executor
                     if let Some(executor) = executor_ref.as_mut() {
-                        return match executor.1.execute(&self.function, Some(&item.args)) {
+                        return match executor.1.execute(function, Some(args)) {
                             Ok(result) => (result, true, None),
                             Err(e) => (String::new(), false, Some(format!("{:#}", e))),
                         };
@@ -146,7 +277,7 @@ impl BatchExecutor {
             // Create new executor
             match ContractExecutor::new((*self.wasm_bytes).clone()) {
                 Ok(mut executor) => {
-                    let result = match executor.execute(&self.function, Some(&item.args)) {
+                    let result = match executor.execute(function, Some(args)) {
                         Ok(result) => (result, true, None),
                         Err(e) => (String::new(), false, Some(format!("{:#}", e))),
                     };
@@ -155,43 +286,61 @@ impl BatchExecutor {
                 }
                 Err(e) => (String::new(), false, Some(format!("{:#}", e))),
             }
-        });
-
-        let duration = start.elapsed().as_millis();
-
-        let passed = if let Some(expected) = &item.expected {
-            success && values_match(&result_str, expected, item.strict)
-        } else {
-            success
-        };
+        })
+    }
 
-        BatchResult {
-            index,
-            label: item.label.clone(),
-            args: item.args.clone(),
-            result: result_str,
-            success,
-            error,
-            expected: item.expected.clone(),
-            passed,
-            duration_ms: duration,
+    /// Run an item on a fresh executor seeded with `storage`, so neither earlier
+    /// items nor this one leak state into other items
+    fn execute_with_storage(
+        &self,
+        function: &str,
+        args: &str,
+        storage: &str,
+    ) -> (String, bool, Option<String>) {
+        let outcome = ContractExecutor::new((*self.wasm_bytes).clone()).and_then(|mut executor| {
+            executor.set_initial_storage(storage.to_string())?;
+            executor.execute(function, Some(args))
+        });
+        match outcome {
+            Ok(result) => (result, true, None),
+            Err(e) => (String::new(), false, Some(format!("{:#}", e))),
         }
     }
 
     /// Generate summary from batch results
     pub fn summarize(results: &[BatchResult]) -> BatchSummary {
         let total = results.len();
-        let passed = results.iter().filter(|r| r.passed).count();
-        let failed = results.iter().filter(|r| r.success && !r.passed).count();
-        let errors = results.iter().filter(|r| !r.success).count();
+        let skipped = results.iter().filter(|r| r.skipped).count();
+        let ran = || results.iter().filter(|r| !r.skipped);
+        let passed = ran().filter(|r| r.passed).count();
+        let failed = ran().filter(|r| r.success && !r.passed).count();
+        let errors = ran().filter(|r| !r.success).count();
         let total_duration_ms = results.iter().map(|r| r.duration_ms).sum();
 
+        let mut failures: Vec<BatchFailure> = ran()
+            .filter(|r| !r.passed)
+            .map(|r| BatchFailure {
+                index: r.index,
+                label: r.label.clone(),
+                reason: match (&r.error, &r.expected) {
+                    (Some(error), _) => error.clone(),
+                    (None, Some(expected)) => {
+                        format!("expected {}, got {}", expected, r.result)
+                    }
+                    (None, None) => "execution failed".to_string(),
+                },
+            })
+            .collect();
+        failures.sort_by_key(|f| f.index);
+
         BatchSummary {
             total,
             passed,
             failed,
             errors,
+            skipped,
             total_duration_ms,
+            failures,
         }
     }
 
@@ -205,7 +354,9 @@ impl BatchExecutor {
         crate::logging::log_display("=".repeat(80), crate::logging::LogLevel::Info);
 
         for result in results {
-            let status = if result.passed {
+            let status = if result.skipped {
+                "SKIP"
+            } else if result.passed {
                 "PASS"
             } else if result.success {
                 "FAIL"
@@ -223,6 +374,13 @@ impl BatchExecutor {
                 format!("  Args: {}", result.args),
                 crate::logging::LogLevel::Info,
             );
+            if result.skipped {
+                crate::logging::log_display(
+                    "  Not run: an earlier item failed (--fail-fast)",
+                    crate::logging::LogLevel::Info,
+                );
+                continue;
+            }
 
             if result.success {
                 crate::logging::log_display(
@@ -293,14 +451,50 @@ impl BatchExecutor {
             );
         }
 
+        if summary.skipped > 0 {
+            crate::logging::log_display(
+                format!("  Skipped:  {}", summary.skipped),
+                crate::logging::LogLevel::Info,
+            );
+        }
+
         crate::logging::log_display(
             format!("  Duration: {}ms", summary.total_duration_ms),
             crate::logging::LogLevel::Info,
         );
+
+        if !summary.failures.is_empty() {
+            crate::logging::log_display("\n  Failed items:", crate::logging::LogLevel::Warn);
+            for failure in &summary.failures {
+                let default_label = format!("Test #{}", failure.index);
+                let label = failure.label.as_deref().unwrap_or(&default_label);
+                crate::logging::log_display(
+                    format!("    {}: {}", label, failure.reason),
+                    crate::logging::LogLevel::Warn,
+                );
+            }
+        }
         crate::logging::log_display("=".repeat(80), crate::logging::LogLevel::Info);
     }
 }
 
+impl BatchResult {
+    fn skipped(index: usize, item: &BatchItem) -> Self {
+        Self {
+            index,
+            label: item.label.clone(),
+            args: item.args.clone(),
+            result: String::new(),
+            success: false,
+            error: None,
+            expected: item.expected.clone(),
+            passed: false,
+            duration_ms: 0,
+            skipped: true,
+        }
+    }
+}
+
 /// Compare a result against an expected value.
 ///
 /// In loose mode (default, `strict = false`):
@@ -353,22 +547,125 @@ impl From<BatchItemInput> for BatchItem {
                 expected: None,
                 label: None,
                 strict: false,
+                storage: None,
+                function: None,
             },
             BatchItemInput::Structured {
                 args,
                 expected,
                 label,
                 strict,
+                storage,
+                function,
             } => Self {
                 args: json_value_to_text(args),
                 expected: expected.map(json_value_to_text),
                 label,
                 strict,
+                storage: storage.map(json_value_to_text),
+                function,
             },
         }
     }
 }
 
+/// Build batch items from CSV text with a header row naming [`CSV_COLUMNS`].
+/// Empty cells are treated as absent; rows without a `label` column are
+/// labelled `row N`, counting data rows from 1.
+fn parse_csv_items(content: &str) -> std::result::Result<Vec<BatchItem>, String> {
+    let mut records = parse_csv_records(content)?.into_iter();
+    let header: Vec<String> = records
+        .next()
+        .ok_or("missing header row")?
+        .iter()
+        .map(|h| h.trim().to_ascii_lowercase())
+        .collect();
+    if let Some(unknown) = header.iter().find(|h| !CSV_COLUMNS.contains(&h.as_str())) {
+        return Err(format!(
+            "unknown column '{}' (expected {})",
+            unknown,
+            CSV_COLUMNS.join(", ")
+        ));
+    }
+    let column = |name: &str| header.iter().position(|h| h == name);
+    let args_col = column("args").ok_or("missing 'args' column")?;
+
+    records
+        .enumerate()
+        .map(|(i, record)| {
+            let row = i + 1;
+            if record.len() != header.len() {
+                return Err(format!(
+                    "row {} has {} fields, expected {}",
+                    row,
+                    record.len(),
+                    header.len()
+                ));
+            }
+            let cell = |col: Option<usize>| {
+                col.map(|c| record[c].trim())
+                    .filter(|v| !v.is_empty())
+                    .map(str::to_string)
+            };
+            Ok(BatchItem {
+                args: cell(Some(args_col)).unwrap_or_else(|| "[]".to_string()),
+                expected: cell(column("expected_result")),
+                label: cell(column("label")).or_else(|| Some(format!("row {}", row))),
+                strict: false,
+                storage: cell(column("storage")),
+                function: cell(column("function")),
+            })
+        })
+        .collect()
+}
+
+/// Split CSV text into records. A field may be wrapped in `"` (with `""` for a
+/// literal quote), which is how JSON args and storage containing commas are
+/// written. Blank lines are skipped.
+fn parse_csv_records(content: &str) -> std::result::Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("unterminated quoted field".to_string());
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records.retain(|r| !(r.len() == 1 && r[0].trim().is_empty()));
+    Ok(records)
+}
+
 fn json_value_to_text(value: Value) -> String {
     match value {
         Value::String(s) => s,
@@ -451,6 +748,39 @@ mod tests {
         assert!(!items[1].strict);
     }
 
+    #[test]
+    fn test_parse_csv_items_reads_quoted_json_cells() {
+        let csv = "args,storage,expected_result,function\n\
+                   \"[1, 2]\",\"{\"\"count\"\": 5}\",8,add\n\
+                   \n\
+                   [3],,,\n";
+        let items = parse_csv_items(csv).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].args, "[1, 2]");
+        assert_eq!(items[0].storage.as_deref(), Some(r#"{"count": 5}"#));
+        assert_eq!(items[0].expected.as_deref(), Some("8"));
+        assert_eq!(items[0].function.as_deref(), Some("add"));
+        assert_eq!(items[0].label.as_deref(), Some("row 1"));
+        assert_eq!(items[1].args, "[3]");
+        assert_eq!(items[1].storage, None);
+        assert_eq!(items[1].function, None);
+        assert_eq!(items[1].label.as_deref(), Some("row 2"));
+    }
+
+    #[test]
+    fn test_parse_csv_items_rejects_bad_shape() {
+        assert!(parse_csv_items("args,expected\n[1],1\n")
+            .unwrap_err()
+            .contains("unknown column 'expected'"));
+        assert!(parse_csv_items("storage\n{}\n")
+            .unwrap_err()
+            .contains("missing 'args'"));
+        assert!(parse_csv_items("args,function\n[1]\n")
+            .unwrap_err()
+            .contains("row 1 has 1 fields"));
+        assert!(parse_csv_items("args\n\"[1]\n").is_err());
+    }
+
     #[test]
     fn test_batch_summary() {
         let results = vec![
@@ -464,6 +794,7 @@ mod tests {
                 expected: Some("ok".to_string()),
                 passed: false,
                 duration_ms: 15,
+                skipped: false,
             },
             BatchResult {
                 index: 2,
@@ -475,6 +806,7 @@ mod tests {
                 expected: Some("ok".to_string()),
                 passed: true,
                 duration_ms: 10,
+                skipped: false,
             },
         ];
 
@@ -484,5 +816,9 @@ mod tests {
         assert_eq!(summary.failed, 1);
         assert_eq!(summary.errors, 0);
         assert_eq!(summary.total_duration_ms, 25);
+        assert_eq!(summary.failures.len(), 1);
+        assert_eq!(summary.failures[0].index, 1);
+        assert_eq!(summary.failures[0].reason, "expected ok, got fail");
+        assert_eq!(summary.skipped, 0);
     }
 }
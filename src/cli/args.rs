@@ -1,4 +1,5 @@
-use crate::config::Config;
+use crate::config::{Config, NetworkLimitsProfile, ThemeName};
+use crate::inspector::storage::StorageDurability;
 use clap::{Parser, Subcommand, ValueEnum};
 
 use clap_complete::Shell;
@@ -50,11 +51,22 @@ pub enum ProfileExportFormat {
     Json,
 }
 
+/// Rendering for captured contract events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum EventsFormat {
+    #[default]
+    Default,
+    /// One CloudEvents 1.0 JSON object per event
+    #[value(name = "cloudevents")]
+    CloudEvents,
+}
+
 /// Format for dependency graph output.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum GraphFormat {
     Dot,
     Mermaid,
+    Json,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
@@ -65,6 +77,17 @@ pub enum SymbolicProfile {
     Deep,
 }
 
+/// How large budget values (CPU instructions, memory bytes) are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NumberFormat {
+    /// Raw digits, e.g. 123456789
+    Plain,
+    /// Thousands separators, e.g. 123,456,789
+    Grouped,
+    /// SI suffixes, e.g. 123.46M
+    Si,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
 pub enum SnapshotCompression {
     #[default]
@@ -101,6 +124,11 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_banner: bool,
 
+    /// How to render budget values: plain, grouped (default), or si.
+    /// JSON output defaults to plain.
+    #[arg(long, global = true, value_enum, value_name = "FORMAT")]
+    pub number_format: Option<NumberFormat>,
+
     /// Override the history file location (useful for CI, sandboxes, and per-project isolation)
     ///
     /// Equivalent to setting `SOROBAN_DEBUG_HISTORY_FILE`.
@@ -124,15 +152,34 @@ pub struct Cli {
     #[arg(long)]
     pub trend_function: Option<String>,
 
+    /// Limit the budget trend to runs recorded at or after this commit (hash or prefix)
+    #[arg(long, value_name = "SHA")]
+    pub since_commit: Option<String>,
+
     #[arg(long, default_value_t = 10.0, value_name = "PCT", value_parser = clap::value_parser!(f64))]
     pub trend_regression_threshold_pct: f64,
 
+    /// CPU change in percent that counts as a regression in the budget trend
+    /// (overrides --trend-regression-threshold-pct and the config file)
+    #[arg(long, value_name = "PCT")]
+    pub cpu_regression_threshold: Option<f64>,
+
+    /// Memory change in percent that counts as a regression in the budget trend
+    /// (overrides --trend-regression-threshold-pct and the config file)
+    #[arg(long, value_name = "PCT")]
+    pub mem_regression_threshold: Option<f64>,
+
     #[arg(long, default_value_t = 2, value_name = "N", value_parser = clap::value_parser!(usize))]
     pub trend_regression_lookback: usize,
 
     #[arg(long, default_value_t = 1, value_name = "N", value_parser = clap::value_parser!(usize))]
     pub trend_regression_smoothing: usize,
 
+    /// Exit with code 5 when the budget trend shows a CPU or memory
+    /// regression above the threshold
+    #[arg(long, requires = "budget_trend")]
+    pub fail_on_regression: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 
@@ -194,6 +241,14 @@ pub enum Commands {
     #[command(subcommand_help_heading = "Analyze and Compare")]
     UpgradeCheck(UpgradeCheckArgs),
 
+    /// Seed a new contract with the old version's storage and run its migration function
+    #[command(subcommand_help_heading = "Analyze and Compare")]
+    MigrateCheck(MigrateCheckArgs),
+
+    /// Compare two contracts' interfaces from their contract specs
+    #[command(subcommand_help_heading = "Analyze and Compare")]
+    DiffSpec(DiffSpecArgs),
+
     /// Analyze contract and generate gas optimization suggestions
     #[command(subcommand_help_heading = "Analyze and Compare")]
     Optimize(OptimizeArgs),
@@ -206,6 +261,18 @@ pub enum Commands {
     #[command(subcommand_help_heading = "Analyze and Compare")]
     Compare(CompareArgs),
 
+    /// Compare two network snapshot files (accounts, balances, contract storage)
+    #[command(subcommand_help_heading = "Analyze and Compare")]
+    SnapshotDiff(SnapshotDiffArgs),
+
+    /// Run a function once and fail if CPU or memory exceeds hard limits (for CI)
+    #[command(subcommand_help_heading = "Analyze and Compare")]
+    Gate(GateArgs),
+
+    /// Binary-search an input range for the smallest value that makes a function fail
+    #[command(subcommand_help_heading = "Analyze and Compare")]
+    Bisect(BisectArgs),
+
     /// Run symbolic execution to explore contract input space
     #[command(subcommand_help_heading = "Analyze and Compare")]
     Symbolic(SymbolicArgs),
@@ -214,11 +281,19 @@ pub enum Commands {
     #[command(subcommand_help_heading = "Analyze and Compare")]
     Analyze(AnalyzeArgs),
 
+    /// Run setup functions and check instance storage against the ledger entry size limit
+    #[command(subcommand_help_heading = "Analyze and Compare")]
+    StorageLimits(StorageLimitsArgs),
+
     // --- Remote and Server ---
     /// Start debug server for remote connections
     #[command(subcommand_help_heading = "Remote and Server")]
     Server(ServerArgs),
 
+    /// Serve the Debug Adapter Protocol over stdio for editor integration
+    #[command(subcommand_help_heading = "Remote and Server")]
+    Dap(DapArgs),
+
     /// Connect to remote debug server
     #[command(subcommand_help_heading = "Remote and Server")]
     Remote(RemoteArgs),
@@ -232,6 +307,10 @@ pub enum Commands {
     #[command(subcommand_help_heading = "Developer Utilities")]
     HistoryPrune(HistoryPruneArgs),
 
+    /// Export run history for spreadsheets and dashboards
+    #[command(subcommand_help_heading = "Developer Utilities")]
+    History(HistoryArgs),
+
     /// Report runtime health and diagnostics for troubleshooting
     Doctor(DoctorArgs),
 
@@ -240,7 +319,7 @@ pub enum Commands {
     External(Vec<String>),
 }
 
-#[derive(Parser)]
+#[derive(Parser, Clone)]
 pub struct RunArgs {
     /// Path to the contract WASM file
     #[arg(
@@ -258,19 +337,64 @@ pub struct RunArgs {
     #[arg(
         short,
         long,
-        required_unless_present_any = ["server", "remote"]
+        required_unless_present_any = ["server", "remote", "args_from_events", "smoke_all"]
     )]
     pub function: Option<String>,
 
-    /// Function arguments as JSON array (e.g., '["arg1", "arg2"]')
+    /// Function arguments as JSON array (e.g., '["arg1", "arg2"]'), or an
+    /// object keyed by parameter name (e.g., '{"to": "G...", "amount": 100}')
     #[arg(short, long)]
     pub args: Option<String>,
 
+    /// Read the function arguments JSON from a file instead of --args.
+    /// Relative paths resolve against the current directory
+    #[arg(long, value_name = "FILE", conflicts_with = "args")]
+    pub args_file: Option<PathBuf>,
+
+    /// Rebuild the function and arguments from an event in this log (RPC getEvents JSON)
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["function", "args", "args_file"]
+    )]
+    pub args_from_events: Option<PathBuf>,
+
+    /// Index of the event to replay with --args-from-events
+    #[arg(long, default_value_t = 0, requires = "args_from_events")]
+    pub event_index: usize,
+
+    /// Skip checking the argument count and types against the contract spec
+    /// before invoking the function
+    #[arg(long)]
+    pub no_arg_check: bool,
+
+    /// Call every exported function once with no arguments (or zero-like defaults
+    /// from the contract spec) and report ok/err/panic per function
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "function",
+            "args",
+            "args_file",
+            "args_from_events",
+            "batch_args",
+            "repeat"
+        ]
+    )]
+    pub smoke_all: bool,
+
     /// Initial storage state as JSON object
     #[arg(short, long)]
     pub storage: Option<String>,
 
-    /// Set breakpoint at function name
+    /// Substitute `${NAME}` in --args, --storage and the --batch-args file
+    /// with VALUE before parsing (repeatable)
+    #[arg(long = "var", value_name = "NAME=VALUE")]
+    pub vars: Vec<String>,
+
+    /// Set breakpoint at function name, optionally only when an argument
+    /// condition holds (e.g. 'transfer when amount > 1000'). Add 'ignore:N' to
+    /// skip the first N hits (e.g. 'heavy ignore:50')
     #[arg(short, long)]
     pub breakpoint: Vec<String>,
 
@@ -278,10 +402,45 @@ pub struct RunArgs {
     #[arg(long, value_name = "FUNCTION=MESSAGE")]
     pub log_point: Vec<String>,
 
-    /// Network snapshot file to load before execution
+    /// Network snapshot file or http(s) URL to load before execution
     #[arg(long)]
     pub network_snapshot: Option<PathBuf>,
 
+    /// Soroban RPC endpoint to fetch --contract-id's live ledger state from
+    #[arg(
+        long,
+        value_name = "URL",
+        requires = "contract_id",
+        conflicts_with = "network_snapshot"
+    )]
+    pub rpc_url: Option<String>,
+
+    /// Contract (C...) whose ledger state is fetched from --rpc-url
+    #[arg(long, value_name = "CONTRACT_ID", requires = "rpc_url")]
+    pub contract_id: Option<String>,
+
+    /// Persistent storage key to fetch from --rpc-url, written like an --args
+    /// value (e.g. '["Balance", "G..."]'); repeat for several keys
+    #[arg(long, value_name = "JSON", requires = "rpc_url")]
+    pub rpc_key: Vec<String>,
+
+    /// Seconds --rpc-url state is reused from the local cache before it is fetched again
+    #[arg(long, default_value_t = 300, value_name = "SECS")]
+    pub rpc_cache_ttl: u64,
+
+    /// Times a failed download is retried when --network-snapshot is a URL or
+    /// --rpc-url is set
+    #[arg(long, default_value_t = 3, value_name = "N")]
+    pub snapshot_retries: u32,
+
+    /// Per-attempt timeout in seconds when --network-snapshot is a URL or --rpc-url is set
+    #[arg(long, default_value_t = 30, value_name = "SECS")]
+    pub snapshot_timeout: u64,
+
+    /// Always download URL and RPC snapshots instead of using the local cache
+    #[arg(long)]
+    pub no_snapshot_cache: bool,
+
     /// Deprecated: use --network-snapshot instead
     #[arg(long, hide = true, alias = "snapshot")]
     pub snapshot: Option<PathBuf>,
@@ -333,10 +492,45 @@ pub struct RunArgs {
     #[arg(long)]
     pub show_auth: bool,
 
+    /// Show CPU and memory charged per host cost type (Wasm execution,
+    /// allocation, storage, ...)
+    #[arg(long)]
+    pub show_budget: bool,
+
+    /// Network whose CPU/memory limits the budget is measured against.
+    /// Overrides `[budget_limits] profile` in .soroban-debug.toml
+    #[arg(long, value_enum, value_name = "PROFILE")]
+    pub network_limits: Option<NetworkLimitsProfile>,
+
     /// Output format as JSON
     #[arg(long)]
     pub json: bool,
 
+    /// Sort JSON object keys so two runs' output can be compared with `diff`.
+    /// Needs JSON output (--json, --output json or --format json)
+    #[arg(long)]
+    pub canonical_json: bool,
+
+    /// Print one JSON object per line as the run progresses (contract
+    /// loaded, stepped instructions, breakpoint hits, then storage changes and
+    /// events once the call returns) and end with a line holding the result
+    /// and budget. Replaces the human-readable output
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "json",
+            "repeat",
+            "batch_args",
+            "save_output",
+            "trace_output",
+            "show_auth",
+            "show_ledger",
+            "alert_on_change",
+            "diff_durability",
+        ]
+    )]
+    pub json_stream: bool,
+
     /// Filter events by topic (deprecated single value). Prefer using --event-filter (repeatable).
     #[arg(long)]
     pub filter_topic: Option<String>,
@@ -345,14 +539,64 @@ pub struct RunArgs {
     #[arg(long, value_name = "PATTERN")]
     pub event_filter: Vec<String>,
 
+    /// Event rendering: default, or cloudevents to emit each event as a
+    /// CloudEvents 1.0 JSON object (implies --show-events)
+    #[arg(long, value_enum, default_value_t = EventsFormat::Default)]
+    pub events_format: EventsFormat,
+
+    /// Validate emitted events against a JSON schema mapping topic names to
+    /// expected topic and data types; the run fails on any mismatch
+    #[arg(long, value_name = "FILE")]
+    pub event_schema: Option<PathBuf>,
+
     /// Execute the contract call N times for stress testing
     #[arg(long)]
     pub repeat: Option<u32>,
 
-    /// Mock cross-contract return: CONTRACT_ID.function=return_value (repeatable)
+    /// Write per-iteration CPU/memory samples and their distribution to this JSON file
+    #[arg(long, value_name = "PATH", requires = "repeat")]
+    pub repeat_json: Option<PathBuf>,
+
+    /// Mock cross-contract return: CONTRACT_ID.function=return_value or
+    /// CONTRACT_ID.function -> return_value (repeatable). The value uses the --args
+    /// JSON format, e.g. `{"type":"i128","value":500}`.
+    /// Append `|cpu=N,mem=N,latency_ms=N` to charge a simulated cost for each mocked call
     #[arg(long, value_name = "CONTRACT_ID.function=return_value")]
     pub mock: Vec<String>,
 
+    /// Make `require_auth` fail for ADDRESS (repeatable). Auths for all other
+    /// addresses are mocked, so authorization error paths can be exercised
+    #[arg(long, value_name = "ADDRESS")]
+    pub deny_auth: Vec<String>,
+
+    /// Execute cross-contract calls against local WASMs from DIR instead of mocks.
+    /// Files are matched by name (`<CONTRACT_ID>.wasm`) or via a `contracts.json`
+    /// id→path map. Targets without a WASM fall back to --mock.
+    #[arg(long, value_name = "DIR")]
+    pub follow_calls: Option<PathBuf>,
+
+    /// Seed storage for another contract in the simulated ledger (repeatable).
+    /// Format: CONTRACT_ID=@file.json or CONTRACT_ID='<json>', using the same
+    /// JSON formats as --storage.
+    #[arg(long, value_name = "CONTRACT_ID=@FILE")]
+    pub contract_storage: Vec<String>,
+
+    /// Compare the local result and budget against a saved RPC response
+    /// (simulateTransaction or getTransaction JSON) and report divergences.
+    /// Exits with an error when the local simulation does not reproduce the chain.
+    #[arg(long, value_name = "FILE")]
+    pub compare_with_chain: Option<PathBuf>,
+
+    /// Allowed relative difference (percent) between local and on-chain budget
+    /// figures for --compare-with-chain
+    #[arg(
+        long,
+        value_name = "PCT",
+        default_value_t = 0.0,
+        requires = "compare_with_chain"
+    )]
+    pub chain_budget_tolerance: f64,
+
     /// Filter storage output by key pattern (repeatable). Supports:
     ///   prefix*       — match keys starting with prefix
     ///   re:<regex>    — match keys by regex
@@ -360,6 +604,13 @@ pub struct RunArgs {
     #[arg(long, value_name = "PATTERN")]
     pub storage_filter: Vec<String>,
 
+    /// Mask values of matching storage keys, ledger entries and events as `***`
+    /// (repeatable). Uses --storage-filter patterns, matched against storage keys
+    /// and event topics. Keys and topics stay visible. With any pattern set, the
+    /// args and storage inputs echoed in JSON `meta` are masked too.
+    #[arg(long, value_name = "PATTERN")]
+    pub redact: Vec<String>,
+
     /// Enable instruction-level debugging
     #[arg(long)]
     pub instruction_debug: bool,
@@ -371,10 +622,34 @@ pub struct RunArgs {
     /// Step mode for instruction debugging (into, over, out, block)
     #[arg(long, default_value = "into")]
     pub step_mode: String,
+
+    /// Fail once this many instructions are stepped, or when the call
+    /// executes more WASM instructions than this
+    #[arg(long, value_name = "N")]
+    pub max_steps: Option<usize>,
+
+    /// Number of execution steps whose storage can be rewound with step back
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = crate::debugger::state::DEFAULT_STORAGE_HISTORY
+    )]
+    pub storage_history: usize,
+
+    /// Drop into the interactive debugger when execution fails, keeping the
+    /// executor state (storage, call stack, budget) from the failed call
+    #[arg(long)]
+    pub interactive_on_error: bool,
+
     /// Execute contract in dry-run mode: simulate execution without persisting storage changes
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Re-run the same invocation whenever the contract WASM changes, until
+    /// interrupted with Ctrl+C
+    #[arg(long, conflicts_with_all = ["server", "remote", "batch_args", "interactive_on_error"])]
+    pub watch: bool,
+
     /// Export storage state to JSON file after execution
     #[arg(long)]
     pub export_storage: Option<PathBuf>,
@@ -383,14 +658,36 @@ pub struct RunArgs {
     #[arg(long, value_enum, default_value_t = SnapshotCompression::None)]
     pub export_compression: SnapshotCompression,
 
+    /// Sample memory at contract entry/exit and mocked cross-contract calls and
+    /// chart it as a timeline
+    #[arg(long)]
+    pub profile_memory_timeline: bool,
+
+    /// Write the memory timeline as JSON to this file (implies --profile-memory-timeline)
+    #[arg(long, value_name = "PATH")]
+    pub memory_timeline_output: Option<PathBuf>,
+
+    /// Record the final ledger (accounts, contract storage, TTLs) as a network snapshot
+    /// loadable with --network-snapshot
+    #[arg(long, visible_alias = "save-snapshot", value_name = "PATH")]
+    pub record_snapshot: Option<PathBuf>,
+
     /// Import storage state from JSON file before execution
     #[arg(long)]
     pub import_storage: Option<PathBuf>,
 
-    /// Path to JSON file containing array of argument sets for batch execution
+    /// Path to a JSON array of argument sets, or a `.csv` file, for batch execution
     #[arg(long)]
     pub batch_args: Option<PathBuf>,
 
+    /// Run at most N batch cases at once (default: one per CPU core)
+    #[arg(long, value_name = "N", requires = "batch_args")]
+    pub batch_concurrency: Option<usize>,
+
+    /// Stop starting batch cases after the first failure or error; the rest are reported as skipped
+    #[arg(long, requires = "batch_args")]
+    pub fail_fast: bool,
+
     /// Automatically generate a unit test file from the execution trace
     #[arg(long, value_name = "FILE")]
     pub generate_test: Option<PathBuf>,
@@ -400,14 +697,43 @@ pub struct RunArgs {
     #[arg(long)]
     pub overwrite: bool,
 
+    /// Seed the generated test with the --storage state via
+    /// soroban-debug-mock's MockEnvBuilder, keeping its original types and
+    /// durabilities (default: on when --storage is given; pass
+    /// --with-storage=false to disable)
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        default_missing_value = "true",
+        requires = "generate_test"
+    )]
+    pub with_storage: Option<bool>,
+
+    /// Generate a proptest property test that fuzzes the function's integer
+    /// arguments instead of replaying the single recorded call
+    #[arg(long, requires = "generate_test")]
+    pub proptest: bool,
+
     /// Execution timeout in seconds (default: 30)
     #[arg(long, default_value = "30")]
     pub timeout: u64,
 
+    /// Maximum cross-contract call depth, counting the entry call as 1 (1-100).
+    /// The first deeper call is reported as a WASM stack overflow at its depth
+    /// and function, and the run's storage writes are rolled back
+    #[arg(long, value_name = "DEPTH")]
+    pub max_call_depth: Option<usize>,
+
     /// Trigger a prominent alert when a critical storage key is modified (repeatable)
     #[arg(long, value_name = "KEY_PATTERN")]
     pub alert_on_change: Vec<String>,
 
+    /// Only show storage changes to entries of these durability tiers
+    /// (repeatable or comma-separated; applies to JSON output too)
+    #[arg(long, value_enum, value_name = "TIER", value_delimiter = ',')]
+    pub diff_durability: Vec<StorageDurability>,
+
     /// Expected SHA-256 hash of the WASM file. If provided, loading will fail if the computed hash does not match.
     #[arg(long)]
     pub expected_hash: Option<String>,
@@ -416,7 +742,7 @@ pub struct RunArgs {
     #[arg(long)]
     pub show_ledger: bool,
 
-    /// TTL warning threshold in ledger sequence numbers (default: 1000)
+    /// Warn about entries with fewer remaining TTL ledgers than this (default: 1000)
     #[arg(long, default_value = "1000")]
     pub ttl_warning_threshold: u32,
 
@@ -536,7 +862,9 @@ pub struct InteractiveArgs {
     #[arg(long)]
     pub import_storage: Option<PathBuf>,
 
-    /// Set breakpoint at function name
+    /// Set breakpoint at function name, optionally only when an argument
+    /// condition holds (e.g. 'transfer when amount > 1000'). Add 'ignore:N' to
+    /// skip the first N hits (e.g. 'heavy ignore:50')
     #[arg(short, long)]
     pub breakpoint: Vec<String>,
 
@@ -544,7 +872,10 @@ pub struct InteractiveArgs {
     #[arg(long, value_name = "FUNCTION=MESSAGE")]
     pub log_point: Vec<String>,
 
-    /// Mock cross-contract return: CONTRACT_ID.function=return_value (repeatable)
+    /// Mock cross-contract return: CONTRACT_ID.function=return_value or
+    /// CONTRACT_ID.function -> return_value (repeatable). The value uses the --args
+    /// JSON format, e.g. `{"type":"i128","value":500}`.
+    /// Append `|cpu=N,mem=N,latency_ms=N` to charge a simulated cost for each mocked call
     #[arg(long, value_name = "CONTRACT_ID.function=return_value")]
     pub mock: Vec<String>,
 
@@ -564,9 +895,21 @@ pub struct InteractiveArgs {
     #[arg(long, default_value = "into")]
     pub step_mode: String,
 
+    /// Number of execution steps whose storage can be rewound with step back
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = crate::debugger::state::DEFAULT_STORAGE_HISTORY
+    )]
+    pub storage_history: usize,
+
     /// Expected SHA-256 hash of the WASM file. If provided, loading will fail if the computed hash does not match.
     #[arg(long)]
     pub expected_hash: Option<String>,
+
+    /// Save the calls made during the session as a scenario file on exit
+    #[arg(long, value_name = "FILE")]
+    pub save_scenario: Option<PathBuf>,
 }
 
 impl InteractiveArgs {
@@ -663,6 +1006,21 @@ pub struct HistoryPruneArgs {
     pub dry_run: bool,
 }
 
+#[derive(Parser)]
+pub struct HistoryArgs {
+    /// Write the matching records to FILE as CSV
+    #[arg(long, value_name = "FILE")]
+    pub export_csv: PathBuf,
+
+    /// Only include records for this contract hash
+    #[arg(long)]
+    pub trend_contract: Option<String>,
+
+    /// Only include records for this function name
+    #[arg(long)]
+    pub trend_function: Option<String>,
+}
+
 #[derive(Parser)]
 pub struct InspectArgs {
     /// Path to the contract WASM file
@@ -700,6 +1058,42 @@ pub struct InspectArgs {
     /// Show cross-contract dependency graph in specified format
     #[arg(long, value_enum)]
     pub dependency_graph: Option<GraphFormat>,
+
+    /// Write the cross-contract dependency graph to this file
+    #[arg(long, value_name = "FILE")]
+    pub graph_output: Option<PathBuf>,
+
+    /// Format for --graph-output (default: dot)
+    #[arg(long, value_enum, requires = "graph_output")]
+    pub graph_format: Option<GraphFormat>,
+
+    /// Name dependency graph nodes: ID=NAME pairs (comma-separated or
+    /// repeated), or @FILE for a JSON object mapping ids to names. Nodes
+    /// without a name keep their raw identifier. Callees whose address is a
+    /// constant in the WASM are keyed by contract id (C...) or 64-hex contract
+    /// hash; those chosen at run time share the node `external_contract`
+    #[arg(long, value_name = "ID=NAME", value_delimiter = ',')]
+    pub contract_map: Vec<String>,
+
+    /// Disassemble the named function's body to WAT, with instruction offsets matching the stepper
+    #[arg(long, value_name = "FUNCTION")]
+    pub wat: Option<String>,
+
+    /// List the host functions the contract imports, grouped by host module
+    /// (ledger, crypto, address, ...). Use --format json for a JSON list
+    #[arg(long)]
+    pub host_imports: bool,
+
+    /// Check the contract against Soroban deployment constraints (no floats
+    /// or other WASM features the host disables, size and count limits,
+    /// required custom sections) and fail if any check does not pass
+    #[arg(long)]
+    pub validate: bool,
+
+    /// Decode the contract spec and print full function signatures and
+    /// declared error enums (`--format json` for JSON)
+    #[arg(long)]
+    pub spec: bool,
 }
 
 #[derive(Parser)]
@@ -713,7 +1107,12 @@ pub struct UpgradeCheckArgs {
     pub new: PathBuf,
 
     /// Output format: text (default) or json
-    #[arg(long, default_value = "text")]
+    #[arg(
+        long,
+        visible_alias = "format",
+        default_value = "text",
+        value_parser = ["text", "json"]
+    )]
     pub output: String,
 
     /// Write report to file instead of stdout
@@ -726,6 +1125,25 @@ pub struct UpgradeCheckArgs {
     pub test_inputs: Option<String>,
 }
 
+#[derive(Parser)]
+pub struct DiffSpecArgs {
+    /// Path to the old contract WASM file
+    #[arg(long)]
+    pub old: PathBuf,
+
+    /// Path to the new contract WASM file
+    #[arg(long)]
+    pub new: PathBuf,
+
+    /// Output format (pretty, json)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
+
+    /// Exit with an error when any breaking interface change is found
+    #[arg(long)]
+    pub fail_on_breaking: bool,
+}
+
 #[derive(Parser)]
 pub struct OptimizeArgs {
     /// Path to the contract WASM file
@@ -744,6 +1162,11 @@ pub struct OptimizeArgs {
     #[arg(short, long)]
     pub args: Option<String>,
 
+    /// Read the function arguments JSON from a file instead of --args.
+    /// Relative paths resolve against the current directory
+    #[arg(long, value_name = "FILE", conflicts_with = "args")]
+    pub args_file: Option<PathBuf>,
+
     /// Output file for the optimization report (default: stdout)
     #[arg(short, long)]
     pub output: Option<PathBuf>,
@@ -763,11 +1186,18 @@ pub struct OptimizeArgs {
     /// Deprecated: use --network-snapshot instead
     #[arg(long, hide = true, alias = "snapshot")]
     pub snapshot: Option<PathBuf>,
+
+    /// Earlier build of the contract to profile the same functions against;
+    /// the report gains per-function CPU/memory deltas
+    #[arg(long, value_name = "OLD_WASM")]
+    pub baseline: Option<PathBuf>,
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Cli, Commands, OutputFormat, SymbolicProfile};
+    use super::{
+        Cli, Commands, NetworkLimitsProfile, OutputFormat, StorageDurability, SymbolicProfile,
+    };
     use clap::Parser;
 
     #[test]
@@ -810,6 +1240,62 @@ mod tests {
         assert!(args.is_json_output());
     }
 
+    #[test]
+    fn rpc_url_requires_contract_id_and_excludes_network_snapshot() {
+        let base = [
+            "soroban-debug",
+            "run",
+            "--contract",
+            "contract.wasm",
+            "--function",
+            "increment",
+            "--rpc-url",
+            "https://soroban-testnet.stellar.org",
+        ];
+
+        assert!(Cli::try_parse_from(base).is_err());
+        assert!(Cli::try_parse_from(base.iter().copied().chain([
+            "--contract-id",
+            "CABC",
+            "--network-snapshot",
+            "snap.json"
+        ]))
+        .is_err());
+
+        let cli = Cli::try_parse_from(base.iter().copied().chain(["--contract-id", "CABC"]))
+            .expect("rpc flags should parse");
+        let Commands::Run(args) = cli.command.expect("run command expected") else {
+            panic!("run command expected");
+        };
+        assert_eq!(args.contract_id.as_deref(), Some("CABC"));
+    }
+
+    #[test]
+    fn canonical_json_accepts_any_json_output_flag() {
+        let base = [
+            "soroban-debug",
+            "run",
+            "--contract",
+            "contract.wasm",
+            "--function",
+            "increment",
+            "--canonical-json",
+        ];
+
+        for json in [
+            &["--json"][..],
+            &["--output", "json"],
+            &["--format", "json"],
+        ] {
+            let cli = Cli::try_parse_from(base.iter().copied().chain(json.iter().copied()))
+                .expect("--canonical-json with JSON output should parse");
+            let Commands::Run(args) = cli.command.expect("run command expected") else {
+                panic!("run command expected");
+            };
+            assert!(args.canonical_json && args.is_json_output(), "{json:?}");
+        }
+    }
+
     #[test]
     fn legacy_json_flag_still_enables_json_mode() {
         let cli = Cli::parse_from([
@@ -965,6 +1451,202 @@ mod tests {
         assert_eq!(args.source_map_limit, 5);
         assert_eq!(args.format, OutputFormat::Json);
     }
+
+    #[test]
+    fn profile_accepts_source_map_wasm() {
+        let cli = Cli::parse_from([
+            "soroban-debug",
+            "profile",
+            "--contract",
+            "release.wasm",
+            "--function",
+            "transfer",
+            "--source-map",
+            "debug.wasm",
+        ]);
+
+        let Commands::Profile(args) = cli.command.expect("profile command expected") else {
+            panic!("profile command expected");
+        };
+
+        assert_eq!(
+            args.source_map,
+            Some(std::path::PathBuf::from("debug.wasm"))
+        );
+    }
+
+    #[test]
+    fn run_with_storage_toggle_accepts_bare_and_explicit_values() {
+        let parse = |extra: &[&str]| {
+            let mut argv = vec![
+                "soroban-debug",
+                "run",
+                "--contract",
+                "c.wasm",
+                "--function",
+                "get",
+                "--generate-test",
+                "t.rs",
+            ];
+            argv.extend_from_slice(extra);
+            let Commands::Run(args) = Cli::parse_from(argv).command.expect("run command expected")
+            else {
+                panic!("run command expected");
+            };
+            args.with_storage
+        };
+
+        assert_eq!(parse(&[]), None);
+        assert_eq!(parse(&["--with-storage"]), Some(true));
+        assert_eq!(parse(&["--with-storage=false"]), Some(false));
+    }
+
+    #[test]
+    fn run_proptest_requires_generate_test() {
+        let base = [
+            "soroban-debug",
+            "run",
+            "--contract",
+            "c.wasm",
+            "--function",
+            "stake",
+        ];
+        assert!(Cli::try_parse_from(base.iter().chain(&["--proptest"])).is_err());
+
+        let cli = Cli::parse_from(
+            base.iter()
+                .chain(&["--generate-test", "t.rs", "--proptest"]),
+        );
+        let Commands::Run(args) = cli.command.expect("run command expected") else {
+            panic!("run command expected");
+        };
+        assert!(args.proptest);
+    }
+
+    #[test]
+    fn run_watch_conflicts_with_interactive_on_error() {
+        let base = [
+            "soroban-debug",
+            "run",
+            "--contract",
+            "c.wasm",
+            "--function",
+            "stake",
+            "--watch",
+        ];
+        let cli = Cli::parse_from(base);
+        let Commands::Run(args) = cli.command.expect("run command expected") else {
+            panic!("run command expected");
+        };
+        assert!(args.watch);
+
+        assert!(Cli::try_parse_from(base.iter().chain(&["--interactive-on-error"])).is_err());
+    }
+
+    #[test]
+    fn run_accepts_network_limits_profile() {
+        let base = [
+            "soroban-debug",
+            "run",
+            "--contract",
+            "c.wasm",
+            "--function",
+            "stake",
+        ];
+        let cli = Cli::parse_from(base.iter().chain(&["--network-limits", "mainnet"]));
+        let Commands::Run(args) = cli.command.expect("run command expected") else {
+            panic!("run command expected");
+        };
+        assert_eq!(args.network_limits, Some(NetworkLimitsProfile::Mainnet));
+
+        assert!(Cli::try_parse_from(base.iter().chain(&["--network-limits", "devnet"])).is_err());
+    }
+
+    #[test]
+    fn json_stream_conflicts_with_flags_it_does_not_stream() {
+        let base = [
+            "soroban-debug",
+            "run",
+            "--contract",
+            "c.wasm",
+            "--function",
+            "stake",
+            "--json-stream",
+        ];
+        assert!(Cli::try_parse_from(base).is_ok());
+
+        for extra in [
+            &["--show-auth"][..],
+            &["--show-ledger"],
+            &["--alert-on-change", "admin"],
+            &["--diff-durability", "persistent"],
+        ] {
+            assert!(
+                Cli::try_parse_from(base.iter().chain(extra)).is_err(),
+                "{extra:?} should conflict with --json-stream"
+            );
+        }
+    }
+
+    #[test]
+    fn diff_durability_accepts_multiple_tiers() {
+        let cli = Cli::parse_from([
+            "soroban-debug",
+            "run",
+            "--contract",
+            "c.wasm",
+            "--function",
+            "transfer",
+            "--diff-durability",
+            "persistent,temporary",
+            "--diff-durability",
+            "instance",
+        ]);
+        let Commands::Run(args) = cli.command.expect("run command expected") else {
+            panic!("run command expected");
+        };
+        assert_eq!(
+            args.diff_durability,
+            vec![
+                StorageDurability::Persistent,
+                StorageDurability::Temporary,
+                StorageDurability::Instance
+            ]
+        );
+    }
+
+    #[test]
+    fn args_file_conflicts_with_args() {
+        let base = [
+            "soroban-debug",
+            "run",
+            "--contract",
+            "c.wasm",
+            "--function",
+            "stake",
+        ];
+        let cli = Cli::parse_from(base.iter().chain(&["--args-file", "args.json"]));
+        let Commands::Run(args) = cli.command.expect("run command expected") else {
+            panic!("run command expected");
+        };
+        assert_eq!(args.args_file, Some(std::path::PathBuf::from("args.json")));
+
+        assert!(Cli::try_parse_from(base.iter().chain(&[
+            "--args-file",
+            "args.json",
+            "--args",
+            "[1]"
+        ]))
+        .is_err());
+    }
+
+    #[test]
+    fn fail_on_regression_requires_budget_trend() {
+        let cli = Cli::parse_from(["soroban-debug", "--budget-trend", "--fail-on-regression"]);
+        assert!(cli.fail_on_regression);
+
+        assert!(Cli::try_parse_from(["soroban-debug", "--fail-on-regression"]).is_err());
+    }
 }
 
 #[derive(Parser)]
@@ -992,6 +1674,21 @@ pub struct CompareArgs {
     pub ignore_field: Vec<String>,
 }
 
+#[derive(Parser)]
+pub struct SnapshotDiffArgs {
+    /// Path to the older network snapshot JSON file
+    #[arg(value_name = "OLD")]
+    pub old: PathBuf,
+
+    /// Path to the newer network snapshot JSON file
+    #[arg(value_name = "NEW")]
+    pub new: PathBuf,
+
+    /// Output the diff as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
 /// Arguments for the TUI dashboard subcommand
 #[derive(Parser)]
 pub struct TuiArgs {
@@ -1011,7 +1708,9 @@ pub struct TuiArgs {
     #[arg(short, long)]
     pub storage: Option<String>,
 
-    /// Set breakpoints at function names
+    /// Set breakpoints at function names, optionally only when an argument
+    /// condition holds (e.g. 'transfer when amount > 1000'). Add 'ignore:N' to
+    /// skip the first N hits (e.g. 'heavy ignore:50')
     #[arg(short, long)]
     pub breakpoint: Vec<String>,
 
@@ -1022,6 +1721,16 @@ pub struct TuiArgs {
     /// Network snapshot file to load before execution
     #[arg(long)]
     pub network_snapshot: Option<PathBuf>,
+
+    /// Network whose CPU/memory limits the budget gauges are sized for.
+    /// Overrides `[budget_limits] profile` in .soroban-debug.toml
+    #[arg(long, value_enum, value_name = "PROFILE")]
+    pub network_limits: Option<NetworkLimitsProfile>,
+
+    /// Dashboard color theme. The choice is saved to `[theme] name` in
+    /// .soroban-debug.toml and used when the flag is omitted
+    #[arg(long, value_enum, value_name = "THEME")]
+    pub theme: Option<ThemeName>,
 }
 
 impl TuiArgs {
@@ -1069,6 +1778,11 @@ pub struct ProfileArgs {
     #[arg(short, long)]
     pub args: Option<String>,
 
+    /// Read the function arguments JSON from a file instead of --args.
+    /// Relative paths resolve against the current directory
+    #[arg(long, value_name = "FILE", conflicts_with = "args")]
+    pub args_file: Option<PathBuf>,
+
     /// Output file for the profile report (default: stdout)
     #[arg(short, long)]
     pub output: Option<PathBuf>,
@@ -1081,11 +1795,147 @@ pub struct ProfileArgs {
     #[arg(long, value_enum, default_value_t = ProfileExportFormat::Report)]
     pub export_format: ProfileExportFormat,
 
+    /// Number of functions to list in each hotspot section; the rest are summarized
+    #[arg(long, value_name = "N", default_value_t = 5)]
+    pub top: usize,
+
+    /// WASM build with DWARF debug info used to attribute cost to source lines
+    /// (default: the profiled contract, when it has a `.debug_line` section)
+    #[arg(long, value_name = "WASM")]
+    pub source_map: Option<PathBuf>,
+
+    /// Write a flamegraph of the call tree weighted by CPU instructions.
+    /// Emits SVG when the path ends in `.svg`, collapsed stacks otherwise.
+    #[arg(long, value_name = "PATH")]
+    pub flamegraph: Option<PathBuf>,
+
+    /// Mock cross-contract return: CONTRACT_ID.function=return_value or
+    /// CONTRACT_ID.function -> return_value (repeatable). The value uses the --args
+    /// JSON format, e.g. `{"type":"i128","value":500}`.
+    /// Append `|cpu=N,mem=N,latency_ms=N` to charge a simulated cost for each mocked call
+    #[arg(long, value_name = "CONTRACT_ID.function=return_value")]
+    pub mock: Vec<String>,
+
+    /// Sort JSON object keys so two runs' output can be compared with `diff`
+    #[arg(long)]
+    pub canonical_json: bool,
+
     /// Expected SHA-256 hash of the WASM file. If provided, loading will fail if the computed hash does not match.
     #[arg(long)]
     pub expected_hash: Option<String>,
 }
 
+#[derive(Parser)]
+#[command(group(
+    clap::ArgGroup::new("limits")
+        .required(true)
+        .multiple(true)
+        .args(["max_cpu", "max_mem"])
+))]
+pub struct GateArgs {
+    /// Path to the contract WASM file
+    #[arg(short, long)]
+    pub contract: PathBuf,
+
+    /// Function name to execute
+    #[arg(short, long)]
+    pub function: String,
+
+    /// Function arguments as JSON array (e.g., '["arg1", "arg2"]')
+    #[arg(short, long)]
+    pub args: Option<String>,
+
+    /// Initial storage state as JSON object
+    #[arg(short, long)]
+    pub storage: Option<String>,
+
+    /// Maximum CPU instructions the call may consume
+    #[arg(long, value_name = "N")]
+    pub max_cpu: Option<u64>,
+
+    /// Maximum memory bytes the call may consume
+    #[arg(long, value_name = "N")]
+    pub max_mem: Option<u64>,
+
+    /// Output format (pretty, json)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
+}
+
+#[derive(Parser)]
+pub struct MigrateCheckArgs {
+    /// Storage exported from the old contract (e.g. with --export-storage), as
+    /// @FILE or an inline JSON object
+    #[arg(long, value_name = "@FILE|JSON")]
+    pub old_storage: String,
+
+    /// Path to the new (upgraded) contract WASM file
+    #[arg(long)]
+    pub new_contract: PathBuf,
+
+    /// Migration function to run on the new contract
+    #[arg(short, long, default_value = "migrate")]
+    pub function: String,
+
+    /// Arguments for the migration function as JSON array
+    #[arg(short, long)]
+    pub args: Option<String>,
+
+    /// Execution timeout in seconds (default: 30)
+    #[arg(long, default_value = "30")]
+    pub timeout: u64,
+
+    /// Output format (pretty, json)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BisectFailOn {
+    /// Any failed call
+    Any,
+    /// Only CPU or memory budget exhaustion
+    Budget,
+    /// Only failures other than budget exhaustion
+    Error,
+}
+
+#[derive(Parser)]
+pub struct BisectArgs {
+    /// Path to the contract WASM file
+    #[arg(short, long)]
+    pub contract: PathBuf,
+
+    /// Function name to execute
+    #[arg(short, long)]
+    pub function: String,
+
+    /// Inclusive range of values to search, as LO..HI (e.g. 1..1000000)
+    #[arg(long, value_name = "LO..HI")]
+    pub param_range: String,
+
+    /// JSON argument array with {n} where the probed value goes
+    /// (e.g. '[{"type":"u64","value":{n}}]'); defaults to passing the value alone
+    #[arg(short, long, default_value = "[{n}]")]
+    pub args: String,
+
+    /// Kind of failure that marks the boundary
+    #[arg(long, value_enum, default_value_t = BisectFailOn::Any)]
+    pub fail_on: BisectFailOn,
+
+    /// Initial storage state as JSON object
+    #[arg(short, long)]
+    pub storage: Option<String>,
+
+    /// Execution timeout in seconds for each probe
+    #[arg(long, default_value = "30")]
+    pub timeout: u64,
+
+    /// Output format (pretty, json)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
+}
+
 #[derive(Parser)]
 pub struct SymbolicArgs {
     /// Path to the contract WASM file
@@ -1170,6 +2020,11 @@ pub struct ReplayArgs {
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 
+    /// Replace a recorded storage entry before replaying: KEY=VALUE, where VALUE
+    /// is JSON such as '{"type":"i128","value":5}' (repeatable)
+    #[arg(long, value_name = "KEY=VALUE")]
+    pub override_storage: Vec<String>,
+
     /// Show verbose output during replay
     #[arg(short, long)]
     pub verbose: bool,
@@ -1197,6 +2052,11 @@ pub struct ServerArgs {
     #[arg(long)]
     pub tls_key: Option<PathBuf>,
 
+    /// Speak the protocol over WebSocket text frames instead of raw
+    /// newline-delimited JSON (connect with `remote --remote ws://HOST:PORT`)
+    #[arg(long)]
+    pub ws: bool,
+
     /// Repeat execution N times and show throughput/latency stats
     #[arg(long, value_name = "N")]
     pub repeat: Option<u32>,
@@ -1213,11 +2073,19 @@ pub struct ServerArgs {
     #[arg(long, value_name = "PATTERN")]
     pub event_filter: Vec<String>,
 
-    /// Mock cross-contract return: CONTRACT_ID.function=return_value (repeatable)
+    /// Mock cross-contract return: CONTRACT_ID.function=return_value or
+    /// CONTRACT_ID.function -> return_value (repeatable). The value uses the --args
+    /// JSON format, e.g. `{"type":"i128","value":500}`.
+    /// Append `|cpu=N,mem=N,latency_ms=N` to charge a simulated cost for each mocked call
     #[arg(long, value_name = "CONTRACT_ID.function=return_value")]
     pub mock: Vec<String>,
 }
 
+/// The contract, function and arguments arrive in the client's `launch`
+/// request, so the subcommand itself takes no options.
+#[derive(Parser)]
+pub struct DapArgs {}
+
 #[derive(Parser)]
 pub struct RemoteArgs {
     /// Remote server address (e.g., localhost:9229)
@@ -1346,8 +2214,12 @@ pub struct RemoteEvaluateArgs {
 #[derive(Parser)]
 pub struct AnalyzeArgs {
     /// Path to the contract WASM file
-    #[arg(short, long)]
-    pub contract: PathBuf,
+    #[arg(short, long, required_unless_present = "list_rules")]
+    pub contract: Option<PathBuf>,
+
+    /// List the available security rules with their default severity and exit
+    #[arg(long)]
+    pub list_rules: bool,
 
     /// Function name to execute for dynamic analysis (optional)
     #[arg(short, long)]
@@ -1357,6 +2229,11 @@ pub struct AnalyzeArgs {
     #[arg(short, long)]
     pub args: Option<String>,
 
+    /// Read the function arguments JSON from a file instead of --args.
+    /// Relative paths resolve against the current directory
+    #[arg(long, value_name = "FILE", conflicts_with = "args")]
+    pub args_file: Option<PathBuf>,
+
     /// Initial storage state as JSON object (optional)
     #[arg(short, long)]
     pub storage: Option<String>,
@@ -1369,6 +2246,10 @@ pub struct AnalyzeArgs {
     #[arg(long, default_value = "text")]
     pub format: String,
 
+    /// Sort JSON object keys so two runs' output can be compared with `diff`
+    #[arg(long)]
+    pub canonical_json: bool,
+
     /// Enable only the specified rule id(s). Repeatable.
     #[arg(long, value_name = "RULE_ID")]
     pub enable_rule: Vec<String>,
@@ -1377,9 +2258,59 @@ pub struct AnalyzeArgs {
     #[arg(long, value_name = "RULE_ID")]
     pub disable_rule: Vec<String>,
 
-    /// Minimum severity to include: low, medium, or high.
+    /// Minimum severity to include: low, medium, high, or critical.
     #[arg(long, default_value = "low", value_name = "SEVERITY")]
     pub min_severity: String,
+
+    /// Exit with code 4 when a reported finding is at or above this
+    /// severity: low, medium, high, or critical. The report is printed first
+    #[arg(long, value_name = "SEVERITY")]
+    pub fail_on: Option<String>,
+}
+
+#[derive(Parser)]
+pub struct StorageLimitsArgs {
+    /// Path to the contract WASM file
+    #[arg(short, long)]
+    pub contract: PathBuf,
+
+    /// Setup call to execute before measuring, in call order (repeatable).
+    /// Format: FUNCTION or FUNCTION=ARGS_JSON, e.g. 'initialize=["GABC...", 2]'
+    #[arg(long, value_name = "FUNCTION[=ARGS_JSON]")]
+    pub setup: Vec<String>,
+
+    /// Initial storage state as JSON object
+    #[arg(short, long)]
+    pub storage: Option<String>,
+
+    /// Maximum serialized size in bytes of a single instance ledger entry
+    #[arg(
+        long,
+        value_name = "BYTES",
+        default_value_t = crate::inspector::storage::DEFAULT_MAX_INSTANCE_ENTRY_BYTES
+    )]
+    pub max_entry_bytes: usize,
+
+    /// Execution timeout in seconds for each setup call (default: 30)
+    #[arg(long, default_value = "30")]
+    pub timeout: u64,
+
+    /// Output format (pretty, json)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
+}
+
+impl StorageLimitsArgs {
+    /// Split each `--setup` value into a function name and optional JSON arguments.
+    pub fn parse_setup_calls(&self) -> Vec<(String, Option<String>)> {
+        self.setup
+            .iter()
+            .map(|spec| match spec.split_once('=') {
+                Some((function, args)) => (function.trim().to_string(), Some(args.to_string())),
+                None => (spec.trim().to_string(), None),
+            })
+            .collect()
+    }
 }
 
 #[derive(Parser)]
@@ -1408,6 +2339,16 @@ pub struct ScenarioArgs {
     /// Skip steps that have any of these tags (comma-separated)
     #[arg(long)]
     pub exclude_tags: Option<String>,
+
+    /// Write one flamegraph aggregating the CPU cost of every executed step.
+    /// Emits SVG when the path ends in `.svg`, collapsed stacks otherwise.
+    #[arg(long, value_name = "PATH")]
+    pub flamegraph: Option<PathBuf>,
+
+    /// Substitute `${NAME}` in step args, expected returns and --storage with
+    /// VALUE, overriding the scenario's `[vars]` table (repeatable)
+    #[arg(long = "var", value_name = "NAME=VALUE")]
+    pub vars: Vec<String>,
 }
 
 /// Arguments for the doctor/health command
@@ -1,15 +1,20 @@
 use crate::analyzer::symbolic::SymbolicConfig;
-use crate::analyzer::upgrade::{CompatibilityReport, ExecutionDiff, UpgradeAnalyzer};
+use crate::analyzer::upgrade::{
+    CompatibilityReport, ExecutionDiff, MigrationReport, UpgradeAnalyzer, UpgradeClass,
+};
 use crate::analyzer::{
     security::SecurityAnalyzer,
     symbolic::{build_replay_bundle, SymbolicAnalyzer},
 };
 use crate::cli::args::{
-    AnalyzeArgs, CompareArgs, HistoryPruneArgs, InspectArgs, InteractiveArgs, OptimizeArgs,
-    OutputFormat, ProfileArgs, RemoteAction, RemoteArgs, ReplArgs, ReplayArgs, RunArgs,
-    ScenarioArgs, ServerArgs, SymbolicArgs, SymbolicProfile, TuiArgs, UpgradeCheckArgs, Verbosity,
+    AnalyzeArgs, BisectArgs, BisectFailOn, CompareArgs, DapArgs, DiffSpecArgs, EventsFormat,
+    GateArgs, GraphFormat, HistoryArgs, HistoryPruneArgs, InspectArgs, InteractiveArgs,
+    MigrateCheckArgs, OptimizeArgs, OutputFormat, ProfileArgs, RemoteAction, RemoteArgs, ReplArgs,
+    ReplayArgs, RunArgs, ScenarioArgs, ServerArgs, SnapshotDiffArgs, StorageLimitsArgs,
+    SymbolicArgs, SymbolicProfile, TuiArgs, UpgradeCheckArgs, Verbosity,
 };
-use crate::cli::output::write_json_pretty_file;
+use crate::cli::output::{to_json_string, write_json_pretty_file};
+use crate::debugger::breakpoint::BreakpointManager;
 use crate::debugger::engine::DebuggerEngine;
 use crate::debugger::instruction_pointer::StepMode;
 use crate::debugger::timeline::{
@@ -99,6 +104,7 @@ fn render_symbolic_report(report: &crate::analyzer::symbolic::SymbolicReport) ->
         format!("Function: {}", report.function),
         format!("Paths explored: {}", report.paths_explored),
         format!("Panics found: {}", report.panics_found),
+        format!("Arithmetic findings: {}", report.arithmetic_findings.len()),
         format!(
             "Replay token: {}",
             report
@@ -141,6 +147,15 @@ fn render_symbolic_report(report: &crate::analyzer::symbolic::SymbolicReport) ->
         ));
     }
 
+    for finding in &report.arithmetic_findings {
+        lines.push(format!(
+            "  {} inputs={} ({})",
+            finding.kind.label(),
+            finding.inputs,
+            finding.detail
+        ));
+    }
+
     if report.paths.is_empty() {
         lines.push("No distinct execution paths were discovered.".to_string());
         return lines.join("\n");
@@ -201,14 +216,15 @@ fn symbolic_config_from_args(args: &SymbolicArgs) -> Result<SymbolicConfig> {
     Ok(config)
 }
 
-fn parse_min_severity(value: &str) -> Result<crate::analyzer::security::Severity> {
+fn parse_severity(flag: &str, value: &str) -> Result<crate::analyzer::security::Severity> {
     match value.to_ascii_lowercase().as_str() {
         "low" => Ok(crate::analyzer::security::Severity::Low),
         "medium" | "med" => Ok(crate::analyzer::security::Severity::Medium),
         "high" => Ok(crate::analyzer::security::Severity::High),
+        "critical" => Ok(crate::analyzer::security::Severity::Critical),
         other => Err(DebuggerError::InvalidArguments(format!(
-            "Unsupported --min-severity '{}'. Use low, medium, or high.",
-            other
+            "Unsupported {} '{}'. Use low, medium, high, or critical.",
+            flag, other
         ))
         .into()),
     }
@@ -277,6 +293,20 @@ fn render_security_report(output: &AnalyzeCommandOutput) -> String {
     lines.join("\n")
 }
 
+fn render_rule_list(rules: &[crate::analyzer::security::RuleMetadata]) -> String {
+    let id_width = rules.iter().map(|r| r.id.len()).max().unwrap_or(0);
+    let mut lines = vec![format!("Security rules ({}):", rules.len())];
+    for rule in rules {
+        lines.push(format!(
+            "  {:<id_width$}  {:<6}  {}",
+            rule.id,
+            format!("{:?}", rule.severity),
+            rule.description
+        ));
+    }
+    lines.join("\n")
+}
+
 /// Run instruction-level stepping mode.
 fn run_instruction_stepping(
     engine: &mut DebuggerEngine,
@@ -331,6 +361,37 @@ fn run_instruction_stepping(
                 display_instruction_context(engine, 5);
                 continue;
             }
+            _ if cmd == "goto" || cmd.starts_with("goto ") => {
+                let Some(pc) = parse_pc(cmd["goto".len()..].trim()) else {
+                    logging::log_display(
+                        "Usage: goto <pc> (offset in hex, e.g. 0x1a2b, or decimal)",
+                        logging::LogLevel::Info,
+                    );
+                    continue;
+                };
+                match engine.run_to_pc(pc) {
+                    Ok(true) => display_instruction_context(engine, 3),
+                    Ok(false)
+                        if engine.pause_reason()
+                            == Some(crate::debugger::state::PauseReason::Breakpoint) =>
+                    {
+                        logging::log_display(
+                            format!("Stopped at a breakpoint before reaching 0x{:08x}", pc),
+                            logging::LogLevel::Info,
+                        );
+                        display_instruction_context(engine, 3);
+                    }
+                    Ok(false) => logging::log_display(
+                        format!("Execution ended before reaching 0x{:08x}", pc),
+                        logging::LogLevel::Info,
+                    ),
+                    Err(e) => logging::log_display(
+                        format!("Error stepping: {}", e),
+                        logging::LogLevel::Info,
+                    ),
+                }
+                continue;
+            }
             "h" | "help" => {
                 logging::log_display(Formatter::format_stepping_help(), logging::LogLevel::Info);
                 continue;
@@ -361,6 +422,7 @@ fn run_instruction_stepping(
                 };
                 logging::log_display(msg, logging::LogLevel::Info);
             }
+            Err(e) if engine.step_limit_exceeded() => return Err(e),
             Err(e) => {
                 logging::log_display(format!("Error stepping: {}", e), logging::LogLevel::Info)
             }
@@ -370,6 +432,14 @@ fn run_instruction_stepping(
     Ok(())
 }
 
+/// Parse a `goto` target: hex with a `0x` prefix, otherwise decimal.
+fn parse_pc(raw: &str) -> Option<usize> {
+    match raw.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16).ok(),
+        None => raw.parse().ok(),
+    }
+}
+
 fn display_instruction_context(engine: &DebuggerEngine, context_size: usize) {
     let context = engine.get_instruction_context(context_size);
     let formatted = Formatter::format_instruction_context(&context, context_size);
@@ -438,6 +508,15 @@ fn display_mock_call_log(calls: &[crate::runtime::executor::MockCallEntry]) {
     }
     print_info("\n--- Mock Contract Calls ---");
     for (i, entry) in calls.iter().enumerate() {
+        if let Some(address) = &entry.auth_denied {
+            print_info(format!(
+                "{}. DENIED require_auth({}) in {}",
+                i + 1,
+                address,
+                entry.function
+            ));
+            continue;
+        }
         let status = if entry.mocked { "MOCKED" } else { "REAL" };
         print_info(format!(
             "{}. {} {} (args: {}) -> {}",
@@ -445,12 +524,16 @@ fn display_mock_call_log(calls: &[crate::runtime::executor::MockCallEntry]) {
             status,
             entry.function,
             entry.args_count,
-            if entry.returned.is_some() {
-                "returned"
-            } else {
-                "pending"
-            }
+            entry.returned.as_deref().unwrap_or("pending")
         ));
+        if !entry.charged.is_zero() {
+            print_info(format!(
+                "   charged: cpu={} mem={} latency={}ms",
+                entry.charged.cpu_instructions,
+                entry.charged.memory_bytes,
+                entry.charged.latency_ms
+            ));
+        }
     }
 }
 
@@ -479,13 +562,14 @@ fn run_batch(args: &RunArgs, batch_file: &std::path::Path) -> Result<()> {
     logging::log_contract_loaded(wasm_bytes.len());
 
     print_info(format!("Loading batch file: {:?}", batch_file));
-    let batch_items = crate::batch::BatchExecutor::load_batch_file(batch_file)?;
+    let vars = crate::utils::template::parse_var_assignments(&args.vars)?;
+    let batch_items = crate::batch::BatchExecutor::load_batch_file_with_vars(batch_file, &vars)?;
     print_success(format!("Loaded {} test cases", batch_items.len()));
 
     if let Some(snapshot_path) = &args.network_snapshot {
         print_info(format!("\nLoading network snapshot: {:?}", snapshot_path));
         logging::log_loading_snapshot(&snapshot_path.to_string_lossy());
-        let loader = SnapshotLoader::from_file(snapshot_path)?;
+        let loader = SnapshotLoader::load(snapshot_path, &snapshot_fetch_config(args))?;
         let loaded_snapshot = loader.apply_to_environment()?;
         logging::log_display(loaded_snapshot.format_summary(), logging::LogLevel::Info);
     }
@@ -497,7 +581,11 @@ fn run_batch(args: &RunArgs, batch_file: &std::path::Path) -> Result<()> {
     ));
     logging::log_execution_start(function, None);
 
-    let executor = crate::batch::BatchExecutor::new(wasm_bytes, function.clone())?;
+    let mut executor = crate::batch::BatchExecutor::new(wasm_bytes, function.clone())?
+        .with_fail_fast(args.fail_fast);
+    if let Some(n) = args.batch_concurrency {
+        executor = executor.with_concurrency(n);
+    }
     let results = executor.execute_batch(batch_items)?;
     let summary = crate::batch::BatchExecutor::summarize(&results);
 
@@ -509,7 +597,7 @@ fn run_batch(args: &RunArgs, batch_file: &std::path::Path) -> Result<()> {
             "summary": summary,
         });
         logging::log_display(
-            serde_json::to_string_pretty(&output).map_err(|e| {
+            to_json_string(&output, args.canonical_json).map_err(|e| {
                 DebuggerError::FileError(format!("Failed to serialize output: {}", e))
             })?,
             logging::LogLevel::Info,
@@ -520,8 +608,8 @@ fn run_batch(args: &RunArgs, batch_file: &std::path::Path) -> Result<()> {
 
     if summary.failed > 0 || summary.errors > 0 {
         return Err(DebuggerError::ExecutionError(format!(
-            "Batch execution completed with failures: {} failed, {} errors",
-            summary.failed, summary.errors
+            "Batch execution completed with failures: {} failed, {} errors, {} skipped",
+            summary.failed, summary.errors, summary.skipped
         ))
         .into());
     }
@@ -529,9 +617,210 @@ fn run_batch(args: &RunArgs, batch_file: &std::path::Path) -> Result<()> {
     Ok(())
 }
 
+/// Execute `function` for `run --json-stream`, printing each record as its
+/// own line, then a final line with the result and budget.
+///
+/// With `--step-instructions` every instruction stepped is streamed before the
+/// call runs and breakpoint hits as they happen. Storage changes reach the
+/// stream only once the call returns, followed by the contract events for
+/// `--show-events`.
+fn run_json_stream(
+    mut engine: DebuggerEngine,
+    run: &RunArgs,
+    contract: &std::path::Path,
+    wasm_bytes: &[u8],
+    redactor: &crate::inspector::storage::StorageRedactor,
+    function: &str,
+    args: Option<&str>,
+) -> Result<()> {
+    use crate::inspector::budget::BudgetInspector;
+    use crate::output::{OutputStatus, StreamRecord};
+
+    let step_mode = run
+        .step_instructions
+        .then(|| parse_step_mode(&run.step_mode));
+    if let Some(mode) = step_mode {
+        engine.enable_instruction_debug(wasm_bytes)?;
+        engine.start_instruction_stepping(mode)?;
+    }
+    let events = engine.event_stream();
+    // The stream ends when the engine is dropped, which lets the printer
+    // finish before the result line is written.
+    let printer = std::thread::spawn(move || {
+        for event in events.filter(StreamRecord::streams) {
+            StreamRecord::Event { event }.emit()?;
+        }
+        Ok::<_, miette::Report>(())
+    });
+
+    let started = std::time::Instant::now();
+    let outcome = match step_mode {
+        Some(mode) => stream_instruction_steps(&mut engine, mode),
+        None => Ok(()),
+    }
+    .and_then(|()| engine.execute(function, args));
+    let wall_time_ms =
+        started.elapsed().as_millis() as u64 + engine.executor().last_mock_latency_ms();
+    let budget = BudgetInspector::get_cpu_usage(engine.executor().host());
+    let show_events = run.show_events || !run.event_filter.is_empty() || run.filter_topic.is_some();
+    let contract_events = if show_events {
+        let mut events = engine.executor().get_events().unwrap_or_default();
+        redactor.redact_events(&mut events);
+        let filter = if !run.event_filter.is_empty() {
+            Some(run.event_filter.join(","))
+        } else {
+            run.filter_topic.clone()
+        };
+        match filter {
+            Some(filter) => EventInspector::filter_events(&events, &filter),
+            None => events,
+        }
+    } else {
+        Vec::new()
+    };
+    let storage = run
+        .export_storage
+        .as_ref()
+        .map(|_| engine.executor().get_storage_snapshot());
+    drop(engine);
+    printer
+        .join()
+        .map_err(|_| DebuggerError::IoError("JSON stream printer panicked".to_string()))??;
+
+    for event in contract_events {
+        StreamRecord::ContractEvent { event }.emit()?;
+    }
+    if let (Some(path), Some(storage)) = (&run.export_storage, storage) {
+        crate::inspector::storage::StorageState::export_to_file(&storage?, path)?;
+    }
+    if let Ok(manager) = HistoryManager::new() {
+        let _ = manager.append_record(RunHistory {
+            date: chrono::Utc::now().to_rfc3339(),
+            contract_hash: contract.to_string_lossy().to_string(),
+            function: function.to_string(),
+            cpu_used: budget.cpu_instructions,
+            memory_used: budget.memory_bytes,
+            commit: crate::history::current_git_commit(),
+            wall_time_ms,
+        });
+    }
+
+    let (status, result, error) = match &outcome {
+        Ok(result) => (OutputStatus::Success, Some(result.clone()), None),
+        Err(err) => (OutputStatus::Error, None, Some(err.to_string())),
+    };
+    StreamRecord::Result {
+        function: function.to_string(),
+        status,
+        result,
+        error,
+        budget,
+    }
+    .emit()?;
+    outcome.map(|_| ())
+}
+
+/// Step through every instruction in `mode` for `--json-stream
+/// --step-instructions`; each step reaches the stream as a
+/// `BeforeInstruction` event. Stops at the end or at `--max-steps`.
+fn stream_instruction_steps(engine: &mut DebuggerEngine, mode: StepMode) -> Result<()> {
+    loop {
+        let stepped = match mode {
+            StepMode::StepInto => engine.step_into()?,
+            StepMode::StepOver => engine.step_over()?,
+            StepMode::StepOut => engine.step_out()?,
+            StepMode::StepBlock => engine.step_block()?,
+        };
+        if !stepped {
+            return engine.continue_execution();
+        }
+    }
+}
+
+/// Rebuild a call from event `index` of an event log for `--args-from-events`.
+fn replay_call_from_events(
+    path: &std::path::Path,
+    index: usize,
+    wasm_bytes: &[u8],
+) -> Result<crate::inspector::event_replay::ReplayCall> {
+    let events = crate::inspector::event_replay::RecordedEvent::load_all(path)?;
+    let event = events.get(index).ok_or_else(|| {
+        DebuggerError::InvalidArguments(format!(
+            "--event-index {} is out of range: {:?} has {} event(s)",
+            index,
+            path,
+            events.len()
+        ))
+    })?;
+    let signatures = crate::utils::wasm::parse_function_signatures(wasm_bytes)?;
+    let call = event.reconstruct_call(&signatures)?;
+    print_info(format!(
+        "Replaying event #{} as {}({})",
+        index, call.function, call.args_json
+    ));
+    Ok(call)
+}
+
+/// Retry, timeout and cache settings for loading --network-snapshot from a URL.
+/// `--snapshot-retries` counts retries, so one more attempt is made in total.
+fn snapshot_fetch_config(args: &RunArgs) -> crate::simulator::UrlFetchConfig {
+    let mut config = crate::simulator::UrlFetchConfig {
+        attempts: args.snapshot_retries.saturating_add(1),
+        timeout: std::time::Duration::from_secs(args.snapshot_timeout),
+        ..Default::default()
+    };
+    if args.no_snapshot_cache {
+        config.cache_dir = None;
+    }
+    config
+}
+
+/// Fetch settings for `--rpc-url`, caching under the temp directory for
+/// `--rpc-cache-ttl` seconds
+fn rpc_fetch_config(args: &RunArgs) -> crate::simulator::UrlFetchConfig {
+    let mut config = snapshot_fetch_config(args);
+    if config.cache_dir.is_some() {
+        config.cache_dir = Some(crate::simulator::UrlFetchConfig::rpc_cache_dir());
+    }
+    config.cache_ttl = Some(std::time::Duration::from_secs(args.rpc_cache_ttl));
+    config
+}
+
 /// Execute the run command.
 #[tracing::instrument(skip_all, fields(contract = ?args.contract, function = args.function))]
-pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
+pub fn run(mut args: RunArgs, verbosity: Verbosity) -> Result<()> {
+    if args.canonical_json && !args.is_json_output() {
+        return Err(DebuggerError::InvalidArguments(
+            "--canonical-json requires JSON output (--json, --output json or --format json)"
+                .to_string(),
+        )
+        .into());
+    }
+
+    if args.watch {
+        return run_watch(args, verbosity);
+    }
+
+    if args.json_stream {
+        // Stdout carries only the JSON lines; logs still go to stderr.
+        Formatter::set_verbosity(0);
+    }
+
+    if let Some(path) = &args.args_file {
+        args.args = Some(read_args_file(path)?);
+    }
+
+    // Resolve `${name}` placeholders before any argument or storage JSON is parsed.
+    let vars = crate::utils::template::parse_var_assignments(&args.vars)?;
+    args.args = args
+        .args
+        .map(|json| crate::utils::template::substitute(&json, &vars))
+        .transpose()?;
+    args.storage = args
+        .storage
+        .map(|json| crate::utils::template::substitute(&json, &vars))
+        .transpose()?;
+
     // Start debug server if requested
     if args.server {
         return server(ServerArgs {
@@ -540,6 +829,7 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
             token: args.token,
             tls_cert: args.tls_cert,
             tls_key: args.tls_key,
+            ws: false,
             repeat: args.repeat,
             storage_filter: args.storage_filter,
             show_events: args.show_events,
@@ -574,6 +864,10 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         );
     }
 
+    let network_limits = crate::config::Config::load_or_default()
+        .budget_limits
+        .resolve(args.network_limits)?;
+
     // Initialize output writer
     let mut output_writer = OutputWriter::new(args.save_output.as_deref(), args.append)?;
 
@@ -590,10 +884,6 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         .contract
         .as_ref()
         .expect("contract is required for run");
-    let function = args
-        .function
-        .as_ref()
-        .expect("function is required for run");
 
     print_info(format!("Loading contract: {:?}", contract));
     output_writer.write(&format!("Loading contract: {:?}", contract))?;
@@ -614,6 +904,21 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         }
     }
 
+    if args.smoke_all {
+        return run_smoke_all(&args, wasm_bytes);
+    }
+
+    let replay_call = args
+        .args_from_events
+        .as_ref()
+        .map(|path| replay_call_from_events(path, args.event_index, &wasm_bytes))
+        .transpose()?;
+    let function = replay_call
+        .as_ref()
+        .map(|call| &call.function)
+        .or(args.function.as_ref())
+        .expect("function is required for run");
+
     print_success(format!(
         "Contract loaded successfully ({} bytes)",
         wasm_bytes.len()
@@ -622,6 +927,14 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         "Contract loaded successfully ({} bytes)",
         wasm_bytes.len()
     ))?;
+    if args.json_stream {
+        crate::output::StreamRecord::ContractLoaded {
+            path: contract.to_string_lossy().to_string(),
+            size: wasm_bytes.len(),
+            sha256: wasm_hash.clone(),
+        }
+        .emit()?;
+    }
 
     if args.verbose || verbosity == Verbosity::Verbose {
         print_verbose(format!("SHA-256: {}", wasm_hash));
@@ -634,21 +947,60 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
 
     logging::log_contract_loaded(wasm_bytes.len());
 
+    let mut network_passphrase = crate::runtime::executor::DEFAULT_NETWORK_PASSPHRASE.to_string();
+    let mut network_state = None;
     if let Some(snapshot_path) = &args.network_snapshot {
         print_info(format!("\nLoading network snapshot: {:?}", snapshot_path));
         output_writer.write(&format!("Loading network snapshot: {:?}", snapshot_path))?;
         logging::log_loading_snapshot(&snapshot_path.to_string_lossy());
-        let loader = SnapshotLoader::from_file(snapshot_path)?;
+        let loader = SnapshotLoader::load(snapshot_path, &snapshot_fetch_config(&args))?;
+        network_passphrase = loader.snapshot().ledger.network_passphrase.clone();
+        let loaded_snapshot = loader.apply_to_environment()?;
+        output_writer.write(&loaded_snapshot.format_summary())?;
+        logging::log_display(loaded_snapshot.format_summary(), logging::LogLevel::Info);
+        network_state = Some(loaded_snapshot);
+    }
+    if let (Some(rpc_url), Some(contract_id)) = (&args.rpc_url, &args.contract_id) {
+        print_info(format!(
+            "\nFetching ledger state for {} from {}",
+            contract_id, rpc_url
+        ));
+        output_writer.write(&format!(
+            "Fetching ledger state for {} from {}",
+            contract_id, rpc_url
+        ))?;
+        let storage_keys = args
+            .rpc_key
+            .iter()
+            .map(|key| crate::simulator::rpc::parse_storage_key(key))
+            .collect::<Result<Vec<_>>>()?;
+        let loader = SnapshotLoader::from_rpc_with_config(
+            rpc_url,
+            contract_id,
+            &storage_keys,
+            &rpc_fetch_config(&args),
+        )?;
+        network_passphrase = loader.snapshot().ledger.network_passphrase.clone();
         let loaded_snapshot = loader.apply_to_environment()?;
         output_writer.write(&loaded_snapshot.format_summary())?;
         logging::log_display(loaded_snapshot.format_summary(), logging::LogLevel::Info);
+        network_state = Some(loaded_snapshot);
     }
 
-    let parsed_args = if let Some(args_json) = &args.args {
+    let parsed_args = if let Some(call) = &replay_call {
+        Some(call.args_json.clone())
+    } else if let Some(args_json) = &args.args {
         Some(parse_args(args_json)?)
     } else {
         None
     };
+    if !args.no_arg_check {
+        crate::runtime::parser::check_args_against_spec(
+            &wasm_bytes,
+            function,
+            parsed_args.as_deref(),
+        )?;
+    }
 
     let mut initial_storage = if let Some(storage_json) = &args.storage {
         Some(parse_storage(storage_json)?)
@@ -666,11 +1018,38 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         })?);
     }
 
+    let contract_storage = args
+        .contract_storage
+        .iter()
+        .map(|spec| parse_contract_storage(spec))
+        .collect::<Result<Vec<_>>>()?;
+
     if let Some(n) = args.repeat {
         logging::log_repeat_execution(function, n as usize);
-        let runner = RepeatRunner::new(wasm_bytes, args.breakpoint, initial_storage);
+        // Validate `when` clauses up front, as a single run does.
+        let mut breakpoints = BreakpointManager::new();
+        for spec in &args.breakpoint {
+            let (function, _) = BreakpointManager::parse_breakpoint(spec);
+            let params = crate::utils::wasm::function_param_names(&wasm_bytes, &function)
+                .unwrap_or_default();
+            breakpoints.add_with_params(spec, &params)?;
+        }
+        let runner = RepeatRunner::new(wasm_bytes, breakpoints.specs(), initial_storage)
+            .with_contract_storage(contract_storage);
         let stats = runner.run(function, parsed_args.as_deref(), n)?;
         stats.display();
+        if let Some(path) = &args.repeat_json {
+            let json = serde_json::to_string_pretty(&stats.to_samples_json()).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize repeat samples: {}", e))
+            })?;
+            fs::write(path, json).map_err(|e| {
+                DebuggerError::FileError(format!(
+                    "Failed to write repeat samples {:?}: {}",
+                    path, e
+                ))
+            })?;
+            print_success(format!("Repeat samples written to {:?}", path));
+        }
         return Ok(());
     }
 
@@ -684,17 +1063,83 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
     }
     logging::log_execution_start(function, parsed_args.as_deref());
 
+    let redactor = crate::inspector::storage::StorageRedactor::new(&args.redact)
+        .map_err(|e| DebuggerError::StorageError(format!("Invalid --redact pattern: {}", e)))?;
+
+    let mut run_meta = crate::output::RunMetadata::new(wasm_hash.clone())
+        .with_inputs(
+            Some(function),
+            args.args
+                .as_deref()
+                .or(replay_call.as_ref().map(|call| call.args_json.as_str())),
+            initial_storage.as_deref(),
+        )
+        .with_network_snapshot(args.network_snapshot.as_deref());
+    redactor.redact_run_metadata(&mut run_meta);
+
     let mut executor = ContractExecutor::new(wasm_bytes.clone())?;
     executor.set_timeout(args.timeout);
-
+    executor.set_network_passphrase(network_passphrase);
+    if let Some(limit) = args.max_call_depth {
+        executor.set_max_call_depth(limit)?;
+    }
+
+    // Applied before `--storage` so explicitly seeded entries take precedence.
+    if let Some(loaded_snapshot) = &network_state {
+        executor.apply_snapshot_ledger(loaded_snapshot)?;
+        let applied = executor.apply_snapshot_storage(loaded_snapshot)?;
+        print_verbose(format!(
+            "Applied {} storage entries from the network snapshot",
+            applied
+        ));
+    }
     if let Some(storage) = initial_storage {
         executor.set_initial_storage(storage)?;
     }
     if !args.mock.is_empty() {
         executor.set_mock_specs(&args.mock)?;
     }
+    if !args.deny_auth.is_empty() {
+        executor.deny_auth(&args.deny_auth)?;
+    }
+    // Followed contracts are registered after mocks so a local WASM takes
+    // precedence over a mock declared for the same contract id.
+    if let Some(dir) = &args.follow_calls {
+        let followed = executor.follow_calls_from_dir(dir)?;
+        print_info(format!(
+            "Following cross-contract calls into {} local contract(s) from {:?}",
+            followed.len(),
+            dir
+        ));
+        for contract_id in &followed {
+            print_verbose(format!("  {}", contract_id));
+        }
+    }
+    // Seeded after followed contracts are registered so instance storage has a
+    // contract instance to live in.
+    for (contract_id, storage) in contract_storage {
+        executor.set_contract_storage(&contract_id, storage)?;
+        print_verbose(format!("Seeded storage for contract {}", contract_id));
+    }
+
+    let mut engine = DebuggerEngine::new(executor, Vec::new());
+    engine.set_storage_history_capacity(args.storage_history);
+    engine.set_max_steps(args.max_steps);
+    for spec in &args.breakpoint {
+        engine.add_breakpoint_spec(spec)?;
+    }
 
-    let mut engine = DebuggerEngine::new(executor, args.breakpoint.clone());
+    if args.json_stream {
+        return run_json_stream(
+            engine,
+            &args,
+            contract,
+            &wasm_bytes,
+            &redactor,
+            function,
+            parsed_args.as_deref(),
+        );
+    }
 
     if args.instruction_debug {
         print_info("Enabling instruction-level debugging...");
@@ -712,11 +1157,68 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         }
     }
 
+    let chain_expectation = args
+        .compare_with_chain
+        .as_ref()
+        .map(crate::compare::ChainExpectation::from_file)
+        .transpose()?;
+
     print_info("\n--- Execution Start ---\n");
     output_writer.write("\n--- Execution Start ---\n")?;
-    let storage_before = engine.executor().get_storage_snapshot()?;
-    let result = engine.execute(function, parsed_args.as_deref())?;
-    let storage_after = engine.executor().get_storage_snapshot()?;
+    // Show `#[contracttype]` struct values in storage and the result by field name.
+    let struct_renderer = crate::inspector::storage::StructValueRenderer::from_wasm(&wasm_bytes);
+    let storage_before = struct_renderer.capture_snapshot(engine.executor().host());
+    let live_until_before = args
+        .show_ledger
+        .then(|| crate::inspector::ledger::capture_live_until(engine.executor().host()));
+    let profile_memory = args.profile_memory_timeline || args.memory_timeline_output.is_some();
+    if profile_memory {
+        engine
+            .executor()
+            .checkpoints()
+            .start(engine.executor().host(), format!("Before {}", function));
+    }
+    let event_schema = args
+        .event_schema
+        .as_ref()
+        .map(crate::inspector::event_schema::EventSchema::load)
+        .transpose()?;
+    let started = std::time::Instant::now();
+    let result = match engine.execute(function, parsed_args.as_deref()) {
+        Ok(result) => result,
+        Err(err) if args.interactive_on_error => {
+            print_warning(format!("Execution failed: {}", err));
+            print_info(
+                "Opening interactive debugger at the point of failure (type 'help' for commands)",
+            );
+            let mut ui = DebuggerUI::new(engine)?;
+            ui.attach_failure(function, err.to_string());
+            ui.run()?;
+            return Err(err);
+        }
+        Err(err) => {
+            if !args.deny_auth.is_empty() {
+                display_mock_call_log(&engine.executor().get_mock_call_log());
+            }
+            return Err(err);
+        }
+    };
+    let wall_time_ms =
+        started.elapsed().as_millis() as u64 + engine.executor().last_mock_latency_ms();
+    let memory_timeline = profile_memory.then(|| {
+        let checkpoints = engine
+            .executor()
+            .checkpoints()
+            .finish(engine.executor().host(), format!("After {}", function));
+        crate::profiler::MemoryTimeline::from_checkpoints(function, &checkpoints)
+    });
+    let storage_after = struct_renderer.capture_snapshot(engine.executor().host());
+    let result = engine
+        .executor()
+        .last_execution()
+        .and_then(|record| record.result.as_ref().ok())
+        .and_then(|value| struct_renderer.render(value))
+        .unwrap_or(result);
     print_success("\n--- Execution Complete ---\n");
     output_writer.write("\n--- Execution Complete ---\n")?;
     print_result(format!("Result: {:?}", result));
@@ -727,7 +1229,24 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
     if let Some(test_path) = &args.generate_test {
         if let Some(record) = engine.executor().last_execution() {
             print_info(format!("\nGenerating unit test: {:?}", test_path));
-            let test_code = crate::codegen::TestGenerator::generate(record, contract)?;
+            let test_storage = if args.with_storage.unwrap_or(args.storage.is_some()) {
+                args.storage.as_deref()
+            } else {
+                None
+            };
+            let test_code = if args.proptest {
+                crate::codegen::TestGenerator::generate_proptest_with_storage(
+                    record,
+                    contract,
+                    test_storage,
+                )?
+            } else {
+                crate::codegen::TestGenerator::generate_with_storage(
+                    record,
+                    contract,
+                    test_storage,
+                )?
+            };
             crate::codegen::TestGenerator::write_to_file(test_path, &test_code, args.overwrite)?;
             print_success(format!(
                 "Unit test generated successfully at {:?}",
@@ -738,24 +1257,66 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         }
     }
 
-    let storage_diff = crate::inspector::storage::StorageInspector::compute_diff(
-        &storage_before,
-        &storage_after,
+    // Decode `#[contracttype]` enum discriminants for display using the contract spec.
+    let enum_renderer = crate::inspector::storage::EnumValueRenderer::from_wasm(&wasm_bytes);
+    let mut storage_diff = crate::inspector::storage::StorageInspector::compute_diff(
+        &enum_renderer.render_snapshot(&storage_before),
+        &enum_renderer.render_snapshot(&storage_after),
         &args.alert_on_change,
     );
+    redactor.redact_diff(&mut storage_diff);
+    storage_diff.retain_durability(&args.diff_durability);
     if !storage_diff.is_empty() || !args.alert_on_change.is_empty() {
         print_info("\n--- Storage Changes ---");
         crate::inspector::storage::StorageInspector::display_diff(&storage_diff);
     }
 
     let mock_calls = engine.executor().get_mock_call_log();
-    if !args.mock.is_empty() {
+    if !args.mock.is_empty() || !args.deny_auth.is_empty() {
         display_mock_call_log(&mock_calls);
     }
 
+    let chain_comparison = chain_expectation.as_ref().and_then(|expected| {
+        engine.executor().last_execution().map(|record| {
+            crate::compare::ChainComparison::compare(
+                expected,
+                &record.result,
+                &record.budget,
+                args.chain_budget_tolerance,
+            )
+        })
+    });
+    if let Some(report) = &chain_comparison {
+        print_info("\n--- Chain Comparison ---");
+        print_info(report.render().trim_end());
+    }
+
+    if let Some(timeline) = &memory_timeline {
+        print_info("\n--- Memory Timeline ---");
+        print_info(timeline.render().trim_end());
+        if let Some(path) = &args.memory_timeline_output {
+            let json = serde_json::to_string_pretty(timeline).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize memory timeline: {}", e))
+            })?;
+            fs::write(path, json).map_err(|e| {
+                DebuggerError::FileError(format!(
+                    "Failed to write memory timeline {:?}: {}",
+                    path, e
+                ))
+            })?;
+            print_success(format!("Memory timeline written to {:?}", path));
+        }
+    }
+
     // Save budget info to history
     let host = engine.executor().host();
-    let budget = crate::inspector::budget::BudgetInspector::get_cpu_usage(host);
+    let budget =
+        crate::inspector::budget::BudgetInspector::get_cpu_usage(host).with_limits(network_limits);
+    if !args.is_json_output() {
+        for message in budget.exceeded_limits() {
+            print_warning(message);
+        }
+    }
     if let Ok(manager) = HistoryManager::new() {
         let record = RunHistory {
             date: chrono::Utc::now().to_rfc3339(),
@@ -763,6 +1324,8 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
             function: function.clone(),
             cpu_used: budget.cpu_instructions,
             memory_used: budget.memory_bytes,
+            commit: crate::history::current_git_commit(),
+            wall_time_ms,
         };
         let _ = manager.append_record(record);
     }
@@ -779,8 +1342,25 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         ));
     }
 
+    if let Some(record_path) = &args.record_snapshot {
+        let snapshot =
+            crate::simulator::SnapshotManager::save_current(engine.executor(), record_path)?;
+        print_success(format!(
+            "Recorded network snapshot ({} accounts, {} contracts) to {:?}",
+            snapshot.accounts.len(),
+            snapshot.contracts.len(),
+            record_path
+        ));
+    }
+
+    let cloudevents = args.events_format == EventsFormat::CloudEvents;
+    let cloudevents_run_id = uuid::Uuid::new_v4().to_string();
     let mut json_events = None;
-    if args.show_events || !args.event_filter.is_empty() || args.filter_topic.is_some() {
+    if args.show_events
+        || !args.event_filter.is_empty()
+        || args.filter_topic.is_some()
+        || cloudevents
+    {
         print_info("\n--- Events ---");
 
         // Attempt to read raw events from executor
@@ -788,7 +1368,7 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
 
         // Convert runtime event objects into our inspector::events::ContractEvent via serde translation.
         // This is a generic, safe conversion as long as runtime events are serializable with sensible fields.
-        let converted_events: Vec<ContractEvent> =
+        let mut converted_events: Vec<ContractEvent> =
             match serde_json::to_value(&raw_events).and_then(serde_json::from_value) {
                 Ok(evts) => evts,
                 Err(e) => {
@@ -804,11 +1384,13 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
                             contract_id: None,
                             topics: vec![],
                             data: format!("{:?}", r),
+                            decoded: None,
                         })
                         .collect();
                     fallback
                 }
             };
+        redactor.redact_events(&mut converted_events);
 
         // Determine filter: prefer repeatable --event-filter, fallback to legacy --filter-topic
         let filter_opt = if !args.event_filter.is_empty() {
@@ -825,6 +1407,12 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
 
         if filtered_events.is_empty() {
             print_warning("No events captured.");
+        } else if cloudevents {
+            if !args.is_json_output() {
+                for event in EventInspector::to_cloudevents(&filtered_events, &cloudevents_run_id) {
+                    println!("{}", event);
+                }
+            }
         } else {
             // Display events in readable form
             let lines = EventInspector::format_events(&filtered_events);
@@ -836,13 +1424,36 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         json_events = Some(filtered_events);
     }
 
+    if let Some(schema) = &event_schema {
+        let events = crate::inspector::event_schema::contract_events(engine.executor().host())?;
+        let violations = schema.validate(&events);
+        if violations.is_empty() {
+            print_success(format!(
+                "All {} event(s) match the event schema",
+                events.len()
+            ));
+        } else {
+            print_warning("\n--- Event Schema Violations ---");
+            for v in &violations {
+                print_warning(format!("  Event #{} ({}): {}", v.index, v.topic, v.message));
+            }
+            return Err(DebuggerError::ExecutionError(format!(
+                "{} of {} event(s) do not match the event schema",
+                violations.len(),
+                events.len()
+            ))
+            .into());
+        }
+    }
+
     if !args.storage_filter.is_empty() {
         let storage_filter = crate::inspector::storage::StorageFilter::new(&args.storage_filter)
             .map_err(|e| DebuggerError::StorageError(format!("Invalid storage filter: {}", e)))?;
 
         print_info("\n--- Storage ---");
-        let inspector =
-            crate::inspector::storage::StorageInspector::with_state(storage_after.clone());
+        let inspector = crate::inspector::storage::StorageInspector::with_state(
+            redactor.redact_snapshot(&enum_renderer.render_snapshot(&storage_after)),
+        );
         inspector.display_filtered(&storage_filter);
     }
 
@@ -861,11 +1472,22 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         json_auth = Some(auth_tree);
     }
 
+    if args.show_budget {
+        let breakdown =
+            crate::inspector::budget::BudgetInspector::get_cost_breakdown(engine.executor().host());
+        print_info("\n--- Budget Breakdown ---");
+        print_info(
+            crate::inspector::budget::BudgetInspector::format_cost_breakdown(&breakdown).trim_end(),
+        );
+    }
+
     let mut json_ledger = None;
     if args.show_ledger {
         print_info("\n--- Ledger Entries ---");
         let mut ledger_inspector = crate::inspector::ledger::LedgerEntryInspector::new();
         ledger_inspector.set_ttl_warning_threshold(args.ttl_warning_threshold);
+        let current_ledger = engine.executor().env().ledger().sequence();
+        let live_until_before = live_until_before.unwrap_or_default();
 
         match engine.executor_mut().finish() {
             Ok((footprint, storage)) => {
@@ -896,15 +1518,31 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
                             use soroban_env_host::storage::AccessType;
                             let is_read = true; // Everything in the footprint is at least read
                             let is_write = matches!(*access_type, AccessType::ReadWrite);
-
-                            ledger_inspector.add_entry(
-                                format!("{:?}", **key),
-                                format!("{:?}", **entry),
-                                storage_type,
-                                ttl.unwrap_or(0),
-                                is_read,
-                                is_write,
-                            );
+                            let value =
+                                redactor.redact_ledger_value(&**key, format!("{:?}", **entry));
+
+                            match ttl {
+                                Some(live_until_ledger) => ledger_inspector.add_entry_with_ttl(
+                                    key_str.clone(),
+                                    value,
+                                    storage_type,
+                                    crate::inspector::ledger::EntryTtl {
+                                        live_until_ledger: *live_until_ledger,
+                                        current_ledger,
+                                        live_until_before: live_until_before.get(&key_str).copied(),
+                                    },
+                                    is_read,
+                                    is_write,
+                                ),
+                                None => ledger_inspector.add_entry(
+                                    key_str,
+                                    value,
+                                    storage_type,
+                                    0,
+                                    is_read,
+                                    is_write,
+                                ),
+                            }
                         }
                     }
                 }
@@ -926,12 +1564,21 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
             "budget": {
                 "cpu_instructions": budget.cpu_instructions,
                 "memory_bytes": budget.memory_bytes,
+                "cpu_limit": budget.cpu_limit,
+                "memory_limit": budget.memory_limit,
             },
             "storage_diff": storage_diff,
         });
 
         if let Some(ref events) = json_events {
-            result_obj["events"] = EventInspector::to_json_value(events);
+            result_obj["events"] = if cloudevents {
+                serde_json::Value::Array(EventInspector::to_cloudevents(
+                    events,
+                    &cloudevents_run_id,
+                ))
+            } else {
+                EventInspector::to_json_value(events)
+            };
         }
         if let Some(auth_tree) = json_auth {
             result_obj["auth"] = crate::inspector::auth::AuthInspector::to_json_value(&auth_tree);
@@ -947,6 +1594,12 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
                             "args_count": entry.args_count,
                             "mocked": entry.mocked,
                             "returned": entry.returned,
+                            "auth_denied": entry.auth_denied,
+                            "charged": {
+                                "cpu_instructions": entry.charged.cpu_instructions,
+                                "memory_bytes": entry.charged.memory_bytes,
+                                "latency_ms": entry.charged.latency_ms,
+                            },
                         })
                     })
                     .collect(),
@@ -955,10 +1608,18 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         if let Some(ref ledger) = json_ledger {
             result_obj["ledger_entries"] = ledger.to_json();
         }
+        if let Some(ref report) = chain_comparison {
+            result_obj["chain_comparison"] =
+                serde_json::to_value(report).unwrap_or(serde_json::Value::Null);
+        }
+        if let Some(ref timeline) = memory_timeline {
+            result_obj["memory_timeline"] =
+                serde_json::to_value(timeline).unwrap_or(serde_json::Value::Null);
+        }
 
-        let output = crate::output::VersionedOutput::success("run", result_obj);
+        let output = crate::output::VersionedOutput::success("run", result_obj).with_meta(run_meta);
 
-        match serde_json::to_string_pretty(&output) {
+        match to_json_string(&output, args.canonical_json) {
             Ok(json) => println!("{}", json),
             Err(e) => {
                 let err_output = crate::output::VersionedOutput::<serde_json::Value>::error(
@@ -1047,7 +1708,10 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         };
 
         let mut pauses = Vec::new();
-        let hit_entry_breakpoint = args.breakpoint.iter().any(|bp| bp == function);
+        let hit_entry_breakpoint = args
+            .breakpoint
+            .iter()
+            .any(|bp| BreakpointManager::parse_breakpoint(bp).0 == *function);
         if engine.is_paused() && hit_entry_breakpoint {
             pauses.push(TimelinePausePoint {
                 index: 0,
@@ -1064,7 +1728,10 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
                 contract_path: contract.to_string_lossy().to_string(),
                 wasm_sha256: Some(wasm_hash.clone()),
                 function: function.to_string(),
-                args_json: args.args.clone(),
+                args_json: args
+                    .args
+                    .clone()
+                    .or_else(|| replay_call.as_ref().map(|call| call.args_json.clone())),
                 result: Some(result.clone()),
                 error: None,
                 budget: Some(budget.clone()),
@@ -1091,6 +1758,113 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         }
     }
 
+    if chain_comparison.is_some_and(|report| !report.matches) {
+        return Err(DebuggerError::ExecutionError(
+            "Local simulation does not reproduce the on-chain execution".to_string(),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// How long the contract file must stay unchanged before a watched run
+/// starts, so a compiler writing the WASM in several steps triggers one run.
+const WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+enum WatchSignal {
+    Changed,
+    Interrupted,
+}
+
+/// Run once, then re-run the same invocation each time the contract WASM
+/// changes until Ctrl+C. A failed run is reported and watching continues.
+fn run_watch(mut args: RunArgs, verbosity: Verbosity) -> Result<()> {
+    use notify::{EventKind, RecursiveMode, Watcher};
+    use std::sync::mpsc;
+
+    args.watch = false;
+    let contract = args.contract.clone().ok_or_else(|| {
+        DebuggerError::InvalidArguments("--watch requires --contract".to_string())
+    })?;
+    let file_name = contract.file_name().map(|name| name.to_os_string());
+    // Watch the directory rather than the file: build tools often replace the
+    // WASM with a new file, which ends a watch on the old one.
+    let watch_dir = match contract.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let change_tx = tx.clone();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else {
+            return;
+        };
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        if event
+            .paths
+            .iter()
+            .any(|path| path.file_name() == file_name.as_deref())
+        {
+            let _ = change_tx.send(WatchSignal::Changed);
+        }
+    })
+    .map_err(|e| DebuggerError::FileError(format!("Failed to start file watcher: {}", e)))?;
+    watcher
+        .watch(&watch_dir, RecursiveMode::NonRecursive)
+        .map_err(|e| DebuggerError::FileError(format!("Failed to watch {:?}: {}", watch_dir, e)))?;
+
+    std::thread::spawn(move || {
+        let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        else {
+            return;
+        };
+        if runtime.block_on(tokio::signal::ctrl_c()).is_ok() {
+            let _ = tx.send(WatchSignal::Interrupted);
+        }
+    });
+
+    if let Err(e) = run(args.clone(), verbosity) {
+        print_warning(format!("Run failed: {}", e));
+    }
+    print_info(format!(
+        "\nWatching {:?} for changes (Ctrl+C to stop)",
+        contract
+    ));
+
+    let mut run_number = 1;
+    while let Ok(WatchSignal::Changed) = rx.recv() {
+        // Wait for the writes to settle before re-running.
+        loop {
+            match rx.recv_timeout(WATCH_DEBOUNCE) {
+                Ok(WatchSignal::Changed) => continue,
+                Ok(WatchSignal::Interrupted) | Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    print_info("\nStopped watching");
+                    return Ok(());
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => break,
+            }
+        }
+
+        run_number += 1;
+        print_info(format!(
+            "\n{} Run #{}: {:?} changed {}",
+            "=".repeat(10),
+            run_number,
+            contract,
+            "=".repeat(10)
+        ));
+        if let Err(e) = run(args.clone(), verbosity) {
+            print_warning(format!("Run failed: {}", e));
+        }
+    }
+
+    print_info("\nStopped watching");
     Ok(())
 }
 
@@ -1355,6 +2129,39 @@ fn display_instruction_counts(counts: &crate::runtime::executor::InstructionCoun
     }
 }
 
+/// Call every exported function once and print an ok/err/panic table.
+fn run_smoke_all(args: &RunArgs, wasm_bytes: Vec<u8>) -> Result<()> {
+    let storage = args.storage.as_deref().map(parse_storage).transpose()?;
+    let results = crate::smoke::SmokeRunner::new(wasm_bytes, storage, args.timeout).run_all()?;
+
+    if args.is_json_output() {
+        let envelope = crate::output::VersionedOutput::success("run", &results);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize smoke results: {}", e))
+            })?
+        );
+        return Ok(());
+    }
+
+    print_info(format!(
+        "\nSmoke testing {} exported functions",
+        results.len()
+    ));
+    print!("{}", crate::smoke::render_table(&results));
+    let ok = results
+        .iter()
+        .filter(|r| r.status == crate::smoke::SmokeStatus::Ok)
+        .count();
+    print_success(format!(
+        "{}/{} functions ran without error",
+        ok,
+        results.len()
+    ));
+    Ok(())
+}
+
 /// Execute the upgrade-check command
 pub fn upgrade_check(args: UpgradeCheckArgs) -> Result<()> {
     print_info(format!("Loading old contract: {:?}", args.old));
@@ -1395,12 +2202,242 @@ pub fn upgrade_check(args: UpgradeCheckArgs) -> Result<()> {
         println!("{}", output);
     }
 
-    if !report.is_compatible {
-        return Err(miette::miette!(
-            "Contracts are not compatible: {} breaking change(s) detected",
-            report.breaking_changes.len()
-        ));
-    }
+    if report.classification == UpgradeClass::Breaking {
+        return Err(DebuggerError::BreakingChanges(format!(
+            "{} breaking change(s) and {} execution mismatch(es) detected",
+            report.breaking_changes.len(),
+            report.summary.execution_mismatches
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Execute the migrate-check command: run the new contract's migration over the old storage.
+pub fn migrate_check(args: MigrateCheckArgs) -> Result<()> {
+    let storage_json = match args.old_storage.strip_prefix('@') {
+        Some(path) => {
+            print_info(format!("Importing old storage from: {}", path));
+            let imported = crate::inspector::storage::StorageState::import_from_file(path)?;
+            serde_json::to_string(&imported).map_err(|e| {
+                DebuggerError::StorageError(format!("Failed to serialize imported storage: {}", e))
+            })?
+        }
+        None => parse_storage(&args.old_storage)?,
+    };
+    let parsed_args = args.args.as_deref().map(parse_args).transpose()?;
+
+    print_info(format!("Loading new contract: {:?}", args.new_contract));
+    let wasm_file = crate::utils::wasm::load_wasm(&args.new_contract)
+        .with_context(|| format!("Failed to read WASM file: {:?}", args.new_contract))?;
+
+    let mut executor = ContractExecutor::new(wasm_file.bytes)?;
+    executor.enable_mock_all_auths();
+    executor.set_timeout(args.timeout);
+    executor
+        .set_initial_storage(storage_json)
+        .context("New contract could not be seeded with the old storage")?;
+
+    let storage_before = executor.get_storage_snapshot()?;
+    print_info(format!(
+        "Running migration '{}' over {} seeded entries",
+        args.function,
+        storage_before.len()
+    ));
+    let outcome = executor.execute(&args.function, parsed_args.as_deref());
+    let storage_after = executor.get_storage_snapshot()?;
+
+    let report = MigrationReport {
+        function: args.function.clone(),
+        seeded_entries: storage_before.len(),
+        success: outcome.is_ok(),
+        result: outcome.as_ref().ok().cloned(),
+        error: outcome.as_ref().err().map(|e| e.to_string()),
+        storage_diff: crate::inspector::storage::StorageInspector::compute_diff(
+            &storage_before,
+            &storage_after,
+            &[],
+        ),
+    };
+
+    match args.format {
+        OutputFormat::Json => {
+            let envelope = crate::output::VersionedOutput::success("migrate-check", &report);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&envelope).map_err(|e| {
+                    DebuggerError::FileError(format!(
+                        "Failed to serialize migrate-check JSON output: {}",
+                        e
+                    ))
+                })?
+            );
+        }
+        OutputFormat::Pretty => {
+            match (&report.result, &report.error) {
+                (Some(result), _) => print_success(format!("Migration succeeded: {}", result)),
+                (None, Some(error)) => print_warning(format!("Migration failed: {}", error)),
+                (None, None) => {}
+            }
+            crate::inspector::storage::StorageInspector::display_diff(&report.storage_diff);
+        }
+    }
+
+    if !report.success {
+        return Err(DebuggerError::ExecutionError(format!(
+            "Migration '{}' failed on the old storage layout",
+            args.function
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Execute the diff-spec command.
+pub fn diff_spec(args: DiffSpecArgs) -> Result<()> {
+    let old_wasm = crate::utils::wasm::load_wasm(&args.old)
+        .with_context(|| format!("Failed to read old WASM file: {:?}", args.old))?;
+    let new_wasm = crate::utils::wasm::load_wasm(&args.new)
+        .with_context(|| format!("Failed to read new WASM file: {:?}", args.new))?;
+
+    let report = crate::analyzer::spec_diff::SpecDiffer::diff_wasm(
+        &old_wasm.bytes,
+        &new_wasm.bytes,
+        &args.old.to_string_lossy(),
+        &args.new.to_string_lossy(),
+    )?;
+
+    match args.format {
+        OutputFormat::Json => {
+            let envelope = crate::output::VersionedOutput::success("diff-spec", &report);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&envelope).map_err(|e| {
+                    DebuggerError::FileError(format!(
+                        "Failed to serialize diff-spec JSON output: {}",
+                        e
+                    ))
+                })?
+            );
+        }
+        OutputFormat::Pretty => print!("{}", report.render()),
+    }
+
+    if args.fail_on_breaking && report.breaking {
+        return Err(DebuggerError::ExecutionError(format!(
+            "{} breaking interface change(s) detected",
+            report.changes.iter().filter(|c| c.is_breaking()).count()
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Execute the gate command: run once and fail when the budget exceeds hard limits.
+pub fn gate(args: GateArgs) -> Result<()> {
+    let wasm_file = crate::utils::wasm::load_wasm(&args.contract)
+        .with_context(|| format!("Failed to read WASM file: {:?}", args.contract))?;
+
+    let parsed_args = if let Some(args_json) = &args.args {
+        Some(parse_args(args_json)?)
+    } else {
+        None
+    };
+
+    let mut executor = ContractExecutor::new(wasm_file.bytes)?;
+    // Auth is mocked so calls guarded by require_auth are measured, not rejected.
+    executor.enable_mock_all_auths();
+    if let Some(storage_json) = &args.storage {
+        executor.set_initial_storage(parse_storage(storage_json)?)?;
+    }
+    executor.execute(&args.function, parsed_args.as_deref())?;
+
+    let budget = crate::inspector::budget::BudgetInspector::get_cpu_usage(executor.host());
+    let report = crate::inspector::budget::BudgetGate {
+        max_cpu: args.max_cpu,
+        max_mem: args.max_mem,
+    }
+    .evaluate(&budget);
+
+    match args.format {
+        OutputFormat::Json => {
+            let envelope = crate::output::VersionedOutput::success("gate", &report);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&envelope).map_err(|e| {
+                    DebuggerError::FileError(format!("Failed to serialize gate JSON output: {}", e))
+                })?
+            );
+        }
+        OutputFormat::Pretty => println!("{} {}", args.function, report.render()),
+    }
+
+    if !report.passed {
+        return Err(DebuggerError::ExecutionError(format!(
+            "Budget gate failed for '{}': {}",
+            args.function,
+            report.violations.join("; ")
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Execute the bisect command: find the smallest value in a range that makes the call fail.
+pub fn bisect(args: BisectArgs) -> Result<()> {
+    use crate::analyzer::bisect::{BisectTarget, FailureCondition, ParamRange};
+
+    let wasm_file = crate::utils::wasm::load_wasm(&args.contract)
+        .with_context(|| format!("Failed to read WASM file: {:?}", args.contract))?;
+    let range = ParamRange::parse(&args.param_range)?;
+    let storage = args.storage.as_deref().map(parse_storage).transpose()?;
+    let condition = match args.fail_on {
+        BisectFailOn::Any => FailureCondition::Any,
+        BisectFailOn::Budget => FailureCondition::Budget,
+        BisectFailOn::Error => FailureCondition::Error,
+    };
+
+    print_info(format!(
+        "Bisecting '{}' over {}..{}",
+        args.function, range.start, range.end
+    ));
+    let report = BisectTarget {
+        wasm: &wasm_file.bytes,
+        function: &args.function,
+        args_template: &args.args,
+        storage: storage.as_deref(),
+        timeout_secs: args.timeout,
+    }
+    .run(range, condition)?;
+
+    match args.format {
+        OutputFormat::Json => {
+            let envelope = crate::output::VersionedOutput::success("bisect", &report);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&envelope).map_err(|e| {
+                    DebuggerError::FileError(format!(
+                        "Failed to serialize bisect JSON output: {}",
+                        e
+                    ))
+                })?
+            );
+        }
+        OutputFormat::Pretty => {
+            print!("{}", report.render());
+            match &report.boundary {
+                Some(value) => print_success(format!("Boundary: {}", value)),
+                None => print_warning(format!(
+                    "'{}' did not fail anywhere in {}..{}",
+                    args.function, range.start, range.end
+                )),
+            }
+        }
+    }
 
     Ok(())
 }
@@ -1506,6 +2543,29 @@ fn format_text_report(report: &CompatibilityReport) -> String {
         }
     }
 
+    out.push('\n');
+    out.push_str(&format!(
+        "Size: {} -> {} bytes ({:+}, {:+.1}%)\n",
+        report.size.old_size,
+        report.size.new_size,
+        report.size.delta,
+        report.size.growth_pct()
+    ));
+    for section in &report.size.sections {
+        out.push_str(&format!(
+            "  {:<28} {:>10} -> {:>10} ({:+})\n",
+            section.name, section.old_size, section.new_size, section.delta
+        ));
+    }
+
+    if !report.notes.is_empty() {
+        out.push('\n');
+        out.push_str("Notes:\n");
+        for note in &report.notes {
+            out.push_str(&format!("  {}\n", note));
+        }
+    }
+
     if !report.execution_diffs.is_empty() {
         out.push('\n');
         out.push_str(&format!(
@@ -1550,6 +2610,21 @@ fn format_text_report(report: &CompatibilityReport) -> String {
     out
 }
 
+/// Read the JSON arguments given with `--args-file`. The contents go through
+/// the same [`parse_args`] validation as `--args`, with the path in the error.
+fn read_args_file(path: &std::path::Path) -> Result<String> {
+    let json = fs::read_to_string(path).map_err(|e| {
+        DebuggerError::FileError(format!("Failed to read args file {:?}: {}", path, e))
+    })?;
+    parse_args(json.trim()).map_err(|e| match e.downcast::<DebuggerError>() {
+        Ok(DebuggerError::InvalidArguments(message)) => {
+            DebuggerError::InvalidArguments(format!("{} (args file {:?})", message, path)).into()
+        }
+        Ok(other) => other.into(),
+        Err(e) => e,
+    })
+}
+
 /// Parse JSON arguments with validation.
 pub fn parse_args(json: &str) -> Result<String> {
     let value = serde_json::from_str::<serde_json::Value>(json).map_err(|e| {
@@ -1585,8 +2660,65 @@ pub fn parse_storage(json: &str) -> Result<String> {
     Ok(json.to_string())
 }
 
+/// Parse a `--contract-storage CONTRACT_ID=STORAGE` spec, where `STORAGE` is inline
+/// JSON or `@path/to/file.json`. Returns the contract id and validated storage JSON.
+pub fn parse_contract_storage(spec: &str) -> Result<(String, String)> {
+    let (contract_id, storage) = spec.split_once('=').ok_or_else(|| {
+        DebuggerError::InvalidArguments(format!(
+            "Invalid --contract-storage '{}': expected CONTRACT_ID=@file.json",
+            spec
+        ))
+    })?;
+    let contract_id = contract_id.trim();
+    if contract_id.is_empty() {
+        return Err(DebuggerError::InvalidArguments(format!(
+            "Invalid --contract-storage '{}': missing contract id",
+            spec
+        ))
+        .into());
+    }
+
+    let storage = match storage.strip_prefix('@') {
+        Some(path) => fs::read_to_string(path).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Failed to read storage file {:?} for contract {}: {}",
+                path, contract_id, e
+            ))
+        })?,
+        None => storage.to_string(),
+    };
+
+    Ok((contract_id.to_string(), parse_storage(&storage)?))
+}
+
+/// Parse a `--override-storage KEY=VALUE` spec. `VALUE` is JSON (typically a
+/// typed annotation); anything that is not valid JSON is taken as a string.
+pub fn parse_storage_override(spec: &str) -> Result<(String, serde_json::Value)> {
+    let (key, value) = spec.split_once('=').ok_or_else(|| {
+        DebuggerError::InvalidArguments(format!(
+            "Invalid --override-storage '{}': expected KEY=VALUE",
+            spec
+        ))
+    })?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(DebuggerError::InvalidArguments(format!(
+            "Invalid --override-storage '{}': missing key",
+            spec
+        ))
+        .into());
+    }
+
+    let value = serde_json::from_str(value.trim())
+        .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+    Ok((key.to_string(), value))
+}
+
 /// Execute the optimize command.
-pub fn optimize(args: OptimizeArgs, _verbosity: Verbosity) -> Result<()> {
+pub fn optimize(mut args: OptimizeArgs, _verbosity: Verbosity) -> Result<()> {
+    if let Some(path) = &args.args_file {
+        args.args = Some(read_args_file(path)?);
+    }
     print_info(format!(
         "Analyzing contract for gas optimization: {:?}",
         args.contract
@@ -1664,7 +2796,8 @@ pub fn optimize(args: OptimizeArgs, _verbosity: Verbosity) -> Result<()> {
                 );
                 print_success(format!(
                     "    CPU: {} instructions, Memory: {} bytes",
-                    profile.total_cpu, profile.total_memory
+                    Formatter::format_number(profile.total_cpu),
+                    Formatter::format_number(profile.total_memory)
                 ));
             }
             Err(e) => {
@@ -1680,7 +2813,30 @@ pub fn optimize(args: OptimizeArgs, _verbosity: Verbosity) -> Result<()> {
 
     let contract_path_str = args.contract.to_string_lossy().to_string();
     let report = optimizer.generate_report(&contract_path_str);
-    let markdown = optimizer.generate_markdown_report(&report);
+    let mut markdown = optimizer.generate_markdown_report(&report);
+
+    if let Some(baseline_path) = &args.baseline {
+        let baseline = optimize_baseline_report(
+            baseline_path,
+            &functions_to_analyze,
+            args.args.as_deref(),
+            args.storage.as_deref(),
+        )?;
+        let deltas = report.compare_to_baseline(&baseline);
+        logging::log_display(
+            format!(
+                "\n{}",
+                crate::profiler::analyzer::OptimizationReport::format_baseline_deltas(&deltas)
+            ),
+            logging::LogLevel::Info,
+        );
+        markdown.push_str(
+            &crate::profiler::analyzer::GasOptimizer::generate_markdown_baseline_section(
+                &baseline.contract_path,
+                &deltas,
+            ),
+        );
+    }
 
     if let Some(output_path) = &args.output {
         fs::write(output_path, &markdown).map_err(|e| {
@@ -1701,8 +2857,40 @@ pub fn optimize(args: OptimizeArgs, _verbosity: Verbosity) -> Result<()> {
     Ok(())
 }
 
+/// Profile `functions` on the baseline build for `optimize --baseline`,
+/// with the same arguments and initial storage as the current build.
+fn optimize_baseline_report(
+    baseline_path: &std::path::Path,
+    functions: &[String],
+    args_json: Option<&str>,
+    storage_json: Option<&str>,
+) -> Result<crate::profiler::analyzer::OptimizationReport> {
+    print_info(format!("\nProfiling baseline: {:?}", baseline_path));
+    let wasm_file = crate::utils::wasm::load_wasm(baseline_path)
+        .with_context(|| format!("Failed to read baseline WASM file: {:?}", baseline_path))?;
+
+    let mut executor = ContractExecutor::new(wasm_file.bytes)?;
+    if let Some(storage_json) = storage_json {
+        executor.set_initial_storage(parse_storage(storage_json)?)?;
+    }
+    let mut optimizer = crate::profiler::analyzer::GasOptimizer::new(executor);
+    for function_name in functions {
+        if let Err(e) = optimizer.analyze_function(function_name, args_json) {
+            print_warning(format!(
+                "    Warning: Failed to analyze baseline function {}: {}",
+                function_name, e
+            ));
+        }
+    }
+
+    Ok(optimizer.generate_report(&baseline_path.to_string_lossy()))
+}
+
 /// ✅ Execute the profile command (hotspots + suggestions)
-pub fn profile(args: ProfileArgs) -> Result<()> {
+pub fn profile(mut args: ProfileArgs) -> Result<()> {
+    if let Some(path) = &args.args_file {
+        args.args = Some(read_args_file(path)?);
+    }
     logging::log_display(
         format!("Profiling contract execution: {:?}", args.contract),
         logging::LogLevel::Info,
@@ -1735,6 +2923,16 @@ pub fn profile(args: ProfileArgs) -> Result<()> {
         None
     };
 
+    // Source lines come from --source-map, or from the contract's own DWARF info
+    let source_wasm = match &args.source_map {
+        Some(path) => {
+            crate::utils::wasm::load_wasm(path)
+                .with_context(|| format!("Failed to read source map WASM: {:?}", path))?
+                .bytes
+        }
+        None => wasm_bytes.clone(),
+    };
+
     // Create executor
     let mut executor = ContractExecutor::new(wasm_bytes)?;
 
@@ -1743,9 +2941,18 @@ pub fn profile(args: ProfileArgs) -> Result<()> {
         let storage = parse_storage(storage_json)?;
         executor.set_initial_storage(storage)?;
     }
+    if !args.mock.is_empty() {
+        executor.set_mock_specs(&args.mock)?;
+    }
 
     // Analyze exactly one function (this command focuses on execution hotspots)
     let mut optimizer = crate::profiler::analyzer::GasOptimizer::new(executor);
+    if !optimizer.enable_source_attribution(source_wasm) && args.source_map.is_some() {
+        logging::log_display(
+            "No DWARF line info in --source-map WASM; showing function-level hotspots only",
+            logging::LogLevel::Warn,
+        );
+    }
 
     logging::log_display(
         format!("\nRunning function: {}", args.function),
@@ -1760,6 +2967,14 @@ pub fn profile(args: ProfileArgs) -> Result<()> {
     let contract_path_str = args.contract.to_string_lossy().to_string();
     let report = optimizer.generate_report(&contract_path_str);
 
+    if let Some(path) = &args.flamegraph {
+        crate::profiler::GasOptimizer::write_flamegraph(&report, path)?;
+        logging::log_display(
+            format!("Flamegraph written to: {:?}", path),
+            logging::LogLevel::Info,
+        );
+    }
+
     // Format output based on export_format
     let output_content = match args.export_format {
         crate::cli::args::ProfileExportFormat::FoldedStack => {
@@ -1769,21 +2984,38 @@ pub fn profile(args: ProfileArgs) -> Result<()> {
         crate::cli::args::ProfileExportFormat::Json => {
             // Export as JSON with basic metrics
             let func_names: Vec<String> = report.functions.iter().map(|f| f.name.clone()).collect();
-            serde_json::to_string_pretty(&serde_json::json!({
+            let meta = crate::output::RunMetadata::new(wasm_hash.clone()).with_inputs(
+                Some(&args.function),
+                args.args.as_deref(),
+                args.storage.as_deref(),
+            );
+            let result = serde_json::json!({
                 "contract": contract_path_str,
                 "functions": func_names,
                 "total_cpu": report.total_cpu,
                 "total_memory": report.total_memory,
                 "potential_cpu_savings": report.potential_cpu_savings,
                 "potential_memory_savings": report.potential_memory_savings,
-            }))
+                "source_hotspots": report
+                    .functions
+                    .iter()
+                    .flat_map(|f| &f.source_costs)
+                    .collect::<Vec<_>>(),
+            });
+            to_json_string(
+                &crate::output::VersionedOutput::success("profile", result).with_meta(meta),
+                args.canonical_json,
+            )
             .unwrap_or_else(|_| "{}".to_string())
         }
         crate::cli::args::ProfileExportFormat::Report => {
             // Default markdown report
-            let hotspots = report.format_hotspots();
+            let hotspots = report.format_hotspots(args.top);
             let markdown = optimizer.generate_markdown_report(&report);
             logging::log_display(format!("\n{}", hotspots), logging::LogLevel::Info);
+            if let Some(source_hotspots) = report.format_source_hotspots(args.top) {
+                logging::log_display(format!("\n{}", source_hotspots), logging::LogLevel::Info);
+            }
             markdown
         }
     };
@@ -1841,6 +3073,32 @@ pub fn compare(args: CompareArgs) -> Result<()> {
     Ok(())
 }
 
+/// Execute the snapshot-diff command.
+pub fn snapshot_diff(args: SnapshotDiffArgs) -> Result<()> {
+    let old = crate::simulator::SnapshotManager::load(&args.old)?;
+    let new = crate::simulator::SnapshotManager::load(&args.new)?;
+    let diff = crate::simulator::SnapshotManager::diff_snapshots(&old, &new);
+
+    if args.json {
+        let envelope = crate::output::VersionedOutput::success("snapshot-diff", &diff);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!(
+                    "Failed to serialize snapshot-diff JSON output: {}",
+                    e
+                ))
+            })?
+        );
+    } else {
+        println!("Old: {:?}", args.old);
+        println!("New: {:?}", args.new);
+        print!("{}", diff.format_summary());
+    }
+
+    Ok(())
+}
+
 /// Execute the replay command.
 /// Execute the replay command.
 pub fn replay(args: ReplayArgs, verbosity: Verbosity) -> Result<()> {
@@ -1874,7 +3132,7 @@ pub fn replay(args: ReplayArgs, verbosity: Verbosity) -> Result<()> {
 
     // Extract function and args from trace
     let function = original_trace.function.as_ref().ok_or_else(|| {
-        DebuggerError::ExecutionError("Trace file does not contain function name".to_string())
+        DebuggerError::FileError("Trace file does not contain function name".to_string())
     })?;
 
     let args_str = original_trace.args.as_deref();
@@ -1894,9 +3152,28 @@ pub fn replay(args: ReplayArgs, verbosity: Verbosity) -> Result<()> {
         print_info(format!("Arguments: {}", a));
     }
 
-    // Set up initial storage from trace
-    let initial_storage = if !original_trace.storage.is_empty() {
-        let storage_json = serde_json::to_string(&original_trace.storage).map_err(|e| {
+    // Set up initial storage from trace, with any counterfactual overrides applied
+    let overrides = args
+        .override_storage
+        .iter()
+        .map(|spec| parse_storage_override(spec))
+        .collect::<Result<Vec<_>>>()?;
+    let mut storage = original_trace.storage.clone();
+    for (key, value) in &overrides {
+        match storage.insert(key.clone(), value.clone()) {
+            Some(previous) => print_info(format!(
+                "Overriding storage {}: {} -> {}",
+                key, previous, value
+            )),
+            None => print_warning(format!(
+                "Storage key {} is not in the trace; adding it with value {}",
+                key, value
+            )),
+        }
+    }
+
+    let initial_storage = if !storage.is_empty() {
+        let storage_json = serde_json::to_string(&storage).map_err(|e| {
             DebuggerError::StorageError(format!("Failed to serialize trace storage: {}", e))
         })?;
         Some(storage_json)
@@ -1961,6 +3238,31 @@ pub fn replay(args: ReplayArgs, verbosity: Verbosity) -> Result<()> {
         logging::log_display(rendered, logging::LogLevel::Info);
     }
 
+    if !overrides.is_empty() {
+        print_info(format!(
+            "\n--- Counterfactual Summary ({} override(s)) ---",
+            overrides.len()
+        ));
+        let rv = &report.return_value_diff;
+        if rv.equal {
+            print_info("Result: unchanged");
+        } else {
+            print_warning(format!(
+                "Result: {} -> {}",
+                rv.a.as_ref()
+                    .map_or("(none)".to_string(), |v| v.to_string()),
+                rv.b.as_ref()
+                    .map_or("(none)".to_string(), |v| v.to_string())
+            ));
+        }
+        if let (Some(cpu), Some(mem)) = (
+            report.budget_diff.cpu_delta,
+            report.budget_diff.memory_delta,
+        ) {
+            print_info(format!("Budget: CPU {:+}, Memory {:+} bytes", cpu, mem));
+        }
+    }
+
     if verbosity == Verbosity::Verbose {
         print_verbose("\n--- Call Sequence (Original) ---");
         for (i, call) in original_trace.call_sequence.iter().enumerate() {
@@ -1998,6 +3300,12 @@ pub fn server(args: ServerArgs) -> Result<()> {
     } else {
         print_info("Token authentication disabled");
     }
+    if args.ws {
+        print_info(format!(
+            "WebSocket transport enabled: connect with ws://{}:{}",
+            args.host, args.port
+        ));
+    }
     if args.tls_cert.is_some() || args.tls_key.is_some() {
         print_info("TLS enabled");
     } else if args.token.is_some() {
@@ -2017,13 +3325,20 @@ pub fn server(args: ServerArgs) -> Result<()> {
         args.show_events,
         args.event_filter,
         args.mock,
-    )?;
+    )?
+    .with_websocket(args.ws);
 
     tokio::runtime::Runtime::new()
         .map_err(|e: std::io::Error| miette::miette!(e))
         .and_then(|rt| rt.block_on(server.run(args.port)))
 }
 
+/// Serve the Debug Adapter Protocol on stdin/stdout. Stdout carries the
+/// protocol, so nothing else may be printed there.
+pub fn dap(_args: DapArgs) -> Result<()> {
+    crate::server::DapServer::stdio().run()
+}
+
 /// Connect to remote debug server
 pub fn remote(args: RemoteArgs, _verbosity: Verbosity) -> Result<()> {
     print_info(format!("Connecting to remote debugger at {}", args.remote));
@@ -2192,7 +3507,11 @@ pub fn interactive(args: InteractiveArgs, _verbosity: Verbosity) -> Result<()> {
         executor.set_mock_specs(&args.mock)?;
     }
 
-    let mut engine = DebuggerEngine::new(executor, args.breakpoint.clone());
+    let mut engine = DebuggerEngine::new(executor, Vec::new());
+    engine.set_storage_history_capacity(args.storage_history);
+    for spec in &args.breakpoint {
+        engine.add_breakpoint_spec(spec)?;
+    }
 
     if args.instruction_debug {
         print_info("Enabling instruction-level debugging...");
@@ -2206,6 +3525,9 @@ pub fn interactive(args: InteractiveArgs, _verbosity: Verbosity) -> Result<()> {
 
     print_info("Starting interactive session (type 'help' for commands)");
     let mut ui = DebuggerUI::new(engine)?;
+    if let Some(path) = args.save_scenario.clone() {
+        ui.set_scenario_output(path);
+    }
     ui.queue_execution(args.function.clone(), parsed_args);
     ui.run()
 }
@@ -2248,10 +3570,26 @@ pub fn tui(args: TuiArgs, _verbosity: Verbosity) -> Result<()> {
         executor.set_initial_storage(storage)?;
     }
 
-    let mut engine = DebuggerEngine::new(executor, args.breakpoint.clone());
+    let mut engine = DebuggerEngine::new(executor, Vec::new());
+    for spec in &args.breakpoint {
+        engine.add_breakpoint_spec(spec)?;
+    }
     engine.stage_execution(&args.function, parsed_args.as_deref());
 
-    run_dashboard(engine, &args.function)
+    let config = crate::config::Config::load_or_default();
+    let network_limits = config.budget_limits.resolve(args.network_limits)?;
+    let theme = args.theme.or(config.theme.name).unwrap_or_default();
+    if let Some(chosen) = args.theme {
+        if let Err(e) = crate::config::Config::save_theme(chosen) {
+            tracing::warn!(error = %e, "Failed to save dashboard theme");
+        }
+    }
+    run_dashboard(
+        engine,
+        &args.function,
+        network_limits,
+        crate::ui::dashboard::Theme::new(theme),
+    )
 }
 
 /// Inspect a WASM contract
@@ -2274,6 +3612,41 @@ pub fn inspect(args: InspectArgs, _verbosity: Verbosity) -> Result<()> {
         return inspect_source_map_diagnostics(&args, &bytes);
     }
 
+    if args.dependency_graph.is_some() || args.graph_output.is_some() {
+        return inspect_dependency_graph(&args, &bytes);
+    }
+
+    if let Some(function) = &args.wat {
+        let disassembly = crate::utils::wasm::disassemble_function(&bytes, function)?;
+        if args.format == OutputFormat::Json {
+            let envelope = crate::output::VersionedOutput::success("inspect", disassembly);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&envelope).map_err(|e| {
+                    DebuggerError::FileError(format!(
+                        "Failed to serialize WAT disassembly JSON output: {}",
+                        e
+                    ))
+                })?
+            );
+        } else {
+            print!("{}", disassembly.to_wat());
+        }
+        return Ok(());
+    }
+
+    if args.host_imports {
+        return inspect_host_imports(&args, &bytes);
+    }
+
+    if args.validate {
+        return inspect_validate(&args, &bytes);
+    }
+
+    if args.spec {
+        return inspect_spec(&args, &bytes);
+    }
+
     let info = crate::utils::wasm::get_module_info(&bytes)?;
     let artifact_metadata = crate::utils::wasm::extract_wasm_artifact_metadata(&bytes)?;
     if args.format == OutputFormat::Json {
@@ -2376,15 +3749,214 @@ pub fn inspect(args: InspectArgs, _verbosity: Verbosity) -> Result<()> {
         let sigs = crate::utils::wasm::parse_function_signatures(&bytes)?;
         println!("Exported functions:");
         for sig in &sigs {
-            let params: Vec<String> = sig
-                .params
-                .iter()
-                .map(|p| format!("{}: {}", p.name, p.type_name))
-                .collect();
-            let ret = sig.return_type.as_deref().unwrap_or("()");
-            println!("  {}({}) -> {}", sig.name, params.join(", "), ret);
+            println!("  {}", sig.to_signature_string());
+        }
+    }
+    Ok(())
+}
+
+fn inspect_host_imports(args: &InspectArgs, wasm_bytes: &[u8]) -> Result<()> {
+    let imports = crate::utils::wasm::parse_host_imports(wasm_bytes)?;
+    if args.format == OutputFormat::Json {
+        let envelope = crate::output::VersionedOutput::success("inspect", &imports);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize host imports: {}", e))
+            })?
+        );
+        return Ok(());
+    }
+
+    println!(
+        "Host imports: {} function(s) from {} module(s)",
+        imports.total(),
+        imports.modules.len()
+    );
+    for (module, functions) in &imports.modules {
+        println!(
+            "  {} ({}): {}",
+            module,
+            functions.len(),
+            functions.join(", ")
+        );
+    }
+    Ok(())
+}
+
+fn inspect_spec(args: &InspectArgs, wasm_bytes: &[u8]) -> Result<()> {
+    let Some(spec) = crate::utils::wasm::parse_contract_spec(wasm_bytes)? else {
+        return Err(miette::miette!(
+            help = "Contracts built with soroban-sdk embed it; make sure the build does not strip custom sections",
+            "{:?} has no contractspecv0 section, so its interface cannot be decoded",
+            args.contract
+        ));
+    };
+
+    if args.format == OutputFormat::Json {
+        let envelope = crate::output::VersionedOutput::success("inspect", &spec);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize contract spec: {}", e))
+            })?
+        );
+        return Ok(());
+    }
+
+    println!("Functions ({}):", spec.functions.len());
+    for function in &spec.functions {
+        println!("  {}", function.to_signature_string());
+    }
+    if !spec.errors.is_empty() {
+        println!("Errors:");
+        for error in &spec.errors {
+            println!("  {}:", error.name);
+            for case in &error.cases {
+                if case.doc.is_empty() {
+                    println!("    {} = {}", case.name, case.code);
+                } else {
+                    println!("    {} = {}  // {}", case.name, case.code, case.doc);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn inspect_validate(args: &InspectArgs, wasm_bytes: &[u8]) -> Result<()> {
+    use crate::output::StatusLabel;
+
+    let report = crate::utils::wasm::validate_soroban_constraints(wasm_bytes)?;
+    let failed = report.checks.iter().filter(|check| !check.passed).count();
+    let failure = (failed > 0).then(|| {
+        format!(
+            "Contract failed {} of {} Soroban deployment check(s)",
+            failed,
+            report.checks.len()
+        )
+    });
+    if args.format == OutputFormat::Json {
+        // A failing report is an error envelope that still carries the checks.
+        let envelope = match &failure {
+            Some(message) => crate::output::VersionedOutput {
+                result: Some(&report),
+                ..crate::output::VersionedOutput::error("inspect", message.clone())
+            },
+            None => crate::output::VersionedOutput::success("inspect", &report),
+        };
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize validation report: {}", e))
+            })?
+        );
+    } else {
+        println!("Soroban deployment checks:");
+        for check in &report.checks {
+            let label = if check.passed {
+                StatusLabel::Pass
+            } else {
+                StatusLabel::Fail
+            };
+            println!("  {} {}: {}", label.as_str(), check.name, check.detail);
+        }
+        println!(
+            "Verdict: {}",
+            if report.passed {
+                "deployable"
+            } else {
+                "would be rejected"
+            }
+        );
+    }
+
+    match failure {
+        Some(message) => Err(miette::miette!(message)),
+        None => Ok(()),
+    }
+}
+
+fn render_dependency_graph(
+    graph: &crate::analyzer::graph::DependencyGraph,
+    format: GraphFormat,
+) -> Result<String> {
+    Ok(match format {
+        GraphFormat::Dot => graph.to_dot(),
+        GraphFormat::Mermaid => graph.to_mermaid(),
+        GraphFormat::Json => serde_json::to_string_pretty(&graph.to_json()).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to serialize dependency graph: {}", e))
+        })?,
+    })
+}
+
+/// Parse `--contract-map` entries: `ID=NAME` pairs or `@file.json` objects.
+fn parse_contract_map(specs: &[String]) -> Result<std::collections::HashMap<String, String>> {
+    let mut labels = std::collections::HashMap::new();
+    for spec in specs {
+        if let Some(path) = spec.strip_prefix('@') {
+            let contents = fs::read_to_string(path).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to read contract map {:?}: {}", path, e))
+            })?;
+            let map: std::collections::HashMap<String, String> = serde_json::from_str(&contents)
+                .map_err(|e| {
+                    DebuggerError::InvalidArguments(format!(
+                        "Contract map {:?} must be a JSON object of id to name: {}",
+                        path, e
+                    ))
+                })?;
+            labels.extend(
+                map.into_iter()
+                    .map(|(id, name)| (contract_map_key(&id), name)),
+            );
+        } else {
+            let (id, name) = spec.split_once('=').ok_or_else(|| {
+                DebuggerError::InvalidArguments(format!(
+                    "Invalid --contract-map entry '{}'. Use ID=NAME or @FILE",
+                    spec
+                ))
+            })?;
+            labels.insert(contract_map_key(id), name.trim().to_string());
         }
     }
+    Ok(labels)
+}
+
+/// Graph node id for a `--contract-map` key: a 64-hex contract hash becomes its
+/// `C...` strkey, anything else (strkey, export name) is used as given.
+fn contract_map_key(id: &str) -> String {
+    use soroban_env_host::xdr::{Hash, ScAddress};
+
+    let id = id.trim();
+    match hex::decode(id) {
+        Ok(bytes) if bytes.len() == 32 => {
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(&bytes);
+            ScAddress::Contract(Hash(hash)).to_string()
+        }
+        _ => id.to_string(),
+    }
+}
+
+/// Print the cross-contract dependency graph and/or write it to `--graph-output`.
+fn inspect_dependency_graph(args: &InspectArgs, wasm_bytes: &[u8]) -> Result<()> {
+    let calls = crate::utils::parse_cross_contract_calls(wasm_bytes)?;
+    let graph = crate::analyzer::graph::DependencyGraph::from_calls(&calls)
+        .with_labels(&parse_contract_map(&args.contract_map)?);
+
+    if let Some(format) = args.dependency_graph {
+        println!("{}", render_dependency_graph(&graph, format)?);
+    }
+    if let Some(path) = &args.graph_output {
+        let format = args.graph_format.unwrap_or(GraphFormat::Dot);
+        fs::write(path, render_dependency_graph(&graph, format)?).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Failed to write dependency graph {:?}: {}",
+                path, e
+            ))
+        })?;
+        print_success(format!("Dependency graph written to {:?}", path));
+    }
     Ok(())
 }
 
@@ -2518,10 +4090,34 @@ pub fn symbolic(args: SymbolicArgs, _verbosity: Verbosity) -> Result<()> {
 }
 
 /// Analyze a contract
-pub fn analyze(args: AnalyzeArgs, _verbosity: Verbosity) -> Result<()> {
-    print_info(format!("Loading contract: {:?}", args.contract));
-    let wasm_file = crate::utils::wasm::load_wasm(&args.contract)
-        .with_context(|| format!("Failed to read WASM file: {:?}", args.contract))?;
+pub fn analyze(mut args: AnalyzeArgs, _verbosity: Verbosity) -> Result<()> {
+    if let Some(path) = &args.args_file {
+        args.args = Some(read_args_file(path)?);
+    }
+    if args.list_rules {
+        let rules = SecurityAnalyzer::new().rule_metadata();
+        match args.format.to_lowercase().as_str() {
+            "json" => {
+                let envelope = crate::output::VersionedOutput::success("analyze", &rules);
+                println!(
+                    "{}",
+                    to_json_string(&envelope, args.canonical_json).map_err(|e| {
+                        DebuggerError::FileError(format!("Failed to serialize rule list: {}", e))
+                    })?
+                );
+            }
+            _ => println!("{}", render_rule_list(&rules)),
+        }
+        return Ok(());
+    }
+
+    let contract = args
+        .contract
+        .as_ref()
+        .expect("contract is required for analyze");
+    print_info(format!("Loading contract: {:?}", contract));
+    let wasm_file = crate::utils::wasm::load_wasm(contract)
+        .with_context(|| format!("Failed to read WASM file: {:?}", contract))?;
 
     let mut dynamic_analysis = None;
     let mut warnings = Vec::new();
@@ -2575,9 +4171,14 @@ pub fn analyze(args: AnalyzeArgs, _verbosity: Verbosity) -> Result<()> {
     let filter = crate::analyzer::security::AnalyzerFilter {
         enable_rules: args.enable_rule.clone(),
         disable_rules: args.disable_rule.clone(),
-        min_severity: parse_min_severity(&args.min_severity)?,
+        min_severity: parse_severity("--min-severity", &args.min_severity)?,
     };
-    let contract_path = args.contract.to_string_lossy().to_string();
+    let fail_on = args
+        .fail_on
+        .as_deref()
+        .map(|value| parse_severity("--fail-on", value))
+        .transpose()?;
+    let contract_path = contract.to_string_lossy().to_string();
     let report = analyzer.analyze(
         &wasm_file.bytes,
         executor.as_ref(),
@@ -2595,10 +4196,16 @@ pub fn analyze(args: AnalyzeArgs, _verbosity: Verbosity) -> Result<()> {
     match args.format.to_lowercase().as_str() {
         "text" => println!("{}", render_security_report(&output)),
         "json" => {
-            let envelope = crate::output::VersionedOutput::success("analyze", &output);
+            let meta = crate::output::RunMetadata::new(wasm_file.sha256_hash.clone()).with_inputs(
+                args.function.as_deref(),
+                args.args.as_deref(),
+                args.storage.as_deref(),
+            );
+            let envelope =
+                crate::output::VersionedOutput::success("analyze", &output).with_meta(meta);
             println!(
                 "{}",
-                serde_json::to_string_pretty(&envelope).map_err(|e| {
+                to_json_string(&envelope, args.canonical_json).map_err(|e| {
                     DebuggerError::FileError(format!("Failed to serialize analysis output: {}", e))
                 })?
             );
@@ -2612,6 +4219,101 @@ pub fn analyze(args: AnalyzeArgs, _verbosity: Verbosity) -> Result<()> {
         }
     }
 
+    if let Some(threshold) = fail_on {
+        let failing = output
+            .findings
+            .iter()
+            .filter(|finding| finding.severity >= threshold)
+            .count();
+        if failing > 0 {
+            return Err(DebuggerError::SecurityFindings(format!(
+                "{} finding(s) at {:?} severity or above",
+                failing, threshold
+            ))
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Run setup calls and check instance storage against the ledger entry size limit.
+pub fn storage_limits(args: StorageLimitsArgs, _verbosity: Verbosity) -> Result<()> {
+    print_info(format!("Loading contract: {:?}", args.contract));
+    let wasm_file = crate::utils::wasm::load_wasm(&args.contract)
+        .with_context(|| format!("Failed to read WASM file: {:?}", args.contract))?;
+
+    let mut executor = ContractExecutor::new(wasm_file.bytes)?;
+    executor.enable_mock_all_auths();
+    executor.set_timeout(args.timeout);
+
+    if let Some(storage_json) = &args.storage {
+        executor.set_initial_storage(parse_storage(storage_json)?)?;
+    }
+
+    for (function, call_args) in args.parse_setup_calls() {
+        let parsed_args = call_args.as_deref().map(parse_args).transpose()?;
+        print_info(format!("Running setup call: {}", function));
+        executor
+            .execute(&function, parsed_args.as_deref())
+            .with_context(|| format!("Setup call '{}' failed", function))?;
+    }
+
+    let report = crate::inspector::storage::StorageInspector::instance_storage_report(
+        executor.host(),
+        args.max_entry_bytes,
+    );
+
+    match args.format {
+        OutputFormat::Json => {
+            let envelope = crate::output::VersionedOutput::success("storage-limits", &report);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&envelope).map_err(|e| {
+                    DebuggerError::FileError(format!(
+                        "Failed to serialize storage limits report: {}",
+                        e
+                    ))
+                })?
+            );
+        }
+        OutputFormat::Pretty => {
+            print_info(format!(
+                "Instance entry size: {} / {} bytes",
+                report.total_bytes, report.limit_bytes
+            ));
+            for entry in &report.entries {
+                print_info(format!("  {} bytes  {}", entry.size_bytes, entry.key));
+            }
+            for entry in report.oversized_entries() {
+                print_warning(format!(
+                    "Instance key {} serializes to {} bytes, exceeding the {} byte limit",
+                    entry.key, entry.size_bytes, report.limit_bytes
+                ));
+            }
+            if report.total_bytes > report.limit_bytes {
+                let largest = report
+                    .entries
+                    .first()
+                    .map(|e| format!(" (largest key: {}, {} bytes)", e.key, e.size_bytes))
+                    .unwrap_or_default();
+                print_warning(format!(
+                    "Instance storage entry is {} bytes, exceeding the {} byte limit{}",
+                    report.total_bytes, report.limit_bytes, largest
+                ));
+            }
+        }
+    }
+
+    if report.exceeds_limit() {
+        return Err(DebuggerError::StorageError(format!(
+            "Instance storage exceeds the {} byte entry limit; deployment would fail",
+            report.limit_bytes
+        ))
+        .into());
+    }
+
+    print_success("Instance storage is within the entry size limit");
     Ok(())
 }
 
@@ -2805,10 +4507,12 @@ pub async fn repl(args: ReplArgs) -> Result<()> {
 pub fn show_budget_trend(
     contract: Option<&str>,
     function: Option<&str>,
+    since_commit: Option<&str>,
     regression: crate::history::RegressionConfig,
+    fail_on_regression: bool,
 ) -> Result<()> {
     let manager = HistoryManager::new()?;
-    let mut records = manager.filter_history(contract, function)?;
+    let mut records = manager.filter_history(contract, function, since_commit)?;
 
     crate::history::sort_records_by_date(&mut records);
 
@@ -2816,9 +4520,10 @@ pub fn show_budget_trend(
         if !Formatter::is_quiet() {
             println!("Budget Trend");
             println!(
-                "Filters: contract={} function={}",
+                "Filters: contract={} function={} since_commit={}",
                 contract.unwrap_or("*"),
-                function.unwrap_or("*")
+                function.unwrap_or("*"),
+                since_commit.unwrap_or("*")
             );
             println!("No run history found yet.");
             println!("Tip: run `soroban-debug run ...` a few times to generate history.");
@@ -2827,19 +4532,24 @@ pub fn show_budget_trend(
     }
 
     let stats = budget_trend_stats_or_err(&records)?;
+    let delta = crate::history::check_regression_with_config(&records, &regression);
     let cpu_values: Vec<u64> = records.iter().map(|r| r.cpu_used).collect();
     let mem_values: Vec<u64> = records.iter().map(|r| r.memory_used).collect();
 
     if !Formatter::is_quiet() {
         println!("Budget Trend");
         println!(
-            "Filters: contract={} function={}",
+            "Filters: contract={} function={} since_commit={}",
             contract.unwrap_or("*"),
-            function.unwrap_or("*")
+            function.unwrap_or("*"),
+            since_commit.unwrap_or("*")
         );
         println!(
-            "Regression params: threshold>{:.1}% lookback={} smoothing={}",
-            regression.threshold_pct, regression.lookback, regression.smoothing_window
+            "Regression params: cpu>{:.1}% mem>{:.1}% lookback={} smoothing={}",
+            regression.thresholds.cpu_pct,
+            regression.thresholds.mem_pct,
+            regression.lookback,
+            regression.smoothing_window
         );
         println!(
             "Runs: {}   Range: {} -> {}",
@@ -2863,22 +4573,57 @@ pub fn show_budget_trend(
         println!("CPU trend: {}", Formatter::sparkline(&cpu_values, 50));
         println!("MEM trend: {}", Formatter::sparkline(&mem_values, 50));
 
-        if let Some((cpu_reg, mem_reg)) =
-            crate::history::check_regression_with_config(&records, &regression)
-        {
-            if cpu_reg > 0.0 || mem_reg > 0.0 {
+        if let Some(delta) = delta {
+            if delta.significant {
                 println!();
-                println!("Regression warning (latest vs baseline):");
-                if cpu_reg > 0.0 {
-                    println!("  CPU increased by {:.1}%", cpu_reg);
-                }
-                if mem_reg > 0.0 {
-                    println!("  Memory increased by {:.1}%", mem_reg);
+                println!("Change (latest vs baseline):");
+                let thresholds = regression.thresholds;
+                for (label, pct, threshold) in [
+                    ("CPU", delta.cpu_delta_pct, thresholds.cpu_pct),
+                    ("Memory", delta.mem_delta_pct, thresholds.mem_pct),
+                ] {
+                    if pct > threshold {
+                        println!(
+                            "  {}",
+                            Formatter::warning(format!("⬆ {} regressed by {:.1}%", label, pct))
+                        );
+                    } else if -pct > threshold {
+                        println!(
+                            "  {}",
+                            Formatter::success(format!("⬇ {} improved by {:.1}%", label, -pct))
+                        );
+                    }
                 }
             }
         }
     }
 
+    if let Some(delta) = delta.filter(|_| fail_on_regression) {
+        let thresholds = regression.thresholds;
+        if delta.cpu_delta_pct > thresholds.cpu_pct || delta.mem_delta_pct > thresholds.mem_pct {
+            return Err(DebuggerError::RegressionDetected(format!(
+                "CPU {:+.1}% (threshold {:.1}%), memory {:+.1}% (threshold {:.1}%)",
+                delta.cpu_delta_pct, thresholds.cpu_pct, delta.mem_delta_pct, thresholds.mem_pct
+            ))
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Export run history to CSV.
+pub fn history(args: HistoryArgs) -> Result<()> {
+    let manager = HistoryManager::new()?;
+    let count = manager.export_csv(
+        &args.export_csv,
+        args.trend_contract.as_deref(),
+        args.trend_function.as_deref(),
+    )?;
+    print_success(format!(
+        "Exported {} record(s) to {:?}",
+        count, args.export_csv
+    ));
     Ok(())
 }
 
@@ -2936,6 +4681,19 @@ pub fn history_prune(args: HistoryPruneArgs) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_storage_override_accepts_typed_and_bare_values() {
+        let (key, value) = parse_storage_override(r#"balance={"type":"i128","value":5}"#).unwrap();
+        assert_eq!(key, "balance");
+        assert_eq!(value, serde_json::json!({ "type": "i128", "value": 5 }));
+
+        let (_, value) = parse_storage_override("admin=GABC").unwrap();
+        assert_eq!(value, serde_json::json!("GABC"));
+
+        assert!(parse_storage_override("balance").is_err());
+        assert!(parse_storage_override("=5").is_err());
+    }
+
     #[test]
     fn budget_trend_stats_or_err_returns_error_instead_of_panicking() {
         let empty: Vec<RunHistory> = Vec::new();
@@ -2965,6 +4723,24 @@ mod tests {
         assert!(json.get("protocol").is_some());
         assert!(json.get("vscode_extension").is_some());
     }
+
+    #[test]
+    fn parse_contract_storage_accepts_inline_json_and_files() {
+        let (id, storage) = parse_contract_storage("CABC=[{\"key\":1,\"value\":2}]").unwrap();
+        assert_eq!(id, "CABC");
+        assert_eq!(storage, "[{\"key\":1,\"value\":2}]");
+
+        let path = std::env::temp_dir().join("soroban-debug-contract-storage.json");
+        fs::write(&path, "{\"admin\": 7}").unwrap();
+        let (id, storage) = parse_contract_storage(&format!("CDEF=@{}", path.display())).unwrap();
+        assert_eq!(id, "CDEF");
+        assert_eq!(storage, "{\"admin\": 7}");
+        let _ = fs::remove_file(&path);
+
+        assert!(parse_contract_storage("no-separator").is_err());
+        assert!(parse_contract_storage("=@file.json").is_err());
+        assert!(parse_contract_storage("CABC={not json").is_err());
+    }
 }
 //
 ///////
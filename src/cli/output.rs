@@ -20,6 +20,33 @@ pub struct BudgetInfo {
     pub memory_bytes: u64,
 }
 
+/// Pretty-print `value` as JSON. With `canonical` (`--canonical-json`), object
+/// keys are sorted at every level so two runs' output can be compared with a
+/// plain `diff`; array order is meaningful and kept.
+pub fn to_json_string<T: Serialize>(value: &T, canonical: bool) -> serde_json::Result<String> {
+    if canonical {
+        serde_json::to_string_pretty(&canonicalize(serde_json::to_value(value)?))
+    } else {
+        serde_json::to_string_pretty(value)
+    }
+}
+
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            entries
+                .into_iter()
+                .map(|(key, value)| (key, canonicalize(value)))
+                .collect::<serde_json::Map<_, _>>()
+                .into()
+        }
+        serde_json::Value::Array(items) => items.into_iter().map(canonicalize).collect(),
+        other => other,
+    }
+}
+
 pub fn write_json_pretty_file<T: Serialize>(path: &Path, value: &T) -> miette::Result<()> {
     if let Some(parent) = path.parent() {
         if !parent.as_os_str().is_empty() {
@@ -33,3 +60,43 @@ pub fn write_json_pretty_file<T: Serialize>(path: &Path, value: &T) -> miette::R
     std::fs::write(path, json).map_err(|e| miette::miette!("Failed to write file: {}", e))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn canonical_json_sorts_nested_keys_and_keeps_array_order() {
+        let value = json!({
+            "zeta": 1,
+            "alpha": { "b": [3, 1, 2], "a": { "y": true, "x": null } },
+        });
+        let json = to_json_string(&value, true).unwrap();
+        let keys: Vec<&str> = json
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix('"'))
+            .filter_map(|line| line.split('"').next())
+            .collect();
+        assert_eq!(keys, vec!["alpha", "a", "x", "y", "b", "zeta"]);
+        assert!(json.contains("[\n      3,\n      1,\n      2\n    ]"));
+    }
+
+    #[test]
+    fn canonical_json_is_independent_of_field_order() {
+        #[derive(Serialize)]
+        struct Ab {
+            a: u32,
+            b: u32,
+        }
+        #[derive(Serialize)]
+        struct Ba {
+            b: u32,
+            a: u32,
+        }
+        assert_eq!(
+            to_json_string(&Ab { a: 1, b: 2 }, true).unwrap(),
+            to_json_string(&Ba { b: 2, a: 1 }, true).unwrap()
+        );
+    }
+}
@@ -11,6 +11,9 @@ use tracing::info;
 
 use rustls::client::ServerName;
 use rustls::{Certificate, ClientConfig, PrivateKey, RootCertStore};
+use tungstenite::client::IntoClientRequest;
+use tungstenite::http::{header::AUTHORIZATION, HeaderValue, StatusCode};
+use tungstenite::Message;
 
 #[derive(Debug, Clone)]
 pub struct RequestTimeouts {
@@ -112,7 +115,7 @@ pub struct ReconnectInfo {
 pub struct RemoteClient {
     addr: String,
     token: Option<String>,
-    stream: BufReader<RemoteStream>,
+    stream: Transport,
     message_id: u64,
     authenticated: bool,
     config: RemoteClientConfig,
@@ -168,6 +171,70 @@ impl RemoteStream {
     }
 }
 
+/// How messages are framed on the connection.
+#[derive(Debug)]
+enum Transport {
+    /// One JSON message per line.
+    Lines(BufReader<RemoteStream>),
+    /// One JSON message per WebSocket text frame.
+    WebSocket(Box<tungstenite::WebSocket<RemoteStream>>),
+}
+
+impl Transport {
+    fn is_websocket(&self) -> bool {
+        matches!(self, Self::WebSocket(_))
+    }
+
+    fn remote(&self) -> &RemoteStream {
+        match self {
+            Self::Lines(reader) => reader.get_ref(),
+            Self::WebSocket(socket) => socket.get_ref(),
+        }
+    }
+
+    fn send(&mut self, json: &str) -> std::io::Result<()> {
+        match self {
+            Self::Lines(reader) => {
+                let stream = reader.get_mut();
+                writeln!(stream, "{}", json)?;
+                stream.flush()
+            }
+            Self::WebSocket(socket) => socket
+                .send(Message::Text(json.to_string()))
+                .map_err(websocket_io_error),
+        }
+    }
+
+    /// The next message, or `None` once the server has closed the connection.
+    fn receive(&mut self) -> std::io::Result<Option<String>> {
+        match self {
+            Self::Lines(reader) => {
+                let mut line = String::new();
+                let n = reader.read_line(&mut line)?;
+                Ok((n > 0).then_some(line))
+            }
+            Self::WebSocket(socket) => loop {
+                match socket.read() {
+                    Ok(Message::Text(text)) => return Ok(Some(text)),
+                    Ok(Message::Close(_))
+                    | Err(
+                        tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed,
+                    ) => return Ok(None),
+                    Ok(_) => continue,
+                    Err(e) => return Err(websocket_io_error(e)),
+                }
+            },
+        }
+    }
+}
+
+fn websocket_io_error(e: tungstenite::Error) -> std::io::Error {
+    match e {
+        tungstenite::Error::Io(e) => e,
+        other => std::io::Error::other(other),
+    }
+}
+
 impl RemoteClient {
     /// Connect to a remote debug server
     pub fn connect(addr: &str, token: Option<String>) -> Result<Self> {
@@ -180,28 +247,73 @@ impl RemoteClient {
         config: RemoteClientConfig,
     ) -> Result<Self> {
         info!("Connecting to debug server at {}", addr);
-        let stream = Self::create_stream(addr, &config)?;
+        let stream = Self::open_transport(addr, token.as_deref(), &config)?;
 
         let mut client = Self {
             addr: addr.to_string(),
             token: token.clone(),
-            stream: BufReader::new(stream),
+            authenticated: token.is_none() || stream.is_websocket(),
+            stream,
             message_id: 0,
-            authenticated: token.is_none(),
             config,
             session_id: None,
         };
 
         client.handshake("rust-remote-client", env!("CARGO_PKG_VERSION"))?;
 
-        // Authenticate if token is provided
-        if let Some(token) = token {
+        // Authenticate if a token is provided and the transport has not already
+        if let Some(token) = token.filter(|_| !client.authenticated) {
             client.authenticate(&token)?;
         }
 
         Ok(client)
     }
 
+    /// Open the transport `addr` names: a `ws://HOST:PORT[/PATH]` URL upgrades
+    /// to WebSocket, presenting `token` as a bearer token during the upgrade;
+    /// anything else is a raw `HOST:PORT` socket.
+    fn open_transport(
+        addr: &str,
+        token: Option<&str>,
+        config: &RemoteClientConfig,
+    ) -> Result<Transport> {
+        let Some(rest) = addr.strip_prefix("ws://") else {
+            let stream = Self::create_stream(addr, config)?;
+            return Ok(Transport::Lines(BufReader::new(stream)));
+        };
+
+        let host_port = rest.split('/').next().unwrap_or(rest);
+        let stream = Self::create_stream(host_port, config)?;
+        let mut request = addr.into_client_request().map_err(|e| {
+            DebuggerError::NetworkError(format!("Invalid WebSocket URL '{}': {}", addr, e))
+        })?;
+        if let Some(token) = token {
+            let value = HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
+                DebuggerError::AuthenticationFailed(
+                    "Token cannot be sent in an Authorization header".to_string(),
+                )
+            })?;
+            request.headers_mut().insert(AUTHORIZATION, value);
+        }
+
+        match tungstenite::client(request, stream) {
+            Ok((socket, _)) => Ok(Transport::WebSocket(Box::new(socket))),
+            Err(tungstenite::HandshakeError::Failure(tungstenite::Error::Http(response)))
+                if response.status() == StatusCode::UNAUTHORIZED =>
+            {
+                Err(DebuggerError::AuthenticationFailed(
+                    "Server rejected the WebSocket token".to_string(),
+                )
+                .into())
+            }
+            Err(e) => Err(DebuggerError::NetworkError(format!(
+                "WebSocket handshake with '{}' failed: {}",
+                addr, e
+            ))
+            .into()),
+        }
+    }
+
     fn create_stream(addr: &str, config: &RemoteClientConfig) -> Result<RemoteStream> {
         use std::net::ToSocketAddrs;
         let socket_addr = addr
@@ -770,9 +882,8 @@ impl RemoteClient {
     }
 
     fn reconnect(&mut self) -> Result<()> {
-        let stream = Self::create_stream(&self.addr, &self.config)?;
-        self.stream = BufReader::new(stream);
-        self.authenticated = self.token.is_none();
+        self.stream = Self::open_transport(&self.addr, self.token.as_deref(), &self.config)?;
+        self.authenticated = self.token.is_none() || self.stream.is_websocket();
 
         // Perform handshake
         let handshake = DebugRequest::Handshake {
@@ -798,7 +909,7 @@ impl RemoteClient {
             }
         }
 
-        if let Some(token) = self.token.clone() {
+        if let Some(token) = self.token.clone().filter(|_| !self.authenticated) {
             self.authenticate(&token)?;
         }
 
@@ -828,15 +939,14 @@ impl RemoteClient {
     /// Returns the reconnection acknowledgment on success, or an error if the
     /// session is expired or the server does not support reconnection.
     pub fn reconnect_to_session(&mut self, session_id: &str) -> Result<ReconnectInfo> {
-        let stream = Self::create_stream(&self.addr, &self.config)?;
-        self.stream = BufReader::new(stream);
-        self.authenticated = self.token.is_none();
+        self.stream = Self::open_transport(&self.addr, self.token.as_deref(), &self.config)?;
+        self.authenticated = self.token.is_none() || self.stream.is_websocket();
 
         // Perform handshake
         self.handshake("rust-remote-client", env!("CARGO_PKG_VERSION"))?;
 
         // Authenticate if needed
-        if let Some(token) = self.token.clone() {
+        if let Some(token) = self.token.clone().filter(|_| !self.authenticated) {
             self.authenticate(&token)?;
         }
 
@@ -957,40 +1067,37 @@ impl RemoteClient {
         let expected_id = self.message_id;
         let message = DebugMessage::request(expected_id, request);
 
-        let request_json = serde_json::to_string(&message)
+        let request_json = message
+            .encode()
             .map_err(|e| SendFailure::Serialize(format!("Failed to serialize request: {}", e)))?;
 
         self.stream
-            .get_mut()
+            .remote()
             .set_read_timeout(Some(timeout))
             .map_err(|e| SendFailure::Io {
                 stage: "set_read_timeout",
                 source: e,
             })?;
         self.stream
-            .get_mut()
+            .remote()
             .set_write_timeout(Some(timeout))
             .map_err(|e| SendFailure::Io {
                 stage: "set_write_timeout",
                 source: e,
             })?;
 
-        writeln!(self.stream.get_mut(), "{}", request_json)
-            .map_err(|e| SendFailure::io("write", e, timeout))?;
         self.stream
-            .get_mut()
-            .flush()
-            .map_err(|e| SendFailure::io("flush", e, timeout))?;
+            .send(&request_json)
+            .map_err(|e| SendFailure::io("write", e, timeout))?;
 
         loop {
-            let mut response_line = String::new();
-            let n = self
+            let Some(response_line) = self
                 .stream
-                .read_line(&mut response_line)
-                .map_err(|e| SendFailure::io("read", e, timeout))?;
-            if n == 0 {
+                .receive()
+                .map_err(|e| SendFailure::io("read", e, timeout))?
+            else {
                 return Err(SendFailure::Disconnected);
-            }
+            };
 
             let msg = DebugMessage::parse(response_line.trim_end())
                 .map_err(|e| SendFailure::Protocol(e.to_string()))?;
@@ -998,15 +1105,12 @@ impl RemoteClient {
             // Handle interleaved Ping from server
             if let Some(DebugRequest::Ping) = msg.request {
                 let pong = DebugMessage::response(msg.id, DebugResponse::Pong);
-                let pong_json = serde_json::to_string(&pong).map_err(|e| {
+                let pong_json = pong.encode().map_err(|e| {
                     SendFailure::Serialize(format!("Failed to serialize pong: {}", e))
                 })?;
-                writeln!(self.stream.get_mut(), "{}", pong_json)
-                    .map_err(|e| SendFailure::io("ping-response", e, timeout))?;
                 self.stream
-                    .get_mut()
-                    .flush()
-                    .map_err(|e| SendFailure::io("ping-flush", e, timeout))?;
+                    .send(&pong_json)
+                    .map_err(|e| SendFailure::io("ping-response", e, timeout))?;
                 continue;
             }
 
@@ -1,6 +1,9 @@
-use crate::runtime::executor::ExecutionRecord;
+use crate::inspector::storage::StorageDurability;
+use crate::runtime::executor::{parse_storage_seed, ExecutionRecord};
+use crate::utils::wasm::{ContractFunctionSignature, CustomError};
 use crate::{DebuggerError, Result};
-use soroban_env_host::xdr::{Limits, WriteXdr};
+use soroban_env_host::xdr::{Limits, ScVal, WriteXdr};
+use soroban_sdk::{Env, TryFromVal, Val};
 use std::fs;
 use std::path::Path;
 
@@ -10,10 +13,31 @@ pub struct TestGenerator;
 impl TestGenerator {
     /// Generate a Rust unit test from an execution record.
     pub fn generate(record: &ExecutionRecord, wasm_path: &Path) -> Result<String> {
+        Self::generate_with_storage(record, wasm_path, None)
+    }
+
+    /// Generate a Rust unit test that seeds the contract with `initial_storage`
+    /// (the `--storage` JSON of the run) before invoking it.
+    ///
+    /// The storage is applied with `soroban_debug_mock::MockEnvBuilder`, one
+    /// `with_contract_entry` per entry with the key, value and durability the
+    /// debugger seeded, so the generated test needs `soroban-debug-mock` as a
+    /// dev-dependency.
+    pub fn generate_with_storage(
+        record: &ExecutionRecord,
+        wasm_path: &Path,
+        initial_storage: Option<&str>,
+    ) -> Result<String> {
+        let storage = parse_initial_storage(initial_storage)?;
+
         let mut code = String::new();
 
         // Imports
-        code.push_str("use soroban_sdk::{Env, Symbol, Val, Vec, xdr::ScVal, TryFromVal};\n\n");
+        if storage.is_some() {
+            code.push_str("use soroban_debug_mock::{MockEnvBuilder, StorageDurability};\n");
+        }
+        code.push_str("use soroban_sdk::xdr::{Limits, ReadXdr, ScVal};\n");
+        code.push_str("use soroban_sdk::{Env, Symbol, TryFromVal, Val, Vec};\n\n");
 
         // Test signature
         code.push_str("#[test]\n");
@@ -25,10 +49,7 @@ impl TestGenerator {
         // Contract registration
         // We assume the WASM path is relevant to where the test is run.
         // For simplicity, we use the filename and assume it's in the same directory or project root.
-        let wasm_file_name = wasm_path
-            .file_name()
-            .map(|f| f.to_string_lossy().to_string())
-            .unwrap_or_else(|| "contract.wasm".to_string());
+        let wasm_file_name = wasm_file_name(wasm_path);
 
         code.push_str(&format!(
             "    // Register contract (assuming {} is in current or parent directory)\n",
@@ -43,6 +64,11 @@ impl TestGenerator {
         ));
         code.push_str("    let contract_id = env.register_contract_wasm(None, wasm);\n\n");
 
+        // Initial storage
+        if let Some(storage) = &storage {
+            push_storage_seed(&mut code, storage, "    ")?;
+        }
+
         // Prepare arguments
         code.push_str("    // Prepare arguments\n");
         code.push_str("    let mut args = Vec::<Val>::new(&env);\n");
@@ -51,7 +77,7 @@ impl TestGenerator {
                 DebuggerError::ExecutionError(format!("Failed to encode argument to XDR: {:?}", e))
             })?;
             code.push_str(&format!(
-                "    args.push_back(Val::try_from_val(&env, &ScVal::from_xdr_base64(\"{}\").unwrap()).unwrap());\n",
+                "    args.push_back(Val::try_from_val(&env, &ScVal::from_xdr_base64(\"{}\", Limits::none()).unwrap()).unwrap());\n",
                 base64
             ));
         }
@@ -75,7 +101,7 @@ impl TestGenerator {
                     ))
                 })?;
                 code.push_str(&format!(
-                    "    let expected = Val::try_from_val(&env, &ScVal::from_xdr_base64(\"{}\").unwrap()).unwrap();\n",
+                    "    let expected = Val::try_from_val(&env, &ScVal::from_xdr_base64(\"{}\", Limits::none()).unwrap()).unwrap();\n",
                     base64
                 ));
                 code.push_str("    assert_eq!(result, expected);\n");
@@ -98,6 +124,47 @@ impl TestGenerator {
         Ok(code)
     }
 
+    /// Generate a `proptest!` test that fuzzes the numeric arguments of the
+    /// recorded call over their full range.
+    ///
+    /// Argument types come from the contract spec of the WASM at `wasm_path`.
+    /// Non-numeric arguments (addresses, strings, ...) keep the values from
+    /// the recorded run. The test fails if the call traps or returns an error
+    /// that is not one of the contract's declared `#[contracterror]` variants.
+    pub fn generate_proptest(record: &ExecutionRecord, wasm_path: &Path) -> Result<String> {
+        Self::generate_proptest_with_storage(record, wasm_path, None)
+    }
+
+    /// Like [`Self::generate_proptest`], seeding every case with
+    /// `initial_storage` as [`Self::generate_with_storage`] does.
+    pub fn generate_proptest_with_storage(
+        record: &ExecutionRecord,
+        wasm_path: &Path,
+        initial_storage: Option<&str>,
+    ) -> Result<String> {
+        let wasm_bytes = fs::read(wasm_path).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to read WASM file {:?}: {}", wasm_path, e))
+        })?;
+        let signature = crate::utils::wasm::parse_function_signatures(&wasm_bytes)?
+            .into_iter()
+            .find(|sig| sig.name == record.function)
+            .ok_or_else(|| {
+                DebuggerError::InvalidArguments(format!(
+                    "Function '{}' has no contract spec signature; cannot generate a property test",
+                    record.function
+                ))
+            })?;
+        let errors = crate::utils::wasm::parse_custom_errors(&wasm_bytes)?;
+        let storage = parse_initial_storage(initial_storage)?;
+        proptest_code(
+            record,
+            &wasm_file_name(wasm_path),
+            &signature,
+            &errors,
+            storage.as_deref(),
+        )
+    }
+
     /// Write the generated test to a file, either overwriting or appending.
     pub fn write_to_file(path: &Path, content: &str, overwrite: bool) -> Result<()> {
         if path.exists() && !overwrite {
@@ -119,11 +186,223 @@ impl TestGenerator {
     }
 }
 
+fn wasm_file_name(wasm_path: &Path) -> String {
+    wasm_path
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_else(|| "contract.wasm".to_string())
+}
+
+/// A `--storage` entry decoded into the values the debugger seeded.
+struct StorageSeed {
+    durability: StorageDurability,
+    key: ScVal,
+    value: ScVal,
+}
+
+fn parse_initial_storage(initial_storage: Option<&str>) -> Result<Option<Vec<StorageSeed>>> {
+    let Some(json) = initial_storage else {
+        return Ok(None);
+    };
+    let env = Env::default();
+    let to_scval = |val: Val| {
+        ScVal::try_from_val(&env, &val).map_err(|e| {
+            DebuggerError::StorageError(format!(
+                "Failed to convert initial storage for test generation: {:?}",
+                e
+            ))
+        })
+    };
+    let mut seeds = Vec::new();
+    for (durability, key, value) in parse_storage_seed(&env, json)? {
+        seeds.push(StorageSeed {
+            durability,
+            key: to_scval(key)?,
+            value: to_scval(value)?,
+        });
+    }
+    Ok(Some(seeds))
+}
+
+/// Emit the `MockEnvBuilder` calls that seed `contract_id` with `storage`.
+fn push_storage_seed(code: &mut String, storage: &[StorageSeed], indent: &str) -> Result<()> {
+    let decode = |val: &ScVal| -> Result<String> {
+        let base64 = val.to_xdr_base64(Limits::none()).map_err(|e| {
+            DebuggerError::StorageError(format!("Failed to encode storage to XDR: {:?}", e))
+        })?;
+        Ok(format!(
+            "&Val::try_from_val(&env, &ScVal::from_xdr_base64(\"{}\", Limits::none()).unwrap()).unwrap()",
+            base64
+        ))
+    };
+    code.push_str(&format!(
+        "{indent}// Seed initial storage captured from the debugger run\n"
+    ));
+    code.push_str(&format!(
+        "{indent}let env = MockEnvBuilder::from_env(env)\n"
+    ));
+    for seed in storage {
+        let durability = match seed.durability {
+            StorageDurability::Instance => "Instance",
+            StorageDurability::Persistent => "Persistent",
+            StorageDurability::Temporary => "Temporary",
+        };
+        code.push_str(&format!(
+            "{indent}    // {:?} => {:?}\n",
+            seed.key, seed.value
+        ));
+        code.push_str(&format!(
+            "{indent}    .with_contract_entry(\n{indent}        &contract_id,\n{indent}        StorageDurability::{},\n{indent}        {},\n{indent}        {},\n{indent}    )\n",
+            durability,
+            decode(&seed.key)?,
+            decode(&seed.value)?
+        ));
+    }
+    code.push_str(&format!("{indent}    .build();\n\n"));
+    Ok(())
+}
+
+/// Rust type of a contract spec integer type that proptest can range over.
+fn fuzzable_type(spec_type: &str) -> Option<&'static str> {
+    match spec_type {
+        "U32" => Some("u32"),
+        "I32" => Some("i32"),
+        "U64" => Some("u64"),
+        "I64" => Some("i64"),
+        "U128" => Some("u128"),
+        "I128" => Some("i128"),
+        _ => None,
+    }
+}
+
+fn proptest_code(
+    record: &ExecutionRecord,
+    wasm_file_name: &str,
+    signature: &ContractFunctionSignature,
+    errors: &[CustomError],
+    storage: Option<&[StorageSeed]>,
+) -> Result<String> {
+    if signature.params.len() != record.args.len() {
+        return Err(DebuggerError::InvalidArguments(format!(
+            "Recorded call to '{}' has {} argument(s) but the contract spec declares {}",
+            record.function,
+            record.args.len(),
+            signature.params.len()
+        ))
+        .into());
+    }
+    let fuzzed: Vec<(&str, &str)> = signature
+        .params
+        .iter()
+        .filter_map(|param| fuzzable_type(&param.type_name).map(|ty| (param.name.as_str(), ty)))
+        .collect();
+    if fuzzed.is_empty() {
+        return Err(DebuggerError::InvalidArguments(format!(
+            "Function '{}' has no integer arguments to fuzz",
+            record.function
+        ))
+        .into());
+    }
+
+    let mut code = String::new();
+
+    // Imports
+    if storage.is_some() {
+        code.push_str("use soroban_debug_mock::{MockEnvBuilder, StorageDurability};\n");
+    }
+    code.push_str("use proptest::prelude::*;\n");
+    code.push_str("use soroban_sdk::xdr::{Limits, ReadXdr, ScErrorType, ScVal};\n");
+    code.push_str("use soroban_sdk::{Env, Error, IntoVal, Symbol, TryFromVal, Val, Vec};\n\n");
+
+    // Declared error codes
+    code.push_str("/// Error codes declared by the contract's `#[contracterror]` enums.\n");
+    code.push_str("const DECLARED_ERROR_CODES: &[u32] = &[\n");
+    for error in errors {
+        code.push_str(&format!("    {}, // {}\n", error.code, error.name));
+    }
+    code.push_str("];\n\n");
+
+    // Test signature
+    let strategies: Vec<String> = fuzzed
+        .iter()
+        .map(|(name, ty)| format!("{} in any::<{}>()", name, ty))
+        .collect();
+    code.push_str("proptest! {\n");
+    code.push_str("    #[test]\n");
+    code.push_str(&format!(
+        "    fn prop_{}_never_traps({}) {{\n",
+        record.function,
+        strategies.join(", ")
+    ));
+
+    // Environment setup
+    code.push_str("        let env = Env::default();\n");
+    code.push_str("        env.mock_all_auths();\n");
+    code.push_str(&format!(
+        "        let wasm = include_bytes!(\"{}\");\n",
+        wasm_file_name
+    ));
+    code.push_str("        let contract_id = env.register_contract_wasm(None, wasm);\n\n");
+
+    if let Some(storage) = storage {
+        push_storage_seed(&mut code, storage, "        ")?;
+    }
+
+    // Prepare arguments
+    code.push_str("        let mut args = Vec::<Val>::new(&env);\n");
+    for (param, arg) in signature.params.iter().zip(&record.args) {
+        if fuzzable_type(&param.type_name).is_some() {
+            code.push_str(&format!(
+                "        args.push_back({}.into_val(&env));\n",
+                param.name
+            ));
+        } else {
+            let base64 = arg.to_xdr_base64(Limits::none()).map_err(|e| {
+                DebuggerError::ExecutionError(format!("Failed to encode argument to XDR: {:?}", e))
+            })?;
+            code.push_str(&format!(
+                "        // {}: {} (held fixed from the recorded run)\n",
+                param.name, param.type_name
+            ));
+            code.push_str(&format!(
+                "        args.push_back(Val::try_from_val(&env, &ScVal::from_xdr_base64(\"{}\", Limits::none()).unwrap()).unwrap());\n",
+                base64
+            ));
+        }
+    }
+    code.push('\n');
+
+    // Invocation and assertion
+    code.push_str(&format!(
+        "        let result = env.try_invoke_contract::<Val, Error>(&contract_id, &Symbol::new(&env, \"{}\"), args);\n",
+        record.function
+    ));
+    code.push_str("        match result {\n");
+    code.push_str("            Ok(_) => {}\n");
+    code.push_str("            Err(Ok(error)) => prop_assert!(\n");
+    code.push_str("                error.is_type(ScErrorType::Contract)\n");
+    code.push_str("                    && DECLARED_ERROR_CODES.contains(&error.get_code()),\n");
+    code.push_str(&format!(
+        "                \"{} failed with an undeclared error: {{:?}}\",\n",
+        record.function
+    ));
+    code.push_str("                error\n");
+    code.push_str("            ),\n");
+    code.push_str(&format!(
+        "            Err(Err(error)) => prop_assert!(false, \"{} aborted: {{:?}}\", error),\n",
+        record.function
+    ));
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n");
+    Ok(code)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::output::InvocationReason;
-    use soroban_env_host::xdr::ScVal;
+    use soroban_env_host::xdr::ReadXdr;
     use std::collections::HashMap;
     use tempfile::tempdir;
 
@@ -152,6 +431,155 @@ mod tests {
         assert!(code.contains("assert_eq!(result, expected)"));
     }
 
+    #[test]
+    fn test_generate_code_with_initial_storage() {
+        let record = ExecutionRecord {
+            function: "get".to_string(),
+            invocation_reason: InvocationReason::Entrypoint,
+            args: vec![],
+            result: Ok(ScVal::I64(41)),
+            budget: crate::inspector::budget::BudgetInfo {
+                cpu_instructions: 0,
+                cpu_limit: 0,
+                memory_bytes: 0,
+                memory_limit: 0,
+            },
+            storage_before: HashMap::new(),
+            storage_after: HashMap::new(),
+        };
+        let code = TestGenerator::generate_with_storage(
+            &record,
+            Path::new("counter.wasm"),
+            Some(
+                r#"[
+                    {"key": "counter", "value": 41},
+                    {"key": {"type": "symbol", "value": "Owner"}, "value": {"type": "u128", "value": "7"}, "durability": "persistent"},
+                    {"key": {"type": "u32", "value": 9}, "value": true, "durability": "temporary"}
+                ]"#,
+            ),
+        )
+        .unwrap();
+
+        let set_call = |durability: &str, key: ScVal, value: ScVal| {
+            format!(
+                ".with_contract_entry(\n            &contract_id,\n            StorageDurability::{},\n            &Val::try_from_val(&env, &ScVal::from_xdr_base64(\"{}\", Limits::none()).unwrap()).unwrap(),\n            &Val::try_from_val(&env, &ScVal::from_xdr_base64(\"{}\", Limits::none()).unwrap()).unwrap(),\n        )",
+                durability,
+                key.to_xdr_base64(Limits::none()).unwrap(),
+                value.to_xdr_base64(Limits::none()).unwrap()
+            )
+        };
+        let symbol = |s: &str| ScVal::Symbol(s.try_into().unwrap());
+        let seeds = [
+            set_call("Instance", symbol("counter"), ScVal::I64(41)),
+            set_call(
+                "Persistent",
+                symbol("Owner"),
+                ScVal::U128(soroban_env_host::xdr::UInt128Parts { hi: 0, lo: 7 }),
+            ),
+            set_call("Temporary", ScVal::U32(9), ScVal::Bool(true)),
+        ];
+        let seeded = code
+            .find("let env = MockEnvBuilder::from_env(env)")
+            .expect("storage seeding");
+        for seed in &seeds {
+            let at = code
+                .find(seed.as_str())
+                .unwrap_or_else(|| panic!("{seed}\n{code}"));
+            assert!(seeded < at && at < code.find("invoke_contract").unwrap());
+        }
+        assert!(code.contains("use soroban_debug_mock::{MockEnvBuilder, StorageDurability};"));
+        assert!(code.contains("use soroban_sdk::xdr::{Limits, ReadXdr, ScVal};"));
+        syn::parse_file(&code).unwrap_or_else(|e| panic!("{e}\n{code}"));
+
+        let plain = TestGenerator::generate(&record, Path::new("counter.wasm")).unwrap();
+        assert!(!plain.contains("MockEnvBuilder"));
+        assert!(TestGenerator::generate_with_storage(
+            &record,
+            Path::new("counter.wasm"),
+            Some("{")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_generate_proptest_fuzzes_numeric_args() {
+        use crate::utils::wasm::FunctionParam;
+        use soroban_env_host::xdr::ScSymbol;
+
+        let record = ExecutionRecord {
+            function: "stake".to_string(),
+            invocation_reason: InvocationReason::Entrypoint,
+            args: vec![
+                ScVal::Symbol(ScSymbol("alice".try_into().unwrap())),
+                ScVal::U64(500),
+            ],
+            result: Ok(ScVal::Void),
+            budget: crate::inspector::budget::BudgetInfo {
+                cpu_instructions: 0,
+                cpu_limit: 0,
+                memory_bytes: 0,
+                memory_limit: 0,
+            },
+            storage_before: HashMap::new(),
+            storage_after: HashMap::new(),
+        };
+        let param = |name: &str, type_name: &str| FunctionParam {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+        };
+        let signature = ContractFunctionSignature {
+            name: "stake".to_string(),
+            params: vec![param("staker", "Address"), param("amount", "U64")],
+            return_type: Some("Result<Void, StakeError>".to_string()),
+        };
+        let errors = vec![CustomError {
+            code: 2,
+            name: "ZeroAmount".to_string(),
+            doc: String::new(),
+        }];
+
+        let code = proptest_code(&record, "staking.wasm", &signature, &errors, None).unwrap();
+
+        assert!(code.contains("fn prop_stake_never_traps(amount in any::<u64>())"));
+        assert!(code.contains("    2, // ZeroAmount\n"));
+        assert!(code.contains("args.push_back(amount.into_val(&env));"));
+        assert!(code.contains("// staker: Address (held fixed from the recorded run)"));
+        assert!(code.contains("try_invoke_contract::<Val, Error>"));
+        assert!(!code.contains("MockEnvBuilder"));
+
+        // The fixed argument decodes back to the recorded value.
+        let base64 = code
+            .split("ScVal::from_xdr_base64(\"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        assert!(code.contains(&format!(
+            "ScVal::from_xdr_base64(\"{base64}\", Limits::none())"
+        )));
+        assert_eq!(
+            ScVal::from_xdr_base64(base64, Limits::none()).unwrap(),
+            record.args[0]
+        );
+
+        // With the strategies written as plain parameters the whole file,
+        // imports and test body included, is valid Rust.
+        let plain = code
+            .replace("amount in any::<u64>()", "amount: u64")
+            .replace("proptest! {", "mod cases {");
+        syn::parse_file(&plain).unwrap_or_else(|e| panic!("{e}\n{plain}"));
+
+        let no_numeric = ContractFunctionSignature {
+            params: vec![param("staker", "Address"), param("memo", "String")],
+            ..signature.clone()
+        };
+        assert!(proptest_code(&record, "staking.wasm", &no_numeric, &errors, None).is_err());
+        let arity_mismatch = ContractFunctionSignature {
+            params: vec![param("amount", "U64")],
+            ..signature
+        };
+        assert!(proptest_code(&record, "staking.wasm", &arity_mismatch, &errors, None).is_err());
+    }
+
     #[test]
     fn test_write_overwrite() {
         let dir = tempdir().unwrap();
@@ -0,0 +1,275 @@
+//! Reproduction check against a real on-chain execution.
+//!
+//! Loads the expected return value and resource cost from a saved RPC response
+//! (`simulateTransaction` or `getTransaction`) and compares them with the result
+//! of a local simulation, so snapshot-based setups can be validated against the
+//! network they are meant to mirror.
+
+use crate::inspector::budget::BudgetInfo;
+use crate::{DebuggerError, Result};
+use serde::Serialize;
+use soroban_env_host::xdr::{Limits, ReadXdr, ScVal, TransactionMeta};
+use std::fs;
+use std::path::Path;
+
+/// Values reported by the chain for a single invocation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChainExpectation {
+    pub return_value: Option<ScVal>,
+    pub cpu_instructions: Option<u64>,
+    pub memory_bytes: Option<u64>,
+}
+
+impl ChainExpectation {
+    /// Load expectations from a saved RPC response JSON file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to read RPC response {:?}: {}", path, e))
+        })?;
+        let json: serde_json::Value = serde_json::from_str(&contents).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to parse RPC response {:?}: {}", path, e))
+        })?;
+        Self::from_rpc_json(&json)
+    }
+
+    /// Extract expectations from an RPC response.
+    ///
+    /// Accepts either a full JSON-RPC envelope or its bare `result` object. The return
+    /// value is read from `results[0].xdr` (simulateTransaction), `returnValue`, or the
+    /// Soroban meta inside `resultMetaXdr` (getTransaction). Cost is read from
+    /// `cost.cpuInsns` / `cost.memBytes`.
+    pub fn from_rpc_json(json: &serde_json::Value) -> Result<Self> {
+        if let Some(error) = json.get("error") {
+            return Err(DebuggerError::InvalidArguments(format!(
+                "RPC response contains an error: {}",
+                error
+            ))
+            .into());
+        }
+        let result = json.get("result").unwrap_or(json);
+
+        let return_value = if let Some(xdr) = result
+            .get("results")
+            .and_then(|r| r.get(0))
+            .and_then(|r| r.get("xdr"))
+            .and_then(|x| x.as_str())
+        {
+            Some(decode_scval(xdr)?)
+        } else if let Some(xdr) = result.get("returnValue").and_then(|x| x.as_str()) {
+            Some(decode_scval(xdr)?)
+        } else if let Some(xdr) = result.get("resultMetaXdr").and_then(|x| x.as_str()) {
+            return_value_from_meta(xdr)?
+        } else {
+            None
+        };
+
+        let cost = result.get("cost");
+        let cpu_instructions = cost.and_then(|c| parse_u64(c.get("cpuInsns")?));
+        let memory_bytes = cost.and_then(|c| parse_u64(c.get("memBytes")?));
+
+        if return_value.is_none() && cpu_instructions.is_none() && memory_bytes.is_none() {
+            return Err(DebuggerError::InvalidArguments(
+                "RPC response has no return value or cost to compare against".to_string(),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            return_value,
+            cpu_instructions,
+            memory_bytes,
+        })
+    }
+}
+
+/// Outcome of comparing one field between the local run and the chain.
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldComparison {
+    pub field: String,
+    pub local: String,
+    pub chain: String,
+    pub matches: bool,
+}
+
+/// Full reproduction report for `run --compare-with-chain`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainComparison {
+    pub matches: bool,
+    pub budget_tolerance_pct: f64,
+    pub fields: Vec<FieldComparison>,
+}
+
+impl ChainComparison {
+    /// Compare a local execution outcome with the chain's expectations.
+    ///
+    /// Budget fields match when the local value is within `tolerance_pct` percent of
+    /// the chain value; the return value must be identical.
+    pub fn compare(
+        expected: &ChainExpectation,
+        local_result: &std::result::Result<ScVal, String>,
+        local_budget: &BudgetInfo,
+        tolerance_pct: f64,
+    ) -> Self {
+        let mut fields = Vec::new();
+
+        if let Some(chain_value) = &expected.return_value {
+            let (local, matches) = match local_result {
+                Ok(value) => (format!("{:?}", value), value == chain_value),
+                Err(err) => (format!("error: {}", err), false),
+            };
+            fields.push(FieldComparison {
+                field: "return_value".to_string(),
+                local,
+                chain: format!("{:?}", chain_value),
+                matches,
+            });
+        }
+
+        let mut budget_field = |field: &str, local: u64, chain: Option<u64>| {
+            if let Some(chain) = chain {
+                fields.push(FieldComparison {
+                    field: field.to_string(),
+                    local: local.to_string(),
+                    chain: chain.to_string(),
+                    matches: within_tolerance(local, chain, tolerance_pct),
+                });
+            }
+        };
+        budget_field(
+            "cpu_instructions",
+            local_budget.cpu_instructions,
+            expected.cpu_instructions,
+        );
+        budget_field(
+            "memory_bytes",
+            local_budget.memory_bytes,
+            expected.memory_bytes,
+        );
+
+        Self {
+            matches: fields.iter().all(|f| f.matches),
+            budget_tolerance_pct: tolerance_pct,
+            fields,
+        }
+    }
+
+    /// Human-readable report highlighting divergences.
+    pub fn render(&self) -> String {
+        let mut out = String::from("Chain reproduction check:\n");
+        for field in &self.fields {
+            if field.matches {
+                out.push_str(&format!("  ✓ {}: {}\n", field.field, field.local));
+            } else {
+                out.push_str(&format!(
+                    "  ✗ {}: local {} != chain {}\n",
+                    field.field, field.local, field.chain
+                ));
+            }
+        }
+        if self.matches {
+            out.push_str("Local simulation reproduces the on-chain execution.\n");
+        } else {
+            out.push_str("Local simulation DIVERGES from the on-chain execution.\n");
+        }
+        out
+    }
+}
+
+fn within_tolerance(local: u64, chain: u64, tolerance_pct: f64) -> bool {
+    if local == chain {
+        return true;
+    }
+    if chain == 0 {
+        return false;
+    }
+    let delta = (local as f64 - chain as f64).abs();
+    delta / chain as f64 * 100.0 <= tolerance_pct
+}
+
+fn parse_u64(value: &serde_json::Value) -> Option<u64> {
+    match value {
+        serde_json::Value::String(s) => s.parse().ok(),
+        other => other.as_u64(),
+    }
+}
+
+fn decode_scval(xdr: &str) -> Result<ScVal> {
+    ScVal::from_xdr_base64(xdr, Limits::none()).map_err(|e| {
+        DebuggerError::InvalidArguments(format!("Invalid ScVal XDR in RPC response: {}", e)).into()
+    })
+}
+
+fn return_value_from_meta(xdr: &str) -> Result<Option<ScVal>> {
+    let meta = TransactionMeta::from_xdr_base64(xdr, Limits::none()).map_err(|e| {
+        DebuggerError::InvalidArguments(format!("Invalid resultMetaXdr in RPC response: {}", e))
+    })?;
+    Ok(match meta {
+        TransactionMeta::V3(v3) => v3.soroban_meta.map(|m| m.return_value),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_env_host::xdr::WriteXdr;
+
+    fn budget(cpu: u64, mem: u64) -> BudgetInfo {
+        BudgetInfo {
+            cpu_instructions: cpu,
+            cpu_limit: 0,
+            memory_bytes: mem,
+            memory_limit: 0,
+        }
+    }
+
+    #[test]
+    fn parses_simulate_transaction_response() {
+        let xdr = ScVal::U32(7).to_xdr_base64(Limits::none()).unwrap();
+        let json = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "results": [{ "xdr": xdr, "auth": [] }],
+                "cost": { "cpuInsns": "1200", "memBytes": "3400" }
+            }
+        });
+
+        let expected = ChainExpectation::from_rpc_json(&json).unwrap();
+        assert_eq!(expected.return_value, Some(ScVal::U32(7)));
+        assert_eq!(expected.cpu_instructions, Some(1200));
+        assert_eq!(expected.memory_bytes, Some(3400));
+    }
+
+    #[test]
+    fn reports_divergences_outside_tolerance() {
+        let expected = ChainExpectation {
+            return_value: Some(ScVal::U32(7)),
+            cpu_instructions: Some(1000),
+            memory_bytes: Some(2000),
+        };
+
+        let report =
+            ChainComparison::compare(&expected, &Ok(ScVal::U32(7)), &budget(1040, 2600), 5.0);
+        assert!(!report.matches);
+        let diverged: Vec<&str> = report
+            .fields
+            .iter()
+            .filter(|f| !f.matches)
+            .map(|f| f.field.as_str())
+            .collect();
+        assert_eq!(diverged, vec!["memory_bytes"]);
+
+        let report =
+            ChainComparison::compare(&expected, &Ok(ScVal::U32(8)), &budget(1000, 2000), 0.0);
+        assert!(!report.matches);
+        assert!(report.render().contains("✗ return_value"));
+    }
+
+    #[test]
+    fn rejects_responses_without_comparable_fields() {
+        let json = serde_json::json!({ "result": { "latestLedger": 42 } });
+        assert!(ChainExpectation::from_rpc_json(&json).is_err());
+    }
+}
@@ -3,6 +3,7 @@
 //! execution flow differences.
 
 use super::trace::{BudgetTrace, CallEntry, EventEntry, ExecutionTrace};
+use crate::ui::formatter::Formatter;
 use std::collections::{BTreeMap, BTreeSet};
 
 // ─── Diff types ──────────────────────────────────────────────────────
@@ -69,6 +70,14 @@ pub struct EventDiff {
     pub b_events: Vec<EventEntry>,
     pub filtered_a_events: Vec<serde_json::Value>,
     pub filtered_b_events: Vec<serde_json::Value>,
+    /// LCS alignment of the two event sequences (text representation)
+    pub diff_lines: Vec<DiffLine>,
+    /// Events emitted by A that B never emits
+    pub missing: Vec<String>,
+    /// Events emitted by B that A never emits
+    pub extra: Vec<String>,
+    /// Events emitted by both traces but at a different position in the sequence
+    pub reordered: Vec<String>,
     pub identical: bool,
 }
 
@@ -352,15 +361,52 @@ impl CompareEngine {
             .filter_map(|entry| Self::normalize_event_entry(entry, filters))
             .collect();
         let identical = filtered_a_events == filtered_b_events;
+        let rendered_a: Vec<String> = filtered_a_events.iter().map(|e| e.to_string()).collect();
+        let rendered_b: Vec<String> = filtered_b_events.iter().map(|e| e.to_string()).collect();
+        let diff_lines = Self::compute_lcs_diff(&rendered_a, &rendered_b);
+        let (missing, extra, reordered) = Self::classify_event_changes(&diff_lines);
+
         EventDiff {
             a_events: a.to_vec(),
             b_events: b.to_vec(),
             filtered_a_events,
             filtered_b_events,
+            diff_lines,
+            missing,
+            extra,
+            reordered,
             identical,
         }
     }
 
+    /// Split the unaligned lines of an event diff into missing, extra and
+    /// reordered events. An event dropped from one position of A and added at
+    /// another position of B was moved rather than removed, so each such pair
+    /// counts once as reordered.
+    fn classify_event_changes(lines: &[DiffLine]) -> (Vec<String>, Vec<String>, Vec<String>) {
+        let mut extra: Vec<String> = lines
+            .iter()
+            .filter_map(|line| match line {
+                DiffLine::OnlyB(s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect();
+        let mut missing = Vec::new();
+        let mut reordered = Vec::new();
+
+        for line in lines {
+            if let DiffLine::OnlyA(s) = line {
+                if let Some(pos) = extra.iter().position(|e| e == s) {
+                    reordered.push(extra.remove(pos));
+                } else {
+                    missing.push(s.clone());
+                }
+            }
+        }
+
+        (missing, extra, reordered)
+    }
+
     fn normalize_storage_map(
         storage: &BTreeMap<String, serde_json::Value>,
         filters: &CompareFilters,
@@ -537,15 +583,15 @@ impl CompareEngine {
                 out.push_str(&format!(
                     "  {:>28}  {:>14}  {:>14}  {:>+14}\n",
                     "CPU instructions",
-                    a.cpu_instructions,
-                    b.cpu_instructions,
+                    Formatter::format_number(a.cpu_instructions),
+                    Formatter::format_number(b.cpu_instructions),
                     bd.cpu_delta.unwrap_or(0)
                 ));
                 out.push_str(&format!(
                     "  {:>28}  {:>14}  {:>14}  {:>+14}\n",
                     "Memory (bytes)",
-                    a.memory_bytes,
-                    b.memory_bytes,
+                    Formatter::format_number(a.memory_bytes),
+                    Formatter::format_number(b.memory_bytes),
                     bd.memory_delta.unwrap_or(0)
                 ));
 
@@ -566,13 +612,15 @@ impl CompareEngine {
             (Some(a), None) => {
                 out.push_str(&format!(
                     "  A: CPU={}, Mem={}\n  B: (no budget data)\n",
-                    a.cpu_instructions, a.memory_bytes
+                    Formatter::format_number(a.cpu_instructions),
+                    Formatter::format_number(a.memory_bytes)
                 ));
             }
             (None, Some(b)) => {
                 out.push_str(&format!(
                     "  A: (no budget data)\n  B: CPU={}, Mem={}\n",
-                    b.cpu_instructions, b.memory_bytes
+                    Formatter::format_number(b.cpu_instructions),
+                    Formatter::format_number(b.memory_bytes)
                 ));
             }
         }
@@ -641,14 +689,26 @@ impl CompareEngine {
                 ed.filtered_b_events.len()
             ));
 
-            out.push_str("  Events in A:\n");
-            for (i, ev) in ed.filtered_a_events.iter().enumerate() {
-                out.push_str(&format!("    [{}] {}\n", i, ev));
+            out.push_str("  Sequence diff (- = only in A, + = only in B):\n\n");
+            for line in &ed.diff_lines {
+                match line {
+                    DiffLine::Same(s) => out.push_str(&format!("    {}\n", s)),
+                    DiffLine::OnlyA(s) => out.push_str(&format!("  - {}\n", s)),
+                    DiffLine::OnlyB(s) => out.push_str(&format!("  + {}\n", s)),
+                }
             }
 
-            out.push_str("\n  Events in B:\n");
-            for (i, ev) in ed.filtered_b_events.iter().enumerate() {
-                out.push_str(&format!("    [{}] {}\n", i, ev));
+            for (title, events) in [
+                ("Missing in B", &ed.missing),
+                ("Extra in B", &ed.extra),
+                ("Reordered", &ed.reordered),
+            ] {
+                if !events.is_empty() {
+                    out.push_str(&format!("\n  {} ({}):\n", title, events.len()));
+                    for ev in events {
+                        out.push_str(&format!("    {}\n", ev));
+                    }
+                }
             }
         }
 
@@ -863,6 +923,66 @@ mod tests {
         assert!(!report.event_diff.identical);
     }
 
+    #[test]
+    fn test_event_diff_flags_extra_event() {
+        let a = make_trace_a();
+        let b = make_trace_b();
+        let report = CompareEngine::compare(&a, &b);
+
+        assert_eq!(report.event_diff.extra.len(), 1);
+        assert!(report.event_diff.extra[0].contains("fee"));
+        assert!(report.event_diff.missing.is_empty());
+        assert!(report.event_diff.reordered.is_empty());
+    }
+
+    fn multisig_event(topic: &str, data: &str) -> EventEntry {
+        EventEntry {
+            contract_id: Some("MULTISIG01".to_string()),
+            topics: vec![topic.to_string()],
+            data: Some(data.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_event_diff_detects_swapped_approvals_as_reorder() {
+        let mut a = make_trace_a();
+        a.events = vec![
+            multisig_event("propose", "1"),
+            multisig_event("approve", "signer1"),
+            multisig_event("approve", "signer2"),
+            multisig_event("execute", "1"),
+        ];
+        let mut b = a.clone();
+        b.events.swap(1, 2);
+
+        let report = CompareEngine::compare(&a, &b);
+        let ed = &report.event_diff;
+
+        assert!(!ed.identical);
+        assert_eq!(ed.reordered.len(), 1);
+        assert!(ed.reordered[0].contains("approve"));
+        assert!(ed.missing.is_empty());
+        assert!(ed.extra.is_empty());
+    }
+
+    #[test]
+    fn test_event_diff_flags_missing_event() {
+        let mut a = make_trace_a();
+        a.events = vec![
+            multisig_event("propose", "1"),
+            multisig_event("approve", "signer1"),
+            multisig_event("execute", "1"),
+        ];
+        let mut b = a.clone();
+        b.events.remove(2);
+
+        let report = CompareEngine::compare(&a, &b);
+
+        assert_eq!(report.event_diff.missing.len(), 1);
+        assert!(report.event_diff.missing[0].contains("execute"));
+        assert!(report.event_diff.reordered.is_empty());
+    }
+
     #[test]
     fn test_render_report_no_panic() {
         let a = make_trace_a();
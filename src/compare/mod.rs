@@ -1,5 +1,7 @@
+pub mod chain;
 pub mod engine;
 pub mod trace;
 
+pub use chain::{ChainComparison, ChainExpectation};
 pub use engine::{CompareEngine, ComparisonReport};
 pub use trace::ExecutionTrace;
@@ -1,4 +1,6 @@
+use crate::inspector::budget::NetworkLimits;
 use crate::{DebuggerError, Result};
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
@@ -13,6 +15,14 @@ pub struct Config {
     pub debug: DebugConfig,
     #[serde(default)]
     pub output: OutputConfig,
+    #[serde(default)]
+    pub history: HistoryConfig,
+    #[serde(default)]
+    pub repl: ReplConfig,
+    #[serde(default)]
+    pub budget_limits: BudgetLimitsConfig,
+    #[serde(default)]
+    pub theme: ThemeConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -43,6 +53,91 @@ pub struct OutputConfig {
     pub suppressions_file: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct HistoryConfig {
+    /// Percent CPU change that the budget trend reports as a regression
+    #[serde(default)]
+    pub cpu_regression_threshold: Option<f64>,
+    /// Percent memory change that the budget trend reports as a regression
+    #[serde(default)]
+    pub mem_regression_threshold: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ReplConfig {
+    /// Persist REPL command history across sessions (default true)
+    #[serde(default)]
+    pub save_history: Option<bool>,
+    /// REPL history file (default `~/.soroban-debug/repl_history`)
+    #[serde(default)]
+    pub history_file: Option<String>,
+    /// Maximum number of commands kept in the history file (default 1000)
+    #[serde(default)]
+    pub history_size: Option<usize>,
+}
+
+/// Network whose per-transaction limits the budget output is measured against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkLimitsProfile {
+    #[default]
+    Testnet,
+    Mainnet,
+    Custom,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BudgetLimitsConfig {
+    /// Profile used when `--network-limits` is not given (default testnet)
+    #[serde(default)]
+    pub profile: Option<NetworkLimitsProfile>,
+    /// Overrides for the testnet limits
+    #[serde(default)]
+    pub testnet: Option<NetworkLimits>,
+    /// Overrides for the mainnet limits
+    #[serde(default)]
+    pub mainnet: Option<NetworkLimits>,
+    /// Limits for the custom profile; required when it is selected
+    #[serde(default)]
+    pub custom: Option<NetworkLimits>,
+}
+
+impl BudgetLimitsConfig {
+    /// Resolve the limits for `profile`, falling back to the configured
+    /// profile and then to testnet.
+    pub fn resolve(&self, profile: Option<NetworkLimitsProfile>) -> Result<NetworkLimits> {
+        match profile.or(self.profile).unwrap_or_default() {
+            NetworkLimitsProfile::Testnet => Ok(self.testnet.unwrap_or_default()),
+            NetworkLimitsProfile::Mainnet => Ok(self.mainnet.unwrap_or_default()),
+            NetworkLimitsProfile::Custom => self.custom.ok_or_else(|| {
+                DebuggerError::InvalidArguments(format!(
+                    "The custom network limits profile needs a [budget_limits.custom] table in {}",
+                    DEFAULT_CONFIG_FILE
+                ))
+                .into()
+            }),
+        }
+    }
+}
+
+/// Built-in palette for the TUI dashboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    /// No RGB colors, for limited terminals and accessibility
+    Mono,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ThemeConfig {
+    /// Dashboard palette used when `--theme` is not given (default dark)
+    #[serde(default)]
+    pub name: Option<ThemeName>,
+}
+
 impl Config {
     /// Load configuration from a file in the project root
     pub fn load() -> Result<Self> {
@@ -69,6 +164,47 @@ impl Config {
         Ok(config)
     }
 
+    /// Record `theme` as `[theme] name` in the config file in the project root
+    pub fn save_theme(theme: ThemeName) -> Result<()> {
+        Self::save_theme_to(Path::new(DEFAULT_CONFIG_FILE), theme)
+    }
+
+    /// Record `theme` in the config file at `path`, creating the file if needed.
+    /// The rest of the file, including comments and formatting, is kept.
+    pub fn save_theme_to(path: &Path, theme: ThemeName) -> Result<()> {
+        let mut doc = if path.exists() {
+            let content = fs::read_to_string(path).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to read config file {:?}: {}", path, e))
+            })?;
+            content.parse::<toml_edit::DocumentMut>().map_err(|e| {
+                DebuggerError::FileError(format!(
+                    "Failed to parse TOML config from {:?}: {}",
+                    path, e
+                ))
+            })?
+        } else {
+            toml_edit::DocumentMut::new()
+        };
+
+        let section = doc.entry("theme").or_insert_with(toml_edit::table);
+        let Some(section) = section.as_table_like_mut() else {
+            return Err(DebuggerError::FileError(format!(
+                "Config file {:?} has a `theme` key that is not a table",
+                path
+            ))
+            .into());
+        };
+        let name = theme
+            .to_possible_value()
+            .expect("theme names are never skipped");
+        section.insert("name", toml_edit::value(name.get_name()));
+
+        fs::write(path, doc.to_string()).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to write config file {:?}: {}", path, e))
+        })?;
+        Ok(())
+    }
+
     /// Load default config if file is missing, otherwise return error on parse failure
     pub fn load_or_default() -> Self {
         match Self::load() {
@@ -80,3 +216,56 @@ impl Config {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_limits_resolve_profiles() {
+        let config: Config = toml::from_str(
+            r#"
+            [budget_limits]
+            profile = "mainnet"
+
+            [budget_limits.mainnet]
+            cpu_limit = 200000000
+            "#,
+        )
+        .unwrap();
+        let limits = &config.budget_limits;
+
+        let mainnet = limits.resolve(None).unwrap();
+        assert_eq!(mainnet.cpu_limit, 200_000_000);
+        assert_eq!(mainnet.memory_limit, NetworkLimits::DEFAULT_MEMORY_LIMIT);
+        assert_eq!(
+            limits.resolve(Some(NetworkLimitsProfile::Testnet)).unwrap(),
+            NetworkLimits::default()
+        );
+        assert!(limits
+            .resolve(Some(NetworkLimitsProfile::Custom))
+            .unwrap_err()
+            .to_string()
+            .contains("[budget_limits.custom]"));
+    }
+
+    #[test]
+    fn test_save_theme_keeps_other_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_CONFIG_FILE);
+        fs::write(
+            &path,
+            "# project settings\n[output]\nshow_events = true # always\n",
+        )
+        .unwrap();
+
+        Config::save_theme_to(&path, ThemeName::Light).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# project settings\n"), "{content}");
+        assert!(content.contains("show_events = true # always"), "{content}");
+        let config: Config = toml::from_str(&content).unwrap();
+        assert_eq!(config.theme.name, Some(ThemeName::Light));
+        assert_eq!(config.output.show_events, Some(true));
+    }
+}
@@ -16,6 +16,10 @@ pub struct Breakpoint {
     pub log_message: Option<String>,
     /// Number of times this breakpoint has been hit
     pub hit_count: usize,
+    /// Parameter names of the function, cached when the breakpoint is set so
+    /// argument conditions don't re-read the contract spec on every hit
+    #[serde(default)]
+    pub params: Vec<String>,
 }
 
 impl Breakpoint {
@@ -28,6 +32,7 @@ impl Breakpoint {
             hit_condition: None,
             log_message: None,
             hit_count: 0,
+            params: Vec::new(),
         }
     }
 
@@ -40,6 +45,7 @@ impl Breakpoint {
             hit_condition: None,
             log_message: None,
             hit_count: 0,
+            params: Vec::new(),
         }
     }
 
@@ -52,6 +58,7 @@ impl Breakpoint {
             hit_condition: Some(hit_condition),
             log_message: None,
             hit_count: 0,
+            params: Vec::new(),
         }
     }
 
@@ -64,6 +71,7 @@ impl Breakpoint {
             hit_condition: None,
             log_message: Some(log_message),
             hit_count: 0,
+            params: Vec::new(),
         }
     }
 
@@ -143,9 +151,25 @@ impl BreakpointManager {
             hit_condition: spec.hit_condition,
             log_message: spec.log_message,
             hit_count: 0,
+            params: Vec::new(),
         });
     }
 
+    /// Every breakpoint as a spec, e.g. to set the same breakpoints on a
+    /// fresh engine.
+    pub fn specs(&self) -> Vec<BreakpointSpec> {
+        self.breakpoints
+            .values()
+            .map(|bp| BreakpointSpec {
+                id: bp.id.clone(),
+                function: bp.function.clone(),
+                condition: bp.condition.clone(),
+                hit_condition: bp.hit_condition.clone(),
+                log_message: bp.log_message.clone(),
+            })
+            .collect()
+    }
+
     /// Remove a breakpoint
     pub fn remove(&mut self, function: &str) -> bool {
         self.remove_breakpoint(function).is_some()
@@ -223,6 +247,26 @@ impl BreakpointManager {
         Ok((true, None))
     }
 
+    /// Count a hit on the breakpoint on `function` when it is reached without
+    /// call arguments, e.g. by stepping into it, and return whether to pause.
+    /// Only the hit condition is checked; expression conditions need the call's
+    /// arguments, and log points never pause.
+    pub fn record_hit(&mut self, function: &str) -> crate::Result<bool> {
+        let Some(bp) = self.breakpoints.get_mut(function) else {
+            return Ok(false);
+        };
+
+        bp.increment_hit();
+
+        if let Some(hit_cond) = &bp.hit_condition {
+            if !evaluate_hit_condition(hit_cond, bp.hit_count)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(!bp.is_log_point())
+    }
+
     /// Simplified check for backward compatibility
     pub fn should_break(&self, function: &str) -> bool {
         self.breakpoints.contains_key(function)
@@ -323,6 +367,95 @@ impl BreakpointManager {
         Ok(s.to_string())
     }
 
+    /// Split a CLI breakpoint such as `transfer when amount > 1000` into the
+    /// function name and the optional condition. Modifiers such as `ignore:N`
+    /// are dropped; see [`Self::parse_ignore_count`].
+    pub fn parse_breakpoint(spec: &str) -> (String, Option<String>) {
+        let (head, condition) = match spec.split_once(" when ") {
+            Some((head, condition)) => (head, Some(condition.trim().to_string())),
+            None => (spec, None),
+        };
+        let function = head.split_whitespace().next().unwrap_or_default();
+        (function.to_string(), condition)
+    }
+
+    /// Parse the `ignore:N` modifier of a CLI breakpoint such as
+    /// `heavy ignore:50`, which skips the first N hits.
+    pub fn parse_ignore_count(spec: &str) -> crate::Result<Option<usize>> {
+        let head = spec.split_once(" when ").map_or(spec, |(head, _)| head);
+        let mut ignore = None;
+        for modifier in head.split_whitespace().skip(1) {
+            let count = modifier
+                .strip_prefix("ignore:")
+                .and_then(|n| n.parse::<usize>().ok())
+                .ok_or_else(|| {
+                    crate::DebuggerError::BreakpointError(format!(
+                        "Invalid breakpoint modifier '{}': expected ignore:N",
+                        modifier
+                    ))
+                })?;
+            ignore = Some(count);
+        }
+        Ok(ignore)
+    }
+
+    /// Number of times the breakpoint on `function` has been reached, including
+    /// hits skipped by `ignore:N` or a false condition. Zero if none is set.
+    pub fn hit_count(&self, function: &str) -> usize {
+        self.breakpoints.get(function).map_or(0, |bp| bp.hit_count)
+    }
+
+    /// Reset every breakpoint's hit count, e.g. before a new run.
+    pub fn reset_hit_counts(&mut self) {
+        for bp in self.breakpoints.values_mut() {
+            bp.hit_count = 0;
+        }
+    }
+
+    /// Add a CLI breakpoint, validating any `when` clause against the names of
+    /// the function's parameters.
+    ///
+    /// Operands may be a parameter name, a positional `argN`, or an integer
+    /// literal. Anything else is rejected here rather than never firing. An
+    /// `ignore:N` modifier becomes the hit condition `>N`.
+    pub fn add_with_params(&mut self, spec: &str, params: &[String]) -> crate::Result<()> {
+        let (function, condition) = Self::parse_breakpoint(spec);
+        let hit_condition = Self::parse_ignore_count(spec)?.map(|n| format!(">{}", n));
+        let Some(condition) = condition else {
+            self.set(Breakpoint {
+                hit_condition,
+                params: params.to_vec(),
+                ..Breakpoint::simple(function)
+            });
+            return Ok(());
+        };
+
+        let condition = Self::parse_condition(&condition)?;
+        let (_, lhs, rhs) = split_condition(&condition)?;
+        for operand in [lhs, rhs] {
+            if parse_integer(operand).is_none() && argument_position(operand, params).is_none() {
+                return Err(crate::DebuggerError::BreakpointError(format!(
+                    "Unknown name '{}' in breakpoint condition for '{}' (parameters: {})",
+                    operand,
+                    function,
+                    if params.is_empty() {
+                        "none".to_string()
+                    } else {
+                        params.join(", ")
+                    }
+                ))
+                .into());
+            }
+        }
+
+        self.set(Breakpoint {
+            hit_condition,
+            params: params.to_vec(),
+            ..Breakpoint::with_condition(function, condition)
+        });
+        Ok(())
+    }
+
     /// Parse a hit condition string
     pub fn parse_hit_condition(s: &str) -> crate::Result<String> {
         let s = s.trim();
@@ -355,6 +488,121 @@ pub trait ConditionEvaluator {
     fn interpolate_log(&self, template: &str) -> crate::Result<String>;
 }
 
+/// Evaluates conditions over a call's decoded integer arguments.
+///
+/// Arguments are addressable by parameter name and by position (`arg0`, `arg1`,
+/// ...). Plain JSON numbers, numeric strings, and typed annotations such as
+/// `{"type": "i128", "value": 5}` are read as integers.
+#[derive(Debug, Clone, Default)]
+pub struct ArgumentEvaluator {
+    params: Vec<String>,
+    values: Vec<Option<i128>>,
+}
+
+impl ArgumentEvaluator {
+    pub fn new(params: &[String], args_json: Option<&str>) -> Self {
+        let values = args_json
+            .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok())
+            .map(|value| match value {
+                serde_json::Value::Array(items) => items,
+                other => vec![other],
+            })
+            .unwrap_or_default()
+            .iter()
+            .map(json_integer)
+            .collect();
+        Self {
+            params: params.to_vec(),
+            values,
+        }
+    }
+
+    /// Whether every operand of `condition` is an argument or integer literal.
+    pub fn can_evaluate(&self, condition: &str) -> bool {
+        split_condition(condition).is_ok_and(|(_, lhs, rhs)| {
+            [lhs, rhs].into_iter().all(|operand| {
+                parse_integer(operand).is_some()
+                    || argument_position(operand, &self.params).is_some()
+            })
+        })
+    }
+
+    fn operand(&self, operand: &str) -> crate::Result<i128> {
+        if let Some(n) = parse_integer(operand) {
+            return Ok(n);
+        }
+        argument_position(operand, &self.params)
+            .and_then(|index| self.values.get(index).copied().flatten())
+            .ok_or_else(|| {
+                crate::DebuggerError::BreakpointError(format!(
+                    "Argument '{}' is missing or not an integer",
+                    operand
+                ))
+                .into()
+            })
+    }
+}
+
+impl ConditionEvaluator for ArgumentEvaluator {
+    fn evaluate(&self, condition: &str) -> crate::Result<bool> {
+        let (op, lhs, rhs) = split_condition(condition)?;
+        let (lhs, rhs) = (self.operand(lhs)?, self.operand(rhs)?);
+        Ok(match op {
+            "==" => lhs == rhs,
+            "!=" => lhs != rhs,
+            ">" => lhs > rhs,
+            "<" => lhs < rhs,
+            ">=" => lhs >= rhs,
+            _ => lhs <= rhs,
+        })
+    }
+
+    fn interpolate_log(&self, template: &str) -> crate::Result<String> {
+        Ok(template.to_string())
+    }
+}
+
+/// Split `lhs op rhs` into its operator and trimmed operands.
+fn split_condition(condition: &str) -> crate::Result<(&'static str, &str, &str)> {
+    let condition = condition.trim();
+    let (op, pos) = find_operator(condition).ok_or_else(|| {
+        crate::DebuggerError::BreakpointError(format!(
+            "No operator found in condition: {}",
+            condition
+        ))
+    })?;
+    Ok((
+        op,
+        condition[..pos].trim(),
+        condition[pos + op.len()..].trim(),
+    ))
+}
+
+fn parse_integer(operand: &str) -> Option<i128> {
+    operand.parse::<i128>().ok()
+}
+
+/// Index of the argument named by `operand`: a parameter name or `argN`.
+fn argument_position(operand: &str, params: &[String]) -> Option<usize> {
+    if let Some(index) = params.iter().position(|p| p == operand) {
+        return Some(index);
+    }
+    let index = operand.strip_prefix("arg")?.parse::<usize>().ok()?;
+    (index < params.len()).then_some(index)
+}
+
+fn json_integer(value: &serde_json::Value) -> Option<i128> {
+    match value {
+        serde_json::Value::Number(n) => n
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from)),
+        serde_json::Value::String(s) => s.parse().ok(),
+        serde_json::Value::Object(obj) => obj.get("value").and_then(json_integer),
+        _ => None,
+    }
+}
+
 fn interpolate_log_message(
     template: &str,
     function: &str,
@@ -965,10 +1213,7 @@ mod tests {
         let mut evaluator = MockEvaluator::new();
         evaluator.set("balance", 1500);
 
-        let bp = Breakpoint::log_point(
-            "transfer".to_string(),
-            "Transfer executed".to_string(),
-        );
+        let bp = Breakpoint::log_point("transfer".to_string(), "Transfer executed".to_string());
         manager.set(bp);
 
         let (should_break, log) = manager
@@ -997,9 +1242,117 @@ mod tests {
             .unwrap();
 
         assert!(!should_break, "Log points should not pause execution");
+        assert_eq!(log, Some("Transfer 100 from balance 1500".to_string()));
+    }
+
+    fn params(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_when_clause_is_validated_against_parameters() {
+        let params = params(&["from", "to", "amount"]);
+        let mut manager = BreakpointManager::new();
+
+        manager
+            .add_with_params("transfer when amount > 1000", &params)
+            .unwrap();
+        let bp = manager.get("transfer").unwrap();
+        assert_eq!(bp.condition.as_deref(), Some("amount > 1000"));
+        assert_eq!(bp.params, params);
+
+        manager
+            .add_with_params("mint when arg2 != 0", &params)
+            .unwrap();
+        manager.add_with_params("burn", &params).unwrap();
+        assert!(manager.get("burn").unwrap().condition.is_none());
+
+        assert!(manager
+            .add_with_params("transfer when amout > 1000", &params)
+            .is_err());
+        assert!(manager
+            .add_with_params("transfer when arg3 > 1", &params)
+            .is_err());
+    }
+
+    #[test]
+    fn test_argument_evaluator_compares_integer_arguments() {
+        let params = params(&["from", "to", "amount"]);
+        let args = r#"["GA", "GB", {"type": "i128", "value": 1500}]"#;
+        let evaluator = ArgumentEvaluator::new(&params, Some(args));
+
+        assert!(evaluator.evaluate("amount > 1000").unwrap());
+        assert!(evaluator.evaluate("arg2 == 1500").unwrap());
+        assert!(!evaluator.evaluate("amount <= 1000").unwrap());
+        assert!(evaluator.evaluate("from == 1").is_err());
+        assert!(evaluator.can_evaluate("1000 < amount"));
+        assert!(!evaluator.can_evaluate("balance > 1000"));
+
+        let mut manager = BreakpointManager::new();
+        manager
+            .add_with_params("transfer when amount > 2000", &params)
+            .unwrap();
+        let (should_break, _) = manager
+            .should_break_with_context("transfer", &evaluator)
+            .unwrap();
+        assert!(!should_break);
+    }
+
+    #[test]
+    fn test_specs_carry_conditions_to_a_new_manager() {
+        let mut manager = BreakpointManager::new();
+        manager
+            .add_with_params("transfer when amount > 1000", &params(&["amount"]))
+            .unwrap();
+        manager.add_with_params("heavy ignore:2", &[]).unwrap();
+
+        let mut copy = BreakpointManager::new();
+        for spec in manager.specs() {
+            copy.add_spec(spec);
+        }
         assert_eq!(
-            log,
-            Some("Transfer 100 from balance 1500".to_string())
+            copy.get("transfer").unwrap().condition.as_deref(),
+            Some("amount > 1000")
         );
+        assert_eq!(
+            copy.get("heavy").unwrap().hit_condition.as_deref(),
+            Some(">2")
+        );
+    }
+
+    #[test]
+    fn test_ignore_modifier_skips_first_hits() {
+        let mut manager = BreakpointManager::new();
+        manager.add_with_params("heavy ignore:2", &[]).unwrap();
+        assert_eq!(
+            BreakpointManager::parse_breakpoint("heavy ignore:2").0,
+            "heavy"
+        );
+
+        let evaluator = MockEvaluator::new();
+        let pauses: Vec<bool> = (0..3)
+            .map(|_| {
+                manager
+                    .should_break_with_context("heavy", &evaluator)
+                    .unwrap()
+                    .0
+            })
+            .collect();
+        assert_eq!(pauses, vec![false, false, true]);
+        assert_eq!(manager.hit_count("heavy"), 3);
+        assert_eq!(manager.hit_count("missing"), 0);
+
+        manager.reset_hit_counts();
+        assert_eq!(manager.hit_count("heavy"), 0);
+
+        let stepped: Vec<bool> = (0..3)
+            .map(|_| manager.record_hit("heavy").unwrap())
+            .collect();
+        assert_eq!(stepped, vec![false, false, true]);
+        assert_eq!(manager.hit_count("heavy"), 3);
+        assert!(!manager.record_hit("missing").unwrap());
+
+        assert!(manager.add_with_params("heavy skip:2", &[]).is_err());
+        assert!(manager.add_with_params("heavy ignore:x", &[]).is_err());
     }
 }
@@ -1,24 +1,88 @@
-use crate::debugger::breakpoint::{BreakpointManager, BreakpointSpec};
-use crate::debugger::breakpoint::{BreakpointManager, ConditionEvaluator};
+use crate::debugger::breakpoint::{
+    ArgumentEvaluator, BreakpointManager, BreakpointSpec, ConditionEvaluator,
+};
 use crate::debugger::instruction_pointer::StepMode;
 use crate::debugger::source_map::{SourceLocation, SourceMap};
-use crate::debugger::state::{DebugState, PauseReason};
-use crate::debugger::stepper::Stepper;
+use crate::debugger::state::{DebugState, HistoryStep, PauseReason};
+use crate::debugger::stepper::{RunToPc, Stepper};
+use crate::inspector::auth::AuthNode;
+use crate::inspector::budget::{BudgetInfo, BudgetInspector};
+use crate::inspector::events::ContractEvent;
+use crate::inspector::storage::{FilterPattern, StorageDiff, StorageInspector};
 use crate::output::InvocationReason;
-use crate::plugin::{EventContext, ExecutionEvent};
-use crate::runtime::executor::ContractExecutor;
+use crate::plugin::{EventContext, ExecutionEvent, StorageOperation};
+use crate::runtime::executor::{ContractExecutor, StorageSnapshot};
 use crate::runtime::instruction::Instruction;
+use crate::runtime::env::StorageAccessType;
 use crate::runtime::instrumentation::Instrumenter;
 use crate::Result;
 use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender, TryIter};
 use std::sync::{Arc, Mutex};
 use tracing::info;
 
+/// CPU instructions the host charges per unit of wasmi fuel: the constant
+/// term of `ContractCostType::WasmInsnExec` in soroban-env-host's default cost
+/// parameters. The host converts the remaining CPU budget into fuel at this
+/// rate, and most WASM instructions cost one unit of fuel.
+const CPU_PER_WASM_INSTRUCTION: u64 = 4;
+
+/// CPU left for instantiation and host functions when a call's budget is
+/// capped by `max_steps`.
+const MAX_STEPS_HOST_CPU_ALLOWANCE: u64 = 10_000_000;
+
 pub struct StepOverResult {
     pub paused: bool,
     pub location: Option<SourceLocation>,
 }
 
+/// Execution events delivered to an embedding application, in the order the
+/// engine emits them.
+///
+/// Iterating blocks until the next event arrives and ends once the engine is
+/// dropped, so drive the engine from another thread or use [`EventStream::try_iter`]
+/// to drain what is already queued.
+pub struct EventStream {
+    receiver: Receiver<ExecutionEvent>,
+}
+
+impl EventStream {
+    /// Events already emitted, without waiting for more.
+    pub fn try_iter(&self) -> TryIter<'_, ExecutionEvent> {
+        self.receiver.try_iter()
+    }
+}
+
+impl Iterator for EventStream {
+    type Item = ExecutionEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok()
+    }
+}
+
+/// Everything one [`DebuggerEngine::run_once`] call produced.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ExecutionSummary {
+    pub function: String,
+    /// The formatted return value.
+    pub result: String,
+    pub storage_diff: StorageDiff,
+    /// Events emitted by this call only.
+    pub events: Vec<ContractEvent>,
+    pub auth_tree: Vec<AuthNode>,
+    /// Host budget usage after the call.
+    pub budget: BudgetInfo,
+}
+
+/// A write or delete of a storage key matching a watchpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchpointHit {
+    pub key: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
 /// Core debugging engine that orchestrates execution and debugging.
 pub struct DebuggerEngine {
     executor: ContractExecutor,
@@ -27,8 +91,16 @@ pub struct DebuggerEngine {
     stepper: Stepper,
     instrumenter: Instrumenter,
     source_map: Option<SourceMap>,
+    /// Export names by function index, for breakpoints hit while stepping.
+    function_names: HashMap<u32, String>,
     paused: bool,
     instruction_debug_enabled: bool,
+    watchpoints: Vec<String>,
+    watchpoint_hits: Vec<WatchpointHit>,
+    event_subscribers: Vec<Sender<ExecutionEvent>>,
+    max_steps: Option<usize>,
+    /// Host CPU and memory limits from before `max_steps` capped the budget.
+    uncapped_budget_limits: Option<(u64, u64)>,
 }
 
 struct EngineConditionEvaluator {
@@ -132,25 +204,34 @@ impl DebuggerEngine {
     }
     /// Create a new debugger engine.
     #[tracing::instrument(skip_all)]
-    pub fn new(
-        executor: ContractExecutor,
-        initial_breakpoints: Vec<String>,
-        initial_log_points: Vec<BreakpointSpec>,
-    ) -> Self {
-        let mut breakpoints = BreakpointManager::new();
-
+    pub fn new(executor: ContractExecutor, initial_breakpoints: Vec<String>) -> Self {
+        let mut engine = Self::with_specs(executor, Vec::new());
         for bp in initial_breakpoints {
-            breakpoints.add_simple(&bp);
+            engine.breakpoints.add_simple(&bp);
             info!("Breakpoint set at function: {}", bp);
         }
+        engine
+    }
 
-        for lp in initial_log_points {
-            breakpoints.add_spec(lp.clone());
-            info!(
-                "Log point set at function: {} with message: {}",
-                lp.function,
-                lp.log_message.as_deref().unwrap_or("")
-            );
+    /// Create a new debugger engine from full breakpoint specs, keeping their
+    /// conditions, hit conditions and log messages.
+    #[tracing::instrument(skip_all)]
+    pub fn with_specs(executor: ContractExecutor, initial_specs: Vec<BreakpointSpec>) -> Self {
+        let mut breakpoints = BreakpointManager::new();
+
+        for spec in initial_specs {
+            match &spec.log_message {
+                Some(message) => info!(
+                    "Log point set at function: {} with message: {}",
+                    spec.function, message
+                ),
+                None => info!("Breakpoint set at function: {}", spec.function),
+            }
+            let function = spec.function.clone();
+            breakpoints.add_spec(spec);
+            if let Some(bp) = breakpoints.get_mut(&function) {
+                bp.params = executor.function_param_names(&function).unwrap_or_default();
+            }
         }
 
         Self {
@@ -160,8 +241,14 @@ impl DebuggerEngine {
             stepper: Stepper::new(),
             instrumenter: Instrumenter::new(),
             source_map: None,
+            function_names: HashMap::new(),
             paused: false,
             instruction_debug_enabled: false,
+            watchpoints: Vec::new(),
+            watchpoint_hits: Vec::new(),
+            event_subscribers: Vec::new(),
+            max_steps: None,
+            uncapped_budget_limits: None,
         }
     }
 
@@ -208,6 +295,8 @@ impl DebuggerEngine {
             .parse_instructions(wasm_bytes)
             .map_err(|e| miette::miette!("Failed to parse instructions: {}", e))?
             .to_vec();
+        self.function_names =
+            crate::utils::wasm::parse_function_exports(wasm_bytes).unwrap_or_default();
 
         if let Ok(mut state) = self.state.lock() {
             state.set_instructions(instructions);
@@ -242,12 +331,57 @@ impl DebuggerEngine {
         self.instruction_debug_enabled
     }
 
-    /// Execute a contract function with debugging.
+    /// Execute a contract function with debugging. Breakpoint hit counts
+    /// carry over from earlier executions; they restart only when the
+    /// breakpoint is set again.
     #[tracing::instrument(skip(self), fields(function = function))]
     pub fn execute(&mut self, function: &str, args: Option<&str>) -> Result<String> {
         self.execute_internal(function, args, true)
     }
 
+    /// Seed `storage_json` (if any), call `function` without stopping at
+    /// breakpoints, and collect what the call did: its result, storage diff,
+    /// events, auth tree and budget. The storage diff also lists instance
+    /// storage keys individually, as watchpoints see them. `args_json` and `storage_json` take the
+    /// same formats as `--args` and `--storage`. The budget counters are reset
+    /// first, so the reported budget covers this call alone.
+    pub fn run_once(
+        &mut self,
+        function: &str,
+        args_json: Option<&str>,
+        storage_json: Option<&str>,
+    ) -> Result<ExecutionSummary> {
+        if let Some(storage) = storage_json {
+            self.executor.set_initial_storage(storage.to_string())?;
+        }
+        let storage_before = self.watch_snapshot();
+        let events_before = self.executor.get_events()?.len();
+
+        let limits = BudgetInspector::get_cpu_usage(self.executor.host());
+        self.executor
+            .host()
+            .budget_cloned()
+            .reset_limits(limits.cpu_limit, limits.memory_limit)
+            .map_err(|e| {
+                crate::DebuggerError::ExecutionError(format!("Failed to reset the budget: {}", e))
+            })?;
+
+        let result = self.execute_without_breakpoints(function, args_json)?;
+
+        let storage_after = self.watch_snapshot();
+        let mut events = self.executor.get_events()?;
+        events.drain(..events_before.min(events.len()));
+
+        Ok(ExecutionSummary {
+            function: function.to_string(),
+            result,
+            storage_diff: StorageInspector::compute_diff(&storage_before, &storage_after, &[]),
+            events,
+            auth_tree: self.executor.get_auth_tree()?,
+            budget: BudgetInspector::get_cpu_usage(self.executor.host()),
+        })
+    }
+
     pub fn execute_without_breakpoints(
         &mut self,
         function: &str,
@@ -283,8 +417,8 @@ impl DebuggerEngine {
             .map(|s| s.call_stack().get_stack().len())
             .unwrap_or(0);
         plugin_ctx.is_paused = self.paused;
-        crate::plugin::registry::dispatch_global_event(
-            &ExecutionEvent::BeforeFunctionCall {
+        self.emit(
+            ExecutionEvent::BeforeFunctionCall {
                 function: function.to_string(),
                 args: args.map(str::to_string),
             },
@@ -292,8 +426,7 @@ impl DebuggerEngine {
         );
 
         if check_breakpoints {
-            let evaluator = self.create_condition_evaluator();
-            match self.breakpoints.should_break_with_context(function, &evaluator) {
+            match self.check_function_breakpoint(function, args) {
                 Ok((should_pause, log_message)) => {
                     if let Some(msg) = log_message {
                         // Log point hit - output message but don't pause
@@ -311,28 +444,41 @@ impl DebuggerEngine {
                 Err(e) => {
                     tracing::warn!("Breakpoint evaluation failed: {}", e);
                 }
-            let storage = self.executor.get_storage_snapshot().unwrap_or_default();
-            let evaluator = EngineConditionEvaluator::new(storage);
-            let (should_pause, log_output) = self
-                .breakpoints_mut()
-                .should_break_with_context(function, &evaluator)?;
-
-            if let Some(message) = log_output {
-                println!("{message}");
-            }
-
-            if should_pause {
-                let condition = self
-                    .breakpoints()
-                    .get_breakpoint(function)
-                    .and_then(|bp| bp.condition.clone());
-                self.pause_at_function(function, condition);
             }
         }
 
+        let watch_before = if self.watchpoints.is_empty() {
+            HashMap::new()
+        } else {
+            self.watch_snapshot()
+        };
+        self.watchpoint_hits.clear();
+
+        let storage_before = self.executor.snapshot_storage();
+        self.record_storage(HistoryStep::Execution, storage_before);
+
+        let accesses_before = self.executor.debug_env().storage_access_count();
+        let capped = self.cap_budget_to_max_steps();
+        let insns_before = self.wasm_instructions_executed();
         let start_time = std::time::Instant::now();
-        let result = self.executor.execute(function, args);
+        let mut result = self.executor.execute(function, args);
         let duration = start_time.elapsed();
+        if let Some(max_steps) = self.max_steps {
+            let budget = self.executor.host().budget_cloned();
+            let exhausted =
+                budget.get_cpu_insns_remaining().unwrap_or(0) < CPU_PER_WASM_INSTRUCTION;
+            let executed = self
+                .wasm_instructions_executed()
+                .saturating_sub(insns_before);
+            if executed > max_steps as u64 || (capped && exhausted && result.is_err()) {
+                result = Err(crate::DebuggerError::ExecutionError(format!(
+                    "step limit exceeded: executed {} WASM instructions (--max-steps {})",
+                    executed, max_steps
+                ))
+                .into());
+            }
+        }
+        self.emit_storage_accesses(accesses_before, &mut plugin_ctx);
 
         self.update_call_stack(duration)?;
 
@@ -340,8 +486,8 @@ impl DebuggerEngine {
             Ok(output) => Ok(output.clone()),
             Err(e) => Err(e.to_string()),
         };
-        crate::plugin::registry::dispatch_global_event(
-            &ExecutionEvent::AfterFunctionCall {
+        self.emit(
+            ExecutionEvent::AfterFunctionCall {
                 function: function.to_string(),
                 result: event_result,
                 duration,
@@ -349,6 +495,10 @@ impl DebuggerEngine {
             &mut plugin_ctx,
         );
 
+        if result.is_ok() && !self.watchpoints.is_empty() {
+            self.check_watchpoints(&watch_before);
+        }
+
         if let Err(ref e) = result {
             tracing::error!("Execution failed: {}", e);
             self.paused = true;
@@ -357,8 +507,8 @@ impl DebuggerEngine {
             }
             let mut plugin_ctx = EventContext::new();
             plugin_ctx.is_paused = true;
-            crate::plugin::registry::dispatch_global_event(
-                &ExecutionEvent::ExecutionPaused {
+            self.emit(
+                ExecutionEvent::ExecutionPaused {
                     reason: PauseReason::Panic.as_str().to_string(),
                 },
                 &mut plugin_ctx,
@@ -375,6 +525,20 @@ impl DebuggerEngine {
         result
     }
 
+    /// Evaluate the function breakpoint on `function` for a call with `args`.
+    /// Returns whether to pause and the rendered log point message, if any.
+    /// Argument conditions are checked against `args`, anything else against
+    /// current storage; the CLI, dashboard and DAP server all stop through here.
+    pub fn check_function_breakpoint(
+        &mut self,
+        function: &str,
+        args: Option<&str>,
+    ) -> Result<(bool, Option<String>)> {
+        let evaluator = self.create_condition_evaluator(function, args);
+        self.breakpoints
+            .should_break_with_context(function, evaluator.as_ref())
+    }
+
     pub fn prepare_breakpoint_stop(&mut self, function: &str, args: Option<&str>) {
         if let Ok(mut state) = self.state.lock() {
             state.set_current_function(
@@ -398,15 +562,16 @@ impl DebuggerEngine {
             .get_breakpoint(function)
             .and_then(|bp| bp.condition.as_ref().map(|c| format!("{:?}", c)));
 
-        crate::plugin::registry::dispatch_global_event(
-            &ExecutionEvent::BreakpointHit {
+        self.emit(
+            ExecutionEvent::BreakpointHit {
                 function: function.to_string(),
                 condition,
+                hit_count: self.breakpoints.hit_count(function),
             },
             &mut plugin_ctx,
         );
-        crate::plugin::registry::dispatch_global_event(
-            &ExecutionEvent::ExecutionPaused {
+        self.emit(
+            ExecutionEvent::ExecutionPaused {
                 reason: PauseReason::Breakpoint.as_str().to_string(),
             },
             &mut plugin_ctx,
@@ -494,25 +659,46 @@ impl DebuggerEngine {
         }
     }
 
-    /// Step into next instruction.
+    /// Step into next instruction. A step that calls into a function with a
+    /// breakpoint counts a hit and pauses there on [`PauseReason::Breakpoint`]
+    /// when the breakpoint's hit condition passes.
     pub fn step_into(&mut self) -> Result<bool> {
         if !self.instruction_debug_enabled {
             return Err(miette::miette!("Instruction debugging not enabled"));
         }
 
-        let stepped = if let Ok(mut state) = self.state.lock() {
-            self.stepper.step_into(&mut state)
+        let storage_before = self.executor.snapshot_storage();
+        let (stepped, entered) = if let Ok(mut state) = self.state.lock() {
+            let depth = state.instruction_pointer().call_stack_depth();
+            let call_target = match state.current_instruction().map(|i| &i.operator) {
+                Some(wasmparser::Operator::Call { function_index }) => Some(*function_index),
+                _ => None,
+            };
+            let stepped = self.stepper.step_into(&mut state);
+            let entered = call_target
+                .filter(|_| stepped && state.instruction_pointer().call_stack_depth() > depth);
+            (stepped, entered)
         } else {
-            false
+            (false, None)
         };
+        if stepped {
+            self.record_storage(HistoryStep::Instruction, storage_before);
+        }
         self.paused = stepped;
-        if let Ok(mut state) = self.state.lock() {
-            if stepped {
-                state.set_pause_reason(PauseReason::StepBoundary);
-            } else {
-                state.set_pause_reason(PauseReason::EndOfExecution);
+        let at_breakpoint = entered.is_some_and(|index| self.stepped_into_breakpoint(index));
+        if !at_breakpoint {
+            if let Ok(mut state) = self.state.lock() {
+                if stepped {
+                    state.set_pause_reason(PauseReason::StepBoundary);
+                } else {
+                    state.set_pause_reason(PauseReason::EndOfExecution);
+                }
             }
         }
+        if stepped {
+            self.emit_current_instruction();
+        }
+        self.ensure_within_step_limit()?;
         Ok(stepped)
     }
 
@@ -522,11 +708,15 @@ impl DebuggerEngine {
             return Err(miette::miette!("Instruction debugging not enabled"));
         }
 
+        let storage_before = self.executor.snapshot_storage();
         let stepped = if let Ok(mut state) = self.state.lock() {
             self.stepper.step_over(&mut state)
         } else {
             false
         };
+        if stepped {
+            self.record_storage(HistoryStep::Instruction, storage_before);
+        }
         self.paused = stepped;
         if let Ok(mut state) = self.state.lock() {
             if stepped {
@@ -535,6 +725,10 @@ impl DebuggerEngine {
                 state.set_pause_reason(PauseReason::EndOfExecution);
             }
         }
+        if stepped {
+            self.emit_current_instruction();
+        }
+        self.ensure_within_step_limit()?;
         Ok(stepped)
     }
 
@@ -544,11 +738,15 @@ impl DebuggerEngine {
             return Err(miette::miette!("Instruction debugging not enabled"));
         }
 
+        let storage_before = self.executor.snapshot_storage();
         let stepped = if let Ok(mut state) = self.state.lock() {
             self.stepper.step_block(&mut state)
         } else {
             false
         };
+        if stepped {
+            self.record_storage(HistoryStep::Instruction, storage_before);
+        }
         self.paused = stepped;
         if let Ok(mut state) = self.state.lock() {
             if stepped {
@@ -557,6 +755,10 @@ impl DebuggerEngine {
                 state.set_pause_reason(PauseReason::EndOfExecution);
             }
         }
+        if stepped {
+            self.emit_current_instruction();
+        }
+        self.ensure_within_step_limit()?;
         Ok(stepped)
     }
 
@@ -585,6 +787,7 @@ impl DebuggerEngine {
                 state.set_pause_reason(PauseReason::EndOfExecution);
             }
         }
+        self.ensure_within_step_limit()?;
         Ok(StepOverResult { paused, location })
     }
 
@@ -594,11 +797,15 @@ impl DebuggerEngine {
             return Err(miette::miette!("Instruction debugging not enabled"));
         }
 
+        let storage_before = self.executor.snapshot_storage();
         let stepped = if let Ok(mut state) = self.state.lock() {
             self.stepper.step_out(&mut state)
         } else {
             false
         };
+        if stepped {
+            self.record_storage(HistoryStep::Instruction, storage_before);
+        }
         self.paused = stepped;
         if let Ok(mut state) = self.state.lock() {
             if stepped {
@@ -607,20 +814,129 @@ impl DebuggerEngine {
                 state.set_pause_reason(PauseReason::EndOfExecution);
             }
         }
+        if stepped {
+            self.emit_current_instruction();
+        }
+        self.ensure_within_step_limit()?;
         Ok(stepped)
     }
 
-    /// Step back to previous instruction.
-    pub fn step_back(&mut self) -> Result<bool> {
+    /// Single-step until the instruction at byte offset `pc` is current, then
+    /// pause there.
+    ///
+    /// Stops early, paused on [`PauseReason::Breakpoint`], when a call steps
+    /// into a function that has a breakpoint (by export name or `func_<index>`)
+    /// and the breakpoint's hit condition passes. Each such call counts as a hit.
+    /// Returns `false` if `pc` was not reached.
+    pub fn run_to_pc(&mut self, pc: usize) -> Result<bool> {
         if !self.instruction_debug_enabled {
             return Err(miette::miette!("Instruction debugging not enabled"));
         }
 
-        let stepped = if let Ok(mut state) = self.state.lock() {
-            self.stepper.step_back(&mut state)
-        } else {
-            false
+        let storage_before = self.executor.snapshot_storage();
+        let names = &self.function_names;
+        let breakpoints = &mut self.breakpoints;
+        let outcome = match self.state.lock() {
+            Ok(mut state) => self.stepper.run_to_pc(&mut state, pc, |index| {
+                let function = names
+                    .get(&index)
+                    .cloned()
+                    .unwrap_or_else(|| format!("func_{}", index));
+                breakpoints.record_hit(&function).unwrap_or_else(|e| {
+                    tracing::warn!("Breakpoint evaluation failed: {}", e);
+                    true
+                })
+            }),
+            Err(_) => RunToPc::NotReached,
+        };
+
+        let reason = match outcome {
+            RunToPc::Reached => PauseReason::StepBoundary,
+            RunToPc::Breakpoint(_) => PauseReason::Breakpoint,
+            RunToPc::NotReached => PauseReason::EndOfExecution,
+        };
+        self.paused = outcome != RunToPc::NotReached;
+        if self.paused {
+            self.record_storage(HistoryStep::Instruction, storage_before);
+        }
+        if let Ok(mut state) = self.state.lock() {
+            state.set_pause_reason(reason);
+        }
+        self.ensure_within_step_limit()?;
+        Ok(outcome == RunToPc::Reached)
+    }
+
+    /// Count a hit on the breakpoint of the function at `index`, which a step
+    /// just called into, and pause there on [`PauseReason::Breakpoint`] if its
+    /// hit condition passes. Returns whether it paused.
+    fn stepped_into_breakpoint(&mut self, index: u32) -> bool {
+        let function = self
+            .function_names
+            .get(&index)
+            .cloned()
+            .unwrap_or_else(|| format!("func_{}", index));
+        let pause = self.breakpoints.record_hit(&function).unwrap_or_else(|e| {
+            tracing::warn!("Breakpoint evaluation failed: {}", e);
+            true
+        });
+        if pause {
+            let condition = self
+                .breakpoints
+                .get_breakpoint(&function)
+                .and_then(|bp| bp.condition.clone());
+            self.pause_at_function(&function, condition);
+        }
+        pause
+    }
+
+    /// Keep the storage as it was before an instruction step or a call so
+    /// [`step_back`](Self::step_back) can rewind it.
+    fn record_storage(&self, step: HistoryStep, snapshot: Result<StorageSnapshot>) {
+        match snapshot {
+            Ok(snapshot) => {
+                if let Ok(mut state) = self.state.lock() {
+                    state.record_storage_step(step, snapshot);
+                }
+            }
+            Err(e) => tracing::warn!("Failed to record storage for step back: {}", e),
+        }
+    }
+
+    /// Undo the most recent instruction step or call, restoring the storage
+    /// snapshot recorded before it.
+    ///
+    /// Undoing an instruction step also moves the instruction pointer back
+    /// one instruction; undoing a call only rewinds storage.
+    pub fn step_back(&mut self) -> Result<bool> {
+        let previous = self
+            .state
+            .lock()
+            .ok()
+            .and_then(|mut state| state.rewind_storage_step());
+        let restored = match &previous {
+            Some((_, snapshot)) => {
+                self.executor.restore_storage(snapshot)?;
+                true
+            }
+            None => false,
+        };
+
+        if !self.instruction_debug_enabled && !restored {
+            return Err(miette::miette!("Instruction debugging not enabled"));
+        }
+
+        let undoes_instruction = match &previous {
+            Some((step, _)) => *step == HistoryStep::Instruction,
+            None => true,
         };
+        let rewound = self.instruction_debug_enabled
+            && undoes_instruction
+            && self
+                .state
+                .lock()
+                .map(|mut state| self.stepper.step_back(&mut state))
+                .unwrap_or(false);
+        let stepped = rewound || restored;
         self.paused = stepped;
         if let Ok(mut state) = self.state.lock() {
             if stepped {
@@ -657,10 +973,7 @@ impl DebuggerEngine {
 
         let mut plugin_ctx = EventContext::new();
         plugin_ctx.is_paused = false;
-        crate::plugin::registry::dispatch_global_event(
-            &ExecutionEvent::ExecutionResumed,
-            &mut plugin_ctx,
-        );
+        self.emit(ExecutionEvent::ExecutionResumed, &mut plugin_ctx);
         Ok(())
     }
 
@@ -680,21 +993,135 @@ impl DebuggerEngine {
 
         let mut plugin_ctx = EventContext::new();
         plugin_ctx.is_paused = true;
-        crate::plugin::registry::dispatch_global_event(
-            &ExecutionEvent::BreakpointHit {
+        self.emit(
+            ExecutionEvent::BreakpointHit {
                 function: function.to_string(),
                 condition,
+                hit_count: self.breakpoints.hit_count(function),
             },
             &mut plugin_ctx,
         );
-        crate::plugin::registry::dispatch_global_event(
-            &ExecutionEvent::ExecutionPaused {
+        self.emit(
+            ExecutionEvent::ExecutionPaused {
                 reason: PauseReason::Breakpoint.as_str().to_string(),
             },
             &mut plugin_ctx,
         );
     }
 
+    /// Subscribe to the events this engine emits. Each call returns an
+    /// independent stream; dropped streams are unsubscribed on the next event.
+    /// Events are not live from the host: a call's `StorageAccess` events
+    /// arrive together after it returns, ahead of its `AfterFunctionCall`, and
+    /// its `WatchpointHit` events after that.
+    pub fn event_stream(&mut self) -> EventStream {
+        let (sender, receiver) = mpsc::channel();
+        self.event_subscribers.push(sender);
+        EventStream { receiver }
+    }
+
+    /// Dispatch `event` to loaded plugins and to every open event stream.
+    fn emit(&mut self, event: ExecutionEvent, context: &mut EventContext) {
+        crate::plugin::registry::dispatch_global_event(&event, context);
+        self.event_subscribers
+            .retain(|subscriber| subscriber.send(event.clone()).is_ok());
+    }
+
+    /// Emit `BeforeInstruction` for the instruction a step paused on.
+    fn emit_current_instruction(&mut self) {
+        if let Some(instruction) = self.current_instruction() {
+            let mut plugin_ctx = EventContext::new();
+            plugin_ctx.is_paused = true;
+            self.emit(
+                ExecutionEvent::BeforeInstruction {
+                    pc: instruction.offset as u32,
+                    instruction: instruction.to_string(),
+                },
+                &mut plugin_ctx,
+            );
+        }
+    }
+
+    /// Emit the storage accesses the executor tracked since `start`. The host
+    /// exposes no hook for storage calls, so these are replayed in access order
+    /// once the call has returned, just before its `AfterFunctionCall`.
+    fn emit_storage_accesses(&mut self, start: usize, context: &mut EventContext) {
+        let accesses = self
+            .executor
+            .debug_env()
+            .storage_accesses()
+            .get(start..)
+            .unwrap_or_default()
+            .to_vec();
+        for access in accesses {
+            let operation = match access.access_type {
+                StorageAccessType::Read => StorageOperation::Read,
+                StorageAccessType::Write => StorageOperation::Write,
+            };
+            self.emit(
+                ExecutionEvent::StorageAccess {
+                    operation,
+                    key: access.key,
+                    value: access.value,
+                },
+                context,
+            );
+        }
+    }
+
+    /// Storage entries watchpoints are matched against and `run_once` diffs:
+    /// ledger entries plus the individual keys of contract instance storage.
+    fn watch_snapshot(&self) -> HashMap<String, String> {
+        let mut snapshot = self.executor.get_storage_snapshot().unwrap_or_default();
+        snapshot.extend(StorageInspector::capture_instance_entries(
+            self.executor.host(),
+        ));
+        snapshot
+    }
+
+    /// Pause on every watched key the last execution wrote or deleted. Matching
+    /// goes through the same diff and pattern logic as `--alert-on-change`.
+    fn check_watchpoints(&mut self, before: &HashMap<String, String>) {
+        let after = self.watch_snapshot();
+        let diff = StorageInspector::compute_diff(before, &after, &self.watchpoints);
+        let mut keys = diff.triggered_alerts;
+        keys.sort();
+
+        for key in keys {
+            let hit = WatchpointHit {
+                before: before.get(&key).cloned(),
+                after: after.get(&key).cloned(),
+                key,
+            };
+            info!(
+                "Watchpoint hit: {} ({:?} -> {:?})",
+                hit.key, hit.before, hit.after
+            );
+            self.paused = true;
+            if let Ok(mut state) = self.state.lock() {
+                state.set_pause_reason(PauseReason::Watchpoint);
+            }
+
+            let mut plugin_ctx = EventContext::new();
+            plugin_ctx.is_paused = true;
+            self.emit(
+                ExecutionEvent::WatchpointHit {
+                    key: hit.key.clone(),
+                    before: hit.before.clone(),
+                    after: hit.after.clone(),
+                },
+                &mut plugin_ctx,
+            );
+            self.emit(
+                ExecutionEvent::ExecutionPaused {
+                    reason: PauseReason::Watchpoint.as_str().to_string(),
+                },
+                &mut plugin_ctx,
+            );
+            self.watchpoint_hits.push(hit);
+        }
+    }
+
     pub fn pause_reason(&self) -> Option<PauseReason> {
         self.state.lock().ok().and_then(|state| state.pause_reason())
     }
@@ -738,6 +1165,131 @@ impl DebuggerEngine {
         &self.breakpoints
     }
 
+    /// Set a breakpoint from a CLI spec such as `transfer when amount > 1000`,
+    /// resolving argument names against the contract spec.
+    pub fn add_breakpoint_spec(&mut self, spec: &str) -> Result<()> {
+        let (function, _) = BreakpointManager::parse_breakpoint(spec);
+        let params = self
+            .executor
+            .function_param_names(&function)
+            .unwrap_or_default();
+        self.breakpoints.add_with_params(spec, &params)?;
+        info!("Breakpoint set: {}", spec);
+        Ok(())
+    }
+
+    /// Pause whenever a storage key matching `key_pattern` is written or
+    /// deleted. Patterns use the `--alert-on-change` syntax: `c*` for a prefix,
+    /// `re:<regex>`, or an exact key. Contract instance keys are matched by name.
+    pub fn add_watchpoint(&mut self, key_pattern: &str) -> Result<()> {
+        FilterPattern::parse(key_pattern).map_err(crate::DebuggerError::BreakpointError)?;
+        self.watchpoints.push(key_pattern.to_string());
+        info!("Watchpoint set on storage keys: {}", key_pattern);
+        Ok(())
+    }
+
+    pub fn watchpoints(&self) -> &[String] {
+        &self.watchpoints
+    }
+
+    /// Watchpoints triggered by the last execution, in key order.
+    pub fn watchpoint_hits(&self) -> &[WatchpointHit] {
+        &self.watchpoint_hits
+    }
+
+    /// Limit how many instruction steps and calls [`Self::step_back`] can
+    /// rewind storage across. Older snapshots are dropped first.
+    pub fn set_storage_history_capacity(&mut self, capacity: usize) {
+        if let Ok(mut state) = self.state.lock() {
+            state.storage_history_mut().set_capacity(capacity);
+        }
+    }
+
+    /// Fail instruction stepping after `max_steps` instructions, and calls
+    /// that execute more than `max_steps` WASM instructions. `None` removes
+    /// the limit.
+    pub fn set_max_steps(&mut self, max_steps: Option<usize>) {
+        self.max_steps = max_steps;
+        if let Ok(mut state) = self.state.lock() {
+            state.set_step_limit(max_steps);
+        }
+    }
+
+    pub fn max_steps(&self) -> Option<usize> {
+        self.max_steps
+    }
+
+    /// Whether instruction stepping stopped at the `max_steps` limit.
+    pub fn step_limit_exceeded(&self) -> bool {
+        self.state
+            .lock()
+            .map(|state| state.step_limit_exceeded())
+            .unwrap_or(false)
+    }
+
+    fn ensure_within_step_limit(&self) -> Result<()> {
+        match self.max_steps {
+            Some(max_steps) if self.step_limit_exceeded() => {
+                Err(crate::DebuggerError::ExecutionError(format!(
+                    "step limit exceeded: stepped {} instructions (--max-steps)",
+                    max_steps
+                ))
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Cap the host's CPU budget so a call stops soon after executing
+    /// `max_steps` WASM instructions instead of running to the full budget or
+    /// `--timeout`. Resets the budget counters, so the budget reported after
+    /// the call covers that call alone. Returns whether the cap is tighter
+    /// than the original limit.
+    ///
+    /// Changing a limit also clears the consumed counters, so the cap stays in
+    /// place after the call for the budget to be reported. Every call derives
+    /// its cap from the original limits instead, and they are put back on the
+    /// first call made once `max_steps` is cleared.
+    fn cap_budget_to_max_steps(&mut self) -> bool {
+        let budget = self.executor.host().budget_cloned();
+        let Some(max_steps) = self.max_steps else {
+            if let Some((cpu_limit, memory_limit)) = self.uncapped_budget_limits.take() {
+                if let Err(e) = budget.reset_limits(cpu_limit, memory_limit) {
+                    tracing::warn!("Failed to restore the budget after --max-steps: {}", e);
+                }
+            }
+            return false;
+        };
+        let info = BudgetInspector::get_cpu_usage(self.executor.host());
+        let (cpu_limit, memory_limit) = *self
+            .uncapped_budget_limits
+            .get_or_insert((info.cpu_limit, info.memory_limit));
+        let cap = (max_steps as u64)
+            .saturating_mul(CPU_PER_WASM_INSTRUCTION)
+            .saturating_add(MAX_STEPS_HOST_CPU_ALLOWANCE);
+        if let Err(e) = budget.reset_limits(cap.min(cpu_limit), memory_limit) {
+            tracing::warn!("Failed to cap the budget for --max-steps: {}", e);
+            return false;
+        }
+        if cap < cpu_limit {
+            info!(
+                "CPU budget capped at {} instructions for max_steps {} (limit {})",
+                cap, max_steps, cpu_limit
+            );
+        }
+        cap < cpu_limit
+    }
+
+    /// WASM instructions the host has metered so far.
+    fn wasm_instructions_executed(&self) -> u64 {
+        self.executor
+            .host()
+            .budget_cloned()
+            .get_tracker(soroban_env_host::xdr::ContractCostType::WasmInsnExec)
+            .map(|tracker| tracker.iterations)
+            .unwrap_or(0)
+    }
+
     pub fn executor(&self) -> &ContractExecutor {
         &self.executor
     }
@@ -751,16 +1303,47 @@ impl DebuggerEngine {
         if self.instruction_debug_enabled {
             let _ = self.step_into()?;
         }
-        if let Ok(mut state) = self.state.lock() {
-            state.increment_step();
-        }
+        let step = match self.state.lock() {
+            Ok(mut state) => {
+                state.increment_step();
+                state.step_count()
+            }
+            Err(_) => 0,
+        };
+        self.executor
+            .checkpoints()
+            .record(self.executor.host(), format!("Step #{}", step));
         Ok(())
     }
 
-    /// Create a condition evaluator for breakpoint evaluation
-    fn create_condition_evaluator(&self) -> Box<dyn crate::debugger::breakpoint::ConditionEvaluator> {
+    /// Create a condition evaluator for a call to `function` with `args`
+    fn create_condition_evaluator(
+        &self,
+        function: &str,
+        args: Option<&str>,
+    ) -> Box<dyn crate::debugger::breakpoint::ConditionEvaluator> {
+        let (arguments, conditional) = self
+            .breakpoints
+            .get(function)
+            .map(|bp| {
+                (
+                    ArgumentEvaluator::new(&bp.params, args),
+                    bp.condition.is_some(),
+                )
+            })
+            .unwrap_or_default();
+        // Only a condition needs the storage snapshot.
+        let storage = if conditional {
+            self.executor.get_storage_snapshot().unwrap_or_default()
+        } else {
+            HashMap::new()
+        };
         Box::new(DebugStateEvaluator {
             state: Arc::clone(&self.state),
+            function: function.to_string(),
+            args: args.map(str::to_string),
+            arguments,
+            storage: EngineConditionEvaluator::new(storage),
         })
     }
 }
@@ -768,28 +1351,32 @@ impl DebuggerEngine {
 /// Evaluates breakpoint conditions by reading from debug state
 struct DebugStateEvaluator {
     state: Arc<Mutex<DebugState>>,
+    function: String,
+    args: Option<String>,
+    arguments: ArgumentEvaluator,
+    storage: EngineConditionEvaluator,
 }
 
 impl crate::debugger::breakpoint::ConditionEvaluator for DebugStateEvaluator {
     fn evaluate(&self, condition: &str) -> crate::Result<bool> {
-        // Simple evaluation - can be enhanced later with full expression parsing
-        // For now, return true to not block execution
         tracing::debug!("Evaluating condition: {}", condition);
-        Ok(true)
+        if self.arguments.can_evaluate(condition) {
+            return self.arguments.evaluate(condition);
+        }
+        // Anything else names a storage key.
+        self.storage.evaluate(condition)
     }
 
     fn interpolate_log(&self, template: &str) -> crate::Result<String> {
-        // Extract function name and args from state and interpolate
+        // Interpolate the call being checked and the step count from state
         if let Ok(state) = self.state.lock() {
             let mut result = template.to_string();
 
             // Interpolate {function} placeholder
-            if let Some(func) = state.current_function() {
-                result = result.replace("{function}", func);
-            }
+            result = result.replace("{function}", &self.function);
 
             // Interpolate {args} placeholder
-            if let Some(args) = state.current_args() {
+            if let Some(args) = &self.args {
                 result = result.replace("{args}", args);
             }
 
@@ -17,3 +17,63 @@ fn no_source_location_without_instruction_state() {
     let engine = create_test_engine();
     assert!(engine.current_source_location().is_none());
 }
+
+#[test]
+fn non_argument_conditions_are_evaluated_against_storage() {
+    use super::{DebugStateEvaluator, EngineConditionEvaluator};
+    use crate::debugger::breakpoint::{ArgumentEvaluator, ConditionEvaluator};
+    use crate::debugger::state::DebugState;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    let evaluator = DebugStateEvaluator {
+        state: Arc::new(Mutex::new(DebugState::new())),
+        function: "withdraw".to_string(),
+        args: Some("[1500]".to_string()),
+        arguments: ArgumentEvaluator::new(&["amount".to_string()], Some("[1500]")),
+        storage: EngineConditionEvaluator::new(HashMap::from([(
+            "balance".to_string(),
+            "10".to_string(),
+        )])),
+    };
+
+    assert!(evaluator.evaluate("amount > 1000").unwrap());
+    assert!(evaluator.evaluate("balance == 10").unwrap());
+    assert!(!evaluator.evaluate("balance > 100").unwrap());
+}
+
+#[test]
+fn function_breakpoint_conditions_use_the_checked_call_arguments() {
+    let mut engine = create_test_engine();
+    engine.add_breakpoint_spec("echo when v > 5").unwrap();
+
+    let mut pauses = |function: &str, args: &str| {
+        engine
+            .check_function_breakpoint(function, Some(args))
+            .unwrap()
+            .0
+    };
+
+    assert!(!pauses("echo", "[3]"));
+    assert!(pauses("echo", "[7]"));
+    assert!(!pauses("other", "[7]"));
+}
+
+#[test]
+fn hit_counts_carry_across_executions() {
+    let mut engine = create_test_engine();
+    engine.add_breakpoint_spec("echo ignore:1").unwrap();
+
+    engine.execute("echo", Some("[1]")).unwrap();
+    assert!(!engine.is_paused());
+    engine.execute("echo", Some("[2]")).unwrap();
+    assert!(engine.is_paused());
+    assert_eq!(engine.breakpoints().hit_count("echo"), 2);
+
+    engine.stage_execution("echo", Some("[3]"));
+    assert_eq!(engine.breakpoints().hit_count("echo"), 2);
+
+    // Setting the breakpoint again starts its count over.
+    engine.add_breakpoint_spec("echo ignore:1").unwrap();
+    assert_eq!(engine.breakpoints().hit_count("echo"), 0);
+}
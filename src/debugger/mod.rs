@@ -8,9 +8,9 @@ pub mod stepper;
 pub mod timeline;
 
 pub use breakpoint::BreakpointManager;
-pub use engine::DebuggerEngine;
+pub use engine::{DebuggerEngine, ExecutionSummary};
 pub use error_db::{ErrorDatabase, ErrorExplanation};
 pub use instruction_pointer::{InstructionPointer, StepMode};
 pub use source_map::{SourceBreakpointResolution, SourceLocation, SourceMap};
 pub use state::DebugState;
-pub use stepper::Stepper;
+pub use stepper::{RunToPc, Stepper};
@@ -2,12 +2,18 @@ use crate::debugger::instruction_pointer::{InstructionPointer, StepMode};
 use crate::inspector::stack::CallStackInspector;
 use crate::output::InvocationReason;
 use crate::runtime::instruction::Instruction;
+use crate::runtime::result::StorageSnapshot;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Number of storage snapshots kept for stepping back by default.
+pub const DEFAULT_STORAGE_HISTORY: usize = 64;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum PauseReason {
     Breakpoint,
+    Watchpoint,
     StepBoundary,
     Panic,
     EndOfExecution,
@@ -18,6 +24,7 @@ impl PauseReason {
     pub fn as_str(self) -> &'static str {
         match self {
             Self::Breakpoint => "breakpoint",
+            Self::Watchpoint => "watchpoint",
             Self::StepBoundary => "step_boundary",
             Self::Panic => "panic",
             Self::EndOfExecution => "end_of_execution",
@@ -26,6 +33,96 @@ impl PauseReason {
     }
 }
 
+/// What a storage snapshot was taken before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryStep {
+    /// A single instruction step.
+    Instruction,
+    /// A whole contract call.
+    Execution,
+}
+
+/// Storage snapshots taken before each instruction step and each call,
+/// keyed by snapshot index.
+///
+/// A ring buffer: once `capacity` snapshots are held, recording a new one
+/// drops the oldest, so stepping back can only rewind the last `capacity`
+/// steps.
+#[derive(Clone)]
+pub struct StorageHistory {
+    capacity: usize,
+    snapshots: VecDeque<(usize, HistoryStep, StorageSnapshot)>,
+}
+
+impl StorageHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            snapshots: VecDeque::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Change the capacity, dropping the oldest snapshots that no longer fit.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.snapshots.len() > capacity {
+            self.snapshots.pop_front();
+        }
+    }
+
+    pub fn push(&mut self, index: usize, step: HistoryStep, snapshot: StorageSnapshot) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back((index, step, snapshot));
+    }
+
+    /// Remove and return the most recent snapshot with its index and the kind
+    /// of step it was taken before.
+    pub fn pop(&mut self) -> Option<(usize, HistoryStep, StorageSnapshot)> {
+        self.snapshots.pop_back()
+    }
+
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
+
+impl Default for StorageHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_STORAGE_HISTORY)
+    }
+}
+
+impl std::fmt::Debug for StorageHistory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let snapshots: Vec<(usize, HistoryStep)> = self
+            .snapshots
+            .iter()
+            .map(|(index, step, _)| (*index, *step))
+            .collect();
+        f.debug_struct("StorageHistory")
+            .field("capacity", &self.capacity)
+            .field("snapshots", &snapshots)
+            .finish()
+    }
+}
+
 /// Represents the current state of the debugger.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugState {
@@ -41,6 +138,16 @@ pub struct DebugState {
     instruction_debug_enabled: bool,
     call_stack: CallStackInspector,
     pause_reason: Option<PauseReason>,
+    #[serde(skip)]
+    storage_history: StorageHistory,
+    /// Index the next storage snapshot is recorded under.
+    #[serde(skip)]
+    snapshot_index: usize,
+    /// Maximum number of instructions stepped before stepping stops.
+    #[serde(skip)]
+    step_limit: Option<usize>,
+    #[serde(skip)]
+    instructions_stepped: usize,
 }
 
 impl DebugState {
@@ -57,6 +164,10 @@ impl DebugState {
             instruction_debug_enabled: false,
             call_stack: CallStackInspector::new(),
             pause_reason: None,
+            storage_history: StorageHistory::default(),
+            snapshot_index: 0,
+            step_limit: None,
+            instructions_stepped: 0,
         }
     }
 
@@ -94,6 +205,34 @@ impl DebugState {
         self.step_count
     }
 
+    /// Record the storage as it was before an instruction step or a call.
+    pub fn record_storage_step(&mut self, step: HistoryStep, snapshot: StorageSnapshot) {
+        self.storage_history
+            .push(self.snapshot_index, step, snapshot);
+        self.snapshot_index += 1;
+    }
+
+    /// Take the storage snapshot recorded before the most recent instruction
+    /// step or call, with the kind of step it undoes.
+    pub fn rewind_storage_step(&mut self) -> Option<(HistoryStep, StorageSnapshot)> {
+        let (index, step, snapshot) = self.storage_history.pop()?;
+        self.snapshot_index = index;
+        Some((step, snapshot))
+    }
+
+    /// Index the next storage snapshot will be recorded under.
+    pub fn snapshot_index(&self) -> usize {
+        self.snapshot_index
+    }
+
+    pub fn storage_history(&self) -> &StorageHistory {
+        &self.storage_history
+    }
+
+    pub fn storage_history_mut(&mut self) -> &mut StorageHistory {
+        &mut self.storage_history
+    }
+
     pub fn set_instructions(&mut self, instructions: Vec<Instruction>) {
         self.instructions = instructions;
         self.current_instruction = self.instructions.first().cloned();
@@ -129,6 +268,22 @@ impl DebugState {
         self.instruction_debug_enabled
     }
 
+    /// Stop stepping once `limit` instructions have been stepped; `None`
+    /// removes the limit.
+    pub fn set_step_limit(&mut self, limit: Option<usize>) {
+        self.step_limit = limit;
+    }
+
+    pub fn step_limit(&self) -> Option<usize> {
+        self.step_limit
+    }
+
+    /// Whether stepping stopped because the step limit was reached.
+    pub fn step_limit_exceeded(&self) -> bool {
+        self.step_limit
+            .is_some_and(|limit| self.instructions_stepped > limit)
+    }
+
     pub fn start_instruction_stepping(&mut self, mode: StepMode) {
         if self.instruction_debug_enabled {
             self.instruction_pointer.start_stepping(mode);
@@ -155,6 +310,11 @@ impl DebugState {
     }
 
     pub fn next_instruction(&mut self) -> Option<&Instruction> {
+        self.instructions_stepped = self.instructions_stepped.saturating_add(1);
+        if self.step_limit_exceeded() {
+            return None;
+        }
+
         let current_index = self.instruction_pointer.current_index();
         let mut next_index = current_index.saturating_add(1);
 
@@ -236,10 +396,13 @@ impl DebugState {
         self.current_function = None;
         self.current_args = None;
         self.step_count = 0;
+        self.instructions_stepped = 0;
         self.instruction_pointer.reset();
         self.current_instruction = self.instructions.first().cloned();
         self.call_stack.clear();
         self.pause_reason = None;
+        self.storage_history.clear();
+        self.snapshot_index = 0;
     }
 
     pub fn get_instruction_context(&self, context_size: usize) -> Vec<(usize, &Instruction, bool)> {
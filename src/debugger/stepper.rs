@@ -5,6 +5,17 @@ use crate::runtime::instruction::Instruction;
 
 const STEP_GUARD: usize = 100_000;
 
+/// Outcome of [`Stepper::run_to_pc`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunToPc {
+    /// The current instruction is at the requested offset.
+    Reached,
+    /// A call into a function with a breakpoint was stepped into first.
+    Breakpoint(u32),
+    /// Execution ended before the offset was reached.
+    NotReached,
+}
+
 pub struct Stepper {
     active: bool,
     step_mode: StepMode,
@@ -121,6 +132,44 @@ impl Stepper {
         self.find_next_control_flow(debug_state)
     }
 
+    /// Single-step into calls until the current instruction is at byte offset
+    /// `pc`. `is_breakpoint` is asked about the target of every call stepped
+    /// into; a hit stops the run inside that function.
+    pub fn run_to_pc(
+        &mut self,
+        debug_state: &mut DebugState,
+        pc: usize,
+        mut is_breakpoint: impl FnMut(u32) -> bool,
+    ) -> RunToPc {
+        if !self.active {
+            return RunToPc::NotReached;
+        }
+        self.step_mode = StepMode::StepInto;
+        debug_state.start_instruction_stepping(StepMode::StepInto);
+
+        for _ in 0..STEP_GUARD {
+            let depth = debug_state.instruction_pointer().call_stack_depth();
+            let call_target = match debug_state.current_instruction().map(|i| &i.operator) {
+                Some(wasmparser::Operator::Call { function_index }) => Some(*function_index),
+                _ => None,
+            };
+            let Some(offset) = debug_state.next_instruction().map(|i| i.offset) else {
+                return RunToPc::NotReached;
+            };
+            if offset == pc {
+                return RunToPc::Reached;
+            }
+            if let Some(target) = call_target {
+                if debug_state.instruction_pointer().call_stack_depth() > depth
+                    && is_breakpoint(target)
+                {
+                    return RunToPc::Breakpoint(target);
+                }
+            }
+        }
+        RunToPc::NotReached
+    }
+
     pub fn step_back(&mut self, debug_state: &mut DebugState) -> bool {
         if !self.active {
             return false;
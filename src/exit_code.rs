@@ -0,0 +1,83 @@
+//! Process exit codes, so CI pipelines can branch on why a command failed
+//! without parsing its output.
+//!
+//! | Code | Meaning |
+//! |------|---------|
+//! | 0 | Success |
+//! | 1 | Any other failure (bad arguments, unreadable files, network errors, ...) |
+//! | 2 | Contract execution failed: trap, panic, budget or step limit |
+//! | 3 | WASM checksum did not match `--expected-hash` |
+//! | 4 | `analyze` found issues at or above `--fail-on <severity>` |
+//! | 5 | `--budget-trend --fail-on-regression` found a regression above the threshold |
+//! | 6 | `upgrade-check` classified the upgrade as Breaking |
+
+use crate::DebuggerError;
+
+pub const SUCCESS: i32 = 0;
+pub const FAILURE: i32 = 1;
+pub const EXECUTION_FAILED: i32 = 2;
+pub const CHECKSUM_MISMATCH: i32 = 3;
+pub const SECURITY_FINDINGS: i32 = 4;
+pub const REGRESSION_DETECTED: i32 = 5;
+pub const BREAKING_CHANGES: i32 = 6;
+
+/// Exit code for a command that failed with `err`.
+pub fn for_error(err: &miette::Report) -> i32 {
+    match err.downcast_ref::<DebuggerError>() {
+        Some(DebuggerError::ExecutionError(_)) => EXECUTION_FAILED,
+        Some(DebuggerError::ChecksumMismatch(..)) => CHECKSUM_MISMATCH,
+        Some(DebuggerError::SecurityFindings(_)) => SECURITY_FINDINGS,
+        Some(DebuggerError::RegressionDetected(_)) => REGRESSION_DETECTED,
+        Some(DebuggerError::BreakingChanges(_)) => BREAKING_CHANGES,
+        _ => FAILURE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miette::WrapErr;
+
+    fn code(err: DebuggerError) -> i32 {
+        for_error(&err.into())
+    }
+
+    #[test]
+    fn maps_debugger_errors_to_distinct_codes() {
+        assert_eq!(
+            code(DebuggerError::ExecutionError("trap".into())),
+            EXECUTION_FAILED
+        );
+        assert_eq!(
+            code(DebuggerError::ChecksumMismatch("a".into(), "b".into())),
+            CHECKSUM_MISMATCH
+        );
+        assert_eq!(
+            code(DebuggerError::SecurityFindings("1 high".into())),
+            SECURITY_FINDINGS
+        );
+        assert_eq!(
+            code(DebuggerError::RegressionDetected("cpu".into())),
+            REGRESSION_DETECTED
+        );
+        assert_eq!(
+            code(DebuggerError::BreakingChanges("get removed".into())),
+            BREAKING_CHANGES
+        );
+        assert_eq!(code(DebuggerError::FileError("gone".into())), FAILURE);
+    }
+
+    #[test]
+    fn looks_through_added_context() {
+        let err: miette::Report = DebuggerError::ExecutionError("trap".into()).into();
+        let err = Err::<(), _>(err)
+            .wrap_err("while running increment")
+            .unwrap_err();
+        assert_eq!(for_error(&err), EXECUTION_FAILED);
+    }
+
+    #[test]
+    fn other_errors_use_the_generic_code() {
+        assert_eq!(for_error(&miette::miette!("plain message")), FAILURE);
+    }
+}
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufReader, BufWriter, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime};
 
 /// A record of a single session reconnection event.
@@ -114,6 +114,35 @@ pub struct RunHistory {
     pub function: String,
     pub cpu_used: u64,
     pub memory_used: u64,
+    /// `git rev-parse HEAD` at record time, `None` outside a repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    #[serde(default)]
+    pub wall_time_ms: u64,
+}
+
+/// Current `HEAD` commit of the git repository containing the working
+/// directory, or `None` if git is unavailable or this is not a repository.
+pub fn current_git_commit() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -491,12 +520,23 @@ impl HistoryManager {
     }
 
     /// Filter historical data based on optional parameters.
+    ///
+    /// With `since_commit`, only records from the first one made at a commit
+    /// matching that hash (or hash prefix) onwards are kept, in file order.
     pub fn filter_history(
         &self,
         contract_hash: Option<&str>,
         function: Option<&str>,
+        since_commit: Option<&str>,
     ) -> Result<Vec<RunHistory>> {
-        let history = self.load_history()?;
+        let mut history = self.load_history()?;
+        if let Some(since) = since_commit {
+            let start = history
+                .iter()
+                .position(|r| r.commit.as_deref().is_some_and(|c| c.starts_with(since)))
+                .unwrap_or(history.len());
+            history.drain(..start);
+        }
         let filtered = history
             .into_iter()
             .filter(|r| {
@@ -514,6 +554,38 @@ impl HistoryManager {
         Ok(filtered)
     }
 
+    /// Write the records matching the filters to `path` as CSV and return how
+    /// many were written. An empty history produces a header-only file.
+    pub fn export_csv(
+        &self,
+        path: &Path,
+        contract_hash: Option<&str>,
+        function: Option<&str>,
+    ) -> Result<usize> {
+        let records = self.filter_history(contract_hash, function, None)?;
+
+        let mut csv =
+            String::from("date,contract_hash,function,cpu_used,memory_used,commit,wall_time_ms\n");
+        for r in &records {
+            let fields = [
+                csv_field(&r.date),
+                csv_field(&r.contract_hash),
+                csv_field(&r.function),
+                r.cpu_used.to_string(),
+                r.memory_used.to_string(),
+                csv_field(r.commit.as_deref().unwrap_or_default()),
+                r.wall_time_ms.to_string(),
+            ];
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+
+        fs::write(path, csv).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to write CSV export {:?}: {}", path, e))
+        })?;
+        Ok(records.len())
+    }
+
     fn acquire_lock(&self) -> Result<HistoryLockGuard> {
         let lock_path = self.file_path.with_extension("lock");
         let start = SystemTime::now();
@@ -559,17 +631,64 @@ impl HistoryManager {
     }
 }
 
-/// Calculate the delta between the last two runs. Returns percentage increase if >10%.
-pub fn check_regression(records: &[RunHistory]) -> Option<(f64, f64)> {
-    check_regression_with_config(records, &RegressionConfig::default())
+/// Change of the latest run against the baseline, in signed percent.
+///
+/// Positive deltas are regressions, negative ones improvements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegressionDelta {
+    pub cpu_delta_pct: f64,
+    pub mem_delta_pct: f64,
+    /// Whether either delta moved by more than the threshold, in either direction.
+    pub significant: bool,
+}
+
+/// Calculate the delta between the last two runs, flagging changes beyond 10%.
+pub fn check_regression(records: &[RunHistory]) -> Option<RegressionDelta> {
+    check_regression_with(records, RegressionThresholds::default())
+}
+
+/// Calculate the delta between the last two runs, flagging changes beyond `thresholds`.
+pub fn check_regression_with(
+    records: &[RunHistory],
+    thresholds: RegressionThresholds,
+) -> Option<RegressionDelta> {
+    check_regression_with_config(
+        records,
+        &RegressionConfig {
+            thresholds,
+            ..RegressionConfig::default()
+        },
+    )
+}
+
+/// Percentage change per metric above which a run counts as a regression (or improvement).
+///
+/// Example: `cpu_pct: 2.0` means "flag the latest run if CPU moved >2% from baseline".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegressionThresholds {
+    pub cpu_pct: f64,
+    pub mem_pct: f64,
+}
+
+impl RegressionThresholds {
+    /// The same threshold for CPU and memory.
+    pub fn uniform(pct: f64) -> Self {
+        Self {
+            cpu_pct: pct,
+            mem_pct: pct,
+        }
+    }
+}
+
+impl Default for RegressionThresholds {
+    fn default() -> Self {
+        Self::uniform(10.0)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RegressionConfig {
-    /// Percentage threshold above which we consider a regression.
-    ///
-    /// Example: `10.0` means "warn if latest is >10% higher than baseline".
-    pub threshold_pct: f64,
+    pub thresholds: RegressionThresholds,
     /// Number of most-recent records to consider for regression detection.
     ///
     /// The baseline is computed from the previous `lookback - 1` runs, and compared to the latest.
@@ -583,7 +702,7 @@ pub struct RegressionConfig {
 impl Default for RegressionConfig {
     fn default() -> Self {
         Self {
-            threshold_pct: 10.0,
+            thresholds: RegressionThresholds::default(),
             lookback: 2,
             smoothing_window: 1,
         }
@@ -606,20 +725,24 @@ fn smooth_trailing(values: &[u64], window: usize) -> Vec<f64> {
     out
 }
 
-/// Check for CPU and memory regressions using a configurable lookback window and smoothing.
+/// Check for CPU and memory regressions or improvements using a configurable lookback
+/// window and smoothing.
 ///
-/// Returns `(cpu_pct, mem_pct)` where each value is `> 0.0` only when it exceeds `threshold_pct`.
+/// Returns `None` when there are fewer than two runs to compare. Otherwise the deltas
+/// are always reported and `significant` is set when either one exceeds its threshold
+/// in absolute value.
 pub fn check_regression_with_config(
     records: &[RunHistory],
     config: &RegressionConfig,
-) -> Option<(f64, f64)> {
+) -> Option<RegressionDelta> {
     if records.len() < 2 {
         return None;
     }
 
     let lookback = config.lookback.max(2);
     let smoothing = config.smoothing_window.max(1);
-    let threshold = config.threshold_pct.max(0.0);
+    let cpu_threshold = config.thresholds.cpu_pct.max(0.0);
+    let mem_threshold = config.thresholds.mem_pct.max(0.0);
 
     let mut sorted: Vec<&RunHistory> = records.iter().collect();
     sorted.sort_by(|a, b| compare_run_history_date(a, b));
@@ -641,28 +764,21 @@ pub fn check_regression_with_config(
     let cpu_baseline = cpu[..cpu.len() - 1].iter().sum::<f64>() / ((cpu.len() - 1) as f64);
     let mem_baseline = mem[..mem.len() - 1].iter().sum::<f64>() / ((mem.len() - 1) as f64);
 
-    let mut regression_cpu = 0.0;
-    let mut regression_mem = 0.0;
-
-    if cpu_baseline > 0.0 && cpu_latest > cpu_baseline {
-        let p = ((cpu_latest - cpu_baseline) / cpu_baseline) * 100.0;
-        if p > threshold {
-            regression_cpu = p;
-        }
-    }
-
-    if mem_baseline > 0.0 && mem_latest > mem_baseline {
-        let p = ((mem_latest - mem_baseline) / mem_baseline) * 100.0;
-        if p > threshold {
-            regression_mem = p;
+    let delta_pct = |latest: f64, baseline: f64| {
+        if baseline > 0.0 {
+            ((latest - baseline) / baseline) * 100.0
+        } else {
+            0.0
         }
-    }
-
-    if regression_cpu > 0.0 || regression_mem > 0.0 {
-        Some((regression_cpu, regression_mem))
-    } else {
-        None
-    }
+    };
+    let cpu_delta_pct = delta_pct(cpu_latest, cpu_baseline);
+    let mem_delta_pct = delta_pct(mem_latest, mem_baseline);
+
+    Some(RegressionDelta {
+        cpu_delta_pct,
+        mem_delta_pct,
+        significant: cpu_delta_pct.abs() > cpu_threshold || mem_delta_pct.abs() > mem_threshold,
+    })
 }
 
 #[derive(Debug, Clone)]
@@ -743,6 +859,8 @@ mod tests {
             function: "func".into(),
             cpu_used: cpu,
             memory_used: mem,
+            commit: None,
+            wall_time_ms: 0,
         }
     }
 
@@ -890,6 +1008,76 @@ mod tests {
         );
     }
 
+    /// Files written before `commit` and `wall_time_ms` existed must still load.
+    #[test]
+    fn load_history_accepts_records_without_commit() {
+        let mut tmp = NamedTempFile::new().unwrap();
+        tmp.write_all(
+            b"[{\"date\":\"2026-01-01\",\"contract_hash\":\"a\",\"function\":\"f\",\"cpu_used\":1,\"memory_used\":2}]",
+        )
+        .unwrap();
+        tmp.flush().unwrap();
+
+        let manager = HistoryManager::with_path(tmp.path().to_path_buf());
+        let history = manager.load_history().unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].commit, None);
+        assert_eq!(history[0].wall_time_ms, 0);
+    }
+
+    #[test]
+    fn filter_history_since_commit() {
+        let temp = TempDir::new().unwrap();
+        let manager = HistoryManager::with_path(temp.path().join("history.json"));
+        for (i, commit) in [None, Some("aaa111"), None, Some("bbb222")]
+            .into_iter()
+            .enumerate()
+        {
+            let mut record = make_record("2026-01-01T00:00:00Z", i as u64, 0);
+            record.commit = commit.map(String::from);
+            manager.append_record(record).unwrap();
+        }
+
+        let cpu = |records: Vec<RunHistory>| records.iter().map(|r| r.cpu_used).collect::<Vec<_>>();
+        let since = |sha| manager.filter_history(None, None, Some(sha)).unwrap();
+        assert_eq!(cpu(since("aaa")), vec![1, 2, 3]);
+        assert_eq!(cpu(since("bbb222")), vec![3]);
+        assert!(since("ccc").is_empty());
+    }
+
+    #[test]
+    fn export_csv_quotes_fields_and_writes_header_when_empty() {
+        let temp = TempDir::new().unwrap();
+        let manager = HistoryManager::with_path(temp.path().join("history.json"));
+        let csv_path = temp.path().join("history.csv");
+
+        assert_eq!(manager.export_csv(&csv_path, None, None).unwrap(), 0);
+        assert_eq!(
+            fs::read_to_string(&csv_path).unwrap(),
+            "date,contract_hash,function,cpu_used,memory_used,commit,wall_time_ms\n"
+        );
+
+        let mut record = make_record("2026-01-01T00:00:00Z", 1500, 2048);
+        record.contract_hash = "contracts/a,b/\"token\".wasm".into();
+        record.wall_time_ms = 7;
+        manager.append_record(record).unwrap();
+        manager
+            .append_record(make_record("2026-01-02T00:00:00Z", 1, 1))
+            .unwrap();
+
+        assert_eq!(
+            manager
+                .export_csv(&csv_path, Some("contracts/a,b/\"token\".wasm"), None)
+                .unwrap(),
+            1
+        );
+        let csv = fs::read_to_string(&csv_path).unwrap();
+        assert_eq!(
+            csv.lines().nth(1).unwrap(),
+            "2026-01-01T00:00:00Z,\"contracts/a,b/\"\"token\"\".wasm\",func,1500,2048,,7"
+        );
+    }
+
     // ── pre-existing tests (unchanged) ───────────────────────────────────────
 
     #[test]
@@ -901,14 +1089,35 @@ mod tests {
             function: "func".into(),
             cpu_used: 1150,    // 15% increase
             memory_used: 1050, // 5% increase
+            commit: None,
+            wall_time_ms: 0,
         };
 
         let records = vec![p1, p2];
-        let regression = check_regression(&records);
-        assert!(regression.is_some());
-        let (cpu, mem) = regression.unwrap();
-        assert_eq!(cpu, 15.0);
-        assert_eq!(mem, 0.0);
+        let delta = check_regression(&records).unwrap();
+        assert!(delta.significant);
+        assert_eq!(delta.cpu_delta_pct, 15.0);
+        assert_eq!(delta.mem_delta_pct, 5.0);
+    }
+
+    #[test]
+    fn test_improvement_detection() {
+        let records = vec![
+            make_record("2026-01-01T00:00:00Z", 1000, 1000),
+            make_record("2026-01-02T00:00:00Z", 820, 990), // -18% cpu, -1% mem
+        ];
+
+        let delta = check_regression(&records).unwrap();
+        assert!(delta.significant);
+        assert!((delta.cpu_delta_pct + 18.0).abs() < 1e-9);
+        assert!((delta.mem_delta_pct + 1.0).abs() < 1e-9);
+
+        let steady = vec![
+            make_record("2026-01-01T00:00:00Z", 1000, 1000),
+            make_record("2026-01-02T00:00:00Z", 950, 1000),
+        ];
+        assert!(!check_regression(&steady).unwrap().significant);
+        assert!(check_regression(&steady[..1]).is_none());
     }
 
     #[test]
@@ -918,11 +1127,35 @@ mod tests {
         let records = vec![p1, p2];
 
         let cfg = RegressionConfig {
-            threshold_pct: 20.0,
+            thresholds: RegressionThresholds::uniform(20.0),
             lookback: 2,
             smoothing_window: 1,
         };
-        assert!(check_regression_with_config(&records, &cfg).is_none());
+        assert!(
+            !check_regression_with_config(&records, &cfg)
+                .unwrap()
+                .significant
+        );
+    }
+
+    #[test]
+    fn regression_thresholds_are_per_metric() {
+        let records = vec![
+            make_record("2026-01-01", 1000, 1000),
+            make_record("2026-01-02", 1030, 1040), // +3% cpu, +4% mem
+        ];
+
+        let strict_cpu = RegressionThresholds {
+            cpu_pct: 2.0,
+            mem_pct: 5.0,
+        };
+        let delta = check_regression_with(&records, strict_cpu).unwrap();
+        assert!(delta.significant);
+        assert!(delta.cpu_delta_pct > strict_cpu.cpu_pct);
+        assert!(delta.mem_delta_pct < strict_cpu.mem_pct);
+
+        let loose = RegressionThresholds::uniform(5.0);
+        assert!(!check_regression_with(&records, loose).unwrap().significant);
     }
 
     #[test]
@@ -937,20 +1170,26 @@ mod tests {
         ];
 
         let cfg_short = RegressionConfig {
-            threshold_pct: 10.0,
+            thresholds: RegressionThresholds::uniform(10.0),
             lookback: 2,
             smoothing_window: 1,
         };
-        assert!(check_regression_with_config(&records, &cfg_short).is_none());
+        assert!(
+            !check_regression_with_config(&records, &cfg_short)
+                .unwrap()
+                .significant
+        );
 
         let cfg_long = RegressionConfig {
-            threshold_pct: 10.0,
+            thresholds: RegressionThresholds::uniform(10.0),
             lookback: 4,
             smoothing_window: 1,
         };
-        let (cpu, mem) = check_regression_with_config(&records, &cfg_long).unwrap();
+        let delta = check_regression_with_config(&records, &cfg_long).unwrap();
+        let cpu = delta.cpu_delta_pct;
+        assert!(delta.significant);
         assert!(cpu > 20.0 && cpu < 30.0, "expected ~25%, got {cpu}");
-        assert_eq!(mem, 0.0);
+        assert_eq!(delta.mem_delta_pct, 0.0);
     }
 
     #[test]
@@ -965,18 +1204,26 @@ mod tests {
         ];
 
         let cfg_raw = RegressionConfig {
-            threshold_pct: 40.0,
+            thresholds: RegressionThresholds::uniform(40.0),
             lookback: 4,
             smoothing_window: 1,
         };
-        assert!(check_regression_with_config(&records, &cfg_raw).is_some());
+        assert!(
+            check_regression_with_config(&records, &cfg_raw)
+                .unwrap()
+                .significant
+        );
 
         let cfg_smooth = RegressionConfig {
-            threshold_pct: 40.0,
+            thresholds: RegressionThresholds::uniform(40.0),
             lookback: 4,
             smoothing_window: 3,
         };
-        assert!(check_regression_with_config(&records, &cfg_smooth).is_none());
+        assert!(
+            !check_regression_with_config(&records, &cfg_smooth)
+                .unwrap()
+                .significant
+        );
     }
 
     #[test]
@@ -1055,6 +1302,8 @@ mod tests {
                         function: "func".into(),
                         cpu_used: (t as u64) * 10 + i as u64,
                         memory_used: (t as u64) * 10 + i as u64,
+                        commit: None,
+                        wall_time_ms: 0,
                     };
                     manager.append_record(record).unwrap();
                 }
@@ -1084,6 +1333,8 @@ mod tests {
                 contract_hash: "h".into(),
                 function: "f".into(),
                 cpu_used: 1,
+                commit: None,
+                wall_time_ms: 0,
                 memory_used: 1,
             })
             .unwrap();
@@ -1,7 +1,10 @@
 use crossterm::style::{Color, Stylize};
 use serde::{Deserialize, Serialize};
+use soroban_env_host::xdr::ContractCostType;
 use soroban_env_host::Host;
+use std::cell::RefCell;
 use std::collections::VecDeque;
+use std::rc::Rc;
 
 /// Tracks resource usage (CPU and memory budget)
 pub struct BudgetInspector;
@@ -23,6 +26,51 @@ impl BudgetInspector {
         }
     }
 
+    /// CPU instructions and memory bytes charged per host cost type, most CPU
+    /// first. Cost types that charged nothing are left out.
+    pub fn get_cost_breakdown(host: &Host) -> Vec<(ContractCostType, u64, u64)> {
+        let budget = host.budget_cloned();
+        let mut breakdown: Vec<_> = ContractCostType::variants()
+            .iter()
+            .filter_map(|ty| budget.get_tracker(*ty).ok().map(|t| (*ty, t.cpu, t.mem)))
+            .filter(|(_, cpu, mem)| *cpu > 0 || *mem > 0)
+            .collect();
+        breakdown.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.2.cmp(&a.2)));
+        breakdown
+    }
+
+    /// Render a cost breakdown as a table with each type's share of the CPU total.
+    pub fn format_cost_breakdown(breakdown: &[(ContractCostType, u64, u64)]) -> String {
+        let total_cpu: u64 = breakdown.iter().map(|(_, cpu, _)| cpu).sum();
+        let name_width = breakdown
+            .iter()
+            .map(|(ty, _, _)| ty.name().len())
+            .max()
+            .unwrap_or(0)
+            .max("Cost type".len());
+
+        let header = format!(
+            "{:<name_width$} | {:>12} | {:>12} | {:>6}",
+            "Cost type", "CPU", "Memory", "CPU %"
+        );
+        let mut out = format!("{}\n{}\n", header, "-".repeat(header.len()));
+        for (ty, cpu, mem) in breakdown {
+            let share = if total_cpu > 0 {
+                *cpu as f64 / total_cpu as f64 * 100.0
+            } else {
+                0.0
+            };
+            out.push_str(&format!(
+                "{:<name_width$} | {:>12} | {:>12} | {:>5.1}%\n",
+                ty.name(),
+                Self::format_cpu_insns(*cpu),
+                Self::format_memory_bytes(*mem),
+                share
+            ));
+        }
+        out
+    }
+
     /// Display budget information with warnings
     pub fn display(host: &Host) {
         let info = Self::get_cpu_usage(host);
@@ -213,6 +261,47 @@ pub struct ResourceCheckpoint {
     pub location_name: String,
 }
 
+/// Collects checkpoints from hooks that run inside the host while a contract
+/// executes. Clones share one buffer; `record` does nothing until `start`.
+#[derive(Clone, Default)]
+pub struct CheckpointRecorder {
+    session: Rc<RefCell<Option<(std::time::Instant, Vec<ResourceCheckpoint>)>>>,
+}
+
+impl CheckpointRecorder {
+    /// Begin a recording with a first checkpoint at `location`, dropping any
+    /// unfinished one.
+    pub fn start(&self, host: &Host, location: impl Into<String>) {
+        let started = std::time::Instant::now();
+        let first = BudgetInspector::create_checkpoint(host, location.into(), Some(started));
+        *self.session.borrow_mut() = Some((started, vec![first]));
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.session.borrow().is_some()
+    }
+
+    pub fn record(&self, host: &Host, location: impl Into<String>) {
+        if let Some((started, checkpoints)) = self.session.borrow_mut().as_mut() {
+            checkpoints.push(BudgetInspector::create_checkpoint(
+                host,
+                location.into(),
+                Some(*started),
+            ));
+        }
+    }
+
+    /// Record a last checkpoint at `location` and end the recording.
+    pub fn finish(&self, host: &Host, location: impl Into<String>) -> Vec<ResourceCheckpoint> {
+        self.record(host, location);
+        self.session
+            .borrow_mut()
+            .take()
+            .map(|(_, checkpoints)| checkpoints)
+            .unwrap_or_default()
+    }
+}
+
 impl BudgetInfo {
     /// Calculate CPU usage percentage
     pub fn cpu_percentage(&self) -> f64 {
@@ -232,6 +321,47 @@ impl BudgetInfo {
         }
     }
 
+    /// Replace the host-reported limits with those of a target network.
+    pub fn with_limits(mut self, limits: NetworkLimits) -> Self {
+        self.cpu_limit = limits.cpu_limit;
+        self.memory_limit = limits.memory_limit;
+        self
+    }
+
+    /// Messages for each resource whose usage is above its limit.
+    pub fn exceeded_limits(&self) -> Vec<String> {
+        self.newly_exceeded_limits(&BudgetInfo {
+            cpu_instructions: 0,
+            cpu_limit: 0,
+            memory_bytes: 0,
+            memory_limit: 0,
+        })
+    }
+
+    /// Messages for each resource whose usage is above its limit now but was
+    /// within it in `previous`.
+    pub fn newly_exceeded_limits(&self, previous: &BudgetInfo) -> Vec<String> {
+        let mut exceeded = Vec::new();
+        if self.cpu_instructions > self.cpu_limit && previous.cpu_instructions <= previous.cpu_limit
+        {
+            exceeded.push(format!(
+                "CPU usage {} exceeds the network limit of {} ({:.1}%)",
+                BudgetInspector::format_cpu_insns(self.cpu_instructions),
+                BudgetInspector::format_cpu_insns(self.cpu_limit),
+                self.cpu_percentage()
+            ));
+        }
+        if self.memory_bytes > self.memory_limit && previous.memory_bytes <= previous.memory_limit {
+            exceeded.push(format!(
+                "Memory usage {} exceeds the network limit of {} ({:.1}%)",
+                BudgetInspector::format_memory_bytes(self.memory_bytes),
+                BudgetInspector::format_memory_bytes(self.memory_limit),
+                self.memory_percentage()
+            ));
+        }
+        exceeded
+    }
+
     /// Compute the resource usage delta relative to an earlier snapshot.
     pub fn delta_from(&self, previous: &BudgetInfo) -> BudgetInfo {
         BudgetInfo {
@@ -245,10 +375,144 @@ impl BudgetInfo {
     }
 }
 
+/// Per-transaction CPU and memory limits of a target network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkLimits {
+    pub cpu_limit: u64,
+    pub memory_limit: u64,
+}
+
+impl NetworkLimits {
+    pub const DEFAULT_CPU_LIMIT: u64 = 100_000_000;
+    pub const DEFAULT_MEMORY_LIMIT: u64 = 40 * 1024 * 1024;
+}
+
+impl Default for NetworkLimits {
+    fn default() -> Self {
+        Self {
+            cpu_limit: Self::DEFAULT_CPU_LIMIT,
+            memory_limit: Self::DEFAULT_MEMORY_LIMIT,
+        }
+    }
+}
+
+/// Hard CPU and memory ceilings for a single invocation, as used by `gate`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BudgetGate {
+    pub max_cpu: Option<u64>,
+    pub max_mem: Option<u64>,
+}
+
+/// Outcome of checking one run against a [`BudgetGate`].
+#[derive(Debug, Clone, Serialize)]
+pub struct GateReport {
+    pub passed: bool,
+    pub cpu_instructions: u64,
+    pub max_cpu: Option<u64>,
+    pub memory_bytes: u64,
+    pub max_mem: Option<u64>,
+    pub violations: Vec<String>,
+}
+
+impl BudgetGate {
+    pub fn evaluate(&self, info: &BudgetInfo) -> GateReport {
+        let mut violations = Vec::new();
+        let mut check = |resource: &str, used: u64, limit: Option<u64>| {
+            if let Some(limit) = limit.filter(|limit| used > *limit) {
+                violations.push(format!(
+                    "{} {} exceeds limit {} (+{:.1}%)",
+                    resource,
+                    used,
+                    limit,
+                    (used - limit) as f64 / limit.max(1) as f64 * 100.0
+                ));
+            }
+        };
+        check("cpu", info.cpu_instructions, self.max_cpu);
+        check("mem", info.memory_bytes, self.max_mem);
+
+        GateReport {
+            passed: violations.is_empty(),
+            cpu_instructions: info.cpu_instructions,
+            max_cpu: self.max_cpu,
+            memory_bytes: info.memory_bytes,
+            max_mem: self.max_mem,
+            violations,
+        }
+    }
+}
+
+impl GateReport {
+    /// One-line pass/fail summary suitable for CI logs.
+    pub fn render(&self) -> String {
+        let usage = |used: u64, limit: Option<u64>| match limit {
+            Some(limit) => format!("{}/{}", used, limit),
+            None => used.to_string(),
+        };
+        let summary = format!(
+            "cpu {}, mem {}",
+            usage(self.cpu_instructions, self.max_cpu),
+            usage(self.memory_bytes, self.max_mem)
+        );
+        if self.passed {
+            format!("PASS: {}", summary)
+        } else {
+            format!("FAIL: {} ({})", summary, self.violations.join("; "))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cost_breakdown_table_lists_share_of_cpu() {
+        let table = BudgetInspector::format_cost_breakdown(&[
+            (ContractCostType::WasmInsnExec, 3_000, 0),
+            (ContractCostType::MemAlloc, 1_000, 2048),
+        ]);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("Cost type"));
+        assert!(lines[2].starts_with("WasmInsnExec") && lines[2].ends_with("75.0%"));
+        assert!(lines[3].contains("2.00 KB") && lines[3].ends_with("25.0%"));
+    }
+
+    #[test]
+    fn test_budget_gate_reports_exceeded_limits() {
+        let info = BudgetInfo {
+            cpu_instructions: 1_200,
+            cpu_limit: 100_000,
+            memory_bytes: 500,
+            memory_limit: 100_000,
+        };
+
+        let gate = BudgetGate {
+            max_cpu: Some(1_000),
+            max_mem: Some(500),
+        };
+        let report = gate.evaluate(&info);
+        assert!(!report.passed);
+        assert_eq!(
+            report.violations,
+            vec!["cpu 1200 exceeds limit 1000 (+20.0%)"]
+        );
+        assert!(report
+            .render()
+            .starts_with("FAIL: cpu 1200/1000, mem 500/500"));
+
+        let report = BudgetGate {
+            max_cpu: Some(2_000),
+            max_mem: None,
+        }
+        .evaluate(&info);
+        assert!(report.passed);
+        assert_eq!(report.render(), "PASS: cpu 1200/2000, mem 500");
+    }
+
     #[test]
     fn test_budget_percentage_calculation() {
         let info = BudgetInfo {
@@ -313,6 +577,44 @@ mod tests {
         assert!(warnings[0].suggestion.is_some());
     }
 
+    #[test]
+    fn test_network_limits_replace_host_limits() {
+        let info = BudgetInfo {
+            cpu_instructions: 120_000_000,
+            cpu_limit: 500_000_000,
+            memory_bytes: 1024,
+            memory_limit: 500_000_000,
+        }
+        .with_limits(NetworkLimits::default());
+
+        assert_eq!(info.cpu_limit, 100_000_000);
+        assert_eq!(info.memory_limit, 40 * 1024 * 1024);
+        let exceeded = info.exceeded_limits();
+        assert_eq!(exceeded.len(), 1);
+        assert!(exceeded[0].starts_with("CPU usage 120.00M exceeds the network limit of 100.00M"));
+    }
+
+    #[test]
+    fn test_newly_exceeded_limits_skips_already_exceeded() {
+        let previous = BudgetInfo {
+            cpu_instructions: 150,
+            cpu_limit: 100,
+            memory_bytes: 20,
+            memory_limit: 200,
+        };
+        let current = BudgetInfo {
+            cpu_instructions: 180,
+            memory_bytes: 250,
+            ..previous.clone()
+        };
+
+        let exceeded = current.newly_exceeded_limits(&previous);
+        assert_eq!(exceeded.len(), 1);
+        assert!(exceeded[0].starts_with("Memory usage"));
+        assert!(current.newly_exceeded_limits(&current).is_empty());
+        assert_eq!(current.exceeded_limits().len(), 2);
+    }
+
     #[test]
     fn test_budget_delta_from_previous_snapshot() {
         let previous = BudgetInfo {
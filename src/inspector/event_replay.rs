@@ -0,0 +1,280 @@
+//! Reconstruct a contract call from an event it emitted.
+//!
+//! Many contracts publish their call arguments in the event they emit: the token
+//! `transfer` event carries `from` and `to` as topics and `amount` as data. Given
+//! such an event, the first `Symbol` topic names the function and the remaining
+//! topics followed by the data are matched against the function's spec to rebuild
+//! the argument list. This is a best-effort heuristic, not a decoder.
+
+use crate::utils::wasm::ContractFunctionSignature;
+use crate::{DebuggerError, Result};
+use serde_json::{json, Value};
+use soroban_env_host::xdr::{Limits, ReadXdr, ScVal};
+use std::fs;
+use std::path::Path;
+
+/// One event as recorded on chain, decoded from XDR.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    pub topics: Vec<ScVal>,
+    pub data: ScVal,
+}
+
+/// A call rebuilt from an event, ready for `ContractExecutor::execute`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayCall {
+    pub function: String,
+    pub args_json: String,
+}
+
+impl RecordedEvent {
+    /// Load events from an RPC `getEvents` response (or its `result`, or a bare array).
+    ///
+    /// Each event needs base64 XDR `topic`/`topics` and `value`/`data` fields.
+    pub fn load_all<P: AsRef<Path>>(path: P) -> Result<Vec<Self>> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to read event log {:?}: {}", path, e))
+        })?;
+        let json: Value = serde_json::from_str(&contents).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to parse event log {:?}: {}", path, e))
+        })?;
+        Self::from_json(&json)
+    }
+
+    pub fn from_json(json: &Value) -> Result<Vec<Self>> {
+        let result = json.get("result").unwrap_or(json);
+        let events = result
+            .get("events")
+            .unwrap_or(result)
+            .as_array()
+            .ok_or_else(|| {
+                DebuggerError::InvalidArguments(
+                    "Event log must be a getEvents response or an array of events".to_string(),
+                )
+            })?;
+
+        events
+            .iter()
+            .enumerate()
+            .map(Self::from_event_json)
+            .collect()
+    }
+
+    fn from_event_json((index, event): (usize, &Value)) -> Result<Self> {
+        let missing = |field: &str| {
+            DebuggerError::InvalidArguments(format!("Event #{} has no '{}' field", index, field))
+        };
+        let topics = event
+            .get("topic")
+            .or_else(|| event.get("topics"))
+            .and_then(Value::as_array)
+            .ok_or_else(|| missing("topic"))?
+            .iter()
+            .map(|t| decode_scval(t.as_str().unwrap_or_default(), index))
+            .collect::<Result<Vec<_>>>()?;
+        let data = event
+            .get("value")
+            .or_else(|| event.get("data"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| missing("value"))?;
+
+        Ok(Self {
+            topics,
+            data: decode_scval(data, index)?,
+        })
+    }
+
+    /// Rebuild the call that emitted this event.
+    ///
+    /// Candidates are the topics after the function name, then the data (a `Vec`
+    /// is spread into its elements). When the function has a spec, candidates are
+    /// assigned to parameters in order, skipping any whose type does not fit, so
+    /// extra topics such as an asset name are ignored.
+    pub fn reconstruct_call(&self, signatures: &[ContractFunctionSignature]) -> Result<ReplayCall> {
+        let function = match self.topics.first() {
+            Some(ScVal::Symbol(symbol)) => symbol.to_utf8_string_lossy(),
+            _ => {
+                return Err(DebuggerError::InvalidArguments(
+                    "Event's first topic is not a Symbol naming the function".to_string(),
+                )
+                .into())
+            }
+        };
+
+        let mut candidates: Vec<&ScVal> = self.topics.iter().skip(1).collect();
+        match &self.data {
+            ScVal::Vec(Some(items)) => candidates.extend(items.iter()),
+            ScVal::Void => {}
+            other => candidates.push(other),
+        }
+
+        let args: Vec<&ScVal> = match signatures.iter().find(|s| s.name == function) {
+            Some(signature) => {
+                let mut remaining = candidates.into_iter();
+                let mut args = Vec::new();
+                for param in &signature.params {
+                    let arg = remaining
+                        .find(|value| scval_fits_type(value, &param.type_name))
+                        .ok_or_else(|| {
+                            DebuggerError::InvalidArguments(format!(
+                                "Event has no value for parameter '{}: {}' of '{}'",
+                                param.name, param.type_name, function
+                            ))
+                        })?;
+                    args.push(arg);
+                }
+                args
+            }
+            None => candidates,
+        };
+
+        let args = args
+            .into_iter()
+            .map(scval_to_typed_json)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ReplayCall {
+            function,
+            args_json: Value::Array(args).to_string(),
+        })
+    }
+}
+
+fn decode_scval(xdr: &str, index: usize) -> Result<ScVal> {
+    ScVal::from_xdr_base64(xdr, Limits::none()).map_err(|e| {
+        DebuggerError::InvalidArguments(format!("Event #{} has invalid ScVal XDR: {}", index, e))
+            .into()
+    })
+}
+
+/// Whether `value` can be passed for a parameter of spec type `type_name`.
+pub(crate) fn scval_fits_type(value: &ScVal, type_name: &str) -> bool {
+    match (value, type_name) {
+        (_, "Val") => true,
+        (ScVal::Bool(_), "Bool") => true,
+        (ScVal::U32(_), "U32") | (ScVal::I32(_), "I32") => true,
+        (ScVal::U64(_), "U64") | (ScVal::I64(_), "I64") => true,
+        (ScVal::U128(_), "U128") | (ScVal::I128(_), "I128") => true,
+        (ScVal::String(_), "String") | (ScVal::Symbol(_), "Symbol") => true,
+        (ScVal::Address(_), "Address") => true,
+        (ScVal::Bytes(_), "Bytes") => true,
+        (ScVal::Bytes(bytes), t) if t.starts_with("BytesN<") => t
+            .trim_start_matches("BytesN<")
+            .trim_end_matches('>')
+            .parse::<usize>()
+            .is_ok_and(|n| n == bytes.len()),
+        (ScVal::Vec(_), t) => t.starts_with("Vec<"),
+        _ => false,
+    }
+}
+
+/// Convert a decoded value into the typed JSON annotation `ArgumentParser` accepts.
+pub(crate) fn scval_to_typed_json(value: &ScVal) -> Result<Value> {
+    let typed = |ty: &str, value: Value| json!({ "type": ty, "value": value });
+    Ok(match value {
+        ScVal::Bool(b) => typed("bool", json!(b)),
+        ScVal::U32(n) => typed("u32", json!(n)),
+        ScVal::I32(n) => typed("i32", json!(n)),
+        ScVal::U64(n) => typed("u64", json!(n)),
+        ScVal::I64(n) => typed("i64", json!(n)),
+        ScVal::U128(parts) => {
+            let n = (u128::from(parts.hi) << 64) | u128::from(parts.lo);
+            typed(
+                "u128",
+                u64::try_from(n).map_or(json!(n.to_string()), |n| json!(n)),
+            )
+        }
+        ScVal::I128(parts) => {
+            let n = (i128::from(parts.hi) << 64) | i128::from(parts.lo);
+            typed(
+                "i128",
+                i64::try_from(n).map_or(json!(n.to_string()), |n| json!(n)),
+            )
+        }
+        ScVal::Symbol(s) => typed("symbol", json!(s.to_utf8_string_lossy())),
+        ScVal::String(s) => typed("string", json!(s.to_utf8_string_lossy())),
+        ScVal::Address(address) => typed("address", json!(address.to_string())),
+        ScVal::Bytes(bytes) => typed("bytes", json!(format!("0x{}", hex::encode(bytes)))),
+        ScVal::Vec(Some(items)) => typed(
+            "vec",
+            Value::Array(
+                items
+                    .iter()
+                    .map(scval_to_typed_json)
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+        ),
+        other => {
+            return Err(DebuggerError::InvalidArguments(format!(
+                "Cannot express {:?} as a typed JSON value",
+                other
+            ))
+            .into())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::wasm::FunctionParam;
+    use soroban_env_host::xdr::{Hash, Int128Parts, ScAddress, ScSymbol, WriteXdr};
+
+    fn symbol(s: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(s.try_into().unwrap()))
+    }
+
+    fn address(byte: u8) -> ScVal {
+        ScVal::Address(ScAddress::Contract(Hash([byte; 32])))
+    }
+
+    #[test]
+    fn reconstructs_token_transfer_from_event() {
+        let xdr = |v: &ScVal| v.to_xdr_base64(Limits::none()).unwrap();
+        let amount = ScVal::I128(Int128Parts { hi: 0, lo: 250 });
+        let json = json!({
+            "result": {
+                "events": [{
+                    "topic": [
+                        xdr(&symbol("transfer")),
+                        xdr(&address(1)),
+                        xdr(&address(2)),
+                        xdr(&ScVal::String("native".try_into().unwrap())),
+                    ],
+                    "value": xdr(&amount),
+                }]
+            }
+        });
+        let events = RecordedEvent::from_json(&json).unwrap();
+
+        let param = |name: &str, ty: &str| FunctionParam {
+            name: name.to_string(),
+            type_name: ty.to_string(),
+        };
+        let signatures = vec![ContractFunctionSignature {
+            name: "transfer".to_string(),
+            params: vec![
+                param("from", "Address"),
+                param("to", "Address"),
+                param("amount", "I128"),
+            ],
+            return_type: None,
+        }];
+
+        let call = events[0].reconstruct_call(&signatures).unwrap();
+        assert_eq!(call.function, "transfer");
+        let args: Vec<Value> = serde_json::from_str(&call.args_json).unwrap();
+        assert_eq!(args.len(), 3);
+        assert_eq!(args[0]["type"], "address");
+        assert_eq!(args[2], json!({ "type": "i128", "value": 250 }));
+    }
+
+    #[test]
+    fn rejects_event_without_function_topic() {
+        let event = RecordedEvent {
+            topics: vec![ScVal::U32(1)],
+            data: ScVal::Void,
+        };
+        assert!(event.reconstruct_call(&[]).is_err());
+    }
+}
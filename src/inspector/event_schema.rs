@@ -0,0 +1,268 @@
+//! Validate emitted contract events against an expected schema.
+//!
+//! Off-chain indexers key on an event's first topic (conventionally a `Symbol`
+//! such as `transfer`) and decode the rest positionally, so a reordered topic or
+//! an extra data field silently breaks them. A schema maps each topic name to the
+//! expected types of the remaining topics and of the data payload:
+//!
+//! ```json
+//! {
+//!   "transfer": { "topics": ["address", "address"], "data": ["i128"] },
+//!   "approve": { "data": ["i128", "u32"] }
+//! }
+//! ```
+//!
+//! Type names are the lowercase `ScVal` variants (`i128`, `address`, `symbol`,
+//! `vec`, ...) or `any`. A `Vec` payload is compared element by element, a `Void`
+//! payload has no elements, and any other payload is a single element. Omitting
+//! `topics` or `data` skips that check.
+
+use super::event_replay::RecordedEvent;
+use crate::{DebuggerError, Result};
+use serde::{Deserialize, Serialize};
+use soroban_env_host::xdr::{ContractEventBody, ContractEventType, ScVal};
+use soroban_env_host::Host;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Type names accepted in a schema, besides `any`.
+const KNOWN_TYPES: &[&str] = &[
+    "bool",
+    "void",
+    "error",
+    "u32",
+    "i32",
+    "u64",
+    "i64",
+    "timepoint",
+    "duration",
+    "u128",
+    "i128",
+    "u256",
+    "i256",
+    "bytes",
+    "string",
+    "symbol",
+    "vec",
+    "map",
+    "address",
+];
+
+/// Expected shape of the events published under one topic name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct EventShape {
+    /// Types of the topics after the name.
+    #[serde(default)]
+    pub topics: Option<Vec<String>>,
+    /// Types of the data payload elements.
+    #[serde(default)]
+    pub data: Option<Vec<String>>,
+}
+
+/// An emitted event that does not match the schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchemaViolation {
+    /// Position of the event among the contract events of the run.
+    pub index: usize,
+    pub topic: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct EventSchema {
+    events: BTreeMap<String, EventShape>,
+}
+
+impl EventSchema {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to read event schema {:?}: {}", path, e))
+        })?;
+        Self::from_json(&contents).map_err(|e| {
+            DebuggerError::InvalidArguments(format!("Invalid event schema {:?}: {}", path, e))
+                .into()
+        })
+    }
+
+    pub fn from_json(json: &str) -> std::result::Result<Self, String> {
+        let events: BTreeMap<String, EventShape> =
+            serde_json::from_str(json).map_err(|e| e.to_string())?;
+        for (name, shape) in &events {
+            let types = shape.topics.iter().chain(shape.data.iter()).flatten();
+            for ty in types {
+                if ty != "any" && !KNOWN_TYPES.contains(&ty.as_str()) {
+                    return Err(format!("unknown type '{}' for event '{}'", ty, name));
+                }
+            }
+        }
+        Ok(Self { events })
+    }
+
+    /// Check every event and return the mismatches, in event order.
+    pub fn validate(&self, events: &[RecordedEvent]) -> Vec<SchemaViolation> {
+        let mut violations = Vec::new();
+        for (index, event) in events.iter().enumerate() {
+            let mut flag = |topic: &str, message: String| {
+                violations.push(SchemaViolation {
+                    index,
+                    topic: topic.to_string(),
+                    message,
+                })
+            };
+
+            let name = match event.topics.first() {
+                Some(ScVal::Symbol(symbol)) => symbol.to_utf8_string_lossy(),
+                Some(other) => {
+                    flag(
+                        type_name(other),
+                        "first topic is not a Symbol naming the event".to_string(),
+                    );
+                    continue;
+                }
+                None => {
+                    flag("", "event has no topics".to_string());
+                    continue;
+                }
+            };
+            let Some(shape) = self.events.get(&name) else {
+                flag(&name, "unexpected topic".to_string());
+                continue;
+            };
+
+            if let Some(expected) = &shape.topics {
+                let actual: Vec<&ScVal> = event.topics.iter().skip(1).collect();
+                if let Some(message) = compare("topics", expected, &actual) {
+                    flag(&name, message);
+                }
+            }
+            if let Some(expected) = &shape.data {
+                let actual: Vec<&ScVal> = match &event.data {
+                    ScVal::Vec(Some(items)) => items.iter().collect(),
+                    ScVal::Void => Vec::new(),
+                    other => vec![other],
+                };
+                if let Some(message) = compare("data", expected, &actual) {
+                    flag(&name, message);
+                }
+            }
+        }
+        violations
+    }
+}
+
+fn compare(what: &str, expected: &[String], actual: &[&ScVal]) -> Option<String> {
+    let actual: Vec<&str> = actual.iter().map(|v| type_name(v)).collect();
+    let matches = expected.len() == actual.len()
+        && expected
+            .iter()
+            .zip(&actual)
+            .all(|(e, a)| e == "any" || e == a);
+    (!matches).then(|| {
+        format!(
+            "{} expected [{}], got [{}]",
+            what,
+            expected.join(", "),
+            actual.join(", ")
+        )
+    })
+}
+
+fn type_name(value: &ScVal) -> &'static str {
+    match value {
+        ScVal::Bool(_) => "bool",
+        ScVal::Void => "void",
+        ScVal::Error(_) => "error",
+        ScVal::U32(_) => "u32",
+        ScVal::I32(_) => "i32",
+        ScVal::U64(_) => "u64",
+        ScVal::I64(_) => "i64",
+        ScVal::Timepoint(_) => "timepoint",
+        ScVal::Duration(_) => "duration",
+        ScVal::U128(_) => "u128",
+        ScVal::I128(_) => "i128",
+        ScVal::U256(_) => "u256",
+        ScVal::I256(_) => "i256",
+        ScVal::Bytes(_) => "bytes",
+        ScVal::String(_) => "string",
+        ScVal::Symbol(_) => "symbol",
+        ScVal::Vec(_) => "vec",
+        ScVal::Map(_) => "map",
+        ScVal::Address(_) => "address",
+        _ => "other",
+    }
+}
+
+/// Contract events published by successful calls in `host`, skipping system and
+/// diagnostic events.
+pub fn contract_events(host: &Host) -> Result<Vec<RecordedEvent>> {
+    let events = host
+        .get_events()
+        .map_err(|e| DebuggerError::ExecutionError(format!("Failed to get events: {}", e)))?
+        .0;
+    Ok(events
+        .iter()
+        .filter(|e| !e.failed_call && e.event.type_ == ContractEventType::Contract)
+        .map(|e| match &e.event.body {
+            ContractEventBody::V0(v0) => RecordedEvent {
+                topics: v0.topics.to_vec(),
+                data: v0.data.clone(),
+            },
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_env_host::xdr::{Hash, Int128Parts, ScAddress, ScSymbol};
+
+    fn symbol(s: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(s.try_into().unwrap()))
+    }
+
+    fn transfer(data: ScVal) -> RecordedEvent {
+        let address = ScVal::Address(ScAddress::Contract(Hash([1; 32])));
+        RecordedEvent {
+            topics: vec![symbol("transfer"), address.clone(), address],
+            data,
+        }
+    }
+
+    #[test]
+    fn flags_unexpected_topics_and_data_shapes() {
+        let schema = EventSchema::from_json(
+            r#"{ "transfer": { "topics": ["address", "address"], "data": ["i128"] } }"#,
+        )
+        .unwrap();
+        let amount = ScVal::I128(Int128Parts { hi: 0, lo: 5 });
+        let events = vec![
+            transfer(amount.clone()),
+            transfer(ScVal::Vec(Some(
+                vec![amount.clone(), ScVal::U32(1)].try_into().unwrap(),
+            ))),
+            RecordedEvent {
+                topics: vec![symbol("burn")],
+                data: amount,
+            },
+        ];
+
+        let violations = schema.validate(&events);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].index, 1);
+        assert_eq!(
+            violations[0].message,
+            "data expected [i128], got [i128, u32]"
+        );
+        assert_eq!(violations[1].topic, "burn");
+        assert_eq!(violations[1].message, "unexpected topic");
+    }
+
+    #[test]
+    fn rejects_unknown_type_names() {
+        let err = EventSchema::from_json(r#"{ "transfer": { "data": ["int"] } }"#).unwrap_err();
+        assert!(err.contains("unknown type 'int'"));
+        assert!(EventSchema::from_json(r#"{ "transfer": { "data": ["any"] } }"#).is_ok());
+    }
+}
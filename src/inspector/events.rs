@@ -1,6 +1,11 @@
+use crate::utils::wasm::ContractFunctionSignature;
 use crate::{DebuggerError, Result};
 use serde::{Deserialize, Serialize};
-use soroban_env_host::{xdr::ContractEventBody, Host};
+use serde_json::Value;
+use soroban_env_host::{
+    xdr::{ContractEventBody, Hash, ScVal},
+    Host,
+};
 
 /// Represents a captured contract event
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +18,124 @@ pub struct ContractEvent {
 
     /// Event data/payload (stringified)
     pub data: String,
+
+    /// Topics and data decoded against the contract spec, when it has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decoded: Option<Value>,
+}
+
+/// Decodes events into named JSON using the contract's function spec.
+///
+/// Contracts commonly publish a call's arguments in the event it emits, for
+/// example the token `transfer` event has `from` and `to` as topics and
+/// `amount` as data. When the first topic is a `Symbol` naming a spec function,
+/// the remaining topics are matched to that function's leading parameters
+/// position by position, and the data to the rest: a single value for one
+/// parameter, a `Vec` with one item per parameter for several. An event whose
+/// shape or types differ from the signature is left undecoded rather than
+/// labelled with the wrong names.
+///
+/// [`RecordedEvent::reconstruct_call`]: crate::inspector::event_replay::RecordedEvent::reconstruct_call
+#[derive(Debug, Clone)]
+pub struct EventDecoder {
+    signatures: Vec<ContractFunctionSignature>,
+    contract: Option<Hash>,
+}
+
+impl EventDecoder {
+    pub fn new(signatures: Vec<ContractFunctionSignature>) -> Self {
+        Self {
+            signatures,
+            contract: None,
+        }
+    }
+
+    /// Build a decoder from the WASM `contractspecv0` section, or `None` when
+    /// the contract has no spec.
+    pub fn from_wasm(wasm_bytes: &[u8]) -> Result<Option<Self>> {
+        let signatures = crate::utils::wasm::parse_function_signatures(wasm_bytes)?;
+        Ok((!signatures.is_empty()).then(|| Self::new(signatures)))
+    }
+
+    /// Only decode events emitted by `contract`; other contracts' events keep
+    /// their raw form since this spec does not describe them.
+    pub fn for_contract(mut self, contract: Hash) -> Self {
+        self.contract = Some(contract);
+        self
+    }
+
+    /// Decode one event, or `None` when it cannot be matched to the spec.
+    pub fn decode(&self, contract: Option<&Hash>, topics: &[ScVal], data: &ScVal) -> Option<Value> {
+        if self.contract.is_some() && self.contract.as_ref() != contract {
+            return None;
+        }
+        let ScVal::Symbol(symbol) = topics.first()? else {
+            return None;
+        };
+        let name = symbol.to_utf8_string_lossy();
+        let signature = self.signatures.iter().find(|s| s.name == name)?;
+
+        let topic_values = &topics[1..];
+        let data_params = signature.params.len().checked_sub(topic_values.len())?;
+        let mut values: Vec<&ScVal> = topic_values.iter().collect();
+        match (data_params, data) {
+            (0, ScVal::Void) => {}
+            (0, _) => return None,
+            (1, value) => values.push(value),
+            (n, ScVal::Vec(Some(items))) if items.len() == n => values.extend(items.iter()),
+            _ => return None,
+        }
+
+        let mut fields = serde_json::Map::new();
+        for (param, value) in signature.params.iter().zip(values) {
+            if !crate::inspector::event_replay::scval_fits_type(value, &param.type_name) {
+                return None;
+            }
+            fields.insert(param.name.clone(), scval_to_json(value));
+        }
+
+        let mut event = serde_json::Map::new();
+        event.insert(name, Value::Object(fields));
+        Some(Value::Object(event))
+    }
+}
+
+/// Render a value as plain JSON: numbers as numbers (128-bit values that do not
+/// fit in 64 bits as strings), addresses as strkeys and bytes as hex.
+fn scval_to_json(value: &ScVal) -> Value {
+    match value {
+        ScVal::Bool(b) => Value::from(*b),
+        ScVal::Void => Value::Null,
+        ScVal::U32(n) => Value::from(*n),
+        ScVal::I32(n) => Value::from(*n),
+        ScVal::U64(n) => Value::from(*n),
+        ScVal::I64(n) => Value::from(*n),
+        ScVal::U128(parts) => {
+            let n = (u128::from(parts.hi) << 64) | u128::from(parts.lo);
+            u64::try_from(n).map_or(Value::from(n.to_string()), Value::from)
+        }
+        ScVal::I128(parts) => {
+            let n = (i128::from(parts.hi) << 64) | i128::from(parts.lo);
+            i64::try_from(n).map_or(Value::from(n.to_string()), Value::from)
+        }
+        ScVal::Symbol(s) => Value::from(s.to_utf8_string_lossy()),
+        ScVal::String(s) => Value::from(s.to_utf8_string_lossy()),
+        ScVal::Address(address) => Value::from(address.to_string()),
+        ScVal::Bytes(bytes) => Value::from(format!("0x{}", hex::encode(bytes))),
+        ScVal::Vec(Some(items)) => Value::Array(items.iter().map(scval_to_json).collect()),
+        ScVal::Map(Some(map)) => Value::Object(
+            map.iter()
+                .map(|entry| {
+                    let key = match scval_to_json(&entry.key) {
+                        Value::String(key) => key,
+                        other => other.to_string(),
+                    };
+                    (key, scval_to_json(&entry.val))
+                })
+                .collect(),
+        ),
+        other => Value::from(format!("{:?}", other)),
+    }
 }
 
 pub struct EventInspector;
@@ -20,6 +143,15 @@ pub struct EventInspector;
 impl EventInspector {
     /// Extract events from the host and convert them to a friendly format
     pub fn get_events(host: &Host) -> Result<Vec<ContractEvent>> {
+        Self::get_events_with_decoder(host, None)
+    }
+
+    /// Like [`get_events`](Self::get_events), additionally decoding each event
+    /// with `decoder` when one is given.
+    pub fn get_events_with_decoder(
+        host: &Host,
+        decoder: Option<&EventDecoder>,
+    ) -> Result<Vec<ContractEvent>> {
         let events = host
             .get_events()
             .map_err(|e| DebuggerError::ExecutionError(format!("Failed to get events: {}", e)))?
@@ -30,14 +162,16 @@ impl EventInspector {
             let event = &host_event.event;
 
             // Extract topics and data from event body
-            let (topics, data) = match &event.body {
+            let (topics, data, decoded) = match &event.body {
                 ContractEventBody::V0(v0) => {
                     let mut topics = Vec::new();
                     for topic in v0.topics.iter() {
                         topics.push(format!("{:?}", topic));
                     }
                     let data = format!("{:?}", v0.data);
-                    (topics, data)
+                    let decoded = decoder
+                        .and_then(|d| d.decode(event.contract_id.as_ref(), &v0.topics, &v0.data));
+                    (topics, data, decoded)
                 }
             };
 
@@ -49,6 +183,7 @@ impl EventInspector {
                 contract_id,
                 topics,
                 data,
+                decoded,
             });
         }
 
@@ -86,8 +221,13 @@ impl EventInspector {
                 "  Contract: {}",
                 ev.contract_id.as_deref().unwrap_or("<none>")
             ));
-            out.push(format!("  Topics: {:?}", ev.topics));
-            out.push(format!("  Data: {}", ev.data));
+            match &ev.decoded {
+                Some(decoded) => out.push(format!("  Event: {}", decoded)),
+                None => {
+                    out.push(format!("  Topics: {:?}", ev.topics));
+                    out.push(format!("  Data: {}", ev.data));
+                }
+            }
         }
         out
     }
@@ -97,16 +237,46 @@ impl EventInspector {
         let arr: Vec<serde_json::Value> = events
             .iter()
             .map(|e| {
-                serde_json::json!({
+                let mut value = serde_json::json!({
                     "contract_id": e.contract_id,
                     "topics": e.topics,
                     "data": e.data,
-                })
+                });
+                if let Some(decoded) = &e.decoded {
+                    value["decoded"] = decoded.clone();
+                }
+                value
             })
             .collect();
         serde_json::Value::Array(arr)
     }
 
+    /// Convert events into CloudEvents 1.0 JSON objects.
+    ///
+    /// `source` is the emitting contract id, and `data` carries the topics and value.
+    /// Event ids are `<run_id>-<index>` so they are unique within and across runs.
+    pub fn to_cloudevents(events: &[ContractEvent], run_id: &str) -> Vec<serde_json::Value> {
+        let time = chrono::Utc::now().to_rfc3339();
+        events
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                serde_json::json!({
+                    "specversion": "1.0",
+                    "id": format!("{}-{}", run_id, i),
+                    "type": "org.stellar.soroban.contract_event",
+                    "source": e.contract_id.as_deref().unwrap_or("urn:soroban:unknown-contract"),
+                    "time": time,
+                    "datacontenttype": "application/json",
+                    "data": {
+                        "topics": e.topics,
+                        "value": e.data,
+                    },
+                })
+            })
+            .collect()
+    }
+
     /// Return the events emitted since the previous snapshot length.
     pub fn events_since(events: &[ContractEvent], previous_len: usize) -> Vec<ContractEvent> {
         events.iter().skip(previous_len).cloned().collect()
@@ -124,16 +294,19 @@ mod tests {
                 contract_id: None,
                 topics: vec!["topic1".to_string(), "common".to_string()],
                 data: "data1".to_string(),
+                decoded: None,
             },
             ContractEvent {
                 contract_id: None,
                 topics: vec!["topic2".to_string(), "common".to_string()],
                 data: "data2".to_string(),
+                decoded: None,
             },
             ContractEvent {
                 contract_id: None,
                 topics: vec!["topic3".to_string()],
                 data: "data3".to_string(),
+                decoded: None,
             },
         ];
 
@@ -155,11 +328,13 @@ mod tests {
                 contract_id: None,
                 topics: vec!["topic1".to_string()],
                 data: "data1".to_string(),
+                decoded: None,
             },
             ContractEvent {
                 contract_id: None,
                 topics: vec!["topic2".to_string()],
                 data: "data2".to_string(),
+                decoded: None,
             },
         ];
 
@@ -167,4 +342,125 @@ mod tests {
         assert_eq!(delta.len(), 1);
         assert_eq!(delta[0].data, "data2");
     }
+
+    fn token_decoder() -> EventDecoder {
+        use crate::utils::wasm::FunctionParam;
+        let param = |name: &str, ty: &str| FunctionParam {
+            name: name.to_string(),
+            type_name: ty.to_string(),
+        };
+        EventDecoder::new(vec![ContractFunctionSignature {
+            name: "transfer".to_string(),
+            params: vec![
+                param("from", "Address"),
+                param("to", "Address"),
+                param("amount", "I128"),
+            ],
+            return_type: None,
+        }])
+    }
+
+    #[test]
+    fn test_decoder_names_transfer_fields() {
+        use soroban_env_host::xdr::{Int128Parts, ScAddress, ScSymbol};
+
+        let from = ScAddress::Contract(Hash([1; 32]));
+        let to = ScAddress::Contract(Hash([2; 32]));
+        let topics = vec![
+            ScVal::Symbol(ScSymbol("transfer".try_into().unwrap())),
+            ScVal::Address(from.clone()),
+            ScVal::Address(to.clone()),
+        ];
+        let data = ScVal::I128(Int128Parts { hi: 0, lo: 400 });
+
+        let decoded = token_decoder().decode(None, &topics, &data).unwrap();
+        assert_eq!(
+            decoded,
+            serde_json::json!({
+                "transfer": {
+                    "from": from.to_string(),
+                    "to": to.to_string(),
+                    "amount": 400,
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_decoder_requires_positional_match() {
+        use soroban_env_host::xdr::{Int128Parts, ScAddress, ScSymbol};
+
+        let admin = ScVal::Address(ScAddress::Contract(Hash([1; 32])));
+        let to = ScVal::Address(ScAddress::Contract(Hash([2; 32])));
+        let amount = ScVal::I128(Int128Parts { hi: 0, lo: 400 });
+        let transfer = ScVal::Symbol(ScSymbol("transfer".try_into().unwrap()));
+        let decoder = token_decoder();
+
+        // An extra leading topic would shift `from` onto `admin`.
+        let topics = vec![transfer.clone(), admin.clone(), admin.clone(), to.clone()];
+        assert!(decoder.decode(None, &topics, &amount).is_none());
+
+        // Too few values, or a value in the wrong position, do not decode.
+        let topics = vec![transfer.clone(), admin.clone()];
+        assert!(decoder.decode(None, &topics, &amount).is_none());
+        let topics = vec![transfer.clone(), admin.clone(), amount.clone()];
+        assert!(decoder.decode(None, &topics, &to).is_none());
+
+        // Data carrying several parameters is a Vec with one item each.
+        let topics = vec![transfer, admin.clone()];
+        let data = ScVal::Vec(Some(vec![to, amount].try_into().unwrap()));
+        let decoded = decoder.decode(None, &topics, &data).unwrap();
+        assert_eq!(decoded["transfer"]["amount"], 400);
+    }
+
+    #[test]
+    fn test_decoder_skips_unknown_events_and_other_contracts() {
+        use soroban_env_host::xdr::ScSymbol;
+
+        let topics = vec![ScVal::Symbol(ScSymbol("mint".try_into().unwrap()))];
+        assert!(token_decoder()
+            .decode(None, &topics, &ScVal::Void)
+            .is_none());
+
+        let topics = vec![ScVal::Symbol(ScSymbol("transfer".try_into().unwrap()))];
+        let decoder = token_decoder().for_contract(Hash([9; 32]));
+        assert!(decoder
+            .decode(Some(&Hash([8; 32])), &topics, &ScVal::Void)
+            .is_none());
+    }
+
+    #[test]
+    fn test_format_events_prefers_decoded_form() {
+        let mut event = ContractEvent {
+            contract_id: None,
+            topics: vec!["Symbol(transfer)".to_string()],
+            data: "I128(400)".to_string(),
+            decoded: None,
+        };
+        assert!(EventInspector::format_events(std::slice::from_ref(&event))
+            .iter()
+            .any(|line| line.starts_with("  Data: ")));
+
+        event.decoded = Some(serde_json::json!({ "transfer": { "amount": 400 } }));
+        let lines = EventInspector::format_events(&[event]);
+        assert!(lines.contains(&r#"  Event: {"transfer":{"amount":400}}"#.to_string()));
+    }
+
+    #[test]
+    fn test_to_cloudevents_envelope() {
+        let events = vec![ContractEvent {
+            contract_id: Some("CCONTRACT".to_string()),
+            topics: vec!["transfer".to_string()],
+            data: "100".to_string(),
+            decoded: None,
+        }];
+
+        let cloud = EventInspector::to_cloudevents(&events, "run1");
+        assert_eq!(cloud.len(), 1);
+        assert_eq!(cloud[0]["specversion"], "1.0");
+        assert_eq!(cloud[0]["id"], "run1-0");
+        assert_eq!(cloud[0]["source"], "CCONTRACT");
+        assert_eq!(cloud[0]["data"]["topics"][0], "transfer");
+        assert_eq!(cloud[0]["data"]["value"], "100");
+    }
 }
@@ -1,5 +1,8 @@
 use crossterm::style::{Color, Stylize};
 use serde::{Deserialize, Serialize};
+use soroban_env_host::budget::AsBudget;
+use soroban_env_host::Host;
+use std::collections::HashMap;
 use std::fmt;
 
 /// Default TTL warning threshold in ledger sequence numbers.
@@ -38,6 +41,55 @@ pub struct LedgerEntryInfo {
     pub is_read: bool,
     /// Whether this entry was written
     pub is_write: bool,
+    /// Last ledger the entry is live for, when read from the host
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub live_until_ledger: Option<u32>,
+    /// Ledgers added to the entry's TTL during execution (`extend_ttl`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extended_by: Option<u32>,
+}
+
+/// TTL of a ledger entry as recorded by the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntryTtl {
+    /// Last ledger the entry is live for after execution.
+    pub live_until_ledger: u32,
+    /// Ledger sequence the execution ran at.
+    pub current_ledger: u32,
+    /// `live_until_ledger` before execution, if the entry already existed.
+    pub live_until_before: Option<u32>,
+}
+
+impl EntryTtl {
+    /// Ledgers left before the entry is archived.
+    pub fn remaining(&self) -> u32 {
+        self.live_until_ledger.saturating_sub(self.current_ledger)
+    }
+
+    /// Ledgers the contract added with `extend_ttl`, if it bumped the entry.
+    pub fn extended_by(&self) -> Option<u32> {
+        self.live_until_before
+            .filter(|before| self.live_until_ledger > *before)
+            .map(|before| self.live_until_ledger - before)
+    }
+}
+
+/// Capture the `live_until_ledger` of every entry in the host's storage,
+/// keyed by the debug-formatted ledger key.
+pub fn capture_live_until(host: &Host) -> HashMap<String, u32> {
+    let captured = host.with_mut_storage(|storage| {
+        let mut live_until = HashMap::new();
+        for (key, entry_opt) in storage.map.iter(host.as_budget())? {
+            if let Some((_, Some(ledger))) = entry_opt.as_ref() {
+                live_until.insert(format!("{:?}", **key), *ledger);
+            }
+        }
+        Ok(live_until)
+    });
+    captured.unwrap_or_else(|e| {
+        tracing::warn!("Failed to capture entry TTLs: {}", e);
+        HashMap::new()
+    })
 }
 
 impl LedgerEntryInfo {
@@ -92,6 +144,31 @@ impl LedgerEntryInspector {
             ttl,
             is_read,
             is_write,
+            live_until_ledger: None,
+            extended_by: None,
+        });
+    }
+
+    /// Add a tracked ledger entry whose TTL was read from the host. The
+    /// entry's `ttl` is the remaining TTL as of the execution ledger.
+    pub fn add_entry_with_ttl(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+        storage_type: StorageType,
+        ttl: EntryTtl,
+        is_read: bool,
+        is_write: bool,
+    ) {
+        self.entries.push(LedgerEntryInfo {
+            key: key.into(),
+            value: value.into(),
+            storage_type,
+            ttl: ttl.remaining(),
+            is_read,
+            is_write,
+            live_until_ledger: Some(ttl.live_until_ledger),
+            extended_by: ttl.extended_by(),
         });
     }
 
@@ -201,12 +278,17 @@ impl LedgerEntryInspector {
                     Color::Green
                 };
 
+                let ttl_display = match entry.extended_by {
+                    Some(extended) => format!("{} (+{})", entry.ttl, extended),
+                    None => entry.ttl.to_string(),
+                };
+
                 crate::logging::log_display(
                     format!(
                         "  {:<30} | {:<8} | {:<10} | {}",
                         key_display.with(Color::White),
                         access.with(Color::Yellow),
-                        entry.ttl.to_string().with(ttl_color),
+                        ttl_display.with(ttl_color),
                         value_display.with(Color::DarkGrey)
                     ),
                     crate::logging::LogLevel::Info,
@@ -297,6 +379,8 @@ impl LedgerEntryInspector {
                     "value": e.value,
                     "storage_type": e.storage_type.to_string(),
                     "ttl": e.ttl,
+                    "live_until_ledger": e.live_until_ledger,
+                    "extended_by": e.extended_by,
                     "is_read": e.is_read,
                     "is_write": e.is_write,
                     "near_expiry": e.is_near_expiry(self.ttl_warning_threshold),
@@ -433,6 +517,8 @@ mod tests {
             ttl: 500,
             is_read: true,
             is_write: false,
+            live_until_ledger: None,
+            extended_by: None,
         };
 
         assert!(entry.is_near_expiry(1000));
@@ -440,6 +526,54 @@ mod tests {
         assert!(!entry.is_near_expiry(100));
     }
 
+    #[test]
+    fn test_add_entry_with_host_ttl() {
+        let mut inspector = LedgerEntryInspector::new();
+        inspector.add_entry_with_ttl(
+            "balance:alice",
+            "1000",
+            StorageType::Persistent,
+            EntryTtl {
+                live_until_ledger: 1_500,
+                current_ledger: 1_000,
+                live_until_before: Some(1_200),
+            },
+            true,
+            true,
+        );
+        inspector.add_entry_with_ttl(
+            "session:xyz",
+            "active",
+            StorageType::Temporary,
+            EntryTtl {
+                live_until_ledger: 900,
+                current_ledger: 1_000,
+                live_until_before: Some(900),
+            },
+            true,
+            false,
+        );
+
+        let entries = inspector.get_entries();
+        assert_eq!(entries[0].ttl, 500);
+        assert_eq!(entries[0].live_until_ledger, Some(1_500));
+        assert_eq!(entries[0].extended_by, Some(300));
+        assert_eq!(entries[1].ttl, 0);
+        assert_eq!(entries[1].extended_by, None);
+
+        // The warning threshold compares against the remaining TTL.
+        inspector.set_ttl_warning_threshold(600);
+        assert_eq!(inspector.get_near_expiry_entries().len(), 2);
+        inspector.set_ttl_warning_threshold(100);
+        let near_expiry = inspector.get_near_expiry_entries();
+        assert_eq!(near_expiry.len(), 1);
+        assert_eq!(near_expiry[0].key, "session:xyz");
+
+        let json = inspector.to_json();
+        assert_eq!(json["entries"][0]["extended_by"], 300);
+        assert_eq!(json["entries"][1]["live_until_ledger"], 900);
+    }
+
     #[test]
     fn test_storage_type_display() {
         assert_eq!(format!("{}", StorageType::Instance), "Instance");
@@ -1,5 +1,7 @@
 pub mod auth;
 pub mod budget;
+pub mod event_replay;
+pub mod event_schema;
 pub mod events;
 pub mod instructions;
 pub mod ledger;
@@ -7,7 +9,7 @@ pub mod stack;
 pub mod storage;
 
 pub use auth::AuthInspector;
-pub use budget::{BudgetInfo, BudgetInspector, MemorySummary, MemoryTracker};
+pub use budget::{BudgetInfo, BudgetInspector, MemorySummary, MemoryTracker, NetworkLimits};
 pub use instructions::{FunctionInstructionCount, InstructionCounter};
 pub use ledger::LedgerEntryInspector;
 pub use stack::CallStackInspector;
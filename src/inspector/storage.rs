@@ -3,12 +3,17 @@ use crossterm::style::{Color, Stylize};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use soroban_env_host::budget::AsBudget;
-use soroban_env_host::xdr::{LedgerEntryData, LedgerKey};
+use soroban_env_host::xdr::{LedgerEntryData, LedgerKey, Limits, ScVal, WriteXdr};
 use soroban_env_host::Host;
 use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 
+/// Default cap on the serialized size of a single contract data ledger entry
+/// (`contract_data_entry_size_bytes` in the Soroban network configuration).
+/// All instance storage for a contract lives inside one such entry.
+pub const DEFAULT_MAX_INSTANCE_ENTRY_BYTES: usize = 65_536;
+
 /// Represents a storage key filter pattern
 #[derive(Debug, Clone)]
 pub enum FilterPattern {
@@ -170,6 +175,99 @@ impl StorageFilter {
     }
 }
 
+/// Masks values of sensitive storage entries and events before they are printed.
+///
+/// Patterns use [`StorageFilter`] syntax and are matched against storage keys and
+/// event topics. Keys and topics stay visible; only the values are replaced with
+/// [`StorageRedactor::MASK`]. Ledger entries are matched by their snapshot key
+/// (see [`StorageInspector::snapshot_key`]).
+#[derive(Debug, Clone)]
+pub struct StorageRedactor {
+    filter: StorageFilter,
+}
+
+impl StorageRedactor {
+    pub const MASK: &'static str = "***";
+
+    pub fn new(patterns: &[String]) -> std::result::Result<Self, String> {
+        Ok(Self {
+            filter: StorageFilter::new(patterns)?,
+        })
+    }
+
+    /// Returns true if no redaction patterns are configured.
+    pub fn is_empty(&self) -> bool {
+        self.filter.is_empty()
+    }
+
+    fn should_redact(&self, key: &str) -> bool {
+        !self.filter.is_empty() && self.filter.matches(key)
+    }
+
+    /// Copy of `snapshot` with the values of matching keys masked.
+    pub fn redact_snapshot(&self, snapshot: &HashMap<String, String>) -> HashMap<String, String> {
+        snapshot
+            .iter()
+            .map(|(key, value)| {
+                let value = if self.should_redact(key) {
+                    Self::MASK.to_string()
+                } else {
+                    value.clone()
+                };
+                (key.clone(), value)
+            })
+            .collect()
+    }
+
+    /// Mask added and modified values in a diff. Deleted keys carry no value.
+    pub fn redact_diff(&self, diff: &mut StorageDiff) {
+        for (key, value) in diff.added.iter_mut() {
+            if self.should_redact(key) {
+                *value = Self::MASK.to_string();
+            }
+        }
+        for (key, (old, new)) in diff.modified.iter_mut() {
+            if self.should_redact(key) {
+                *old = Self::MASK.to_string();
+                *new = Self::MASK.to_string();
+            }
+        }
+    }
+
+    /// `value` of the ledger entry under `key`, masked if its snapshot key matches.
+    pub fn redact_ledger_value(&self, key: &LedgerKey, value: String) -> String {
+        if self.should_redact(&StorageInspector::snapshot_key(key)) {
+            Self::MASK.to_string()
+        } else {
+            value
+        }
+    }
+
+    /// Mask the call arguments and `--storage` seed echoed in JSON run metadata.
+    /// They are raw user input with no storage keys to match against, so any
+    /// configured pattern masks them whole.
+    pub fn redact_run_metadata(&self, meta: &mut crate::output::RunMetadata) {
+        if self.is_empty() {
+            return;
+        }
+        for input in [&mut meta.args, &mut meta.storage] {
+            if input.is_some() {
+                *input = Some(Self::MASK.to_string());
+            }
+        }
+    }
+
+    /// Mask the data of every event with at least one matching topic.
+    pub fn redact_events(&self, events: &mut [crate::inspector::events::ContractEvent]) {
+        for event in events {
+            if event.topics.iter().any(|topic| self.should_redact(topic)) {
+                event.data = Self::MASK.to_string();
+                event.decoded = None;
+            }
+        }
+    }
+}
+
 impl StorageQuery {
     pub fn normalized_filter(&self) -> Option<&str> {
         self.filter.as_deref().map(str::trim).filter(|s| !s.is_empty())
@@ -527,6 +625,24 @@ impl StorageInspector {
 
     /// Capture a snapshot of all storage entries from the host
     pub fn capture_snapshot(host: &Host) -> HashMap<String, String> {
+        Self::capture_snapshot_with(host, &|val| format!("{:?}", val))
+    }
+
+    /// The key under which a ledger entry appears in a storage snapshot.
+    pub fn snapshot_key(key: &LedgerKey) -> String {
+        match key {
+            LedgerKey::ContractData(cd) => {
+                format!("contract_data:{:?}:{:?}", cd.durability, cd.key)
+            }
+            LedgerKey::ContractCode(_) => "contract_code".to_string(),
+            other => format!("{:?}", other),
+        }
+    }
+
+    fn capture_snapshot_with(
+        host: &Host,
+        render: &dyn Fn(&ScVal) -> String,
+    ) -> HashMap<String, String> {
         match host.with_mut_storage(|storage| {
             let mut snapshot = HashMap::new();
 
@@ -535,16 +651,10 @@ impl StorageInspector {
                     continue;
                 };
 
-                let key_str = match key.as_ref() {
-                    LedgerKey::ContractData(cd) => {
-                        format!("contract_data:{:?}:{:?}", cd.durability, cd.key)
-                    }
-                    LedgerKey::ContractCode(_) => "contract_code".to_string(),
-                    other => format!("{:?}", other),
-                };
+                let key_str = Self::snapshot_key(key);
 
                 let mut value_str = match &entry.as_ref().data {
-                    LedgerEntryData::ContractData(cd) => format!("{:?}", cd.val),
+                    LedgerEntryData::ContractData(cd) => render(&cd.val),
                     other => format!("{:?}", other),
                 };
 
@@ -565,6 +675,137 @@ impl StorageInspector {
         }
     }
 
+    /// Capture the entries of every contract instance's storage map, keyed by
+    /// the key's plain name (`c` rather than `Symbol(ScSymbol(StringM(c)))`) so
+    /// watchpoints and alerts can target individual instance keys.
+    pub fn capture_instance_entries(host: &Host) -> HashMap<String, String> {
+        let captured = host.with_mut_storage(|storage| {
+            let mut entries = HashMap::new();
+
+            for (_key, entry_opt) in storage.map.iter(host.as_budget())? {
+                let Some((entry, _ttl)) = entry_opt.as_ref() else {
+                    continue;
+                };
+                let LedgerEntryData::ContractData(cd) = &entry.as_ref().data else {
+                    continue;
+                };
+                let ScVal::ContractInstance(instance) = &cd.val else {
+                    continue;
+                };
+
+                if let Some(map) = &instance.storage {
+                    for item in map.iter() {
+                        let key = match &item.key {
+                            ScVal::Symbol(symbol) => symbol.to_utf8_string_lossy(),
+                            ScVal::String(string) => string.to_utf8_string_lossy(),
+                            other => format!("{:?}", other),
+                        };
+                        entries.insert(key, format!("{:?}", item.val));
+                    }
+                }
+            }
+
+            Ok(entries)
+        });
+
+        captured.unwrap_or_else(|e| {
+            tracing::warn!("Failed to capture instance storage: {}", e);
+            HashMap::new()
+        })
+    }
+
+    /// Capture the `Symbol`-keyed instance storage entries as typed JSON values,
+    /// in the plain-object form `--storage` and `set_initial_storage` accept, so
+    /// the captured state can be seeded back into a fresh executor.
+    pub fn capture_instance_seed(host: &Host) -> BTreeMap<String, serde_json::Value> {
+        let captured = host.with_mut_storage(|storage| {
+            let mut entries = BTreeMap::new();
+
+            for (_key, entry_opt) in storage.map.iter(host.as_budget())? {
+                let Some((entry, _ttl)) = entry_opt.as_ref() else {
+                    continue;
+                };
+                let LedgerEntryData::ContractData(cd) = &entry.as_ref().data else {
+                    continue;
+                };
+                let ScVal::ContractInstance(instance) = &cd.val else {
+                    continue;
+                };
+
+                for item in instance.storage.iter().flat_map(|map| map.iter()) {
+                    let ScVal::Symbol(symbol) = &item.key else {
+                        tracing::warn!("Skipping non-symbol instance key {:?}", item.key);
+                        continue;
+                    };
+                    match crate::inspector::event_replay::scval_to_typed_json(&item.val) {
+                        Ok(value) => {
+                            entries.insert(symbol.to_utf8_string_lossy(), value);
+                        }
+                        Err(e) => tracing::warn!(
+                            "Skipping instance entry {}: {}",
+                            symbol.to_utf8_string_lossy(),
+                            e
+                        ),
+                    }
+                }
+            }
+
+            Ok(entries)
+        });
+
+        captured.unwrap_or_else(|e| {
+            tracing::warn!("Failed to capture instance storage: {}", e);
+            BTreeMap::new()
+        })
+    }
+
+    /// Measure the serialized size of every instance storage entry held by the host.
+    pub fn instance_storage_report(host: &Host, limit_bytes: usize) -> InstanceStorageReport {
+        let measured = host.with_mut_storage(|storage| {
+            let mut total_bytes = 0usize;
+            let mut entries = Vec::new();
+
+            for (_key, entry_opt) in storage.map.iter(host.as_budget())? {
+                let Some((entry, _ttl)) = entry_opt.as_ref() else {
+                    continue;
+                };
+                let LedgerEntryData::ContractData(cd) = &entry.as_ref().data else {
+                    continue;
+                };
+                let ScVal::ContractInstance(instance) = &cd.val else {
+                    continue;
+                };
+
+                total_bytes = total_bytes.max(xdr_len(entry.as_ref()));
+                if let Some(map) = &instance.storage {
+                    for item in map.iter() {
+                        entries.push(InstanceEntrySize {
+                            key: format!("{:?}", item.key),
+                            size_bytes: xdr_len(&item.key) + xdr_len(&item.val),
+                        });
+                    }
+                }
+            }
+
+            Ok((total_bytes, entries))
+        });
+
+        let (total_bytes, mut entries) = match measured {
+            Ok(measured) => measured,
+            Err(e) => {
+                tracing::warn!("Failed to measure instance storage: {}", e);
+                (0, Vec::new())
+            }
+        };
+        entries.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes).then(a.key.cmp(&b.key)));
+
+        InstanceStorageReport {
+            limit_bytes,
+            total_bytes,
+            entries,
+        }
+    }
+
     /// Compute the difference between two storage snapshots
     pub fn compute_diff(
         before: &HashMap<String, String>,
@@ -686,6 +927,212 @@ impl StorageInspector {
     }
 }
 
+/// Renders stored enum discriminants as `Enum::Variant` using the contract spec.
+///
+/// Storage does not record which `#[contracttype]` a value was written as, so a
+/// key is joined to an enum by name: a key symbol `Status` matches `EscrowStatus`
+/// (and vice versa). Keys matching more than one enum are left undecoded.
+#[derive(Debug, Clone, Default)]
+pub struct EnumValueRenderer {
+    enums: Vec<crate::utils::wasm::ContractEnum>,
+}
+
+impl EnumValueRenderer {
+    pub fn new(enums: Vec<crate::utils::wasm::ContractEnum>) -> Self {
+        Self { enums }
+    }
+
+    /// Build a renderer from the contract spec embedded in `wasm_bytes`.
+    pub fn from_wasm(wasm_bytes: &[u8]) -> Self {
+        Self::new(crate::utils::wasm::parse_contract_enums(wasm_bytes).unwrap_or_default())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.enums.is_empty()
+    }
+
+    /// Render `value` for storage `key`, decoding a `U32(n)` discriminant when the
+    /// key resolves to a known enum. Any trailing annotation (e.g. TTL) is kept.
+    pub fn render(&self, key: &str, value: &str) -> String {
+        let Some(rest) = value.strip_prefix("U32(") else {
+            return value.to_string();
+        };
+        let Some((digits, suffix)) = rest.split_once(')') else {
+            return value.to_string();
+        };
+        let Ok(discriminant) = digits.parse::<u32>() else {
+            return value.to_string();
+        };
+        let Some(contract_enum) = self.enum_for_key(key) else {
+            return value.to_string();
+        };
+        match contract_enum.variant_for(discriminant) {
+            Some(variant) => format!("{}::{}{}", contract_enum.name, variant, suffix),
+            None => value.to_string(),
+        }
+    }
+
+    /// Apply [`Self::render`] to every entry of a storage snapshot.
+    pub fn render_snapshot(&self, snapshot: &HashMap<String, String>) -> HashMap<String, String> {
+        snapshot
+            .iter()
+            .map(|(k, v)| (k.clone(), self.render(k, v)))
+            .collect()
+    }
+
+    fn enum_for_key(&self, key: &str) -> Option<&crate::utils::wasm::ContractEnum> {
+        let ident = key_identifier(key)?.to_ascii_lowercase();
+        if let Some(exact) = self
+            .enums
+            .iter()
+            .find(|e| e.name.to_ascii_lowercase() == ident)
+        {
+            return Some(exact);
+        }
+
+        let mut candidates = self.enums.iter().filter(|e| {
+            let name = e.name.to_ascii_lowercase();
+            name.ends_with(&ident) || ident.ends_with(&name)
+        });
+        match (candidates.next(), candidates.next()) {
+            (Some(only), None) => Some(only),
+            _ => None,
+        }
+    }
+}
+
+/// First symbol-like identifier in a storage key, e.g. `Status` from
+/// `contract_data:Persistent:Symbol(ScSymbol(StringM(Status)))`.
+fn key_identifier(key: &str) -> Option<&str> {
+    if let Some(start) = key.find("StringM(") {
+        let rest = &key[start + "StringM(".len()..];
+        return rest.split(')').next().filter(|s| !s.is_empty());
+    }
+    key.rsplit(':')
+        .next()
+        .map(str::trim)
+        .filter(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+}
+
+/// Serialized size of a single key/value pair held in instance storage.
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceEntrySize {
+    pub key: String,
+    pub size_bytes: usize,
+}
+
+/// Instance storage usage measured against the ledger entry size limit.
+#[derive(Debug, Clone, Serialize)]
+pub struct InstanceStorageReport {
+    pub limit_bytes: usize,
+    /// Serialized size of the largest contract instance ledger entry.
+    pub total_bytes: usize,
+    /// Per-key sizes, largest first.
+    pub entries: Vec<InstanceEntrySize>,
+}
+
+impl InstanceStorageReport {
+    /// Keys whose serialized key + value alone exceed the limit.
+    pub fn oversized_entries(&self) -> Vec<&InstanceEntrySize> {
+        self.entries
+            .iter()
+            .filter(|e| e.size_bytes > self.limit_bytes)
+            .collect()
+    }
+
+    pub fn exceeds_limit(&self) -> bool {
+        self.total_bytes > self.limit_bytes || !self.oversized_entries().is_empty()
+    }
+}
+
+/// Renders `#[contracttype]` struct values with their field names.
+///
+/// Named structs are stored as a `Map` keyed by field symbol. A map whose keys are
+/// exactly the fields of one spec struct is shown as `Name { field: value, .. }` in
+/// declaration order; anything else keeps its debug representation.
+#[derive(Debug, Clone, Default)]
+pub struct StructValueRenderer {
+    structs: Vec<crate::utils::wasm::ContractStruct>,
+}
+
+impl StructValueRenderer {
+    pub fn new(structs: Vec<crate::utils::wasm::ContractStruct>) -> Self {
+        Self { structs }
+    }
+
+    /// Build a renderer from the contract spec embedded in `wasm_bytes`.
+    pub fn from_wasm(wasm_bytes: &[u8]) -> Self {
+        Self::new(crate::utils::wasm::parse_contract_structs(wasm_bytes).unwrap_or_default())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.structs.is_empty()
+    }
+
+    /// Render `value` as a named struct, or `None` if it matches no spec struct.
+    pub fn render(&self, value: &ScVal) -> Option<String> {
+        let ScVal::Map(Some(map)) = value else {
+            return None;
+        };
+        let mut fields = BTreeMap::new();
+        for entry in map.iter() {
+            let ScVal::Symbol(symbol) = &entry.key else {
+                return None;
+            };
+            fields.insert(symbol.0.to_utf8_string_lossy(), &entry.val);
+        }
+
+        let def = self.structs.iter().find(|s| {
+            !s.is_tuple()
+                && s.fields.len() == fields.len()
+                && s.fields.iter().all(|f| fields.contains_key(&f.name))
+        })?;
+        let rendered: Vec<String> = def
+            .fields
+            .iter()
+            .map(|f| format!("{}: {}", f.name, self.render_field(fields[&f.name])))
+            .collect();
+        Some(format!("{} {{ {} }}", def.name, rendered.join(", ")))
+    }
+
+    /// Like [`StorageInspector::capture_snapshot`], with struct values rendered by
+    /// field name.
+    pub fn capture_snapshot(&self, host: &Host) -> HashMap<String, String> {
+        if self.is_empty() {
+            return StorageInspector::capture_snapshot(host);
+        }
+        StorageInspector::capture_snapshot_with(host, &|val| {
+            self.render(val).unwrap_or_else(|| format!("{:?}", val))
+        })
+    }
+
+    fn render_field(&self, value: &ScVal) -> String {
+        if let Some(nested) = self.render(value) {
+            return nested;
+        }
+        match value {
+            ScVal::Void => "()".to_string(),
+            ScVal::Bool(b) => b.to_string(),
+            ScVal::U32(n) => n.to_string(),
+            ScVal::I32(n) => n.to_string(),
+            ScVal::U64(n) => n.to_string(),
+            ScVal::I64(n) => n.to_string(),
+            ScVal::Timepoint(t) => t.0.to_string(),
+            ScVal::Duration(d) => d.0.to_string(),
+            ScVal::U128(parts) => (((parts.hi as u128) << 64) | parts.lo as u128).to_string(),
+            ScVal::I128(parts) => (((parts.hi as i128) << 64) | parts.lo as i128).to_string(),
+            ScVal::Symbol(symbol) => symbol.0.to_utf8_string_lossy(),
+            ScVal::String(string) => format!("{:?}", string.0.to_utf8_string_lossy()),
+            ScVal::Address(address) => address.to_string(),
+            ScVal::Vec(Some(items)) => {
+                let items: Vec<String> = items.iter().map(|v| self.render_field(v)).collect();
+                format!("[{}]", items.join(", "))
+            }
+            other => format!("{:?}", other),
+        }
+    }
+}
+
 /// Represents the differences between two storage states
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct StorageDiff {
@@ -699,6 +1146,45 @@ impl StorageDiff {
     pub fn is_empty(&self) -> bool {
         self.added.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
     }
+
+    /// Keep only the changes to entries of the given tiers. An empty list
+    /// keeps everything. Triggered alerts are left untouched.
+    pub fn retain_durability(&mut self, tiers: &[StorageDurability]) {
+        if tiers.is_empty() {
+            return;
+        }
+        let keep =
+            |key: &str| StorageDurability::of_key(key).is_some_and(|tier| tiers.contains(&tier));
+        self.added.retain(|key, _| keep(key));
+        self.modified.retain(|key, _| keep(key));
+        self.deleted.retain(|key| keep(key));
+    }
+}
+
+/// Durability tier of a contract data entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageDurability {
+    Instance,
+    Persistent,
+    Temporary,
+}
+
+impl StorageDurability {
+    /// Tier of a snapshot key as written by [`StorageInspector::capture_snapshot`].
+    /// Instance storage lives in the contract instance entry, which is itself
+    /// persistent; keys that are not contract data have no tier.
+    pub fn of_key(key: &str) -> Option<Self> {
+        if key.starts_with("contract_data:") && key.ends_with(":LedgerKeyContractInstance") {
+            Some(Self::Instance)
+        } else if key.starts_with("contract_data:Persistent:") {
+            Some(Self::Persistent)
+        } else if key.starts_with("contract_data:Temporary:") {
+            Some(Self::Temporary)
+        } else {
+            None
+        }
+    }
 }
 
 /// Statistics for a single storage access key
@@ -723,6 +1209,10 @@ impl Default for StorageInspector {
     }
 }
 
+fn xdr_len(value: &impl WriteXdr) -> usize {
+    value.to_xdr(Limits::none()).map(|b| b.len()).unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -763,6 +1253,109 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // ── StorageRedactor tests ────────────────────────────────────────
+
+    #[test]
+    fn test_redactor_masks_values_but_keeps_keys() {
+        let redactor = StorageRedactor::new(&["balance:*".to_string()]).unwrap();
+
+        let mut snapshot = HashMap::new();
+        snapshot.insert("balance:alice".to_string(), "1000".to_string());
+        snapshot.insert("admin".to_string(), "GABC".to_string());
+        let redacted = redactor.redact_snapshot(&snapshot);
+        assert_eq!(redacted["balance:alice"], StorageRedactor::MASK);
+        assert_eq!(redacted["admin"], "GABC");
+
+        let mut diff = StorageDiff::default();
+        diff.modified.insert(
+            "balance:alice".to_string(),
+            ("1000".to_string(), "900".to_string()),
+        );
+        diff.added.insert("admin".to_string(), "GABC".to_string());
+        redactor.redact_diff(&mut diff);
+        assert_eq!(
+            diff.modified["balance:alice"],
+            ("***".to_string(), "***".to_string())
+        );
+        assert_eq!(diff.added["admin"], "GABC");
+
+        let mut events = vec![crate::inspector::events::ContractEvent {
+            contract_id: None,
+            topics: vec!["transfer".to_string(), "balance:alice".to_string()],
+            data: "100".to_string(),
+            decoded: None,
+        }];
+        redactor.redact_events(&mut events);
+        assert_eq!(events[0].data, StorageRedactor::MASK);
+        assert_eq!(events[0].topics[1], "balance:alice");
+    }
+
+    #[test]
+    fn test_empty_redactor_masks_nothing() {
+        let redactor = StorageRedactor::new(&[]).unwrap();
+        let mut snapshot = HashMap::new();
+        snapshot.insert("balance:alice".to_string(), "1000".to_string());
+        assert_eq!(redactor.redact_snapshot(&snapshot), snapshot);
+    }
+
+    #[test]
+    fn test_redactor_masks_run_metadata_inputs() {
+        let redactor = StorageRedactor::new(&["secret".to_string()]).unwrap();
+        let mut meta = crate::output::RunMetadata::new("abc").with_inputs(
+            Some("transfer"),
+            Some(r#"["GABC", 100]"#),
+            None,
+        );
+        redactor.redact_run_metadata(&mut meta);
+        assert_eq!(meta.function.as_deref(), Some("transfer"));
+        assert_eq!(meta.args.as_deref(), Some(StorageRedactor::MASK));
+        assert_eq!(meta.storage, None);
+    }
+
+    // ── StructValueRenderer tests ────────────────────────────────────
+
+    #[test]
+    fn test_struct_renderer_shows_named_fields_in_declaration_order() {
+        use soroban_env_host::xdr::{ScMap, ScMapEntry, ScString, ScSymbol};
+
+        let field = |name: &str, type_name: &str| crate::utils::wasm::FunctionParam {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+        };
+        let renderer = StructValueRenderer::new(vec![crate::utils::wasm::ContractStruct {
+            name: "Proposal".to_string(),
+            fields: vec![
+                field("id", "U32"),
+                field("title", "String"),
+                field("closed", "Bool"),
+            ],
+        }]);
+
+        let entry = |key: &str, val: ScVal| ScMapEntry {
+            key: ScVal::Symbol(ScSymbol(key.try_into().unwrap())),
+            val,
+        };
+        // Map keys are stored sorted, not in declaration order.
+        let value = ScVal::Map(Some(ScMap(
+            vec![
+                entry("closed", ScVal::Bool(false)),
+                entry("id", ScVal::U32(3)),
+                entry(
+                    "title",
+                    ScVal::String(ScString("Fund docs".try_into().unwrap())),
+                ),
+            ]
+            .try_into()
+            .unwrap(),
+        )));
+
+        assert_eq!(
+            renderer.render(&value).as_deref(),
+            Some(r#"Proposal { id: 3, title: "Fund docs", closed: false }"#)
+        );
+        assert_eq!(renderer.render(&ScVal::U32(3)), None);
+    }
+
     // ── StorageFilter tests ──────────────────────────────────────────
 
     #[test]
@@ -975,6 +1568,40 @@ mod tests {
         assert!(diff.deleted.contains(&"deleted".to_string()));
     }
 
+    #[test]
+    fn test_storage_diff_retain_durability() {
+        let instance = "contract_data:Persistent:LedgerKeyContractInstance";
+        let balance = "contract_data:Persistent:Symbol(ScSymbol(StringM(bal)))";
+        let nonce = "contract_data:Temporary:Symbol(ScSymbol(StringM(nonce)))";
+
+        let before = HashMap::from([
+            (instance.to_string(), "old".to_string()),
+            (nonce.to_string(), "1".to_string()),
+        ]);
+        let after = HashMap::from([
+            (instance.to_string(), "new".to_string()),
+            (balance.to_string(), "100".to_string()),
+            ("contract_code".to_string(), "code".to_string()),
+        ]);
+        let diff = StorageInspector::compute_diff(&before, &after, &[]);
+
+        let mut persistent = diff.clone();
+        persistent.retain_durability(&[StorageDurability::Persistent]);
+        assert_eq!(persistent.added.keys().collect::<Vec<_>>(), vec![balance]);
+        assert!(persistent.modified.is_empty());
+        assert!(persistent.deleted.is_empty());
+
+        let mut mixed = diff.clone();
+        mixed.retain_durability(&[StorageDurability::Instance, StorageDurability::Temporary]);
+        assert!(mixed.added.is_empty());
+        assert!(mixed.modified.contains_key(instance));
+        assert_eq!(mixed.deleted, vec![nonce.to_string()]);
+
+        let mut all = diff.clone();
+        all.retain_durability(&[]);
+        assert_eq!(all.added.len(), 2);
+    }
+
     // ── StorageState import/export tests ─────────────────────────────
 
     #[test]
@@ -1179,4 +1806,78 @@ mod tests {
         // Ensure display_diff doesn't panic with these values
         StorageInspector::display_diff(&diff);
     }
+
+    fn escrow_status_renderer() -> EnumValueRenderer {
+        use crate::utils::wasm::{ContractEnum, ContractEnumCase};
+        EnumValueRenderer::new(vec![ContractEnum {
+            name: "EscrowStatus".to_string(),
+            cases: vec![
+                ContractEnumCase {
+                    name: "Pending".to_string(),
+                    value: 0,
+                },
+                ContractEnumCase {
+                    name: "Released".to_string(),
+                    value: 1,
+                },
+            ],
+        }])
+    }
+
+    #[test]
+    fn test_enum_renderer_decodes_matching_key() {
+        let renderer = escrow_status_renderer();
+        let key = "contract_data:Persistent:Symbol(ScSymbol(StringM(Status)))";
+        assert_eq!(
+            renderer.render(key, "U32(1) (ttl=4095)"),
+            "EscrowStatus::Released (ttl=4095)"
+        );
+        assert_eq!(renderer.render("status", "U32(0)"), "EscrowStatus::Pending");
+    }
+
+    #[test]
+    fn test_enum_renderer_leaves_unrelated_values() {
+        let renderer = escrow_status_renderer();
+        assert_eq!(renderer.render("counter", "U32(1)"), "U32(1)");
+        assert_eq!(renderer.render("status", "U32(9)"), "U32(9)");
+        assert_eq!(renderer.render("status", "I64(1)"), "I64(1)");
+    }
+
+    #[test]
+    fn test_instance_storage_report_flags_oversized_entries() {
+        let report = InstanceStorageReport {
+            limit_bytes: 100,
+            total_bytes: 180,
+            entries: vec![
+                InstanceEntrySize {
+                    key: "Symbol(Config)".to_string(),
+                    size_bytes: 150,
+                },
+                InstanceEntrySize {
+                    key: "Symbol(Admin)".to_string(),
+                    size_bytes: 30,
+                },
+            ],
+        };
+
+        let oversized = report.oversized_entries();
+        assert_eq!(oversized.len(), 1);
+        assert_eq!(oversized[0].key, "Symbol(Config)");
+        assert!(report.exceeds_limit());
+    }
+
+    #[test]
+    fn test_instance_storage_report_within_limit() {
+        let report = InstanceStorageReport {
+            limit_bytes: DEFAULT_MAX_INSTANCE_ENTRY_BYTES,
+            total_bytes: 512,
+            entries: vec![InstanceEntrySize {
+                key: "Symbol(Admin)".to_string(),
+                size_bytes: 64,
+            }],
+        };
+
+        assert!(report.oversized_entries().is_empty());
+        assert!(!report.exceeds_limit());
+    }
 }
@@ -1,3 +1,25 @@
+//! Debugger and inspection tooling for Soroban smart contracts.
+//!
+//! The `soroban-debug` binary is a thin layer over this crate. To drive an
+//! execution from your own code, load a contract into a [`ContractExecutor`],
+//! wrap it in a [`DebuggerEngine`] and call [`DebuggerEngine::run_once`]:
+//!
+//! ```no_run
+//! use soroban_debugger::{ContractExecutor, DebuggerEngine};
+//!
+//! # fn main() -> soroban_debugger::Result<()> {
+//! let wasm = std::fs::read("counter.wasm").expect("read contract");
+//! let mut engine = DebuggerEngine::new(ContractExecutor::new(wasm)?, Vec::new());
+//! let summary = engine.run_once("increment", None, Some(r#"{"c": 41}"#))?;
+//! println!("{} -> {}", summary.function, summary.result);
+//! println!("{} storage changes", summary.storage_diff.modified.len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The returned [`debugger::ExecutionSummary`] holds the result, the
+//! storage diff, the events and auth tree of the call, and the budget used.
+
 #![recursion_limit = "256"]
 
 pub mod analyzer;
@@ -9,6 +31,7 @@ pub mod codegen;
 pub mod compare;
 pub mod config;
 pub mod debugger;
+pub mod exit_code;
 pub mod history;
 pub mod inspector;
 pub mod logging;
@@ -22,6 +45,7 @@ pub mod runtime;
 pub mod scenario;
 pub mod server;
 pub mod simulator;
+pub mod smoke;
 pub mod ui;
 pub mod utils;
 
@@ -119,4 +143,25 @@ pub enum DebuggerError {
         help("Action: Ensure the shared security token matches the server, and the transport protocol is correct.\nContext: The server rejected communication because authentication wasn't verified.")
     )]
     AuthenticationFailed(String),
+
+    #[error("Security findings at or above the --fail-on threshold: {0}")]
+    #[diagnostic(
+        code(debugger::security_findings),
+        help("Action: Fix the reported findings, suppress accepted ones in the suppressions file, or raise --fail-on.\nContext: The analyzer found issues at or above the severity the build is gated on.")
+    )]
+    SecurityFindings(String),
+
+    #[error("Budget regression detected: {0}")]
+    #[diagnostic(
+        code(debugger::regression_detected),
+        help("Action: Compare the latest run against the baseline with --budget-trend, or raise the regression threshold if the increase is expected.\nContext: CPU or memory usage grew by more than the configured percentage.")
+    )]
+    RegressionDetected(String),
+
+    #[error("Upgrade is not compatible: {0}")]
+    #[diagnostic(
+        code(debugger::breaking_changes),
+        help("Action: Restore the removed or changed functions, or ship the change as a new contract.\nContext: upgrade-check found changes that break existing callers of the contract.")
+    )]
+    BreakingChanges(String),
 }
@@ -1,6 +1,7 @@
 #![recursion_limit = "256"]
 use clap::{CommandFactory, Parser};
 use clap_complete::generate;
+use soroban_debugger::cli::args::NumberFormat;
 use soroban_debugger::cli::{Cli, Commands, Verbosity};
 use soroban_debugger::ui::formatter::Formatter;
 use std::io;
@@ -154,6 +155,11 @@ fn main() -> miette::Result<()> {
                     .as_deref()
                     .is_some_and(|f| f.eq_ignore_ascii_case("json"))
     );
+    Formatter::set_number_format(cli.number_format.unwrap_or(if run_json_output_requested {
+        NumberFormat::Plain
+    } else {
+        NumberFormat::Grouped
+    }));
     let verbosity = cli.verbosity();
 
     Formatter::set_verbosity(verbosity_to_level(verbosity));
@@ -179,7 +185,10 @@ fn main() -> miette::Result<()> {
             soroban_debugger::cli::commands::optimize(args, verbosity)
         }
         Some(Commands::UpgradeCheck(args)) => soroban_debugger::cli::commands::upgrade_check(args),
+        Some(Commands::MigrateCheck(args)) => soroban_debugger::cli::commands::migrate_check(args),
+        Some(Commands::DiffSpec(args)) => soroban_debugger::cli::commands::diff_spec(args),
         Some(Commands::Compare(args)) => soroban_debugger::cli::commands::compare(args),
+        Some(Commands::SnapshotDiff(args)) => soroban_debugger::cli::commands::snapshot_diff(args),
         Some(Commands::Replay(args)) => soroban_debugger::cli::commands::replay(args, verbosity),
         Some(Commands::Completions(args)) => {
             let mut cmd = Cli::command();
@@ -187,16 +196,23 @@ fn main() -> miette::Result<()> {
             Ok(())
         }
         Some(Commands::Profile(args)) => soroban_debugger::cli::commands::profile(args),
+        Some(Commands::Gate(args)) => soroban_debugger::cli::commands::gate(args),
+        Some(Commands::Bisect(args)) => soroban_debugger::cli::commands::bisect(args),
         Some(Commands::Symbolic(args)) => {
             soroban_debugger::cli::commands::symbolic(args, verbosity)
         }
         Some(Commands::Server(args)) => soroban_debugger::cli::commands::server(args),
+        Some(Commands::Dap(args)) => soroban_debugger::cli::commands::dap(args),
         Some(Commands::Remote(args)) => soroban_debugger::cli::commands::remote(args, verbosity),
         Some(Commands::Analyze(args)) => soroban_debugger::cli::commands::analyze(args, verbosity),
+        Some(Commands::StorageLimits(args)) => {
+            soroban_debugger::cli::commands::storage_limits(args, verbosity)
+        }
         Some(Commands::Scenario(args)) => {
             soroban_debugger::cli::commands::scenario(args, verbosity)
         }
         Some(Commands::HistoryPrune(args)) => soroban_debugger::cli::commands::history_prune(args),
+        Some(Commands::History(args)) => soroban_debugger::cli::commands::history(args),
         Some(Commands::Repl(mut args)) => {
             args.merge_config(&config);
             tokio::runtime::Runtime::new()
@@ -299,6 +315,13 @@ fn main() -> miette::Result<()> {
                         source_map_limit: 20,
                         expected_hash: None,
                         dependency_graph: None,
+                        graph_output: None,
+                        graph_format: None,
+                        contract_map: Vec::new(),
+                        wat: None,
+                        host_imports: false,
+                        validate: false,
+                        spec: false,
                     },
                     verbosity,
                 );
@@ -307,11 +330,22 @@ fn main() -> miette::Result<()> {
                 soroban_debugger::cli::commands::show_budget_trend(
                     cli.trend_contract.as_deref(),
                     cli.trend_function.as_deref(),
+                    cli.since_commit.as_deref(),
                     soroban_debugger::history::RegressionConfig {
-                        threshold_pct: cli.trend_regression_threshold_pct,
+                        thresholds: soroban_debugger::history::RegressionThresholds {
+                            cpu_pct: cli
+                                .cpu_regression_threshold
+                                .or(config.history.cpu_regression_threshold)
+                                .unwrap_or(cli.trend_regression_threshold_pct),
+                            mem_pct: cli
+                                .mem_regression_threshold
+                                .or(config.history.mem_regression_threshold)
+                                .unwrap_or(cli.trend_regression_threshold_pct),
+                        },
                         lookback: cli.trend_regression_lookback,
                         smoothing_window: cli.trend_regression_smoothing,
                     },
+                    cli.fail_on_regression,
                 )
             } else {
                 let mut cmd = Cli::command();
@@ -339,6 +373,12 @@ fn main() -> miette::Result<()> {
             "{}",
             Formatter::error(format!("Error handling deprecations: {err:#}"))
         );
+        let code = soroban_debugger::exit_code::for_error(&err);
+        if code != soroban_debugger::exit_code::FAILURE {
+            // Same report `main` would print, with the specific exit code.
+            eprintln!("Error: {err:?}");
+            std::process::exit(code);
+        }
         return Err(err);
     }
 
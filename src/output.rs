@@ -333,6 +333,57 @@ pub fn collect_runtime_diagnostics(
     diagnostics
 }
 
+/// Reproducibility metadata attached to JSON outputs, so a maintainer can
+/// re-run the exact invocation from an attached report alone.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RunMetadata {
+    pub debugger_version: String,
+    pub protocol_version: u32,
+    pub timestamp: String,
+    pub contract_sha256: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_snapshot: Option<String>,
+}
+
+impl RunMetadata {
+    pub fn new(contract_sha256: impl Into<String>) -> Self {
+        Self {
+            debugger_version: env!("CARGO_PKG_VERSION").to_string(),
+            protocol_version: crate::server::protocol::PROTOCOL_VERSION,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            contract_sha256: contract_sha256.into(),
+            function: None,
+            args: None,
+            storage: None,
+            network_snapshot: None,
+        }
+    }
+
+    /// Record the invocation inputs exactly as they were supplied.
+    pub fn with_inputs(
+        mut self,
+        function: Option<&str>,
+        args: Option<&str>,
+        storage: Option<&str>,
+    ) -> Self {
+        self.function = function.map(str::to_string);
+        self.args = args.map(str::to_string);
+        self.storage = storage.map(str::to_string);
+        self
+    }
+
+    pub fn with_network_snapshot(mut self, path: Option<&std::path::Path>) -> Self {
+        self.network_snapshot = path.map(|p| p.to_string_lossy().to_string());
+        self
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct VersionedOutput<T>
 where
@@ -343,6 +394,8 @@ where
     pub status: OutputStatus,
     pub result: Option<T>,
     pub error: Option<OutputError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<RunMetadata>,
 }
 
 impl<T> VersionedOutput<T>
@@ -356,9 +409,16 @@ where
             status: OutputStatus::Success,
             result: Some(result),
             error: None,
+            meta: None,
         }
     }
 
+    /// Attach reproducibility metadata to this envelope.
+    pub fn with_meta(mut self, meta: RunMetadata) -> Self {
+        self.meta = Some(meta);
+        self
+    }
+
     pub fn error(command: impl Into<String>, message: impl Into<String>) -> Self {
         Self {
             schema_version: SCHEMA_VERSION,
@@ -368,10 +428,69 @@ where
             error: Some(OutputError {
                 message: message.into(),
             }),
+            meta: None,
         }
     }
 }
 
+/// One line of `run --json-stream` output. Each record is written as soon
+/// as it happens, so tooling can follow a long run line by line.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StreamRecord {
+    ContractLoaded {
+        path: String,
+        size: usize,
+        sha256: String,
+    },
+    /// A stepped instruction, breakpoint hit, watchpoint hit or storage change.
+    Event {
+        event: crate::plugin::ExecutionEvent,
+    },
+    /// A contract event, for `--show-events`.
+    ContractEvent {
+        event: crate::inspector::events::ContractEvent,
+    },
+    /// Always the last line of the stream.
+    Result {
+        function: String,
+        status: OutputStatus,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        result: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+        budget: crate::inspector::budget::BudgetInfo,
+    },
+}
+
+impl StreamRecord {
+    /// Whether `event` is worth a line in the stream.
+    pub fn streams(event: &crate::plugin::ExecutionEvent) -> bool {
+        use crate::plugin::{ExecutionEvent, StorageOperation};
+        matches!(
+            event,
+            ExecutionEvent::BeforeInstruction { .. }
+                | ExecutionEvent::BreakpointHit { .. }
+                | ExecutionEvent::WatchpointHit { .. }
+                | ExecutionEvent::StorageAccess {
+                    operation: StorageOperation::Write | StorageOperation::Delete,
+                    ..
+                }
+        )
+    }
+
+    /// Write the record to stdout as a single line and flush it.
+    pub fn emit(&self) -> miette::Result<()> {
+        use std::io::Write;
+        let line = serde_json::to_string(self)
+            .map_err(|e| miette::miette!("Failed to serialize stream record: {}", e))?;
+        let mut stdout = std::io::stdout().lock();
+        writeln!(stdout, "{}", line)
+            .and_then(|_| stdout.flush())
+            .map_err(|e| miette::miette!("Failed to write stream record: {}", e))
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SymbolicReplayBundle {
     pub schema_version: u8,
@@ -621,6 +740,31 @@ pub fn format_resource_timeline(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_versioned_output_omits_meta_by_default() {
+        let output = VersionedOutput::success("run", serde_json::json!({"result": "ok"}));
+        let json = serde_json::to_value(&output).unwrap();
+        assert!(json.get("meta").is_none());
+    }
+
+    #[test]
+    fn test_versioned_output_includes_run_metadata() {
+        let meta = RunMetadata::new("deadbeef").with_inputs(
+            Some("transfer"),
+            Some(r#"["alice", "bob", 10]"#),
+            Some(r#"{"balance": 100}"#),
+        );
+        let output = VersionedOutput::success("run", serde_json::json!({})).with_meta(meta);
+        let json = serde_json::to_value(&output).unwrap();
+
+        assert_eq!(json["meta"]["contract_sha256"], "deadbeef");
+        assert_eq!(json["meta"]["function"], "transfer");
+        assert_eq!(json["meta"]["args"], r#"["alice", "bob", 10]"#);
+        assert_eq!(json["meta"]["storage"], r#"{"balance": 100}"#);
+        assert_eq!(json["meta"]["debugger_version"], env!("CARGO_PKG_VERSION"));
+        assert!(json["meta"].get("network_snapshot").is_none());
+    }
+
     #[test]
     fn test_replay_bundle_serializes() {
         let bundle = SymbolicReplayBundle {
@@ -28,6 +28,15 @@ pub enum ExecutionEvent {
     BreakpointHit {
         function: String,
         condition: Option<String>,
+        /// Times the breakpoint has been reached, including skipped hits
+        hit_count: usize,
+    },
+
+    /// Fired when a watched storage key is written or deleted
+    WatchpointHit {
+        key: String,
+        before: Option<String>,
+        after: Option<String>,
     },
 
     /// Fired when execution is paused
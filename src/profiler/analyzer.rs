@@ -1,9 +1,17 @@
-use crate::profiler::session::ProfileSession;
+use crate::debugger::source_map::SourceMap;
+use crate::inspector::budget::{BudgetInfo, BudgetInspector};
+use crate::profiler::flamegraph::{FlameGraphAccumulator, FlameGraphGenerator};
+use crate::profiler::session::{CallFrame, ProfileSession};
+use crate::profiler::source_cost::{attribute_source_costs, SourceLineCost};
 use crate::runtime::executor::ContractExecutor;
+use crate::runtime::mocking::MockCost;
+use crate::ui::formatter::Formatter;
 use crate::{DebuggerError, Result};
-use std::collections::HashMap;
+use soroban_env_host::xdr::{ContractEvent, ContractEventBody, Hash, ScAddress, ScVal};
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::fmt::Write;
+use std::path::Path;
 
 #[derive(Debug, Clone)]
 pub struct OperationCost {
@@ -30,8 +38,10 @@ pub struct FunctionProfile {
     pub wall_time_ms: u128,
     pub operations: Vec<OperationCost>,
     pub storage_accesses: HashMap<String, StorageAccess>,
-    pub call_tree: Option<Vec<crate::profiler::session::CallFrame>>,
+    pub call_tree: Option<Vec<CallFrame>>,
     pub timeline: Option<Vec<crate::inspector::budget::ResourceCheckpoint>>,
+    /// Estimated cost per source line; empty without DWARF line info.
+    pub source_costs: Vec<SourceLineCost>,
 }
 
 /// Folded stack sample for external tools (issue #502).
@@ -83,9 +93,47 @@ pub struct OptimizationReport {
     pub potential_memory_savings: u64,
 }
 
+/// Cost change of one function between a baseline build and the current one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionDelta {
+    pub name: String,
+    pub baseline_cpu: u64,
+    pub current_cpu: u64,
+    pub baseline_memory: u64,
+    pub current_memory: u64,
+}
+
+impl FunctionDelta {
+    pub fn cpu_delta(&self) -> i128 {
+        self.current_cpu as i128 - self.baseline_cpu as i128
+    }
+
+    pub fn memory_delta(&self) -> i128 {
+        self.current_memory as i128 - self.baseline_memory as i128
+    }
+
+    /// True when the current build costs more CPU or memory than the baseline.
+    pub fn is_regression(&self) -> bool {
+        self.cpu_delta() > 0 || self.memory_delta() > 0
+    }
+}
+
+fn format_delta(delta: i128, baseline: u64) -> String {
+    if baseline == 0 {
+        return format!("{:+}", delta);
+    }
+    format!(
+        "{:+} ({:+.2}%)",
+        delta,
+        delta as f64 / baseline as f64 * 100.0
+    )
+}
+
 pub struct GasOptimizer {
     executor: ContractExecutor,
     function_profiles: HashMap<String, FunctionProfile>,
+    /// WASM bytes and their line table, when source attribution is enabled.
+    source_attribution: Option<(Vec<u8>, SourceMap)>,
 }
 
 impl GasOptimizer {
@@ -93,14 +141,42 @@ impl GasOptimizer {
         Self {
             executor,
             function_profiles: HashMap::new(),
+            source_attribution: None,
         }
     }
 
+    /// Attribute the cost of each analyzed function to source lines using the
+    /// DWARF line table of `wasm_bytes`, a debug build of the profiled
+    /// contract. Returns `false`, leaving attribution off, when the module has
+    /// no usable `.debug_line` data.
+    pub fn enable_source_attribution(&mut self, wasm_bytes: Vec<u8>) -> bool {
+        let mut source_map = SourceMap::new();
+        if source_map.load(&wasm_bytes).is_err() || source_map.is_empty() {
+            return false;
+        }
+        self.source_attribution = Some((wasm_bytes, source_map));
+        true
+    }
+
+    fn source_costs(&self, function_name: &str, total_cpu: u64) -> Vec<SourceLineCost> {
+        let Some((wasm_bytes, source_map)) = &self.source_attribution else {
+            return Vec::new();
+        };
+        attribute_source_costs(wasm_bytes, source_map, function_name, total_cpu).unwrap_or_default()
+    }
+
     pub fn analyze_function(
         &mut self,
         function_name: &str,
         args: Option<&str>,
     ) -> Result<FunctionProfile> {
+        let mock_calls_before = self.executor.get_mock_call_log().len();
+        let events_before = self
+            .executor
+            .get_diagnostic_events()
+            .map(|events| events.len())
+            .unwrap_or(0);
+        let budget_before = BudgetInspector::get_cpu_usage(self.executor.host());
         let session = ProfileSession::start(self.executor.host());
 
         let operations = Vec::new();
@@ -114,7 +190,9 @@ impl GasOptimizer {
         let metrics = session.finish(self.executor.host());
         let total_cpu = metrics.cpu_instructions;
         let total_memory = metrics.memory_bytes;
-        let wall_time_ms = metrics.wall_time.as_millis();
+        let wall_time_ms =
+            metrics.wall_time.as_millis() + u128::from(self.executor.last_mock_latency_ms());
+        let source_costs = self.source_costs(function_name, total_cpu);
 
         match exec_result {
             Ok(Ok(_)) => {
@@ -131,6 +209,7 @@ impl GasOptimizer {
                     storage_accesses,
                     call_tree: None,
                     timeline: Some(metrics.timeline.clone()),
+                    source_costs,
                 };
                 self.function_profiles
                     .insert(function_name.to_string(), profile.clone());
@@ -147,6 +226,7 @@ impl GasOptimizer {
                     storage_accesses,
                     call_tree: None,
                     timeline: Some(metrics.timeline.clone()),
+                    source_costs,
                 };
                 self.function_profiles
                     .insert(function_name.to_string(), profile.clone());
@@ -158,6 +238,14 @@ impl GasOptimizer {
             }
         }
 
+        let call_tree = self.call_tree(
+            function_name,
+            total_cpu,
+            total_memory,
+            mock_calls_before,
+            events_before,
+            &budget_before,
+        );
         let profile = FunctionProfile {
             name: function_name.to_string(),
             total_cpu,
@@ -165,8 +253,9 @@ impl GasOptimizer {
             wall_time_ms,
             operations,
             storage_accesses,
-            call_tree: None,
+            call_tree: Some(call_tree),
             timeline: Some(metrics.timeline),
+            source_costs,
         };
 
         self.function_profiles
@@ -174,6 +263,52 @@ impl GasOptimizer {
         Ok(profile)
     }
 
+    /// Call tree of one invocation, from the host's `fn_call`/`fn_return`
+    /// diagnostic events emitted after `events_before`, with mocked calls
+    /// costed from the mock call log entries after `mock_calls_before` and
+    /// placed in the budget consumed since `budget_before`.
+    fn call_tree(
+        &self,
+        function_name: &str,
+        total_cpu: u64,
+        total_memory: u64,
+        mock_calls_before: usize,
+        events_before: usize,
+        budget_before: &BudgetInfo,
+    ) -> Vec<CallFrame> {
+        let events = self
+            .executor
+            .get_diagnostic_events()
+            .unwrap_or_default()
+            .into_iter()
+            .skip(events_before)
+            .collect::<Vec<_>>();
+        let calls = self
+            .executor
+            .get_mock_call_log()
+            .into_iter()
+            .skip(mock_calls_before)
+            .filter(|call| call.auth_denied.is_none())
+            .map(|call| MockedCall {
+                callee: format!("{}.{}", call.contract_id, call.function),
+                cost: call.charged,
+                cpu_at: call
+                    .cpu_at_call
+                    .saturating_sub(budget_before.cpu_instructions),
+                memory_at: call
+                    .memory_at_call
+                    .saturating_sub(budget_before.memory_bytes),
+            })
+            .collect();
+        vec![call_tree_from_events(
+            function_name,
+            total_cpu,
+            total_memory,
+            &events,
+            calls,
+        )]
+    }
+
     pub fn generate_report(&self, contract_path: &str) -> OptimizationReport {
         let functions: Vec<FunctionProfile> = self.function_profiles.values().cloned().collect();
 
@@ -439,6 +574,60 @@ impl GasOptimizer {
         output
     }
 
+    /// Markdown section comparing `deltas` against a baseline build, appended
+    /// to [`generate_markdown_report`](Self::generate_markdown_report) by
+    /// `optimize --baseline`.
+    pub fn generate_markdown_baseline_section(
+        baseline_path: &str,
+        deltas: &[FunctionDelta],
+    ) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "## Changes vs baseline").unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "**Baseline:** `{}`", baseline_path).unwrap();
+        writeln!(output).unwrap();
+
+        if deltas.is_empty() {
+            writeln!(output, "No function was profiled in both builds.").unwrap();
+            return output;
+        }
+
+        writeln!(
+            output,
+            "| Function | Baseline CPU | Current CPU | CPU Delta | Baseline Memory | Current Memory | Memory Delta |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|----------|--------------|-------------|-----------|-----------------|----------------|--------------|"
+        )
+        .unwrap();
+        for delta in deltas {
+            let marker = if delta.is_regression() {
+                " (regression)"
+            } else {
+                ""
+            };
+            writeln!(
+                output,
+                "| {}{} | {} | {} | {} | {} | {} | {} |",
+                delta.name,
+                marker,
+                delta.baseline_cpu,
+                delta.current_cpu,
+                format_delta(delta.cpu_delta(), delta.baseline_cpu),
+                delta.baseline_memory,
+                delta.current_memory,
+                format_delta(delta.memory_delta(), delta.baseline_memory)
+            )
+            .unwrap();
+        }
+        writeln!(output).unwrap();
+
+        output
+    }
+
     /// Export profiling data as folded stack format (issue #502).
     /// Format: function1;function2;operation 123 (where 123 is the count)
     pub fn to_folded_stack_format(&self, report: &OptimizationReport) -> String {
@@ -471,6 +660,45 @@ impl GasOptimizer {
         lines.join("\n")
     }
 
+    /// Render the recorded call trees of `report` as collapsed stacks
+    /// (`parent;child count` lines, as read by flamegraph.pl, inferno and
+    /// speedscope) weighted by CPU instructions. Each frame is counted by its
+    /// self cost, so its rendered width equals its inclusive cost.
+    pub fn generate_flamegraph(report: &OptimizationReport) -> String {
+        FlameGraphGenerator::to_collapsed_stack_format(&Self::flamegraph_samples(report).stacks())
+    }
+
+    /// Write the flamegraph of `report`: SVG when `path` ends in `.svg`,
+    /// collapsed stacks otherwise.
+    pub fn write_flamegraph<P: AsRef<Path>>(report: &OptimizationReport, path: P) -> Result<()> {
+        Self::flamegraph_samples(report).write_to(path)
+    }
+
+    fn flamegraph_samples(report: &OptimizationReport) -> FlameGraphAccumulator {
+        fn add_frame(samples: &mut FlameGraphAccumulator, parent: &[String], frame: &CallFrame) {
+            let mut stack = parent.to_vec();
+            stack.push(frame.function.clone());
+            let child_cpu: u64 = frame.children.iter().map(|c| c.cpu_cost).sum();
+            samples.add(stack.clone(), frame.cpu_cost.saturating_sub(child_cpu));
+            for child in &frame.children {
+                add_frame(samples, &stack, child);
+            }
+        }
+
+        let mut samples = FlameGraphAccumulator::new();
+        for function in &report.functions {
+            match &function.call_tree {
+                Some(frames) if !frames.is_empty() => {
+                    for frame in frames {
+                        add_frame(&mut samples, &[], frame);
+                    }
+                }
+                _ => samples.add(vec![function.name.clone()], function.total_cpu.max(1)),
+            }
+        }
+        samples
+    }
+
     /// Get call tree hotpaths (issue #503).
     /// Returns subtrees representing the most expensive call chains.
     pub fn get_hotpath_trees(&self, report: &OptimizationReport) -> Vec<CallTree> {
@@ -507,7 +735,52 @@ pub struct CallTree {
 
 /// ✅ This MUST be outside `impl GasOptimizer`
 impl OptimizationReport {
-    pub fn format_hotspots(&self) -> String {
+    /// Pair each function profiled here with the same function in
+    /// `baseline`. Functions missing from either report are skipped.
+    pub fn compare_to_baseline(&self, baseline: &OptimizationReport) -> Vec<FunctionDelta> {
+        self.functions
+            .iter()
+            .filter_map(|current| {
+                let old = baseline.functions.iter().find(|f| f.name == current.name)?;
+                Some(FunctionDelta {
+                    name: current.name.clone(),
+                    baseline_cpu: old.total_cpu,
+                    current_cpu: current.total_cpu,
+                    baseline_memory: old.total_memory,
+                    current_memory: current.total_memory,
+                })
+            })
+            .collect()
+    }
+
+    /// Render `deltas` as a terminal table, with regressions in red.
+    pub fn format_baseline_deltas(deltas: &[FunctionDelta]) -> String {
+        let mut out = String::new();
+        let _ = writeln!(&mut out, "=== Changes vs baseline ===");
+        let _ = writeln!(
+            &mut out,
+            "  {:<24} {:>24} {:>24}",
+            "Function", "CPU delta", "Memory delta"
+        );
+        for delta in deltas {
+            let row = format!(
+                "  {:<24} {:>24} {:>24}",
+                delta.name,
+                format_delta(delta.cpu_delta(), delta.baseline_cpu),
+                format_delta(delta.memory_delta(), delta.baseline_memory)
+            );
+            if delta.is_regression() {
+                let _ = writeln!(&mut out, "{}", Formatter::error(row));
+            } else {
+                let _ = writeln!(&mut out, "{}", row);
+            }
+        }
+        out
+    }
+
+    /// Render the top `top` functions by CPU, memory and wall time; functions
+    /// past the limit are summarized as a single "… and M more" line.
+    pub fn format_hotspots(&self, top: usize) -> String {
         let mut out = String::new();
 
         let mut by_cpu = self.functions.clone();
@@ -522,36 +795,415 @@ impl OptimizationReport {
         let _ = writeln!(
             &mut out,
             "=== Profiling Report ===\nContract: {}\nTotal CPU: {}\nTotal Memory: {} bytes\n",
-            self.contract_path, self.total_cpu, self.total_memory
+            self.contract_path,
+            Formatter::format_number(self.total_cpu),
+            Formatter::format_number(self.total_memory)
         );
 
-        let _ = writeln!(&mut out, "--- Hotspots: CPU (top 5) ---");
-        for f in by_cpu.iter().take(5) {
+        let _ = writeln!(&mut out, "--- Hotspots: CPU (top {}) ---", top);
+        for f in by_cpu.iter().take(top) {
             let _ = writeln!(
                 &mut out,
                 "  {:<24} cpu={} mem={}B time={}ms",
-                f.name, f.total_cpu, f.total_memory, f.wall_time_ms
+                f.name,
+                Formatter::format_number(f.total_cpu),
+                Formatter::format_number(f.total_memory),
+                f.wall_time_ms
             );
         }
+        Self::write_remainder(&mut out, self.functions.len(), top);
 
-        let _ = writeln!(&mut out, "\n--- Hotspots: Memory (top 5) ---");
-        for f in by_mem.iter().take(5) {
+        let _ = writeln!(&mut out, "\n--- Hotspots: Memory (top {}) ---", top);
+        for f in by_mem.iter().take(top) {
             let _ = writeln!(
                 &mut out,
                 "  {:<24} mem={}B cpu={} time={}ms",
-                f.name, f.total_memory, f.total_cpu, f.wall_time_ms
+                f.name,
+                Formatter::format_number(f.total_memory),
+                Formatter::format_number(f.total_cpu),
+                f.wall_time_ms
             );
         }
+        Self::write_remainder(&mut out, self.functions.len(), top);
 
-        let _ = writeln!(&mut out, "\n--- Hotspots: Wall time (top 5) ---");
-        for f in by_time.iter().take(5) {
+        let _ = writeln!(&mut out, "\n--- Hotspots: Wall time (top {}) ---", top);
+        for f in by_time.iter().take(top) {
             let _ = writeln!(
                 &mut out,
                 "  {:<24} time={}ms cpu={} mem={}B",
-                f.name, f.wall_time_ms, f.total_cpu, f.total_memory
+                f.name,
+                f.wall_time_ms,
+                Formatter::format_number(f.total_cpu),
+                Formatter::format_number(f.total_memory)
             );
         }
+        Self::write_remainder(&mut out, self.functions.len(), top);
 
         out
     }
+
+    /// Render the top `top` source lines by estimated CPU across all
+    /// functions. Returns `None` when no function has source attribution, so
+    /// callers can fall back to [`format_hotspots`](Self::format_hotspots).
+    pub fn format_source_hotspots(&self, top: usize) -> Option<String> {
+        let mut lines: Vec<&SourceLineCost> = self
+            .functions
+            .iter()
+            .flat_map(|f| f.source_costs.iter())
+            .collect();
+        if lines.is_empty() {
+            return None;
+        }
+        lines.sort_by_key(|l| std::cmp::Reverse(l.cpu_cost));
+
+        let mut out = String::new();
+        let _ = writeln!(
+            &mut out,
+            "--- Hotspots: Source lines (top {}, estimated) ---",
+            top
+        );
+        for l in lines.iter().take(top) {
+            let _ = writeln!(
+                &mut out,
+                "  {:<40} cpu~{} fn={} instrs={}",
+                format!("{}:{}", l.file.display(), l.line),
+                Formatter::format_number(l.cpu_cost),
+                l.function,
+                l.instructions
+            );
+        }
+        Self::write_remainder(&mut out, lines.len(), top);
+        Some(out)
+    }
+
+    fn write_remainder(out: &mut String, total: usize, shown: usize) {
+        if total > shown {
+            let _ = writeln!(out, "  … and {} more", total - shown);
+        }
+    }
+}
+
+/// A mocked call made during one invocation, with the budget the host had
+/// consumed since the invocation started when the call was made.
+struct MockedCall {
+    /// Frame name of the callee, `CONTRACT_ID.function`.
+    callee: String,
+    cost: MockCost,
+    cpu_at: u64,
+    memory_at: u64,
+}
+
+/// Build the call tree of one invocation of `function_name` from its
+/// `fn_call`/`fn_return` diagnostic events; the first `fn_call` is the entry
+/// point itself. Nested frames are named `CONTRACT_ID.function`.
+///
+/// The host meters nested calls into one shared budget, which is only
+/// observed when a mocked callee is called. Each mocked callee is matched, in
+/// call order, against `calls` and costed by its declared cost; the budget
+/// consumed since the previous mocked call returned is charged to the frame
+/// making the call. A nested Wasm frame that calls no mocked callee is
+/// metered with its caller. The entry point's cost is the measured total, and
+/// a nested frame's cost includes its children's.
+fn call_tree_from_events(
+    function_name: &str,
+    total_cpu: u64,
+    total_memory: u64,
+    events: &[ContractEvent],
+    mut calls: VecDeque<MockedCall>,
+) -> CallFrame {
+    fn add_children_cost(frame: &mut CallFrame) {
+        for child in &frame.children {
+            frame.cpu_cost = frame.cpu_cost.saturating_add(child.cpu_cost);
+            frame.memory_cost = frame.memory_cost.saturating_add(child.memory_cost);
+        }
+    }
+
+    /// Close the innermost nested frame into its caller.
+    fn close(stack: &mut Vec<CallFrame>) {
+        if stack.len() > 1 {
+            if let Some(mut frame) = stack.pop() {
+                add_children_cost(&mut frame);
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(frame);
+                }
+            }
+        }
+    }
+
+    let mut stack = vec![CallFrame::new(function_name.to_string(), 0)];
+    let mut entered = false;
+    // Budget consumed up to the end of the last mocked call.
+    let (mut cpu_metered, mut memory_metered) = (0u64, 0u64);
+    for event in events {
+        let ContractEventBody::V0(body) = &event.body;
+        match body.topics.first() {
+            Some(ScVal::Symbol(sym)) if sym.0.as_slice() == b"fn_call" => {
+                if !entered {
+                    entered = true;
+                    continue;
+                }
+                let contract = match body.topics.get(1) {
+                    Some(ScVal::Bytes(bytes)) => <[u8; 32]>::try_from(bytes.as_slice())
+                        .map(|hash| ScAddress::Contract(Hash(hash)).to_string())
+                        .unwrap_or_else(|_| "<unknown>".to_string()),
+                    _ => "<unknown>".to_string(),
+                };
+                let function = match body.topics.get(2) {
+                    Some(ScVal::Symbol(name)) => name.to_utf8_string_lossy(),
+                    _ => "<unknown>".to_string(),
+                };
+                let mut frame = CallFrame::new(format!("{contract}.{function}"), stack.len());
+                if calls
+                    .front()
+                    .is_some_and(|call| call.callee == frame.function)
+                {
+                    if let Some(call) = calls.pop_front() {
+                        if let Some(caller) = stack.last_mut() {
+                            caller.cpu_cost = caller
+                                .cpu_cost
+                                .saturating_add(call.cpu_at.saturating_sub(cpu_metered));
+                            caller.memory_cost = caller
+                                .memory_cost
+                                .saturating_add(call.memory_at.saturating_sub(memory_metered));
+                        }
+                        cpu_metered = call.cpu_at.saturating_add(call.cost.cpu_instructions);
+                        memory_metered = call.memory_at.saturating_add(call.cost.memory_bytes);
+                        frame.cpu_cost = call.cost.cpu_instructions;
+                        frame.memory_cost = call.cost.memory_bytes;
+                    }
+                }
+                stack.push(frame);
+            }
+            // A call that fails emits no `fn_return`; its frame is closed
+            // with its caller's.
+            Some(ScVal::Symbol(sym)) if sym.0.as_slice() == b"fn_return" && stack.len() > 1 => {
+                close(&mut stack);
+            }
+            _ => {}
+        }
+    }
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+
+    let mut root = stack.remove(0);
+    root.cpu_cost = total_cpu;
+    root.memory_cost = total_memory;
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(name: &str, cpu: u64) -> FunctionProfile {
+        FunctionProfile {
+            name: name.to_string(),
+            total_cpu: cpu,
+            total_memory: 0,
+            wall_time_ms: 0,
+            operations: Vec::new(),
+            storage_accesses: HashMap::new(),
+            call_tree: None,
+            timeline: None,
+            source_costs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn format_hotspots_limits_to_top_n_and_summarizes_the_rest() {
+        let report = OptimizationReport {
+            contract_path: "c.wasm".to_string(),
+            functions: vec![
+                profile("cheap", 10),
+                profile("hot", 300),
+                profile("warm", 200),
+            ],
+            suggestions: Vec::new(),
+            total_cpu: 510,
+            total_memory: 0,
+            potential_cpu_savings: 0,
+            potential_memory_savings: 0,
+        };
+
+        let out = report.format_hotspots(2);
+        let cpu_section = out
+            .split("--- Hotspots: CPU (top 2) ---")
+            .nth(1)
+            .and_then(|rest| rest.split("\n\n").next())
+            .unwrap();
+        assert!(cpu_section.contains("hot"));
+        assert!(cpu_section.contains("warm"));
+        assert!(!cpu_section.contains("cheap"));
+        assert!(cpu_section.contains("… and 1 more"));
+        assert!(!report.format_hotspots(3).contains("more"));
+    }
+
+    #[test]
+    fn format_source_hotspots_ranks_lines_and_falls_back_without_debug_info() {
+        let line = |line: u32, cpu_cost: u64| SourceLineCost {
+            file: std::path::PathBuf::from("src/lib.rs"),
+            line,
+            function: "hot".to_string(),
+            cpu_cost,
+            instructions: 1,
+        };
+        let mut hot = profile("hot", 300);
+        hot.source_costs = vec![line(12, 100), line(7, 200), line(30, 1)];
+        let mut report = OptimizationReport {
+            contract_path: "c.wasm".to_string(),
+            functions: vec![hot],
+            suggestions: Vec::new(),
+            total_cpu: 300,
+            total_memory: 0,
+            potential_cpu_savings: 0,
+            potential_memory_savings: 0,
+        };
+
+        let out = report.format_source_hotspots(2).unwrap();
+        let first = out.find("src/lib.rs:7").unwrap();
+        let second = out.find("src/lib.rs:12").unwrap();
+        assert!(first < second);
+        assert!(!out.contains("src/lib.rs:30"));
+        assert!(out.contains("… and 1 more"));
+
+        report.functions[0].source_costs.clear();
+        assert!(report.format_source_hotspots(2).is_none());
+    }
+
+    #[test]
+    fn compare_to_baseline_reports_negative_delta_for_cheaper_function() {
+        let report = |functions| OptimizationReport {
+            contract_path: "c.wasm".to_string(),
+            functions,
+            suggestions: Vec::new(),
+            total_cpu: 0,
+            total_memory: 0,
+            potential_cpu_savings: 0,
+            potential_memory_savings: 0,
+        };
+        let baseline = report(vec![profile("transfer", 1000), profile("mint", 100)]);
+        let current = report(vec![
+            profile("transfer", 600),
+            profile("mint", 150),
+            profile("burn", 10),
+        ]);
+
+        let deltas = current.compare_to_baseline(&baseline);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].name, "transfer");
+        assert_eq!(deltas[0].cpu_delta(), -400);
+        assert!(!deltas[0].is_regression());
+        assert_eq!(deltas[1].cpu_delta(), 50);
+        assert!(deltas[1].is_regression());
+
+        let markdown = GasOptimizer::generate_markdown_baseline_section("old.wasm", &deltas);
+        assert!(markdown.contains("## Changes vs baseline"));
+        assert!(markdown.contains("| transfer | 1000 | 600 | -400 (-40.00%) |"));
+        assert!(markdown.contains("| mint (regression) |"));
+    }
+
+    #[test]
+    fn generate_flamegraph_weights_frames_by_self_cost() {
+        let mut callee = CallFrame::new("C1.transfer".to_string(), 1);
+        callee.cpu_cost = 400;
+        let mut root = CallFrame::new("swap".to_string(), 0);
+        root.cpu_cost = 1000;
+        root.children.push(callee);
+        let mut swap = profile("swap", 600);
+        swap.call_tree = Some(vec![root]);
+        let report = OptimizationReport {
+            contract_path: "c.wasm".to_string(),
+            functions: vec![swap, profile("init", 0)],
+            suggestions: Vec::new(),
+            total_cpu: 600,
+            total_memory: 0,
+            potential_cpu_savings: 0,
+            potential_memory_savings: 0,
+        };
+
+        let folded = GasOptimizer::generate_flamegraph(&report);
+        let lines: Vec<&str> = folded.lines().collect();
+        assert_eq!(lines, vec!["init 1", "swap 600", "swap;C1.transfer 400"]);
+    }
+
+    fn call_event(contract: u8, function: &str) -> ContractEvent {
+        diagnostic_event(vec![
+            ScVal::Symbol("fn_call".try_into().unwrap()),
+            ScVal::Bytes(vec![contract; 32].try_into().unwrap()),
+            ScVal::Symbol(function.try_into().unwrap()),
+        ])
+    }
+
+    fn return_event(function: &str) -> ContractEvent {
+        diagnostic_event(vec![
+            ScVal::Symbol("fn_return".try_into().unwrap()),
+            ScVal::Symbol(function.try_into().unwrap()),
+        ])
+    }
+
+    fn diagnostic_event(topics: Vec<ScVal>) -> ContractEvent {
+        ContractEvent {
+            ext: soroban_env_host::xdr::ExtensionPoint::V0,
+            contract_id: None,
+            type_: soroban_env_host::xdr::ContractEventType::Diagnostic,
+            body: ContractEventBody::V0(soroban_env_host::xdr::ContractEventV0 {
+                topics: topics.try_into().unwrap(),
+                data: ScVal::Void,
+            }),
+        }
+    }
+
+    #[test]
+    fn call_tree_nests_frames_from_diagnostic_events() {
+        let contract = |byte: u8| ScAddress::Contract(Hash([byte; 32])).to_string();
+        let transfer = format!("{}.transfer", contract(2));
+        let ping = format!("{}.ping", contract(2));
+        let charge = |cpu: u64| MockCost {
+            cpu_instructions: cpu,
+            memory_bytes: cpu / 10,
+            latency_ms: 0,
+        };
+        let events = vec![
+            call_event(9, "entry"),
+            call_event(1, "swap"),
+            call_event(2, "transfer"),
+            return_event("transfer"),
+            return_event("swap"),
+            // A failed call emits no fn_return.
+            call_event(2, "ping"),
+            return_event("entry"),
+        ];
+        let calls = VecDeque::from(vec![
+            MockedCall {
+                callee: transfer.clone(),
+                cost: charge(400),
+                cpu_at: 300,
+                memory_at: 20,
+            },
+            MockedCall {
+                callee: ping.clone(),
+                cost: charge(100),
+                cpu_at: 800,
+                memory_at: 60,
+            },
+        ]);
+
+        let root = call_tree_from_events("entry", 1000, 50, &events, calls);
+
+        assert_eq!(root.function, "entry");
+        assert_eq!((root.cpu_cost, root.memory_cost), (1000, 50));
+        let names: Vec<&str> = root.children.iter().map(|c| c.function.as_str()).collect();
+        assert_eq!(names, vec![format!("{}.swap", contract(1)), ping]);
+        // The budget consumed before `transfer` is swap's own cost.
+        let swap = &root.children[0];
+        assert_eq!((swap.depth, swap.cpu_cost, swap.memory_cost), (1, 700, 60));
+        assert_eq!(swap.children.len(), 1);
+        assert_eq!(swap.children[0].function, transfer);
+        assert_eq!(
+            (swap.children[0].depth, swap.children[0].cpu_cost),
+            (2, 400)
+        );
+        assert_eq!(root.children[1].cpu_cost, 100);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::profiler::analyzer::OptimizationReport;
 use crate::Result;
+use std::collections::BTreeMap;
 use std::fmt::Write as _;
 use std::path::Path;
 
@@ -81,12 +82,10 @@ impl FlameGraphGenerator {
         opts.image_width = Some(width);
 
         let mut svg = Vec::new();
-        inferno::flamegraph::from_reader(&mut opts, reader, &mut svg).map_err(|e| {
-            crate::DebuggerError::ExecutionError(format!("Flamegraph render error: {e}"))
-        })?;
+        inferno::flamegraph::from_reader(&mut opts, reader, &mut svg)
+            .map_err(|e| miette::miette!("Flamegraph render error: {e}"))?;
 
-        String::from_utf8(svg)
-            .map_err(|e| crate::DebuggerError::ExecutionError(format!("UTF-8 error: {e}")).into())
+        String::from_utf8(svg).map_err(|e| miette::miette!("UTF-8 error: {e}"))
     }
 
     pub fn write_collapsed_stack_file<P: AsRef<Path>>(
@@ -122,6 +121,76 @@ impl FlameGraphGenerator {
     }
 }
 
+/// Accumulates folded-stack samples across several invocations so a whole
+/// workflow (for example a scenario) renders as one flamegraph.
+///
+/// Identical stacks are merged, so calling the same function in several steps
+/// adds up to its cumulative cost.
+#[derive(Debug, Default)]
+pub struct FlameGraphAccumulator {
+    samples: BTreeMap<Vec<String>, u64>,
+}
+
+impl FlameGraphAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `count` samples for `stack`. Zero-count samples are ignored.
+    pub fn add(&mut self, stack: Vec<String>, count: u64) {
+        if count == 0 || stack.is_empty() {
+            return;
+        }
+        let entry = self.samples.entry(stack).or_insert(0);
+        *entry = entry.saturating_add(count);
+    }
+
+    /// Record one invocation of `function` costing `total_cpu`, of which
+    /// `children` (callee frame, cpu) were spent in nested calls.
+    ///
+    /// The function frame receives only its self cost so that the rendered
+    /// width of the frame equals `total_cpu`.
+    pub fn add_invocation(&mut self, function: &str, total_cpu: u64, children: &[(String, u64)]) {
+        let child_cpu: u64 = children.iter().map(|(_, cpu)| *cpu).sum();
+        self.add(
+            vec![function.to_string()],
+            total_cpu.saturating_sub(child_cpu),
+        );
+        for (callee, cpu) in children {
+            self.add(vec![function.to_string(), callee.clone()], *cpu);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn stacks(&self) -> Vec<FlameGraphStack> {
+        self.samples
+            .iter()
+            .map(|(stack, count)| FlameGraphStack {
+                stack: stack.clone(),
+                count: *count,
+            })
+            .collect()
+    }
+
+    /// Write the aggregated flamegraph: SVG when `path` ends in `.svg`,
+    /// collapsed-stack text otherwise.
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let stacks = self.stacks();
+        let is_svg = path
+            .as_ref()
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"));
+        if is_svg {
+            FlameGraphGenerator::write_svg_file(&stacks, path, 1200, 800)
+        } else {
+            FlameGraphGenerator::write_collapsed_stack_file(&stacks, path)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,6 +209,7 @@ mod tests {
                 storage_accesses: HashMap::new(),
                 call_tree: None,
                 timeline: None,
+                source_costs: vec![],
             }],
             suggestions: vec![],
             total_cpu: 1000,
@@ -191,4 +261,17 @@ mod tests {
 
         let _ = std::fs::remove_file(&file_path);
     }
+
+    #[test]
+    fn test_accumulator_merges_repeated_invocations() {
+        let mut acc = FlameGraphAccumulator::new();
+        acc.add_invocation("init", 100, &[]);
+        acc.add_invocation("transfer", 500, &[("token.balance".to_string(), 200)]);
+        acc.add_invocation("transfer", 300, &[]);
+
+        let collapsed = FlameGraphGenerator::to_collapsed_stack_format(&acc.stacks());
+        assert!(collapsed.contains("init 100"));
+        assert!(collapsed.contains("transfer 600"));
+        assert!(collapsed.contains("transfer;token.balance 200"));
+    }
 }
@@ -0,0 +1,125 @@
+//! Memory usage over the course of a single execution.
+//!
+//! The budget only reports the final memory figure. A `MemoryTimeline` keeps the
+//! memory reading taken at every checkpoint of a run (before and after the call,
+//! on contract entry and exit, around mocked cross-contract calls and at each
+//! step) so growth can be seen, either as a terminal chart or as a JSON series.
+//! The budget counts every byte charged so far and never goes down, so a reading
+//! is memory allocated up to that point, not memory still live.
+
+use crate::inspector::budget::{BudgetInspector, ResourceCheckpoint};
+use serde::Serialize;
+use textplots::{Chart, Plot, Shape};
+
+/// Memory reading at one instrumentation hook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MemorySample {
+    pub step: usize,
+    pub memory_bytes: u64,
+    pub location: String,
+}
+
+/// Step-indexed memory samples for one function call.
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryTimeline {
+    pub function: String,
+    pub peak_bytes: u64,
+    pub samples: Vec<MemorySample>,
+}
+
+impl MemoryTimeline {
+    /// Build a timeline from the checkpoints recorded during a run.
+    pub fn from_checkpoints(function: &str, checkpoints: &[ResourceCheckpoint]) -> Self {
+        let samples: Vec<MemorySample> = checkpoints
+            .iter()
+            .enumerate()
+            .map(|(step, checkpoint)| MemorySample {
+                step,
+                memory_bytes: checkpoint.memory_bytes,
+                location: checkpoint.location_name.clone(),
+            })
+            .collect();
+
+        let peak_bytes = samples.iter().map(|s| s.memory_bytes).max().unwrap_or(0);
+
+        Self {
+            function: function.to_string(),
+            peak_bytes,
+            samples,
+        }
+    }
+
+    /// Line chart of step index against bytes.
+    pub fn render_chart(&self, width: u32, height: u32) -> String {
+        let points: Vec<(f32, f32)> = self
+            .samples
+            .iter()
+            .map(|s| (s.step as f32, s.memory_bytes as f32))
+            .collect();
+        let xmax = points.len().saturating_sub(1).max(1) as f32;
+
+        let shape = Shape::Lines(&points);
+        let mut chart = Chart::new(width, height, 0.0, xmax);
+        let chart = chart.lineplot(&shape);
+        chart.axis();
+        chart.figures();
+        chart.to_string()
+    }
+
+    /// Chart followed by the per-step readings.
+    pub fn render(&self) -> String {
+        let mut out = format!("Memory timeline for {}:\n", self.function);
+        if self.samples.is_empty() {
+            out.push_str("  (no samples)\n");
+            return out;
+        }
+
+        out.push_str(&self.render_chart(120, 40));
+        out.push('\n');
+        for sample in &self.samples {
+            out.push_str(&format!(
+                "  [{:>3}] {:>12}  {}\n",
+                sample.step,
+                BudgetInspector::format_memory_bytes(sample.memory_bytes),
+                sample.location
+            ));
+        }
+        out.push_str(&format!(
+            "Peak: {}\n",
+            BudgetInspector::format_memory_bytes(self.peak_bytes)
+        ));
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkpoint(memory_bytes: u64, location: &str) -> ResourceCheckpoint {
+        ResourceCheckpoint {
+            timestamp_ms: 0,
+            cpu_instructions: 0,
+            memory_bytes,
+            location_name: location.to_string(),
+        }
+    }
+
+    #[test]
+    fn from_checkpoints_tracks_peak() {
+        let timeline = MemoryTimeline::from_checkpoints(
+            "alloc",
+            &[
+                checkpoint(100, "Session Start"),
+                checkpoint(400, "Enter \"alloc\""),
+                checkpoint(900, "Exit \"alloc\""),
+                checkpoint(950, "Session End"),
+            ],
+        );
+
+        assert_eq!(timeline.samples.len(), 4);
+        assert_eq!(timeline.samples[2].step, 2);
+        assert_eq!(timeline.peak_bytes, 950);
+        assert!(timeline.render().contains("Session End"));
+    }
+}
@@ -1,6 +1,10 @@
 pub mod analyzer;
 pub mod flamegraph;
+pub mod memory_timeline;
 pub mod session;
+pub mod source_cost;
 
 pub use analyzer::{GasOptimizer, OptimizationReport, OptimizationSuggestion};
-pub use flamegraph::FlameGraphGenerator;
+pub use flamegraph::{FlameGraphAccumulator, FlameGraphGenerator};
+pub use memory_timeline::MemoryTimeline;
+pub use source_cost::SourceLineCost;
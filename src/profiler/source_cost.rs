@@ -0,0 +1,218 @@
+//! Source-line cost attribution for the gas profiler.
+//!
+//! The host meters an invocation as a whole, not per instruction, so the CPU
+//! measured for a function is spread over the instructions reachable from its
+//! export. Each instruction gets a rough static weight, and the weights are
+//! summed per DWARF source line. The result ranks lines by estimated share of
+//! the measured cost; it does not account for how often a loop runs.
+
+use crate::debugger::source_map::SourceMap;
+use crate::{DebuggerError, Result};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use wasmparser::{ExternalKind, Operator, Parser, Payload, TypeRef};
+
+/// Estimated cost of one source line within a profiled function.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct SourceLineCost {
+    pub file: PathBuf,
+    pub line: u32,
+    /// Exported function whose invocation the cost was measured for.
+    pub function: String,
+    pub cpu_cost: u64,
+    /// Number of instructions mapped to this line.
+    pub instructions: usize,
+}
+
+struct Body {
+    /// `(module offset, weight)` for each instruction.
+    instructions: Vec<(usize, u64)>,
+    callees: Vec<u32>,
+}
+
+/// Spread `total_cpu` over the source lines of the code reachable from the
+/// export `function`, most expensive line first.
+///
+/// Returns an empty list when `source_map` has no entries for that code.
+pub fn attribute_source_costs(
+    wasm_bytes: &[u8],
+    source_map: &SourceMap,
+    function: &str,
+    total_cpu: u64,
+) -> Result<Vec<SourceLineCost>> {
+    let parse_err = |e: wasmparser::BinaryReaderError| {
+        DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e))
+    };
+
+    let mut imported_funcs = 0u32;
+    let mut export_index = None;
+    let mut bodies: HashMap<u32, Body> = HashMap::new();
+
+    for payload in Parser::new(0).parse_all(wasm_bytes) {
+        match payload.map_err(parse_err)? {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    if matches!(import.map_err(parse_err)?.ty, TypeRef::Func(_)) {
+                        imported_funcs += 1;
+                    }
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    let export = export.map_err(parse_err)?;
+                    if export.kind == ExternalKind::Func && export.name == function {
+                        export_index = Some(export.index);
+                    }
+                }
+            }
+            Payload::CodeSectionEntry(body) => {
+                let index = imported_funcs + bodies.len() as u32;
+                let mut reader = body.get_operators_reader().map_err(parse_err)?;
+                let mut parsed = Body {
+                    instructions: Vec::new(),
+                    callees: Vec::new(),
+                };
+                while !reader.eof() {
+                    let offset = reader.original_position();
+                    let op = reader.read().map_err(parse_err)?;
+                    if let Operator::Call { function_index } = op {
+                        if function_index >= imported_funcs {
+                            parsed.callees.push(function_index);
+                        }
+                    }
+                    parsed
+                        .instructions
+                        .push((offset, instruction_weight(&op, imported_funcs)));
+                }
+                bodies.insert(index, parsed);
+            }
+            _ => {}
+        }
+    }
+
+    let Some(export_index) = export_index else {
+        return Ok(Vec::new());
+    };
+
+    let mut weights: HashMap<(PathBuf, u32), (u64, usize)> = HashMap::new();
+    let mut seen = HashSet::from([export_index]);
+    let mut queue = VecDeque::from([export_index]);
+    while let Some(index) = queue.pop_front() {
+        let Some(body) = bodies.get(&index) else {
+            continue;
+        };
+        for &(offset, weight) in &body.instructions {
+            let Some(location) = source_map.lookup(offset) else {
+                continue;
+            };
+            if location.line == 0 {
+                continue;
+            }
+            let entry = weights.entry((location.file, location.line)).or_default();
+            entry.0 += weight;
+            entry.1 += 1;
+        }
+        for &callee in &body.callees {
+            if seen.insert(callee) {
+                queue.push_back(callee);
+            }
+        }
+    }
+
+    let total_weight: u64 = weights.values().map(|(weight, _)| weight).sum();
+    if total_weight == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut costs: Vec<SourceLineCost> = weights
+        .into_iter()
+        .map(|((file, line), (weight, instructions))| SourceLineCost {
+            file,
+            line,
+            function: function.to_string(),
+            cpu_cost: (total_cpu as u128 * weight as u128 / total_weight as u128) as u64,
+            instructions,
+        })
+        .collect();
+    costs.sort_by(|a, b| {
+        b.cpu_cost
+            .cmp(&a.cpu_cost)
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.line.cmp(&b.line))
+    });
+    Ok(costs)
+}
+
+/// Rough relative cost of one instruction. Host calls dominate real budgets,
+/// so calls into imports weigh far more than plain arithmetic.
+fn instruction_weight(op: &Operator, imported_funcs: u32) -> u64 {
+    match op {
+        Operator::Call { function_index } if *function_index < imported_funcs => 20,
+        Operator::Call { .. } | Operator::CallIndirect { .. } => 5,
+        Operator::MemoryGrow { .. } | Operator::MemoryCopy { .. } | Operator::MemoryFill { .. } => {
+            10
+        }
+        Operator::I32Load { .. }
+        | Operator::I64Load { .. }
+        | Operator::I32Store { .. }
+        | Operator::I64Store { .. } => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debugger::source_map::SourceLocation;
+
+    /// Module importing one host function (index 0) and exporting `run`
+    /// (index 1), whose body is `call 0; nop; end`.
+    fn wasm() -> Vec<u8> {
+        let mut wasm = vec![0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00];
+        wasm.extend_from_slice(&[0x01, 0x04, 0x01, 0x60, 0x00, 0x00]);
+        wasm.extend_from_slice(&[0x02, 0x07, 0x01, 0x01, b'x', 0x01, b'_', 0x00, 0x00]);
+        wasm.extend_from_slice(&[0x03, 0x02, 0x01, 0x00]);
+        wasm.extend_from_slice(&[0x07, 0x07, 0x01, 0x03, b'r', b'u', b'n', 0x00, 0x01]);
+        wasm.extend_from_slice(&[0x0A, 0x07, 0x01, 0x05, 0x00, 0x10, 0x00, 0x01, 0x0B]);
+        wasm
+    }
+
+    fn location(line: u32) -> SourceLocation {
+        SourceLocation {
+            file: PathBuf::from("src/lib.rs"),
+            line,
+            column: None,
+        }
+    }
+
+    #[test]
+    fn costs_are_split_by_instruction_weight() {
+        let wasm = wasm();
+        // `call 0` is at offset 41, `nop` at 43 and `end` at 44.
+        let mut source_map = SourceMap::new();
+        source_map.add_mapping(41, location(10));
+        source_map.add_mapping(43, location(11));
+
+        let costs = attribute_source_costs(&wasm, &source_map, "run", 2200).unwrap();
+
+        assert_eq!(costs.len(), 2);
+        assert_eq!((costs[0].line, costs[0].cpu_cost), (10, 2000));
+        // `nop` and `end` both fall on line 11.
+        assert_eq!((costs[1].line, costs[1].cpu_cost), (11, 200));
+        assert_eq!(costs[1].instructions, 2);
+        assert_eq!(costs[0].function, "run");
+    }
+
+    #[test]
+    fn missing_line_info_yields_no_costs() {
+        let costs = attribute_source_costs(&wasm(), &SourceMap::new(), "run", 1000).unwrap();
+        assert!(costs.is_empty());
+        let mut source_map = SourceMap::new();
+        source_map.add_mapping(41, location(10));
+        assert!(
+            attribute_source_costs(&wasm(), &source_map, "missing", 1000)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
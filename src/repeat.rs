@@ -1,8 +1,10 @@
+use crate::debugger::breakpoint::BreakpointSpec;
 use crate::debugger::engine::DebuggerEngine;
 use crate::inspector::budget::{BudgetInfo, BudgetInspector};
 use crate::logging;
 use crate::runtime::executor::ContractExecutor;
 use crate::Result;
+use serde::Serialize;
 use std::time::{Duration, Instant};
 
 /// Stats captured from a single execution run.
@@ -14,6 +16,51 @@ pub struct RunStats {
     pub result: String,
 }
 
+/// Distribution of one metric across runs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SampleStats {
+    pub min: u64,
+    pub max: u64,
+    pub median: u64,
+    pub p95: u64,
+    pub p99: u64,
+    pub stddev: f64,
+}
+
+impl SampleStats {
+    /// Compute the distribution of `samples` (must be non-empty).
+    ///
+    /// Percentiles use the nearest-rank method and the standard deviation is
+    /// the population one, so a single run reports a deviation of zero.
+    pub fn from_samples(samples: &[u64]) -> Self {
+        assert!(!samples.is_empty(), "Cannot summarize zero samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let percentile = |p: f64| {
+            let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+            sorted[rank.clamp(1, sorted.len()) - 1]
+        };
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().map(|&v| v as f64).sum::<f64>() / n;
+        let variance = sorted
+            .iter()
+            .map(|&v| (v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Self {
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            median: percentile(50.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
 /// Aggregate statistics computed over N runs.
 #[derive(Debug)]
 pub struct AggregateStats {
@@ -27,6 +74,8 @@ pub struct AggregateStats {
     pub min_memory: u64,
     pub max_memory: u64,
     pub avg_memory: u64,
+    pub cpu_stats: SampleStats,
+    pub memory_stats: SampleStats,
     pub inconsistent_results: bool,
 }
 
@@ -88,7 +137,12 @@ impl AggregateStats {
             }
         }
 
+        let cpu_samples: Vec<u64> = runs.iter().map(|r| r.budget.cpu_instructions).collect();
+        let memory_samples: Vec<u64> = runs.iter().map(|r| r.budget.memory_bytes).collect();
+
         AggregateStats {
+            cpu_stats: SampleStats::from_samples(&cpu_samples),
+            memory_stats: SampleStats::from_samples(&memory_samples),
             runs,
             min_duration: min_dur,
             max_duration: max_dur,
@@ -138,14 +192,46 @@ impl AggregateStats {
             );
 
             println!("{}", Formatter::info("CPU Instructions:"));
-            println!("{}", Formatter::info(format!("  Min: {}", self.min_cpu)));
-            println!("{}", Formatter::info(format!("  Max: {}", self.max_cpu)));
-            println!("{}", Formatter::info(format!("  Avg: {}", self.avg_cpu)));
+            println!(
+                "{}",
+                Formatter::info(format!("  Min: {}", Formatter::format_number(self.min_cpu)))
+            );
+            println!(
+                "{}",
+                Formatter::info(format!("  Max: {}", Formatter::format_number(self.max_cpu)))
+            );
+            println!(
+                "{}",
+                Formatter::info(format!("  Avg: {}", Formatter::format_number(self.avg_cpu)))
+            );
 
             println!("{}", Formatter::info("Memory (bytes):"));
-            println!("{}", Formatter::info(format!("  Min: {}", self.min_memory)));
-            println!("{}", Formatter::info(format!("  Max: {}", self.max_memory)));
-            println!("{}", Formatter::info(format!("  Avg: {}", self.avg_memory)));
+            println!(
+                "{}",
+                Formatter::info(format!(
+                    "  Min: {}",
+                    Formatter::format_number(self.min_memory)
+                ))
+            );
+            println!(
+                "{}",
+                Formatter::info(format!(
+                    "  Max: {}",
+                    Formatter::format_number(self.max_memory)
+                ))
+            );
+            println!(
+                "{}",
+                Formatter::info(format!(
+                    "  Avg: {}",
+                    Formatter::format_number(self.avg_memory)
+                ))
+            );
+
+            println!("{}", Formatter::info("Distribution:"));
+            for line in self.distribution_table().lines() {
+                println!("{}", Formatter::info(format!("  {}", line)));
+            }
 
             if self.inconsistent_results {
                 println!(
@@ -186,6 +272,9 @@ impl AggregateStats {
             min_memory = self.min_memory,
             max_memory = self.max_memory,
             avg_memory = self.avg_memory,
+            p95_cpu = self.cpu_stats.p95,
+            p99_cpu = self.cpu_stats.p99,
+            stddev_cpu = self.cpu_stats.stddev,
             inconsistent = self.inconsistent_results,
             "Repeat run summary"
         );
@@ -204,6 +293,44 @@ impl AggregateStats {
             }
         }
     }
+
+    /// Min, median, p95, p99, max and standard deviation for CPU and memory.
+    pub fn distribution_table(&self) -> String {
+        let mut out = format!(
+            "{:<8} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12}\n",
+            "Metric", "Min", "Median", "p95", "p99", "Max", "StdDev"
+        );
+        for (label, stats) in [("CPU", &self.cpu_stats), ("Memory", &self.memory_stats)] {
+            out.push_str(&format!(
+                "{:<8} {:>12} {:>12} {:>12} {:>12} {:>12} {:>12.1}\n",
+                label, stats.min, stats.median, stats.p95, stats.p99, stats.max, stats.stddev
+            ));
+        }
+        out
+    }
+
+    /// Per-iteration samples and their distributions, for `--repeat-json`.
+    pub fn to_samples_json(&self) -> serde_json::Value {
+        let runs: Vec<serde_json::Value> = self
+            .runs
+            .iter()
+            .map(|run| {
+                serde_json::json!({
+                    "iteration": run.iteration,
+                    "duration_ms": run.duration.as_secs_f64() * 1000.0,
+                    "cpu_instructions": run.budget.cpu_instructions,
+                    "memory_bytes": run.budget.memory_bytes,
+                    "result": run.result,
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "runs": runs,
+            "cpu": self.cpu_stats,
+            "memory": self.memory_stats,
+            "inconsistent_results": self.inconsistent_results,
+        })
+    }
 }
 
 /// Truncate a string to `max_len` characters, adding "…" if truncated.
@@ -220,23 +347,32 @@ fn truncate(s: &str, max_len: usize) -> String {
 /// Orchestrates repeated contract execution.
 pub struct RepeatRunner {
     wasm_bytes: Vec<u8>,
-    breakpoints: Vec<String>,
+    breakpoints: Vec<BreakpointSpec>,
     initial_storage: Option<String>,
+    contract_storage: Vec<(String, String)>,
 }
 
 impl RepeatRunner {
     pub fn new(
         wasm_bytes: Vec<u8>,
-        breakpoints: Vec<String>,
+        breakpoints: Vec<BreakpointSpec>,
         initial_storage: Option<String>,
     ) -> Self {
         Self {
             wasm_bytes,
             breakpoints,
             initial_storage,
+            contract_storage: Vec::new(),
         }
     }
 
+    /// Seed storage for other contracts (`--contract-storage`) before every
+    /// iteration, as a single run does.
+    pub fn with_contract_storage(mut self, contract_storage: Vec<(String, String)>) -> Self {
+        self.contract_storage = contract_storage;
+        self
+    }
+
     /// Run the contract function `n` times and return aggregate stats.
     pub fn run(&self, function: &str, args: Option<&str>, n: u32) -> Result<AggregateStats> {
         logging::log_repeat_execution(function, n as usize);
@@ -256,8 +392,11 @@ impl RepeatRunner {
             if let Some(ref storage) = self.initial_storage {
                 executor.set_initial_storage(storage.clone())?;
             }
+            for (contract_id, storage) in &self.contract_storage {
+                executor.set_contract_storage(contract_id, storage.clone())?;
+            }
 
-            let mut engine = DebuggerEngine::new(executor, self.breakpoints.clone());
+            let mut engine = DebuggerEngine::with_specs(executor, self.breakpoints.clone());
 
             let start = Instant::now();
             let result = engine.execute(function, args)?;
@@ -368,6 +507,41 @@ mod tests {
         assert!(!stats.inconsistent_results);
     }
 
+    #[test]
+    fn test_sample_stats_percentiles_and_stddev() {
+        let samples: Vec<u64> = (1..=100).collect();
+        let stats = SampleStats::from_samples(&samples);
+
+        assert_eq!(stats.min, 1);
+        assert_eq!(stats.max, 100);
+        assert_eq!(stats.median, 50);
+        assert_eq!(stats.p95, 95);
+        assert_eq!(stats.p99, 99);
+        assert!((stats.stddev - 28.866).abs() < 0.01);
+
+        let single = SampleStats::from_samples(&[42]);
+        assert_eq!(single.p99, 42);
+        assert_eq!(single.stddev, 0.0);
+    }
+
+    #[test]
+    fn test_outlier_shows_in_p99_not_median() {
+        let mut runs: Vec<RunStats> = (1..=99)
+            .map(|i| make_run(i, 10, 1000, 500, "Ok(())"))
+            .collect();
+        runs.push(make_run(100, 10, 90_000, 500, "Ok(())"));
+        let stats = AggregateStats::from_runs(runs);
+
+        assert_eq!(stats.cpu_stats.median, 1000);
+        assert_eq!(stats.cpu_stats.p99, 1000);
+        assert_eq!(stats.cpu_stats.max, 90_000);
+        assert!(stats.avg_cpu > 1000);
+        assert_eq!(
+            stats.to_samples_json()["runs"].as_array().unwrap().len(),
+            100
+        );
+    }
+
     #[test]
     fn test_display_does_not_panic() {
         let runs = vec![
@@ -7,7 +7,7 @@ use crate::Result;
 pub enum ReplCommand {
     /// No operation for empty input
     Noop,
-    /// Call a contract function: call <function> [args...]
+    /// Call a contract function: call|exec <function> [args...]
     Call {
         function: String,
         args: Vec<String>,
@@ -34,6 +34,10 @@ pub enum ReplCommand {
         function: String,
     },
     Functions,
+    /// Save the session's calls as an execution trace: save-trace <path>
+    SaveTrace {
+        path: String,
+    },
 }
 
 impl ReplCommand {
@@ -41,6 +45,7 @@ impl ReplCommand {
     pub fn builtins() -> &'static [&'static str] {
         &[
             "call",
+            "exec",
             "storage",
             "history",
             "clear",
@@ -51,6 +56,7 @@ impl ReplCommand {
             "list-breaks",
             "clear-break",
             "functions",
+            "save-trace",
         ]
     }
 
@@ -67,6 +73,16 @@ impl ReplCommand {
         }
     }
 
+    /// Parse like [`ReplCommand::parse`], treating a bare exported function
+    /// name as an implicit call: `approve Alice 5` runs `call approve Alice 5`.
+    pub fn parse_with_functions(input: &str, functions: &[String]) -> Result<Self> {
+        let first = input.split_whitespace().next().unwrap_or_default();
+        if !Self::builtins().contains(&first) && functions.iter().any(|f| f == first) {
+            return Self::parse(&format!("call {}", input.trim()));
+        }
+        Self::parse(input)
+    }
+
     /// Parse a command string into a ReplCommand
     pub fn parse(input: &str) -> Result<Self> {
         let trimmed = input.trim();
@@ -78,9 +94,9 @@ impl ReplCommand {
         }
 
         match parts[0].as_str() {
-            "call" => {
+            "call" | "exec" => {
                 if parts.len() < 2 {
-                    return Err(miette::miette!("call requires a function name"));
+                    return Err(miette::miette!("{} requires a function name", parts[0]));
                 }
                 let function = parts[1].clone();
                 let args = parts[2..].to_vec();
@@ -112,6 +128,14 @@ impl ReplCommand {
             "storage" => Ok(ReplCommand::Storage),
             "history" => Ok(ReplCommand::History),
             "functions" => Ok(ReplCommand::Functions),
+            "save-trace" => {
+                if parts.len() < 2 {
+                    return Err(miette::miette!("save-trace requires a file path"));
+                }
+                Ok(ReplCommand::SaveTrace {
+                    path: parts[1].clone(),
+                })
+            }
             "clear" => Ok(ReplCommand::Clear),
             "help" => Ok(ReplCommand::Help),
             "exit" | "quit" => Ok(ReplCommand::Exit),
@@ -151,6 +175,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_exec_is_an_alias_for_call() {
+        let cmd = ReplCommand::parse("exec approve Alice 5").unwrap();
+        match cmd {
+            ReplCommand::Call { function, args } => {
+                assert_eq!(function, "approve");
+                assert_eq!(args, vec!["Alice", "5"]);
+            }
+            _ => panic!("Expected Call command"),
+        }
+        assert!(ReplCommand::parse("exec").is_err());
+    }
+
+    #[test]
+    fn test_parse_save_trace_command() {
+        let cmd = ReplCommand::parse("save-trace session.json").unwrap();
+        assert!(matches!(cmd, ReplCommand::SaveTrace { path } if path == "session.json"));
+        assert!(ReplCommand::parse("save-trace").is_err());
+    }
+
     #[test]
     fn test_parse_storage_command() {
         let cmd = ReplCommand::parse("storage").unwrap();
@@ -196,6 +240,23 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_bare_function_name_is_an_implicit_call() {
+        let functions = vec!["approve".to_string(), "clear".to_string()];
+        let cmd = ReplCommand::parse_with_functions("approve Alice 5", &functions).unwrap();
+        match cmd {
+            ReplCommand::Call { function, args } => {
+                assert_eq!(function, "approve");
+                assert_eq!(args, vec!["Alice", "5"]);
+            }
+            _ => panic!("Expected Call command"),
+        }
+        // Built-in commands win over a function of the same name.
+        let cmd = ReplCommand::parse_with_functions("clear", &functions).unwrap();
+        assert!(matches!(cmd, ReplCommand::Clear));
+        assert!(ReplCommand::parse_with_functions("unknown", &functions).is_err());
+    }
+
     #[test]
     fn test_invalid_quote_fails() {
         let result = ReplCommand::parse(r#"call transfer "unterminated"#);
@@ -3,22 +3,44 @@
 /// Handles execution of function calls and storage inspection
 /// against the loaded contract.
 use super::ReplConfig;
+use crate::compare::trace::{BudgetTrace, CallEntry, EventEntry};
+use crate::compare::ExecutionTrace;
 use crate::inspector::StorageInspector;
 use crate::runtime::executor::ContractExecutor;
-use crate::utils::wasm::{parse_function_signatures, ContractFunctionSignature};
+use crate::utils::wasm::{parse_function_signatures, parse_functions, ContractFunctionSignature};
 use crate::Result;
 use serde_json::json;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::path::{Path, PathBuf};
 
 /// Executor for REPL commands
 pub struct ReplExecutor {
     engine: crate::debugger::engine::DebuggerEngine,
     signatures: HashMap<String, ContractFunctionSignature>,
+    exported_functions: Vec<String>,
     address_aliases: HashMap<String, String>,
     alias_path: std::path::PathBuf,
     watch_keys: Vec<String>,
+    contract_path: PathBuf,
+    /// Completed calls in session order, for `save-trace`.
+    calls: Vec<RecordedCall>,
+}
+
+/// One completed REPL call as it is written to a saved trace.
+struct RecordedCall {
+    function: String,
+    args: Option<String>,
+    result: String,
+    events: Vec<EventEntry>,
+    /// Instance storage as the call found it, in `--storage` seed form, which
+    /// replaying the call starts from.
+    storage_before: BTreeMap<String, Value>,
+    cpu_instructions: u64,
+    memory_bytes: u64,
+    cpu_limit: u64,
+    memory_limit: u64,
 }
 
 impl ReplExecutor {
@@ -34,6 +56,7 @@ impl ReplExecutor {
             .into_iter()
             .map(|sig| (sig.name.clone(), sig))
             .collect();
+        let exported_functions = parse_functions(&wasm_bytes)?;
         let executor = ContractExecutor::new(wasm_bytes)?;
         let mut engine = crate::debugger::engine::DebuggerEngine::new(executor, Vec::new());
         engine.executor_mut().enable_mock_all_auths();
@@ -70,9 +93,12 @@ impl ReplExecutor {
         Ok(ReplExecutor {
             engine,
             signatures,
+            exported_functions,
             address_aliases,
             alias_path,
             watch_keys: config.watch_keys.clone(),
+            contract_path: config.contract_path.clone(),
+            calls: Vec::new(),
         })
     }
 
@@ -96,8 +122,10 @@ impl ReplExecutor {
         }
 
         let storage_before = self.engine.executor().get_storage_snapshot()?;
+        let seed_before = StorageInspector::capture_instance_seed(self.engine.executor().host());
         let result = self.engine.execute(function, args_ref)?;
         let storage_after = self.engine.executor().get_storage_snapshot()?;
+        self.record_call(function, args_ref, &result, seed_before);
 
         crate::logging::log_display(
             format!("Result: {}", result),
@@ -115,10 +143,112 @@ impl ReplExecutor {
         Ok(())
     }
 
+    fn record_call(
+        &mut self,
+        function: &str,
+        args: Option<&str>,
+        result: &str,
+        storage_before: BTreeMap<String, Value>,
+    ) {
+        let executor = self.engine.executor();
+        let events: Vec<EventEntry> = executor
+            .get_events()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|e| EventEntry {
+                contract_id: e.contract_id,
+                topics: e.topics,
+                data: Some(e.data),
+            })
+            .collect();
+        // The host may keep events from earlier calls; only keep the new ones.
+        let seen: Vec<&EventEntry> = self.calls.iter().flat_map(|c| &c.events).collect();
+        let events = if events.len() >= seen.len() && events.iter().zip(&seen).all(|(a, b)| a == *b)
+        {
+            events[seen.len()..].to_vec()
+        } else {
+            events
+        };
+        let budget = executor.last_execution().map(|record| &record.budget);
+
+        self.calls.push(RecordedCall {
+            function: function.to_string(),
+            args: args.map(str::to_string),
+            result: result.to_string(),
+            events,
+            storage_before,
+            cpu_instructions: budget.map_or(0, |b| b.cpu_instructions),
+            memory_bytes: budget.map_or(0, |b| b.memory_bytes),
+            cpu_limit: budget.map_or(0, |b| b.cpu_limit),
+            memory_limit: budget.map_or(0, |b| b.memory_limit),
+        });
+    }
+
+    /// The calls made so far as an [`ExecutionTrace`]: every call in order in
+    /// `call_sequence` and all events. `function`, `args`, `return_value` and
+    /// `budget` describe the last call, which is what `replay` re-executes, and
+    /// `storage` is the instance storage that call started from, keyed by symbol
+    /// name with typed values so it can be passed back as `--storage`.
+    pub fn session_trace(&self) -> Result<ExecutionTrace> {
+        let last = self
+            .calls
+            .last()
+            .ok_or_else(|| miette::miette!("No calls made yet; nothing to save"))?;
+
+        let contract = self.contract_path.display().to_string();
+
+        Ok(ExecutionTrace {
+            label: Some(format!(
+                "REPL session on {} ({} calls)",
+                contract,
+                self.calls.len()
+            )),
+            contract: Some(contract),
+            function: Some(last.function.clone()),
+            args: last.args.clone(),
+            storage: last.storage_before.clone(),
+            budget: Some(BudgetTrace {
+                cpu_instructions: last.cpu_instructions,
+                memory_bytes: last.memory_bytes,
+                cpu_limit: Some(last.cpu_limit),
+                memory_limit: Some(last.memory_limit),
+            }),
+            return_value: Some(
+                serde_json::from_str(&last.result)
+                    .unwrap_or_else(|_| Value::String(last.result.clone())),
+            ),
+            call_sequence: self
+                .calls
+                .iter()
+                .map(|c| CallEntry {
+                    function: c.function.clone(),
+                    args: c.args.clone(),
+                    depth: 0,
+                })
+                .collect(),
+            events: self.calls.iter().flat_map(|c| c.events.clone()).collect(),
+        })
+    }
+
+    /// Write [`Self::session_trace`] to `path` as trace JSON.
+    pub fn save_trace(&self, path: &Path) -> Result<()> {
+        let json = self.session_trace()?.to_json()?;
+        fs::write(path, json).map_err(|e| {
+            crate::DebuggerError::FileError(format!("Failed to write trace file {:?}: {}", path, e))
+        })?;
+        Ok(())
+    }
+
     /// Return known exported function names for REPL completion.
     pub fn function_names(&self) -> Vec<String> {
-        let mut names: Vec<String> = self.signatures.keys().cloned().collect();
+        let mut names: Vec<String> = self
+            .exported_functions
+            .iter()
+            .chain(self.signatures.keys())
+            .cloned()
+            .collect();
         names.sort();
+        names.dedup();
         names
     }
 
@@ -16,6 +16,9 @@ use rustyline::validate::{ValidationContext, ValidationResult, Validator};
 use rustyline::{Context, Editor, Helper};
 use std::path::PathBuf;
 
+/// Number of commands kept in the REPL history file unless configured.
+pub const DEFAULT_HISTORY_SIZE: usize = 1000;
+
 /// REPL session state and editor
 pub struct ReplSession {
     editor: Editor<ReplHelper, FileHistory>,
@@ -54,18 +57,19 @@ impl ReplHelper {
         let input = &line[..pos];
         let tokens: Vec<&str> = input.split_whitespace().collect();
 
-        // Complete top-level command name.
+        // Complete top-level command or contract function name.
         if tokens.is_empty() || (tokens.len() == 1 && !input.ends_with(' ')) {
             let (start, prefix) = match tokens.first() {
                 Some(prefix) => (pos.saturating_sub(prefix.len()), *prefix),
                 None => (pos, ""),
             };
-            let matches = Self::complete_from(&self.commands, prefix);
+            let mut matches = Self::complete_from(&self.commands, prefix);
+            matches.extend(Self::complete_from(&self.functions, prefix));
             return (start, matches);
         }
 
-        // Complete function name after `call`.
-        if tokens.first() == Some(&"call") {
+        // Complete function name after `call` / `exec`.
+        if matches!(tokens.first(), Some(&"call") | Some(&"exec")) {
             if input.ends_with(' ') {
                 if tokens.len() == 1 {
                     let start = pos;
@@ -119,19 +123,15 @@ impl ReplSession {
     pub fn new(config: ReplConfig) -> Result<Self> {
         let global_config = crate::config::Config::load_or_default();
         let save_history = global_config.repl.save_history.unwrap_or(true);
-        
+        let history_size = global_config
+            .repl
+            .history_size
+            .unwrap_or(DEFAULT_HISTORY_SIZE);
+
         let history_path = if let Some(path) = global_config.repl.history_file {
             PathBuf::from(path)
         } else {
-            let history_base_dir = dirs::home_dir().unwrap_or_else(|| {
-                let fallback_dir = std::env::temp_dir();
-                tracing::warn!(
-                    "HOME directory is unavailable; REPL history will be stored in temporary directory: {}",
-                    fallback_dir.display()
-                );
-                fallback_dir
-            });
-            history_base_dir.join(".soroban_repl_history")
+            default_history_path()
         };
 
         let executor = ReplExecutor::new(&config)?;
@@ -143,8 +143,9 @@ impl ReplSession {
             executor.function_names(),
         );
 
-        let mut editor = Editor::<ReplHelper, FileHistory>::new()
-            .map_err(|e| miette::miette!("Failed to initialize REPL editor: {}", e))?;
+        let mut editor =
+            Editor::<ReplHelper, FileHistory>::with_config(editor_config(history_size)?)
+                .map_err(|e| miette::miette!("Failed to initialize REPL editor: {}", e))?;
         editor.set_helper(Some(helper));
 
         if save_history {
@@ -187,7 +188,8 @@ impl ReplSession {
                         continue;
                     }
 
-                    match ReplCommand::parse(&line) {
+                    let functions = self.executor.function_names();
+                    match ReplCommand::parse_with_functions(&line, &functions) {
                         Ok(cmd) => {
                             if self.save_history && !cmd.is_sensitive() {
                                 let _ = self.editor.add_history_entry(line.clone());
@@ -230,7 +232,10 @@ impl ReplSession {
         }
 
         if self.save_history {
-            // Save history
+            // History is a convenience: an unwritable file is not worth an error.
+            if let Some(parent) = self.history_path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
             let _ = self.editor.save_history(&self.history_path);
         }
 
@@ -309,6 +314,14 @@ impl ReplSession {
                 self.executor.display_functions()?;
                 Ok(false)
             }
+            ReplCommand::SaveTrace { path } => {
+                self.executor.save_trace(std::path::Path::new(&path))?;
+                tracing::info!(
+                    "{}",
+                    Formatter::success(format!("Session trace saved to {}", path).as_str())
+                );
+                Ok(false)
+            }
             ReplCommand::Palette => {
                 tracing::info!("{}", Formatter::info("Command palette opened. Type an action to run:"));
                 tracing::info!("  export-trace");
@@ -334,7 +347,7 @@ impl ReplSession {
         tracing::info!("");
         tracing::info!("{}", Formatter::success("Available Commands:"));
         tracing::info!(
-            "  {} <func> [args...]  Call a contract function",
+            "  {} <func> [args...]  Call a contract function (alias: exec)",
             Formatter::info("call")
         );
         tracing::info!(
@@ -369,6 +382,10 @@ impl ReplSession {
             "  {}                 Show available contract functions",
             Formatter::info("functions")
         );
+        tracing::info!(
+            "  {} <path>       Save the session's calls as a replayable trace",
+            Formatter::info("save-trace")
+        );
         tracing::info!(
             "  {}                   Open the command palette",
             Formatter::info("palette")
@@ -390,6 +407,104 @@ impl ReplSession {
     }
 }
 
+/// `~/.soroban-debug/repl_history`, or the temporary directory when HOME is unavailable.
+fn default_history_path() -> PathBuf {
+    let history_base_dir = dirs::home_dir().unwrap_or_else(|| {
+        let fallback_dir = std::env::temp_dir();
+        tracing::warn!(
+            "HOME directory is unavailable; REPL history will be stored in temporary directory: {}",
+            fallback_dir.display()
+        );
+        fallback_dir
+    });
+    history_base_dir.join(".soroban-debug").join("repl_history")
+}
+
+/// Editor settings: keep at most `history_size` commands and skip a command
+/// identical to the one before it.
+fn editor_config(history_size: usize) -> Result<rustyline::Config> {
+    let config = rustyline::Config::builder()
+        .max_history_size(history_size)
+        .and_then(|builder| builder.history_ignore_dups(true))
+        .map_err(|e| miette::miette!("Invalid REPL history settings: {}", e))?
+        .build();
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustyline::history::History;
+
+    #[test]
+    fn history_file_is_capped_and_skips_consecutive_duplicates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("repl_history");
+
+        let mut history = FileHistory::with_config(editor_config(3).unwrap());
+        for entry in [
+            "storage",
+            "call get",
+            "call get",
+            "history",
+            "call increment",
+        ] {
+            history.add(entry).unwrap();
+        }
+        history.save(&path).unwrap();
+
+        let mut reloaded = FileHistory::with_config(editor_config(3).unwrap());
+        reloaded.load(&path).unwrap();
+        let entries: Vec<&str> = reloaded.iter().map(String::as_str).collect();
+        assert_eq!(entries, ["call get", "history", "call increment"]);
+    }
+
+    fn helper() -> ReplHelper {
+        ReplHelper::new(
+            ReplCommand::builtins()
+                .iter()
+                .map(|cmd| (*cmd).to_string())
+                .collect(),
+            vec![
+                "admin_mint".to_string(),
+                "approve".to_string(),
+                "balance".to_string(),
+            ],
+        )
+    }
+
+    fn replacements(line: &str) -> (usize, Vec<String>) {
+        let (start, pairs) = helper().complete_for_input(line, line.len());
+        (start, pairs.into_iter().map(|p| p.replacement).collect())
+    }
+
+    #[test]
+    fn completes_function_names_at_top_level() {
+        assert_eq!(replacements("app"), (0, vec!["approve".to_string()]));
+        assert_eq!(
+            replacements("a"),
+            (0, vec!["admin_mint".to_string(), "approve".to_string()])
+        );
+        assert_eq!(replacements("sto"), (0, vec!["storage".to_string()]));
+    }
+
+    #[test]
+    fn completes_first_argument_of_call_and_exec() {
+        assert_eq!(
+            replacements("call adm"),
+            (5, vec!["admin_mint".to_string()])
+        );
+        assert_eq!(replacements("exec ap"), (5, vec!["approve".to_string()]));
+        assert_eq!(replacements("exec ").1.len(), 3);
+        assert!(replacements("call approve al").1.is_empty());
+    }
+
+    #[test]
+    fn default_history_lives_in_soroban_debug_dir() {
+        assert!(default_history_path().ends_with(".soroban-debug/repl_history"));
+    }
+}
+
 // Editing this code
 //I love writing beutiful code
 //I'm going to make this the best REPL session management code ever!
\ No newline at end of file
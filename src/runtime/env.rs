@@ -47,6 +47,8 @@ pub struct DebugEnv {
     call_depth: usize,
     /// Unified dynamic trace events for analysis
     dynamic_events: Vec<crate::server::protocol::DynamicTraceEvent>,
+    /// Maximum nesting of cross-contract calls, counting the entry call
+    max_call_depth: usize,
 }
 
 impl DebugEnv {
@@ -58,9 +60,44 @@ impl DebugEnv {
             operation_sequence: 0,
             call_depth: 0,
             dynamic_events: Vec::new(),
+            max_call_depth: soroban_env_host::DEFAULT_HOST_DEPTH_LIMIT as usize,
         }
     }
 
+    /// Set the maximum cross-contract call depth. The host aborts anything
+    /// nested deeper than `DEFAULT_HOST_DEPTH_LIMIT` frames on its own, so
+    /// larger values are rejected.
+    pub fn set_max_call_depth(&mut self, limit: usize) -> crate::Result<()> {
+        let host_limit = soroban_env_host::DEFAULT_HOST_DEPTH_LIMIT as usize;
+        if limit == 0 || limit > host_limit {
+            return Err(crate::DebuggerError::InvalidArguments(format!(
+                "Max call depth must be between 1 and {}, got {}",
+                host_limit, limit
+            ))
+            .into());
+        }
+        self.max_call_depth = limit;
+        Ok(())
+    }
+
+    /// Get the maximum cross-contract call depth
+    pub fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
+    /// Fail with a stack overflow if a call to `function` at `depth` nests
+    /// deeper than the configured limit
+    pub fn check_call_depth(&self, depth: usize, function: &str) -> crate::Result<()> {
+        if depth > self.max_call_depth {
+            return Err(crate::DebuggerError::ExecutionError(format!(
+                "WASM stack overflow at depth {} in function {} (--max-call-depth {})",
+                depth, function, self.max_call_depth
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
     /// Record a dynamic trace event
     pub fn record_event(
         &mut self,
@@ -522,4 +559,18 @@ mod tests {
         // track_storage_* / enter_function / record_function_call all increment the sequence.
         assert_eq!(env.operation_count(), 4);
     }
+
+    #[test]
+    fn test_max_call_depth() {
+        let mut env = DebugEnv::new();
+        assert!(env.set_max_call_depth(0).is_err());
+        assert!(env.set_max_call_depth(1000).is_err());
+        env.set_max_call_depth(3).unwrap();
+
+        assert!(env.check_call_depth(3, "recurse").is_ok());
+        let err = env.check_call_depth(4, "recurse").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("WASM stack overflow at depth 4 in function recurse"));
+    }
 }
@@ -8,7 +8,9 @@
 //! - [`super::invoker`] â€” Function invocation with timeout protection.
 //! - [`super::result`]  â€” Result types and formatting helpers.
 
-use crate::inspector::budget::MemorySummary;
+use crate::inspector::budget::{CheckpointRecorder, MemorySummary};
+use crate::inspector::events::EventDecoder;
+use crate::inspector::storage::StorageDurability;
 use crate::output::InvocationReason;
 use crate::runtime::env::DebugEnv;
 use crate::runtime::mocking::{MockCallLogEntry, MockContractDispatcher, MockRegistry};
@@ -17,12 +19,14 @@ use crate::utils::arguments::ArgumentParser;
 use crate::{DebuggerError, Result};
 
 use serde_json::{json, Value};
-use soroban_env_host::Host;
+use soroban_env_host::xdr::ScAddress;
+use soroban_env_host::{ContractInvocationEvent, Host};
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::testutils::Ledger as _;
 use soroban_sdk::{Address, Env, Val};
 use std::collections::HashMap;
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc, Mutex,
@@ -36,6 +40,9 @@ pub use crate::runtime::result::{ExecutionRecord, InstructionCounts, StorageSnap
 /// Executes Soroban contracts in a test environment.
 pub const DEFAULT_EXECUTION_TIMEOUT_SECS: u64 = 30;
 
+/// Network passphrase assumed until a network snapshot supplies one.
+pub const DEFAULT_NETWORK_PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
 pub struct ContractExecutor {
     env: Env,
     contract_address: Address,
@@ -48,6 +55,12 @@ pub struct ContractExecutor {
     debug_env: DebugEnv,
     /// Accumulated CPU instruction deltas keyed by function name.
     per_function_cpu: HashMap<String, u64>,
+    network_passphrase: String,
+    checkpoints: CheckpointRecorder,
+    /// Decoder for this contract's events, parsed from its spec once.
+    event_decoder: Option<EventDecoder>,
+    /// Latency declared by the mocked calls of the last execution.
+    last_mock_latency_ms: u64,
 }
 
 impl ContractExecutor {
@@ -55,6 +68,36 @@ impl ContractExecutor {
     #[tracing::instrument(skip_all)]
     pub fn new(wasm: Vec<u8>) -> Result<Self> {
         let loaded = crate::runtime::loader::load_contract(&wasm)?;
+
+        // Sample the budget as the host enters and leaves the top-level
+        // contract frame, inside the call rather than around it.
+        let checkpoints = CheckpointRecorder::default();
+        let hook_checkpoints = checkpoints.clone();
+        loaded
+            .env
+            .host()
+            .set_top_contract_invocation_hook(Some(Rc::new(move |host, event| {
+                let location = match event {
+                    ContractInvocationEvent::Start => "Contract entry",
+                    ContractInvocationEvent::Finish => "Contract exit",
+                };
+                hook_checkpoints.record(host, location);
+            })))
+            .map_err(|e| {
+                DebuggerError::ExecutionError(format!("Failed to install invocation hook: {:?}", e))
+            })?;
+
+        let event_decoder = match EventDecoder::from_wasm(&wasm) {
+            Ok(decoder) => decoder.map(|decoder| match ScAddress::from(&loaded.contract_address) {
+                ScAddress::Contract(hash) => decoder.for_contract(hash),
+                ScAddress::Account(_) => decoder,
+            }),
+            Err(e) => {
+                warn!("Failed to read contract spec for event decoding: {}", e);
+                None
+            }
+        };
+
         Ok(Self {
             env: loaded.env,
             contract_address: loaded.contract_address,
@@ -66,6 +109,10 @@ impl ContractExecutor {
             error_db: loaded.error_db,
             debug_env: DebugEnv::new(),
             per_function_cpu: HashMap::new(),
+            network_passphrase: DEFAULT_NETWORK_PASSPHRASE.to_string(),
+            checkpoints,
+            event_decoder,
+            last_mock_latency_ms: 0,
         })
     }
 
@@ -81,6 +128,20 @@ impl ContractExecutor {
         self.timeout_secs = secs;
     }
 
+    /// Set the maximum cross-contract call depth (see [`DebugEnv::set_max_call_depth`]).
+    pub fn set_max_call_depth(&mut self, limit: usize) -> Result<()> {
+        self.debug_env.set_max_call_depth(limit)
+    }
+
+    /// Passphrase of the network whose ledger this executor simulates.
+    pub fn network_passphrase(&self) -> &str {
+        &self.network_passphrase
+    }
+
+    pub fn set_network_passphrase(&mut self, passphrase: impl Into<String>) {
+        self.network_passphrase = passphrase.into();
+    }
+
     pub fn timeout_secs(&self) -> u64 {
         self.timeout_secs
     }
@@ -135,8 +196,32 @@ impl ContractExecutor {
         let storage_fn = || self.get_storage_snapshot();
         let storage_before = storage_fn()?;
 
+        let mock_calls_before = self
+            .mock_registry
+            .lock()
+            .map(|r| r.calls().len())
+            .unwrap_or(0);
+        let events_before = self.get_diagnostic_events()?.len();
+        let denied_auth = self
+            .mock_registry
+            .lock()
+            .map(|r| r.denied_auth().to_vec())
+            .unwrap_or_default();
+        let auth_rollback = if denied_auth.is_empty() {
+            None
+        } else {
+            Some(self.snapshot_storage()?)
+        };
+        let replay_args = auth_rollback.as_ref().map(|_| parsed_args.clone());
+        let depth_rollback = if self.debug_env.max_call_depth()
+            < soroban_env_host::DEFAULT_HOST_DEPTH_LIMIT as usize
+        {
+            Some(self.snapshot_storage()?)
+        } else {
+            None
+        };
         let timeout_guard = ExecutionTimeoutWatchdog::start(self.timeout_secs);
-        let (display, record) = crate::runtime::invoker::invoke_function(
+        let invoke_result = crate::runtime::invoker::invoke_function(
             &self.env,
             &self.contract_address,
             &self.error_db,
@@ -147,9 +232,47 @@ impl ContractExecutor {
             },
             self.timeout_secs,
             storage_fn,
-        )?;
+        );
         drop(timeout_guard);
 
+        // The host's depth limit can't be lowered and it has no hook on frame
+        // pushes, so the nesting is replayed from the call's fn_call/fn_return
+        // events, which it emits in execution order. The first call past the
+        // limit fails the run as the host's own abort would, with the call's
+        // storage writes rolled back. Checked before propagating the invocation
+        // error so a host depth abort is reported with the frame that caused it.
+        if let Err(overflow) = self.check_call_depth(events_before) {
+            if let Some(snapshot) = &depth_rollback {
+                self.restore_storage(snapshot)?;
+            }
+            return Err(overflow);
+        }
+        let (mut display, mut record) = invoke_result?;
+
+        // Auths are mocked while a deny list is set, so a denied address's
+        // `require_auth` succeeded and was recorded. Replay the call with auth
+        // enforced for it, so the host itself rejects the call.
+        if let (Some(snapshot), Some(args)) = (auth_rollback, replay_args) {
+            if let Some(address) = self.first_denied_auth(&denied_auth) {
+                if let Ok(mut registry) = self.mock_registry.lock() {
+                    // The replay makes the mocked calls again; log and charge
+                    // them once.
+                    registry.truncate_calls(mock_calls_before);
+                    registry.record_denied_auth(&address, &contract_addr_str, function);
+                }
+                (display, record) =
+                    self.replay_with_denied_auth(function, args, &denied_auth, &snapshot)?;
+            }
+        }
+
+        // Mocked calls charged their CPU and memory to the host budget as they
+        // ran; their latency is simulated by adding it to the reported time.
+        self.last_mock_latency_ms = self
+            .mock_registry
+            .lock()
+            .map(|r| r.charged_since(mock_calls_before).latency_ms)
+            .unwrap_or_default();
+
         // Track storage changes as accesses
         let storage_after = &record.storage_after;
         self.track_storage_changes(&storage_before, storage_after);
@@ -213,187 +336,59 @@ impl ContractExecutor {
         &self.debug_env
     }
 
+    /// Budget checkpoints taken at contract entry/exit and at mocked
+    /// cross-contract calls while a recording is active.
+    pub fn checkpoints(&self) -> &CheckpointRecorder {
+        &self.checkpoints
+    }
+
     pub fn debug_env_mut(&mut self) -> &mut DebugEnv {
         &mut self.debug_env
     }
 
+    /// Seed the contract's storage before execution. Accepts either a JSON
+    /// object mapping symbol keys to values, written to instance storage, or a
+    /// list of `{"key": ..., "value": ..., "durability": ...}` entries where
+    /// `durability` is `instance` (default), `persistent` or `temporary`.
     pub fn set_initial_storage(&mut self, storage_json: String) -> Result<()> {
-        #[derive(Debug, Clone, Copy)]
-        enum Durability {
-            Instance,
-            Persistent,
-            Temporary,
-        }
-
-        fn is_typed_annotation(value: &serde_json::Value) -> bool {
-            matches!(
-                value,
-                serde_json::Value::Object(obj) if obj.get("type").is_some() && obj.get("value").is_some()
-            )
-        }
-
-        fn normalize_numbers(value: &serde_json::Value) -> Result<serde_json::Value> {
-            use serde_json::Value;
-
-            if is_typed_annotation(value) {
-                return Ok(value.clone());
-            }
-
-            match value {
-                Value::Null | Value::Bool(_) | Value::String(_) => Ok(value.clone()),
-                Value::Number(n) => {
-                    if let Some(i) = n.as_i64() {
-                        Ok(serde_json::json!({ "type": "i64", "value": i }))
-                    } else if let Some(u) = n.as_u64() {
-                        if u <= i64::MAX as u64 {
-                            Ok(serde_json::json!({ "type": "i64", "value": u as i64 }))
-                        } else {
-                            Ok(serde_json::json!({ "type": "u64", "value": u }))
-                        }
-                    } else {
-                        Err(DebuggerError::StorageError(
-                            "Floating-point numbers are not supported in --storage".to_string(),
-                        )
-                        .into())
-                    }
-                }
-                Value::Array(arr) => {
-                    let mut out = Vec::with_capacity(arr.len());
-                    for item in arr {
-                        out.push(normalize_numbers(item)?);
-                    }
-                    Ok(Value::Array(out))
-                }
-                Value::Object(map) => {
-                    let mut out = serde_json::Map::new();
-                    for (k, v) in map {
-                        out.insert(k.clone(), normalize_numbers(v)?);
-                    }
-                    Ok(Value::Object(out))
-                }
-            }
-        }
-
-        fn parse_one_val(env: &Env, value: &serde_json::Value) -> Result<soroban_sdk::Val> {
-            let parser = ArgumentParser::new(env.clone());
-            let json = serde_json::to_string(value).map_err(|e| {
-                DebuggerError::StorageError(format!("Failed to serialize storage JSON value: {e}"))
-            })?;
-            let mut vals = parser.parse_args_string(&json).map_err(|e| {
-                DebuggerError::StorageError(format!("Failed to parse storage value: {e}"))
-            })?;
-            if vals.len() != 1 {
-                return Err(DebuggerError::StorageError(format!(
-                    "Storage entry must resolve to exactly 1 value, got {}",
-                    vals.len()
-                ))
-                .into());
-            }
-            Ok(vals.remove(0))
-        }
+        let contract_address = self.contract_address.clone();
+        self.seed_storage(&contract_address, storage_json)
+    }
 
-        fn parse_durability(raw: Option<&serde_json::Value>) -> Result<Durability> {
-            let Some(v) = raw else {
-                return Ok(Durability::Instance);
-            };
-            let Some(s) = v.as_str() else {
-                return Err(DebuggerError::StorageError(
-                    "durability must be a string: instance|persistent|temporary".to_string(),
-                )
-                .into());
-            };
-            match s {
-                "instance" => Ok(Durability::Instance),
-                "persistent" => Ok(Durability::Persistent),
-                "temporary" => Ok(Durability::Temporary),
-                other => Err(DebuggerError::StorageError(format!(
-                    "Unsupported durability '{other}'. Use instance|persistent|temporary."
-                ))
-                .into()),
-            }
-        }
+    /// Seed storage for another contract in the simulated ledger, e.g. the callee
+    /// of a cross-contract call. Accepts the same JSON formats as
+    /// [`Self::set_initial_storage`]. Instance entries require the contract to be
+    /// registered (for example via `--follow-calls`).
+    pub fn set_contract_storage(&mut self, contract_id: &str, storage_json: String) -> Result<()> {
+        let address = self.parse_contract_address(contract_id)?;
+        self.seed_storage(&address, storage_json)?;
+        info!("Seeded storage for contract {}", contract_id);
+        Ok(())
+    }
 
+    fn seed_storage(&mut self, address: &Address, storage_json: String) -> Result<()> {
         info!("Setting initial storage");
-        let root: serde_json::Value = serde_json::from_str(&storage_json).map_err(|e| {
-            DebuggerError::StorageError(format!("Failed to parse initial storage JSON: {e}"))
-        })?;
-
-        let mut entries: Vec<(Durability, soroban_sdk::Val, soroban_sdk::Val)> = Vec::new();
-
-        match root {
-            serde_json::Value::Object(map) => {
-                if let Some(entries_field) = map.get("entries") {
-                    if entries_field.is_object() {
-                        return Err(DebuggerError::StorageError(
-                            "Unsupported --storage format: looks like an exported snapshot. Use a plain object mapping keys to values, e.g. {\"c\": 41}, or use the list form [{\"key\":...,\"value\":...}].".to_string(),
-                        )
-                        .into());
-                    }
-                }
-
-                for (k, v) in map {
-                    let key_json = serde_json::json!({ "type": "symbol", "value": k });
-                    let key_val = parse_one_val(&self.env, &key_json)?;
-                    let value_json = normalize_numbers(&v)?;
-                    let value_val = parse_one_val(&self.env, &value_json)?;
-                    entries.push((Durability::Instance, key_val, value_val));
-                }
-            }
-            serde_json::Value::Array(arr) => {
-                for item in arr {
-                    let serde_json::Value::Object(obj) = item else {
-                        return Err(DebuggerError::StorageError(
-                            "Storage list entries must be objects with {key,value[,durability]}"
-                                .to_string(),
-                        )
-                        .into());
-                    };
-                    let durability = parse_durability(obj.get("durability"))?;
-                    let Some(key) = obj.get("key") else {
-                        return Err(DebuggerError::StorageError(
-                            "Storage entry is missing required field 'key'".to_string(),
-                        )
-                        .into());
-                    };
-                    let Some(value) = obj.get("value") else {
-                        return Err(DebuggerError::StorageError(
-                            "Storage entry is missing required field 'value'".to_string(),
-                        )
-                        .into());
-                    };
-
-                    let key_val = parse_one_val(&self.env, key)?;
-                    let value_json = normalize_numbers(value)?;
-                    let value_val = parse_one_val(&self.env, &value_json)?;
-                    entries.push((durability, key_val, value_val));
-                }
-            }
-            other => {
-                return Err(DebuggerError::StorageError(format!(
-                    "Unsupported --storage JSON: expected object or array, got {other}"
-                ))
-                .into())
-            }
-        }
+        let entries = parse_storage_seed(&self.env, &storage_json)?;
+        self.write_storage(address, entries);
+        Ok(())
+    }
 
-        let contract_address = self.contract_address.clone();
-        self.env.as_contract(&contract_address, || {
+    fn write_storage(&self, address: &Address, entries: Vec<(StorageDurability, Val, Val)>) {
+        self.env.as_contract(address, || {
             for (durability, key_val, value_val) in entries {
                 match durability {
-                    Durability::Instance => {
+                    StorageDurability::Instance => {
                         self.env.storage().instance().set(&key_val, &value_val);
                     }
-                    Durability::Persistent => {
+                    StorageDurability::Persistent => {
                         self.env.storage().persistent().set(&key_val, &value_val);
                     }
-                    Durability::Temporary => {
+                    StorageDurability::Temporary => {
                         self.env.storage().temporary().set(&key_val, &value_val);
                     }
                 }
             }
         });
-
-        Ok(())
     }
     /// Apply ledger metadata (sequence, timestamp, network ID) from a network snapshot.
     pub fn apply_snapshot_ledger(
@@ -410,11 +405,41 @@ impl ContractExecutor {
         hasher.update(passphrase.as_bytes());
         let network_id: [u8; 32] = hasher.finalize().into();
 
+        // Entries created so far, such as this contract's instance and code,
+        // live relative to the current sequence. Move them forward with it so
+        // jumping to a network sequence does not archive them.
+        let shift = seq.saturating_sub(self.env.ledger().sequence());
+        if shift > 0 {
+            let host = self.env.host();
+            let budget = host.budget_cloned();
+            host.with_mut_storage(|storage| {
+                let entries = storage
+                    .map
+                    .iter(&budget)?
+                    .map(|(key, entry)| {
+                        let entry = entry.as_ref().map(|(entry, live_until)| {
+                            (entry.clone(), live_until.map(|l| l.saturating_add(shift)))
+                        });
+                        (key.clone(), entry)
+                    })
+                    .collect();
+                storage.map = soroban_env_host::storage::StorageMap::from_map(entries, &budget)?;
+                Ok(())
+            })
+            .map_err(|e| {
+                DebuggerError::ExecutionError(format!(
+                    "Failed to carry entry TTLs forward: {:?}",
+                    e
+                ))
+            })?;
+        }
+
         self.env.ledger().with_mut(|l| {
             l.sequence_number = seq;
             l.timestamp = ts;
             l.network_id = network_id;
         });
+        self.network_passphrase = passphrase.to_string();
 
         info!(
             "Applied snapshot ledger state: sequence={}, timestamp={}",
@@ -424,14 +449,131 @@ impl ContractExecutor {
         Ok(())
     }
 
+    /// Write the storage a network snapshot recorded for this contract: the
+    /// snapshot contract whose id is this contract's address or, failing that,
+    /// the snapshot's only contract. Entries recorded without `key_xdr` cannot
+    /// be restored and are skipped. Returns the number of entries written.
+    pub fn apply_snapshot_storage(
+        &mut self,
+        snapshot: &crate::simulator::LoadedSnapshot,
+    ) -> Result<usize> {
+        use soroban_env_host::xdr::{Limits, ReadXdr, ScVal};
+        use soroban_sdk::TryFromVal;
+
+        let contracts = &snapshot.snapshot().contracts;
+        let address = ScAddress::from(&self.contract_address).to_string();
+        let contract = contracts
+            .iter()
+            .find(|contract| contract.contract_id == address)
+            .or(match contracts.as_slice() {
+                [only] => Some(only),
+                _ => None,
+            });
+        let Some(contract) = contract else {
+            return Ok(0);
+        };
+
+        let decode = |name: &str, xdr: &str| -> Result<Val> {
+            let value = ScVal::from_xdr_base64(xdr, Limits::none()).map_err(|e| {
+                DebuggerError::StorageError(format!(
+                    "Invalid XDR in snapshot storage entry {name}: {e}"
+                ))
+            })?;
+            Val::try_from_val(&self.env, &value).map_err(|e| {
+                DebuggerError::StorageError(format!(
+                    "Failed to convert snapshot storage entry {name}: {e:?}"
+                ))
+                .into()
+            })
+        };
+        let mut entries = Vec::new();
+        for (name, recorded) in &contract.storage {
+            let field = |field: &str| recorded.get(field).and_then(|v| v.as_str());
+            let (Some(key_xdr), Some(value_xdr)) = (field("key_xdr"), field("xdr")) else {
+                warn!("Skipping snapshot storage entry {} without key XDR", name);
+                continue;
+            };
+            let durability = match field("durability") {
+                Some("persistent") => StorageDurability::Persistent,
+                Some("temporary") => StorageDurability::Temporary,
+                _ => StorageDurability::Instance,
+            };
+            entries.push((durability, decode(name, key_xdr)?, decode(name, value_xdr)?));
+        }
+
+        let count = entries.len();
+        let address = self.contract_address.clone();
+        self.write_storage(&address, entries);
+        info!(
+            "Applied {} snapshot storage entries from contract {}",
+            count, contract.contract_id
+        );
+        Ok(count)
+    }
+
     pub fn set_mock_specs(&mut self, specs: &[String]) -> Result<()> {
-        let registry = MockRegistry::from_cli_specs(&self.env, specs)?;
+        let mut registry = MockRegistry::from_cli_specs(&self.env, specs)?;
+        if let Ok(current) = self.mock_registry.lock() {
+            registry.deny_auth(current.denied_auth())?;
+        }
         self.set_mock_registry(registry)
     }
+
+    /// Make `require_auth` fail for `addresses`. Auths are mocked for every
+    /// other address, so the call fails only because of the deny list.
+    pub fn deny_auth(&mut self, addresses: &[String]) -> Result<()> {
+        self.mock_registry
+            .lock()
+            .map_err(|_| miette::miette!("Mock registry lock poisoned"))?
+            .deny_auth(addresses)?;
+        self.env.mock_all_auths();
+        Ok(())
+    }
     pub fn set_mock_registry(&mut self, registry: MockRegistry) -> Result<()> {
         self.mock_registry = Arc::new(Mutex::new(registry));
         self.install_mock_dispatchers()
     }
+    /// Register `wasm` at `contract_id` so cross-contract calls to that id execute
+    /// the real contract instead of resolving through a mock.
+    pub fn register_contract_at(&mut self, contract_id: &str, wasm: &[u8]) -> Result<()> {
+        let address = self.parse_contract_address(contract_id)?;
+        catch_unwind(AssertUnwindSafe(|| {
+            self.env.register_at(&address, wasm, ());
+        }))
+        .map_err(|_| {
+            DebuggerError::WasmLoadError(format!(
+                "Failed to register followed contract {contract_id}"
+            ))
+        })?;
+        info!("Registered followed contract {}", contract_id);
+        Ok(())
+    }
+
+    /// Register every WASM resolved from `dir` (see
+    /// [`crate::runtime::loader::discover_contract_wasms`]) at its contract id.
+    /// Returns the ids that were registered.
+    pub fn follow_calls_from_dir(&mut self, dir: &std::path::Path) -> Result<Vec<String>> {
+        let mapping = crate::runtime::loader::discover_contract_wasms(dir)?;
+        let mut registered = Vec::with_capacity(mapping.len());
+        for (contract_id, path) in mapping {
+            let wasm = std::fs::read(&path).map_err(|e| {
+                DebuggerError::WasmLoadError(format!(
+                    "Failed to read WASM for {contract_id} at {:?}: {e}",
+                    path
+                ))
+            })?;
+            self.register_contract_at(&contract_id, &wasm)?;
+            registered.push(contract_id);
+        }
+        Ok(registered)
+    }
+
+    /// Total `latency_ms` declared by the mocked calls of the last execution.
+    /// It is not slept; add it to measured wall time to simulate the callees.
+    pub fn last_mock_latency_ms(&self) -> u64 {
+        self.last_mock_latency_ms
+    }
+
     pub fn get_mock_call_log(&self) -> Vec<MockCallLogEntry> {
         self.mock_registry
             .lock()
@@ -457,8 +599,17 @@ impl ContractExecutor {
     pub fn get_auth_tree(&self) -> Result<Vec<crate::inspector::auth::AuthNode>> {
         crate::inspector::auth::AuthInspector::get_auth_tree(&self.env)
     }
+    /// Parameter names of `function` from the contract spec, if it has one.
+    pub fn function_param_names(&self, function: &str) -> Option<Vec<String>> {
+        crate::utils::wasm::function_param_names(&self.wasm_bytes, function)
+    }
+    /// Events emitted so far. This contract's events are decoded against its
+    /// spec when the WASM has one.
     pub fn get_events(&self) -> Result<Vec<crate::inspector::events::ContractEvent>> {
-        crate::inspector::events::EventInspector::get_events(self.env.host())
+        crate::inspector::events::EventInspector::get_events_with_decoder(
+            self.env.host(),
+            self.event_decoder.as_ref(),
+        )
     }
     pub fn get_storage_snapshot(&self) -> Result<HashMap<String, String>> {
         Ok(crate::inspector::storage::StorageInspector::capture_snapshot(self.env.host()))
@@ -523,6 +674,33 @@ impl ContractExecutor {
             .collect())
     }
 
+    /// Walk the host's `fn_call`/`fn_return` events emitted after the first
+    /// `skip` events and fail on the first call nested deeper than
+    /// `--max-call-depth`, counting the entry call as depth 1.
+    fn check_call_depth(&self, skip: usize) -> Result<()> {
+        use soroban_env_host::xdr::{ContractEventBody, ScVal};
+
+        let mut depth = 0usize;
+        for event in self.get_diagnostic_events()?.into_iter().skip(skip) {
+            let ContractEventBody::V0(body) = &event.body;
+            match body.topics.first() {
+                Some(ScVal::Symbol(sym)) if sym.0.as_slice() == b"fn_call" => {
+                    depth += 1;
+                    let function = match body.topics.get(2) {
+                        Some(ScVal::Symbol(name)) => name.to_utf8_string_lossy(),
+                        _ => "<unknown>".to_string(),
+                    };
+                    self.debug_env.check_call_depth(depth, &function)?;
+                }
+                Some(ScVal::Symbol(sym)) if sym.0.as_slice() == b"fn_return" => {
+                    depth = depth.saturating_sub(1);
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     #[allow(dead_code)]
     fn parse_args(&self, function: &str, args_json: &str) -> Result<Vec<Val>> {
         let normalized_args_json = self
@@ -605,12 +783,13 @@ impl ContractExecutor {
             .mock_registry
             .lock()
             .map(|r| r.mocked_contract_ids())
-            .map_err(|_| DebuggerError::ExecutionError("Mock registry lock poisoned".into()))?;
+            .map_err(|_| miette::miette!("Mock registry lock poisoned"))?;
 
         for contract_id in ids {
             let address = self.parse_contract_address(&contract_id)?;
             let dispatcher =
                 MockContractDispatcher::new(contract_id.clone(), Arc::clone(&self.mock_registry))
+                    .with_checkpoints(self.checkpoints.clone())
                     .boxed();
             self.env
                 .host()
@@ -625,13 +804,71 @@ impl ContractExecutor {
         Ok(())
     }
 
+    /// Re-run `function` from `snapshot` with auth enforced: every
+    /// `require_auth` of the mocked run is authorized except those by
+    /// `denied` addresses, which the host rejects with an `Auth` error.
+    fn replay_with_denied_auth(
+        &mut self,
+        function: &str,
+        args: Vec<Val>,
+        denied: &[String],
+        snapshot: &StorageSnapshot,
+    ) -> Result<(String, ExecutionRecord)> {
+        self.restore_storage(snapshot)?;
+        let auth = crate::runtime::mocking::replay_auth_entries(&self.env, denied)?;
+        self.env.set_auths(&auth.entries);
+
+        let storage_fn = || self.get_storage_snapshot();
+        let timeout_guard = ExecutionTimeoutWatchdog::start(self.timeout_secs);
+        let replay = crate::runtime::invoker::invoke_function(
+            &self.env,
+            &self.contract_address,
+            &self.error_db,
+            crate::runtime::invoker::InvokeArgs {
+                function,
+                args,
+                reason: InvocationReason::Entrypoint,
+            },
+            self.timeout_secs,
+            storage_fn,
+        );
+        drop(timeout_guard);
+        self.env.mock_all_auths();
+
+        match replay {
+            Ok((display, mut record)) => {
+                auth.undo(self.env.host())?;
+                record.storage_after = self.get_storage_snapshot()?;
+                Ok((display, record))
+            }
+            Err(e) => {
+                // The host already rolled back the call; this also drops the
+                // signers added to authorize the other addresses.
+                self.restore_storage(snapshot)?;
+                Err(e)
+            }
+        }
+    }
+
+    /// First address in `denied` that the last invocation required auth from.
+    fn first_denied_auth(&self, denied: &[String]) -> Option<String> {
+        let recorded = self.env.auths();
+        denied
+            .iter()
+            .find(|strkey| {
+                self.parse_contract_address(strkey)
+                    .map(|address| recorded.iter().any(|(auth, _)| *auth == address))
+                    .unwrap_or(false)
+            })
+            .cloned()
+    }
+
     fn parse_contract_address(&self, contract_id: &str) -> Result<Address> {
         catch_unwind(AssertUnwindSafe(|| {
             Address::from_str(&self.env, contract_id)
         }))
         .map_err(|_| {
-            DebuggerError::InvalidArguments(format!("Invalid contract id in --mock: {contract_id}"))
-                .into()
+            DebuggerError::InvalidArguments(format!("Invalid contract id: {contract_id}")).into()
         })
     }
 }
@@ -658,6 +895,166 @@ impl CancellationToken {
     }
 }
 
+/// Parse `--storage` JSON, in either format accepted by
+/// [`ContractExecutor::set_initial_storage`], into `(durability, key, value)`
+/// entries for `env`.
+pub fn parse_storage_seed(
+    env: &Env,
+    storage_json: &str,
+) -> Result<Vec<(StorageDurability, Val, Val)>> {
+    fn is_typed_annotation(value: &serde_json::Value) -> bool {
+        matches!(
+            value,
+            serde_json::Value::Object(obj) if obj.get("type").is_some() && obj.get("value").is_some()
+        )
+    }
+
+    fn normalize_numbers(value: &serde_json::Value) -> Result<serde_json::Value> {
+        use serde_json::Value;
+
+        if is_typed_annotation(value) {
+            return Ok(value.clone());
+        }
+
+        match value {
+            Value::Null | Value::Bool(_) | Value::String(_) => Ok(value.clone()),
+            Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    Ok(serde_json::json!({ "type": "i64", "value": i }))
+                } else if let Some(u) = n.as_u64() {
+                    if u <= i64::MAX as u64 {
+                        Ok(serde_json::json!({ "type": "i64", "value": u as i64 }))
+                    } else {
+                        Ok(serde_json::json!({ "type": "u64", "value": u }))
+                    }
+                } else {
+                    Err(DebuggerError::StorageError(
+                        "Floating-point numbers are not supported in --storage".to_string(),
+                    )
+                    .into())
+                }
+            }
+            Value::Array(arr) => {
+                let mut out = Vec::with_capacity(arr.len());
+                for item in arr {
+                    out.push(normalize_numbers(item)?);
+                }
+                Ok(Value::Array(out))
+            }
+            Value::Object(map) => {
+                let mut out = serde_json::Map::new();
+                for (k, v) in map {
+                    out.insert(k.clone(), normalize_numbers(v)?);
+                }
+                Ok(Value::Object(out))
+            }
+        }
+    }
+
+    fn parse_one_val(env: &Env, value: &serde_json::Value) -> Result<Val> {
+        let parser = ArgumentParser::new(env.clone());
+        let json = serde_json::to_string(value).map_err(|e| {
+            DebuggerError::StorageError(format!("Failed to serialize storage JSON value: {e}"))
+        })?;
+        let mut vals = parser.parse_args_string(&json).map_err(|e| {
+            DebuggerError::StorageError(format!("Failed to parse storage value: {e}"))
+        })?;
+        if vals.len() != 1 {
+            return Err(DebuggerError::StorageError(format!(
+                "Storage entry must resolve to exactly 1 value, got {}",
+                vals.len()
+            ))
+            .into());
+        }
+        Ok(vals.remove(0))
+    }
+
+    fn parse_durability(raw: Option<&serde_json::Value>) -> Result<StorageDurability> {
+        let Some(v) = raw else {
+            return Ok(StorageDurability::Instance);
+        };
+        let Some(s) = v.as_str() else {
+            return Err(DebuggerError::StorageError(
+                "durability must be a string: instance|persistent|temporary".to_string(),
+            )
+            .into());
+        };
+        match s {
+            "instance" => Ok(StorageDurability::Instance),
+            "persistent" => Ok(StorageDurability::Persistent),
+            "temporary" => Ok(StorageDurability::Temporary),
+            other => Err(DebuggerError::StorageError(format!(
+                "Unsupported durability '{other}'. Use instance|persistent|temporary."
+            ))
+            .into()),
+        }
+    }
+
+    let root: serde_json::Value = serde_json::from_str(storage_json).map_err(|e| {
+        DebuggerError::StorageError(format!("Failed to parse initial storage JSON: {e}"))
+    })?;
+
+    let mut entries: Vec<(StorageDurability, Val, Val)> = Vec::new();
+
+    match root {
+        serde_json::Value::Object(map) => {
+            if let Some(entries_field) = map.get("entries") {
+                if entries_field.is_object() {
+                    return Err(DebuggerError::StorageError(
+                        "Unsupported --storage format: looks like an exported snapshot. Use a plain object mapping keys to values, e.g. {\"c\": 41}, or use the list form [{\"key\":...,\"value\":...}].".to_string(),
+                    )
+                    .into());
+                }
+            }
+
+            for (k, v) in map {
+                let key_json = serde_json::json!({ "type": "symbol", "value": k });
+                let key_val = parse_one_val(env, &key_json)?;
+                let value_json = normalize_numbers(&v)?;
+                let value_val = parse_one_val(env, &value_json)?;
+                entries.push((StorageDurability::Instance, key_val, value_val));
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for item in arr {
+                let serde_json::Value::Object(obj) = item else {
+                    return Err(DebuggerError::StorageError(
+                        "Storage list entries must be objects with {key,value[,durability]}"
+                            .to_string(),
+                    )
+                    .into());
+                };
+                let durability = parse_durability(obj.get("durability"))?;
+                let Some(key) = obj.get("key") else {
+                    return Err(DebuggerError::StorageError(
+                        "Storage entry is missing required field 'key'".to_string(),
+                    )
+                    .into());
+                };
+                let Some(value) = obj.get("value") else {
+                    return Err(DebuggerError::StorageError(
+                        "Storage entry is missing required field 'value'".to_string(),
+                    )
+                    .into());
+                };
+
+                let key_val = parse_one_val(env, key)?;
+                let value_json = normalize_numbers(value)?;
+                let value_val = parse_one_val(env, &value_json)?;
+                entries.push((durability, key_val, value_val));
+            }
+        }
+        other => {
+            return Err(DebuggerError::StorageError(format!(
+                "Unsupported --storage JSON: expected object or array, got {other}"
+            ))
+            .into())
+        }
+    }
+
+    Ok(entries)
+}
+
 fn tuple_arity_from_type_name(type_name: &str) -> Option<usize> {
     if !type_name.starts_with("Tuple<") || !type_name.ends_with('>') {
         return None;
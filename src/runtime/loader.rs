@@ -15,6 +15,9 @@ use crate::{DebuggerError, Result};
 use indicatif::{ProgressBar, ProgressStyle};
 use soroban_env_host::DiagnosticLevel;
 use soroban_sdk::{Address, Env};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use tracing::{info, warn};
 
 /// Output of a successful [`load_contract`] call.
@@ -24,6 +27,82 @@ pub struct LoadedContract {
     pub error_db: ErrorDatabase,
 }
 
+/// File name of the optional id→WASM manifest inside a `--follow-calls` directory.
+pub const FOLLOW_CALLS_MANIFEST: &str = "contracts.json";
+
+/// Resolve contract ids to local WASM files inside `dir`.
+///
+/// Every `<CONTRACT_ID>.wasm` file in the directory whose stem is a `C...`
+/// contract strkey is mapped to that id. A `contracts.json` object
+/// (`{"CONTRACT_ID": "relative/path.wasm"}`) may be added to map ids to files
+/// with arbitrary names; its entries take precedence. Other `.wasm` files are
+/// skipped with a warning unless the manifest names them.
+pub fn discover_contract_wasms(dir: &Path) -> Result<BTreeMap<String, PathBuf>> {
+    let read_dir = std::fs::read_dir(dir).map_err(|e| {
+        DebuggerError::FileError(format!(
+            "Failed to read --follow-calls directory {:?}: {}",
+            dir, e
+        ))
+    })?;
+    let manifest = read_follow_calls_manifest(dir)?;
+
+    let mut mapping = BTreeMap::new();
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("wasm") {
+            continue;
+        }
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if is_contract_strkey(stem) {
+            mapping.insert(stem.to_string(), path.clone());
+        } else if !manifest.values().any(|mapped| *mapped == path) {
+            warn!(
+                "Skipping {:?}: file name is not a contract id; map it in {}",
+                path, FOLLOW_CALLS_MANIFEST
+            );
+        }
+    }
+
+    mapping.extend(manifest);
+    Ok(mapping)
+}
+
+fn is_contract_strkey(s: &str) -> bool {
+    matches!(
+        soroban_env_host::xdr::ScAddress::from_str(s),
+        Ok(soroban_env_host::xdr::ScAddress::Contract(_))
+    )
+}
+
+/// The id -> path entries of `dir`'s `contracts.json`, with paths resolved
+/// against `dir`. Empty when there is no manifest.
+fn read_follow_calls_manifest(dir: &Path) -> Result<BTreeMap<String, PathBuf>> {
+    let mut mapping = BTreeMap::new();
+    let manifest_path = dir.join(FOLLOW_CALLS_MANIFEST);
+    if manifest_path.exists() {
+        let raw = std::fs::read_to_string(&manifest_path).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to read {:?}: {}", manifest_path, e))
+        })?;
+        let manifest: BTreeMap<String, PathBuf> = serde_json::from_str(&raw).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Invalid {} (expected an object of CONTRACT_ID -> path): {}",
+                FOLLOW_CALLS_MANIFEST, e
+            ))
+        })?;
+        for (contract_id, path) in manifest {
+            let path = if path.is_absolute() {
+                path
+            } else {
+                dir.join(path)
+            };
+            mapping.insert(contract_id, path);
+        }
+    }
+    Ok(mapping)
+}
+
 pub fn inspect_contract_artifact(wasm: &[u8]) -> Result<WasmArtifactMetadata> {
     extract_wasm_artifact_metadata(wasm)
 }
@@ -99,3 +178,60 @@ pub fn load_contract(wasm: &[u8]) -> Result<LoadedContract> {
         error_db,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN_ID: &str = "CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526";
+    const ORACLE_ID: &str = "CABAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAFNSZ";
+
+    #[test]
+    fn discovers_wasms_by_file_stem_and_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(format!("{TOKEN_ID}.wasm")), b"\0asm").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), b"ignored").unwrap();
+        std::fs::create_dir(dir.path().join("build")).unwrap();
+        std::fs::write(dir.path().join("build").join("oracle.wasm"), b"\0asm").unwrap();
+        std::fs::write(
+            dir.path().join(FOLLOW_CALLS_MANIFEST),
+            format!(r#"{{"{ORACLE_ID}": "build/oracle.wasm"}}"#),
+        )
+        .unwrap();
+
+        let mapping = discover_contract_wasms(dir.path()).unwrap();
+
+        assert_eq!(mapping.len(), 2);
+        assert_eq!(
+            mapping[TOKEN_ID],
+            dir.path().join(format!("{TOKEN_ID}.wasm"))
+        );
+        assert_eq!(
+            mapping[ORACLE_ID],
+            dir.path().join("build").join("oracle.wasm")
+        );
+    }
+
+    #[test]
+    fn skips_wasms_not_named_by_contract_id() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("token.wasm"), b"\0asm").unwrap();
+        std::fs::write(dir.path().join("helper.wasm"), b"\0asm").unwrap();
+        std::fs::write(
+            dir.path().join(FOLLOW_CALLS_MANIFEST),
+            format!(r#"{{"{TOKEN_ID}": "token.wasm"}}"#),
+        )
+        .unwrap();
+
+        let mapping = discover_contract_wasms(dir.path()).unwrap();
+
+        assert_eq!(mapping.len(), 1);
+        assert_eq!(mapping[TOKEN_ID], dir.path().join("token.wasm"));
+    }
+
+    #[test]
+    fn rejects_missing_follow_calls_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(discover_contract_wasms(&dir.path().join("missing")).is_err());
+    }
+}
@@ -1,9 +1,23 @@
+use crate::inspector::budget::{BudgetInspector, CheckpointRecorder};
 use crate::utils::ArgumentParser;
 use crate::{DebuggerError, Result};
-use soroban_env_host::{ContractFunctionSet, Host, Symbol as HostSymbol, Val as HostVal};
-use soroban_sdk::{Env, Val};
+use ed25519_dalek::{Signer as _, SigningKey};
+use sha2::{Digest, Sha256};
+use soroban_env_host::xdr::{
+    AccountEntry, AccountEntryExt, ContractCostType, ContractDataDurability, Hash, HashIdPreimage,
+    HashIdPreimageSorobanAuthorization, LedgerEntry, LedgerEntryData, LedgerEntryExt, LedgerKey,
+    LedgerKeyAccount, LedgerKeyContractData, Limits, ScAddress, ScMap, ScMapEntry, ScSymbol, ScVal,
+    ScVec, SequenceNumber, Signer, SignerKey, SorobanAddressCredentials, SorobanAuthorizationEntry,
+    SorobanCredentials, Thresholds, Uint256, WriteXdr,
+};
+use soroban_env_host::{
+    ContractFunctionSet, Host, HostError, Symbol as HostSymbol, Val as HostVal,
+};
+use soroban_sdk::testutils::MockAuthContract;
+use soroban_sdk::{Address, Env, TryFromVal, Val};
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use tracing::warn;
 
@@ -13,11 +27,88 @@ pub struct MockKey {
     pub function: String,
 }
 
+/// Simulated resource cost charged for a single mocked cross-contract call.
+///
+/// Declared on a mock spec as a `|`-separated suffix, e.g.
+/// `CONTRACT_ID.transfer=true|cpu=250000,mem=4096,latency_ms=15`.
+/// CPU and memory are charged to the host budget when the mock is called, so
+/// they count toward its limits. Latency is only reported, never slept.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MockCost {
+    pub cpu_instructions: u64,
+    pub memory_bytes: u64,
+    pub latency_ms: u64,
+}
+
+impl MockCost {
+    pub fn is_zero(&self) -> bool {
+        self.cpu_instructions == 0 && self.memory_bytes == 0 && self.latency_ms == 0
+    }
+
+    /// Charge the CPU and memory of this cost to `host`'s budget. Memory is
+    /// charged as one host allocation, which also costs some CPU; the rest of
+    /// the CPU is charged as WASM instructions, so the total is rounded down
+    /// to a multiple of one instruction's cost.
+    pub fn charge(&self, host: &Host) -> std::result::Result<(), HostError> {
+        let budget = host.budget_cloned();
+        let cpu_before = budget.get_cpu_insns_consumed()?;
+        if self.memory_bytes > 0 {
+            budget.charge(ContractCostType::MemAlloc, Some(self.memory_bytes))?;
+        }
+        let charged = budget.get_cpu_insns_consumed()?.saturating_sub(cpu_before);
+        let remaining = self.cpu_instructions.saturating_sub(charged);
+        if remaining > 0 {
+            let before = budget.get_cpu_insns_consumed()?;
+            budget.charge(ContractCostType::WasmInsnExec, None)?;
+            let unit = budget.get_cpu_insns_consumed()?.saturating_sub(before);
+            if unit > 0 && remaining > unit {
+                budget.bulk_charge(
+                    ContractCostType::WasmInsnExec,
+                    (remaining - unit) / unit,
+                    None,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn parse(raw: &str) -> Result<Self> {
+        let mut cost = Self::default();
+        for part in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, value) = part.split_once('=').ok_or_else(|| {
+                DebuggerError::InvalidArguments(format!(
+                    "Invalid mock cost '{part}'. Expected name=value (cpu, mem, latency_ms)"
+                ))
+            })?;
+            let value: u64 = value.trim().parse().map_err(|_| {
+                DebuggerError::InvalidArguments(format!(
+                    "Invalid mock cost value '{}' for '{}': expected a non-negative integer",
+                    value.trim(),
+                    name.trim()
+                ))
+            })?;
+            match name.trim() {
+                "cpu" => cost.cpu_instructions = value,
+                "mem" => cost.memory_bytes = value,
+                "latency_ms" => cost.latency_ms = value,
+                other => {
+                    return Err(DebuggerError::InvalidArguments(format!(
+                        "Unknown mock cost '{other}'. Supported: cpu, mem, latency_ms"
+                    ))
+                    .into())
+                }
+            }
+        }
+        Ok(cost)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct MockSpec {
     pub key: MockKey,
     pub return_raw: String,
     pub return_val: Val,
+    pub cost: MockCost,
 }
 
 #[derive(Clone, Debug)]
@@ -26,13 +117,26 @@ pub struct MockCallLogEntry {
     pub function: String,
     pub args_count: usize,
     pub mocked: bool,
+    /// Typed value handed back to the caller, formatted like invocation results.
     pub returned: Option<String>,
+    /// Simulated cost charged for this call (zero for unmocked calls).
+    pub charged: MockCost,
+    /// Address whose `require_auth` was rejected because it is on the deny
+    /// list; `None` for cross-contract calls.
+    pub auth_denied: Option<String>,
+    /// Host CPU instructions consumed when the call was made, before its
+    /// cost was charged.
+    pub cpu_at_call: u64,
+    /// Host memory bytes consumed when the call was made, before its cost
+    /// was charged.
+    pub memory_at_call: u64,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct MockRegistry {
     entries: HashMap<MockKey, MockSpec>,
     calls: Vec<MockCallLogEntry>,
+    denied_auth: Vec<String>,
 }
 
 impl MockRegistry {
@@ -46,9 +150,46 @@ impl MockRegistry {
         Ok(Self {
             entries,
             calls: Vec::new(),
+            denied_auth: Vec::new(),
         })
     }
 
+    /// Reject `require_auth` for `addresses` (G... or C... strkeys).
+    pub fn deny_auth(&mut self, addresses: &[String]) -> Result<()> {
+        for address in addresses {
+            let address = address.trim();
+            if ScAddress::from_str(address).is_err() {
+                return Err(DebuggerError::InvalidArguments(format!(
+                    "Invalid --deny-auth address '{address}'. Expected a G... or C... strkey"
+                ))
+                .into());
+            }
+            if !self.denied_auth.iter().any(|denied| denied == address) {
+                self.denied_auth.push(address.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn denied_auth(&self) -> &[String] {
+        &self.denied_auth
+    }
+
+    /// Log a `require_auth` by a denied address during `function`.
+    pub fn record_denied_auth(&mut self, address: &str, contract_id: &str, function: &str) {
+        self.calls.push(MockCallLogEntry {
+            contract_id: contract_id.to_string(),
+            function: function.to_string(),
+            args_count: 0,
+            mocked: true,
+            returned: None,
+            charged: MockCost::default(),
+            auth_denied: Some(address.to_string()),
+            cpu_at_call: 0,
+            memory_at_call: 0,
+        });
+    }
+
     pub fn mocked_contract_ids(&self) -> HashSet<String> {
         self.entries.keys().map(|k| k.contract_id.clone()).collect()
     }
@@ -69,7 +210,11 @@ impl MockRegistry {
                 function: function.to_string(),
                 args_count,
                 mocked: true,
-                returned: Some(spec.return_raw.clone()),
+                returned: Some(format!("{:?}", spec.return_val)),
+                charged: spec.cost,
+                auth_denied: None,
+                cpu_at_call: 0,
+                memory_at_call: 0,
             });
             return Some(spec.return_val);
         }
@@ -79,6 +224,10 @@ impl MockRegistry {
             args_count,
             mocked: false,
             returned: None,
+            charged: MockCost::default(),
+            auth_denied: None,
+            cpu_at_call: 0,
+            memory_at_call: 0,
         });
         None
     }
@@ -87,12 +236,49 @@ impl MockRegistry {
         &self.calls
     }
 
+    /// Drop the calls logged after the first `len`, e.g. before a call is
+    /// replayed and makes them again.
+    pub fn truncate_calls(&mut self, len: usize) {
+        self.calls.truncate(len);
+    }
+
+    /// Sum of the simulated cost charged by calls logged from index `start` onwards.
+    pub fn charged_since(&self, start: usize) -> MockCost {
+        self.calls
+            .iter()
+            .skip(start)
+            .fold(MockCost::default(), |acc, call| MockCost {
+                cpu_instructions: acc
+                    .cpu_instructions
+                    .saturating_add(call.charged.cpu_instructions),
+                memory_bytes: acc.memory_bytes.saturating_add(call.charged.memory_bytes),
+                latency_ms: acc.latency_ms.saturating_add(call.charged.latency_ms),
+            })
+    }
+
+    /// Parse `CONTRACT_ID.function=return_value` or
+    /// `CONTRACT_ID.function -> return_value`, where the return value is any
+    /// single value [`ArgumentParser`] accepts, including typed JSON such as
+    /// `{"type":"i128","value":500}`.
     fn parse_spec(parser: &ArgumentParser, spec: &str) -> Result<MockSpec> {
-        let (signature, return_raw) = spec.split_once('=').ok_or_else(|| {
-            DebuggerError::InvalidArguments(format!(
-                "Invalid mock '{spec}'. Expected CONTRACT_ID.function=return_value"
-            ))
-        })?;
+        // The signature holds neither separator, so the first one found ends it.
+        let split = match (spec.find("->"), spec.find('=')) {
+            (Some(arrow), Some(eq)) if eq < arrow => Some((eq, 1)),
+            (Some(arrow), _) => Some((arrow, 2)),
+            (None, Some(eq)) => Some((eq, 1)),
+            (None, None) => None,
+        };
+        let (signature, rest) = split
+            .map(|(at, len)| (&spec[..at], &spec[at + len..]))
+            .ok_or_else(|| {
+                DebuggerError::InvalidArguments(format!(
+                    "Invalid mock '{spec}'. Expected CONTRACT_ID.function=return_value[|cpu=N,mem=N,latency_ms=N]"
+                ))
+            })?;
+        let (return_raw, cost) = match rest.rsplit_once('|') {
+            Some((value, cost_raw)) => (value, MockCost::parse(cost_raw)?),
+            None => (rest, MockCost::default()),
+        };
         let (contract_id, function) = signature.rsplit_once('.').ok_or_else(|| {
             DebuggerError::InvalidArguments(format!(
                 "Invalid mock signature '{signature}'. Expected CONTRACT_ID.function"
@@ -125,6 +311,7 @@ impl MockRegistry {
             },
             return_raw: return_raw.to_string(),
             return_val: parsed[0],
+            cost,
         })
     }
 }
@@ -132,6 +319,7 @@ impl MockRegistry {
 pub struct MockContractDispatcher {
     contract_id: String,
     registry: Arc<Mutex<MockRegistry>>,
+    checkpoints: Option<CheckpointRecorder>,
 }
 
 impl MockContractDispatcher {
@@ -139,16 +327,23 @@ impl MockContractDispatcher {
         Self {
             contract_id,
             registry,
+            checkpoints: None,
         }
     }
 
+    /// Record a budget checkpoint on entry to and exit from each mocked call.
+    pub fn with_checkpoints(mut self, checkpoints: CheckpointRecorder) -> Self {
+        self.checkpoints = Some(checkpoints);
+        self
+    }
+
     pub fn boxed(self) -> Rc<dyn ContractFunctionSet> {
         Rc::new(self)
     }
 }
 
 impl ContractFunctionSet for MockContractDispatcher {
-    fn call(&self, func: &HostSymbol, _host: &Host, args: &[HostVal]) -> Option<HostVal> {
+    fn call(&self, func: &HostSymbol, host: &Host, args: &[HostVal]) -> Option<HostVal> {
         let debug_str = format!("{:?}", func);
         let function = if let Some(s) = debug_str.strip_prefix("Symbol(") {
             s.trim_end_matches(')').to_string()
@@ -159,21 +354,272 @@ impl ContractFunctionSet for MockContractDispatcher {
         } else {
             debug_str
         };
+        if let Some(checkpoints) = &self.checkpoints {
+            checkpoints.record(host, format!("Call {}.{}", self.contract_id, function));
+        }
         let mut guard = match self.registry.lock() {
             Ok(g) => g,
             Err(_) => return None,
         };
+        let budget = BudgetInspector::get_cpu_usage(host);
         let resolved = guard.resolve_call(&self.contract_id, &function, args.len());
+        if let Some(call) = guard.calls.last_mut() {
+            call.cpu_at_call = budget.cpu_instructions;
+            call.memory_at_call = budget.memory_bytes;
+        }
         if resolved.is_none() {
             warn!(
                 contract_id = self.contract_id,
                 function, "No mock found for cross-contract call"
             );
         }
+        let charged = guard.calls().last().map(|c| c.charged).unwrap_or_default();
+        drop(guard);
+        if let Err(e) = charged.charge(host) {
+            warn!(
+                contract_id = self.contract_id,
+                function, "Mocked call exceeded the budget: {:?}", e
+            );
+            return None;
+        }
+        if let Some(checkpoints) = &self.checkpoints {
+            checkpoints.record(host, format!("Return {}.{}", self.contract_id, function));
+        }
         resolved
     }
 }
 
+/// Authorization entries for replaying a call with enforced auth, and the
+/// ledger changes made to sign them, which [`AuthReplay::undo`] reverts.
+pub struct AuthReplay {
+    pub entries: Vec<SorobanAuthorizationEntry>,
+    signer: [u8; 32],
+    /// Accounts given the replay signer.
+    signed_accounts: Vec<Rc<LedgerKey>>,
+    /// Accounts and auth contracts that did not exist before the replay.
+    created: Vec<Rc<LedgerKey>>,
+}
+
+impl AuthReplay {
+    /// Remove the replay signer from the accounts it signed for and delete the
+    /// accounts and auth contracts created for the replay, keeping whatever
+    /// else the replayed call wrote.
+    pub fn undo(&self, host: &Host) -> Result<()> {
+        let budget = host.budget_cloned();
+        for key in &self.signed_accounts {
+            if self.created.contains(key) {
+                continue;
+            }
+            let entry = host
+                .with_mut_storage(|storage| storage.get(key, &budget))
+                .map_err(replay_error)?;
+            let mut entry = (*entry).clone();
+            if let LedgerEntryData::Account(account) = &mut entry.data {
+                let signers: Vec<Signer> = account
+                    .signers
+                    .iter()
+                    .filter(|signer| signer.key != SignerKey::Ed25519(Uint256(self.signer)))
+                    .cloned()
+                    .collect();
+                account.signers = signers
+                    .try_into()
+                    .map_err(|e: soroban_env_host::xdr::Error| replay_error(e.into()))?;
+            }
+            host.add_ledger_entry(key, &Rc::new(entry), None)
+                .map_err(replay_error)?;
+        }
+        for key in &self.created {
+            host.with_mut_storage(|storage| storage.del(key, &budget))
+                .map_err(replay_error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Authorization entries covering every `require_auth` the last invocation
+/// recorded in mocked-auth mode, except those by `denied` addresses.
+///
+/// Accounts are signed for with a key generated for this replay and added to
+/// the account as a signer, and contract addresses without a contract of
+/// their own accept any `__check_auth`. Installing the entries switches the
+/// host to enforcing auth, so a replay of the call fails inside the host when
+/// it reaches a denied address, with the same `Auth` error as a missing
+/// signature on the network. Call [`AuthReplay::undo`] once the replay is done.
+pub fn replay_auth_entries(env: &Env, denied: &[String]) -> Result<AuthReplay> {
+    let host = env.host();
+    let denied: Vec<ScAddress> = denied
+        .iter()
+        .filter_map(|address| ScAddress::from_str(address.trim()).ok())
+        .collect();
+    let payloads = host.get_recorded_auth_payloads().map_err(replay_error)?;
+
+    let seed: [u8; 32] = Sha256::digest(
+        [
+            uuid::Uuid::new_v4().into_bytes(),
+            uuid::Uuid::new_v4().into_bytes(),
+        ]
+        .concat(),
+    )
+    .into();
+    let signing_key = SigningKey::from_bytes(&seed);
+    let public_key = signing_key.verifying_key().to_bytes();
+    let network_id = Hash(env.ledger().network_id().to_array());
+    let expiration_ledger = env.ledger().sequence() + env.storage().max_ttl();
+
+    let mut replay = AuthReplay {
+        entries: Vec::new(),
+        signer: public_key,
+        signed_accounts: Vec::new(),
+        created: Vec::new(),
+    };
+    for payload in payloads {
+        let Some(address) = payload.address else {
+            replay.entries.push(SorobanAuthorizationEntry {
+                credentials: SorobanCredentials::SourceAccount,
+                root_invocation: payload.invocation,
+            });
+            continue;
+        };
+        if denied.contains(&address) {
+            continue;
+        }
+
+        let nonce = payload.nonce.unwrap_or_default();
+        let signature = match &address {
+            ScAddress::Account(account_id) => {
+                let key = Rc::new(LedgerKey::Account(LedgerKeyAccount {
+                    account_id: account_id.clone(),
+                }));
+                if !replay.signed_accounts.contains(&key) {
+                    if add_replay_signer(host, &key, public_key).map_err(replay_error)? {
+                        replay.created.push(Rc::clone(&key));
+                    }
+                    replay.signed_accounts.push(key);
+                }
+                let preimage =
+                    HashIdPreimage::SorobanAuthorization(HashIdPreimageSorobanAuthorization {
+                        network_id: network_id.clone(),
+                        nonce,
+                        signature_expiration_ledger: expiration_ledger,
+                        invocation: payload.invocation.clone(),
+                    });
+                let preimage = preimage.to_xdr(Limits::none()).map_err(|e| {
+                    DebuggerError::ExecutionError(format!("Failed to encode auth payload: {}", e))
+                })?;
+                let signature = signing_key.sign(&Sha256::digest(preimage)).to_bytes();
+                account_signature(&public_key, &signature)
+            }
+            ScAddress::Contract(_) => {
+                let contract = Address::try_from_val(env, &address).map_err(|e| {
+                    DebuggerError::ExecutionError(format!("Invalid auth address: {:?}", e))
+                })?;
+                if !contract_exists(host, &address).map_err(replay_error)? {
+                    env.register_at(&contract, MockAuthContract, ());
+                    replay.created.push(contract_instance_key(&address));
+                }
+                ScVal::Void
+            }
+        };
+        replay.entries.push(SorobanAuthorizationEntry {
+            credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+                address,
+                nonce,
+                signature_expiration_ledger: expiration_ledger,
+                signature,
+            }),
+            root_invocation: payload.invocation,
+        });
+    }
+    Ok(replay)
+}
+
+fn replay_error(e: HostError) -> DebuggerError {
+    DebuggerError::ExecutionError(format!("Failed to authorize auth replay: {:?}", e))
+}
+
+/// Make `public_key` a full-weight signer of the account under `key`,
+/// creating the account if the ledger has none. Returns whether it was created.
+fn add_replay_signer(
+    host: &Host,
+    key: &Rc<LedgerKey>,
+    public_key: [u8; 32],
+) -> std::result::Result<bool, HostError> {
+    let LedgerKey::Account(LedgerKeyAccount { account_id }) = key.as_ref() else {
+        return Ok(false);
+    };
+    let budget = host.budget_cloned();
+    let existing = host.with_mut_storage(|storage| {
+        if storage.has(key, &budget)? {
+            storage.get(key, &budget).map(Some)
+        } else {
+            Ok(None)
+        }
+    })?;
+    let created = existing.is_none();
+    let mut entry = match existing {
+        Some(entry) => (*entry).clone(),
+        None => LedgerEntry {
+            last_modified_ledger_seq: 0,
+            data: LedgerEntryData::Account(AccountEntry {
+                account_id: account_id.clone(),
+                balance: 0,
+                seq_num: SequenceNumber(0),
+                num_sub_entries: 0,
+                inflation_dest: None,
+                flags: 0,
+                home_domain: Default::default(),
+                thresholds: Thresholds([1, 0, 0, 0]),
+                signers: Default::default(),
+                ext: AccountEntryExt::V0,
+            }),
+            ext: LedgerEntryExt::V0,
+        },
+    };
+    if let LedgerEntryData::Account(account) = &mut entry.data {
+        let mut signers = account.signers.to_vec();
+        signers.push(Signer {
+            key: SignerKey::Ed25519(Uint256(public_key)),
+            weight: u8::MAX.into(),
+        });
+        account.signers = signers.try_into()?;
+    }
+    host.add_ledger_entry(key, &Rc::new(entry), None)?;
+    Ok(created)
+}
+
+fn contract_exists(host: &Host, address: &ScAddress) -> std::result::Result<bool, HostError> {
+    let key = contract_instance_key(address);
+    let budget = host.budget_cloned();
+    host.with_mut_storage(|storage| storage.has(&key, &budget))
+}
+
+fn contract_instance_key(address: &ScAddress) -> Rc<LedgerKey> {
+    Rc::new(LedgerKey::ContractData(LedgerKeyContractData {
+        contract: address.clone(),
+        key: ScVal::LedgerKeyContractInstance,
+        durability: ContractDataDurability::Persistent,
+    }))
+}
+
+/// The `Vec<Map>` signature an account's `__check_auth` expects.
+fn account_signature(public_key: &[u8; 32], signature: &[u8; 64]) -> ScVal {
+    let field = |name: &str, bytes: &[u8]| ScMapEntry {
+        key: ScVal::Symbol(ScSymbol(name.try_into().unwrap_or_default())),
+        val: ScVal::Bytes(bytes.to_vec().try_into().unwrap_or_default()),
+    };
+    let map = ScMap(
+        vec![
+            field("public_key", public_key),
+            field("signature", signature),
+        ]
+        .try_into()
+        .unwrap_or_default(),
+    );
+    ScVal::Vec(Some(ScVec(
+        vec![ScVal::Map(Some(map))].try_into().unwrap_or_default(),
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +660,174 @@ mod tests {
         assert_eq!(registry.calls().len(), 1);
         assert!(!registry.calls()[0].mocked);
     }
+
+    #[test]
+    fn records_declared_cost_for_mocked_call() {
+        let env = Env::default();
+        let specs = vec![
+            "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M.echo=42|cpu=1500,mem=256"
+                .to_string(),
+        ];
+        let mut registry = MockRegistry::from_cli_specs(&env, &specs).unwrap();
+
+        registry.resolve_call(
+            "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M",
+            "echo",
+            1,
+        );
+        registry.resolve_call(
+            "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M",
+            "echo",
+            1,
+        );
+
+        assert_eq!(registry.calls()[0].charged.cpu_instructions, 1500);
+        assert_eq!(registry.calls()[0].charged.memory_bytes, 256);
+        let total = registry.charged_since(0);
+        assert_eq!(total.cpu_instructions, 3000);
+        assert_eq!(total.memory_bytes, 512);
+        assert_eq!(registry.charged_since(1).cpu_instructions, 1500);
+    }
+
+    #[test]
+    fn charging_a_cost_consumes_the_host_budget() {
+        let env = Env::default();
+        let budget = env.host().budget_cloned();
+        let cpu_before = budget.get_cpu_insns_consumed().unwrap();
+        let mem_before = budget.get_mem_bytes_consumed().unwrap();
+
+        MockCost {
+            cpu_instructions: 250_000,
+            memory_bytes: 4096,
+            latency_ms: 15,
+        }
+        .charge(env.host())
+        .unwrap();
+
+        let cpu = budget.get_cpu_insns_consumed().unwrap() - cpu_before;
+        let mem = budget.get_mem_bytes_consumed().unwrap() - mem_before;
+        assert!((249_990..=250_000).contains(&cpu), "cpu charged: {cpu}");
+        assert!((4096..4096 + 64).contains(&mem), "memory charged: {mem}");
+    }
+
+    #[test]
+    fn arrow_spec_returns_typed_json_value() {
+        let env = Env::default();
+        let specs = vec![
+            r#"CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M.get_price -> {"type":"i128","value":500}|cpu=10"#
+                .to_string(),
+        ];
+        let mut registry = MockRegistry::from_cli_specs(&env, &specs).unwrap();
+
+        let resolved = registry
+            .resolve_call(
+                "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M",
+                "get_price",
+                0,
+            )
+            .unwrap();
+
+        let price: i128 = soroban_sdk::TryFromVal::try_from_val(&env, &resolved).unwrap();
+        assert_eq!(price, 500);
+        assert_eq!(registry.calls()[0].returned.as_deref(), Some("I128(500)"));
+        assert_eq!(registry.calls()[0].charged.cpu_instructions, 10);
+    }
+
+    #[test]
+    fn records_denied_auth_attempts() {
+        let mut registry = MockRegistry::default();
+        let address = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7".to_string();
+        registry
+            .deny_auth(&[address.clone(), address.clone()])
+            .unwrap();
+        assert_eq!(registry.denied_auth(), [address.clone()]);
+
+        registry.record_denied_auth(&address, "CWALLET", "withdraw");
+        assert_eq!(registry.calls().len(), 1);
+        assert_eq!(
+            registry.calls()[0].auth_denied.as_deref(),
+            Some(address.as_str())
+        );
+        assert_eq!(registry.calls()[0].function, "withdraw");
+    }
+
+    mod auth_contract {
+        use soroban_sdk::{contract, contractimpl, Address};
+
+        #[contract]
+        pub struct AuthContract;
+
+        #[contractimpl]
+        impl AuthContract {
+            pub fn pay(from: Address, to: Address) {
+                from.require_auth();
+                to.require_auth();
+            }
+        }
+    }
+
+    #[test]
+    fn replayed_auth_rejects_only_denied_addresses() {
+        use soroban_sdk::IntoVal;
+
+        let from = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+        let to = "GAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQDZ7H";
+        for (denied, succeeds) in [(vec![], true), (vec![to.to_string()], false)] {
+            let env = Env::default();
+            env.mock_all_auths();
+            let contract = env.register(auth_contract::AuthContract, ());
+            let pay = soroban_sdk::Symbol::new(&env, "pay");
+            let args: soroban_sdk::Vec<Val> = soroban_sdk::vec![
+                &env,
+                Address::from_str(&env, from).into_val(&env),
+                Address::from_str(&env, to).into_val(&env),
+            ];
+
+            // Replay from the ledger the mocked run started on, so the
+            // recorded nonces are still unused.
+            let before = env.host().with_mut_storage(|s| Ok(s.clone())).unwrap();
+            env.invoke_contract::<()>(&contract, &pay, args.clone());
+            env.host()
+                .with_mut_storage(|s| {
+                    *s = before;
+                    Ok(())
+                })
+                .unwrap();
+
+            let replay = replay_auth_entries(&env, &denied).unwrap();
+            env.set_auths(&replay.entries);
+            let result = env.try_invoke_contract::<(), soroban_sdk::Error>(&contract, &pay, args);
+            assert_eq!(result.is_ok(), succeeds, "denied: {denied:?}");
+
+            // Undoing the replay removes the accounts created to sign for it.
+            replay.undo(env.host()).unwrap();
+            let from_key = Rc::new(LedgerKey::Account(LedgerKeyAccount {
+                account_id: match ScAddress::from_str(from).unwrap() {
+                    ScAddress::Account(account_id) => account_id,
+                    other => panic!("not an account: {other:?}"),
+                },
+            }));
+            let budget = env.host().budget_cloned();
+            let exists = env
+                .host()
+                .with_mut_storage(|s| s.has(&from_key, &budget))
+                .unwrap();
+            assert!(!exists, "denied: {denied:?}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_denied_auth_address() {
+        let mut registry = MockRegistry::default();
+        assert!(registry.deny_auth(&["not-an-address".to_string()]).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_mock_cost_field() {
+        let env = Env::default();
+        let specs = vec![
+            "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M.echo=42|gas=10".to_string(),
+        ];
+        assert!(MockRegistry::from_cli_specs(&env, &specs).is_err());
+    }
 }
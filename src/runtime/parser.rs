@@ -8,6 +8,8 @@
 //! - Parse raw `--args` JSON into [`soroban_sdk::Val`] slices.
 //! - Normalise `Option<T>` and `Tuple<…>` arguments automatically so callers
 //!   do not need to spell out the annotation envelope themselves.
+//! - Validate `#[contracttype]` struct arguments against the spec's struct
+//!   definitions, so fields can be passed by name in any order.
 
 use crate::{DebuggerError, Result};
 use serde_json::Value as JsonValue;
@@ -16,16 +18,18 @@ use tracing::warn;
 
 /// Parse a raw JSON argument string into a `Vec<Val>` using the given environment.
 ///
-/// `wasm_bytes` is used to look up the function signature so that `Option` and
-/// `Tuple` parameters are wrapped in the typed-annotation envelope automatically.
+/// `wasm_bytes` is used to look up the function signature so that `Option`,
+/// `Tuple` and struct parameters are wrapped in the typed-annotation envelope
+/// automatically.
 pub fn parse_args(
     env: &Env,
     wasm_bytes: &[u8],
     function: &str,
     args_json: &str,
 ) -> Result<Vec<Val>> {
-    let parser = crate::utils::ArgumentParser::new(env.clone());
-    let normalized = normalize_args_for_function(wasm_bytes, function, args_json)?;
+    let structs = crate::utils::wasm::parse_contract_structs(wasm_bytes).unwrap_or_default();
+    let normalized = normalize_args_for_function(wasm_bytes, function, args_json, &structs)?;
+    let parser = crate::utils::ArgumentParser::new(env.clone()).with_structs(structs);
     parser.parse_args_string(&normalized).map_err(|e| {
         warn!("Failed to parse arguments: {}", e);
         DebuggerError::InvalidArguments(e.to_string()).into()
@@ -37,10 +41,14 @@ pub fn parse_args(
 /// Wraps `Option<T>` arguments in `{"type":"option","value":…}` and
 /// `Tuple<…>` arguments in `{"type":"tuple","arity":N,"value":[…]}` so that
 /// the downstream [`ArgumentParser`] can handle them without caller involvement.
+/// Plain objects passed for a struct parameter become
+/// `{"type":"struct","name":…,"fields":{…}}`. Arguments passed by name are
+/// first put in declaration order (see [`named_args`]).
 fn normalize_args_for_function(
     wasm_bytes: &[u8],
     function: &str,
     args_json: &str,
+    structs: &[crate::utils::wasm::ContractStruct],
 ) -> Result<String> {
     let signatures = crate::utils::wasm::parse_function_signatures(wasm_bytes)?;
     let Some(signature) = signatures.into_iter().find(|sig| sig.name == function) else {
@@ -49,12 +57,26 @@ fn normalize_args_for_function(
 
     let mut args_value: JsonValue = serde_json::from_str(args_json)
         .map_err(|e| DebuggerError::InvalidArguments(format!("Invalid JSON in --args: {}", e)))?;
+    if let Some(positional) = named_args(&signature, &args_value)? {
+        args_value = JsonValue::Array(positional);
+    }
 
     let JsonValue::Array(args) = &mut args_value else {
         return Ok(args_json.to_string());
     };
 
     for (arg, param) in args.iter_mut().zip(signature.params.iter()) {
+        if structs.iter().any(|s| s.name == param.type_name) {
+            if !is_typed_annotation(arg) && (arg.is_object() || arg.is_array()) {
+                *arg = serde_json::json!({
+                    "type": "struct",
+                    "name": param.type_name,
+                    "fields": arg.clone(),
+                });
+            }
+            continue;
+        }
+
         if param.type_name.starts_with("Option<") {
             if !is_typed_annotation(arg) {
                 *arg = serde_json::json!({"type": "option", "value": arg.clone()});
@@ -99,6 +121,188 @@ fn normalize_args_for_function(
     })
 }
 
+/// Check the argument count and the shape of each argument against the
+/// function's spec before invoking it, so mistakes surface as an
+/// [`DebuggerError::InvalidArguments`] instead of a trap inside the VM.
+///
+/// Contracts without a `contractspecv0` section, and functions missing from
+/// it, are not checked. Values whose type cannot be judged from JSON alone
+/// (`Option`, `Val` and user-defined types) are accepted as-is.
+pub fn check_args_against_spec(
+    wasm_bytes: &[u8],
+    function: &str,
+    args_json: Option<&str>,
+) -> Result<()> {
+    let Some(spec) = crate::utils::wasm::parse_contract_spec(wasm_bytes)? else {
+        return Ok(());
+    };
+    let Some(signature) = spec.functions.iter().find(|sig| sig.name == function) else {
+        return Ok(());
+    };
+
+    let args = match args_json {
+        None => Vec::new(),
+        Some(json) => match serde_json::from_str(json).map_err(|e| {
+            DebuggerError::InvalidArguments(format!("Invalid JSON in --args: {}", e))
+        })? {
+            JsonValue::Array(items) => items,
+            single => named_args(signature, &single)?.unwrap_or_else(|| vec![single]),
+        },
+    };
+
+    if args.len() != signature.params.len() {
+        let types: Vec<&str> = signature
+            .params
+            .iter()
+            .map(|p| p.type_name.as_str())
+            .collect();
+        return Err(DebuggerError::InvalidArguments(format!(
+            "function {} expects {} arg{} ({}), got {}",
+            function,
+            types.len(),
+            if types.len() == 1 { "" } else { "s" },
+            types.join(", "),
+            args.len()
+        ))
+        .into());
+    }
+
+    for (index, (arg, param)) in args.iter().zip(&signature.params).enumerate() {
+        if !arg_matches_type(arg, &param.type_name) {
+            return Err(DebuggerError::InvalidArguments(format!(
+                "function {} argument {} ('{}') expects {}, got {} {}",
+                function,
+                index + 1,
+                param.name,
+                param.type_name,
+                json_type_name(arg),
+                arg
+            ))
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Turn a `{"param": value}` object into positional arguments in the order
+/// the spec declares them, or `None` if `value` is not in that form.
+///
+/// A plain object names the parameters unless the function takes a single
+/// non-scalar parameter whose name is not among its keys; then the object is
+/// that parameter's value (a map or struct), as before. Omitted `Option`
+/// parameters become `null`. Bare scalars are annotated with the declared
+/// type so that, for example, `"count": 5` reaches a `U32` parameter as a
+/// `u32` rather than the default `i128`.
+fn named_args(
+    signature: &crate::utils::wasm::ContractFunctionSignature,
+    value: &JsonValue,
+) -> Result<Option<Vec<JsonValue>>> {
+    let JsonValue::Object(obj) = value else {
+        return Ok(None);
+    };
+    if is_typed_annotation(value) {
+        return Ok(None);
+    }
+    if let [param] = signature.params.as_slice() {
+        let scalar = is_integer_type(&param.type_name.to_ascii_lowercase())
+            || matches!(
+                param.type_name.as_str(),
+                "Bool" | "String" | "Symbol" | "Address" | "Bytes"
+            );
+        if !obj.contains_key(&param.name) && !scalar {
+            return Ok(None);
+        }
+    }
+
+    let names: Vec<&str> = signature.params.iter().map(|p| p.name.as_str()).collect();
+    if let Some(unknown) = obj.keys().find(|key| !names.contains(&key.as_str())) {
+        return Err(DebuggerError::InvalidArguments(format!(
+            "function {} has no parameter '{}' (parameters: {})",
+            signature.name,
+            unknown,
+            names.join(", ")
+        ))
+        .into());
+    }
+
+    signature
+        .params
+        .iter()
+        .map(|param| match obj.get(&param.name) {
+            Some(arg) => Ok(annotate_scalar(arg, &param.type_name)),
+            None if param.type_name.starts_with("Option<") => Ok(JsonValue::Null),
+            None => Err(DebuggerError::InvalidArguments(format!(
+                "function {} is missing argument '{}' ({})",
+                signature.name, param.name, param.type_name
+            ))
+            .into()),
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Wrap a bare number or string in the typed annotation for a scalar spec
+/// type. Anything else, or a value that does not fit, is returned unchanged
+/// for the argument check to report.
+fn annotate_scalar(arg: &JsonValue, type_name: &str) -> JsonValue {
+    let annotation = type_name.to_ascii_lowercase();
+    let fits = match arg {
+        JsonValue::Number(_) => is_integer_type(&annotation),
+        JsonValue::String(s) if is_integer_type(&annotation) => looks_like_integer(s),
+        JsonValue::String(_) => matches!(annotation.as_str(), "string" | "symbol" | "address"),
+        _ => false,
+    };
+    if fits {
+        serde_json::json!({"type": annotation, "value": arg})
+    } else {
+        arg.clone()
+    }
+}
+
+/// Whether `arg` can be converted to the spec type `type_name` by the
+/// argument parser. Errs on the side of accepting.
+fn arg_matches_type(arg: &JsonValue, type_name: &str) -> bool {
+    let base = type_name
+        .split('<')
+        .next()
+        .unwrap_or(type_name)
+        .to_ascii_lowercase();
+    let is_integer = is_integer_type(&base);
+    let is_builtin = is_integer
+        || matches!(
+            base.as_str(),
+            "bool" | "string" | "symbol" | "address" | "bytes" | "bytesn" | "vec" | "map" | "tuple"
+        );
+    if !is_builtin {
+        // Option, Val, Void, Error and user-defined types.
+        return true;
+    }
+
+    if is_typed_annotation(arg) {
+        let annotated = arg["type"]
+            .as_str()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        return annotated == base
+            || (annotated.starts_with("bytes") && base.starts_with("bytes"))
+            || annotated == "option";
+    }
+
+    match arg {
+        JsonValue::Number(_) => is_integer,
+        // Integers wider than 64 bits may be written as strings.
+        JsonValue::String(s) if is_integer => looks_like_integer(s),
+        JsonValue::String(_) => matches!(
+            base.as_str(),
+            "string" | "symbol" | "address" | "bytes" | "bytesn"
+        ),
+        JsonValue::Bool(_) => base == "bool",
+        JsonValue::Array(_) => matches!(base.as_str(), "vec" | "tuple" | "bytes" | "bytesn"),
+        JsonValue::Object(_) => base == "map",
+        JsonValue::Null => false,
+    }
+}
+
 // ── helpers ──────────────────────────────────────────────────────────────────
 
 fn tuple_arity_from_type_name(type_name: &str) -> Option<usize> {
@@ -119,10 +323,37 @@ fn tuple_arity_from_type_name(type_name: &str) -> Option<usize> {
     Some(arity)
 }
 
+/// `base` is a lowercase spec type name such as `u32` or `timepoint`.
+fn is_integer_type(base: &str) -> bool {
+    matches!(
+        base,
+        "u32"
+            | "i32"
+            | "u64"
+            | "i64"
+            | "u128"
+            | "i128"
+            | "u256"
+            | "i256"
+            | "timepoint"
+            | "duration"
+    )
+}
+
+fn looks_like_integer(s: &str) -> bool {
+    let s = s.trim();
+    let s = s.strip_prefix('-').unwrap_or(s);
+    match s.strip_prefix("0x") {
+        Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()),
+    }
+}
+
 fn is_typed_annotation(value: &JsonValue) -> bool {
     matches!(
         value,
-        JsonValue::Object(obj) if obj.get("type").is_some() && obj.get("value").is_some()
+        JsonValue::Object(obj) if obj.get("type").is_some()
+            && (obj.get("value").is_some() || obj.get("fields").is_some())
     )
 }
 
@@ -141,7 +372,23 @@ fn json_type_name(value: &JsonValue) -> &'static str {
 
 #[cfg(test)]
 mod tests {
-    use super::tuple_arity_from_type_name;
+    use super::{arg_matches_type, named_args, tuple_arity_from_type_name};
+    use crate::utils::wasm::{ContractFunctionSignature, FunctionParam};
+    use serde_json::json;
+
+    fn signature(name: &str, params: &[(&str, &str)]) -> ContractFunctionSignature {
+        ContractFunctionSignature {
+            name: name.to_string(),
+            params: params
+                .iter()
+                .map(|(name, type_name)| FunctionParam {
+                    name: name.to_string(),
+                    type_name: type_name.to_string(),
+                })
+                .collect(),
+            return_type: None,
+        }
+    }
 
     #[test]
     fn tuple_arity_counts_top_level_types() {
@@ -162,4 +409,79 @@ mod tests {
     fn tuple_arity_returns_none_for_bad_prefix() {
         assert_eq!(tuple_arity_from_type_name("Vec<U32>"), None);
     }
+
+    #[test]
+    fn arg_types_follow_parser_conversions() {
+        assert!(arg_matches_type(&json!(5), "U32"));
+        assert!(arg_matches_type(
+            &json!("170141183460469231731687303715884105727"),
+            "I128"
+        ));
+        assert!(arg_matches_type(&json!({"type": "u32", "value": 5}), "U32"));
+        assert!(arg_matches_type(&json!([1, 2]), "Vec<I128>"));
+        assert!(arg_matches_type(&json!(null), "Option<Address>"));
+        assert!(arg_matches_type(&json!(7), "DataKey"));
+
+        assert!(!arg_matches_type(&json!(5), "Address"));
+        assert!(!arg_matches_type(&json!("abc"), "I128"));
+        assert!(!arg_matches_type(
+            &json!({"type": "i64", "value": 5}),
+            "U32"
+        ));
+        assert!(!arg_matches_type(&json!(true), "Symbol"));
+        assert!(!arg_matches_type(&json!(null), "U64"));
+    }
+
+    #[test]
+    fn named_args_follow_declaration_order() {
+        let transfer = signature(
+            "transfer",
+            &[("from", "Address"), ("to", "Address"), ("amount", "I128")],
+        );
+        let args = json!({"amount": 100, "to": "GBOB", "from": "GALICE"});
+
+        assert_eq!(
+            named_args(&transfer, &args).unwrap(),
+            Some(vec![
+                json!({"type": "address", "value": "GALICE"}),
+                json!({"type": "address", "value": "GBOB"}),
+                json!({"type": "i128", "value": 100}),
+            ])
+        );
+    }
+
+    #[test]
+    fn named_args_report_unknown_and_missing_parameters() {
+        let transfer = signature(
+            "transfer",
+            &[("from", "Address"), ("to", "Address"), ("amount", "I128")],
+        );
+
+        let err = named_args(&transfer, &json!({"from": "G", "to": "G", "amout": 1}))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("has no parameter 'amout'"), "{err}");
+
+        let err = named_args(&transfer, &json!({"from": "G", "to": "G"}))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("missing argument 'amount' (I128)"), "{err}");
+    }
+
+    #[test]
+    fn named_args_leave_optional_and_single_map_parameters_alone() {
+        let mint = signature("mint", &[("to", "Address"), ("memo", "Option<String>")]);
+        assert_eq!(
+            named_args(&mint, &json!({"to": "GALICE"})).unwrap(),
+            Some(vec![
+                json!({"type": "address", "value": "GALICE"}),
+                json!(null)
+            ])
+        );
+
+        // An object for a lone map parameter is still the map itself.
+        let set = signature("set_all", &[("entries", "Map<Symbol, I128>")]);
+        assert_eq!(named_args(&set, &json!({"a": 1})).unwrap(), None);
+        assert_eq!(named_args(&set, &json!([1])).unwrap(), None);
+    }
 }
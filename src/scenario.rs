@@ -3,8 +3,10 @@ use crate::debugger::engine::DebuggerEngine;
 use crate::inspector::budget::{BudgetInfo, BudgetInspector};
 use crate::inspector::events::{ContractEvent, EventInspector};
 use crate::logging;
+use crate::profiler::FlameGraphAccumulator;
 use crate::runtime::executor::{ContractExecutor, DEFAULT_EXECUTION_TIMEOUT_SECS};
 use crate::ui::formatter::Formatter;
+use crate::utils::template;
 use crate::{DebuggerError, Result};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -17,10 +19,15 @@ pub struct Scenario {
     /// Optional list of fragment TOML files whose steps are prepended to this scenario.
     /// Paths are resolved relative to the directory that contains this file.
     /// Includes are processed recursively; cycles are detected and reported as errors.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub include: Vec<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "ScenarioDefaults::is_empty")]
     pub defaults: ScenarioDefaults,
+    /// Values for `${name}` placeholders in step `args`, `expected_return` and
+    /// the initial `--storage`. Only the root file's table is used; `--var`
+    /// overrides entries here.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub vars: HashMap<String, String>,
     pub steps: Vec<ScenarioStep>,
 }
 
@@ -29,6 +36,12 @@ pub struct ScenarioDefaults {
     pub timeout_secs: Option<u64>,
 }
 
+impl ScenarioDefaults {
+    fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ScenarioStep {
     pub name: Option<String>,
@@ -64,6 +77,64 @@ pub struct ScenarioBudgetAssertion {
     pub max_memory_bytes: Option<u64>,
 }
 
+/// A call made during an interactive session, replayable as a scenario step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedCall {
+    pub function: String,
+    pub args: Option<String>,
+    /// Return value on success, error message on failure.
+    pub outcome: std::result::Result<String, String>,
+}
+
+impl Scenario {
+    /// Build a scenario that replays `calls` in order, asserting each recorded
+    /// return value, or for failed calls, the recorded error.
+    pub fn from_recorded_calls(calls: &[RecordedCall]) -> Self {
+        let steps = calls
+            .iter()
+            .map(|call| {
+                let (expected_return, expected_error) = match &call.outcome {
+                    Ok(value) => (Some(value.trim().to_string()), None),
+                    Err(error) => (None, Some(error.clone())),
+                };
+                ScenarioStep {
+                    name: None,
+                    function: call.function.clone(),
+                    args: call.args.clone(),
+                    timeout_secs: None,
+                    expected_return,
+                    expected_storage: None,
+                    expected_events: None,
+                    budget_limits: None,
+                    expected_error,
+                    expected_panic: None,
+                    capture: None,
+                    tags: None,
+                    notes: None,
+                }
+            })
+            .collect();
+
+        Self {
+            include: Vec::new(),
+            defaults: ScenarioDefaults::default(),
+            vars: HashMap::new(),
+            steps,
+        }
+    }
+
+    /// Write this scenario to `path` as TOML.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = toml::to_string_pretty(self).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to serialize scenario: {}", e))
+        })?;
+        fs::write(path, content).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to write scenario file {:?}: {}", path, e))
+        })?;
+        Ok(())
+    }
+}
+
 /// Load a scenario file, recursively resolving `include` directives.
 ///
 /// `visiting` tracks canonical paths currently on the call stack so that
@@ -119,15 +190,18 @@ pub fn run_scenario(args: ScenarioArgs, _verbosity: Verbosity) -> Result<()> {
     );
 
     let root_content = fs::read_to_string(&args.scenario).map_err(|e| {
-        DebuggerError::ExecutionError(format!("Failed to read root scenario file: {}", e))
+        DebuggerError::FileError(format!("Failed to read root scenario file: {}", e))
     })?;
     let root_scenario: Scenario = toml::from_str(&root_content).map_err(|e| {
-        DebuggerError::ExecutionError(format!("Failed to parse root scenario file: {}", e))
+        DebuggerError::FileError(format!("Failed to parse root scenario file: {}", e))
     })?;
 
     let mut visiting = HashSet::new();
     let steps = load_scenario(&args.scenario, &mut visiting)?;
 
+    let mut template_vars = root_scenario.vars.clone();
+    template_vars.extend(template::parse_var_assignments(&args.vars)?);
+
     println!(
         "{}",
         Formatter::info(format!("Loading contract: {:?}", args.contract))
@@ -141,10 +215,11 @@ pub fn run_scenario(args: ScenarioArgs, _verbosity: Verbosity) -> Result<()> {
     let mut executor = ContractExecutor::new(wasm_file.bytes)?;
 
     if let Some(storage_json) = &args.storage {
-        serde_json::from_str::<serde_json::Value>(storage_json).map_err(|e| {
+        let storage_json = template::substitute(storage_json, &template_vars)?;
+        serde_json::from_str::<serde_json::Value>(&storage_json).map_err(|e| {
             DebuggerError::StorageError(format!("Failed to parse initial storage JSON: {}", e))
         })?;
-        executor.set_initial_storage(storage_json.clone())?;
+        executor.set_initial_storage(storage_json)?;
     }
 
     println!(
@@ -154,6 +229,7 @@ pub fn run_scenario(args: ScenarioArgs, _verbosity: Verbosity) -> Result<()> {
 
     let mut engine = DebuggerEngine::new(executor, vec![]);
     let mut all_passed = true;
+    let mut flamegraph = FlameGraphAccumulator::new();
     let mut variables: HashMap<String, String> = HashMap::new();
 
     let include_tags: Option<Vec<String>> = args.tags.as_ref().map(|s| s.split(',').map(|t| t.trim().to_string()).collect());
@@ -206,13 +282,15 @@ pub fn run_scenario(args: ScenarioArgs, _verbosity: Verbosity) -> Result<()> {
         }
 
         let resolved_args = if let Some(args_json) = &step.args {
-            Some(interpolate_variables(args_json, &variables)?)
+            let args_json = template::substitute(args_json, &template_vars)?;
+            Some(interpolate_variables(&args_json, &variables)?)
         } else {
             None
         };
 
         let resolved_expected_return = if let Some(expected) = &step.expected_return {
-            Some(interpolate_variables(expected, &variables)?)
+            let expected = template::substitute(expected, &template_vars)?;
+            Some(interpolate_variables(&expected, &variables)?)
         } else {
             None
         };
@@ -224,8 +302,31 @@ pub fn run_scenario(args: ScenarioArgs, _verbosity: Verbosity) -> Result<()> {
         };
 
         let events_before_len = engine.executor().get_events()?.len();
+        let mock_calls_before_len = engine.executor().get_mock_call_log().len();
         let result = engine.execute(&step.function, parsed_args.as_deref());
 
+        if args.flamegraph.is_some() && result.is_ok() {
+            if let Some(record) = engine.executor().last_execution() {
+                let callees: Vec<(String, u64)> = engine
+                    .executor()
+                    .get_mock_call_log()
+                    .iter()
+                    .skip(mock_calls_before_len)
+                    .map(|call| {
+                        (
+                            format!("{}.{}", call.contract_id, call.function),
+                            call.charged.cpu_instructions,
+                        )
+                    })
+                    .collect();
+                flamegraph.add_invocation(
+                    &step.function,
+                    record.budget.cpu_instructions,
+                    &callees,
+                );
+            }
+        }
+
         let mut step_passed = true;
         let expects_failure = step.expected_error.is_some() || step.expected_panic.is_some();
 
@@ -407,6 +508,21 @@ pub fn run_scenario(args: ScenarioArgs, _verbosity: Verbosity) -> Result<()> {
         }
     }
 
+    if let Some(path) = &args.flamegraph {
+        if flamegraph.is_empty() {
+            println!(
+                "{}",
+                Formatter::warning("No successful steps to include in the flamegraph")
+            );
+        } else {
+            flamegraph.write_to(path)?;
+            println!(
+                "{}",
+                Formatter::success(format!("Scenario flamegraph written to {:?}", path))
+            );
+        }
+    }
+
     if all_passed {
         println!(
             "{}",
@@ -445,7 +561,7 @@ fn interpolate_variables(template: &str, variables: &HashMap<String, String>) ->
                 .collect::<Vec<_>>()
                 .join(", ")
         };
-        return Err(DebuggerError::ExecutionError(format!(
+        return Err(DebuggerError::InvalidArguments(format!(
             "Undefined variable(s) referenced in scenario step: [{}]. Available variables: [{}]",
             missing.join(", "),
             available_str
@@ -608,6 +724,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_recorded_calls_round_trip_through_scenario_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.toml");
+        let calls = vec![
+            RecordedCall {
+                function: "increment".to_string(),
+                args: Some("[5]".to_string()),
+                outcome: Ok("I64(5)\n".to_string()),
+            },
+            RecordedCall {
+                function: "decrement".to_string(),
+                args: None,
+                outcome: Err("Contract error: underflow".to_string()),
+            },
+        ];
+
+        Scenario::from_recorded_calls(&calls).save(&path).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("[vars]"));
+        assert!(!content.contains("[defaults]"));
+        let steps = load_scenario(&path, &mut HashSet::new()).unwrap();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].function, "increment");
+        assert_eq!(steps[0].args.as_deref(), Some("[5]"));
+        assert_eq!(steps[0].expected_return.as_deref(), Some("I64(5)"));
+        assert_eq!(steps[1].args, None);
+        assert_eq!(
+            steps[1].expected_error.as_deref(),
+            Some("Contract error: underflow")
+        );
+    }
+
+    #[test]
+    fn test_vars_table_deserialization() {
+        let toml_str = r#"
+            [vars]
+            alice = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7"
+
+            [[steps]]
+            function = "approve"
+            args = '["${alice}", "${alice}"]'
+        "#;
+
+        let scenario: Scenario = toml::from_str(toml_str).unwrap();
+        let args = template::substitute(scenario.steps[0].args.as_deref().unwrap(), &scenario.vars)
+            .unwrap();
+        assert_eq!(
+            args,
+            r#"["GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7", "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7"]"#
+        );
+    }
+
     #[test]
     fn test_capture_field_deserialization() {
         let toml_str = r#"
@@ -942,6 +1112,7 @@ function = "increment"
             contract_id: None,
             topics: vec!["topic".to_string()],
             data: "payload".to_string(),
+            decoded: None,
         }];
 
         assert!(assert_expected_events(&expected, &actual).is_ok());
@@ -954,6 +1125,7 @@ function = "increment"
             contract_id: None,
             topics: vec!["topic".to_string()],
             data: "payload".to_string(),
+            decoded: None,
         }];
 
         let err = assert_expected_events(&expected, &actual).unwrap_err();
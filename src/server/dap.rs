@@ -0,0 +1,638 @@
+//! Debug Adapter Protocol server over stdio.
+//!
+//! Editors speak DAP rather than the custom `DebugRequest`/`DebugResponse`
+//! protocol used by [`super::DebugServer`]. [`DapServer`] reads
+//! `Content-Length` framed requests, maps them onto [`DebuggerEngine`], and
+//! writes responses and events back on the same stream.
+//!
+//! A session follows the usual DAP sequence: `initialize`, `launch` with the
+//! contract path and function, breakpoint configuration, then
+//! `configurationDone`, which starts the call. Function breakpoints pause
+//! before the call runs, matching the remote server; `continue` and the step
+//! requests then run it to completion unless instruction stepping is available.
+
+use crate::debugger::breakpoint::BreakpointManager;
+use crate::debugger::engine::DebuggerEngine;
+use crate::runtime::executor::ContractExecutor;
+use crate::{DebuggerError, Result};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+/// DAP has no notion of threads for a contract call; everything runs on one.
+const THREAD_ID: i64 = 1;
+const ARGUMENTS_REFERENCE: i64 = 1;
+const STORAGE_REFERENCE: i64 = 2;
+
+/// A function breakpoint as requested by the client.
+#[derive(Debug, Clone)]
+struct FunctionBreakpoint {
+    name: String,
+    condition: Option<String>,
+    hit_condition: Option<String>,
+}
+
+/// A launched contract call.
+struct Session {
+    engine: DebuggerEngine,
+    exported: Vec<String>,
+    function: String,
+    args: Option<String>,
+    stop_on_entry: bool,
+    /// Paused before the call runs, at a function breakpoint or on entry.
+    pending: bool,
+}
+
+/// Response body plus the events to send after the response.
+struct Reply {
+    body: Value,
+    events: Vec<Value>,
+}
+
+impl Reply {
+    fn body(body: Value) -> Self {
+        Self {
+            body,
+            events: Vec::new(),
+        }
+    }
+
+    fn empty() -> Self {
+        Self::body(json!({}))
+    }
+}
+
+pub struct DapServer<R, W> {
+    reader: R,
+    writer: W,
+    seq: i64,
+    session: Option<Session>,
+    function_breakpoints: Vec<FunctionBreakpoint>,
+    source_breakpoints: BTreeMap<String, Vec<String>>,
+}
+
+impl DapServer<std::io::StdinLock<'static>, std::io::Stdout> {
+    /// Serve DAP on the process's stdin and stdout.
+    pub fn stdio() -> Self {
+        Self::new(std::io::stdin().lock(), std::io::stdout())
+    }
+}
+
+impl<R: BufRead, W: Write> DapServer<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader,
+            writer,
+            seq: 0,
+            session: None,
+            function_breakpoints: Vec::new(),
+            source_breakpoints: BTreeMap::new(),
+        }
+    }
+
+    /// Handle requests until the client disconnects or closes the stream.
+    pub fn run(mut self) -> Result<()> {
+        while let Some(message) = self.read_message()? {
+            if message["type"] != "request" {
+                continue;
+            }
+            let command = message["command"].as_str().unwrap_or_default().to_string();
+            let arguments = message.get("arguments").cloned().unwrap_or(Value::Null);
+
+            let reply = self.handle(&command, &arguments);
+            let mut response = json!({
+                "type": "response",
+                "request_seq": message["seq"],
+                "command": command,
+                "success": reply.is_ok(),
+            });
+            let events = match reply {
+                Ok(reply) => {
+                    response["body"] = reply.body;
+                    reply.events
+                }
+                Err(message) => {
+                    response["message"] = json!(message);
+                    Vec::new()
+                }
+            };
+            self.send(response)?;
+            for event in events {
+                self.send(event)?;
+            }
+
+            if command == "disconnect" || command == "terminate" {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle(&mut self, command: &str, arguments: &Value) -> std::result::Result<Reply, String> {
+        match command {
+            "initialize" => Ok(Reply::body(json!({
+                "supportsConfigurationDoneRequest": true,
+                "supportsFunctionBreakpoints": true,
+                "supportsConditionalBreakpoints": true,
+                "supportsHitConditionalBreakpoints": true,
+            }))),
+            "launch" => self.launch(arguments),
+            "setBreakpoints" => self.set_source_breakpoints(arguments),
+            "setFunctionBreakpoints" => self.set_function_breakpoints(arguments),
+            "setExceptionBreakpoints" => Ok(Reply::body(json!({ "breakpoints": [] }))),
+            "configurationDone" => self.start(),
+            "threads" => Ok(Reply::body(json!({
+                "threads": [{ "id": THREAD_ID, "name": "main" }],
+            }))),
+            "stackTrace" => self.stack_trace(),
+            "scopes" => Ok(Reply::body(json!({
+                "scopes": [
+                    {
+                        "name": "Arguments",
+                        "variablesReference": ARGUMENTS_REFERENCE,
+                        "expensive": false,
+                    },
+                    {
+                        "name": "Storage",
+                        "variablesReference": STORAGE_REFERENCE,
+                        "expensive": false,
+                    },
+                ],
+            }))),
+            "variables" => self.variables(arguments),
+            "continue" => self.resume(None),
+            "next" => self.resume(Some(DebuggerEngine::step_over)),
+            "stepIn" => self.resume(Some(DebuggerEngine::step_into)),
+            "stepOut" => self.resume(Some(DebuggerEngine::step_out)),
+            "disconnect" | "terminate" => Ok(Reply::empty()),
+            other => Err(format!("Unsupported request '{}'", other)),
+        }
+    }
+
+    fn launch(&mut self, arguments: &Value) -> std::result::Result<Reply, String> {
+        let path = arguments["contract"]
+            .as_str()
+            .or_else(|| arguments["wasm"].as_str())
+            .ok_or("launch requires a 'contract' (or 'wasm') path")?;
+        let function = arguments["function"]
+            .as_str()
+            .ok_or("launch requires a 'function' to call")?;
+        let args = match &arguments["args"] {
+            Value::Null => None,
+            Value::String(json) => Some(json.clone()),
+            other => Some(other.to_string()),
+        };
+
+        let wasm = fs::read(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        let exported = crate::utils::wasm::parse_functions(&wasm).map_err(|e| e.to_string())?;
+        if !exported.iter().any(|f| f == function) {
+            return Err(format!("'{}' is not exported by {}", function, path));
+        }
+        let executor = ContractExecutor::new(wasm.clone()).map_err(|e| e.to_string())?;
+        let mut engine = DebuggerEngine::new(executor, Vec::new());
+        let mut events = Vec::new();
+        // Without instruction stepping the session still runs, but each step
+        // request runs the call to completion.
+        if let Err(e) = engine.enable_instruction_debug(&wasm) {
+            events.push(output_event(
+                "stderr",
+                format!(
+                    "Instruction stepping is unavailable, steps will run to completion: {}",
+                    e
+                ),
+            ));
+        }
+
+        self.session = Some(Session {
+            engine,
+            exported,
+            function: function.to_string(),
+            args,
+            stop_on_entry: arguments["stopOnEntry"].as_bool().unwrap_or(false),
+            pending: false,
+        });
+
+        // Breakpoints are only accepted once there is a contract to resolve
+        // them against, so configuration starts after launch.
+        events.push(event("initialized", json!({})));
+        Ok(Reply {
+            body: json!({}),
+            events,
+        })
+    }
+
+    fn set_function_breakpoints(
+        &mut self,
+        arguments: &Value,
+    ) -> std::result::Result<Reply, String> {
+        self.function_breakpoints = arguments["breakpoints"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|bp| {
+                Some(FunctionBreakpoint {
+                    name: bp["name"].as_str()?.to_string(),
+                    condition: bp["condition"].as_str().map(str::to_string),
+                    hit_condition: bp["hitCondition"].as_str().map(str::to_string),
+                })
+            })
+            .collect();
+        let results = self.sync_breakpoints()?;
+        Ok(Reply::body(json!({
+            "breakpoints": results,
+        })))
+    }
+
+    /// Bind source lines to the exported functions that contain them. Lines
+    /// that cannot be mapped are reported unverified.
+    fn set_source_breakpoints(&mut self, arguments: &Value) -> std::result::Result<Reply, String> {
+        let session = self.session.as_ref().ok_or("No contract launched")?;
+        let source = arguments["source"]["path"].as_str().unwrap_or_default();
+        let lines: Vec<u32> = arguments["breakpoints"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|bp| bp["line"].as_u64().map(|line| line as u32))
+            .collect();
+
+        let mut functions = Vec::new();
+        let breakpoints: Vec<Value> = match session.engine.source_map() {
+            Some(source_map) => source_map
+                .resolve_source_breakpoints(&[], Path::new(source), &lines, None)
+                .into_iter()
+                .map(|resolution| {
+                    let function = resolution
+                        .function
+                        .filter(|f| resolution.verified && session.exported.contains(f));
+                    if let Some(function) = &function {
+                        functions.push(function.clone());
+                    }
+                    json!({
+                        "verified": function.is_some(),
+                        "line": resolution.line,
+                        "message": resolution.message,
+                    })
+                })
+                .collect(),
+            None => lines
+                .iter()
+                .map(|line| {
+                    json!({
+                        "verified": false,
+                        "line": line,
+                        "message": "Contract has no DWARF debug info; use function breakpoints",
+                    })
+                })
+                .collect(),
+        };
+
+        self.source_breakpoints
+            .insert(source.to_string(), functions);
+        self.sync_breakpoints()?;
+        Ok(Reply::body(json!({ "breakpoints": breakpoints })))
+    }
+
+    /// Rebuild the engine's breakpoints from the function and source
+    /// breakpoints the client has set, returning one DAP breakpoint per
+    /// function breakpoint.
+    fn sync_breakpoints(&mut self) -> std::result::Result<Vec<Value>, String> {
+        let Some(session) = self.session.as_mut() else {
+            return Err("No contract launched".to_string());
+        };
+        session.engine.breakpoints_mut().clear();
+
+        let mut results = Vec::new();
+        for bp in &self.function_breakpoints {
+            let outcome = if session.exported.contains(&bp.name) {
+                apply_breakpoint(&mut session.engine, bp).map_err(|e| e.to_string())
+            } else {
+                Err(format!("'{}' is not an exported function", bp.name))
+            };
+            results.push(match outcome {
+                Ok(()) => json!({ "verified": true }),
+                Err(message) => json!({ "verified": false, "message": message }),
+            });
+        }
+        for function in self.source_breakpoints.values().flatten() {
+            if session.engine.breakpoints().get(function).is_none() {
+                session.engine.breakpoints_mut().add(function);
+            }
+        }
+        Ok(results)
+    }
+
+    /// Start the launched call: pause at a matching function breakpoint or on
+    /// entry, otherwise run it to completion.
+    fn start(&mut self) -> std::result::Result<Reply, String> {
+        let session = self.session.as_mut().ok_or("No contract launched")?;
+        let function = session.function.clone();
+        let args = session.args.clone();
+
+        let (should_pause, log_message) = session
+            .engine
+            .check_function_breakpoint(&function, args.as_deref())
+            .map_err(|e| e.to_string())?;
+
+        let mut events: Vec<Value> = log_message
+            .map(|message| output("console", format!("[LOG @{}] {}\n", function, message)))
+            .into_iter()
+            .collect();
+        if should_pause {
+            session
+                .engine
+                .prepare_breakpoint_stop(&function, args.as_deref());
+            session.pending = true;
+            events.push(stopped("breakpoint", Some(&function)));
+        } else if session.stop_on_entry {
+            session.engine.stage_execution(&function, args.as_deref());
+            session.pending = true;
+            events.push(stopped("entry", None));
+        } else {
+            events.extend(session.complete());
+        }
+        Ok(Reply {
+            body: json!({}),
+            events,
+        })
+    }
+
+    /// Continue or step. A call paused before it runs is stepped through
+    /// instructions when instruction debugging is available and otherwise run
+    /// to completion, as is a call whose stepping has reached the end.
+    fn resume(
+        &mut self,
+        step: Option<fn(&mut DebuggerEngine) -> Result<bool>>,
+    ) -> std::result::Result<Reply, String> {
+        let session = self.session.as_mut().ok_or("No contract launched")?;
+        if let Some(step) = step {
+            if session.engine.is_instruction_debug_enabled() {
+                if let Ok(true) = step(&mut session.engine) {
+                    return Ok(Reply {
+                        body: json!({}),
+                        events: vec![stopped("step", None)],
+                    });
+                }
+            }
+        }
+        if !session.pending {
+            return Ok(Reply {
+                body: json!({ "allThreadsContinued": true }),
+                events: vec![event("terminated", json!({}))],
+            });
+        }
+        Ok(Reply {
+            body: json!({ "allThreadsContinued": true }),
+            events: session.complete(),
+        })
+    }
+
+    /// Frames from the engine's call stack, innermost first.
+    fn stack_trace(&self) -> std::result::Result<Reply, String> {
+        let session = self.session.as_ref().ok_or("No contract launched")?;
+        let location = session.engine.current_source_location();
+        let state = session
+            .engine
+            .state()
+            .lock()
+            .map_err(|e| format!("Failed to acquire state lock: {}", e))?
+            .clone();
+
+        let frames: Vec<Value> = state
+            .call_stack()
+            .get_stack()
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(index, frame)| {
+                let name = match &frame.contract_id {
+                    Some(id) => format!("{} [{}]", frame.function, id),
+                    None => frame.function.clone(),
+                };
+                let mut value = json!({
+                    "id": index,
+                    "name": name,
+                    "line": 0,
+                    "column": 0,
+                });
+                if let (0, Some(location)) = (index, &location) {
+                    value["source"] = json!({ "path": location.file });
+                    value["line"] = json!(location.line);
+                    value["column"] = json!(location.column.unwrap_or(1));
+                }
+                value
+            })
+            .collect();
+        let total = frames.len();
+        Ok(Reply::body(json!({
+            "stackFrames": frames,
+            "totalFrames": total,
+        })))
+    }
+
+    fn variables(&self, arguments: &Value) -> std::result::Result<Reply, String> {
+        let session = self.session.as_ref().ok_or("No contract launched")?;
+        let variables: Vec<Value> = match arguments["variablesReference"].as_i64() {
+            Some(ARGUMENTS_REFERENCE) => {
+                let params = session
+                    .engine
+                    .executor()
+                    .function_param_names(&session.function)
+                    .unwrap_or_default();
+                let values = session
+                    .args
+                    .as_deref()
+                    .and_then(|json| serde_json::from_str::<Value>(json).ok())
+                    .map(|value| match value {
+                        Value::Array(items) => items,
+                        other => vec![other],
+                    })
+                    .unwrap_or_default();
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let name = params
+                            .get(index)
+                            .cloned()
+                            .unwrap_or_else(|| format!("arg{}", index));
+                        variable(name, value.to_string())
+                    })
+                    .collect()
+            }
+            Some(STORAGE_REFERENCE) => {
+                let snapshot = session
+                    .engine
+                    .executor()
+                    .get_storage_snapshot()
+                    .map_err(|e| e.to_string())?;
+                snapshot
+                    .into_iter()
+                    .collect::<BTreeMap<_, _>>()
+                    .into_iter()
+                    .map(|(key, value)| variable(key, value))
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        Ok(Reply::body(json!({ "variables": variables })))
+    }
+
+    fn read_message(&mut self) -> Result<Option<Value>> {
+        let mut content_length = None;
+        loop {
+            let mut line = String::new();
+            let read = self.reader.read_line(&mut line).map_err(io_error)?;
+            if read == 0 {
+                return Ok(None);
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some(length) = line.strip_prefix("Content-Length:") {
+                content_length = length.trim().parse::<usize>().ok();
+            }
+        }
+
+        let length = content_length.ok_or_else(|| {
+            DebuggerError::NetworkError("DAP message is missing Content-Length".to_string())
+        })?;
+        let mut body = vec![0; length];
+        self.reader.read_exact(&mut body).map_err(io_error)?;
+        serde_json::from_slice(&body)
+            .map(Some)
+            .map_err(|e| DebuggerError::NetworkError(format!("Invalid DAP message: {}", e)).into())
+    }
+
+    fn send(&mut self, mut message: Value) -> Result<()> {
+        self.seq += 1;
+        message["seq"] = json!(self.seq);
+        let body = serde_json::to_vec(&message)
+            .map_err(|e| DebuggerError::NetworkError(format!("Failed to encode DAP: {}", e)))?;
+        write!(self.writer, "Content-Length: {}\r\n\r\n", body.len()).map_err(io_error)?;
+        self.writer.write_all(&body).map_err(io_error)?;
+        self.writer.flush().map_err(io_error)
+    }
+}
+
+impl Session {
+    /// Run the call and report its result, ending the debug session.
+    fn complete(&mut self) -> Vec<Value> {
+        self.pending = false;
+        let result = self
+            .engine
+            .execute_without_breakpoints(&self.function, self.args.as_deref());
+        let (report, exit_code) = match result {
+            Ok(output) => (output_event("stdout", output), 0),
+            Err(e) => (output_event("stderr", e.to_string()), 1),
+        };
+        vec![
+            report,
+            event("exited", json!({ "exitCode": exit_code })),
+            event("terminated", json!({})),
+        ]
+    }
+}
+
+fn apply_breakpoint(engine: &mut DebuggerEngine, bp: &FunctionBreakpoint) -> Result<()> {
+    let spec = match &bp.condition {
+        Some(condition) => format!("{} when {}", bp.name, condition),
+        None => bp.name.clone(),
+    };
+    engine.add_breakpoint_spec(&spec)?;
+    if let Some(hit_condition) = &bp.hit_condition {
+        let hit_condition = BreakpointManager::parse_hit_condition(hit_condition)?;
+        if let Some(breakpoint) = engine.breakpoints_mut().get_mut(&bp.name) {
+            breakpoint.hit_condition = Some(hit_condition);
+        }
+    }
+    Ok(())
+}
+
+fn event(name: &str, body: Value) -> Value {
+    json!({ "type": "event", "event": name, "body": body })
+}
+
+fn stopped(reason: &str, function: Option<&str>) -> Value {
+    let mut body = json!({
+        "reason": reason,
+        "threadId": THREAD_ID,
+        "allThreadsStopped": true,
+    });
+    if let Some(function) = function {
+        body["description"] = json!(format!("Paused at {}", function));
+    }
+    event("stopped", body)
+}
+
+fn output(category: &str, text: String) -> Value {
+    event("output", json!({ "category": category, "output": text }))
+}
+
+fn output_event(category: &str, text: String) -> Value {
+    output(category, format!("{}\n", text))
+}
+
+fn variable(name: String, value: String) -> Value {
+    json!({ "name": name, "value": value, "variablesReference": 0 })
+}
+
+fn io_error(e: std::io::Error) -> miette::Report {
+    DebuggerError::IoError(e.to_string()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(message: Value) -> String {
+        let body = message.to_string();
+        format!("Content-Length: {}\r\n\r\n{}", body.len(), body)
+    }
+
+    fn request(seq: i64, command: &str, arguments: Value) -> String {
+        frame(json!({
+            "seq": seq,
+            "type": "request",
+            "command": command,
+            "arguments": arguments,
+        }))
+    }
+
+    fn parse_messages(mut output: &[u8]) -> Vec<Value> {
+        let mut messages = Vec::new();
+        let mut server = DapServer::new(&mut output, Vec::new());
+        while let Some(message) = server.read_message().unwrap() {
+            messages.push(message);
+        }
+        messages
+    }
+
+    #[test]
+    fn frames_responses_and_rejects_unknown_requests() {
+        let input = [
+            request(1, "initialize", json!({ "adapterID": "soroban" })),
+            request(2, "evaluate", json!({ "expression": "1" })),
+            request(3, "threads", Value::Null),
+            request(4, "disconnect", Value::Null),
+        ]
+        .concat();
+        let mut output = Vec::new();
+        DapServer::new(input.as_bytes(), &mut output).run().unwrap();
+
+        let messages = parse_messages(&output);
+        assert_eq!(messages.len(), 4);
+        assert_eq!(messages[0]["command"], "initialize");
+        assert_eq!(messages[0]["body"]["supportsFunctionBreakpoints"], true);
+        assert_eq!(messages[1]["success"], false);
+        assert_eq!(messages[2]["body"]["threads"][0]["id"], THREAD_ID);
+        assert_eq!(messages[3]["request_seq"], 4);
+        let seqs: Vec<i64> = messages
+            .iter()
+            .map(|m| m["seq"].as_i64().unwrap())
+            .collect();
+        assert_eq!(seqs, vec![1, 2, 3, 4]);
+    }
+}
@@ -49,6 +49,8 @@ pub struct DebugServer {
     last_disconnect: Option<std::time::Instant>,
     /// Log of successful reconnection events in the current session.
     reconnection_log: ReconnectionLog,
+    /// Upgrade connections to WebSocket instead of newline-delimited JSON.
+    websocket: bool,
 }
 
 struct PendingExecution {
@@ -63,6 +65,68 @@ struct SessionContext {
 
 static SESSION_COUNTER: AtomicU64 = AtomicU64::new(1);
 
+/// Channels between a connection's transport tasks and its request loop.
+struct ConnectionChannels {
+    incoming: tokio::sync::mpsc::UnboundedReceiver<String>,
+    outgoing: tokio::sync::mpsc::UnboundedSender<DebugMessage>,
+    is_executing: Arc<std::sync::atomic::AtomicBool>,
+    router: IncomingRouter,
+}
+
+impl ConnectionChannels {
+    /// The channels plus the receiver the transport's writer task drains.
+    fn new() -> (Self, tokio::sync::mpsc::UnboundedReceiver<DebugMessage>) {
+        let (tx_in, rx_in) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let (tx_out, rx_out) = tokio::sync::mpsc::unbounded_channel::<DebugMessage>();
+        let is_executing = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let router = IncomingRouter {
+            incoming: tx_in,
+            outgoing: tx_out.clone(),
+            is_executing: Arc::clone(&is_executing),
+        };
+        let channels = Self {
+            incoming: rx_in,
+            outgoing: tx_out,
+            is_executing,
+            router,
+        };
+        (channels, rx_out)
+    }
+
+    fn router(&self) -> IncomingRouter {
+        self.router.clone()
+    }
+}
+
+/// Forwards messages from a transport's reader task to the request loop.
+/// `Cancel` is answered here because the loop is blocked while a contract
+/// executes.
+#[derive(Clone)]
+struct IncomingRouter {
+    incoming: tokio::sync::mpsc::UnboundedSender<String>,
+    outgoing: tokio::sync::mpsc::UnboundedSender<DebugMessage>,
+    is_executing: Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl IncomingRouter {
+    /// Returns false once the request loop has gone away.
+    async fn route(&self, line: String) -> bool {
+        if let Ok(msg) = DebugMessage::parse(line.trim_end()) {
+            if matches!(msg.request, Some(DebugRequest::Cancel)) {
+                let response = DebugMessage::response(msg.id, DebugResponse::CancelAck);
+                let _ = self.outgoing.send(response);
+                if self.is_executing.load(Ordering::SeqCst) {
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                    eprintln!("Execution cancelled via request. Aborting with exit code 125.");
+                    std::process::exit(125);
+                }
+                return true;
+            }
+        }
+        self.incoming.send(line).is_ok()
+    }
+}
+
 impl DebugServer {
     pub fn new(
         host: String,
@@ -98,15 +162,26 @@ impl DebugServer {
             session_id: Uuid::new_v4().to_string(),
             last_disconnect: None,
             reconnection_log: ReconnectionLog::new(),
+            websocket: false,
         })
     }
 
+    /// Serve the protocol over WebSocket text frames (`--ws`).
+    pub fn with_websocket(mut self, enabled: bool) -> Self {
+        self.websocket = enabled;
+        self
+    }
+
     pub async fn run(mut self, port: u16) -> Result<()> {
         let addr = format!("{}:{}", self.host, port);
         let listener = TcpListener::bind(&addr)
             .await
             .map_err(|e| miette::miette!("Failed to bind to {}: {}", addr, e))?;
-        info!("Debug server listening on {}", addr);
+        info!(
+            "Debug server listening on {}{}",
+            addr,
+            if self.websocket { " (WebSocket)" } else { "" }
+        );
         if self.token.is_some() && self.tls_config.is_none() {
             warn!(
                 "Token authentication is enabled without TLS. Treat this as plaintext transport and \
@@ -132,13 +207,13 @@ impl DebugServer {
                             if let Some(ref acceptor) = acceptor {
                                 match acceptor.accept(stream).await {
                                     Ok(tls_stream) => {
-                                        if let Err(e) = self.handle_single_connection(tls_stream, &peer).await {
+                                        if let Err(e) = self.handle_connection(tls_stream, &peer).await {
                                             error!("TLS connection error: {}", e);
                                         }
                                     }
                                     Err(e) => error!("TLS accept error: {}", e),
                                 }
-                            } else if let Err(e) = self.handle_single_connection(stream, &peer).await {
+                            } else if let Err(e) = self.handle_connection(stream, &peer).await {
                                 error!("TCP connection error: {}", e);
                             }
                         }
@@ -156,33 +231,27 @@ impl DebugServer {
         Ok(())
     }
 
-    async fn handle_single_connection<S>(&mut self, stream: S, peer_addr: &str) -> Result<()>
+    async fn handle_connection<S>(&mut self, stream: S, peer_addr: &str) -> Result<()>
     where
         S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + Send + 'static,
     {
-        // Check if a previously "parked" session has expired before we do anything else.
-        if let Some(instant) = self.last_disconnect {
-            if instant.elapsed().as_secs() > SESSION_GRACE_PERIOD_SECS {
-                info!(
-                    "Previous session {} expired after {} seconds. Resetting session state.",
-                    self.session_id, SESSION_GRACE_PERIOD_SECS
-                );
-                self.engine = None;
-                self.pending_execution = None;
-                self.contract_wasm = None;
-                self.last_disconnect = None;
-                // Generate a new session id for this fresh connection
-                self.session_id = Uuid::new_v4().to_string();
-            }
+        if self.websocket {
+            let (socket, authenticated) =
+                crate::server::websocket::accept(stream, self.token.as_deref()).await?;
+            self.handle_websocket_connection(socket, peer_addr, authenticated)
+                .await
+        } else {
+            self.handle_single_connection(stream, peer_addr).await
         }
+    }
 
-        let mut authenticated = self.token.is_none();
-        let mut handshake_done = false;
+    async fn handle_single_connection<S>(&mut self, stream: S, peer_addr: &str) -> Result<()>
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + Send + 'static,
+    {
         let (reader, writer) = tokio::io::split(stream);
         let mut reader = tokio::io::BufReader::new(reader);
-
-        let (tx_in, mut rx_in) = tokio::sync::mpsc::unbounded_channel::<String>();
-        let (tx_out, mut rx_out) = tokio::sync::mpsc::unbounded_channel::<DebugMessage>();
+        let (channels, mut rx_out) = ConnectionChannels::new();
 
         tokio::spawn(async move {
             let mut writer = writer;
@@ -199,40 +268,99 @@ impl DebugServer {
             }
         });
 
-        let tx_out_reader = tx_out.clone();
-        let is_executing = Arc::new(std::sync::atomic::AtomicBool::new(false));
-        let is_executing_reader = Arc::clone(&is_executing);
-
+        let router = channels.router();
         tokio::spawn(async move {
             let mut line = String::new();
             loop {
                 line.clear();
                 let n = reader.read_line(&mut line).await.unwrap_or(0);
-                if n == 0 {
+                if n == 0 || !router.route(line.clone()).await {
                     break;
                 }
+            }
+        });
 
-                if let Ok(msg) = DebugMessage::parse(line.trim_end()) {
-                    if matches!(msg.request, Some(DebugRequest::Cancel)) {
-                        let response = DebugMessage::response(msg.id, DebugResponse::CancelAck);
-                        let _ = tx_out_reader.send(response);
-                        if is_executing_reader.load(std::sync::atomic::Ordering::SeqCst) {
-                            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
-                            eprintln!(
-                                "Execution cancelled via request. Aborting with exit code 125."
-                            );
-                            std::process::exit(125);
-                        }
-                        continue;
-                    }
-                }
+        self.serve_connection(channels, peer_addr, false).await
+    }
 
-                if tx_in.send(line.clone()).is_err() {
+    /// Same request loop as the raw socket, with one message per text frame.
+    async fn handle_websocket_connection<S>(
+        &mut self,
+        socket: tokio_tungstenite::WebSocketStream<S>,
+        peer_addr: &str,
+        authenticated: bool,
+    ) -> Result<()>
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + Send + 'static,
+    {
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (mut sink, mut frames) = socket.split();
+        let (channels, mut rx_out) = ConnectionChannels::new();
+
+        tokio::spawn(async move {
+            while let Some(msg) = rx_out.recv().await {
+                let Ok(json) = msg.encode() else { continue };
+                if sink.send(Message::Text(json)).await.is_err() {
                     break;
                 }
             }
+            let _ = sink.close().await;
         });
 
+        let router = channels.router();
+        tokio::spawn(async move {
+            while let Some(Ok(frame)) = frames.next().await {
+                match frame {
+                    Message::Text(text) => {
+                        if !router.route(text).await {
+                            break;
+                        }
+                    }
+                    Message::Close(_) => break,
+                    _ => {}
+                }
+            }
+        });
+
+        self.serve_connection(channels, peer_addr, authenticated)
+            .await
+    }
+
+    /// Handle requests from one client until it disconnects. `authenticated`
+    /// is set when the transport already verified the token.
+    async fn serve_connection(
+        &mut self,
+        channels: ConnectionChannels,
+        peer_addr: &str,
+        authenticated: bool,
+    ) -> Result<()> {
+        // Check if a previously "parked" session has expired before we do anything else.
+        if let Some(instant) = self.last_disconnect {
+            if instant.elapsed().as_secs() > SESSION_GRACE_PERIOD_SECS {
+                info!(
+                    "Previous session {} expired after {} seconds. Resetting session state.",
+                    self.session_id, SESSION_GRACE_PERIOD_SECS
+                );
+                self.engine = None;
+                self.pending_execution = None;
+                self.contract_wasm = None;
+                self.last_disconnect = None;
+                // Generate a new session id for this fresh connection
+                self.session_id = Uuid::new_v4().to_string();
+            }
+        }
+
+        let mut authenticated = authenticated || self.token.is_none();
+        let mut handshake_done = false;
+        let ConnectionChannels {
+            incoming: mut rx_in,
+            outgoing: tx_out,
+            is_executing,
+            ..
+        } = channels;
+
         // Helper closure to abstract away tx_out
         let send_msg = |msg: DebugMessage| -> Result<()> {
             tx_out
@@ -638,7 +766,7 @@ impl DebugServer {
                                 let breakpoints = self
                                     .engine
                                     .as_ref()
-                                    .map(|e| e.breakpoints().list())
+                                    .map(|e| e.breakpoints().specs())
                                     .unwrap_or_default();
                                 let initial_storage = self
                                     .engine
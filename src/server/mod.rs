@@ -1,7 +1,10 @@
 //! Canonical remote debug server exports.
 
+pub mod dap;
 pub mod debug_server;
 pub mod protocol;
+pub mod websocket;
 
+pub use dap::DapServer;
 pub use debug_server::DebugServer;
 pub use protocol::{DebugMessage, DebugRequest, DebugResponse};
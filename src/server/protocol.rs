@@ -462,6 +462,12 @@ impl DebugMessage {
         self.id == expected_id && self.response.is_some()
     }
 
+    /// Serialize to the JSON carried by both transports: one line on the raw
+    /// socket, one text frame on WebSocket.
+    pub fn encode(&self) -> std::result::Result<String, String> {
+        serde_json::to_string(self).map_err(|e| e.to_string())
+    }
+
     /// Parse a JSON string into a DebugMessage with field-aware error reporting.
     pub fn parse(json: &str) -> std::result::Result<Self, String> {
         let deserializer = &mut serde_json::Deserializer::from_str(json);
@@ -480,7 +486,7 @@ pub async fn send_response<S>(
 where
     S: tokio::io::AsyncWrite + Unpin,
 {
-    let json = serde_json::to_string(&response).map_err(|e| e.to_string())?;
+    let json = response.encode()?;
     writer
         .write_all(json.as_bytes())
         .await
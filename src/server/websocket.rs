@@ -0,0 +1,68 @@
+//! WebSocket transport for the remote debug server.
+//!
+//! Browser-based tooling cannot open the raw newline-delimited socket, so with
+//! `--ws` the server upgrades each connection to WebSocket and carries every
+//! [`DebugMessage`](super::DebugMessage) as one text frame. Both transports
+//! feed the same request loop in [`super::DebugServer`].
+//!
+//! A client may present the server token as `Authorization: Bearer <token>`
+//! during the upgrade. A correct token authenticates the connection up front;
+//! a wrong one is refused with `401 Unauthorized`. Clients that cannot set
+//! headers (browsers) omit it and send an `Authenticate` request instead.
+
+use crate::{DebuggerError, Result};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::{header::AUTHORIZATION, StatusCode};
+use tokio_tungstenite::WebSocketStream;
+
+/// Complete the WebSocket upgrade on `stream`, checking any `Authorization`
+/// header against `token`. Returns the socket and whether the header
+/// authenticated the connection.
+pub(crate) async fn accept<S>(stream: S, token: Option<&str>) -> Result<(WebSocketStream<S>, bool)>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut authenticated = false;
+    let check_token = |request: &Request, response: Response| {
+        let (Some(expected), Some(header)) = (token, request.headers().get(AUTHORIZATION)) else {
+            return Ok(response);
+        };
+        let presented = header.to_str().map(bearer_token).unwrap_or_default();
+        if presented == expected {
+            authenticated = true;
+            Ok(response)
+        } else {
+            let mut rejection = ErrorResponse::new(Some("Authentication failed".to_string()));
+            *rejection.status_mut() = StatusCode::UNAUTHORIZED;
+            Err(rejection)
+        }
+    };
+
+    let socket = tokio_tungstenite::accept_hdr_async(stream, check_token)
+        .await
+        .map_err(|e| DebuggerError::NetworkError(format!("WebSocket handshake failed: {}", e)))?;
+    Ok((socket, authenticated))
+}
+
+/// The token in an `Authorization` header value, with or without the
+/// `Bearer` scheme.
+pub(crate) fn bearer_token(value: &str) -> &str {
+    let value = value.trim();
+    match value.split_once(' ') {
+        Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") => token.trim(),
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bearer_token_accepts_scheme_or_bare_token() {
+        assert_eq!(bearer_token("Bearer abc123"), "abc123");
+        assert_eq!(bearer_token("bearer  abc123 "), "abc123");
+        assert_eq!(bearer_token("abc123"), "abc123");
+    }
+}
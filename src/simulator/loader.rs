@@ -5,16 +5,207 @@
 
 use super::state::{AccountState, ContractState, NetworkSnapshot};
 use crate::Result;
+use sha2::{Digest, Sha256};
+use soroban_env_host::xdr::{Limits, ScVal, WriteXdr};
 use std::fs;
-use std::path::Path;
-use tracing::{debug, info};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// How long `--rpc-url` state is served from the cache before it is fetched
+/// again, unless `--rpc-cache-ttl` says otherwise. Matches that flag's default,
+/// which args.rs spells as a literal so the build script can include it.
+pub const RPC_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Where a loaded snapshot came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotOrigin {
+    /// Read from a local file
+    File,
+    /// Downloaded from a URL
+    Network,
+    /// Served from the local URL cache
+    Cache,
+}
+
+impl std::fmt::Display for SnapshotOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotOrigin::File => write!(f, "file"),
+            SnapshotOrigin::Network => write!(f, "network"),
+            SnapshotOrigin::Cache => write!(f, "cache"),
+        }
+    }
+}
+
+/// Retry, timeout and cache settings for loading snapshots from URLs
+#[derive(Debug, Clone)]
+pub struct UrlFetchConfig {
+    /// Total number of download attempts (at least one is always made)
+    pub attempts: u32,
+    /// Timeout for a single attempt
+    pub timeout: Duration,
+    /// Delay before the first retry; doubled after every failed attempt
+    pub initial_backoff: Duration,
+    /// Directory for cached downloads, `None` disables caching
+    pub cache_dir: Option<PathBuf>,
+    /// Age after which a cached copy is fetched again, `None` keeps it forever
+    pub cache_ttl: Option<Duration>,
+}
+
+impl Default for UrlFetchConfig {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            timeout: Duration::from_secs(30),
+            initial_backoff: Duration::from_millis(500),
+            cache_dir: dirs::cache_dir().map(|dir| dir.join("soroban-debug").join("snapshots")),
+            cache_ttl: None,
+        }
+    }
+}
+
+impl UrlFetchConfig {
+    /// Temp directory holding snapshots fetched with `--rpc-url`
+    pub fn rpc_cache_dir() -> PathBuf {
+        std::env::temp_dir()
+            .join("soroban-debug")
+            .join("rpc-snapshots")
+    }
+
+    /// Cache file used for `url`, keyed by the SHA-256 of the URL
+    pub fn cache_path(&self, url: &str) -> Option<PathBuf> {
+        let digest = Sha256::digest(url.as_bytes());
+        self.cache_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}.json", hex::encode(digest))))
+    }
+
+    /// Whether the cache file at `path` exists and is younger than `cache_ttl`
+    fn is_fresh(&self, path: &Path) -> bool {
+        let Some(ttl) = self.cache_ttl else {
+            return path.exists();
+        };
+        fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age <= ttl)
+    }
+}
 
 /// Loads and applies network snapshots to a debug environment
 pub struct SnapshotLoader {
     snapshot: NetworkSnapshot,
+    origin: SnapshotOrigin,
 }
 
 impl SnapshotLoader {
+    /// Load a snapshot from a local file or, for `http://` and `https://` sources, a URL
+    pub fn load<P: AsRef<Path>>(source: P, config: &UrlFetchConfig) -> Result<Self> {
+        let source = source.as_ref();
+        match source.to_str() {
+            Some(url) if is_url(url) => Self::from_url(url, config),
+            _ => Self::from_file(source),
+        }
+    }
+
+    /// Load a snapshot from a URL, using the local cache when it holds a valid copy
+    pub fn from_url(url: &str, config: &UrlFetchConfig) -> Result<Self> {
+        let cache_path = config.cache_path(url);
+
+        if let Some(path) = cache_path.as_ref().filter(|p| config.is_fresh(p)) {
+            match Self::from_file(path) {
+                Ok(mut loader) => {
+                    info!("Using cached snapshot for {} ({:?})", url, path);
+                    loader.origin = SnapshotOrigin::Cache;
+                    return Ok(loader);
+                }
+                Err(e) => warn!("Ignoring unreadable cached snapshot {:?}: {}", path, e),
+            }
+        }
+
+        let contents = fetch_with_retry(url, None, config)?;
+        let snapshot = parse_snapshot(&contents)?;
+
+        if let Some(path) = &cache_path {
+            let cached = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(path, &contents));
+            if let Err(e) = cached {
+                warn!("Failed to cache snapshot at {:?}: {}", path, e);
+            }
+        }
+
+        Ok(Self {
+            snapshot,
+            origin: SnapshotOrigin::Network,
+        })
+    }
+
+    /// Fetch `contract_id`'s ledger state from the Soroban RPC server at `url`,
+    /// caching the result in the temp directory for [`RPC_CACHE_TTL`]
+    pub fn from_rpc(url: &str, contract_id: &str) -> Result<Self> {
+        let config = UrlFetchConfig {
+            cache_dir: Some(UrlFetchConfig::rpc_cache_dir()),
+            cache_ttl: Some(RPC_CACHE_TTL),
+            ..Default::default()
+        };
+        Self::from_rpc_with_config(url, contract_id, &[], &config)
+    }
+
+    /// Fetch `contract_id`'s instance and its persistent `storage_keys` from
+    /// the Soroban RPC server at `url`, using the cached copy in
+    /// `config.cache_dir` while it is fresh
+    pub fn from_rpc_with_config(
+        url: &str,
+        contract_id: &str,
+        storage_keys: &[ScVal],
+        config: &UrlFetchConfig,
+    ) -> Result<Self> {
+        let mut cache_key = format!("{}#{}", url, contract_id);
+        for key in storage_keys {
+            let xdr = key.to_xdr_base64(Limits::none()).map_err(|e| {
+                crate::DebuggerError::InvalidArguments(format!("Invalid storage key: {}", e))
+            })?;
+            cache_key.push('#');
+            cache_key.push_str(&xdr);
+        }
+        let cache_path = config.cache_path(&cache_key);
+
+        if let Some(path) = cache_path.as_ref().filter(|p| config.is_fresh(p)) {
+            match Self::from_file(path) {
+                Ok(mut loader) => {
+                    info!("Using cached RPC snapshot for {} ({:?})", contract_id, path);
+                    loader.origin = SnapshotOrigin::Cache;
+                    return Ok(loader);
+                }
+                Err(e) => warn!("Ignoring unreadable cached snapshot {:?}: {}", path, e),
+            }
+        }
+
+        let snapshot = super::rpc::fetch_contract_snapshot(url, contract_id, storage_keys, config)?;
+
+        if let Some(path) = &cache_path {
+            let cached = serde_json::to_string_pretty(&snapshot)
+                .map_err(std::io::Error::from)
+                .and_then(|json| {
+                    path.parent()
+                        .map_or(Ok(()), fs::create_dir_all)
+                        .and_then(|_| fs::write(path, json))
+                });
+            if let Err(e) = cached {
+                warn!("Failed to cache snapshot at {:?}: {}", path, e);
+            }
+        }
+
+        Ok(Self {
+            snapshot,
+            origin: SnapshotOrigin::Network,
+        })
+    }
+
     /// Load a snapshot from a JSON file
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
         let path = path.as_ref();
@@ -28,28 +219,26 @@ impl SnapshotLoader {
             ))
         })?;
 
-        // Parse JSON
-        let snapshot: NetworkSnapshot = serde_json::from_str(&contents).map_err(|e| {
-            crate::DebuggerError::FileError(format!("Failed to parse snapshot JSON: {}", e))
-        })?;
-
-        // Validate the snapshot
-        snapshot.validate()?;
+        let snapshot = parse_snapshot(&contents)?;
 
-        info!(
-            "Snapshot loaded: {} accounts, {} contracts, ledger seq={}",
-            snapshot.accounts.len(),
-            snapshot.contracts.len(),
-            snapshot.ledger.sequence
-        );
-
-        Ok(Self { snapshot })
+        Ok(Self {
+            snapshot,
+            origin: SnapshotOrigin::File,
+        })
     }
 
     /// Create a snapshot from a NetworkSnapshot struct directly
     pub fn from_snapshot(snapshot: NetworkSnapshot) -> Result<Self> {
         snapshot.validate()?;
-        Ok(Self { snapshot })
+        Ok(Self {
+            snapshot,
+            origin: SnapshotOrigin::File,
+        })
+    }
+
+    /// Where this snapshot was loaded from
+    pub fn origin(&self) -> &SnapshotOrigin {
+        &self.origin
     }
 
     /// Get reference to the underlying snapshot
@@ -76,6 +265,7 @@ impl SnapshotLoader {
             network_passphrase: self.snapshot.ledger.network_passphrase.clone(),
             account_count: self.snapshot.accounts.len(),
             contract_count: self.snapshot.contracts.len(),
+            origin: self.origin.clone(),
         };
 
         debug!("Snapshot info: {:?}", snapshot_info);
@@ -169,6 +359,11 @@ impl LoadedSnapshot {
         self.info.contract_count
     }
 
+    /// Get where the snapshot was loaded from
+    pub fn origin(&self) -> &SnapshotOrigin {
+        &self.info.origin
+    }
+
     /// Get underlying snapshot
     pub fn snapshot(&self) -> &NetworkSnapshot {
         &self.snapshot
@@ -187,12 +382,14 @@ impl LoadedSnapshot {
             Timestamp: {}\n  \
             Network: {}\n  \
             Accounts: {}\n  \
-            Contracts: {}",
+            Contracts: {}\n  \
+            Source: {}",
             self.info.ledger_sequence,
             self.info.ledger_timestamp,
             self.info.network_passphrase,
             self.info.account_count,
-            self.info.contract_count
+            self.info.contract_count,
+            self.info.origin
         )
     }
 }
@@ -205,6 +402,72 @@ pub struct SnapshotInfo {
     network_passphrase: String,
     account_count: usize,
     contract_count: usize,
+    origin: SnapshotOrigin,
+}
+
+fn is_url(source: &str) -> bool {
+    source.starts_with("http://") || source.starts_with("https://")
+}
+
+/// Parse and validate snapshot JSON
+fn parse_snapshot(contents: &str) -> Result<NetworkSnapshot> {
+    let snapshot: NetworkSnapshot = serde_json::from_str(contents).map_err(|e| {
+        crate::DebuggerError::FileError(format!("Failed to parse snapshot JSON: {}", e))
+    })?;
+
+    snapshot.validate()?;
+
+    info!(
+        "Snapshot loaded: {} accounts, {} contracts, ledger seq={}",
+        snapshot.accounts.len(),
+        snapshot.contracts.len(),
+        snapshot.ledger.sequence
+    );
+
+    Ok(snapshot)
+}
+
+/// Download `url`, retrying with exponential backoff. With `json_body` the
+/// request is a JSON POST (as for Soroban RPC calls), otherwise a GET.
+pub(super) fn fetch_with_retry(
+    url: &str,
+    json_body: Option<&str>,
+    config: &UrlFetchConfig,
+) -> Result<String> {
+    let agent = ureq::AgentBuilder::new().timeout(config.timeout).build();
+    let attempts = config.attempts.max(1);
+    let mut backoff = config.initial_backoff;
+    let mut last_error = String::new();
+
+    for attempt in 1..=attempts {
+        info!("Requesting {} (attempt {}/{})", url, attempt, attempts);
+        let response = match json_body {
+            Some(body) => agent
+                .post(url)
+                .set("Content-Type", "application/json")
+                .send_string(body),
+            None => agent.get(url).call(),
+        };
+        match response.map(|r| r.into_string()) {
+            Ok(Ok(body)) => return Ok(body),
+            Ok(Err(e)) => last_error = e.to_string(),
+            Err(e) => last_error = e.to_string(),
+        }
+        if attempt < attempts {
+            warn!(
+                "Request to {} failed: {}; retrying in {:?}",
+                url, last_error, backoff
+            );
+            std::thread::sleep(backoff);
+            backoff *= 2;
+        }
+    }
+
+    Err(crate::DebuggerError::NetworkError(format!(
+        "Failed to fetch {} after {} attempt(s): {}",
+        url, attempts, last_error
+    ))
+    .into())
 }
 
 #[cfg(test)]
@@ -225,6 +488,103 @@ mod tests {
         assert_eq!(loader.snapshot().ledger.sequence, 100);
     }
 
+    #[test]
+    fn test_from_url_prefers_cached_copy() {
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let config = UrlFetchConfig {
+            attempts: 1,
+            timeout: Duration::from_millis(100),
+            initial_backoff: Duration::ZERO,
+            cache_dir: Some(cache_dir.path().to_path_buf()),
+            cache_ttl: None,
+        };
+        let url = "http://127.0.0.1:9/snapshot.json";
+        let snapshot = NetworkSnapshot::new(42, "Test Network", 1234567890);
+        fs::write(
+            config.cache_path(url).unwrap(),
+            serde_json::to_string(&snapshot).unwrap(),
+        )
+        .unwrap();
+
+        let loader = SnapshotLoader::load(url, &config).unwrap();
+        assert_eq!(loader.origin(), &SnapshotOrigin::Cache);
+        let loaded = loader.apply_to_environment().unwrap();
+        assert_eq!(loaded.ledger_sequence(), 42);
+        assert!(loaded.format_summary().contains("Source: cache"));
+    }
+
+    #[test]
+    fn test_from_rpc_prefers_cached_copy() {
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let config = UrlFetchConfig {
+            attempts: 1,
+            timeout: Duration::from_millis(100),
+            initial_backoff: Duration::ZERO,
+            cache_dir: Some(cache_dir.path().to_path_buf()),
+            cache_ttl: None,
+        };
+        let url = "http://127.0.0.1:9";
+        let contract_id = "CADQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQP5KR";
+        let mut snapshot = NetworkSnapshot::new(7, "Test Network", 1234567890);
+        snapshot
+            .add_contract(ContractState::new(contract_id, "aabbccdd"))
+            .unwrap();
+        fs::write(
+            config
+                .cache_path(&format!("{}#{}", url, contract_id))
+                .unwrap(),
+            serde_json::to_string(&snapshot).unwrap(),
+        )
+        .unwrap();
+
+        let loader = SnapshotLoader::from_rpc_with_config(url, contract_id, &[], &config).unwrap();
+        assert_eq!(loader.origin(), &SnapshotOrigin::Cache);
+        assert_eq!(loader.list_contracts(), vec![contract_id]);
+    }
+
+    #[test]
+    fn test_expired_rpc_cache_is_fetched_again() {
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let config = UrlFetchConfig {
+            attempts: 1,
+            timeout: Duration::from_millis(100),
+            initial_backoff: Duration::ZERO,
+            cache_dir: Some(cache_dir.path().to_path_buf()),
+            cache_ttl: Some(Duration::ZERO),
+        };
+        let url = "http://127.0.0.1:9";
+        let contract_id = "CADQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQP5KR";
+        let snapshot = NetworkSnapshot::new(7, "Test Network", 1234567890);
+        fs::write(
+            config
+                .cache_path(&format!("{}#{}", url, contract_id))
+                .unwrap(),
+            serde_json::to_string(&snapshot).unwrap(),
+        )
+        .unwrap();
+        std::thread::sleep(Duration::from_millis(10));
+
+        let err = SnapshotLoader::from_rpc_with_config(url, contract_id, &[], &config)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("after 1 attempt(s)"));
+    }
+
+    #[test]
+    fn test_from_url_reports_failure_after_retries() {
+        let config = UrlFetchConfig {
+            attempts: 2,
+            timeout: Duration::from_millis(100),
+            initial_backoff: Duration::ZERO,
+            cache_dir: None,
+            cache_ttl: None,
+        };
+        let err = SnapshotLoader::from_url("http://127.0.0.1:9/snapshot.json", &config)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("after 2 attempt(s)"));
+    }
+
     #[test]
     fn test_validation_passes_for_valid_snapshot() {
         let snapshot = NetworkSnapshot::new(100, "Test Network", 1234567890);
@@ -3,14 +3,16 @@
 //! This module provides comprehensive network state simulation for Soroban debugging.
 //! It allows users to:
 //! - Load network snapshots from JSON files
+//! - Fetch live contract state from a Soroban RPC endpoint
 //! - Configure mock ledger state (accounts, contracts, balances)
 //! - Pre-deploy contract instances with populated storage
 //! - Save and restore ledger state for iterative debugging
 
 pub mod loader;
+pub mod rpc;
 pub mod snapshot;
 pub mod state;
 
-pub use loader::{LoadedSnapshot, SnapshotLoader};
-pub use snapshot::{AccountDiff, ContractDiff, SnapshotDiff, SnapshotManager};
+pub use loader::{LoadedSnapshot, SnapshotLoader, SnapshotOrigin, UrlFetchConfig};
+pub use snapshot::{AccountDiff, ContractDiff, SnapshotDiff, SnapshotManager, StorageChange};
 pub use state::{AccountState, ContractState, LedgerMetadata, NetworkSnapshot, SimulatorError};
@@ -0,0 +1,349 @@
+//! Live contract state from a Soroban RPC endpoint
+//!
+//! Builds a [`NetworkSnapshot`] for a single contract from the `getNetwork`
+//! and `getLedgerEntries` JSON-RPC methods, so a run can start from the
+//! state the contract currently has on chain.
+
+use super::loader::{fetch_with_retry, UrlFetchConfig};
+use super::snapshot::recorded_value;
+use super::state::{ContractState, NetworkSnapshot, SimulatorError};
+use crate::{DebuggerError, Result};
+use soroban_env_host::xdr::{
+    ContractDataDurability, ContractExecutable, LedgerEntryData, LedgerKey, LedgerKeyContractData,
+    Limits, ReadXdr, ScAddress, ScVal, WriteXdr,
+};
+use soroban_sdk::TryFromVal;
+use std::str::FromStr;
+use tracing::{info, warn};
+
+/// Fetch the ledger state of `contract_id` from the Soroban RPC server at `url`.
+///
+/// The contract instance entry and the persistent entries under
+/// `storage_keys` are fetched with one `getLedgerEntries` call: the instance's
+/// executable provides the WASM hash, and its instance storage and the
+/// persistent entries become the contract's storage. `getLedgerEntries` only
+/// returns entries for keys the caller names, so persistent entries that are
+/// not listed in `storage_keys` are not fetched.
+pub fn fetch_contract_snapshot(
+    url: &str,
+    contract_id: &str,
+    storage_keys: &[ScVal],
+    config: &UrlFetchConfig,
+) -> Result<NetworkSnapshot> {
+    let address = contract_address(contract_id)?;
+
+    let network = call_rpc(url, "getNetwork", serde_json::json!({}), config)?;
+    let passphrase = network
+        .get("passphrase")
+        .and_then(|p| p.as_str())
+        .ok_or_else(|| SimulatorError::RpcError("getNetwork returned no passphrase".to_string()))?
+        .to_string();
+
+    let keys = std::iter::once(&ScVal::LedgerKeyContractInstance)
+        .chain(storage_keys)
+        .map(|key| {
+            LedgerKey::ContractData(LedgerKeyContractData {
+                contract: address.clone(),
+                key: key.clone(),
+                durability: ContractDataDurability::Persistent,
+            })
+            .to_xdr_base64(Limits::none())
+            .map_err(|e| SimulatorError::RpcError(format!("Failed to encode ledger key: {}", e)))
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let entries = call_rpc(
+        url,
+        "getLedgerEntries",
+        serde_json::json!({ "keys": keys }),
+        config,
+    )?;
+
+    let latest_ledger = entries
+        .get("latestLedger")
+        .and_then(|l| l.as_u64())
+        .ok_or_else(|| {
+            SimulatorError::RpcError("getLedgerEntries returned no latestLedger".to_string())
+        })?;
+    let mut instance = None;
+    let mut persistent = Vec::new();
+    for entry in entries
+        .get("entries")
+        .and_then(|e| e.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let entry_xdr = entry
+            .get("xdr")
+            .and_then(|x| x.as_str())
+            .ok_or_else(|| SimulatorError::RpcError("Ledger entry has no xdr".to_string()))?;
+        let live_until = entry
+            .get("liveUntilLedgerSeq")
+            .and_then(|l| l.as_u64())
+            .map(|l| l as u32);
+        let entry = decode_entry(entry_xdr)?;
+        match &entry {
+            LedgerEntryData::ContractData(data) if data.key == ScVal::LedgerKeyContractInstance => {
+                instance = Some((entry, live_until));
+            }
+            _ => persistent.push((entry, live_until)),
+        }
+    }
+    let (entry, live_until) =
+        instance.ok_or_else(|| SimulatorError::ContractNotFound(contract_id.to_string()))?;
+    if persistent.len() < storage_keys.len() {
+        warn!(
+            "{} of {} requested storage keys have no persistent entry for {}",
+            storage_keys.len() - persistent.len(),
+            storage_keys.len(),
+            contract_id
+        );
+    }
+
+    // RPC does not report the close time of the latest ledger; the fetch time
+    // is close enough for timestamp-dependent contract logic.
+    let timestamp = chrono::Utc::now().timestamp().max(0) as u64;
+    let mut snapshot = NetworkSnapshot::new(latest_ledger as u32, passphrase, timestamp);
+    let mut contract = contract_state_from_instance(contract_id, &entry, live_until)?;
+    for (entry, live_until) in &persistent {
+        add_persistent_entry(&mut contract, entry, *live_until)?;
+    }
+    snapshot.add_contract(contract)?;
+
+    info!(
+        "Fetched contract {} and {} persistent entries from {} at ledger {}",
+        contract_id,
+        persistent.len(),
+        url,
+        latest_ledger
+    );
+    Ok(snapshot)
+}
+
+/// Parse a `--rpc-key` storage key, written like a `--args` value, to the
+/// `ScVal` used in its ledger key.
+pub fn parse_storage_key(json: &str) -> Result<ScVal> {
+    let env = soroban_sdk::Env::default();
+    let invalid =
+        |e: String| DebuggerError::InvalidArguments(format!("Invalid --rpc-key {}: {}", json, e));
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| invalid(e.to_string()))?;
+    let mut vals = crate::utils::ArgumentParser::new(env.clone())
+        .parse_args_string(&serde_json::Value::Array(vec![value]).to_string())
+        .map_err(|e| invalid(e.to_string()))?;
+    ScVal::try_from_val(&env, &vals.remove(0)).map_err(|e| invalid(format!("{:?}", e)).into())
+}
+
+fn contract_address(contract_id: &str) -> Result<ScAddress> {
+    match ScAddress::from_str(contract_id) {
+        Ok(address @ ScAddress::Contract(_)) => Ok(address),
+        _ => Err(SimulatorError::InvalidContractId(format!(
+            "Expected a contract strkey (C...), got '{}'",
+            contract_id
+        ))
+        .into()),
+    }
+}
+
+fn decode_entry(entry_xdr: &str) -> Result<LedgerEntryData> {
+    LedgerEntryData::from_xdr_base64(entry_xdr, Limits::none())
+        .map_err(|e| SimulatorError::RpcError(format!("Invalid ledger entry XDR: {}", e)).into())
+}
+
+/// Convert a contract instance ledger entry into a [`ContractState`], storing
+/// instance storage in the same `Instance:<key>` layout as
+/// [`SnapshotManager::capture`](super::SnapshotManager::capture).
+fn contract_state_from_instance(
+    contract_id: &str,
+    entry: &LedgerEntryData,
+    live_until: Option<u32>,
+) -> Result<ContractState> {
+    let instance = match entry {
+        LedgerEntryData::ContractData(data) => match &data.val {
+            ScVal::ContractInstance(instance) => instance,
+            other => {
+                return Err(SimulatorError::RpcError(format!(
+                    "Expected a contract instance, got {:?}",
+                    other
+                ))
+                .into())
+            }
+        },
+        other => {
+            return Err(SimulatorError::RpcError(format!(
+                "Expected contract data, got {:?}",
+                other
+            ))
+            .into())
+        }
+    };
+
+    let mut contract = match &instance.executable {
+        ContractExecutable::Wasm(hash) => ContractState::new(contract_id, hex::encode(hash.0)),
+        ContractExecutable::StellarAsset => {
+            let mut contract = ContractState::new(contract_id, hex::encode([0u8; 32]));
+            contract.set_wasm_ref("stellar-asset");
+            contract
+        }
+    };
+    for item in instance.storage.iter().flat_map(|map| map.iter()) {
+        contract.set_storage(
+            format!("Instance:{:?}", item.key),
+            recorded_value("instance", &item.key, &item.val, live_until)?,
+        );
+    }
+    Ok(contract)
+}
+
+/// Store a persistent contract data entry in the `Persistent:<key>` layout of
+/// [`SnapshotManager::capture`](super::SnapshotManager::capture).
+fn add_persistent_entry(
+    contract: &mut ContractState,
+    entry: &LedgerEntryData,
+    live_until: Option<u32>,
+) -> Result<()> {
+    let LedgerEntryData::ContractData(data) = entry else {
+        return Err(
+            SimulatorError::RpcError(format!("Expected contract data, got {:?}", entry)).into(),
+        );
+    };
+    contract.set_storage(
+        format!("{:?}:{:?}", data.durability, data.key),
+        recorded_value("persistent", &data.key, &data.val, live_until)?,
+    );
+    Ok(())
+}
+
+/// Issue a JSON-RPC call, retrying transport failures through [`fetch_with_retry`].
+fn call_rpc(
+    url: &str,
+    method: &str,
+    params: serde_json::Value,
+    config: &UrlFetchConfig,
+) -> Result<serde_json::Value> {
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    });
+    info!("Calling {} on {}", method, url);
+    let body = fetch_with_retry(url, Some(&request.to_string()), config)?;
+    let body: serde_json::Value = serde_json::from_str(&body)
+        .map_err(|e| SimulatorError::RpcError(format!("Invalid {} response: {}", method, e)))?;
+    rpc_result(method, body)
+}
+
+fn rpc_result(method: &str, mut body: serde_json::Value) -> Result<serde_json::Value> {
+    if let Some(error) = body.get("error") {
+        return Err(
+            SimulatorError::RpcError(format!("{} returned an error: {}", method, error)).into(),
+        );
+    }
+    match body.get_mut("result") {
+        Some(result) => Ok(result.take()),
+        None => Err(SimulatorError::RpcError(format!("{} response has no result", method)).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_env_host::xdr::{
+        ContractDataEntry, ExtensionPoint, Hash, ScContractInstance, ScMap, ScMapEntry, ScSymbol,
+    };
+    use std::time::Duration;
+
+    const CONTRACT_ID: &str = "CADQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQP5KR";
+
+    fn instance_entry(storage: Option<ScMap>) -> LedgerEntryData {
+        LedgerEntryData::ContractData(ContractDataEntry {
+            ext: ExtensionPoint::V0,
+            contract: ScAddress::Contract(Hash([7; 32])),
+            key: ScVal::LedgerKeyContractInstance,
+            durability: ContractDataDurability::Persistent,
+            val: ScVal::ContractInstance(ScContractInstance {
+                executable: ContractExecutable::Wasm(Hash([0xab; 32])),
+                storage,
+            }),
+        })
+    }
+
+    #[test]
+    fn test_instance_entry_becomes_contract_state() {
+        let storage = ScMap::sorted_from(vec![ScMapEntry {
+            key: ScVal::Symbol(ScSymbol("admin".try_into().unwrap())),
+            val: ScVal::U32(7),
+        }])
+        .unwrap();
+        let entry = decode_entry(
+            &instance_entry(Some(storage))
+                .to_xdr_base64(Limits::none())
+                .unwrap(),
+        )
+        .unwrap();
+
+        let contract = contract_state_from_instance(CONTRACT_ID, &entry, Some(500)).unwrap();
+
+        assert_eq!(contract.wasm_hash, "ab".repeat(32));
+        assert_eq!(contract.storage.len(), 1);
+        let (key, value) = contract.storage.iter().next().unwrap();
+        assert!(key.starts_with("Instance:") && key.contains("admin"));
+        assert_eq!(value["value"], "U32(7)");
+        assert_eq!(value["live_until_ledger"], 500);
+    }
+
+    #[test]
+    fn test_persistent_entry_is_stored_with_its_key() {
+        let key = parse_storage_key(r#"["Balance", 7]"#).unwrap();
+        let entry = LedgerEntryData::ContractData(ContractDataEntry {
+            ext: ExtensionPoint::V0,
+            contract: ScAddress::Contract(Hash([7; 32])),
+            key: key.clone(),
+            durability: ContractDataDurability::Persistent,
+            val: ScVal::U32(5),
+        });
+        let mut contract = ContractState::new(CONTRACT_ID, "ab".repeat(32));
+
+        add_persistent_entry(&mut contract, &entry, Some(900)).unwrap();
+
+        let (name, value) = contract.storage.iter().next().unwrap();
+        assert!(name.starts_with("Persistent:") && name.contains("Balance"));
+        assert_eq!(value["durability"], "persistent");
+        assert_eq!(value["key_xdr"], key.to_xdr_base64(Limits::none()).unwrap());
+        assert_eq!(value["live_until_ledger"], 900);
+    }
+
+    #[test]
+    fn test_rejects_invalid_storage_key() {
+        let err = parse_storage_key("{not json").unwrap_err();
+        assert!(err.to_string().contains("--rpc-key"));
+    }
+
+    #[test]
+    fn test_rejects_account_address_as_contract_id() {
+        let err = contract_address("GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7")
+            .unwrap_err();
+        assert!(err.to_string().contains("contract strkey"));
+    }
+
+    #[test]
+    fn test_rpc_error_response_is_reported() {
+        let body = serde_json::json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32601 } });
+        let err = rpc_result("getNetwork", body).unwrap_err();
+        assert!(err.to_string().contains("getNetwork returned an error"));
+    }
+
+    #[test]
+    fn test_unreachable_endpoint_reports_failure_after_retries() {
+        let config = UrlFetchConfig {
+            attempts: 2,
+            timeout: Duration::from_millis(100),
+            initial_backoff: Duration::ZERO,
+            cache_dir: None,
+            cache_ttl: None,
+        };
+        let err =
+            fetch_contract_snapshot("http://127.0.0.1:9", CONTRACT_ID, &[], &config).unwrap_err();
+        assert!(err.to_string().contains("after 2 attempt(s)"));
+    }
+}
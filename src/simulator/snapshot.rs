@@ -4,8 +4,12 @@
 //! allowing users to capture the state of a ledger after debugging and
 //! restore it later for continued work.
 
-use super::state::NetworkSnapshot;
+use super::state::{AccountState, ContractState, NetworkSnapshot};
 use crate::Result;
+use serde::Serialize;
+use soroban_env_host::xdr::{
+    ContractDataDurability, ContractExecutable, LedgerEntryData, Limits, ScVal, WriteXdr,
+};
 use std::fs;
 use std::path::Path;
 use tracing::info;
@@ -70,6 +74,89 @@ impl SnapshotManager {
         Ok(snapshot)
     }
 
+    /// Convert the host's final ledger state into a [`NetworkSnapshot`].
+    ///
+    /// Accounts and contract instances become snapshot entries. Each contract's
+    /// instance, persistent and temporary entries are stored under
+    /// `"<Durability>:<key>"` as `{durability, key_xdr, value, xdr, live_until_ledger}`,
+    /// where `key_xdr` and `xdr` are the base64 `ScVal` key and value so the entry
+    /// survives a round trip exactly.
+    pub fn capture(
+        ledger: &soroban_ledger_snapshot::LedgerSnapshot,
+        network_passphrase: &str,
+    ) -> Result<NetworkSnapshot> {
+        // A fresh test environment starts at sequence 0, which snapshots reject.
+        let mut snapshot = NetworkSnapshot::new(
+            ledger.sequence_number.max(1),
+            network_passphrase,
+            ledger.timestamp,
+        );
+        let mut contracts: std::collections::BTreeMap<String, ContractState> =
+            std::collections::BTreeMap::new();
+
+        for (_key, (entry, live_until)) in &ledger.ledger_entries {
+            match &entry.data {
+                LedgerEntryData::Account(account) => {
+                    snapshot.add_account(AccountState::new(
+                        account.account_id.to_string(),
+                        account.balance.max(0).to_string(),
+                        account.seq_num.0.max(0) as u64,
+                    ))?;
+                }
+                LedgerEntryData::ContractData(data) => {
+                    let state = contract_entry(&mut contracts, data.contract.to_string());
+                    if let ScVal::ContractInstance(instance) = &data.val {
+                        match &instance.executable {
+                            ContractExecutable::Wasm(hash) => state.wasm_hash = hex::encode(hash.0),
+                            ContractExecutable::StellarAsset => state.set_wasm_ref("stellar-asset"),
+                        }
+                        for item in instance.storage.iter().flat_map(|map| map.iter()) {
+                            state.set_storage(
+                                format!("Instance:{:?}", item.key),
+                                recorded_value("instance", &item.key, &item.val, *live_until)?,
+                            );
+                        }
+                    } else {
+                        let durability = match data.durability {
+                            ContractDataDurability::Persistent => "persistent",
+                            ContractDataDurability::Temporary => "temporary",
+                        };
+                        state.set_storage(
+                            format!("{:?}:{:?}", data.durability, data.key),
+                            recorded_value(durability, &data.key, &data.val, *live_until)?,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for state in contracts.into_values() {
+            snapshot.add_contract(state)?;
+        }
+        info!(
+            "Captured snapshot: {} accounts, {} contracts",
+            snapshot.accounts.len(),
+            snapshot.contracts.len()
+        );
+        Ok(snapshot)
+    }
+
+    /// Capture the executor's current ledger and save it to `path`.
+    ///
+    /// The file is written in the `--network-snapshot` format, so
+    /// [`SnapshotLoader::from_file`](super::SnapshotLoader::from_file) can load
+    /// it to continue from this state in a later run.
+    pub fn save_current<P: AsRef<Path>>(
+        executor: &crate::runtime::executor::ContractExecutor,
+        path: P,
+    ) -> Result<NetworkSnapshot> {
+        let ledger = executor.get_ledger_snapshot()?;
+        let snapshot = Self::capture(&ledger, executor.network_passphrase())?;
+        Self::save(&snapshot, path)?;
+        Ok(snapshot)
+    }
+
     /// Generate a summary of differences between two snapshots
     pub fn diff_snapshots(before: &NetworkSnapshot, after: &NetworkSnapshot) -> SnapshotDiff {
         SnapshotDiff::compute(before, after)
@@ -83,8 +170,40 @@ impl SnapshotManager {
     }
 }
 
+/// The state captured for contract `id`, created with a placeholder WASM hash
+/// until its instance entry is seen.
+fn contract_entry(
+    contracts: &mut std::collections::BTreeMap<String, ContractState>,
+    id: String,
+) -> &mut ContractState {
+    contracts
+        .entry(id.clone())
+        .or_insert_with(|| ContractState::new(id, "0".repeat(64)))
+}
+
+pub(super) fn recorded_value(
+    durability: &str,
+    key: &ScVal,
+    value: &ScVal,
+    live_until: Option<u32>,
+) -> Result<serde_json::Value> {
+    let key_xdr = key.to_xdr_base64(Limits::none()).map_err(|e| {
+        crate::DebuggerError::StorageError(format!("Failed to encode storage key: {}", e))
+    })?;
+    let xdr = value.to_xdr_base64(Limits::none()).map_err(|e| {
+        crate::DebuggerError::StorageError(format!("Failed to encode storage value: {}", e))
+    })?;
+    Ok(serde_json::json!({
+        "durability": durability,
+        "key_xdr": key_xdr,
+        "value": format!("{:?}", value),
+        "xdr": xdr,
+        "live_until_ledger": live_until,
+    }))
+}
+
 /// Represents the differences between two network snapshots
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SnapshotDiff {
     /// State fingerprints
     pub old_fingerprint: String,
@@ -204,16 +323,28 @@ impl SnapshotDiff {
                 continue; // Skip if contract not found (shouldn't happen)
             };
 
-            let storage_changed = before_contract.storage != after_contract.storage;
+            let storage_changes =
+                StorageChange::compute(&before_contract.storage, &after_contract.storage);
 
-            if storage_changed {
+            if !storage_changes.is_empty() {
                 diff.contracts_modified.push(ContractDiff {
                     contract_id: id.clone(),
-                    storage_changed,
+                    storage_changed: true,
+                    storage_changes,
                 });
             }
         }
 
+        // Set iteration order is random; sort so reports are stable.
+        diff.accounts_added.sort();
+        diff.accounts_removed.sort();
+        diff.accounts_modified
+            .sort_by(|a, b| a.address.cmp(&b.address));
+        diff.contracts_added.sort();
+        diff.contracts_removed.sort();
+        diff.contracts_modified
+            .sort_by(|a, b| a.contract_id.cmp(&b.contract_id));
+
         diff
     }
 
@@ -247,17 +378,17 @@ impl SnapshotDiff {
 
         // Account changes
         if !self.accounts_added.is_empty() {
-            output.push_str(&format!(
-                "Accounts added: {}\n",
-                self.accounts_added.join(", ")
-            ));
+            output.push_str("Accounts added (only in new snapshot):\n");
+            for address in &self.accounts_added {
+                output.push_str(&format!("  + {}\n", address));
+            }
         }
 
         if !self.accounts_removed.is_empty() {
-            output.push_str(&format!(
-                "Accounts removed: {}\n",
-                self.accounts_removed.join(", ")
-            ));
+            output.push_str("Accounts removed (only in old snapshot):\n");
+            for address in &self.accounts_removed {
+                output.push_str(&format!("  - {}\n", address));
+            }
         }
 
         if !self.accounts_modified.is_empty() {
@@ -284,32 +415,31 @@ impl SnapshotDiff {
 
         // Contract changes
         if !self.contracts_added.is_empty() {
-            output.push_str(&format!(
-                "Contracts added: {}\n",
-                self.contracts_added.join(", ")
-            ));
+            output.push_str("Contracts added (only in new snapshot):\n");
+            for id in &self.contracts_added {
+                output.push_str(&format!("  + {}\n", id));
+            }
         }
 
         if !self.contracts_removed.is_empty() {
-            output.push_str(&format!(
-                "Contracts removed: {}\n",
-                self.contracts_removed.join(", ")
-            ));
+            output.push_str("Contracts removed (only in old snapshot):\n");
+            for id in &self.contracts_removed {
+                output.push_str(&format!("  - {}\n", id));
+            }
         }
 
         if !self.contracts_modified.is_empty() {
-            output.push_str(&format!(
-                "Contracts with storage changes: {}\n",
-                self.contracts_modified
-                    .iter()
-                    .map(|c| c.contract_id.as_str())
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            ));
+            output.push_str("Contracts with storage changes:\n");
+            for contract_diff in &self.contracts_modified {
+                output.push_str(&format!("  {}\n", contract_diff.contract_id));
+                for change in &contract_diff.storage_changes {
+                    output.push_str(&format!("    {}\n", change));
+                }
+            }
         }
 
-        if output.is_empty() {
-            output.push_str("No changes detected between snapshots");
+        if !self.has_changes() {
+            output.push_str("No changes detected between snapshots\n");
         }
 
         output
@@ -329,7 +459,7 @@ impl SnapshotDiff {
 }
 
 /// Differences in a single account between snapshots
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AccountDiff {
     pub address: String,
     pub balance_changed: bool,
@@ -341,10 +471,69 @@ pub struct AccountDiff {
 }
 
 /// Differences in a single contract between snapshots
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ContractDiff {
     pub contract_id: String,
     pub storage_changed: bool,
+    /// Added, removed and changed storage keys, sorted by key
+    pub storage_changes: Vec<StorageChange>,
+}
+
+/// A storage key whose value differs between snapshots.
+///
+/// `old` is `None` for a key only in the new snapshot and `new` is `None`
+/// for a key only in the old one.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StorageChange {
+    pub key: String,
+    pub old: Option<serde_json::Value>,
+    pub new: Option<serde_json::Value>,
+}
+
+impl StorageChange {
+    fn compute(
+        before: &std::collections::BTreeMap<String, serde_json::Value>,
+        after: &std::collections::BTreeMap<String, serde_json::Value>,
+    ) -> Vec<Self> {
+        let keys: std::collections::BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+        keys.into_iter()
+            .filter_map(|key| {
+                let old = before.get(key);
+                let new = after.get(key);
+                (old != new).then(|| StorageChange {
+                    key: key.clone(),
+                    old: old.cloned(),
+                    new: new.cloned(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for StorageChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(
+                f,
+                "~ {}: {} → {}",
+                self.key,
+                display_storage_value(old),
+                display_storage_value(new)
+            ),
+            (None, Some(new)) => write!(f, "+ {}: {}", self.key, display_storage_value(new)),
+            (Some(old), None) => write!(f, "- {}: {}", self.key, display_storage_value(old)),
+            (None, None) => write!(f, "  {}", self.key),
+        }
+    }
+}
+
+/// Show the readable `value` of a recorded entry rather than the whole
+/// `{durability, key_xdr, value, xdr, live_until_ledger}` record.
+fn display_storage_value(value: &serde_json::Value) -> String {
+    match value.get("value").unwrap_or(value) {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
 }
 
 #[cfg(test)]
@@ -370,6 +559,46 @@ mod tests {
         assert_eq!(loaded.accounts.len(), 1);
     }
 
+    #[test]
+    fn test_capture_records_contract_storage() {
+        use soroban_env_host::xdr::{
+            ContractDataEntry, ExtensionPoint, Hash, LedgerEntry, LedgerEntryExt, LedgerKey,
+            LedgerKeyContractData, ScAddress,
+        };
+
+        let contract = ScAddress::Contract(Hash([1; 32]));
+        let key = LedgerKey::ContractData(LedgerKeyContractData {
+            contract: contract.clone(),
+            key: ScVal::U32(1),
+            durability: ContractDataDurability::Persistent,
+        });
+        let entry = LedgerEntry {
+            last_modified_ledger_seq: 0,
+            data: LedgerEntryData::ContractData(ContractDataEntry {
+                ext: ExtensionPoint::V0,
+                contract: contract.clone(),
+                key: ScVal::U32(1),
+                durability: ContractDataDurability::Persistent,
+                val: ScVal::U32(5),
+            }),
+            ext: LedgerEntryExt::V0,
+        };
+        let ledger = soroban_ledger_snapshot::LedgerSnapshot {
+            ledger_entries: vec![(Box::new(key), (Box::new(entry), Some(4096)))],
+            ..Default::default()
+        };
+
+        let snapshot = SnapshotManager::capture(&ledger, "Test Network").unwrap();
+        assert_eq!(snapshot.ledger.sequence, 1);
+        let state = snapshot.get_contract(&contract.to_string()).unwrap();
+        let value = state.get_storage("Persistent:U32(1)").unwrap();
+        assert_eq!(value["durability"], "persistent");
+        assert_eq!(value["value"], "U32(5)");
+        assert_eq!(value["key_xdr"], ScVal::U32(1).to_xdr_base64(Limits::none()).unwrap());
+        assert_eq!(value["live_until_ledger"], 4096);
+        snapshot.validate().unwrap();
+    }
+
     #[test]
     fn test_compute_account_changes() {
         let mut before = NetworkSnapshot::new(100, "Test Network", 1234567890);
@@ -416,6 +645,42 @@ mod tests {
         assert!(diff.has_changes());
     }
 
+    #[test]
+    fn test_diff_reports_storage_before_and_after() {
+        use crate::simulator::state::ContractState;
+
+        let mut old_contract = ContractState::new("CABC", "aabbccdd");
+        old_contract.set_storage("balance", serde_json::json!({ "value": "I128(10)" }));
+        old_contract.set_storage("owner", serde_json::json!("alice"));
+        let mut new_contract = ContractState::new("CABC", "aabbccdd");
+        new_contract.set_storage("balance", serde_json::json!({ "value": "I128(25)" }));
+        new_contract.set_storage("paused", serde_json::json!(true));
+
+        let mut before = NetworkSnapshot::new(100, "Test Network", 1234567890);
+        before.add_contract(old_contract).unwrap();
+        before
+            .add_account(AccountState::new("GOLD", "1000", 1))
+            .unwrap();
+        let mut after = NetworkSnapshot::new(100, "Test Network", 1234567890);
+        after.add_contract(new_contract).unwrap();
+        after
+            .add_account(AccountState::new("GNEW", "1000", 1))
+            .unwrap();
+
+        let diff = SnapshotManager::diff_snapshots(&before, &after);
+        let changes = &diff.contracts_modified[0].storage_changes;
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[2].key, "paused");
+        assert_eq!(changes[2].old, None);
+
+        let summary = diff.format_summary();
+        assert!(summary.contains("Accounts added (only in new snapshot):\n  + GNEW"));
+        assert!(summary.contains("Accounts removed (only in old snapshot):\n  - GOLD"));
+        assert!(summary.contains("~ balance: I128(10) → I128(25)"));
+        assert!(summary.contains("- owner: alice"));
+        assert!(summary.contains("+ paused: true"));
+    }
+
     #[test]
     fn test_diff_no_changes() {
         let before = NetworkSnapshot::new(100, "Test Network", 1234567890);
@@ -33,6 +33,9 @@ pub enum SimulatorError {
     #[error("Account not found: {0}")]
     AccountNotFound(String),
 
+    #[error("Soroban RPC request failed: {0}")]
+    RpcError(String),
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
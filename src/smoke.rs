@@ -0,0 +1,218 @@
+//! Smoke test every exported function of a contract.
+//!
+//! Each export is called once in a fresh executor, with no arguments or with
+//! zero-like defaults derived from the contract spec, to get a quick picture of
+//! which entry points run at all.
+
+use crate::runtime::executor::ContractExecutor;
+use crate::utils::wasm::{parse_function_signatures, parse_functions, ContractFunctionSignature};
+use crate::Result;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Account strkey for the all-zero ed25519 key, used as the default `Address`.
+const DEFAULT_ADDRESS: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmokeStatus {
+    Ok,
+    Err,
+    Panic,
+}
+
+/// Outcome of calling one exported function.
+#[derive(Debug, Clone, Serialize)]
+pub struct SmokeResult {
+    pub function: String,
+    pub args: String,
+    pub status: SmokeStatus,
+    /// Return value for `ok`, error or panic message otherwise.
+    pub detail: String,
+}
+
+pub struct SmokeRunner {
+    wasm_bytes: Vec<u8>,
+    storage: Option<String>,
+    timeout_secs: u64,
+}
+
+impl SmokeRunner {
+    pub fn new(wasm_bytes: Vec<u8>, storage: Option<String>, timeout_secs: u64) -> Self {
+        Self {
+            wasm_bytes,
+            storage,
+            timeout_secs,
+        }
+    }
+
+    /// Call every exported function once, in export order.
+    pub fn run_all(&self) -> Result<Vec<SmokeResult>> {
+        let signatures = parse_function_signatures(&self.wasm_bytes).unwrap_or_default();
+        let functions = parse_functions(&self.wasm_bytes)?;
+
+        Ok(functions
+            .into_iter()
+            .filter(|name| !name.starts_with('_'))
+            .map(|name| {
+                let args = signatures
+                    .iter()
+                    .find(|sig| sig.name == name)
+                    .and_then(default_args)
+                    .unwrap_or_else(|| "[]".to_string());
+                self.run_one(name, args)
+            })
+            .collect())
+    }
+
+    fn run_one(&self, function: String, args: String) -> SmokeResult {
+        let outcome = catch_unwind(AssertUnwindSafe(|| {
+            let mut executor = ContractExecutor::new(self.wasm_bytes.clone())?;
+            executor.set_timeout(self.timeout_secs);
+            if let Some(storage) = &self.storage {
+                executor.set_initial_storage(storage.clone())?;
+            }
+            executor.execute(&function, Some(&args))
+        }));
+
+        let (status, detail) = match outcome {
+            Ok(Ok(value)) => (SmokeStatus::Ok, value),
+            Ok(Err(e)) => (SmokeStatus::Err, e.to_string()),
+            Err(payload) => (
+                SmokeStatus::Panic,
+                payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "Contract execution panicked".to_string()),
+            ),
+        };
+
+        SmokeResult {
+            function,
+            args,
+            status,
+            detail,
+        }
+    }
+}
+
+/// Zero-like arguments for `signature`, or `None` if any parameter type has no default.
+pub fn default_args(signature: &ContractFunctionSignature) -> Option<String> {
+    let args = signature
+        .params
+        .iter()
+        .map(|param| default_value(&param.type_name))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Value::Array(args).to_string())
+}
+
+fn default_value(type_name: &str) -> Option<Value> {
+    let typed = |ty: &str, value: Value| json!({ "type": ty, "value": value });
+    Some(match type_name {
+        "U32" | "I32" | "U64" | "I64" | "U128" | "I128" => {
+            typed(&type_name.to_ascii_lowercase(), json!(0))
+        }
+        "Bool" => typed("bool", json!(false)),
+        "String" => typed("string", json!("")),
+        "Symbol" => typed("symbol", json!("")),
+        "Address" => typed("address", json!(DEFAULT_ADDRESS)),
+        "Bytes" => typed("bytes", json!("0x")),
+        t if t.starts_with("BytesN<") => {
+            let len = t
+                .trim_start_matches("BytesN<")
+                .trim_end_matches('>')
+                .parse::<usize>()
+                .ok()?;
+            json!({ "type": "bytesn", "length": len, "value": format!("0x{}", "00".repeat(len)) })
+        }
+        t if t.starts_with("Vec<") => typed("vec", json!([])),
+        t if t.starts_with("Option<") => Value::Null,
+        _ => return None,
+    })
+}
+
+/// Fixed-width table of the results, one row per function.
+pub fn render_table(results: &[SmokeResult]) -> String {
+    let width = results
+        .iter()
+        .map(|r| r.function.len())
+        .max()
+        .unwrap_or(0)
+        .max("Function".len());
+
+    let mut out = format!("{:<width$}  {:<6}  Detail\n", "Function", "Status");
+    out.push_str(&format!("{}\n", "-".repeat(width + 16)));
+    for result in results {
+        let status = match result.status {
+            SmokeStatus::Ok => "ok",
+            SmokeStatus::Err => "err",
+            SmokeStatus::Panic => "panic",
+        };
+        let detail = result.detail.lines().next().unwrap_or_default();
+        out.push_str(&format!(
+            "{:<width$}  {:<6}  {}\n",
+            result.function, status, detail
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::wasm::FunctionParam;
+
+    fn signature(types: &[&str]) -> ContractFunctionSignature {
+        ContractFunctionSignature {
+            name: "f".to_string(),
+            params: types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| FunctionParam {
+                    name: format!("p{}", i),
+                    type_name: ty.to_string(),
+                })
+                .collect(),
+            return_type: None,
+        }
+    }
+
+    #[test]
+    fn default_args_cover_spec_types() {
+        let args =
+            default_args(&signature(&["U32", "Address", "Option<I128>", "BytesN<2>"])).unwrap();
+        let args: Vec<Value> = serde_json::from_str(&args).unwrap();
+        assert_eq!(args[0], json!({ "type": "u32", "value": 0 }));
+        assert_eq!(args[1]["value"], DEFAULT_ADDRESS);
+        assert!(args[2].is_null());
+        assert_eq!(args[3]["value"], "0x0000");
+
+        assert_eq!(default_args(&signature(&[])).unwrap(), "[]");
+        assert!(default_args(&signature(&["Proposal"])).is_none());
+    }
+
+    #[test]
+    fn render_table_lists_each_function() {
+        let results = vec![
+            SmokeResult {
+                function: "total_supply".to_string(),
+                args: "[]".to_string(),
+                status: SmokeStatus::Ok,
+                detail: "1000".to_string(),
+            },
+            SmokeResult {
+                function: "get_signers".to_string(),
+                args: "[]".to_string(),
+                status: SmokeStatus::Panic,
+                detail: "not initialized\nbacktrace".to_string(),
+            },
+        ];
+
+        let table = render_table(&results);
+        assert!(table.contains("total_supply  ok"));
+        assert!(table.contains("get_signers   panic   not initialized"));
+        assert!(!table.contains("backtrace"));
+    }
+}
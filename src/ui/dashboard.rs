@@ -5,14 +5,21 @@
 //! - Storage state with key-value pairs
 //! - Real-time CPU and memory budget meters with history
 //! - Execution log with timestamped events
+//! - Function breakpoints with their hit counts
 //!
 //! The dashboard supports keyboard navigation between panes (Tab, arrow keys) and
-//! debugger control actions (step, continue, refresh).
+//! debugger control actions (step, continue, refresh, toggle breakpoint).
 
+use crate::config::ThemeName;
+use crate::debugger::breakpoint::{Breakpoint, BreakpointManager};
 use crate::debugger::engine::DebuggerEngine;
-use crate::inspector::budget::BudgetInfo;
+use crate::debugger::state::PauseReason;
+use crate::inspector::auth::{AuthNode, AuthStatus};
+use crate::inspector::budget::{BudgetInfo, NetworkLimits};
+use crate::inspector::events::ContractEvent;
 use crate::inspector::storage::{StorageInspector, StorageQuery};
 use crate::inspector::stack::CallFrame;
+use crate::ui::formatter::Formatter;
 use crate::{DebuggerError, Result};
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
@@ -37,20 +44,100 @@ use std::{
 };
 
 // ─── Palette ────────────────────────────────────────────────────────────────
-const COLOR_BG: Color = Color::Rgb(15, 17, 26);
-const COLOR_SURFACE: Color = Color::Rgb(22, 27, 40);
-const COLOR_BORDER: Color = Color::Rgb(48, 64, 96);
-const COLOR_BORDER_ACTIVE: Color = Color::Rgb(99, 179, 237);
-const COLOR_TEXT: Color = Color::Rgb(220, 226, 240);
-const COLOR_TEXT_DIM: Color = Color::Rgb(100, 116, 140);
-const COLOR_ACCENT: Color = Color::Rgb(99, 179, 237);
-const COLOR_GREEN: Color = Color::Rgb(72, 199, 142);
-const COLOR_YELLOW: Color = Color::Rgb(252, 196, 25);
-const COLOR_RED: Color = Color::Rgb(252, 87, 87);
-const COLOR_PURPLE: Color = Color::Rgb(180, 130, 255);
-const COLOR_CYAN: Color = Color::Rgb(56, 210, 220);
-const COLOR_CPU_FILL: Color = Color::Rgb(99, 179, 237);
-const COLOR_MEM_FILL: Color = Color::Rgb(72, 199, 142);
+/// Colors used to draw the dashboard, selected with `--theme` or the
+/// `[theme]` table of the config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub bg: Color,
+    pub surface: Color,
+    pub border: Color,
+    pub border_active: Color,
+    pub text: Color,
+    pub text_dim: Color,
+    pub accent: Color,
+    pub green: Color,
+    pub yellow: Color,
+    pub red: Color,
+    pub purple: Color,
+    pub cyan: Color,
+    pub cpu_fill: Color,
+    pub mem_fill: Color,
+}
+
+impl Theme {
+    pub fn new(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self::dark(),
+            ThemeName::Light => Self::light(),
+            ThemeName::Mono => Self::mono(),
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            bg: Color::Rgb(15, 17, 26),
+            surface: Color::Rgb(22, 27, 40),
+            border: Color::Rgb(48, 64, 96),
+            border_active: Color::Rgb(99, 179, 237),
+            text: Color::Rgb(220, 226, 240),
+            text_dim: Color::Rgb(100, 116, 140),
+            accent: Color::Rgb(99, 179, 237),
+            green: Color::Rgb(72, 199, 142),
+            yellow: Color::Rgb(252, 196, 25),
+            red: Color::Rgb(252, 87, 87),
+            purple: Color::Rgb(180, 130, 255),
+            cyan: Color::Rgb(56, 210, 220),
+            cpu_fill: Color::Rgb(99, 179, 237),
+            mem_fill: Color::Rgb(72, 199, 142),
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            bg: Color::Rgb(250, 250, 252),
+            surface: Color::Rgb(240, 242, 247),
+            border: Color::Rgb(190, 198, 214),
+            border_active: Color::Rgb(37, 99, 235),
+            text: Color::Rgb(30, 35, 45),
+            text_dim: Color::Rgb(100, 110, 125),
+            accent: Color::Rgb(37, 99, 235),
+            green: Color::Rgb(22, 128, 61),
+            yellow: Color::Rgb(161, 98, 7),
+            red: Color::Rgb(200, 30, 30),
+            purple: Color::Rgb(109, 40, 217),
+            cyan: Color::Rgb(14, 116, 144),
+            cpu_fill: Color::Rgb(37, 99, 235),
+            mem_fill: Color::Rgb(22, 128, 61),
+        }
+    }
+
+    /// The terminal's own colors plus grays, for terminals without RGB
+    /// support; emphasis comes from bold and border styles.
+    pub fn mono() -> Self {
+        Self {
+            bg: Color::Reset,
+            surface: Color::Reset,
+            border: Color::DarkGray,
+            border_active: Color::White,
+            text: Color::Reset,
+            text_dim: Color::DarkGray,
+            accent: Color::White,
+            green: Color::Reset,
+            yellow: Color::White,
+            red: Color::White,
+            purple: Color::Reset,
+            cyan: Color::Reset,
+            cpu_fill: Color::White,
+            mem_fill: Color::Gray,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
 
 // ─── Pane enum ───────────────────────────────────────────────────────────────
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -61,6 +148,9 @@ pub enum ActivePane {
     Budget,
     Log,
     Diagnostics,
+    Events,
+    Auth,
+    Breakpoints,
 }
 
 impl ActivePane {
@@ -71,18 +161,24 @@ impl ActivePane {
             ActivePane::Storage => ActivePane::Budget,
             ActivePane::Budget => ActivePane::Log,
             ActivePane::Log => ActivePane::Diagnostics,
-            ActivePane::Diagnostics => ActivePane::Execution,
+            ActivePane::Diagnostics => ActivePane::Events,
+            ActivePane::Events => ActivePane::Auth,
+            ActivePane::Auth => ActivePane::Breakpoints,
+            ActivePane::Breakpoints => ActivePane::Execution,
         }
     }
 
     fn prev(self) -> Self {
         match self {
-            ActivePane::Execution => ActivePane::Diagnostics,
+            ActivePane::Execution => ActivePane::Breakpoints,
             ActivePane::CallStack => ActivePane::Execution,
             ActivePane::Storage => ActivePane::CallStack,
             ActivePane::Budget => ActivePane::Storage,
             ActivePane::Log => ActivePane::Budget,
             ActivePane::Diagnostics => ActivePane::Log,
+            ActivePane::Events => ActivePane::Diagnostics,
+            ActivePane::Auth => ActivePane::Events,
+            ActivePane::Breakpoints => ActivePane::Auth,
         }
     }
 
@@ -94,6 +190,9 @@ impl ActivePane {
             ActivePane::Budget => "Budget Meters",
             ActivePane::Log => "Execution Log",
             ActivePane::Diagnostics => "Diagnostics",
+            ActivePane::Events => "Events",
+            ActivePane::Auth => "Auth Tree",
+            ActivePane::Breakpoints => "Breakpoints",
         }
     }
 }
@@ -102,6 +201,9 @@ impl ActivePane {
 struct PendingExecution {
     function: String,
     args: Option<String>,
+    /// Set once the call has stopped at (or passed) its function breakpoint,
+    /// so the next continue runs it.
+    breakpoint_checked: bool,
 }
 
 // ─── TUI state ───────────────────────────────────────────────────────────────
@@ -130,25 +232,49 @@ pub struct DashboardApp {
 
     // Budget pane
     budget_info: BudgetInfo,
+    network_limits: NetworkLimits,
     budget_history_cpu: VecDeque<f64>,
     budget_history_mem: VecDeque<f64>,
 
     // Log pane
-    log_entries: Vec<LogEntry>,
+    log_entries: VecDeque<LogEntry>,
+    /// Entries dropped from the front of `log_entries` so far.
+    log_dropped: usize,
+    /// Positions, counted from the first entry ever logged, of the entries
+    /// that pass the level and text filters.
+    visible_log: VecDeque<usize>,
     log_scroll: usize,
     log_scroll_state: ScrollbarState,
+    log_filter: String,
+    log_filter_editing: bool,
+    log_level_filter: LogLevelFilter,
 
     // Diagnostics pane
     diagnostics: Vec<crate::output::DiagnosticRecord>,
     diagnostics_state: ListState,
     diagnostics_scroll_state: ScrollbarState,
 
+    // Events pane
+    events: Vec<ContractEvent>,
+    events_scroll: usize,
+    events_scroll_state: ScrollbarState,
+
+    // Auth pane
+    auth_nodes: Vec<AuthNode>,
+    auth_scroll: usize,
+    auth_scroll_state: ScrollbarState,
+
+    // Breakpoints pane
+    breakpoints_state: ListState,
+    breakpoint_input: Option<String>,
+
     // Misc
     last_refresh: Instant,
     step_count: usize,
     function_name: String,
     show_help: bool,
     status_message: Option<(String, StatusKind)>,
+    theme: Theme,
 }
 
 #[derive(Debug, Clone)]
@@ -167,6 +293,50 @@ enum LogLevel {
     Step,
 }
 
+/// Oldest log entries are dropped beyond this many.
+const MAX_LOG_ENTRIES: usize = 2000;
+
+/// Which log levels the log pane shows, cycled with `v`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogLevelFilter {
+    All,
+    HideDebug,
+    WarnAndError,
+}
+
+impl LogLevelFilter {
+    fn next(self) -> Self {
+        match self {
+            LogLevelFilter::All => LogLevelFilter::HideDebug,
+            LogLevelFilter::HideDebug => LogLevelFilter::WarnAndError,
+            LogLevelFilter::WarnAndError => LogLevelFilter::All,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            LogLevelFilter::All => "all",
+            LogLevelFilter::HideDebug => "no debug",
+            LogLevelFilter::WarnAndError => "warn+error",
+        }
+    }
+
+    fn allows(self, level: LogLevel) -> bool {
+        match self {
+            LogLevelFilter::All => true,
+            LogLevelFilter::HideDebug => !matches!(level, LogLevel::Debug),
+            LogLevelFilter::WarnAndError => matches!(level, LogLevel::Warn | LogLevel::Error),
+        }
+    }
+}
+
+/// Whether `entry` passes the level filter and contains `needle`, which must
+/// already be lowercase.
+fn log_entry_matches(entry: &LogEntry, level_filter: LogLevelFilter, needle: &str) -> bool {
+    level_filter.allows(entry.level)
+        && (needle.is_empty() || entry.message.to_lowercase().contains(needle))
+}
+
 #[derive(Debug, Clone, Copy)]
 enum StatusKind {
     Info,
@@ -180,12 +350,18 @@ enum StorageInputMode {
 }
 
 impl DashboardApp {
-    pub fn new(engine: DebuggerEngine, function_name: String) -> Self {
+    pub fn new(
+        engine: DebuggerEngine,
+        function_name: String,
+        network_limits: NetworkLimits,
+        theme: Theme,
+    ) -> Self {
         let pending_execution = if engine.is_paused() {
             engine.state().lock().ok().and_then(|state| {
                 state.current_function().map(|f| PendingExecution {
                     function: f.to_string(),
                     args: state.current_args().map(str::to_string),
+                    breakpoint_checked: false,
                 })
             })
         } else {
@@ -220,15 +396,21 @@ impl DashboardApp {
             storage_input_value: String::new(),
             budget_info: BudgetInfo {
                 cpu_instructions: 0,
-                cpu_limit: 100_000_000,
+                cpu_limit: network_limits.cpu_limit,
                 memory_bytes: 0,
-                memory_limit: 40 * 1024 * 1024,
+                memory_limit: network_limits.memory_limit,
             },
+            network_limits,
             budget_history_cpu: VecDeque::with_capacity(60),
             budget_history_mem: VecDeque::with_capacity(60),
-            log_entries: Vec::new(),
+            log_entries: VecDeque::new(),
+            log_dropped: 0,
+            visible_log: VecDeque::new(),
             log_scroll: 0,
             log_scroll_state: ScrollbarState::default().content_length(0),
+            log_filter: String::new(),
+            log_filter_editing: false,
+            log_level_filter: LogLevelFilter::All,
             diagnostics: Vec::new(),
             diagnostics_state: {
                 let mut state = ListState::default();
@@ -236,11 +418,24 @@ impl DashboardApp {
                 state
             },
             diagnostics_scroll_state: ScrollbarState::default().content_length(0),
+            events: Vec::new(),
+            events_scroll: 0,
+            events_scroll_state: ScrollbarState::default().content_length(0),
+            auth_nodes: Vec::new(),
+            auth_scroll: 0,
+            auth_scroll_state: ScrollbarState::default().content_length(0),
+            breakpoints_state: {
+                let mut state = ListState::default();
+                state.select(Some(0));
+                state
+            },
+            breakpoint_input: None,
             last_refresh: Instant::now(),
             step_count: 0,
             function_name,
             show_help: false,
             status_message: None,
+            theme,
         };
 
         app.push_log(
@@ -270,22 +465,234 @@ impl DashboardApp {
     // ── Helpers ──────────────────────────────────────────────────────────────
 
     fn push_log(&mut self, level: LogLevel, message: String) {
-        let timestamp = format_timestamp();
-        self.log_entries.push(LogEntry {
-            timestamp,
+        let entry = LogEntry {
+            timestamp: format_timestamp(),
             level,
             message,
-        });
+        };
+        let needle = self.log_filter.to_lowercase();
+        if log_entry_matches(&entry, self.log_level_filter, &needle) {
+            self.visible_log
+                .push_back(self.log_dropped + self.log_entries.len());
+        }
+        self.log_entries.push_back(entry);
+        if self.log_entries.len() > MAX_LOG_ENTRIES {
+            self.log_entries.pop_front();
+            if self.visible_log.front() == Some(&self.log_dropped) {
+                self.visible_log.pop_front();
+            }
+            self.log_dropped += 1;
+        }
+
+        // Auto-scroll to bottom unless the user is searching
+        let len = self.visible_log.len();
+        if self.log_filter.is_empty() {
+            self.log_scroll = len.saturating_sub(1);
+        }
+        self.sync_log_scroll_state(len);
+    }
+
+    /// Recompute which log entries pass the level and text filters after
+    /// either changes; returns how many do.
+    fn refresh_visible_log(&mut self) -> usize {
+        let needle = self.log_filter.to_lowercase();
+        self.visible_log = self
+            .log_entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| log_entry_matches(entry, self.log_level_filter, &needle))
+            .map(|(index, _)| self.log_dropped + index)
+            .collect();
+        self.visible_log.len()
+    }
+
+    /// The log entry at `position`, counted from the first entry ever logged.
+    fn log_entry(&self, position: usize) -> &LogEntry {
+        &self.log_entries[position - self.log_dropped]
+    }
 
-        // Auto-scroll to bottom
-        let len = self.log_entries.len();
-        self.log_scroll = len.saturating_sub(1);
+    fn sync_log_scroll_state(&mut self, visible: usize) {
+        self.log_scroll = self.log_scroll.min(visible.saturating_sub(1));
         self.log_scroll_state = self
             .log_scroll_state
-            .content_length(len)
+            .content_length(visible)
             .position(self.log_scroll);
     }
 
+    /// Handle a key while the log filter prompt is open. Typing filters live;
+    /// Enter keeps the filter and Esc discards it.
+    fn handle_log_filter_key(&mut self, key: event::KeyEvent) -> bool {
+        if !self.log_filter_editing {
+            return false;
+        }
+        match key.code {
+            KeyCode::Enter => self.log_filter_editing = false,
+            KeyCode::Esc => {
+                self.log_filter_editing = false;
+                self.clear_log_filter();
+                return true;
+            }
+            KeyCode::Backspace => {
+                self.log_filter.pop();
+            }
+            KeyCode::Char(c) => self.log_filter.push(c),
+            _ => return true,
+        }
+        self.log_scroll = 0;
+        let visible = self.refresh_visible_log();
+        self.sync_log_scroll_state(visible);
+        true
+    }
+
+    fn clear_log_filter(&mut self) {
+        self.log_filter.clear();
+        let visible = self.refresh_visible_log();
+        self.log_scroll = visible.saturating_sub(1);
+        self.sync_log_scroll_state(visible);
+    }
+
+    fn cycle_log_level_filter(&mut self) {
+        self.log_level_filter = self.log_level_filter.next();
+        let visible = self.refresh_visible_log();
+        if self.log_filter.is_empty() {
+            self.log_scroll = visible.saturating_sub(1);
+        }
+        self.sync_log_scroll_state(visible);
+        self.status_message = Some((
+            format!("Log levels: {}", self.log_level_filter.label()),
+            StatusKind::Info,
+        ));
+    }
+
+    /// Move to the next (or previous) match of the log filter, wrapping around.
+    fn jump_log_match(&mut self, forward: bool) {
+        let visible = self.visible_log.len();
+        if self.log_filter.is_empty() || visible == 0 {
+            return;
+        }
+        self.log_scroll = if forward {
+            (self.log_scroll + 1) % visible
+        } else {
+            (self.log_scroll + visible - 1) % visible
+        };
+        self.sync_log_scroll_state(visible);
+    }
+
+    /// Handle a key while the breakpoint prompt is open. Enter toggles the
+    /// breakpoint on the typed function and Esc cancels.
+    fn handle_breakpoint_input_key(&mut self, key: event::KeyEvent) -> bool {
+        let Some(input) = self.breakpoint_input.as_mut() else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Enter => {
+                let spec = input.clone();
+                self.breakpoint_input = None;
+                self.toggle_breakpoint(&spec);
+            }
+            KeyCode::Esc => self.breakpoint_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+        true
+    }
+
+    /// Remove the breakpoint on the function named by `spec`, or set one from
+    /// `spec` if there is none. `spec` takes the `--breakpoint` syntax, so it
+    /// may carry a `when` condition or `ignore:N`.
+    fn toggle_breakpoint(&mut self, spec: &str) {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            return;
+        }
+        let (function, _) = BreakpointManager::parse_breakpoint(spec);
+        if self.engine.breakpoints_mut().remove(&function) {
+            self.push_log(LogLevel::Info, format!("Breakpoint removed: {}", function));
+            self.status_message = Some((
+                format!("Removed breakpoint on {}", function),
+                StatusKind::Info,
+            ));
+        } else {
+            match self.engine.add_breakpoint_spec(spec) {
+                Ok(()) => {
+                    self.push_log(LogLevel::Info, format!("Breakpoint set: {}", spec));
+                    self.status_message =
+                        Some((format!("Breakpoint set on {}", function), StatusKind::Info));
+                }
+                Err(e) => {
+                    self.push_log(LogLevel::Error, format!("Invalid breakpoint: {}", e));
+                    self.status_message =
+                        Some((format!("Breakpoint error: {}", e), StatusKind::Error));
+                }
+            }
+        }
+
+        let len = self.engine.breakpoints().count();
+        let sel = self.breakpoints_state.selected().unwrap_or(0);
+        self.breakpoints_state
+            .select(Some(sel.min(len.saturating_sub(1))));
+    }
+
+    /// The function whose breakpoint the engine is currently stopped at.
+    fn hit_breakpoint(&self) -> Option<String> {
+        if !self.engine.is_paused() {
+            return None;
+        }
+        let state = self.engine.state();
+        let state = state.lock().ok()?;
+        if state.pause_reason() != Some(PauseReason::Breakpoint) {
+            return None;
+        }
+        state.current_function().map(str::to_string)
+    }
+
+    /// Stop `pending` at its function breakpoint if one matches. Returns
+    /// whether execution stopped; log points are written to the log instead.
+    fn stop_at_breakpoint(&mut self, pending: &PendingExecution) -> bool {
+        let (should_pause, log_message) = match self
+            .engine
+            .check_function_breakpoint(&pending.function, pending.args.as_deref())
+        {
+            Ok(result) => result,
+            Err(e) => {
+                self.push_log(
+                    LogLevel::Warn,
+                    format!("Breakpoint evaluation failed: {}", e),
+                );
+                return false;
+            }
+        };
+
+        if let Some(message) = log_message {
+            self.push_log(
+                LogLevel::Info,
+                format!("[LOG @{}] {}", pending.function, message),
+            );
+        }
+        if !should_pause {
+            return false;
+        }
+
+        self.engine
+            .prepare_breakpoint_stop(&pending.function, pending.args.as_deref());
+        let hits = self.engine.breakpoints().hit_count(&pending.function);
+        self.push_log(
+            LogLevel::Warn,
+            format!("Breakpoint hit: {} (hit #{})", pending.function, hits),
+        );
+        self.status_message = Some((
+            format!(
+                "Paused at breakpoint {} — press 'c' to run",
+                pending.function
+            ),
+            StatusKind::Info,
+        ));
+        true
+    }
+
     fn refresh_state(&mut self) {
         // ── Call Stack ─────────────────────────────────────────────────
         if let Ok(state) = self.engine.state().lock() {
@@ -302,7 +709,8 @@ impl DashboardApp {
 
         // ── Budget ─────────────────────────────────────────────────────
         let new_budget =
-            crate::inspector::budget::BudgetInspector::get_cpu_usage(self.engine.executor().host());
+            crate::inspector::budget::BudgetInspector::get_cpu_usage(self.engine.executor().host())
+                .with_limits(self.network_limits);
 
         let cpu_pct = new_budget.cpu_percentage();
         let mem_pct = new_budget.memory_percentage();
@@ -310,6 +718,9 @@ impl DashboardApp {
         if cpu_pct != self.budget_info.cpu_percentage() && cpu_pct > 80.0 {
             self.push_log(LogLevel::Warn, format!("CPU usage high: {:.1}%", cpu_pct));
         }
+        for message in new_budget.newly_exceeded_limits(&self.budget_info) {
+            self.push_log(LogLevel::Warn, message);
+        }
         self.budget_info = new_budget;
 
         if self.budget_history_cpu.len() >= 60 {
@@ -342,6 +753,44 @@ impl DashboardApp {
         self.clamp_storage_selection();
         self.sync_storage_scroll_state();
 
+        // ── Events ─────────────────────────────────────────────────────
+        match self.engine.executor().get_events() {
+            Ok(events) => {
+                if events.len() > self.events.len() {
+                    self.push_log(
+                        LogLevel::Debug,
+                        format!("{} new event(s)", events.len() - self.events.len()),
+                    );
+                }
+                self.events = events;
+                self.events_scroll_state = self
+                    .events_scroll_state
+                    .content_length(self.events.len())
+                    .position(self.events_scroll);
+            }
+            Err(e) => self.push_log(LogLevel::Error, format!("Event capture failed: {}", e)),
+        }
+
+        // ── Auth tree ──────────────────────────────────────────────────
+        match self.engine.executor().get_auth_tree() {
+            Ok(nodes) => {
+                if nodes.len() != self.auth_nodes.len() {
+                    self.push_log(
+                        LogLevel::Debug,
+                        format!("Authorizations recorded: {}", nodes.len()),
+                    );
+                }
+                self.auth_nodes = nodes;
+                let rows = flatten_auth_tree(&self.auth_nodes).len();
+                self.auth_scroll = self.auth_scroll.min(rows.saturating_sub(1));
+                self.auth_scroll_state = self
+                    .auth_scroll_state
+                    .content_length(rows)
+                    .position(self.auth_scroll);
+            }
+            Err(e) => self.push_log(LogLevel::Error, format!("Auth tree failed: {}", e)),
+        }
+
         self.rebuild_diagnostics();
         self.last_refresh = Instant::now();
     }
@@ -405,6 +854,20 @@ impl DashboardApp {
 
     // ── Step action ──────────────────────────────────────────────────────────
     fn do_step(&mut self) {
+        // The first step of a staged call stops at its entry breakpoint, as
+        // continuing would.
+        if let Some(mut pending) = self.pending_execution.take() {
+            let stopped = !pending.breakpoint_checked && {
+                pending.breakpoint_checked = true;
+                self.stop_at_breakpoint(&pending)
+            };
+            self.pending_execution = Some(pending);
+            if stopped {
+                self.refresh_state();
+                return;
+            }
+        }
+
         match self.engine.step() {
             Ok(()) => {
                 self.step_count += 1;
@@ -412,6 +875,20 @@ impl DashboardApp {
                     LogLevel::Step,
                     format!("Step #{} completed", self.step_count),
                 );
+                // Stepping into a call checks that function's breakpoint.
+                if self.engine.is_instruction_debug_enabled() {
+                    if let Some(function) = self.hit_breakpoint() {
+                        let hits = self.engine.breakpoints().hit_count(&function);
+                        self.push_log(
+                            LogLevel::Warn,
+                            format!("Breakpoint hit: {} (hit #{})", function, hits),
+                        );
+                        self.status_message = Some((
+                            format!("Paused at breakpoint {} — press 'c' to run", function),
+                            StatusKind::Info,
+                        ));
+                    }
+                }
             }
             Err(e) => {
                 self.push_log(LogLevel::Error, format!("Step failed: {}", e));
@@ -423,7 +900,15 @@ impl DashboardApp {
 
     // ── Continue action ──────────────────────────────────────────────────────
     fn do_continue(&mut self) {
-        if let Some(pending) = self.pending_execution.take() {
+        if let Some(mut pending) = self.pending_execution.take() {
+            if !pending.breakpoint_checked {
+                pending.breakpoint_checked = true;
+                if self.stop_at_breakpoint(&pending) {
+                    self.pending_execution = Some(pending);
+                    self.refresh_state();
+                    return;
+                }
+            }
             self.push_log(LogLevel::Info, format!("Executing {}…", pending.function));
             match self
                 .engine
@@ -476,7 +961,7 @@ impl DashboardApp {
                 self.move_storage_selection(1);
             }
             ActivePane::Log => {
-                let len = self.log_entries.len();
+                let len = self.visible_log.len();
                 self.log_scroll = (self.log_scroll + 1).min(len.saturating_sub(1));
                 self.log_scroll_state = self.log_scroll_state.position(self.log_scroll);
             }
@@ -491,6 +976,24 @@ impl DashboardApp {
                 self.diagnostics_state.select(Some(new_sel));
                 self.diagnostics_scroll_state = self.diagnostics_scroll_state.position(new_sel);
             }
+            ActivePane::Events => {
+                let len = self.events.len();
+                self.events_scroll = (self.events_scroll + 1).min(len.saturating_sub(1));
+                self.events_scroll_state = self.events_scroll_state.position(self.events_scroll);
+            }
+            ActivePane::Auth => {
+                let len = flatten_auth_tree(&self.auth_nodes).len();
+                self.auth_scroll = (self.auth_scroll + 1).min(len.saturating_sub(1));
+                self.auth_scroll_state = self.auth_scroll_state.position(self.auth_scroll);
+            }
+            ActivePane::Breakpoints => {
+                let len = self.engine.breakpoints().count();
+                if len == 0 {
+                    return;
+                }
+                let sel = self.breakpoints_state.selected().unwrap_or(0);
+                self.breakpoints_state.select(Some((sel + 1).min(len - 1)));
+            }
         }
     }
 
@@ -515,6 +1018,18 @@ impl DashboardApp {
                 self.diagnostics_state.select(Some(new_sel));
                 self.diagnostics_scroll_state = self.diagnostics_scroll_state.position(new_sel);
             }
+            ActivePane::Events => {
+                self.events_scroll = self.events_scroll.saturating_sub(1);
+                self.events_scroll_state = self.events_scroll_state.position(self.events_scroll);
+            }
+            ActivePane::Auth => {
+                self.auth_scroll = self.auth_scroll.saturating_sub(1);
+                self.auth_scroll_state = self.auth_scroll_state.position(self.auth_scroll);
+            }
+            ActivePane::Breakpoints => {
+                let sel = self.breakpoints_state.selected().unwrap_or(0);
+                self.breakpoints_state.select(Some(sel.saturating_sub(1)));
+            }
         }
     }
 }
@@ -526,15 +1041,22 @@ impl DashboardApp {
 /// # Arguments
 /// * `engine` - The debugger engine instance with contract state
 /// * `function_name` - The name of the contract function being debugged
+/// * `network_limits` - CPU and memory limits the budget gauges are sized for
+/// * `theme` - Palette the dashboard is drawn with
 ///
 /// # Returns
 /// Returns `Ok(())` on successful exit (via 'q' or Ctrl+C),
 /// or a `DebuggerError` if terminal setup/teardown fails.
-pub fn run_dashboard(engine: DebuggerEngine, function_name: &str) -> Result<()> {
+pub fn run_dashboard(
+    engine: DebuggerEngine,
+    function_name: &str,
+    network_limits: NetworkLimits,
+    theme: Theme,
+) -> Result<()> {
     use crate::DebuggerError;
 
     if std::env::var_os("SOROBAN_DEBUG_TUI_SMOKE").is_some() {
-        return run_dashboard_smoke(engine, function_name);
+        return run_dashboard_smoke(engine, function_name, network_limits, theme);
     }
     // Setup terminal
     enable_raw_mode()
@@ -547,7 +1069,7 @@ pub fn run_dashboard(engine: DebuggerEngine, function_name: &str) -> Result<()>
     let mut terminal = Terminal::new(backend)
         .map_err(|e| DebuggerError::IoError(format!("Failed to create terminal: {}", e)))?;
 
-    let res = run_app(&mut terminal, engine, function_name);
+    let res = run_app(&mut terminal, engine, function_name, network_limits, theme);
 
     // Restore terminal
     disable_raw_mode()
@@ -569,14 +1091,19 @@ pub fn run_dashboard(engine: DebuggerEngine, function_name: &str) -> Result<()>
     Ok(())
 }
 
-fn run_dashboard_smoke(engine: DebuggerEngine, function_name: &str) -> Result<()> {
+fn run_dashboard_smoke(
+    engine: DebuggerEngine,
+    function_name: &str,
+    network_limits: NetworkLimits,
+    theme: Theme,
+) -> Result<()> {
     use ratatui::backend::TestBackend;
 
     let backend = TestBackend::new(120, 40);
     let mut terminal = Terminal::new(backend)
         .map_err(|e| DebuggerError::IoError(format!("Failed to create terminal: {}", e)))?;
 
-    let mut app = DashboardApp::new(engine, function_name.to_string());
+    let mut app = DashboardApp::new(engine, function_name.to_string(), network_limits, theme);
     app.do_continue();
 
     terminal
@@ -590,8 +1117,10 @@ fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     engine: DebuggerEngine,
     function_name: &str,
+    network_limits: NetworkLimits,
+    theme: Theme,
 ) -> Result<()> {
-    let mut app = DashboardApp::new(engine, function_name.to_string());
+    let mut app = DashboardApp::new(engine, function_name.to_string(), network_limits, theme);
     let tick_rate = Duration::from_millis(250);
     let mut last_tick = Instant::now();
 
@@ -615,7 +1144,10 @@ fn run_app<B: ratatui::backend::Backend>(
                     return Ok(());
                 }
 
-                if app.handle_storage_input_key(key) {
+                if app.handle_storage_input_key(key)
+                    || app.handle_log_filter_key(key)
+                    || app.handle_breakpoint_input_key(key)
+                {
                     continue;
                 }
 
@@ -641,6 +1173,9 @@ fn run_app<B: ratatui::backend::Backend>(
                     KeyCode::Char('4') => app.active_pane = ActivePane::Budget,
                     KeyCode::Char('5') => app.active_pane = ActivePane::Log,
                     KeyCode::Char('6') => app.active_pane = ActivePane::Diagnostics,
+                    KeyCode::Char('7') => app.active_pane = ActivePane::Events,
+                    KeyCode::Char('8') => app.active_pane = ActivePane::Auth,
+                    KeyCode::Char('9') => app.active_pane = ActivePane::Breakpoints,
 
                     // ── Scroll ────────────────────────────────────
                     KeyCode::Down | KeyCode::Char('j') => {
@@ -674,6 +1209,23 @@ fn run_app<B: ratatui::backend::Backend>(
                     KeyCode::Char('/') => {
                         if app.active_pane == ActivePane::Storage {
                             app.open_storage_input(StorageInputMode::Filter);
+                        } else if app.active_pane == ActivePane::Log {
+                            app.log_filter_editing = true;
+                        }
+                    }
+                    KeyCode::Char('n') => {
+                        if app.active_pane == ActivePane::Log {
+                            app.jump_log_match(true);
+                        }
+                    }
+                    KeyCode::Char('N') => {
+                        if app.active_pane == ActivePane::Log {
+                            app.jump_log_match(false);
+                        }
+                    }
+                    KeyCode::Char('v') => {
+                        if app.active_pane == ActivePane::Log {
+                            app.cycle_log_level_filter();
                         }
                     }
                     KeyCode::Char('g') => {
@@ -684,6 +1236,8 @@ fn run_app<B: ratatui::backend::Backend>(
                     KeyCode::Char('x') | KeyCode::Esc => {
                         if app.active_pane == ActivePane::Storage {
                             app.clear_storage_filter();
+                        } else if app.active_pane == ActivePane::Log {
+                            app.clear_log_filter();
                         }
                     }
                     KeyCode::Char('s') | KeyCode::Char('S') => {
@@ -692,6 +1246,10 @@ fn run_app<B: ratatui::backend::Backend>(
                     KeyCode::Char('c') => {
                         app.do_continue();
                     }
+                    KeyCode::Char('b') | KeyCode::Char('B') => {
+                        app.active_pane = ActivePane::Breakpoints;
+                        app.breakpoint_input = Some(String::new());
+                    }
                     KeyCode::Char('r') | KeyCode::Char('R') => {
                         app.refresh_state();
                         app.push_log(LogLevel::Info, "Manually refreshed state.".to_string());
@@ -713,9 +1271,10 @@ fn run_app<B: ratatui::backend::Backend>(
 // ─── Drawing ──────────────────────────────────────────────────────────────
 fn ui(f: &mut Frame, app: &mut DashboardApp) {
     let area = f.size();
+    let theme = app.theme;
 
     // Background
-    f.render_widget(Block::default().style(Style::default().bg(COLOR_BG)), area);
+    f.render_widget(Block::default().style(Style::default().bg(theme.bg)), area);
 
     // ── Outer layout: header + body + footer ──────────────────────────────
     let outer = Layout::default()
@@ -733,27 +1292,28 @@ fn ui(f: &mut Frame, app: &mut DashboardApp) {
 
     // Help overlay
     if app.show_help {
-        render_help_overlay(f, area);
+        render_help_overlay(f, area, &theme);
     }
 }
 
 // ─── Header ───────────────────────────────────────────────────────────────
 fn render_header(f: &mut Frame, app: &DashboardApp, area: Rect) {
+    let theme = app.theme;
     let title_line = Line::from(vec![
         Span::styled(
             " ◆ SOROBAN DEBUGGER ",
             Style::default()
-                .fg(COLOR_ACCENT)
+                .fg(theme.accent)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled("│ ", Style::default().fg(COLOR_BORDER)),
+        Span::styled("│ ", Style::default().fg(theme.border)),
         Span::styled(
             format!(" fn: {} ", app.function_name),
             Style::default()
-                .fg(COLOR_PURPLE)
+                .fg(theme.purple)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled("│ ", Style::default().fg(COLOR_BORDER)),
+        Span::styled("│ ", Style::default().fg(theme.border)),
         Span::styled(
             format!(
                 " CPU: {:.1}%  MEM: {:.1}% ",
@@ -761,18 +1321,18 @@ fn render_header(f: &mut Frame, app: &DashboardApp, area: Rect) {
                 app.budget_info.memory_percentage()
             ),
             Style::default()
-                .fg(gauge_color(app.budget_info.cpu_percentage()))
+                .fg(gauge_color(&theme, app.budget_info.cpu_percentage()))
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled("│ ", Style::default().fg(COLOR_BORDER)),
+        Span::styled("│ ", Style::default().fg(theme.border)),
         Span::styled(
             format!(" Steps: {} ", app.step_count),
-            Style::default().fg(COLOR_CYAN),
+            Style::default().fg(theme.cyan),
         ),
-        Span::styled("│ ", Style::default().fg(COLOR_BORDER)),
+        Span::styled("│ ", Style::default().fg(theme.border)),
         Span::styled(
-            " [?]Help  [q]Quit  [Tab]Pane  [s]Step  [c]Continue ",
-            Style::default().fg(COLOR_TEXT_DIM),
+            " [?]Help  [q]Quit  [Tab]Pane  [s]Step  [c]Continue  [b]Breakpoint ",
+            Style::default().fg(theme.text_dim),
         ),
     ]);
 
@@ -781,8 +1341,8 @@ fn render_header(f: &mut Frame, app: &DashboardApp, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(COLOR_ACCENT))
-                .style(Style::default().bg(COLOR_SURFACE)),
+                .border_style(Style::default().fg(theme.accent))
+                .style(Style::default().bg(theme.surface)),
         )
         .alignment(Alignment::Left);
 
@@ -820,7 +1380,21 @@ fn render_body(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
         render_execution(f, app, center_column[0]);
         render_storage(f, app, center_column[1]);
         render_log(f, app, center_column[2]);
-        render_diagnostics(f, app, columns[2]);
+
+        let right_column = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+            ])
+            .split(columns[2]);
+
+        render_diagnostics(f, app, right_column[0]);
+        render_events(f, app, right_column[1]);
+        render_auth(f, app, right_column[2]);
+        render_breakpoints(f, app, right_column[3]);
     } else {
         let columns = Layout::default()
             .direction(Direction::Horizontal)
@@ -830,9 +1404,11 @@ fn render_body(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
         let left_column = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Percentage(38),
-                Constraint::Percentage(27),
-                Constraint::Percentage(35),
+                Constraint::Percentage(25),
+                Constraint::Percentage(20),
+                Constraint::Percentage(15),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
             ])
             .split(columns[0]);
 
@@ -840,24 +1416,29 @@ fn render_body(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(7),
-                Constraint::Percentage(45),
-                Constraint::Percentage(55),
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
+                Constraint::Percentage(30),
             ])
             .split(columns[1]);
 
         render_call_stack(f, app, left_column[0]);
         render_budget(f, app, left_column[1]);
         render_diagnostics(f, app, left_column[2]);
+        render_auth(f, app, left_column[3]);
+        render_breakpoints(f, app, left_column[4]);
         render_execution(f, app, right_column[0]);
         render_storage(f, app, right_column[1]);
         render_log(f, app, right_column[2]);
+        render_events(f, app, right_column[3]);
     }
 }
 
 // ─── Call Stack pane ──────────────────────────────────────────────────────
 fn render_execution(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
+    let theme = app.theme;
     let is_active = app.active_pane == ActivePane::Execution;
-    let block = pane_block("  Execution", "1", is_active);
+    let block = pane_block(&theme, "  Execution", "1", is_active);
     let inner = block.inner(area);
     f.render_widget(block, area);
 
@@ -891,22 +1472,22 @@ fn render_execution(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
 
     let mut lines = vec![
         Line::from(vec![
-            Span::styled("Status: ", Style::default().fg(COLOR_TEXT_DIM)),
-            Span::styled(status, Style::default().fg(COLOR_ACCENT)),
-            Span::styled("  │  ", Style::default().fg(COLOR_BORDER)),
-            Span::styled("Paused: ", Style::default().fg(COLOR_TEXT_DIM)),
+            Span::styled("Status: ", Style::default().fg(theme.text_dim)),
+            Span::styled(status, Style::default().fg(theme.accent)),
+            Span::styled("  │  ", Style::default().fg(theme.border)),
+            Span::styled("Paused: ", Style::default().fg(theme.text_dim)),
             Span::styled(
                 format!("{}", paused),
-                Style::default().fg(if paused { COLOR_YELLOW } else { COLOR_GREEN }),
+                Style::default().fg(if paused { theme.yellow } else { theme.green }),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Fn: ", Style::default().fg(COLOR_TEXT_DIM)),
-            Span::styled(current_fn, Style::default().fg(COLOR_PURPLE)),
+            Span::styled("Fn: ", Style::default().fg(theme.text_dim)),
+            Span::styled(current_fn, Style::default().fg(theme.purple)),
         ]),
         Line::from(vec![
-            Span::styled("Args: ", Style::default().fg(COLOR_TEXT_DIM)),
-            Span::styled(arg_text, Style::default().fg(COLOR_TEXT)),
+            Span::styled("Args: ", Style::default().fg(theme.text_dim)),
+            Span::styled(arg_text, Style::default().fg(theme.text)),
         ]),
     ];
 
@@ -914,8 +1495,8 @@ fn render_execution(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
     if paused {
         if let Some(reason) = app.engine.pause_reason_label() {
             lines.push(Line::from(vec![
-                Span::styled("Pause reason: ", Style::default().fg(COLOR_TEXT_DIM)),
-                Span::styled(reason, Style::default().fg(COLOR_YELLOW)),
+                Span::styled("Pause reason: ", Style::default().fg(theme.text_dim)),
+                Span::styled(reason, Style::default().fg(theme.yellow)),
             ]));
         }
         if let Some(loc) = app.engine.current_source_location() {
@@ -923,10 +1504,10 @@ fn render_execution(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
             let line = loc.line;
             let col = loc.column.map(|c| format!(":{}", c)).unwrap_or_default();
             lines.push(Line::from(vec![
-                Span::styled("Paused at: ", Style::default().fg(COLOR_TEXT_DIM)),
+                Span::styled("Paused at: ", Style::default().fg(theme.text_dim)),
                 Span::styled(
                     format!("{}:{}{}", file, line, col),
-                    Style::default().fg(COLOR_YELLOW),
+                    Style::default().fg(theme.yellow),
                 ),
             ]));
         }
@@ -934,30 +1515,31 @@ fn render_execution(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
 
     if let Some(result) = &app.last_result {
         lines.push(Line::from(vec![
-            Span::styled("Result: ", Style::default().fg(COLOR_TEXT_DIM)),
-            Span::styled(result.clone(), Style::default().fg(COLOR_GREEN)),
+            Span::styled("Result: ", Style::default().fg(theme.text_dim)),
+            Span::styled(result.clone(), Style::default().fg(theme.green)),
         ]));
     } else if let Some(err) = &app.last_error {
         lines.push(Line::from(vec![
-            Span::styled("Error: ", Style::default().fg(COLOR_TEXT_DIM)),
-            Span::styled(err.clone(), Style::default().fg(COLOR_RED)),
+            Span::styled("Error: ", Style::default().fg(theme.text_dim)),
+            Span::styled(err.clone(), Style::default().fg(theme.red)),
         ]));
     } else {
         lines.push(Line::from(vec![Span::styled(
             "Result: (none yet)",
-            Style::default().fg(COLOR_TEXT_DIM),
+            Style::default().fg(theme.text_dim),
         )]));
     }
 
     let exec_widget = Paragraph::new(lines)
-        .style(Style::default().bg(COLOR_SURFACE))
+        .style(Style::default().bg(theme.surface))
         .wrap(Wrap { trim: true });
     f.render_widget(exec_widget, inner);
 }
 
 fn render_call_stack(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
+    let theme = app.theme;
     let is_active = app.active_pane == ActivePane::CallStack;
-    let block = pane_block("  Call Stack", "2", is_active);
+    let block = pane_block(&theme, "  Call Stack", "2", is_active);
 
     let inner = block.inner(area);
     f.render_widget(block, area);
@@ -965,9 +1547,9 @@ fn render_call_stack(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
     if app.call_stack_frames.is_empty() {
         let empty = Paragraph::new(Line::from(vec![Span::styled(
             "  (empty — no execution active)",
-            Style::default().fg(COLOR_TEXT_DIM),
+            Style::default().fg(theme.text_dim),
         )]))
-        .style(Style::default().bg(COLOR_SURFACE));
+        .style(Style::default().bg(theme.surface));
         f.render_widget(empty, inner);
         return;
     }
@@ -993,7 +1575,7 @@ fn render_call_stack(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
                 .map(|d| format!(" ({:.2}ms)", d.as_secs_f64() * 1000.0))
                 .unwrap_or_default();
 
-            let func_color = if is_top { COLOR_ACCENT } else { COLOR_TEXT };
+            let func_color = if is_top { theme.accent } else { theme.text };
             let frame_style = if is_top {
                 Style::default()
                     .fg(func_color)
@@ -1006,11 +1588,11 @@ fn render_call_stack(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
             ListItem::new(Line::from(vec![
                 Span::styled(
                     format!("{}{}", indent, arrow),
-                    Style::default().fg(COLOR_TEXT_DIM),
+                    Style::default().fg(theme.text_dim),
                 ),
                 Span::styled(frame.function.clone(), frame_style),
-                Span::styled(contract_ctx, Style::default().fg(COLOR_PURPLE)),
-                Span::styled(dur_ctx, Style::default().fg(COLOR_TEXT_DIM)),
+                Span::styled(contract_ctx, Style::default().fg(theme.purple)),
+                Span::styled(dur_ctx, Style::default().fg(theme.text_dim)),
             ]))
         })
         .collect();
@@ -1028,6 +1610,7 @@ fn render_call_stack(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
 
 // ─── Storage pane ─────────────────────────────────────────────────────────
 fn render_storage(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
+    let theme = app.theme;
     let is_active = app.active_pane == ActivePane::Storage;
     let count = app.storage_entries.len();
     let matched = app.storage_filtered_len();
@@ -1036,7 +1619,7 @@ fn render_storage(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
     } else {
         format!("  Storage  ({} / {} entries)", matched, count)
     };
-    let block = pane_block(&title, "3", is_active);
+    let block = pane_block(&theme, &title, "3", is_active);
 
     let inner = block.inner(area);
     f.render_widget(block, area);
@@ -1075,18 +1658,18 @@ fn render_storage(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
         )
     };
     let meta = Paragraph::new(vec![
-        Line::from(Span::styled(summary, Style::default().fg(COLOR_TEXT_DIM))),
-        Line::from(Span::styled(filter_line, Style::default().fg(COLOR_TEXT_DIM))),
+        Line::from(Span::styled(summary, Style::default().fg(theme.text_dim))),
+        Line::from(Span::styled(filter_line, Style::default().fg(theme.text_dim))),
     ])
-    .style(Style::default().bg(COLOR_SURFACE));
+    .style(Style::default().bg(theme.surface));
     f.render_widget(meta, sections[0]);
 
     if app.storage_entries.is_empty() {
         let msg = Paragraph::new(Line::from(vec![Span::styled(
             "  (no storage captured — run a contract to populate)",
-            Style::default().fg(COLOR_TEXT_DIM),
+            Style::default().fg(theme.text_dim),
         )]))
-        .style(Style::default().bg(COLOR_SURFACE))
+        .style(Style::default().bg(theme.surface))
         .wrap(Wrap { trim: false });
         f.render_widget(msg, list_region);
         return;
@@ -1095,13 +1678,13 @@ fn render_storage(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
     if page.entries.is_empty() {
         let msg = Paragraph::new(Line::from(vec![Span::styled(
             "  (no storage entries match the current filter)",
-            Style::default().fg(COLOR_TEXT_DIM),
+            Style::default().fg(theme.text_dim),
         )]))
-        .style(Style::default().bg(COLOR_SURFACE))
+        .style(Style::default().bg(theme.surface))
         .wrap(Wrap { trim: false });
         f.render_widget(msg, list_region);
         if let Some(mode) = app.storage_input_mode {
-            render_storage_prompt(f, area, mode, &app.storage_input_value);
+            render_storage_prompt(f, area, &theme, mode, &app.storage_input_value);
         }
         return;
     }
@@ -1119,14 +1702,14 @@ fn render_storage(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
             ListItem::new(Line::from(vec![
                 Span::styled(
                     format!("{:>4} ", page.page_start + offset + 1),
-                    Style::default().fg(COLOR_TEXT_DIM),
+                    Style::default().fg(theme.text_dim),
                 ),
                 Span::styled(
                     key_display,
-                    Style::default().fg(COLOR_CYAN).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.cyan).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" = ", Style::default().fg(COLOR_TEXT_DIM)),
-                Span::styled(val_display, Style::default().fg(COLOR_TEXT)),
+                Span::styled(" = ", Style::default().fg(theme.text_dim)),
+                Span::styled(val_display, Style::default().fg(theme.text)),
             ]))
         })
         .collect();
@@ -1156,18 +1739,24 @@ fn render_storage(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
         Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some("↑"))
             .end_symbol(Some("↓"))
-            .style(Style::default().fg(COLOR_BORDER)),
+            .style(Style::default().fg(theme.border)),
         scroll_area,
         &mut app.storage_scroll_state,
     );
 
     if let Some(mode) = app.storage_input_mode {
-        render_storage_prompt(f, area, mode, &app.storage_input_value);
+        render_storage_prompt(f, area, &theme, mode, &app.storage_input_value);
     }
 }
 
 // ─── Budget pane ──────────────────────────────────────────────────────────
-fn render_storage_prompt(f: &mut Frame, area: Rect, mode: StorageInputMode, input: &str) {
+fn render_storage_prompt(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    mode: StorageInputMode,
+    input: &str,
+) {
     let popup_width = 64u16.min(area.width.saturating_sub(4));
     let popup_height = 5u16.min(area.height.saturating_sub(2));
     let x = area.x + area.width.saturating_sub(popup_width) / 2;
@@ -1183,11 +1772,11 @@ fn render_storage_prompt(f: &mut Frame, area: Rect, mode: StorageInputMode, inpu
     };
 
     let widget = Paragraph::new(vec![
-        Line::from(Span::styled(hint, Style::default().fg(COLOR_TEXT_DIM))),
+        Line::from(Span::styled(hint, Style::default().fg(theme.text_dim))),
         Line::from(""),
         Line::from(vec![
-            Span::styled("> ", Style::default().fg(COLOR_ACCENT)),
-            Span::styled(input.to_string(), Style::default().fg(COLOR_TEXT)),
+            Span::styled("> ", Style::default().fg(theme.accent)),
+            Span::styled(input.to_string(), Style::default().fg(theme.text)),
         ]),
     ])
     .block(
@@ -1195,21 +1784,22 @@ fn render_storage_prompt(f: &mut Frame, area: Rect, mode: StorageInputMode, inpu
             .title(Span::styled(
                 title,
                 Style::default()
-                    .fg(COLOR_ACCENT)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD),
             ))
             .borders(Borders::ALL)
             .border_type(BorderType::Double)
-            .border_style(Style::default().fg(COLOR_ACCENT))
-            .style(Style::default().bg(COLOR_SURFACE)),
+            .border_style(Style::default().fg(theme.accent))
+            .style(Style::default().bg(theme.surface)),
     );
 
     f.render_widget(widget, popup);
 }
 
 fn render_budget(f: &mut Frame, app: &DashboardApp, area: Rect) {
+    let theme = app.theme;
     let is_active = app.active_pane == ActivePane::Budget;
-    let block = pane_block("  Budget Meters", "4", is_active);
+    let block = pane_block(&theme, "  Budget Meters", "4", is_active);
 
     let inner = block.inner(area);
     f.render_widget(block, area);
@@ -1230,11 +1820,11 @@ fn render_budget(f: &mut Frame, app: &DashboardApp, area: Rect) {
 
     // ── CPU ─────────────────────────────────────────────────────────
     let cpu_pct = app.budget_info.cpu_percentage();
-    let cpu_color = gauge_color(cpu_pct);
+    let cpu_color = gauge_color(&theme, cpu_pct);
     let cpu_label = Paragraph::new(Line::from(vec![
         Span::styled(
             "  CPU Instructions  ",
-            Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
         ),
         Span::styled(
             format!(
@@ -1242,7 +1832,7 @@ fn render_budget(f: &mut Frame, app: &DashboardApp, area: Rect) {
                 fmt_num(app.budget_info.cpu_instructions),
                 fmt_num(app.budget_info.cpu_limit)
             ),
-            Style::default().fg(COLOR_TEXT_DIM),
+            Style::default().fg(theme.text_dim),
         ),
         Span::styled(
             format!("  {:>6.2}%", cpu_pct),
@@ -1254,7 +1844,7 @@ fn render_budget(f: &mut Frame, app: &DashboardApp, area: Rect) {
     let cpu_gauge = Gauge::default()
         .gauge_style(
             Style::default()
-                .fg(COLOR_CPU_FILL)
+                .fg(theme.cpu_fill)
                 .bg(Color::Rgb(30, 40, 60)),
         )
         .percent(cpu_pct.min(100.0) as u16)
@@ -1268,11 +1858,11 @@ fn render_budget(f: &mut Frame, app: &DashboardApp, area: Rect) {
 
     // ── MEM ─────────────────────────────────────────────────────────
     let mem_pct = app.budget_info.memory_percentage();
-    let mem_color = gauge_color(mem_pct);
+    let mem_color = gauge_color(&theme, mem_pct);
     let mem_label = Paragraph::new(Line::from(vec![
         Span::styled(
             "  Memory Bytes      ",
-            Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
         ),
         Span::styled(
             format!(
@@ -1280,7 +1870,7 @@ fn render_budget(f: &mut Frame, app: &DashboardApp, area: Rect) {
                 fmt_bytes(app.budget_info.memory_bytes),
                 fmt_bytes(app.budget_info.memory_limit)
             ),
-            Style::default().fg(COLOR_TEXT_DIM),
+            Style::default().fg(theme.text_dim),
         ),
         Span::styled(
             format!("  {:>6.2}%", mem_pct),
@@ -1292,7 +1882,7 @@ fn render_budget(f: &mut Frame, app: &DashboardApp, area: Rect) {
     let mem_gauge = Gauge::default()
         .gauge_style(
             Style::default()
-                .fg(COLOR_MEM_FILL)
+                .fg(theme.mem_fill)
                 .bg(Color::Rgb(20, 45, 35)),
         )
         .percent(mem_pct.min(100.0) as u16)
@@ -1311,8 +1901,18 @@ fn render_budget(f: &mut Frame, app: &DashboardApp, area: Rect) {
             .constraints([Constraint::Length(1); 2])
             .split(rows[6]);
 
-        let cpu_spark = build_sparkline(&app.budget_history_cpu, "CPU trend: ", COLOR_CPU_FILL);
-        let mem_spark = build_sparkline(&app.budget_history_mem, "MEM trend: ", COLOR_MEM_FILL);
+        let cpu_spark = build_sparkline(
+            &theme,
+            &app.budget_history_cpu,
+            "CPU trend: ",
+            theme.cpu_fill,
+        );
+        let mem_spark = build_sparkline(
+            &theme,
+            &app.budget_history_mem,
+            "MEM trend: ",
+            theme.mem_fill,
+        );
 
         if !sparkline_row.is_empty() {
             f.render_widget(Paragraph::new(cpu_spark), sparkline_row[0]);
@@ -1323,7 +1923,12 @@ fn render_budget(f: &mut Frame, app: &DashboardApp, area: Rect) {
     }
 }
 
-fn build_sparkline(history: &VecDeque<f64>, prefix: &str, color: Color) -> Line<'static> {
+fn build_sparkline(
+    theme: &Theme,
+    history: &VecDeque<f64>,
+    prefix: &str,
+    color: Color,
+) -> Line<'static> {
     let bar_chars = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
     let spark: String = history
         .iter()
@@ -1334,31 +1939,68 @@ fn build_sparkline(history: &VecDeque<f64>, prefix: &str, color: Color) -> Line<
         .collect();
 
     Line::from(vec![
-        Span::styled(format!("  {}", prefix), Style::default().fg(COLOR_TEXT_DIM)),
+        Span::styled(format!("  {}", prefix), Style::default().fg(theme.text_dim)),
         Span::styled(spark, Style::default().fg(color)),
     ])
 }
 
 // ─── Log pane ─────────────────────────────────────────────────────────────
 fn render_log(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
+    let theme = app.theme;
     let is_active = app.active_pane == ActivePane::Log;
     let count = app.log_entries.len();
-    let title = format!("  Execution Log  ({} events)", count);
-    let block = pane_block(&title, "5", is_active);
+    let total = app.visible_log.len();
+    let title = if total == count {
+        format!("  Execution Log  ({} events)", count)
+    } else {
+        format!("  Execution Log  ({} of {} events)", total, count)
+    };
+    let block = pane_block(&theme, &title, "5", is_active);
 
-    let inner = block.inner(area);
+    let mut inner = block.inner(area);
     f.render_widget(block, area);
 
-    if app.log_entries.is_empty() {
-        let msg =
-            Paragraph::new("  (no log entries yet)").style(Style::default().fg(COLOR_TEXT_DIM));
+    if app.log_filter_editing
+        || !app.log_filter.is_empty()
+        || app.log_level_filter != LogLevelFilter::All
+    {
+        let prompt_area = Rect {
+            y: inner.y + inner.height.saturating_sub(1),
+            height: inner.height.min(1),
+            ..inner
+        };
+        inner.height = inner.height.saturating_sub(1);
+        let cursor = if app.log_filter_editing { "_" } else { "" };
+        let prompt = Line::from(vec![
+            Span::styled("  / ", Style::default().fg(theme.accent)),
+            Span::styled(
+                format!("{}{}", app.log_filter, cursor),
+                Style::default().fg(theme.text),
+            ),
+            Span::styled(
+                format!(
+                    "   levels: {}  n/N=match  v=levels  x=clear",
+                    app.log_level_filter.label()
+                ),
+                Style::default().fg(theme.text_dim),
+            ),
+        ]);
+        f.render_widget(Paragraph::new(prompt), prompt_area);
+    }
+
+    if total == 0 {
+        let text = if app.log_entries.is_empty() {
+            "  (no log entries yet)"
+        } else {
+            "  (no log entries match the filter)"
+        };
+        let msg = Paragraph::new(text).style(Style::default().fg(theme.text_dim));
         f.render_widget(msg, inner);
         return;
     }
 
     // Determine the window of lines to show
     let visible_height = inner.height as usize;
-    let total = app.log_entries.len();
 
     // Keep scroll in bounds
     if app.log_scroll >= total {
@@ -1371,21 +2013,33 @@ fn render_log(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
         0
     };
     let end = (start + visible_height).min(total);
+    let highlight_match = !app.log_filter.is_empty();
 
-    let lines: Vec<Line> = app.log_entries[start..end]
-        .iter()
-        .map(|entry| {
+    let lines: Vec<Line> = app
+        .visible_log
+        .range(start..end)
+        .enumerate()
+        .map(|(offset, &position)| {
+            let entry = app.log_entry(position);
             let (level_str, level_color) = match entry.level {
-                LogLevel::Info => (" INFO ", COLOR_ACCENT),
-                LogLevel::Warn => (" WARN ", COLOR_YELLOW),
-                LogLevel::Error => (" ERR  ", COLOR_RED),
-                LogLevel::Debug => (" DBG  ", COLOR_TEXT_DIM),
-                LogLevel::Step => (" STEP ", COLOR_GREEN),
+                LogLevel::Info => (" INFO ", theme.accent),
+                LogLevel::Warn => (" WARN ", theme.yellow),
+                LogLevel::Error => (" ERR  ", theme.red),
+                LogLevel::Debug => (" DBG  ", theme.text_dim),
+                LogLevel::Step => (" STEP ", theme.green),
+            };
+            let message_style = if highlight_match && start + offset == app.log_scroll {
+                Style::default()
+                    .fg(theme.text)
+                    .bg(Color::Rgb(45, 50, 72))
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
             };
             Line::from(vec![
                 Span::styled(
                     format!(" {} ", entry.timestamp),
-                    Style::default().fg(COLOR_TEXT_DIM),
+                    Style::default().fg(theme.text_dim),
                 ),
                 Span::styled(
                     level_str,
@@ -1395,7 +2049,7 @@ fn render_log(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw("  "),
-                Span::styled(entry.message.clone(), Style::default().fg(COLOR_TEXT)),
+                Span::styled(entry.message.clone(), message_style),
             ])
         })
         .collect();
@@ -1412,24 +2066,309 @@ fn render_log(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
         ..inner
     };
 
-    let log_widget = Paragraph::new(lines).style(Style::default().bg(COLOR_SURFACE));
+    let log_widget = Paragraph::new(lines).style(Style::default().bg(theme.surface));
     f.render_widget(log_widget, text_area);
 
     f.render_stateful_widget(
         Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some("↑"))
             .end_symbol(Some("↓"))
-            .style(Style::default().fg(COLOR_BORDER)),
+            .style(Style::default().fg(theme.border)),
         scroll_area,
         &mut app.log_scroll_state,
     );
 }
 
+// ─── Events pane ──────────────────────────────────────────────────────────
+fn render_events(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
+    let theme = app.theme;
+    let is_active = app.active_pane == ActivePane::Events;
+    let title = format!("  Events  ({} emitted)", app.events.len());
+    let block = pane_block(&theme, &title, "7", is_active);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.events.is_empty() {
+        let msg =
+            Paragraph::new("  (no events emitted yet)").style(Style::default().fg(theme.text_dim));
+        f.render_widget(msg, inner);
+        return;
+    }
+
+    let visible_height = inner.height as usize;
+    let total = app.events.len();
+
+    if app.events_scroll >= total {
+        app.events_scroll = total.saturating_sub(1);
+    }
+
+    let start = if total > visible_height {
+        app.events_scroll.min(total - visible_height)
+    } else {
+        0
+    };
+    let end = (start + visible_height).min(total);
+
+    let lines: Vec<Line> = app.events[start..end]
+        .iter()
+        .enumerate()
+        .map(|(offset, event)| {
+            let contract = event
+                .contract_id
+                .as_deref()
+                .map(shorten_id)
+                .unwrap_or_else(|| "-".to_string());
+            Line::from(vec![
+                Span::styled(
+                    format!(" #{:<3}", start + offset),
+                    Style::default().fg(theme.text_dim),
+                ),
+                Span::styled(format!("{} ", contract), Style::default().fg(theme.purple)),
+                Span::styled(
+                    format!("[{}]", event.topics.join(", ")),
+                    Style::default().fg(theme.cyan),
+                ),
+                Span::styled(" → ", Style::default().fg(theme.text_dim)),
+                Span::styled(event.data.clone(), Style::default().fg(theme.text)),
+            ])
+        })
+        .collect();
+
+    let scroll_area = Rect {
+        x: inner.x + inner.width.saturating_sub(1),
+        y: inner.y,
+        width: 1,
+        height: inner.height,
+    };
+    let text_area = Rect {
+        width: inner.width.saturating_sub(1),
+        ..inner
+    };
+
+    let events_widget = Paragraph::new(lines).style(Style::default().bg(theme.surface));
+    f.render_widget(events_widget, text_area);
+
+    f.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("↑"))
+            .end_symbol(Some("↓"))
+            .style(Style::default().fg(theme.border)),
+        scroll_area,
+        &mut app.events_scroll_state,
+    );
+}
+
+// ─── Auth pane ────────────────────────────────────────────────────────────
+
+/// Depth-first list of auth nodes with their nesting depth, in display order.
+fn flatten_auth_tree(nodes: &[AuthNode]) -> Vec<(usize, &AuthNode)> {
+    fn visit<'a>(node: &'a AuthNode, depth: usize, out: &mut Vec<(usize, &'a AuthNode)>) {
+        out.push((depth, node));
+        for sub in &node.sub_invocations {
+            visit(sub, depth + 1, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    for node in nodes {
+        visit(node, 0, &mut out);
+    }
+    out
+}
+
+fn render_auth(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
+    let theme = app.theme;
+    let is_active = app.active_pane == ActivePane::Auth;
+    let rows = flatten_auth_tree(&app.auth_nodes);
+    let title = format!("  Auth Tree  ({} signer(s))", app.auth_nodes.len());
+    let block = pane_block(&theme, &title, "8", is_active);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if rows.is_empty() {
+        let msg = Paragraph::new("  (no authorizations recorded)")
+            .style(Style::default().fg(theme.text_dim));
+        f.render_widget(msg, inner);
+        return;
+    }
+
+    let visible_height = inner.height as usize;
+    let total = rows.len();
+    let start = if total > visible_height {
+        app.auth_scroll.min(total - visible_height)
+    } else {
+        0
+    };
+    let end = (start + visible_height).min(total);
+
+    let lines: Vec<Line> = rows[start..end]
+        .iter()
+        .map(|(depth, node)| {
+            let (status, status_color) = match node.status {
+                AuthStatus::Authorized => ("✓", theme.green),
+                AuthStatus::Missing => ("?", theme.red),
+                AuthStatus::Failed => ("✗", theme.red),
+            };
+            let mut spans = vec![Span::raw(" ")];
+            if *depth == 0 {
+                spans.push(Span::styled(
+                    format!("{} ", shorten_id(&node.address)),
+                    Style::default()
+                        .fg(theme.yellow)
+                        .add_modifier(Modifier::BOLD),
+                ));
+                spans.push(Span::styled("→ ", Style::default().fg(theme.text_dim)));
+            } else {
+                spans.push(Span::styled(
+                    format!("{}└─ ", "   ".repeat(depth - 1)),
+                    Style::default().fg(theme.border),
+                ));
+            }
+            spans.push(Span::styled(
+                format!("{} ", status),
+                Style::default().fg(status_color),
+            ));
+            spans.push(Span::styled(
+                format!("{} ", shorten_id(&node.contract_id)),
+                Style::default().fg(theme.purple),
+            ));
+            spans.push(Span::styled(
+                node.function.clone(),
+                Style::default().fg(theme.text),
+            ));
+            Line::from(spans)
+        })
+        .collect();
+
+    let scroll_area = Rect {
+        x: inner.x + inner.width.saturating_sub(1),
+        y: inner.y,
+        width: 1,
+        height: inner.height,
+    };
+    let text_area = Rect {
+        width: inner.width.saturating_sub(1),
+        ..inner
+    };
+
+    f.render_widget(
+        Paragraph::new(lines).style(Style::default().bg(theme.surface)),
+        text_area,
+    );
+    f.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("↑"))
+            .end_symbol(Some("↓"))
+            .style(Style::default().fg(theme.border)),
+        scroll_area,
+        &mut app.auth_scroll_state,
+    );
+}
+
+// ─── Breakpoints pane ─────────────────────────────────────────────────────
+/// Breakpoints in the order the breakpoints pane lists them.
+fn sorted_breakpoints(manager: &BreakpointManager) -> Vec<&Breakpoint> {
+    let mut breakpoints = manager.list_detailed();
+    breakpoints.sort_by(|a, b| a.function.cmp(&b.function));
+    breakpoints
+}
+
+fn render_breakpoints(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
+    let theme = app.theme;
+    let is_active = app.active_pane == ActivePane::Breakpoints;
+    let hit = app.hit_breakpoint();
+    let breakpoints = sorted_breakpoints(app.engine.breakpoints());
+    let title = format!("  Breakpoints  ({} set)", breakpoints.len());
+    let block = pane_block(&theme, &title, "9", is_active);
+
+    let mut inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if let Some(input) = &app.breakpoint_input {
+        let prompt_area = Rect {
+            y: inner.y + inner.height.saturating_sub(1),
+            height: inner.height.min(1),
+            ..inner
+        };
+        inner.height = inner.height.saturating_sub(1);
+        let prompt = Line::from(vec![
+            Span::styled("  b ", Style::default().fg(theme.accent)),
+            Span::styled(format!("{}_", input), Style::default().fg(theme.text)),
+            Span::styled(
+                "   Enter=toggle  Esc=cancel",
+                Style::default().fg(theme.text_dim),
+            ),
+        ]);
+        f.render_widget(Paragraph::new(prompt), prompt_area);
+    }
+
+    if breakpoints.is_empty() {
+        let msg = Paragraph::new("  (no breakpoints — press b to add one)")
+            .style(Style::default().fg(theme.text_dim));
+        f.render_widget(msg, inner);
+        return;
+    }
+
+    let items: Vec<ListItem> = breakpoints
+        .iter()
+        .map(|bp| {
+            let is_hit = hit.as_deref() == Some(bp.function.as_str());
+            let (marker, marker_color) = if is_hit {
+                ("▶ ", theme.yellow)
+            } else if bp.is_log_point() {
+                ("◇ ", theme.cyan)
+            } else {
+                ("● ", theme.red)
+            };
+            let name_style = if is_hit {
+                Style::default()
+                    .fg(theme.yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+
+            let mut spans = vec![
+                Span::styled(marker, Style::default().fg(marker_color)),
+                Span::styled(bp.function.clone(), name_style),
+                Span::styled(
+                    format!("  hits: {}", bp.hit_count),
+                    Style::default().fg(theme.text_dim),
+                ),
+            ];
+            if let Some(condition) = &bp.condition {
+                spans.push(Span::styled(
+                    format!("  when {}", condition),
+                    Style::default().fg(theme.purple),
+                ));
+            }
+            if is_hit {
+                spans.push(Span::styled(
+                    "  ← hit",
+                    Style::default()
+                        .fg(theme.yellow)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("› ");
+
+    f.render_stateful_widget(list, inner, &mut app.breakpoints_state);
+}
+
 // ─── Status bar ───────────────────────────────────────────────────────────
 fn render_diagnostics(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
+    let theme = app.theme;
     let is_active = app.active_pane == ActivePane::Diagnostics;
     let title = format!("  Diagnostics  ({} active)", app.diagnostics.len());
-    let block = pane_block(&title, "6", is_active);
+    let block = pane_block(&theme, &title, "6", is_active);
     let inner = block.inner(area);
     f.render_widget(block, area);
 
@@ -1437,11 +2376,11 @@ fn render_diagnostics(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
         let msg = Paragraph::new(vec![
             Line::from(Span::styled(
                 "  No active diagnostics.",
-                Style::default().fg(COLOR_GREEN),
+                Style::default().fg(theme.green),
             )),
             Line::from(Span::styled(
                 "  Warnings and notices will appear here.",
-                Style::default().fg(COLOR_TEXT_DIM),
+                Style::default().fg(theme.text_dim),
             )),
         ])
         .wrap(Wrap { trim: false });
@@ -1454,9 +2393,9 @@ fn render_diagnostics(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
         .iter()
         .map(|diagnostic| {
             let severity_color = match diagnostic.severity {
-                crate::output::DiagnosticSeverity::Notice => COLOR_ACCENT,
-                crate::output::DiagnosticSeverity::Warning => COLOR_YELLOW,
-                crate::output::DiagnosticSeverity::Error => COLOR_RED,
+                crate::output::DiagnosticSeverity::Notice => theme.accent,
+                crate::output::DiagnosticSeverity::Warning => theme.yellow,
+                crate::output::DiagnosticSeverity::Error => theme.red,
             };
 
             let mut lines = vec![
@@ -1472,20 +2411,20 @@ fn render_diagnostics(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
                     Span::styled(
                         diagnostic.source.to_uppercase(),
                         Style::default()
-                            .fg(COLOR_TEXT_DIM)
+                            .fg(theme.text_dim)
                             .add_modifier(Modifier::BOLD),
                     ),
                 ]),
                 Line::from(Span::styled(
                     format!(" {}", diagnostic.summary),
-                    Style::default().fg(COLOR_TEXT),
+                    Style::default().fg(theme.text),
                 )),
             ];
 
             if let Some(detail) = &diagnostic.detail {
                 lines.push(Line::from(Span::styled(
                     format!(" {}", detail),
-                    Style::default().fg(COLOR_TEXT_DIM),
+                    Style::default().fg(theme.text_dim),
                 )));
             }
 
@@ -1517,58 +2456,59 @@ fn render_diagnostics(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
         Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some("â†‘"))
             .end_symbol(Some("â†“"))
-            .style(Style::default().fg(COLOR_BORDER)),
+            .style(Style::default().fg(theme.border)),
         scroll_area,
         &mut app.diagnostics_scroll_state,
     );
 }
 
 fn render_status_bar(f: &mut Frame, app: &DashboardApp, area: Rect) {
+    let theme = app.theme;
     let active_label = app.active_pane.label();
     let (msg, msg_color) = if let Some((ref s, kind)) = app.status_message {
         let c = match kind {
-            StatusKind::Info => COLOR_ACCENT,
-            StatusKind::Error => COLOR_RED,
+            StatusKind::Info => theme.accent,
+            StatusKind::Error => theme.red,
         };
         (s.as_str(), c)
     } else {
-        ("Ready", COLOR_GREEN)
+        ("Ready", theme.green)
     };
 
     let line = Line::from(vec![
         Span::styled(
             format!(" ◆ Active: {} ", active_label),
             Style::default()
-                .fg(COLOR_ACCENT)
-                .bg(COLOR_SURFACE)
+                .fg(theme.accent)
+                .bg(theme.surface)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(" │ ", Style::default().fg(COLOR_BORDER).bg(COLOR_SURFACE)),
+        Span::styled(" │ ", Style::default().fg(theme.border).bg(theme.surface)),
         Span::styled(
             format!(" {} ", msg),
-            Style::default().fg(msg_color).bg(COLOR_SURFACE),
+            Style::default().fg(msg_color).bg(theme.surface),
         ),
         Span::styled(
-            " │ Tab=next pane  ↑↓/jk=scroll  s=step  c=continue  r=refresh  q=quit ",
-            Style::default().fg(COLOR_TEXT_DIM).bg(COLOR_SURFACE),
+            " │ Tab=next pane  ↑↓/jk=scroll  s=step  c=continue  b=breakpoint  r=refresh  q=quit ",
+            Style::default().fg(theme.text_dim).bg(theme.surface),
         ),
     ]);
 
-    let bar = Paragraph::new(line).style(Style::default().bg(COLOR_SURFACE));
+    let bar = Paragraph::new(line).style(Style::default().bg(theme.surface));
     f.render_widget(bar, area);
 }
 
 // ─── Help overlay ─────────────────────────────────────────────────────────
-fn render_help_overlay(f: &mut Frame, area: Rect) {
+fn render_help_overlay(f: &mut Frame, area: Rect, theme: &Theme) {
     // Center a 60×22 box
     let popup_width = 60u16.min(area.width.saturating_sub(4));
-    let popup_height = 24u16.min(area.height.saturating_sub(2));
+    let popup_height = 26u16.min(area.height.saturating_sub(2));
     let x = (area.width.saturating_sub(popup_width)) / 2 + area.x;
     let y = (area.height.saturating_sub(popup_height)) / 2 + area.y;
     let popup_area = Rect::new(x, y, popup_width, popup_height);
 
     f.render_widget(
-        Block::default().style(Style::default().bg(COLOR_BG)),
+        Block::default().style(Style::default().bg(theme.bg)),
         popup_area,
     );
 
@@ -1576,44 +2516,47 @@ fn render_help_overlay(f: &mut Frame, area: Rect) {
         Line::from(Span::styled(
             "  Keyboard Reference",
             Style::default()
-                .fg(COLOR_ACCENT)
+                .fg(theme.accent)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
         )),
         Line::from(""),
         Line::from(vec![Span::styled(
             "  Navigation",
             Style::default()
-                .fg(COLOR_PURPLE)
+                .fg(theme.purple)
                 .add_modifier(Modifier::BOLD),
         )]),
-        bind("Tab / Shift+Tab", "Cycle panes forward / backward"),
-        bind("1 / 2 / 3 / 4 / 5", "Jump directly to pane"),
-        bind("↑ / k", "Scroll active pane up"),
-        bind("↓ / j", "Scroll active pane down"),
+        bind(theme, "Tab / Shift+Tab", "Cycle panes forward / backward"),
+        bind(theme, "1 – 9", "Jump directly to pane"),
+        bind(theme, "↑ / k", "Scroll active pane up"),
+        bind(theme, "↓ / j", "Scroll active pane down"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "  Debugger Actions",
             Style::default()
-                .fg(COLOR_PURPLE)
+                .fg(theme.purple)
                 .add_modifier(Modifier::BOLD),
         )]),
-        bind("s / S", "Step (one instruction)"),
-        bind("c", "Continue execution"),
-        bind("r / R", "Refresh state manually"),
+        bind(theme, "s / S", "Step (one instruction)"),
+        bind(theme, "c", "Continue execution"),
+        bind(theme, "b / B", "Toggle a breakpoint on a function"),
+        bind(theme, "r / R", "Refresh state manually"),
+        bind(theme, "/  n / N", "Search log / next / previous match"),
+        bind(theme, "v", "Cycle log levels shown"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "  General",
             Style::default()
-                .fg(COLOR_PURPLE)
+                .fg(theme.purple)
                 .add_modifier(Modifier::BOLD),
         )]),
-        bind("?", "Toggle this help overlay"),
-        bind("q / Q", "Quit dashboard"),
-        bind("Ctrl+C", "Force quit"),
+        bind(theme, "?", "Toggle this help overlay"),
+        bind(theme, "q / Q", "Quit dashboard"),
+        bind(theme, "Ctrl+C", "Force quit"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "  Press ? again to close",
-            Style::default().fg(COLOR_TEXT_DIM),
+            Style::default().fg(theme.text_dim),
         )]),
     ];
 
@@ -1623,38 +2566,38 @@ fn render_help_overlay(f: &mut Frame, area: Rect) {
                 .title(Span::styled(
                     " Help ",
                     Style::default()
-                        .fg(COLOR_ACCENT)
+                        .fg(theme.accent)
                         .add_modifier(Modifier::BOLD),
                 ))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Double)
-                .border_style(Style::default().fg(COLOR_ACCENT))
-                .style(Style::default().bg(COLOR_SURFACE)),
+                .border_style(Style::default().fg(theme.accent))
+                .style(Style::default().bg(theme.surface)),
         )
         .wrap(Wrap { trim: false });
 
     f.render_widget(help_widget, popup_area);
 }
 
-fn bind(key: &'static str, desc: &'static str) -> Line<'static> {
+fn bind(theme: &Theme, key: &'static str, desc: &'static str) -> Line<'static> {
     Line::from(vec![
         Span::raw("    "),
         Span::styled(
             format!("{:<20}", key),
             Style::default()
-                .fg(COLOR_YELLOW)
+                .fg(theme.yellow)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(desc, Style::default().fg(COLOR_TEXT)),
+        Span::styled(desc, Style::default().fg(theme.text)),
     ])
 }
 
 // ─── Shared block builder ─────────────────────────────────────────────────
-fn pane_block(title: &str, num: &str, is_active: bool) -> Block<'static> {
+fn pane_block(theme: &Theme, title: &str, num: &str, is_active: bool) -> Block<'static> {
     let border_color = if is_active {
-        COLOR_BORDER_ACTIVE
+        theme.border_active
     } else {
-        COLOR_BORDER
+        theme.border
     };
     let title_str = format!("{}  [{}]", title, num);
     Block::default()
@@ -1662,9 +2605,9 @@ fn pane_block(title: &str, num: &str, is_active: bool) -> Block<'static> {
             title_str,
             Style::default()
                 .fg(if is_active {
-                    COLOR_ACCENT
+                    theme.accent
                 } else {
-                    COLOR_TEXT_DIM
+                    theme.text_dim
                 })
                 .add_modifier(if is_active {
                     Modifier::BOLD
@@ -1679,7 +2622,7 @@ fn pane_block(title: &str, num: &str, is_active: bool) -> Block<'static> {
             BorderType::Rounded
         })
         .border_style(Style::default().fg(border_color))
-        .style(Style::default().bg(COLOR_SURFACE))
+        .style(Style::default().bg(theme.surface))
 }
 
 // ─── Utilities ────────────────────────────────────────────────────────────
@@ -1694,27 +2637,18 @@ fn format_timestamp() -> String {
     format!("{:02}:{:02}:{:02}", hours, mins, s)
 }
 
-fn gauge_color(pct: f64) -> Color {
+fn gauge_color(theme: &Theme, pct: f64) -> Color {
     if pct >= 90.0 {
-        COLOR_RED
+        theme.red
     } else if pct >= 70.0 {
-        COLOR_YELLOW
+        theme.yellow
     } else {
-        COLOR_GREEN
+        theme.green
     }
 }
 
 fn fmt_num(n: u64) -> String {
-    // Insert thousands separators
-    let s = n.to_string();
-    let mut result = String::new();
-    for (i, c) in s.chars().rev().enumerate() {
-        if i > 0 && i % 3 == 0 {
-            result.push('_');
-        }
-        result.push(c);
-    }
-    result.chars().rev().collect()
+    Formatter::format_number(n)
 }
 
 fn fmt_bytes(bytes: u64) -> String {
@@ -1746,3 +2680,192 @@ fn shorten_id(id: &str) -> String {
         id.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::executor::ContractExecutor;
+
+    fn echo_app() -> DashboardApp {
+        let wasm = include_bytes!("../../tests/fixtures/wasm/echo.wasm").to_vec();
+        let executor = ContractExecutor::new(wasm).unwrap();
+        let mut engine = DebuggerEngine::new(executor, vec![]);
+        engine.stage_execution("echo", Some("[7]"));
+        DashboardApp::new(
+            engine,
+            "echo".to_string(),
+            NetworkLimits::default(),
+            Theme::default(),
+        )
+    }
+
+    #[test]
+    fn toggle_breakpoint_adds_and_removes() {
+        let mut app = echo_app();
+        app.toggle_breakpoint("echo");
+        assert!(app.engine.breakpoints().get("echo").is_some());
+
+        app.toggle_breakpoint("echo");
+        assert!(app.engine.breakpoints().get("echo").is_none());
+    }
+
+    #[test]
+    fn continue_stops_and_highlights_hit_breakpoint() {
+        let mut app = echo_app();
+        app.toggle_breakpoint("echo");
+
+        app.do_continue();
+        assert_eq!(app.hit_breakpoint().as_deref(), Some("echo"));
+        assert!(app.pending_execution.is_some());
+        assert!(app.last_result.is_none());
+
+        app.do_continue();
+        assert!(app.hit_breakpoint().is_none());
+        assert!(app.last_result.is_some());
+    }
+
+    #[test]
+    fn step_stops_at_entry_breakpoint_before_running() {
+        let mut app = echo_app();
+        app.toggle_breakpoint("echo");
+
+        app.do_step();
+        assert_eq!(app.hit_breakpoint().as_deref(), Some("echo"));
+        assert_eq!(app.engine.breakpoints().hit_count("echo"), 1);
+        assert!(app.pending_execution.is_some());
+
+        app.do_continue();
+        assert!(app.hit_breakpoint().is_none());
+        assert!(app.last_result.is_some());
+    }
+
+    #[test]
+    fn mono_theme_uses_no_rgb_colors() {
+        let theme = Theme::new(ThemeName::Mono);
+        let colors = [
+            theme.bg,
+            theme.surface,
+            theme.border,
+            theme.border_active,
+            theme.text,
+            theme.text_dim,
+            theme.accent,
+            theme.green,
+            theme.yellow,
+            theme.red,
+            theme.purple,
+            theme.cyan,
+            theme.cpu_fill,
+            theme.mem_fill,
+        ];
+        assert!(colors.iter().all(|c| !matches!(c, Color::Rgb(..))));
+        assert_eq!(Theme::default().bg, Theme::dark().bg);
+    }
+
+    #[test]
+    fn pane_cycle_visits_events_and_wraps() {
+        let mut pane = ActivePane::Execution;
+        let mut visited = Vec::new();
+        for _ in 0..9 {
+            pane = pane.next();
+            visited.push(pane);
+        }
+        assert_eq!(pane, ActivePane::Execution);
+        assert_eq!(visited[5], ActivePane::Events);
+        assert_eq!(visited[6], ActivePane::Auth);
+        assert_eq!(visited[7], ActivePane::Breakpoints);
+        assert_eq!(ActivePane::Execution.prev(), ActivePane::Breakpoints);
+        assert_eq!(ActivePane::Events.prev(), ActivePane::Diagnostics);
+    }
+
+    #[test]
+    fn log_keeps_the_filtered_view_while_dropping_old_entries() {
+        let mut app = echo_app();
+        app.log_filter = "even".to_string();
+        app.refresh_visible_log();
+
+        for i in 0..MAX_LOG_ENTRIES + 10 {
+            let parity = if i % 2 == 0 { "even" } else { "odd" };
+            app.push_log(LogLevel::Info, format!("entry {i} {parity}"));
+        }
+
+        assert_eq!(app.log_entries.len(), MAX_LOG_ENTRIES);
+        let cached: Vec<usize> = app.visible_log.iter().copied().collect();
+        app.refresh_visible_log();
+        assert_eq!(cached, app.visible_log.iter().copied().collect::<Vec<_>>());
+        assert_eq!(
+            app.log_entry(*app.visible_log.back().unwrap()).message,
+            format!("entry {} even", MAX_LOG_ENTRIES + 8)
+        );
+    }
+
+    #[test]
+    fn log_filter_matches_case_insensitively_within_level_filter() {
+        let entry = |level, message: &str| LogEntry {
+            timestamp: "00:00:00".to_string(),
+            level,
+            message: message.to_string(),
+        };
+        let warn = entry(LogLevel::Warn, "CPU usage high: 91.0%");
+        let debug = entry(LogLevel::Debug, "Storage entries: 3");
+
+        assert!(log_entry_matches(&warn, LogLevelFilter::All, "cpu"));
+        assert!(!log_entry_matches(&warn, LogLevelFilter::All, "storage"));
+        assert!(log_entry_matches(&debug, LogLevelFilter::All, ""));
+        assert!(!log_entry_matches(&debug, LogLevelFilter::HideDebug, ""));
+        assert!(log_entry_matches(&warn, LogLevelFilter::WarnAndError, ""));
+        assert!(!log_entry_matches(
+            &entry(LogLevel::Step, "Step #1 completed"),
+            LogLevelFilter::WarnAndError,
+            ""
+        ));
+    }
+
+    #[test]
+    fn breakpoints_are_listed_by_function_name() {
+        let mut manager = BreakpointManager::new();
+        manager.add("transfer");
+        manager.add("approve");
+        manager.add_spec(crate::debugger::breakpoint::BreakpointSpec {
+            id: "mint".to_string(),
+            function: "mint".to_string(),
+            condition: None,
+            hit_condition: None,
+            log_message: Some("minting".to_string()),
+        });
+
+        let names: Vec<&str> = sorted_breakpoints(&manager)
+            .iter()
+            .map(|bp| bp.function.as_str())
+            .collect();
+        assert_eq!(names, ["approve", "mint", "transfer"]);
+        assert!(manager.get("mint").unwrap().is_log_point());
+    }
+
+    #[test]
+    fn auth_tree_flattens_depth_first_with_depths() {
+        let node = |function: &str, sub_invocations| AuthNode {
+            address: "GA".to_string(),
+            function: function.to_string(),
+            contract_id: "C1".to_string(),
+            status: AuthStatus::Authorized,
+            sub_invocations,
+        };
+        let tree = vec![
+            node(
+                "approve",
+                vec![node("transfer", vec![node("burn", vec![])])],
+            ),
+            node("execute", vec![]),
+        ];
+
+        let rows: Vec<(usize, &str)> = flatten_auth_tree(&tree)
+            .into_iter()
+            .map(|(depth, n)| (depth, n.function.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![(0, "approve"), (1, "transfer"), (2, "burn"), (0, "execute")]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::cli::args::NumberFormat;
 use crate::debugger::instruction_pointer::StepMode;
 use crate::runtime::instruction::Instruction;
 use crossterm::style::Stylize;
@@ -6,6 +7,9 @@ use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 /// Verbosity level stored as u8: 0 = Quiet, 1 = Normal, 2 = Verbose
 static VERBOSITY_LEVEL: AtomicU8 = AtomicU8::new(1);
 
+/// Number format stored as u8: 0 = Plain, 1 = Grouped, 2 = SI
+static NUMBER_FORMAT: AtomicU8 = AtomicU8::new(1);
+
 /// Pretty printing utilities for debugger output
 pub struct Formatter;
 
@@ -44,10 +48,47 @@ impl Formatter {
 
         format!(
             "CPU: {}/{} ({:.1}%) | Memory: {}/{} bytes ({:.1}%)",
-            cpu, cpu_limit, cpu_pct, mem, mem_limit, mem_pct
+            Self::format_number(cpu),
+            Self::format_number(cpu_limit),
+            cpu_pct,
+            Self::format_number(mem),
+            Self::format_number(mem_limit),
+            mem_pct
         )
     }
 
+    /// Set the global number format used for budget values.
+    pub fn set_number_format(format: NumberFormat) {
+        let level = match format {
+            NumberFormat::Plain => 0,
+            NumberFormat::Grouped => 1,
+            NumberFormat::Si => 2,
+        };
+        NUMBER_FORMAT.store(level, Ordering::Relaxed);
+    }
+
+    /// Format a budget value according to the global number format.
+    pub fn format_number(value: u64) -> String {
+        match NUMBER_FORMAT.load(Ordering::Relaxed) {
+            0 => value.to_string(),
+            2 => Self::format_compact_u64(value),
+            _ => Self::format_grouped_u64(value),
+        }
+    }
+
+    /// Format a value with `,` thousands separators.
+    pub fn format_grouped_u64(value: u64) -> String {
+        let digits = value.to_string();
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                out.push(',');
+            }
+            out.push(c);
+        }
+        out
+    }
+
     /// Format a single instruction for display.
     pub fn format_instruction(instruction: &Instruction, is_current: bool) -> String {
         let prefix = if is_current { "►" } else { " " };
@@ -143,6 +184,7 @@ impl Formatter {
             "  u, out        Step out of function",
             "  b, block      Step to next basic block",
             "  p, prev       Step back",
+            "  goto <pc>     Run to the instruction at offset <pc>",
             "  c, continue   Continue execution",
             "  i, info       Show instruction state",
             "  ctx, context  Show instruction context",
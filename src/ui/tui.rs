@@ -1,8 +1,10 @@
 use crate::debugger::engine::DebuggerEngine;
 use crate::inspector::{StorageInspector, storage::StorageQuery};
 use crate::inspector::BudgetInspector;
+use crate::scenario::{RecordedCall, Scenario};
 use crate::Result;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone)]
 struct PendingExecution {
@@ -36,6 +38,8 @@ pub struct DebuggerUI {
     pending_execution: Option<PendingExecution>,
     last_output: Option<String>,
     last_error: Option<String>,
+    recorded_calls: Vec<RecordedCall>,
+    scenario_output: Option<PathBuf>,
 }
 
 impl DebuggerUI {
@@ -46,9 +50,37 @@ impl DebuggerUI {
             pending_execution: None,
             last_output: None,
             last_error: None,
+            recorded_calls: Vec::new(),
+            scenario_output: None,
         })
     }
 
+    /// Save the calls made during the session as a scenario file when the
+    /// session exits.
+    pub fn set_scenario_output(&mut self, path: PathBuf) {
+        self.scenario_output = Some(path);
+    }
+
+    /// Calls executed so far in this session, in order.
+    pub fn recorded_calls(&self) -> &[RecordedCall] {
+        &self.recorded_calls
+    }
+
+    /// Write the calls executed so far as a scenario file that replays them
+    /// and asserts their recorded results.
+    pub fn save_scenario(&self, path: &Path) -> Result<()> {
+        Scenario::from_recorded_calls(&self.recorded_calls).save(path)?;
+        crate::logging::log_display(
+            format!(
+                "Saved {} call(s) to scenario {}",
+                self.recorded_calls.len(),
+                path.display()
+            ),
+            crate::logging::LogLevel::Info,
+        );
+        Ok(())
+    }
+
     /// Stage an execution so the session starts "paused" before running.
     ///
     /// Use `continue` to execute the staged call.
@@ -59,6 +91,22 @@ impl DebuggerUI {
         self.last_error = None;
     }
 
+    /// Open the session on a call that has already failed.
+    ///
+    /// The executor keeps the storage, budget and call stack left behind by the
+    /// failing invocation, so `storage`, `budget` and `stack` show the state at the
+    /// point of failure.
+    pub fn attach_failure(&mut self, function: &str, error: String) {
+        self.pending_execution = None;
+        self.last_output = None;
+        crate::logging::log_display(
+            format!("Execution of '{}' failed: {}", function, error),
+            crate::logging::LogLevel::Error,
+        );
+        self.last_error = Some(error);
+        self.inspect();
+    }
+
     pub fn last_output(&self) -> Option<&str> {
         self.last_output.as_deref()
     }
@@ -99,6 +147,10 @@ impl DebuggerUI {
             }
         }
 
+        if let Some(path) = self.scenario_output.clone() {
+            self.save_scenario(&path)?;
+        }
+
         Ok(())
     }
 
@@ -125,6 +177,11 @@ impl DebuggerUI {
                         .execute_without_breakpoints(&pending.function, pending.args.as_deref())
                     {
                         Ok(output) => {
+                            self.recorded_calls.push(RecordedCall {
+                                function: pending.function,
+                                args: pending.args,
+                                outcome: Ok(output.clone()),
+                            });
                             self.last_error = None;
                             self.last_output = Some(output.clone());
                             crate::logging::log_display(
@@ -133,6 +190,11 @@ impl DebuggerUI {
                             );
                         }
                         Err(e) => {
+                            self.recorded_calls.push(RecordedCall {
+                                function: pending.function,
+                                args: pending.args,
+                                outcome: Err(e.to_string()),
+                            });
                             self.last_output = None;
                             self.last_error = Some(e.to_string());
                             crate::logging::log_display(
@@ -235,6 +297,13 @@ impl DebuggerUI {
             "palette" => {
                 self.show_palette()?;
             }
+            "save-scenario" => {
+                if parts.len() < 2 {
+                    tracing::warn!("save-scenario command missing file path");
+                } else {
+                    self.save_scenario(Path::new(parts[1]))?;
+                }
+            }
             "help" => self.print_help(),
             c if c == kb.quit || c == "quit" || c == "exit" => {
                 tracing::info!("Exiting debugger");
@@ -445,6 +514,10 @@ impl DebuggerUI {
             "  palette            Open command palette",
             crate::logging::LogLevel::Info,
         );
+        crate::logging::log_display(
+            "  save-scenario <file> Save calls made so far as a scenario",
+            crate::logging::LogLevel::Info,
+        );
         crate::logging::log_display(
             "  help               Show this help",
             crate::logging::LogLevel::Info,
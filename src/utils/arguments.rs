@@ -18,15 +18,34 @@
 //! | `i64`    | `{"type": "i64", "value": -999}`         | Signed 64-bit integer          |
 //! | `u128`   | `{"type": "u128", "value": 100}`         | Unsigned 128-bit integer       |
 //! | `i128`   | `{"type": "i128", "value": 100}`         | Signed 128-bit integer         |
+//! | `u256`   | `{"type": "u256", "value": "0xff"}`      | Unsigned 256-bit integer       |
+//! | `i256`   | `{"type": "i256", "value": "-1000"}`     | Signed 256-bit integer         |
+//! | `timepoint` | `{"type": "timepoint", "value": 1700000000}` | Unix time in seconds   |
+//! | `duration` | `{"type": "duration", "value": 3600}`  | Duration in seconds            |
 //! | `bool`   | `{"type": "bool", "value": true}`        | Boolean                        |
 //! | `symbol` | `{"type": "symbol", "value": "hello"}`   | Soroban Symbol (≤32 chars)     |
 //! | `string` | `{"type": "string", "value": "long..."}`  | Soroban String (any length)    |
+//! | `struct` | `{"type": "struct", "name": "Proposal", "fields": {...}}` | `#[contracttype]` struct |
+//! | `map`    | `{"type": "map", "key_type": "u32", "value_type": "i128", "value": {"1": 100}}` | Soroban Map with typed keys/values |
+//!
+//! Struct annotations are validated against the struct definitions loaded with
+//! [`ArgumentParser::with_structs`]: every field must be present, unknown fields are
+//! rejected, and bare field values are converted to the field's declared type.
+//!
+//! 128- and 256-bit values beyond 64 bits exceed JSON number precision, so they
+//! can be written as decimal strings or `0x`-prefixed hex strings; a leading
+//! `-` negates a signed value in either form.
+//!
+//! Map annotations take the keys of the JSON object and coerce each one to
+//! `key_type` (so `"1"` becomes a `u32` key); `value_type` is optional and
+//! applies the same way to every value.
 //!
 //! Bare values (without type annotation) still work:
 //! - Numbers → `i128`
 //! - Strings → `Symbol`
 //! - Booleans → `Bool`
 
+use crate::utils::wasm::ContractStruct;
 use hex;
 use serde_json::Value;
 use soroban_sdk::{
@@ -42,7 +61,7 @@ pub enum ArgumentParseError {
     #[error("Invalid argument: {0}")]
     InvalidArgument(String),
 
-    #[error("Unsupported type: {0}. Supported types: u32, i32, u64, u128, i128, bool, string, symbol, address, option, tuple, vec, bytes, bytesn")]
+    #[error("Unsupported type: {0}. Supported types: u32, i32, u64, u128, i128, u256, i256, timepoint, duration, bool, string, symbol, address, option, tuple, vec, map, bytes, bytesn, struct")]
     UnsupportedType(String),
 
     #[error("Failed to convert value: {0}")]
@@ -66,15 +85,33 @@ pub enum ArgumentParseError {
     },
 }
 
+const U256_MAX: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+const I256_MIN: &str =
+    "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+const I256_MAX: &str =
+    "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+
 /// Argument parser for converting JSON to Soroban values
 pub struct ArgumentParser {
     env: Env,
+    structs: Vec<ContractStruct>,
 }
 
 impl ArgumentParser {
     /// Create a new argument parser with the given Soroban environment
     pub fn new(env: Env) -> Self {
-        Self { env }
+        Self {
+            env,
+            structs: Vec::new(),
+        }
+    }
+
+    /// Struct definitions used to validate `{"type": "struct", ...}` annotations,
+    /// typically from [`crate::utils::wasm::parse_contract_structs`].
+    pub fn with_structs(mut self, structs: Vec<ContractStruct>) -> Self {
+        self.structs = structs;
+        self
     }
 
     fn looks_like_strkey_address(s: &str) -> bool {
@@ -155,20 +192,27 @@ impl ArgumentParser {
     /// Check if a JSON value is a type annotation object `{"type": "...", "value": ...}`
     fn is_typed_annotation(&self, value: &Value) -> bool {
         if let Value::Object(obj) = value {
+            if obj.get("type").and_then(Value::as_str) == Some("struct") {
+                return obj.contains_key("fields")
+                    && obj
+                        .keys()
+                        .all(|k| k == "type" || k == "name" || k == "fields");
+            }
             if !obj.contains_key("type") || !obj.contains_key("value") || !obj["type"].is_string() {
                 return false;
             }
 
             let type_name = obj["type"].as_str().unwrap_or_default();
-            let allowed_extra = match type_name {
-                "tuple" => Some("arity"),
-                "vec" => Some("element_type"),
-                "bytesn" => Some("length"),
-                _ => None,
+            let allowed_extra: &[&str] = match type_name {
+                "tuple" => &["arity"],
+                "vec" => &["element_type"],
+                "map" => &["key_type", "value_type"],
+                "bytesn" => &["length"],
+                _ => &[],
             };
 
             obj.keys()
-                .all(|k| k == "type" || k == "value" || Some(k.as_str()) == allowed_extra)
+                .all(|k| k == "type" || k == "value" || allowed_extra.contains(&k.as_str()))
         } else {
             false
         }
@@ -184,6 +228,10 @@ impl ArgumentParser {
             ArgumentParseError::InvalidArgument("Type field must be a string".to_string())
         })?;
 
+        if type_name == "struct" {
+            return self.convert_struct(obj);
+        }
+
         let val = &obj["value"];
 
         match type_name {
@@ -193,6 +241,10 @@ impl ArgumentParser {
             "i64" => self.convert_i64(val),
             "u128" => self.convert_u128(val),
             "i128" => self.convert_i128(val),
+            "u256" => self.convert_u256(val),
+            "i256" => self.convert_i256(val),
+            "timepoint" => self.convert_timepoint(val),
+            "duration" => self.convert_duration(val),
             "bool" => self.convert_bool(val),
             "string" => self.convert_string(val),
             "symbol" => self.convert_symbol(val),
@@ -200,6 +252,7 @@ impl ArgumentParser {
             "option" => self.convert_option(val),
             "tuple" => self.convert_tuple(val, obj),
             "vec" => self.convert_vec(val, obj),
+            "map" => self.convert_map(val, obj),
             "bytes" => self.convert_bytes(val),
             "bytesn" => self.convert_bytesn(val, obj),
             other => Err(ArgumentParseError::UnsupportedType(other.to_string())),
@@ -280,34 +333,220 @@ impl ArgumentParser {
         })
     }
 
-    /// Convert a JSON number to u128 Val
+    /// Convert a JSON number, decimal string or `0x` hex string to u128 Val
     fn convert_u128(&self, value: &Value) -> Result<Val, ArgumentParseError> {
-        let n = value
-            .as_u64()
-            .ok_or_else(|| ArgumentParseError::TypeMismatch {
-                expected: "u128 (non-negative integer)".to_string(),
-                actual: format!("{}", value),
-            })?;
+        let text = Self::integer_text(value, "u128")?;
+        let n = Self::parse_int128(&text, "u128", u128::MIN, u128::MAX, u128::from_str_radix)?;
 
-        Val::try_from_val(&self.env, &(n as u128)).map_err(|e| {
+        Val::try_from_val(&self.env, &n).map_err(|e| {
             ArgumentParseError::ConversionError(format!("Failed to convert u128 to Val: {:?}", e))
         })
     }
 
-    /// Convert a JSON number to i128 Val
+    /// Convert a JSON number, decimal string or `0x` hex string to i128 Val
     fn convert_i128(&self, value: &Value) -> Result<Val, ArgumentParseError> {
-        let n = value
-            .as_i64()
-            .ok_or_else(|| ArgumentParseError::TypeMismatch {
-                expected: "i128 (integer)".to_string(),
+        let text = Self::integer_text(value, "i128")?;
+        let n = Self::parse_int128(&text, "i128", i128::MIN, i128::MAX, i128::from_str_radix)?;
+
+        Val::try_from_val(&self.env, &n).map_err(|e| {
+            ArgumentParseError::ConversionError(format!("Failed to convert i128 to Val: {:?}", e))
+        })
+    }
+
+    /// Parse a decimal or `0x` hex integer (with an optional leading `-`),
+    /// failing with `OutOfRange` when it lies outside `min..=max`.
+    fn parse_int128<T: std::fmt::Display>(
+        text: &str,
+        type_name: &str,
+        min: T,
+        max: T,
+        from_str_radix: fn(&str, u32) -> Result<T, std::num::ParseIntError>,
+    ) -> Result<T, ArgumentParseError> {
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text),
+        };
+        let (radix, digits) = match unsigned
+            .strip_prefix("0x")
+            .or_else(|| unsigned.strip_prefix("0X"))
+        {
+            Some(hex) => (16, hex),
+            None => (10, unsigned),
+        };
+        let digits_valid = !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix));
+        if !digits_valid {
+            return Err(ArgumentParseError::TypeMismatch {
+                expected: format!("{} (integer, decimal or 0x hex string)", type_name),
+                actual: text.to_string(),
+            });
+        }
+
+        // With the digits already checked, a failure means the value does not
+        // fit (unsigned parsers also land here for a leading `-`).
+        from_str_radix(&format!("{}{}", sign, digits), radix).map_err(|_| {
+            ArgumentParseError::OutOfRange {
+                type_name: type_name.to_string(),
+                value: text.to_string(),
+                min: min.to_string(),
+                max: max.to_string(),
+            }
+        })
+    }
+
+    /// Convert a decimal or `0x` hex string (or a JSON number) to U256 Val
+    fn convert_u256(&self, value: &Value) -> Result<Val, ArgumentParseError> {
+        let text = Self::integer_text(value, "u256")?;
+        let limbs = Self::parse_u256_magnitude(&text, "u256", "0", U256_MAX)?;
+
+        let n = soroban_sdk::U256::from_parts(&self.env, limbs[0], limbs[1], limbs[2], limbs[3]);
+        Val::try_from_val(&self.env, &n).map_err(|e| {
+            ArgumentParseError::ConversionError(format!("Failed to convert u256 to Val: {:?}", e))
+        })
+    }
+
+    /// Convert a decimal or `0x` hex string, optionally negative, to I256 Val
+    fn convert_i256(&self, value: &Value) -> Result<Val, ArgumentParseError> {
+        let text = Self::integer_text(value, "i256")?;
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.as_str()),
+        };
+        let out_of_range = || ArgumentParseError::OutOfRange {
+            type_name: "i256".to_string(),
+            value: text.clone(),
+            min: I256_MIN.to_string(),
+            max: I256_MAX.to_string(),
+        };
+        let mut limbs = Self::parse_u256_magnitude(digits, "i256", I256_MIN, I256_MAX).map_err(
+            |e| match e {
+                ArgumentParseError::OutOfRange { .. } => out_of_range(),
+                other => other,
+            },
+        )?;
+
+        // The magnitude may be at most 2^255 - 1, or exactly 2^255 when negative.
+        let sign_bit = 1u64 << 63;
+        let is_min = limbs == [sign_bit, 0, 0, 0];
+        if limbs[0] & sign_bit != 0 && !(negative && is_min) {
+            return Err(out_of_range());
+        }
+        if negative {
+            // Two's complement: invert and add one.
+            let mut carry = true;
+            for limb in limbs.iter_mut().rev() {
+                let (sum, overflow) = (!*limb).overflowing_add(carry as u64);
+                *limb = sum;
+                carry = overflow;
+            }
+        }
+
+        let n =
+            soroban_sdk::I256::from_parts(&self.env, limbs[0] as i64, limbs[1], limbs[2], limbs[3]);
+        Val::try_from_val(&self.env, &n).map_err(|e| {
+            ArgumentParseError::ConversionError(format!("Failed to convert i256 to Val: {:?}", e))
+        })
+    }
+
+    /// The text of a wide integer value, which may be a string or an integer
+    /// JSON number.
+    fn integer_text(value: &Value, type_name: &str) -> Result<String, ArgumentParseError> {
+        match value {
+            Value::String(s) => Ok(s.trim().to_string()),
+            Value::Number(n) if n.is_i64() || n.is_u64() => Ok(n.to_string()),
+            _ => Err(ArgumentParseError::TypeMismatch {
+                expected: format!("{} (decimal or 0x hex string)", type_name),
                 actual: format!("{}", value),
+            }),
+        }
+    }
+
+    /// Parse an unsigned decimal or `0x` hex integer into big-endian 64-bit
+    /// limbs, failing with `OutOfRange` when it does not fit in 256 bits.
+    fn parse_u256_magnitude(
+        text: &str,
+        type_name: &str,
+        min: &str,
+        max: &str,
+    ) -> Result<[u64; 4], ArgumentParseError> {
+        let (radix, digits) = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+            Some(hex) => (16u32, hex),
+            None => (10u32, text),
+        };
+        if digits.is_empty() {
+            return Err(ArgumentParseError::InvalidArgument(format!(
+                "Invalid {} value: '{}'",
+                type_name, text
+            )));
+        }
+
+        let mut limbs = [0u64; 4];
+        for c in digits.chars() {
+            let digit = c.to_digit(radix).ok_or_else(|| {
+                ArgumentParseError::InvalidArgument(format!(
+                    "Invalid {} value: '{}'",
+                    type_name, text
+                ))
             })?;
+            let mut carry = digit as u128;
+            for limb in limbs.iter_mut().rev() {
+                let wide = *limb as u128 * radix as u128 + carry;
+                *limb = wide as u64;
+                carry = wide >> 64;
+            }
+            if carry != 0 {
+                return Err(ArgumentParseError::OutOfRange {
+                    type_name: type_name.to_string(),
+                    value: text.to_string(),
+                    min: min.to_string(),
+                    max: max.to_string(),
+                });
+            }
+        }
+        Ok(limbs)
+    }
 
-        Val::try_from_val(&self.env, &(n as i128)).map_err(|e| {
-            ArgumentParseError::ConversionError(format!("Failed to convert i128 to Val: {:?}", e))
+    /// Convert a JSON number of seconds since the Unix epoch to Timepoint Val
+    fn convert_timepoint(&self, value: &Value) -> Result<Val, ArgumentParseError> {
+        let seconds = Self::seconds_u64(value, "timepoint")?;
+        let timepoint = soroban_sdk::Timepoint::from_unix(&self.env, seconds);
+        Val::try_from_val(&self.env, &timepoint).map_err(|e| {
+            ArgumentParseError::ConversionError(format!(
+                "Failed to convert timepoint to Val: {:?}",
+                e
+            ))
         })
     }
 
+    /// Convert a JSON number of seconds to Duration Val
+    fn convert_duration(&self, value: &Value) -> Result<Val, ArgumentParseError> {
+        let seconds = Self::seconds_u64(value, "duration")?;
+        let duration = soroban_sdk::Duration::from_seconds(&self.env, seconds);
+        Val::try_from_val(&self.env, &duration).map_err(|e| {
+            ArgumentParseError::ConversionError(format!(
+                "Failed to convert duration to Val: {:?}",
+                e
+            ))
+        })
+    }
+
+    fn seconds_u64(value: &Value, type_name: &str) -> Result<u64, ArgumentParseError> {
+        if let Some(n) = value.as_u64() {
+            return Ok(n);
+        }
+        match value.as_i64() {
+            Some(n) => Err(ArgumentParseError::OutOfRange {
+                type_name: type_name.to_string(),
+                value: n.to_string(),
+                min: "0".to_string(),
+                max: u64::MAX.to_string(),
+            }),
+            None => Err(ArgumentParseError::TypeMismatch {
+                expected: format!("{} (seconds as a non-negative integer)", type_name),
+                actual: format!("{}", value),
+            }),
+        }
+    }
+
     /// Convert a JSON boolean to Bool Val
     fn convert_bool(&self, value: &Value) -> Result<Val, ArgumentParseError> {
         let b = value
@@ -410,6 +649,67 @@ impl ArgumentParser {
         Ok(soroban_vec.into())
     }
 
+    /// Convert a JSON object to a Soroban Map with typed keys and values
+    ///
+    /// JSON object keys are always strings, so each key is first read as the
+    /// JSON literal the declared `key_type` expects (`"1"` → `1`, `"true"` →
+    /// `true`); string-like key types take the key text as is.
+    fn convert_map(
+        &self,
+        value: &Value,
+        obj: &serde_json::Map<String, Value>,
+    ) -> Result<Val, ArgumentParseError> {
+        let entries = value
+            .as_object()
+            .ok_or_else(|| ArgumentParseError::TypeMismatch {
+                expected: "object for map".to_string(),
+                actual: format!("{}", value),
+            })?;
+
+        let key_type = obj.get("key_type").and_then(Value::as_str).ok_or_else(|| {
+            ArgumentParseError::InvalidArgument(
+                "Map requires a string 'key_type' field".to_string(),
+            )
+        })?;
+        let value_type = obj.get("value_type").and_then(Value::as_str);
+
+        let mut soroban_map = Map::<Val, Val>::new(&self.env);
+
+        for (key, item) in entries {
+            let key_json = match key_type {
+                "u32" | "i32" | "u64" | "i64" | "u128" | "i128" | "bool" => {
+                    serde_json::from_str::<Value>(key).unwrap_or(Value::String(key.clone()))
+                }
+                _ => Value::String(key.clone()),
+            };
+            let key_val = self
+                .parse_typed_value(&serde_json::json!({ "type": key_type, "value": key_json }))
+                .map_err(|e| {
+                    ArgumentParseError::InvalidArgument(format!(
+                        "Map key '{}' is not a valid {}: {}",
+                        key, key_type, e
+                    ))
+                })?;
+
+            let val = match value_type {
+                Some(vt) => {
+                    self.parse_typed_value(&serde_json::json!({ "type": vt, "value": item }))
+                }
+                None => self.json_to_soroban_val(item),
+            }
+            .map_err(|e| {
+                ArgumentParseError::ConversionError(format!(
+                    "Cannot convert value for map key '{}': {}",
+                    key, e
+                ))
+            })?;
+
+            soroban_map.set(key_val, val);
+        }
+
+        Ok(soroban_map.into())
+    }
+
     /// Convert a JSON array to a Soroban tuple (fixed length array)
     fn convert_tuple(
         &self,
@@ -531,6 +831,124 @@ impl ArgumentParser {
         })
     }
 
+    /// Convert a `{"type": "struct", "name": ..., "fields": ...}` annotation.
+    ///
+    /// Named structs are encoded as a `Map` keyed by field symbol, tuple structs as
+    /// a `Vec` in field order, matching the `#[contracttype]` encoding.
+    fn convert_struct(
+        &self,
+        obj: &serde_json::Map<String, Value>,
+    ) -> Result<Val, ArgumentParseError> {
+        let name = obj.get("name").and_then(Value::as_str).ok_or_else(|| {
+            ArgumentParseError::InvalidArgument(
+                "Struct annotation requires a string 'name' field".to_string(),
+            )
+        })?;
+        let def = self
+            .structs
+            .iter()
+            .find(|s| s.name == name)
+            .ok_or_else(|| {
+                let known: Vec<&str> = self.structs.iter().map(|s| s.name.as_str()).collect();
+                ArgumentParseError::InvalidArgument(format!(
+                    "Unknown struct '{}'. Structs in the contract spec: [{}]",
+                    name,
+                    known.join(", ")
+                ))
+            })?;
+        let fields = &obj["fields"];
+
+        if def.is_tuple() {
+            let items = fields
+                .as_array()
+                .ok_or_else(|| ArgumentParseError::TypeMismatch {
+                    expected: format!(
+                        "array of {} fields for tuple struct {}",
+                        def.fields.len(),
+                        name
+                    ),
+                    actual: format!("{}", fields),
+                })?;
+            if items.len() != def.fields.len() {
+                return Err(ArgumentParseError::InvalidArgument(format!(
+                    "Struct {} expects {} fields, got {}",
+                    name,
+                    def.fields.len(),
+                    items.len()
+                )));
+            }
+            let mut soroban_vec = SorobanVec::<Val>::new(&self.env);
+            for (field, item) in def.fields.iter().zip(items) {
+                soroban_vec.push_back(self.convert_struct_field(name, field, item)?);
+            }
+            return Ok(soroban_vec.into());
+        }
+
+        let values = fields
+            .as_object()
+            .ok_or_else(|| ArgumentParseError::TypeMismatch {
+                expected: format!("object of fields for struct {}", name),
+                actual: format!("{}", fields),
+            })?;
+        if let Some(unknown) = values.keys().find(|k| def.field(k).is_none()) {
+            return Err(ArgumentParseError::InvalidArgument(format!(
+                "Struct {} has no field '{}'",
+                name, unknown
+            )));
+        }
+
+        let mut soroban_map = Map::<Symbol, Val>::new(&self.env);
+        for field in &def.fields {
+            let value = values.get(&field.name).ok_or_else(|| {
+                ArgumentParseError::InvalidArgument(format!(
+                    "Struct {} is missing field '{}' ({})",
+                    name, field.name, field.type_name
+                ))
+            })?;
+            let val = self.convert_struct_field(name, field, value)?;
+            soroban_map.set(Symbol::new(&self.env, &field.name), val);
+        }
+
+        Ok(soroban_map.into())
+    }
+
+    /// Convert one struct field, using its spec type when the JSON value is bare.
+    fn convert_struct_field(
+        &self,
+        struct_name: &str,
+        field: &crate::utils::wasm::FunctionParam,
+        value: &Value,
+    ) -> Result<Val, ArgumentParseError> {
+        let annotation = match field.type_name.as_str() {
+            "U32" | "I32" | "U64" | "I64" | "U128" | "I128" | "U256" | "I256" | "Timepoint"
+            | "Duration" | "Bool" | "String" | "Symbol" | "Address" | "Bytes" => {
+                Some(field.type_name.to_ascii_lowercase())
+            }
+            _ => None,
+        };
+
+        let result = if self.is_typed_annotation(value) {
+            self.parse_typed_value(value)
+        } else if let Some(type_name) = annotation {
+            self.parse_typed_value(&serde_json::json!({ "type": type_name, "value": value }))
+        } else if value.is_object() && self.structs.iter().any(|s| s.name == field.type_name) {
+            self.parse_typed_value(&serde_json::json!({
+                "type": "struct",
+                "name": field.type_name,
+                "fields": value,
+            }))
+        } else {
+            self.json_to_soroban_val(value)
+        };
+
+        result.map_err(|e| {
+            ArgumentParseError::ConversionError(format!(
+                "Struct {} field '{}' ({}): {}",
+                struct_name, field.name, field.type_name, e
+            ))
+        })
+    }
+
     /// Convert a JSON value to a Soroban Val (bare values without type annotation)
     fn json_to_soroban_val(&self, json_value: &Value) -> Result<Val, ArgumentParseError> {
         match json_value {
@@ -1239,6 +1657,52 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("Invalid address"));
     }
 
+    #[test]
+    fn test_typed_contract_address() {
+        let parser = create_parser();
+        let addr = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+        let json = format!(r#"[{{ "type": "address", "value": "{}" }}]"#, addr);
+        let vals = parser.parse_args_string(&json).unwrap();
+        let address = Address::try_from_val(&parser.env, &vals[0]).unwrap();
+        assert_eq!(address.to_string().to_string(), addr);
+    }
+
+    #[test]
+    fn test_bare_contract_address_is_not_symbol() {
+        let parser = create_parser();
+        let addr = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+        let vals = parser
+            .parse_args_string(&format!(r#"["{}"]"#, addr))
+            .unwrap();
+        assert!(Address::try_from_val(&parser.env, &vals[0]).is_ok());
+        assert!(Symbol::try_from_val(&parser.env, &vals[0]).is_err());
+    }
+
+    #[test]
+    fn test_typed_map_u32_keys() {
+        let parser = create_parser();
+        let vals = parser
+            .parse_args_string(
+                r#"[{"type": "map", "key_type": "u32", "value_type": "i128", "value": {"1": 100, "2": 200}}]"#,
+            )
+            .unwrap();
+        let map = Map::<u32, i128>::try_from_val(&parser.env, &vals[0]).unwrap();
+        assert_eq!(map.get(1), Some(100));
+        assert_eq!(map.get(2), Some(200));
+    }
+
+    #[test]
+    fn test_typed_map_invalid_key() {
+        let parser = create_parser();
+        let result = parser.parse_args_string(
+            r#"[{"type": "map", "key_type": "u32", "value_type": "i128", "value": {"one": 100}}]"#,
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Map key 'one' is not a valid u32"));
+    }
+
     #[test]
     fn test_typed_vec_u32() {
         let parser = create_parser();
@@ -1362,7 +1826,7 @@ mod tests {
     #[test]
     fn test_typed_u128_large() {
         let parser = create_parser();
-        // u128 conversion goes through as_u64(), so max representable is u64::MAX
+        // JSON numbers go up to u64::MAX; larger values are written as strings
         let result =
             parser.parse_args_string(r#"[{"type": "u128", "value": 18446744073709551615}]"#);
         assert!(result.is_ok(), "u128 large failed: {:?}", result.err());
@@ -1375,6 +1839,33 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_typed_u128_string_above_u64_max() {
+        let parser = create_parser();
+        let vals = parser
+            .parse_args_string(
+                r#"[{"type": "u128", "value": "18446744073709551616"}, {"type": "u128", "value": "0xffffffffffffffffffffffffffffffff"}]"#,
+            )
+            .unwrap();
+        let n = u128::try_from_val(&parser.env, &vals[0]).unwrap();
+        assert_eq!(n, u64::MAX as u128 + 1);
+        let n = u128::try_from_val(&parser.env, &vals[1]).unwrap();
+        assert_eq!(n, u128::MAX);
+    }
+
+    #[test]
+    fn test_typed_u128_string_out_of_range() {
+        let parser = create_parser();
+        for value in ["340282366920938463463374607431768211456", "-5"] {
+            let json = format!(r#"[{{"type": "u128", "value": "{}"}}]"#, value);
+            let err = parser.parse_args_string(&json).unwrap_err();
+            assert!(
+                matches!(err, ArgumentParseError::OutOfRange { .. }),
+                "{err}"
+            );
+        }
+    }
+
     // ── i128 boundary values ─────────────────────────────────────────
 
     #[test]
@@ -1403,6 +1894,125 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_typed_i128_string_bounds() {
+        let parser = create_parser();
+        let vals = parser
+            .parse_args_string(
+                r#"[{"type": "i128", "value": "170141183460469231731687303715884105727"}, {"type": "i128", "value": "-170141183460469231731687303715884105728"}, {"type": "i128", "value": "-0x10"}]"#,
+            )
+            .unwrap();
+        assert_eq!(
+            i128::try_from_val(&parser.env, &vals[0]).unwrap(),
+            i128::MAX
+        );
+        assert_eq!(
+            i128::try_from_val(&parser.env, &vals[1]).unwrap(),
+            i128::MIN
+        );
+        assert_eq!(i128::try_from_val(&parser.env, &vals[2]).unwrap(), -16);
+
+        let err = parser
+            .parse_args_string(
+                r#"[{"type": "i128", "value": "170141183460469231731687303715884105728"}]"#,
+            )
+            .unwrap_err();
+        assert!(
+            matches!(err, ArgumentParseError::OutOfRange { .. }),
+            "{err}"
+        );
+    }
+
+    // ── 256-bit and temporal types ───────────────────────────────────
+
+    #[test]
+    fn test_typed_u256_decimal_and_hex() {
+        let parser = create_parser();
+        let vals = parser
+            .parse_args_string(
+                r#"[{"type": "u256", "value": "18446744073709551616"}, {"type": "u256", "value": "0x10000000000000000"}, {"type": "u256", "value": 7}]"#,
+            )
+            .unwrap();
+        let expected = soroban_sdk::U256::from_parts(&parser.env, 0, 0, 1, 0);
+        for val in &vals[..2] {
+            let n = soroban_sdk::U256::try_from_val(&parser.env, val).unwrap();
+            assert_eq!(n, expected);
+        }
+        let n = soroban_sdk::U256::try_from_val(&parser.env, &vals[2]).unwrap();
+        assert_eq!(n, soroban_sdk::U256::from_u32(&parser.env, 7));
+    }
+
+    #[test]
+    fn test_typed_u256_bounds() {
+        let parser = create_parser();
+        let max = format!(r#"[{{"type": "u256", "value": "{}"}}]"#, U256_MAX);
+        assert!(parser.parse_args_string(&max).is_ok());
+
+        let over = format!(r#"[{{"type": "u256", "value": "0x1{}"}}]"#, "0".repeat(64));
+        let err = parser.parse_args_string(&over).unwrap_err();
+        assert!(
+            matches!(err, ArgumentParseError::OutOfRange { .. }),
+            "{err}"
+        );
+
+        assert!(parser
+            .parse_args_string(r#"[{"type": "u256", "value": "-1"}]"#)
+            .is_err());
+        assert!(parser
+            .parse_args_string(r#"[{"type": "u256", "value": "12ab"}]"#)
+            .is_err());
+    }
+
+    #[test]
+    fn test_typed_i256_negative_and_bounds() {
+        let parser = create_parser();
+        let vals = parser
+            .parse_args_string(
+                r#"[{"type": "i256", "value": "-1"}, {"type": "i256", "value": "-0x10"}]"#,
+            )
+            .unwrap();
+        let n = soroban_sdk::I256::try_from_val(&parser.env, &vals[0]).unwrap();
+        assert_eq!(n, soroban_sdk::I256::from_i128(&parser.env, -1));
+        let n = soroban_sdk::I256::try_from_val(&parser.env, &vals[1]).unwrap();
+        assert_eq!(n, soroban_sdk::I256::from_i128(&parser.env, -16));
+
+        for bound in [I256_MIN, I256_MAX] {
+            let json = format!(r#"[{{"type": "i256", "value": "{}"}}]"#, bound);
+            assert!(parser.parse_args_string(&json).is_ok(), "{bound}");
+        }
+        let over = format!(r#"[{{"type": "i256", "value": "0x8{}"}}]"#, "0".repeat(63));
+        let err = parser.parse_args_string(&over).unwrap_err();
+        assert!(
+            matches!(err, ArgumentParseError::OutOfRange { .. }),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_typed_timepoint_and_duration() {
+        let parser = create_parser();
+        let vals = parser
+            .parse_args_string(
+                r#"[{"type": "timepoint", "value": 1700000000}, {"type": "duration", "value": 3600}]"#,
+            )
+            .unwrap();
+        let unlock_time = soroban_sdk::Timepoint::try_from_val(&parser.env, &vals[0]).unwrap();
+        assert_eq!(unlock_time.to_unix(), 1_700_000_000);
+        let duration = soroban_sdk::Duration::try_from_val(&parser.env, &vals[1]).unwrap();
+        assert_eq!(duration.to_seconds(), 3600);
+
+        let err = parser
+            .parse_args_string(r#"[{"type": "timepoint", "value": -1}]"#)
+            .unwrap_err();
+        assert!(
+            matches!(err, ArgumentParseError::OutOfRange { .. }),
+            "{err}"
+        );
+        assert!(parser
+            .parse_args_string(r#"[{"type": "duration", "value": "1h"}]"#)
+            .is_err());
+    }
+
     // ── Invalid JSON inputs ──────────────────────────────────────────
 
     #[test]
@@ -1721,4 +2331,65 @@ mod tests {
         let result = parser.parse_args_string(r#"[{"type": "address", "value": 42}]"#);
         assert!(result.is_err());
     }
+
+    // ── Struct annotations ───────────────────────────────────────────
+
+    fn proposal_parser() -> ArgumentParser {
+        let field = |name: &str, type_name: &str| crate::utils::wasm::FunctionParam {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+        };
+        create_parser().with_structs(vec![ContractStruct {
+            name: "Proposal".to_string(),
+            fields: vec![
+                field("id", "U32"),
+                field("title", "String"),
+                field("closed", "Bool"),
+            ],
+        }])
+    }
+
+    #[test]
+    fn test_struct_annotation_builds_map_with_typed_fields() {
+        let parser = proposal_parser();
+        let vals = parser
+            .parse_args_string(
+                r#"[{"type": "struct", "name": "Proposal", "fields": {"closed": false, "title": "Fund docs", "id": 7}}]"#,
+            )
+            .unwrap();
+        assert_eq!(vals.len(), 1);
+
+        let map = Map::<Symbol, Val>::try_from_val(&parser.env, &vals[0]).unwrap();
+        assert_eq!(map.len(), 3);
+        let id = map.get(Symbol::new(&parser.env, "id")).unwrap();
+        assert_eq!(u32::try_from_val(&parser.env, &id).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_struct_annotation_validates_against_spec() {
+        let parser = proposal_parser();
+
+        let missing = parser.parse_args_string(
+            r#"[{"type": "struct", "name": "Proposal", "fields": {"id": 1, "title": "x"}}]"#,
+        );
+        assert!(missing
+            .unwrap_err()
+            .to_string()
+            .contains("missing field 'closed'"));
+
+        let unknown = parser.parse_args_string(
+            r#"[{"type": "struct", "name": "Proposal", "fields": {"id": 1, "title": "x", "closed": true, "votes": 3}}]"#,
+        );
+        assert!(unknown
+            .unwrap_err()
+            .to_string()
+            .contains("no field 'votes'"));
+
+        let undefined =
+            parser.parse_args_string(r#"[{"type": "struct", "name": "Ballot", "fields": {}}]"#);
+        assert!(undefined
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown struct 'Ballot'"));
+    }
 }
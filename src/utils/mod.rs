@@ -1,4 +1,5 @@
 pub mod arguments;
+pub mod template;
 pub mod wasm;
 
 pub use arguments::ArgumentParser;
@@ -0,0 +1,116 @@
+//! `${name}` substitution for argument and storage JSON
+//!
+//! Scenario and batch files often repeat the same literal (typically an
+//! address) in many places. Templates reference it as `${name}` instead, with
+//! values supplied by `--var name=value` or a scenario's `[vars]` table.
+//! Substitution is textual and happens before the JSON is parsed, so a value
+//! used inside a JSON string is written as `"${alice}"`. A placeholder naming
+//! an undefined variable is left as written, and `$${` produces a literal `${`.
+
+use crate::{DebuggerError, Result};
+use regex::{Captures, Regex};
+use std::collections::{BTreeSet, HashMap};
+use tracing::warn;
+
+/// Parse repeated `name=value` assignments. Later assignments win.
+pub fn parse_var_assignments(specs: &[String]) -> Result<HashMap<String, String>> {
+    let mut vars = HashMap::new();
+    for spec in specs {
+        let (name, value) = spec.split_once('=').ok_or_else(|| {
+            DebuggerError::InvalidArguments(format!(
+                "Invalid --var '{}': expected NAME=VALUE",
+                spec
+            ))
+        })?;
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(DebuggerError::InvalidArguments(format!(
+                "Invalid --var name '{}': use letters, digits and underscores",
+                name
+            ))
+            .into());
+        }
+        vars.insert(name.to_string(), value.to_string());
+    }
+    Ok(vars)
+}
+
+/// Replace every `${name}` in `template` with its value from `vars`.
+///
+/// Placeholders naming an undefined variable are kept verbatim so JSON that
+/// legitimately contains `${...}` still works without `--var`; when variables
+/// are defined, the unknown names are logged as a likely typo.
+pub fn substitute(template: &str, vars: &HashMap<String, String>) -> Result<String> {
+    let re = Regex::new(r"\$(\$?)\{(\w+)\}").unwrap();
+
+    let missing: BTreeSet<&str> = re
+        .captures_iter(template)
+        .filter(|caps| caps[1].is_empty())
+        .map(|caps| caps.get(2).unwrap().as_str())
+        .filter(|name| !vars.contains_key(*name))
+        .collect();
+    if !missing.is_empty() && !vars.is_empty() {
+        let mut defined: Vec<&str> = vars.keys().map(String::as_str).collect();
+        defined.sort_unstable();
+        warn!(
+            "Leaving undefined template variable(s) unchanged: [{}]. Defined: [{}]",
+            missing.into_iter().collect::<Vec<_>>().join(", "),
+            defined.join(", ")
+        );
+    }
+
+    let result = re.replace_all(template, |caps: &Captures| {
+        if !caps[1].is_empty() {
+            format!("${{{}}}", &caps[2])
+        } else if let Some(value) = vars.get(&caps[2]) {
+            value.clone()
+        } else {
+            caps[0].to_string()
+        }
+    });
+    Ok(result.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn substitutes_repeated_placeholders() {
+        let vars = vars(&[("alice", "GABC"), ("n", "3")]);
+        let result = substitute(r#"["${alice}", "${alice}", ${n}]"#, &vars).unwrap();
+        assert_eq!(result, r#"["GABC", "GABC", 3]"#);
+    }
+
+    #[test]
+    fn escaped_placeholder_is_left_literal() {
+        let result = substitute("[\"$${alice}\"]", &HashMap::new()).unwrap();
+        assert_eq!(result, "[\"${alice}\"]");
+    }
+
+    #[test]
+    fn undefined_placeholders_are_left_unchanged() {
+        let literal = "[\"${bob}\", 1]";
+        assert_eq!(substitute(literal, &HashMap::new()).unwrap(), literal);
+
+        let vars = vars(&[("alice", "GABC")]);
+        let result = substitute("[\"${alice}\", \"${bob}\"]", &vars).unwrap();
+        assert_eq!(result, "[\"GABC\", \"${bob}\"]");
+    }
+
+    #[test]
+    fn parses_assignments_and_keeps_equals_in_value() {
+        let vars = parse_var_assignments(&["a=1".into(), "b=x=y".into(), "a=2".into()]).unwrap();
+        assert_eq!(vars["a"], "2");
+        assert_eq!(vars["b"], "x=y");
+        assert!(parse_var_assignments(&["novalue".into()]).is_err());
+        assert!(parse_var_assignments(&["bad-name=1".into()]).is_err());
+    }
+}
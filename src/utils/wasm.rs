@@ -485,6 +485,29 @@ pub fn parse_functions(wasm_bytes: &[u8]) -> Result<Vec<String>> {
     Ok(functions)
 }
 
+/// Map the index of each exported function to its export name.
+pub fn parse_function_exports(wasm_bytes: &[u8]) -> Result<HashMap<u32, String>> {
+    let mut functions = HashMap::new();
+    let parser = Parser::new(0);
+
+    for payload in parser.parse_all(wasm_bytes) {
+        if let Payload::ExportSection(reader) = payload
+            .map_err(|e| DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e)))?
+        {
+            for export in reader {
+                let export = export.map_err(|e| {
+                    DebuggerError::WasmLoadError(format!("Failed to read export: {}", e))
+                })?;
+                if matches!(export.kind, wasmparser::ExternalKind::Func) {
+                    functions.insert(export.index, export.name.to_string());
+                }
+            }
+        }
+    }
+
+    Ok(functions)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CrossContractCall {
     pub caller: String,
@@ -492,7 +515,46 @@ pub struct CrossContractCall {
     pub host_function: String,
 }
 
+/// Expands the `call_macro_with_all_host_functions!` x-macro into
+/// `HOST_MODULES` (`(export, name)` per host module) and `HOST_FUNCTIONS`
+/// (`(module export, function export, name)` per host function), so the
+/// tables track the env interface of the soroban-env-common in use.
+macro_rules! host_function_tables {
+    {
+        $(
+            $(#[$mod_attr:meta])*
+            mod $mod_id:ident $mod_str:literal
+            {
+                $(
+                    $(#[$fn_attr:meta])*
+                    { $fn_str:literal, $($min_proto:literal)?, $($max_proto:literal)?, fn $fn_id:ident $args:tt -> $ret:ty }
+                )*
+            }
+        )*
+    } => {
+        const HOST_MODULES: &[(&str, &str)] = &[$(($mod_str, stringify!($mod_id))),*];
+        const HOST_FUNCTIONS: &[(&str, &str, &str)] = &[
+            $($(($mod_str, $fn_str, stringify!($fn_id)),)*)*
+        ];
+    };
+}
+
+soroban_env_common::call_macro_with_all_host_functions! { host_function_tables }
+
+/// Full name of a host function imported under the short export names that
+/// soroban-sdk builds use, e.g. module `d` function `_` is `call`.
+pub fn sdk_host_function_name(module: &str, name: &str) -> Option<&'static str> {
+    HOST_FUNCTIONS
+        .iter()
+        .find(|(m, f, _)| *m == module && *f == name)
+        .map(|(_, _, host_fn)| *host_fn)
+}
+
 fn is_cross_contract_import(module: &str, name: &str) -> bool {
+    if let Some(host_fn) = sdk_host_function_name(module, name) {
+        return matches!(host_fn, "call" | "try_call");
+    }
+
     let module = module.to_ascii_lowercase();
     let name = name.to_ascii_lowercase();
 
@@ -504,7 +566,10 @@ fn is_cross_contract_import(module: &str, name: &str) -> bool {
 
 fn map_import_to_target(import_name: &str) -> String {
     let import_name = import_name.to_ascii_lowercase();
-    if import_name.contains("invoke_contract") || import_name.contains("call_contract") {
+    if import_name.contains("invoke_contract")
+        || import_name.contains("call_contract")
+        || import_name == "call"
+    {
         "external_contract".to_string()
     } else {
         format!("external::{}", import_name)
@@ -512,6 +577,11 @@ fn map_import_to_target(import_name: &str) -> String {
 }
 
 /// Parse cross-contract call sites by scanning WASM calls to known host imports.
+///
+/// A call's target is the contract strkey when the calling function loads the
+/// address of one stored in an active data segment, e.g. a `C...` literal
+/// passed to `Address::from_str`. Targets chosen at runtime fall back to
+/// `external_contract`.
 pub fn parse_cross_contract_calls(wasm_bytes: &[u8]) -> Result<Vec<CrossContractCall>> {
     use std::collections::{BTreeSet, HashMap};
     use wasmparser::Operator;
@@ -520,8 +590,8 @@ pub fn parse_cross_contract_calls(wasm_bytes: &[u8]) -> Result<Vec<CrossContract
     let mut cross_contract_imports: HashMap<u32, String> = HashMap::new();
     let mut imported_func_count = 0u32;
     let mut local_function_index = 0u32;
-    let mut calls = Vec::new();
-    let mut dedupe = BTreeSet::new();
+    let mut sites: Vec<(String, String, Vec<u32>)> = Vec::new();
+    let mut segments: Vec<(u32, &[u8])> = Vec::new();
 
     for payload in Parser::new(0).parse_all(wasm_bytes) {
         match payload
@@ -536,7 +606,9 @@ pub fn parse_cross_contract_calls(wasm_bytes: &[u8]) -> Result<Vec<CrossContract
                         let current_index = imported_func_count;
                         imported_func_count += 1;
                         if is_cross_contract_import(import.module, import.name) {
-                            cross_contract_imports.insert(current_index, import.name.to_string());
+                            let host_fn = sdk_host_function_name(import.module, import.name)
+                                .unwrap_or(import.name);
+                            cross_contract_imports.insert(current_index, host_fn.to_string());
                         }
                     }
                 }
@@ -562,21 +634,37 @@ pub fn parse_cross_contract_calls(wasm_bytes: &[u8]) -> Result<Vec<CrossContract
                 let mut reader = body.get_operators_reader().map_err(|e| {
                     DebuggerError::WasmLoadError(format!("Failed to get operators reader: {}", e))
                 })?;
+                let mut constants = Vec::new();
+                let mut host_fns = Vec::new();
                 while !reader.eof() {
-                    if let Operator::Call { function_index } = reader.read().map_err(|e| {
+                    match reader.read().map_err(|e| {
                         DebuggerError::WasmLoadError(format!("Failed to read operator: {}", e))
                     })? {
-                        if let Some(host_fn_name) = cross_contract_imports.get(&function_index) {
-                            let target = map_import_to_target(host_fn_name);
-                            let key = format!("{caller}->{target}:{host_fn_name}");
-                            if dedupe.insert(key) {
-                                calls.push(CrossContractCall {
-                                    caller: caller.clone(),
-                                    target,
-                                    host_function: host_fn_name.clone(),
-                                });
+                        Operator::I32Const { value } => constants.push(value as u32),
+                        Operator::Call { function_index } => {
+                            if let Some(host_fn_name) = cross_contract_imports.get(&function_index)
+                            {
+                                host_fns.push(host_fn_name.clone());
                             }
                         }
+                        _ => {}
+                    }
+                }
+                for host_fn_name in host_fns {
+                    sites.push((caller.clone(), host_fn_name, constants.clone()));
+                }
+            }
+            Payload::DataSection(reader) => {
+                for data in reader {
+                    let data = data.map_err(|e| {
+                        DebuggerError::WasmLoadError(format!("Failed to read data segment: {}", e))
+                    })?;
+                    if let wasmparser::DataKind::Active { offset_expr, .. } = data.kind {
+                        if let Ok(Operator::I32Const { value }) =
+                            offset_expr.get_operators_reader().read()
+                        {
+                            segments.push((value as u32, data.data));
+                        }
                     }
                 }
             }
@@ -584,9 +672,511 @@ pub fn parse_cross_contract_calls(wasm_bytes: &[u8]) -> Result<Vec<CrossContract
         }
     }
 
+    // The data section follows the code section, so targets are resolved
+    // once every segment is known.
+    let mut calls = Vec::new();
+    let mut dedupe = BTreeSet::new();
+    for (caller, host_fn_name, constants) in sites {
+        let mut targets: Vec<String> = constants
+            .iter()
+            .filter_map(|address| contract_strkey_at(&segments, *address))
+            .collect();
+        if targets.is_empty() {
+            targets.push(map_import_to_target(&host_fn_name));
+        }
+        for target in targets {
+            let key = format!("{caller}->{target}:{host_fn_name}");
+            if dedupe.insert(key) {
+                calls.push(CrossContractCall {
+                    caller: caller.clone(),
+                    target,
+                    host_function: host_fn_name.clone(),
+                });
+            }
+        }
+    }
+
     Ok(calls)
 }
 
+/// Contract strkey stored at `address` in one of the active data segments.
+fn contract_strkey_at(segments: &[(u32, &[u8])], address: u32) -> Option<String> {
+    use std::str::FromStr;
+    use stellar_xdr::curr::ScAddress;
+
+    const STRKEY_LEN: usize = 56;
+    segments.iter().find_map(|(offset, bytes)| {
+        let start = address.checked_sub(*offset)? as usize;
+        let candidate = std::str::from_utf8(bytes.get(start..start + STRKEY_LEN)?).ok()?;
+        match ScAddress::from_str(candidate).ok()? {
+            ScAddress::Contract(_) => Some(candidate.to_string()),
+            _ => None,
+        }
+    })
+}
+
+/// Full name of a Soroban host module imported under its short export name,
+/// e.g. `l` is `ledger`.
+pub fn host_module_name(module: &str) -> Option<&'static str> {
+    HOST_MODULES
+        .iter()
+        .find(|(export, _)| *export == module)
+        .map(|(_, name)| *name)
+}
+
+/// Imported functions of a contract, grouped by host module.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct HostImports {
+    /// Function names per module, both sorted. Host short names are expanded
+    /// (`l` / `_` is `ledger` / `put_contract_data`); others are kept as
+    /// imported.
+    pub modules: std::collections::BTreeMap<String, Vec<String>>,
+}
+
+impl HostImports {
+    /// Number of distinct imported functions.
+    pub fn total(&self) -> usize {
+        self.modules.values().map(Vec::len).sum()
+    }
+}
+
+/// List the host functions a contract imports, grouped by host module.
+pub fn parse_host_imports(wasm_bytes: &[u8]) -> Result<HostImports> {
+    let mut modules: std::collections::BTreeMap<String, BTreeSet<String>> = Default::default();
+
+    for payload in Parser::new(0).parse_all(wasm_bytes) {
+        if let Payload::ImportSection(reader) = payload
+            .map_err(|e| DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e)))?
+        {
+            for import in reader {
+                let import = import.map_err(|e| {
+                    DebuggerError::WasmLoadError(format!("Failed to read import: {}", e))
+                })?;
+                if !matches!(import.ty, wasmparser::TypeRef::Func(_)) {
+                    continue;
+                }
+                let module = host_module_name(import.module).unwrap_or(import.module);
+                let name =
+                    sdk_host_function_name(import.module, import.name).unwrap_or(import.name);
+                modules
+                    .entry(module.to_string())
+                    .or_default()
+                    .insert(name.to_string());
+            }
+        }
+    }
+
+    Ok(HostImports {
+        modules: modules
+            .into_iter()
+            .map(|(module, names)| (module, names.into_iter().collect()))
+            .collect(),
+    })
+}
+
+/// Largest contract the network accepts with its default
+/// `contract_max_size_bytes` setting.
+pub const MAX_CONTRACT_SIZE_BYTES: usize = 64 * 1024;
+/// Bounds for `validate_soroban_constraints`. They sit far above what
+/// soroban-sdk contracts use, so a module over them is almost certainly not
+/// deployable.
+const MAX_FUNCTIONS: usize = 10_000;
+const MAX_TYPES: usize = 1_000;
+const MAX_DATA_SEGMENTS: usize = 1_000;
+
+/// Outcome of one deployment constraint check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConstraintCheck {
+    pub name: &'static str,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// Result of `validate_soroban_constraints`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConstraintReport {
+    /// True when every check passed.
+    pub passed: bool,
+    pub checks: Vec<ConstraintCheck>,
+}
+
+/// Uses of a WASM feature the host rejects, and where the first one is.
+#[derive(Debug, Default)]
+struct FeatureUses {
+    count: usize,
+    first: Option<String>,
+}
+
+impl FeatureUses {
+    fn record(&mut self, location: &str) {
+        self.count += 1;
+        self.first.get_or_insert_with(|| location.to_string());
+    }
+
+    fn check(&self, name: &'static str, what: &str) -> ConstraintCheck {
+        ConstraintCheck {
+            name,
+            passed: self.count == 0,
+            detail: match &self.first {
+                Some(location) => format!("{} {}(s), first in {}", self.count, what, location),
+                None => format!("no {}", what),
+            },
+        }
+    }
+}
+
+fn is_float_type(ty: &ValType) -> bool {
+    matches!(ty, ValType::F32 | ValType::F64)
+}
+
+/// Whether `op` is an MVP float instruction: arithmetic, comparison,
+/// memory access or a conversion to or from an integer.
+fn is_float_operator(op: &Operator) -> bool {
+    use Operator::*;
+    matches!(
+        op,
+        F32Load { .. }
+            | F64Load { .. }
+            | F32Store { .. }
+            | F64Store { .. }
+            | F32Const { .. }
+            | F64Const { .. }
+            | F32Eq
+            | F32Ne
+            | F32Lt
+            | F32Gt
+            | F32Le
+            | F32Ge
+            | F64Eq
+            | F64Ne
+            | F64Lt
+            | F64Gt
+            | F64Le
+            | F64Ge
+            | F32Abs
+            | F32Neg
+            | F32Ceil
+            | F32Floor
+            | F32Trunc
+            | F32Nearest
+            | F32Sqrt
+            | F32Add
+            | F32Sub
+            | F32Mul
+            | F32Div
+            | F32Min
+            | F32Max
+            | F32Copysign
+            | F64Abs
+            | F64Neg
+            | F64Ceil
+            | F64Floor
+            | F64Trunc
+            | F64Nearest
+            | F64Sqrt
+            | F64Add
+            | F64Sub
+            | F64Mul
+            | F64Div
+            | F64Min
+            | F64Max
+            | F64Copysign
+            | I32TruncF32S
+            | I32TruncF32U
+            | I32TruncF64S
+            | I32TruncF64U
+            | I64TruncF32S
+            | I64TruncF32U
+            | I64TruncF64S
+            | I64TruncF64U
+            | F32ConvertI32S
+            | F32ConvertI32U
+            | F32ConvertI64S
+            | F32ConvertI64U
+            | F32DemoteF64
+            | F64ConvertI32S
+            | F64ConvertI32U
+            | F64ConvertI64S
+            | F64ConvertI64U
+            | F64PromoteF32
+            | I32ReinterpretF32
+            | I64ReinterpretF64
+            | F32ReinterpretI32
+            | F64ReinterpretI64
+    )
+}
+
+fn is_saturating_truncation(op: &Operator) -> bool {
+    use Operator::*;
+    matches!(
+        op,
+        I32TruncSatF32S
+            | I32TruncSatF32U
+            | I32TruncSatF64S
+            | I32TruncSatF64U
+            | I64TruncSatF32S
+            | I64TruncSatF32U
+            | I64TruncSatF64S
+            | I64TruncSatF64U
+    )
+}
+
+fn is_reference_operator(op: &Operator) -> bool {
+    use Operator::*;
+    matches!(
+        op,
+        RefNull { .. }
+            | RefIsNull
+            | RefFunc { .. }
+            | TypedSelect { .. }
+            | TableFill { .. }
+            | TableGet { .. }
+            | TableSet { .. }
+            | TableGrow { .. }
+            | TableSize { .. }
+    )
+}
+
+/// Whether a constant expression uses more than a single constant or
+/// `global.get`, which needs the extended-const proposal.
+fn is_extended_const(expr: &wasmparser::ConstExpr) -> bool {
+    let mut reader = expr.get_operators_reader();
+    let mut operators = 0;
+    while let Ok(op) = reader.read() {
+        if matches!(op, Operator::End) {
+            break;
+        }
+        operators += 1;
+    }
+    operators > 1
+}
+
+/// Check a module against what the Soroban host accepts. The host runs
+/// contracts with floats, saturating float-to-int conversions, multi-value,
+/// reference types, tail calls and extended constant expressions disabled
+/// (bulk memory, sign extension and mutable globals stay on). Also checks
+/// bounded function, type and data segment counts, a size within the
+/// network limit, and the `contractspecv0` and `contractenvmetav0` custom
+/// sections that soroban-sdk emits.
+pub fn validate_soroban_constraints(wasm_bytes: &[u8]) -> Result<ConstraintReport> {
+    let parse_err = |e: wasmparser::BinaryReaderError| {
+        DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e))
+    };
+
+    let mut floats = FeatureUses::default();
+    let mut saturating = FeatureUses::default();
+    let mut multi_value = FeatureUses::default();
+    let mut reference_types = FeatureUses::default();
+    let mut tail_calls = FeatureUses::default();
+    let mut extended_const = FeatureUses::default();
+    let mut tables = 0usize;
+    let mut function_count = 0usize;
+    let mut type_count = 0usize;
+    let mut data_segments = 0usize;
+    let mut largest_segment = 0usize;
+    let mut custom_sections = BTreeSet::new();
+    let mut export_names: HashMap<u32, String> = HashMap::new();
+    let mut imported_funcs = 0u32;
+    let mut body_index = 0u32;
+
+    for payload in Parser::new(0).parse_all(wasm_bytes) {
+        match payload.map_err(parse_err)? {
+            Payload::TypeSection(reader) => {
+                for group in reader {
+                    for ty in group.map_err(parse_err)?.into_types() {
+                        type_count += 1;
+                        if let wasmparser::CompositeType::Func(func) = &ty.composite_type {
+                            let types = || func.params().iter().chain(func.results());
+                            if types().any(is_float_type) {
+                                floats.record("a function type");
+                            }
+                            if types().any(|ty| matches!(ty, ValType::Ref(_))) {
+                                reference_types.record("a function type");
+                            }
+                            if func.results().len() > 1 {
+                                multi_value.record("a function type");
+                            }
+                        }
+                    }
+                }
+            }
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    match import.map_err(parse_err)?.ty {
+                        wasmparser::TypeRef::Func(_) => {
+                            imported_funcs += 1;
+                            function_count += 1;
+                        }
+                        wasmparser::TypeRef::Table(_) => tables += 1,
+                        wasmparser::TypeRef::Global(global) => {
+                            if is_float_type(&global.content_type) {
+                                floats.record("an imported global");
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Payload::FunctionSection(reader) => function_count += reader.count() as usize,
+            Payload::TableSection(reader) => tables += reader.count() as usize,
+            Payload::GlobalSection(reader) => {
+                for global in reader {
+                    let global = global.map_err(parse_err)?;
+                    if is_float_type(&global.ty.content_type) {
+                        floats.record("a global");
+                    }
+                    if matches!(global.ty.content_type, ValType::Ref(_)) {
+                        reference_types.record("a global");
+                    }
+                    if is_extended_const(&global.init_expr) {
+                        extended_const.record("a global initializer");
+                    }
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    let export = export.map_err(parse_err)?;
+                    if export.kind == wasmparser::ExternalKind::Func {
+                        export_names.insert(export.index, export.name.to_string());
+                    }
+                }
+            }
+            Payload::ElementSection(reader) => {
+                for element in reader {
+                    if let wasmparser::ElementKind::Active { offset_expr, .. } =
+                        element.map_err(parse_err)?.kind
+                    {
+                        if is_extended_const(&offset_expr) {
+                            extended_const.record("an element segment offset");
+                        }
+                    }
+                }
+            }
+            Payload::DataSection(reader) => {
+                for data in reader {
+                    let data = data.map_err(parse_err)?;
+                    data_segments += 1;
+                    largest_segment = largest_segment.max(data.data.len());
+                    if let wasmparser::DataKind::Active { offset_expr, .. } = data.kind {
+                        if is_extended_const(&offset_expr) {
+                            extended_const.record("a data segment offset");
+                        }
+                    }
+                }
+            }
+            Payload::CustomSection(reader) => {
+                custom_sections.insert(reader.name().to_string());
+            }
+            Payload::CodeSectionEntry(body) => {
+                let index = imported_funcs + body_index;
+                body_index += 1;
+                let function = export_names
+                    .get(&index)
+                    .map(|name| format!("function '{}'", name))
+                    .unwrap_or_else(|| format!("func_{}", index));
+
+                for local in body.get_locals_reader().map_err(parse_err)? {
+                    let ty = local.map_err(parse_err)?.1;
+                    if is_float_type(&ty) {
+                        floats.record(&function);
+                    }
+                    if matches!(ty, ValType::Ref(_)) {
+                        reference_types.record(&function);
+                    }
+                }
+                let mut reader = body.get_operators_reader().map_err(parse_err)?;
+                while !reader.eof() {
+                    let op = reader.read().map_err(parse_err)?;
+                    if is_float_operator(&op) {
+                        floats.record(&function);
+                    } else if is_saturating_truncation(&op) {
+                        saturating.record(&function);
+                    } else if is_reference_operator(&op) {
+                        reference_types.record(&function);
+                    }
+                    match op {
+                        Operator::Block { blockty }
+                        | Operator::Loop { blockty }
+                        | Operator::If { blockty } => {
+                            if matches!(blockty, wasmparser::BlockType::FuncType(_)) {
+                                multi_value.record(&function);
+                            }
+                        }
+                        Operator::CallIndirect { table_index, .. } if table_index != 0 => {
+                            reference_types.record(&function);
+                        }
+                        Operator::ReturnCall { .. } | Operator::ReturnCallIndirect { .. } => {
+                            tail_calls.record(&function);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    if tables > 1 {
+        reference_types.record("the table section");
+    }
+
+    let mut checks = vec![
+        ConstraintCheck {
+            name: "contract-size",
+            passed: wasm_bytes.len() <= MAX_CONTRACT_SIZE_BYTES,
+            detail: format!(
+                "{} bytes (limit {})",
+                wasm_bytes.len(),
+                MAX_CONTRACT_SIZE_BYTES
+            ),
+        },
+        floats.check("no-floating-point", "f32/f64 use"),
+        saturating.check(
+            "no-saturating-float-to-int",
+            "saturating float-to-int conversion",
+        ),
+        multi_value.check("no-multi-value", "multi-value signature"),
+        reference_types.check("no-reference-types", "reference type use"),
+        tail_calls.check("no-tail-call", "tail call"),
+        extended_const.check("no-extended-const", "extended constant expression"),
+        ConstraintCheck {
+            name: "function-count",
+            passed: function_count <= MAX_FUNCTIONS,
+            detail: format!("{} (limit {})", function_count, MAX_FUNCTIONS),
+        },
+        ConstraintCheck {
+            name: "type-count",
+            passed: type_count <= MAX_TYPES,
+            detail: format!("{} (limit {})", type_count, MAX_TYPES),
+        },
+        ConstraintCheck {
+            name: "data-segments",
+            passed: data_segments <= MAX_DATA_SEGMENTS
+                && largest_segment <= MAX_CONTRACT_SIZE_BYTES,
+            detail: format!(
+                "{} segment(s) (limit {}), largest {} bytes",
+                data_segments, MAX_DATA_SEGMENTS, largest_segment
+            ),
+        },
+    ];
+    for section in ["contractspecv0", "contractenvmetav0"] {
+        let present = custom_sections.contains(section);
+        checks.push(ConstraintCheck {
+            name: section,
+            passed: present,
+            detail: if present {
+                "custom section present".to_string()
+            } else {
+                "custom section missing; build the contract with soroban-sdk".to_string()
+            },
+        });
+    }
+
+    Ok(ConstraintReport {
+        passed: checks.iter().all(|check| check.passed),
+        checks,
+    })
+}
+
 pub fn get_module_info(wasm_bytes: &[u8]) -> Result<ModuleInfo> {
     let mut info = ModuleInfo {
         total_size: wasm_bytes.len(),
@@ -653,6 +1243,340 @@ pub fn code_section_range(wasm_bytes: &[u8]) -> Result<Option<std::ops::Range<us
     Ok(None)
 }
 
+// ─── WAT disassembly ──────────────────────────────────────────────────────────
+
+/// One disassembled instruction, tagged with its absolute byte offset in the module.
+///
+/// Offsets use the same basis as the stepper (`0x...` positions in the WASM binary),
+/// so a line can be matched directly against the instruction pointer.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatLine {
+    pub offset: usize,
+    pub depth: usize,
+    pub text: String,
+}
+
+/// WAT rendering of a single function body.
+#[derive(Debug, Clone, Serialize)]
+pub struct FunctionDisassembly {
+    pub name: String,
+    pub function_index: u32,
+    pub params: Vec<String>,
+    pub results: Vec<String>,
+    pub locals: Vec<String>,
+    pub lines: Vec<WatLine>,
+}
+
+impl FunctionDisassembly {
+    /// Render the function as WAT with instruction offsets in the left margin.
+    pub fn to_wat(&self) -> String {
+        let margin = " ".repeat(12);
+        let mut header = format!("(func ${} (;{};)", self.name, self.function_index);
+        if !self.params.is_empty() {
+            header.push_str(&format!(" (param {})", self.params.join(" ")));
+        }
+        if !self.results.is_empty() {
+            header.push_str(&format!(" (result {})", self.results.join(" ")));
+        }
+
+        let mut out = format!("{}{}\n", margin, header);
+        if !self.locals.is_empty() {
+            out.push_str(&format!("{}  (local {})\n", margin, self.locals.join(" ")));
+        }
+        for line in &self.lines {
+            out.push_str(&format!(
+                "0x{:08x}  {}{}\n",
+                line.offset,
+                "  ".repeat(line.depth + 1),
+                line.text
+            ));
+        }
+        out.push_str(&format!("{})\n", margin));
+        out
+    }
+}
+
+/// Disassemble the body of `function` to WAT.
+///
+/// The function is looked up by export name first and then by the `name` custom
+/// section, so internal (non-exported) functions can be shown for unstripped builds.
+pub fn disassemble_function(wasm_bytes: &[u8], function: &str) -> Result<FunctionDisassembly> {
+    let parse_err = |e: wasmparser::BinaryReaderError| {
+        DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e))
+    };
+
+    let mut func_types: Vec<Option<wasmparser::FuncType>> = Vec::new();
+    let mut func_type_indices: Vec<u32> = Vec::new();
+    let mut imported_funcs = 0u32;
+    let mut func_names: HashMap<u32, String> = HashMap::new();
+    let mut export_index: Option<u32> = None;
+    let mut bodies = Vec::new();
+
+    for payload in Parser::new(0).parse_all(wasm_bytes) {
+        match payload.map_err(parse_err)? {
+            Payload::TypeSection(reader) => {
+                for rec_group in reader {
+                    for sub_type in rec_group.map_err(parse_err)?.into_types() {
+                        func_types.push(match sub_type.composite_type {
+                            wasmparser::CompositeType::Func(func_type) => Some(func_type),
+                            _ => None,
+                        });
+                    }
+                }
+            }
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    let import = import.map_err(parse_err)?;
+                    if let wasmparser::TypeRef::Func(type_index) = import.ty {
+                        func_names
+                            .insert(imported_funcs, format!("{}.{}", import.module, import.name));
+                        func_type_indices.push(type_index);
+                        imported_funcs += 1;
+                    }
+                }
+            }
+            Payload::FunctionSection(reader) => {
+                for type_index in reader {
+                    func_type_indices.push(type_index.map_err(parse_err)?);
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    let export = export.map_err(parse_err)?;
+                    if matches!(export.kind, wasmparser::ExternalKind::Func) {
+                        func_names
+                            .entry(export.index)
+                            .or_insert_with(|| export.name.to_string());
+                        if export.name == function {
+                            export_index = Some(export.index);
+                        }
+                    }
+                }
+            }
+            Payload::CodeSectionEntry(body) => bodies.push(body),
+            Payload::CustomSection(reader) if reader.name() == "name" => {
+                let name_reader = NameSectionReader::new(reader.data(), reader.data_offset());
+                for subsection in name_reader {
+                    if let Name::Function(map) = subsection.map_err(parse_err)? {
+                        for naming in map {
+                            let naming = naming.map_err(parse_err)?;
+                            func_names
+                                .entry(naming.index)
+                                .or_insert_with(|| naming.name.to_string());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let function_index = export_index
+        .or_else(|| {
+            func_names
+                .iter()
+                .find(|(index, name)| **index >= imported_funcs && name.as_str() == function)
+                .map(|(index, _)| *index)
+        })
+        .ok_or_else(|| DebuggerError::InvalidFunction(function.to_string()))?;
+
+    let body = function_index
+        .checked_sub(imported_funcs)
+        .and_then(|local| bodies.get(local as usize))
+        .ok_or_else(|| {
+            DebuggerError::WasmLoadError(format!(
+                "Function '{}' (index {}) has no code body",
+                function, function_index
+            ))
+        })?;
+
+    let signature = func_type_indices
+        .get(function_index as usize)
+        .and_then(|type_index| func_types.get(*type_index as usize))
+        .and_then(|ty| ty.as_ref());
+    let params = signature
+        .map(|ty| ty.params().iter().map(|t| t.to_string()).collect())
+        .unwrap_or_default();
+    let results = signature
+        .map(|ty| ty.results().iter().map(|t| t.to_string()).collect())
+        .unwrap_or_default();
+
+    let mut locals = Vec::new();
+    for local in body.get_locals_reader().map_err(parse_err)? {
+        let (count, ty) = local.map_err(parse_err)?;
+        locals.extend(std::iter::repeat(ty.to_string()).take(count as usize));
+    }
+
+    let mut lines = Vec::new();
+    let mut depth = 0usize;
+    let mut reader = body.get_operators_reader().map_err(parse_err)?;
+    while !reader.eof() {
+        let offset = reader.original_position();
+        let op = reader.read().map_err(parse_err)?;
+
+        let line_depth = match op {
+            Operator::End | Operator::Else => depth.saturating_sub(1),
+            _ => depth,
+        };
+        lines.push(WatLine {
+            offset,
+            depth: line_depth,
+            text: wat_instruction(&op, &func_names),
+        });
+
+        match op {
+            Operator::Block { .. } | Operator::Loop { .. } | Operator::If { .. } => depth += 1,
+            Operator::End => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    Ok(FunctionDisassembly {
+        name: function.to_string(),
+        function_index,
+        params,
+        results,
+        locals,
+        lines,
+    })
+}
+
+/// Render a single operator as WAT text (mnemonic plus immediates).
+fn wat_instruction(op: &Operator<'_>, func_names: &HashMap<u32, String>) -> String {
+    let mnemonic = wat_mnemonic(op);
+    let immediates = match op {
+        Operator::Block { blockty } | Operator::Loop { blockty } | Operator::If { blockty } => {
+            match blockty {
+                wasmparser::BlockType::Empty => String::new(),
+                wasmparser::BlockType::Type(ty) => format!("(result {})", ty),
+                wasmparser::BlockType::FuncType(index) => format!("(type {})", index),
+            }
+        }
+        Operator::Br { relative_depth } | Operator::BrIf { relative_depth } => {
+            relative_depth.to_string()
+        }
+        Operator::BrTable { targets } => {
+            let mut parts: Vec<String> = targets
+                .targets()
+                .filter_map(|t| t.ok())
+                .map(|t| t.to_string())
+                .collect();
+            parts.push(targets.default().to_string());
+            parts.join(" ")
+        }
+        Operator::Call { function_index } | Operator::ReturnCall { function_index } => {
+            match func_names.get(function_index) {
+                Some(name) => format!("${}", name),
+                None => function_index.to_string(),
+            }
+        }
+        Operator::CallIndirect { type_index, .. }
+        | Operator::ReturnCallIndirect { type_index, .. } => format!("(type {})", type_index),
+        Operator::LocalGet { local_index }
+        | Operator::LocalSet { local_index }
+        | Operator::LocalTee { local_index } => local_index.to_string(),
+        Operator::GlobalGet { global_index } | Operator::GlobalSet { global_index } => {
+            global_index.to_string()
+        }
+        Operator::I32Const { value } => value.to_string(),
+        Operator::I64Const { value } => value.to_string(),
+        Operator::F32Const { value } => f32::from_bits(value.bits()).to_string(),
+        Operator::F64Const { value } => f64::from_bits(value.bits()).to_string(),
+        Operator::I32Load { memarg }
+        | Operator::I64Load { memarg }
+        | Operator::F32Load { memarg }
+        | Operator::F64Load { memarg }
+        | Operator::I32Load8S { memarg }
+        | Operator::I32Load8U { memarg }
+        | Operator::I32Load16S { memarg }
+        | Operator::I32Load16U { memarg }
+        | Operator::I64Load8S { memarg }
+        | Operator::I64Load8U { memarg }
+        | Operator::I64Load16S { memarg }
+        | Operator::I64Load16U { memarg }
+        | Operator::I64Load32S { memarg }
+        | Operator::I64Load32U { memarg }
+        | Operator::I32Store { memarg }
+        | Operator::I64Store { memarg }
+        | Operator::F32Store { memarg }
+        | Operator::F64Store { memarg }
+        | Operator::I32Store8 { memarg }
+        | Operator::I32Store16 { memarg }
+        | Operator::I64Store8 { memarg }
+        | Operator::I64Store16 { memarg }
+        | Operator::I64Store32 { memarg } => {
+            let mut parts = Vec::new();
+            if memarg.offset != 0 {
+                parts.push(format!("offset={}", memarg.offset));
+            }
+            if memarg.align != memarg.max_align {
+                parts.push(format!("align={}", 1u64 << memarg.align));
+            }
+            parts.join(" ")
+        }
+        _ => String::new(),
+    };
+
+    if immediates.is_empty() {
+        mnemonic
+    } else {
+        format!("{} {}", mnemonic, immediates)
+    }
+}
+
+/// Derive the WAT mnemonic from the operator's variant name
+/// (`I64ExtendI32U` → `i64.extend_i32_u`, `LocalGet` → `local.get`).
+fn wat_mnemonic(op: &Operator<'_>) -> String {
+    if matches!(op, Operator::TypedSelect { .. }) {
+        return "select".to_string();
+    }
+
+    let debug = format!("{:?}", op);
+    let variant = debug
+        .split(|c: char| c == ' ' || c == '{' || c == '(')
+        .next()
+        .unwrap_or_default();
+
+    for prefix in ["I32", "I64", "F32", "F64", "V128", "I8", "I16"] {
+        if let Some(rest) = variant.strip_prefix(prefix) {
+            // SIMD lane shapes such as `I32x4Add` → `i32x4.add`.
+            if let Some(lanes) = rest.strip_prefix('x') {
+                let digits: String = lanes.chars().take_while(|c| c.is_ascii_digit()).collect();
+                let tail = &lanes[digits.len()..];
+                return format!("{}x{}.{}", prefix.to_lowercase(), digits, snake_case(tail));
+            }
+            if rest.starts_with(|c: char| c.is_ascii_uppercase()) {
+                return format!("{}.{}", prefix.to_lowercase(), snake_case(rest));
+            }
+        }
+    }
+
+    for prefix in ["Local", "Global", "Memory", "Table", "Ref", "Data", "Elem"] {
+        if let Some(rest) = variant.strip_prefix(prefix) {
+            if rest.starts_with(|c: char| c.is_ascii_uppercase()) {
+                return format!("{}.{}", prefix.to_lowercase(), snake_case(rest));
+            }
+        }
+    }
+
+    snake_case(variant)
+}
+
+fn snake_case(camel: &str) -> String {
+    let mut out = String::with_capacity(camel.len() + 4);
+    for (i, c) in camel.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
 /// Information about a WASM module.
 #[derive(Debug, Default, Serialize)]
 pub struct ModuleInfo {
@@ -1184,33 +2108,97 @@ pub fn extract_contract_metadata(wasm_bytes: &[u8]) -> Result<ContractMetadata>
         }
     }
 
-    Ok(metadata)
+    Ok(metadata)
+}
+
+// ─── contract spec / function signatures ─────────────────────────────────────
+
+/// A single function parameter: name and its Soroban type as a display string.
+/// A function parameter for a contract spec-level signature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionParam {
+    pub name: String,
+    pub type_name: String,
+}
+
+/// A full contract-spec-level signature for one exported contract function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractFunctionSignature {
+    pub name: String,
+    pub params: Vec<FunctionParam>,
+    pub return_type: Option<String>,
+}
+
+/// A custom error definition extracted from a contract spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CustomError {
+    pub code: u32,
+    pub name: String,
+    pub doc: String,
+}
+
+/// A `#[contracttype]` integer enum definition extracted from a contract spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractEnum {
+    pub name: String,
+    pub cases: Vec<ContractEnumCase>,
+}
+
+/// A single variant of a [`ContractEnum`] and its discriminant.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractEnumCase {
+    pub name: String,
+    pub value: u32,
+}
+
+impl ContractEnum {
+    /// Variant name for a stored discriminant, if one exists.
+    pub fn variant_for(&self, value: u32) -> Option<&str> {
+        self.cases
+            .iter()
+            .find(|c| c.value == value)
+            .map(|c| c.name.as_str())
+    }
+}
+
+/// A `#[contracttype]` struct definition extracted from a contract spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractStruct {
+    pub name: String,
+    /// Fields in declaration order.
+    pub fields: Vec<FunctionParam>,
 }
 
-// ─── contract spec / function signatures ─────────────────────────────────────
+impl ContractStruct {
+    /// Tuple structs (`struct Pair(u32, u32)`) have numeric field names and are
+    /// encoded as a `Vec` rather than a `Map`.
+    pub fn is_tuple(&self) -> bool {
+        !self.fields.is_empty()
+            && self
+                .fields
+                .iter()
+                .all(|f| f.name.chars().all(|c| c.is_ascii_digit()))
+    }
 
-/// A single function parameter: name and its Soroban type as a display string.
-/// A function parameter for a contract spec-level signature.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct FunctionParam {
-    pub name: String,
-    pub type_name: String,
+    pub fn field(&self, name: &str) -> Option<&FunctionParam> {
+        self.fields.iter().find(|f| f.name == name)
+    }
 }
 
-/// A full contract-spec-level signature for one exported contract function.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct ContractFunctionSignature {
+/// A `#[contracttype]` enum with data-carrying variants (an XDR union), such
+/// as the `DataKey` enum most contracts use for storage keys.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractUnion {
     pub name: String,
-    pub params: Vec<FunctionParam>,
-    pub return_type: Option<String>,
+    pub cases: Vec<ContractUnionCase>,
 }
 
-/// A custom error definition extracted from a contract spec.
+/// A single variant of a [`ContractUnion`] and the types it carries.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-pub struct CustomError {
-    pub code: u32,
+pub struct ContractUnionCase {
     pub name: String,
-    pub doc: String,
+    /// Payload types in order; empty for a unit variant.
+    pub types: Vec<String>,
 }
 
 /// Convert an XDR `ScSpecTypeDef` into a human-readable type string.
@@ -1271,60 +2259,39 @@ fn stringm_to_string(bytes: &[u8]) -> String {
 /// this keeps callers simple and backward-compatible with contracts that
 /// pre-date the spec section.
 pub fn parse_function_signatures(wasm_bytes: &[u8]) -> Result<Vec<ContractFunctionSignature>> {
-    use stellar_xdr::curr::{Limited, Limits, ReadXdr, ScSpecEntry};
-
-    let mut signatures = Vec::new();
-    let parser = Parser::new(0);
-
-    for payload in parser.parse_all(wasm_bytes) {
-        let Payload::CustomSection(reader) = payload
-            .map_err(|e| DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e)))?
-        else {
-            continue;
-        };
-
-        if reader.name() != "contractspecv0" {
-            continue;
-        }
-
-        let data = reader.data();
-        let cursor = std::io::Cursor::new(data);
-        let mut limited = Limited::new(cursor, Limits::none());
-
-        // The section is a packed sequence of XDR-encoded ScSpecEntry values.
-        loop {
-            match ScSpecEntry::read_xdr(&mut limited) {
-                Ok(ScSpecEntry::FunctionV0(func)) => {
-                    let name = stringm_to_string(func.name.0.as_slice());
-
-                    let params = func
-                        .inputs
-                        .iter()
-                        .map(|input| FunctionParam {
-                            name: stringm_to_string(input.name.as_slice()),
-                            type_name: spec_type_to_string(&input.type_),
-                        })
-                        .collect();
-
-                    let return_type = func.outputs.first().map(spec_type_to_string);
-
-                    signatures.push(ContractFunctionSignature {
-                        name,
-                        params,
-                        return_type,
-                    });
-                }
-                Ok(_) => {
-                    // UDT definitions, events, etc. — skip
-                }
-                Err(_) => break, // end of section or corrupt data
-            }
-        }
+    let entries = read_spec_section(wasm_bytes)?.unwrap_or_default();
+    Ok(entries
+        .iter()
+        .filter_map(|entry| match entry {
+            stellar_xdr::curr::ScSpecEntry::FunctionV0(func) => Some(function_signature(func)),
+            _ => None,
+        })
+        .collect())
+}
 
-        break; // only one contractspecv0 section exists per contract
+fn function_signature(func: &stellar_xdr::curr::ScSpecFunctionV0) -> ContractFunctionSignature {
+    ContractFunctionSignature {
+        name: stringm_to_string(func.name.0.as_slice()),
+        params: func
+            .inputs
+            .iter()
+            .map(|input| FunctionParam {
+                name: stringm_to_string(input.name.as_slice()),
+                type_name: spec_type_to_string(&input.type_),
+            })
+            .collect(),
+        return_type: func.outputs.first().map(spec_type_to_string),
     }
+}
 
-    Ok(signatures)
+/// Parameter names of the exported `function` from the contract spec, if the
+/// contract has one.
+pub fn function_param_names(wasm_bytes: &[u8], function: &str) -> Option<Vec<String>> {
+    parse_function_signatures(wasm_bytes)
+        .ok()?
+        .into_iter()
+        .find(|s| s.name == function)
+        .map(|s| s.params.into_iter().map(|p| p.name).collect())
 }
 
 #[allow(dead_code)]
@@ -1348,48 +2315,191 @@ fn val_type_to_wasm_type(vt: &ValType) -> WasmType {
 }
 /// Parse custom error definitions from the WASM `contractspecv0` custom section.
 pub fn parse_custom_errors(wasm_bytes: &[u8]) -> Result<Vec<CustomError>> {
-    use stellar_xdr::curr::{Limited, Limits, ReadXdr, ScSpecEntry};
+    let entries = read_spec_section(wasm_bytes)?.unwrap_or_default();
+    Ok(entries
+        .iter()
+        .filter_map(|entry| match entry {
+            stellar_xdr::curr::ScSpecEntry::UdtErrorEnumV0(err_enum) => {
+                Some(error_enum_cases(err_enum))
+            }
+            _ => None,
+        })
+        .flatten()
+        .collect())
+}
 
-    let mut errors = Vec::new();
-    let parser = Parser::new(0);
+fn error_enum_cases(err_enum: &stellar_xdr::curr::ScSpecUdtErrorEnumV0) -> Vec<CustomError> {
+    err_enum
+        .cases
+        .iter()
+        .map(|case| CustomError {
+            code: case.value,
+            name: stringm_to_string(case.name.as_slice()),
+            doc: stringm_to_string(case.doc.as_slice()),
+        })
+        .collect()
+}
 
-    for payload in parser.parse_all(wasm_bytes) {
+/// Parse `#[contracttype]` integer enum definitions from the `contractspecv0` custom section.
+pub fn parse_contract_enums(wasm_bytes: &[u8]) -> Result<Vec<ContractEnum>> {
+    let entries = read_spec_section(wasm_bytes)?.unwrap_or_default();
+    Ok(entries
+        .iter()
+        .filter_map(|entry| match entry {
+            stellar_xdr::curr::ScSpecEntry::UdtEnumV0(udt) => Some(ContractEnum {
+                name: stringm_to_string(udt.name.as_slice()),
+                cases: udt
+                    .cases
+                    .iter()
+                    .map(|case| ContractEnumCase {
+                        name: stringm_to_string(case.name.as_slice()),
+                        value: case.value,
+                    })
+                    .collect(),
+            }),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Parse `#[contracttype]` struct definitions from the `contractspecv0` custom section.
+pub fn parse_contract_structs(wasm_bytes: &[u8]) -> Result<Vec<ContractStruct>> {
+    let entries = read_spec_section(wasm_bytes)?.unwrap_or_default();
+    Ok(entries
+        .iter()
+        .filter_map(|entry| match entry {
+            stellar_xdr::curr::ScSpecEntry::UdtStructV0(udt) => Some(ContractStruct {
+                name: stringm_to_string(udt.name.as_slice()),
+                fields: udt
+                    .fields
+                    .iter()
+                    .map(|field| FunctionParam {
+                        name: stringm_to_string(field.name.as_slice()),
+                        type_name: spec_type_to_string(&field.type_),
+                    })
+                    .collect(),
+            }),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Parse `#[contracttype]` union definitions from the `contractspecv0` custom section.
+pub fn parse_contract_unions(wasm_bytes: &[u8]) -> Result<Vec<ContractUnion>> {
+    use stellar_xdr::curr::{ScSpecEntry, ScSpecUdtUnionCaseV0};
+
+    let entries = read_spec_section(wasm_bytes)?.unwrap_or_default();
+    Ok(entries
+        .iter()
+        .filter_map(|entry| match entry {
+            ScSpecEntry::UdtUnionV0(udt) => Some(ContractUnion {
+                name: stringm_to_string(udt.name.as_slice()),
+                cases: udt
+                    .cases
+                    .iter()
+                    .map(|case| match case {
+                        ScSpecUdtUnionCaseV0::VoidV0(void) => ContractUnionCase {
+                            name: stringm_to_string(void.name.as_slice()),
+                            types: Vec::new(),
+                        },
+                        ScSpecUdtUnionCaseV0::TupleV0(tuple) => ContractUnionCase {
+                            name: stringm_to_string(tuple.name.as_slice()),
+                            types: tuple.type_.iter().map(spec_type_to_string).collect(),
+                        },
+                    })
+                    .collect(),
+            }),
+            _ => None,
+        })
+        .collect())
+}
+
+/// A `#[contracterror]` enum extracted from a contract spec, with its cases
+/// in declaration order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractErrorEnum {
+    pub name: String,
+    pub cases: Vec<CustomError>,
+}
+
+/// The callable interface declared in a contract's `contractspecv0` section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractSpec {
+    pub functions: Vec<ContractFunctionSignature>,
+    pub errors: Vec<ContractErrorEnum>,
+}
+
+impl ContractFunctionSignature {
+    /// Rust-like rendering, e.g. `transfer(from: Address, amount: I128) -> Void`.
+    pub fn to_signature_string(&self) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|p| format!("{}: {}", p.name, p.type_name))
+            .collect();
+        format!(
+            "{}({}) -> {}",
+            self.name,
+            params.join(", "),
+            self.return_type.as_deref().unwrap_or("()")
+        )
+    }
+}
+
+/// Parse function signatures and error enums from the `contractspecv0`
+/// custom section in a single pass.
+///
+/// Unlike [`parse_function_signatures`], this returns `None` when the section
+/// is missing so callers can tell "no spec" apart from "empty spec".
+pub fn parse_contract_spec(wasm_bytes: &[u8]) -> Result<Option<ContractSpec>> {
+    let Some(entries) = read_spec_section(wasm_bytes)? else {
+        return Ok(None);
+    };
+    let mut spec = ContractSpec {
+        functions: Vec::new(),
+        errors: Vec::new(),
+    };
+    for entry in entries {
+        match entry {
+            stellar_xdr::curr::ScSpecEntry::FunctionV0(func) => {
+                spec.functions.push(function_signature(&func));
+            }
+            stellar_xdr::curr::ScSpecEntry::UdtErrorEnumV0(err_enum) => {
+                spec.errors.push(ContractErrorEnum {
+                    name: stringm_to_string(err_enum.name.as_slice()),
+                    cases: error_enum_cases(&err_enum),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(Some(spec))
+}
+
+/// Decode every entry of the `contractspecv0` section, or `None` if the
+/// module has no such section.
+fn read_spec_section(wasm_bytes: &[u8]) -> Result<Option<Vec<stellar_xdr::curr::ScSpecEntry>>> {
+    use stellar_xdr::curr::{Limited, Limits, ReadXdr, ScSpecEntry};
+
+    for payload in Parser::new(0).parse_all(wasm_bytes) {
         let Payload::CustomSection(reader) = payload
             .map_err(|e| DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e)))?
         else {
             continue;
         };
-
         if reader.name() != "contractspecv0" {
             continue;
         }
 
-        let data = reader.data();
-        let cursor = std::io::Cursor::new(data);
-        let mut limited = Limited::new(cursor, Limits::none());
-
-        loop {
-            match ScSpecEntry::read_xdr(&mut limited) {
-                Ok(ScSpecEntry::UdtErrorEnumV0(err_enum)) => {
-                    for case in err_enum.cases.iter() {
-                        errors.push(CustomError {
-                            code: case.value,
-                            name: stringm_to_string(case.name.as_slice()),
-                            doc: stringm_to_string(case.doc.as_slice()),
-                        });
-                    }
-                }
-                Ok(_) => {
-                    // Other spec entries — skip
-                }
-                Err(_) => break, // end of section or corrupt data
-            }
+        let mut limited = Limited::new(std::io::Cursor::new(reader.data()), Limits::none());
+        let mut entries = Vec::new();
+        // Stop at the end of the section or at the first corrupt entry.
+        while let Ok(entry) = ScSpecEntry::read_xdr(&mut limited) {
+            entries.push(entry);
         }
-
-        break;
+        return Ok(Some(entries));
     }
-
-    Ok(errors)
+    Ok(None)
 }
 
 // ─── tests ────────────────────────────────────────────────────────────────────
@@ -1560,6 +2670,12 @@ mod tests {
     }
 
     fn make_wasm_with_cross_contract_call() -> Vec<u8> {
+        make_wasm_calling_contract(None)
+    }
+
+    /// Like `make_wasm_with_cross_contract_call`, optionally loading the address
+    /// of `contract` stored in a data segment before the call.
+    fn make_wasm_calling_contract(contract: Option<&str>) -> Vec<u8> {
         let mut module = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
 
         // Type section: one () -> () function type.
@@ -1585,6 +2701,11 @@ mod tests {
         functions.extend_from_slice(&uleb128(0));
         append_section(&mut module, 3, &functions);
 
+        if contract.is_some() {
+            // Memory section: one memory with a single page.
+            append_section(&mut module, 5, &[0x01, 0x00, 0x01]);
+        }
+
         // Export section: export local function at index 1 (import is index 0).
         let mut exports = Vec::new();
         exports.extend_from_slice(&uleb128(1));
@@ -1596,11 +2717,25 @@ mod tests {
         // Code section: body = call imported function index 0; end.
         let mut code = Vec::new();
         code.extend_from_slice(&uleb128(1)); // one body
-        let body = vec![0x00, 0x10, 0x00, 0x0b]; // no locals, call 0, end
+        let body = match contract {
+            // no locals, i32.const 16, drop, call 0, end
+            Some(_) => vec![0x00, 0x41, 0x10, 0x1a, 0x10, 0x00, 0x0b],
+            None => vec![0x00, 0x10, 0x00, 0x0b], // no locals, call 0, end
+        };
         code.extend_from_slice(&uleb128(body.len()));
         code.extend_from_slice(&body);
         append_section(&mut module, 10, &code);
 
+        if let Some(contract) = contract {
+            // Data section: one active segment at offset 16 holding the strkey.
+            let mut data = Vec::new();
+            data.extend_from_slice(&uleb128(1));
+            data.extend_from_slice(&[0x00, 0x41, 0x10, 0x0b]);
+            data.extend_from_slice(&uleb128(contract.len()));
+            data.extend_from_slice(contract.as_bytes());
+            append_section(&mut module, 11, &data);
+        }
+
         module
     }
 
@@ -1685,6 +2820,156 @@ implementation_notes=Line-based format
         assert!(meta.is_empty());
     }
 
+    /// Module importing the given `(module, name)` functions and nothing else.
+    fn make_wasm_with_imports(imports: &[(&str, &str)]) -> Vec<u8> {
+        let mut module = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        append_section(&mut module, 1, &[0x01, 0x60, 0x00, 0x00]);
+
+        let mut section = uleb128(imports.len());
+        for (module_name, name) in imports {
+            encode_string(&mut section, module_name);
+            encode_string(&mut section, name);
+            section.push(0x00);
+            section.push(0x00);
+        }
+        append_section(&mut module, 2, &section);
+        module
+    }
+
+    fn failed_checks(wasm: &[u8]) -> Vec<&'static str> {
+        validate_soroban_constraints(wasm)
+            .expect("should validate")
+            .checks
+            .into_iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.name)
+            .collect()
+    }
+
+    #[test]
+    fn validation_rejects_floating_point() {
+        let mut module = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        append_section(&mut module, 1, &[0x01, 0x60, 0x00, 0x00]);
+        append_section(&mut module, 3, &[0x01, 0x00]);
+        // f64.const 1.5; drop; end
+        let mut body = vec![0x00, 0x44];
+        body.extend_from_slice(&1.5f64.to_le_bytes());
+        body.extend_from_slice(&[0x1A, 0x0B]);
+        let mut code = uleb128(1);
+        code.extend_from_slice(&uleb128(body.len()));
+        code.extend_from_slice(&body);
+        append_section(&mut module, 10, &code);
+
+        assert_eq!(
+            failed_checks(&module),
+            vec!["no-floating-point", "contractspecv0", "contractenvmetav0"]
+        );
+    }
+
+    /// Module with one function of type `func_type` (an encoded `functype`)
+    /// and one page of memory, whose body is `code` with no locals.
+    fn single_function_module(func_type: &[u8], code: &[u8]) -> Vec<u8> {
+        let mut module = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        append_section(&mut module, 1, &[&[0x01][..], func_type].concat());
+        append_section(&mut module, 3, &[0x01, 0x00]);
+        append_section(&mut module, 5, &[0x01, 0x00, 0x01]);
+        let body = [&[0x00][..], code, &[0x0B]].concat();
+        let mut section = uleb128(1);
+        section.extend_from_slice(&uleb128(body.len()));
+        section.extend_from_slice(&body);
+        append_section(&mut module, 10, &section);
+        module
+    }
+
+    #[test]
+    fn validation_checks_the_features_the_host_disables() {
+        const EMPTY: &[u8] = &[0x60, 0x00, 0x00];
+        let sdk_sections = ["contractspecv0", "contractenvmetav0"];
+
+        // i32.const 0 (x3); memory.fill. Bulk memory is enabled on the host.
+        let fill = single_function_module(
+            EMPTY,
+            &[0x41, 0x00, 0x41, 0x00, 0x41, 0x00, 0xFC, 0x0B, 0x00],
+        );
+        assert_eq!(failed_checks(&fill), sdk_sections);
+
+        // () -> (i32, i32)
+        let pair =
+            single_function_module(&[0x60, 0x00, 0x02, 0x7F, 0x7F], &[0x41, 0x01, 0x41, 0x02]);
+        assert_eq!(failed_checks(&pair)[0], "no-multi-value");
+
+        // f32.const 1.0; i32.trunc_sat_f32_s; drop
+        let saturating =
+            single_function_module(EMPTY, &[0x43, 0x00, 0x00, 0x80, 0x3F, 0xFC, 0x00, 0x1A]);
+        assert_eq!(
+            failed_checks(&saturating)[..2],
+            ["no-floating-point", "no-saturating-float-to-int"]
+        );
+
+        // return_call 0
+        let tail_call = single_function_module(EMPTY, &[0x12, 0x00]);
+        assert_eq!(failed_checks(&tail_call)[0], "no-tail-call");
+
+        // ref.null func; drop
+        let reference = single_function_module(EMPTY, &[0xD0, 0x70, 0x1A]);
+        assert_eq!(failed_checks(&reference)[0], "no-reference-types");
+    }
+
+    #[test]
+    fn validation_passes_with_sdk_custom_sections() {
+        let mut module = make_wasm_with_imports(&[("l", "_")]);
+        for name in ["contractspecv0", "contractenvmetav0"] {
+            let mut section = Vec::new();
+            encode_string(&mut section, name);
+            append_section(&mut module, 0, &section);
+        }
+
+        let report = validate_soroban_constraints(&module).expect("should validate");
+        assert!(report.passed, "{:?}", report.checks);
+    }
+
+    #[test]
+    fn parse_host_imports_groups_by_host_module() {
+        let wasm = make_wasm_with_imports(&[
+            ("l", "_"),
+            ("a", "0"),
+            ("l", "1"),
+            ("d", "_"),
+            ("l", "_"),
+            ("v", "_"),
+            ("c", "_"),
+            ("env", "custom"),
+        ]);
+
+        let imports = parse_host_imports(&wasm).expect("should parse imports");
+
+        assert_eq!(
+            imports.modules.get("ledger"),
+            Some(&vec![
+                "get_contract_data".to_string(),
+                "put_contract_data".to_string()
+            ])
+        );
+        assert_eq!(
+            imports.modules.get("address"),
+            Some(&vec!["require_auth".to_string()])
+        );
+        assert_eq!(imports.modules.get("call"), Some(&vec!["call".to_string()]));
+        assert_eq!(
+            imports.modules.get("vec"),
+            Some(&vec!["vec_new".to_string()])
+        );
+        assert_eq!(
+            imports.modules.get("crypto"),
+            Some(&vec!["compute_hash_sha256".to_string()])
+        );
+        assert_eq!(
+            imports.modules.get("env"),
+            Some(&vec!["custom".to_string()])
+        );
+        assert_eq!(imports.total(), 7);
+    }
+
     #[test]
     fn parse_cross_contract_calls_detects_invoke_contract_import() {
         let wasm = make_wasm_with_cross_contract_call();
@@ -1696,6 +2981,17 @@ implementation_notes=Line-based format
         assert_eq!(calls[0].host_function, "invoke_contract");
     }
 
+    #[test]
+    fn parse_cross_contract_calls_resolves_constant_contract_address() {
+        let callee = "CADQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQP5KR";
+        let wasm = make_wasm_calling_contract(Some(callee));
+        let calls = parse_cross_contract_calls(&wasm).expect("should parse calls");
+
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].caller, "entrypoint");
+        assert_eq!(calls[0].target, callee);
+    }
+
     #[test]
     fn test_get_module_info_with_sections() {
         let wasm = make_custom_section_wasm("test_section", &[0x01, 0x02, 0x03]);
@@ -1835,4 +3131,249 @@ implementation_notes=Line-based format
         assert_eq!(errors[1].name, "ErrorTwo");
         assert_eq!(errors[1].doc, "My Error 2");
     }
+
+    #[test]
+    fn extract_contract_enums() {
+        use stellar_xdr::curr::{
+            ScSpecEntry, ScSpecUdtEnumCaseV0, ScSpecUdtEnumV0, StringM, WriteXdr,
+        };
+
+        let case = |name: &str, value: u32| ScSpecUdtEnumCaseV0 {
+            doc: StringM::default(),
+            name: StringM::try_from(name.as_bytes().to_vec()).unwrap(),
+            value,
+        };
+        let udt = ScSpecUdtEnumV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: StringM::try_from("EscrowStatus".as_bytes().to_vec()).unwrap(),
+            cases: vec![case("Pending", 0), case("Released", 1)]
+                .try_into()
+                .unwrap(),
+        };
+
+        let payload = ScSpecEntry::UdtEnumV0(udt)
+            .to_xdr(stellar_xdr::curr::Limits::none())
+            .unwrap();
+        let wasm = make_custom_section_wasm("contractspecv0", &payload);
+
+        let enums = parse_contract_enums(&wasm).expect("parsing should succeed");
+        assert_eq!(enums.len(), 1);
+        assert_eq!(enums[0].name, "EscrowStatus");
+        assert_eq!(enums[0].variant_for(1), Some("Released"));
+        assert_eq!(enums[0].variant_for(7), None);
+    }
+
+    #[test]
+    fn parse_contract_structs_from_spec() {
+        use stellar_xdr::curr::{
+            ScSpecEntry, ScSpecTypeDef, ScSpecUdtStructFieldV0, ScSpecUdtStructV0, StringM,
+            WriteXdr,
+        };
+
+        let field = |name: &str, type_: ScSpecTypeDef| ScSpecUdtStructFieldV0 {
+            doc: StringM::default(),
+            name: StringM::try_from(name.as_bytes().to_vec()).unwrap(),
+            type_,
+        };
+        let udt = ScSpecUdtStructV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: StringM::try_from("Proposal".as_bytes().to_vec()).unwrap(),
+            fields: vec![
+                field("id", ScSpecTypeDef::U32),
+                field("title", ScSpecTypeDef::String),
+                field("closed", ScSpecTypeDef::Bool),
+            ]
+            .try_into()
+            .unwrap(),
+        };
+
+        let payload = ScSpecEntry::UdtStructV0(udt)
+            .to_xdr(stellar_xdr::curr::Limits::none())
+            .unwrap();
+        let wasm = make_custom_section_wasm("contractspecv0", &payload);
+
+        let structs = parse_contract_structs(&wasm).expect("parsing should succeed");
+        assert_eq!(structs.len(), 1);
+        assert_eq!(structs[0].name, "Proposal");
+        assert!(!structs[0].is_tuple());
+        let fields: Vec<(&str, &str)> = structs[0]
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.type_name.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![("id", "U32"), ("title", "String"), ("closed", "Bool")]
+        );
+    }
+
+    #[test]
+    fn parse_contract_unions_from_spec() {
+        use stellar_xdr::curr::{
+            ScSpecEntry, ScSpecTypeDef, ScSpecUdtUnionCaseTupleV0, ScSpecUdtUnionCaseV0,
+            ScSpecUdtUnionCaseVoidV0, ScSpecUdtUnionV0, StringM, WriteXdr,
+        };
+
+        let name = |s: &str| StringM::try_from(s.as_bytes().to_vec()).unwrap();
+        let udt = ScSpecUdtUnionV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: name("DataKey"),
+            cases: vec![
+                ScSpecUdtUnionCaseV0::VoidV0(ScSpecUdtUnionCaseVoidV0 {
+                    doc: StringM::default(),
+                    name: name("Admin"),
+                }),
+                ScSpecUdtUnionCaseV0::TupleV0(ScSpecUdtUnionCaseTupleV0 {
+                    doc: StringM::default(),
+                    name: name("Balance"),
+                    type_: vec![ScSpecTypeDef::Address].try_into().unwrap(),
+                }),
+            ]
+            .try_into()
+            .unwrap(),
+        };
+
+        let payload = ScSpecEntry::UdtUnionV0(udt)
+            .to_xdr(stellar_xdr::curr::Limits::none())
+            .unwrap();
+        let wasm = make_custom_section_wasm("contractspecv0", &payload);
+
+        let unions = parse_contract_unions(&wasm).expect("parsing should succeed");
+        assert_eq!(unions.len(), 1);
+        assert_eq!(unions[0].name, "DataKey");
+        assert_eq!(
+            unions[0].cases,
+            vec![
+                ContractUnionCase {
+                    name: "Admin".to_string(),
+                    types: Vec::new(),
+                },
+                ContractUnionCase {
+                    name: "Balance".to_string(),
+                    types: vec!["Address".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_contract_spec_reads_signatures_and_error_enums() {
+        use stellar_xdr::curr::{
+            ScSpecEntry, ScSpecFunctionInputV0, ScSpecFunctionV0, ScSpecTypeDef, ScSpecTypeResult,
+            ScSpecTypeUdt, ScSpecUdtErrorEnumCaseV0, ScSpecUdtErrorEnumV0, ScSymbol, StringM,
+            WriteXdr,
+        };
+
+        let input = |n: &str, type_: ScSpecTypeDef| ScSpecFunctionInputV0 {
+            doc: StringM::default(),
+            name: n.try_into().unwrap(),
+            type_,
+        };
+        let transfer = ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+            doc: StringM::default(),
+            name: ScSymbol("transfer".try_into().unwrap()),
+            inputs: vec![
+                input("from", ScSpecTypeDef::Address),
+                input("to", ScSpecTypeDef::Address),
+                input("amount", ScSpecTypeDef::I128),
+            ]
+            .try_into()
+            .unwrap(),
+            outputs: vec![ScSpecTypeDef::Result(Box::new(ScSpecTypeResult {
+                ok_type: Box::new(ScSpecTypeDef::Void),
+                error_type: Box::new(ScSpecTypeDef::Udt(ScSpecTypeUdt {
+                    name: "TokenError".try_into().unwrap(),
+                })),
+            }))]
+            .try_into()
+            .unwrap(),
+        });
+        let errors = ScSpecEntry::UdtErrorEnumV0(ScSpecUdtErrorEnumV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: "TokenError".try_into().unwrap(),
+            cases: vec![ScSpecUdtErrorEnumCaseV0 {
+                doc: StringM::default(),
+                name: "InsufficientBalance".try_into().unwrap(),
+                value: 1,
+            }]
+            .try_into()
+            .unwrap(),
+        });
+
+        let mut payload = transfer.to_xdr(stellar_xdr::curr::Limits::none()).unwrap();
+        payload.extend(errors.to_xdr(stellar_xdr::curr::Limits::none()).unwrap());
+        let wasm = make_custom_section_wasm("contractspecv0", &payload);
+
+        let spec = parse_contract_spec(&wasm).unwrap().expect("spec section");
+        assert_eq!(spec.functions.len(), 1);
+        assert_eq!(
+            spec.functions[0].to_signature_string(),
+            "transfer(from: Address, to: Address, amount: I128) -> Result<Void, TokenError>"
+        );
+        assert_eq!(spec.errors.len(), 1);
+        assert_eq!(spec.errors[0].name, "TokenError");
+        assert_eq!(spec.errors[0].cases[0].name, "InsufficientBalance");
+        assert_eq!(spec.errors[0].cases[0].code, 1);
+    }
+
+    #[test]
+    fn parse_contract_spec_is_none_without_section() {
+        let wasm = make_custom_section_wasm("contractenvmetav0", &[]);
+        assert_eq!(parse_contract_spec(&wasm).unwrap(), None);
+    }
+
+    #[test]
+    fn disassemble_function_renders_wat_with_stepper_offsets() {
+        let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+        // (type (func (param i64) (result i64)))
+        wasm.extend_from_slice(&[0x01, 0x06, 0x01, 0x60, 0x01, 0x7e, 0x01, 0x7e]);
+        wasm.extend_from_slice(&[0x03, 0x02, 0x01, 0x00]);
+        // (export "add" (func 0))
+        wasm.extend_from_slice(&[0x07, 0x07, 0x01, 0x03, b'a', b'd', b'd', 0x00, 0x00]);
+        // local.get 0; i64.const 1; i64.add; end
+        wasm.extend_from_slice(&[
+            0x0a, 0x09, 0x01, 0x07, 0x00, 0x20, 0x00, 0x42, 0x01, 0x7c, 0x0b,
+        ]);
+
+        let disassembly = disassemble_function(&wasm, "add").expect("disassembly should succeed");
+        assert_eq!(disassembly.function_index, 0);
+        assert_eq!(disassembly.params, vec!["i64"]);
+        assert_eq!(disassembly.results, vec!["i64"]);
+
+        let lines: Vec<(usize, &str)> = disassembly
+            .lines
+            .iter()
+            .map(|line| (line.offset, line.text.as_str()))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (0x22, "local.get 0"),
+                (0x24, "i64.const 1"),
+                (0x26, "i64.add"),
+                (0x27, "end"),
+            ]
+        );
+
+        let wat = disassembly.to_wat();
+        assert!(wat.contains("(func $add (;0;) (param i64) (result i64)"));
+        assert!(wat.contains("0x00000026    i64.add"));
+
+        assert!(disassemble_function(&wasm, "missing").is_err());
+    }
+
+    #[test]
+    fn wat_mnemonic_follows_text_format_naming() {
+        assert_eq!(wat_mnemonic(&Operator::I64ExtendI32U), "i64.extend_i32_u");
+        assert_eq!(
+            wat_mnemonic(&Operator::LocalTee { local_index: 1 }),
+            "local.tee"
+        );
+        assert_eq!(wat_mnemonic(&Operator::BrIf { relative_depth: 0 }), "br_if");
+        assert_eq!(wat_mnemonic(&Operator::I32Add), "i32.add");
+    }
 }
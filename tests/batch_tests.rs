@@ -1,3 +1,6 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
 use soroban_debugger::batch::{BatchExecutor, BatchItem};
 use std::fs;
 use tempfile::TempDir;
@@ -64,6 +67,7 @@ fn test_batch_summary_calculation() {
             expected: Some("ok".to_string()),
             passed: true,
             duration_ms: 10,
+            skipped: false,
         },
         BatchResult {
             index: 1,
@@ -75,6 +79,7 @@ fn test_batch_summary_calculation() {
             expected: Some("ok".to_string()),
             passed: false,
             duration_ms: 15,
+            skipped: false,
         },
         BatchResult {
             index: 2,
@@ -86,6 +91,7 @@ fn test_batch_summary_calculation() {
             expected: None,
             passed: false,
             duration_ms: 5,
+            skipped: false,
         },
     ];
 
@@ -119,3 +125,99 @@ fn test_batch_file_not_array() {
     let result = BatchExecutor::load_batch_file(&batch_file);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_load_batch_file_substitutes_vars() {
+    let temp_dir = TempDir::new().unwrap();
+    let batch_file = temp_dir.path().join("batch.json");
+
+    fs::write(
+        &batch_file,
+        r#"[
+        {"args": ["${signer}", 1], "label": "first"},
+        {"args": ["${signer}", "${signer}"], "expected": "${signer}"}
+    ]"#,
+    )
+    .unwrap();
+
+    let vars = [("signer".to_string(), "GSIGNER".to_string())].into();
+    let items = BatchExecutor::load_batch_file_with_vars(&batch_file, &vars).unwrap();
+    assert_eq!(items[0].args, r#"["GSIGNER",1]"#);
+    assert_eq!(items[1].args, r#"["GSIGNER","GSIGNER"]"#);
+    assert_eq!(items[1].expected, Some("GSIGNER".to_string()));
+
+    let items = BatchExecutor::load_batch_file(&batch_file).unwrap();
+    assert_eq!(items[0].args, r#"["${signer}",1]"#);
+}
+
+#[test]
+fn test_load_batch_csv_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let batch_file = temp_dir.path().join("sweep.csv");
+
+    fs::write(
+        &batch_file,
+        "function,args,storage,expected_result\n\
+         increment,\"[${step}]\",\"{\"\"count\"\": 5}\",6\n\
+         ,[2],,\n",
+    )
+    .unwrap();
+
+    let vars = [("step".to_string(), "1".to_string())].into();
+    let items = BatchExecutor::load_batch_file_with_vars(&batch_file, &vars).unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].function.as_deref(), Some("increment"));
+    assert_eq!(items[0].args, "[1]");
+    assert_eq!(items[0].storage.as_deref(), Some(r#"{"count": 5}"#));
+    assert_eq!(items[0].expected.as_deref(), Some("6"));
+    assert_eq!(items[1].function, None);
+    assert_eq!(items[1].expected, None);
+    assert_eq!(items[1].label.as_deref(), Some("row 2"));
+}
+
+#[test]
+fn test_json_batch_item_accepts_storage_object() {
+    let temp_dir = TempDir::new().unwrap();
+    let batch_file = temp_dir.path().join("batch.json");
+
+    fs::write(
+        &batch_file,
+        r#"[{"args": [1], "storage": {"count": 5}, "function": "increment"}]"#,
+    )
+    .unwrap();
+
+    let items = BatchExecutor::load_batch_file(&batch_file).unwrap();
+    assert_eq!(items[0].storage.as_deref(), Some(r#"{"count":5}"#));
+    assert_eq!(items[0].function.as_deref(), Some("increment"));
+}
+
+#[test]
+fn test_fail_fast_skips_items_after_first_failure() {
+    let wasm = fixtures::load_fixture(fixtures::names::COUNTER);
+
+    let item = |expected: &str| BatchItem {
+        args: "[]".to_string(),
+        expected: Some(expected.to_string()),
+        label: None,
+        strict: false,
+        storage: None,
+        function: None,
+    };
+    // One worker runs the items in order, so the first mismatch stops the rest.
+    let executor = BatchExecutor::new(wasm, "get".to_string())
+        .unwrap()
+        .with_concurrency(1)
+        .with_fail_fast(true);
+    let results = executor
+        .execute_batch(vec![item("99"), item("0"), item("0")])
+        .unwrap();
+    let summary = BatchExecutor::summarize(&results);
+
+    assert_eq!(summary.total, 3);
+    assert_eq!(summary.failed, 1);
+    assert_eq!(summary.passed, 0);
+    assert_eq!(summary.skipped, 2);
+    assert_eq!(summary.failures.len(), 1);
+    assert!(!results[0].skipped);
+    assert!(results[1].skipped && results[2].skipped);
+}
@@ -152,3 +152,246 @@ fn test_inspect_json_output_includes_artifact_metadata() {
         .stdout(predicate::str::contains("\"build_profile_hint\""))
         .stdout(predicate::str::contains("\"optimization_hint\""));
 }
+
+#[test]
+fn test_inspect_writes_dependency_graph_json() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let graph_file = temp_dir.path().join("graph.json");
+
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    cmd.args([
+        "inspect",
+        "--contract",
+        "tests/fixtures/wasm/cross_contract.wasm",
+        "--graph-output",
+        graph_file.to_str().unwrap(),
+        "--graph-format",
+        "json",
+    ])
+    .assert()
+    .success();
+
+    let graph: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&graph_file).unwrap()).unwrap();
+    assert_eq!(
+        graph,
+        serde_json::json!({
+            "nodes": ["call", "external_contract"],
+            "edges": [{"from": "call", "to": "external_contract"}],
+        })
+    );
+}
+
+#[test]
+fn test_inspect_prints_only_the_requested_graph_format() {
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    cmd.env("NO_BANNER", "1")
+        .args([
+            "inspect",
+            "--contract",
+            "tests/fixtures/wasm/cross_contract.wasm",
+            "--dependency-graph",
+            "dot",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("digraph contract_dependencies"))
+        .stdout(predicate::str::contains(
+            "\"call\" -> \"external_contract\";",
+        ));
+}
+
+#[test]
+fn test_inspect_dependency_graph_uses_contract_map_names() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let map_file = temp_dir.path().join("contracts.json");
+    std::fs::write(&map_file, r#"{"external_contract": "token"}"#).unwrap();
+
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    cmd.env("NO_BANNER", "1")
+        .args([
+            "inspect",
+            "--contract",
+            "tests/fixtures/wasm/cross_contract.wasm",
+            "--dependency-graph",
+            "dot",
+            "--contract-map",
+            &format!("call=vault,@{}", map_file.display()),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"vault\" -> \"token\";"))
+        .stdout(predicate::str::contains("external_contract").not());
+}
+
+#[test]
+fn test_inspect_lists_host_imports() {
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    cmd.env("NO_BANNER", "1")
+        .args([
+            "inspect",
+            "--contract",
+            "tests/fixtures/wasm/cross_contract.wasm",
+            "--host-imports",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Host imports: 2 function(s) from 2 module(s)",
+        ))
+        .stdout(predicate::str::contains("  call (1): call"))
+        .stdout(predicate::str::contains("  ledger (1): put_contract_data"));
+}
+
+#[test]
+fn test_inspect_host_imports_json() {
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    let output = cmd
+        .env("NO_BANNER", "1")
+        .args([
+            "inspect",
+            "--contract",
+            "tests/fixtures/wasm/cross_contract.wasm",
+            "--host-imports",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("Failed to execute command");
+    assert!(output.status.success());
+
+    let envelope: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        envelope["result"]["modules"],
+        serde_json::json!({"call": ["call"], "ledger": ["put_contract_data"]})
+    );
+}
+
+#[test]
+fn test_inspect_validate_passes_sdk_built_fixture() {
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    cmd.env("NO_BANNER", "1")
+        .args([
+            "inspect",
+            "--contract",
+            "tests/fixtures/wasm/always_panic.wasm",
+            "--validate",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[PASS] no-floating-point"))
+        .stdout(predicate::str::contains("[FAIL]").not())
+        .stdout(predicate::str::contains("Verdict: deployable"));
+}
+
+#[test]
+fn test_inspect_validate_fails_module_using_f64() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let contract_file = temp_dir.path().join("float.wasm");
+    // One function whose body is `f64.const 1.5; drop; end`.
+    let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    wasm.extend_from_slice(&[0x01, 0x04, 0x01, 0x60, 0x00, 0x00]);
+    wasm.extend_from_slice(&[0x03, 0x02, 0x01, 0x00]);
+    wasm.extend_from_slice(&[0x0A, 0x0E, 0x01, 0x0C, 0x00, 0x44]);
+    wasm.extend_from_slice(&1.5f64.to_le_bytes());
+    wasm.extend_from_slice(&[0x1A, 0x0B]);
+    std::fs::write(&contract_file, wasm).expect("Failed to write temp file");
+
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    cmd.env("NO_BANNER", "1")
+        .args([
+            "inspect",
+            "--contract",
+            contract_file.to_str().unwrap(),
+            "--validate",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("[FAIL] no-floating-point"))
+        .stdout(predicate::str::contains("Verdict: would be rejected"));
+
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    let output = cmd
+        .env("NO_BANNER", "1")
+        .args([
+            "inspect",
+            "--contract",
+            contract_file.to_str().unwrap(),
+            "--validate",
+            "--format",
+            "json",
+        ])
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+    let envelope: serde_json::Value =
+        serde_json::from_slice(&output).expect("validation output should be JSON");
+    assert_eq!(envelope["status"], "error");
+    assert!(envelope["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("Soroban deployment check"));
+    assert_eq!(envelope["result"]["passed"], false);
+}
+
+#[test]
+fn test_inspect_spec_prints_function_signatures() {
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    cmd.env("NO_BANNER", "1")
+        .args(["inspect", "--contract", fixture_wasm(), "--spec"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Functions (2):"))
+        .stdout(predicate::str::contains("increment() -> I64"))
+        .stdout(predicate::str::contains("get() -> I64"));
+}
+
+#[test]
+fn test_inspect_spec_json_output() {
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    let output = cmd
+        .env("NO_BANNER", "1")
+        .args([
+            "inspect",
+            "--contract",
+            fixture_wasm(),
+            "--spec",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("Failed to run inspect");
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let functions = json["result"]["functions"].as_array().unwrap();
+    assert!(functions
+        .iter()
+        .any(|f| f["name"] == "increment" && f["return_type"] == "I64"));
+    assert_eq!(json["result"]["errors"], serde_json::json!([]));
+}
+
+#[test]
+fn test_inspect_spec_without_section_fails_clearly() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let contract_file = temp_dir.path().join("bare.wasm");
+    std::fs::write(
+        &contract_file,
+        [0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00],
+    )
+    .expect("Failed to write temp file");
+
+    let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
+    cmd.env("NO_BANNER", "1")
+        .args([
+            "inspect",
+            "--contract",
+            contract_file.to_str().unwrap(),
+            "--spec",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no contractspecv0 section"));
+}
@@ -5,6 +5,8 @@ use tempfile::NamedTempFile;
 
 #[path = "fixtures/mod.rs"]
 mod fixtures;
+#[path = "fixtures/wallet.rs"]
+mod wallet;
 
 fn fixture_wasm(name: &str) -> std::path::PathBuf {
     fixtures::get_fixture_path(name)
@@ -60,6 +62,89 @@ fn symbolic_writes_scenario_toml() {
     assert!(written.contains("function = \"increment\""));
 }
 
+#[test]
+fn symbolic_panic_scenario_reproduces_the_trap() {
+    let wasm = fixture_wasm("always_panic");
+    let output = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "symbolic",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "panic",
+            "--output",
+            output.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let written = fs::read_to_string(output.path()).unwrap();
+    assert!(written.contains("[[steps]]"), "{written}");
+    assert!(written.contains("name = \"panic #1\""), "{written}");
+    assert!(written.contains("args = \"[]\""), "{written}");
+    assert!(written.contains("expected_panic = "), "{written}");
+
+    // The generated file is itself a scenario whose step asserts the panic.
+    base_cmd()
+        .args([
+            "scenario",
+            "--scenario",
+            output.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Expected panic assertion passed"))
+        .stdout(predicate::str::contains(
+            "All scenario steps passed successfully!",
+        ));
+}
+
+#[test]
+fn symbolic_conditional_panic_scenario_reproduces_the_trap() {
+    let wasm = fixture_wasm("conditional_panic");
+    let output = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "symbolic",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "check",
+            "--output",
+            output.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    // Inputs within the bound return normally; the step covers the first
+    // seed past it.
+    let written = fs::read_to_string(output.path()).unwrap();
+    assert!(written.contains("expected_return = "), "{written}");
+    assert!(written.contains("[[steps]]"), "{written}");
+    assert!(written.contains("args = \"[2147483647]\""), "{written}");
+    assert!(!written.contains("args = \"[42]\""), "{written}");
+
+    base_cmd()
+        .args([
+            "scenario",
+            "--scenario",
+            output.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Expected panic assertion passed"))
+        .stdout(predicate::str::contains(
+            "All scenario steps passed successfully!",
+        ));
+}
+
 #[test]
 fn symbolic_cli_honors_caps_and_reports_truncation() {
     let wasm = fixture_wasm("budget_heavy");
@@ -176,6 +261,38 @@ fn analyze_dynamic_execution_reports_function_metadata() {
         ));
 }
 
+fn analyze_wallet_failing_on(severity: &str) -> assert_cmd::assert::Assert {
+    let dir = tempfile::tempdir().unwrap();
+    let wasm = dir.path().join("wallet.wasm");
+    fs::write(&wasm, wallet::wallet_wasm()).unwrap();
+
+    base_cmd()
+        .args([
+            "analyze",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--fail-on",
+            severity,
+        ])
+        .assert()
+}
+
+#[test]
+fn analyze_fail_on_exits_with_findings_code() {
+    analyze_wallet_failing_on("high")
+        .code(soroban_debugger::exit_code::SECURITY_FINDINGS)
+        // The report is still printed before the command fails.
+        .stdout(predicate::str::contains("withdraw_buggy"))
+        .stderr(predicate::str::contains("--fail-on"));
+}
+
+#[test]
+fn analyze_fail_on_passes_below_threshold() {
+    analyze_wallet_failing_on("critical")
+        .success()
+        .stdout(predicate::str::contains("withdraw_buggy"));
+}
+
 #[test]
 fn scenario_runs_counter_steps() {
     let wasm = fixture_wasm("counter");
@@ -753,3 +870,660 @@ fn run_export_storage_performs_single_export() {
         combined
     );
 }
+
+#[test]
+fn run_show_budget_prints_cost_type_breakdown() {
+    let wasm = fixture_wasm("budget_heavy");
+
+    let output = base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "heavy",
+            "--args",
+            "[200]",
+            "--show-budget",
+        ])
+        .output()
+        .unwrap();
+
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output.status.success(), "{combined}");
+    assert!(combined.contains("--- Budget Breakdown ---"));
+    assert!(combined.contains("WasmInsnExec"));
+}
+
+#[test]
+fn run_warns_when_budget_exceeds_custom_network_limits() {
+    let wasm = fixture_wasm("budget_heavy");
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(
+        dir.path().join(".soroban-debug.toml"),
+        "[budget_limits.custom]\ncpu_limit = 1000\n",
+    )
+    .unwrap();
+
+    let output = base_cmd()
+        .current_dir(dir.path())
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "heavy",
+            "--args",
+            "[200]",
+            "--network-limits",
+            "custom",
+        ])
+        .output()
+        .unwrap();
+
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output.status.success(), "{combined}");
+    assert!(combined.contains("exceeds the network limit of 1.00K"));
+    assert!(!combined.contains("Memory usage"));
+}
+
+#[test]
+fn run_reads_arguments_from_args_file() {
+    let wasm = fixture_wasm("budget_heavy");
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("args.json"), "[3]\n").unwrap();
+
+    base_cmd()
+        .current_dir(dir.path())
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "heavy",
+            "--args-file",
+            "args.json",
+        ])
+        .assert()
+        .success();
+}
+
+fn run_heavy_with_args(args: &str, extra: &[&str]) -> assert_cmd::assert::Assert {
+    let wasm = fixture_wasm("budget_heavy");
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "heavy",
+            "--args",
+            args,
+        ])
+        .args(extra)
+        .assert()
+}
+
+#[test]
+fn run_rejects_wrong_argument_count_before_invoking() {
+    run_heavy_with_args("[1, 2]", &[])
+        .failure()
+        .stderr(predicate::str::contains(
+            "function heavy expects 1 arg (U32), got 2",
+        ));
+}
+
+#[test]
+fn run_rejects_wrong_argument_type_before_invoking() {
+    run_heavy_with_args(r#"["lots"]"#, &[])
+        .failure()
+        .stderr(predicate::str::contains(
+            "function heavy argument 1 ('n') expects U32, got string \"lots\"",
+        ));
+}
+
+#[test]
+fn run_accepts_arguments_by_parameter_name() {
+    run_heavy_with_args(r#"{"n": 3}"#, &[]).success();
+}
+
+#[test]
+fn run_rejects_unknown_named_argument() {
+    run_heavy_with_args(r#"{"count": 3}"#, &[])
+        .failure()
+        .stderr(predicate::str::contains(
+            "function heavy has no parameter 'count' (parameters: n)",
+        ));
+}
+
+#[test]
+fn run_no_arg_check_leaves_validation_to_the_contract() {
+    run_heavy_with_args("[1, 2]", &["--no-arg-check"])
+        .failure()
+        .stderr(predicate::str::contains("expects 1 arg").not());
+}
+
+#[test]
+fn run_reports_args_file_path_on_invalid_json() {
+    let wasm = fixture_wasm("budget_heavy");
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("bad-args.json"), "[3,").unwrap();
+
+    base_cmd()
+        .current_dir(dir.path())
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "heavy",
+            "--args-file",
+            "bad-args.json",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to parse JSON arguments"))
+        .stderr(predicate::str::contains("bad-args.json"));
+}
+
+#[test]
+fn run_watch_reruns_when_the_contract_changes() {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+
+    let dir = tempfile::tempdir().unwrap();
+    let wasm = dir.path().join("counter.wasm");
+    fs::copy(fixture_wasm("counter"), &wasm).unwrap();
+
+    let mut child = std::process::Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .env("NO_COLOR", "1")
+        .env("NO_BANNER", "1")
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--watch",
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    let wait_for = |needle: &str| {
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while let Some(left) = deadline.checked_duration_since(Instant::now()) {
+            match rx.recv_timeout(left) {
+                Ok(line) if line.contains(needle) => return true,
+                Ok(_) => continue,
+                Err(_) => return false,
+            }
+        }
+        false
+    };
+
+    let watching = wait_for("Watching");
+    if watching {
+        fs::copy(fixture_wasm("counter"), &wasm).unwrap();
+    }
+    let reran = watching && wait_for("Run #2");
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert!(watching, "the first run should finish and start watching");
+    assert!(reran, "rewriting the contract should start a second run");
+}
+
+#[test]
+fn run_json_stream_prints_one_object_per_line() {
+    let wasm = fixture_wasm("counter");
+
+    let output = base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--breakpoint",
+            "increment",
+            "--json-stream",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let records: Vec<serde_json::Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("every stdout line is JSON"))
+        .collect();
+    let kinds: Vec<&str> = records
+        .iter()
+        .map(|record| record["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(kinds.first(), Some(&"contract_loaded"));
+    assert_eq!(kinds.last(), Some(&"result"));
+    assert!(records
+        .iter()
+        .any(|record| record["event"]["BreakpointHit"]["function"] == "increment"));
+    assert!(records
+        .iter()
+        .any(|record| record["event"]["StorageAccess"]["operation"] == "Write"));
+
+    let result = records.last().unwrap();
+    assert_eq!(result["status"], "success");
+    assert_eq!(result["result"], "I64(1)");
+    assert!(result["budget"]["cpu_instructions"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn run_exits_with_execution_code_when_contract_panics() {
+    let wasm = fixture_wasm("always_panic");
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "panic",
+        ])
+        .assert()
+        .code(soroban_debugger::exit_code::EXECUTION_FAILED);
+}
+
+#[test]
+fn run_exits_with_checksum_code_on_hash_mismatch() {
+    let wasm = fixture_wasm("counter");
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "get",
+            "--expected-hash",
+            &"0".repeat(64),
+        ])
+        .assert()
+        .code(soroban_debugger::exit_code::CHECKSUM_MISMATCH);
+}
+
+#[test]
+fn optimize_baseline_adds_changes_section() {
+    let wasm = fixture_wasm("counter");
+    let output = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "optimize",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "get",
+            "--baseline",
+            wasm.to_str().unwrap(),
+            "--output",
+            output.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Changes vs baseline"));
+
+    let report = fs::read_to_string(output.path()).unwrap();
+    assert!(report.contains("## Changes vs baseline"), "{report}");
+    assert!(report.contains("| get |"), "{report}");
+}
+
+#[test]
+fn upgrade_check_exits_with_breaking_code() {
+    base_cmd()
+        .args([
+            "upgrade-check",
+            "--old",
+            fixture_wasm("counter").to_str().unwrap(),
+            "--new",
+            fixture_wasm("echo").to_str().unwrap(),
+        ])
+        .assert()
+        .code(soroban_debugger::exit_code::BREAKING_CHANGES)
+        .stdout(predicate::str::contains("Breaking"));
+}
+
+#[test]
+fn upgrade_check_reports_size_comparison() {
+    let wasm = fixture_wasm("counter");
+    let size = fs::metadata(&wasm).unwrap().len();
+
+    base_cmd()
+        .args([
+            "upgrade-check",
+            "--old",
+            wasm.to_str().unwrap(),
+            "--new",
+            wasm.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Size: {size} -> {size} bytes (+0, +0.0%)"
+        )))
+        .stdout(predicate::str::contains("Custom (contractspecv0)"));
+
+    let output = base_cmd()
+        .args([
+            "--quiet",
+            "upgrade-check",
+            "--old",
+            wasm.to_str().unwrap(),
+            "--new",
+            wasm.to_str().unwrap(),
+            "--output",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["result"]["size"]["old_size"], size);
+    assert_eq!(json["result"]["size"]["delta"], 0);
+}
+
+fn read_uleb(bytes: &[u8], pos: &mut usize) -> usize {
+    let (mut value, mut shift) = (0usize, 0);
+    loop {
+        let byte = bytes[*pos];
+        *pos += 1;
+        value |= ((byte & 0x7f) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return value;
+        }
+        shift += 7;
+    }
+}
+
+fn write_uleb(mut value: usize, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// The counter fixture with a `reset()` function added to its contract spec,
+/// standing in for a v2 build that gained a method.
+fn counter_v2_wasm() -> Vec<u8> {
+    use soroban_env_host::xdr::{Limits, ScSpecEntry, ScSpecFunctionV0, ScSymbol, WriteXdr};
+
+    let reset = ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+        doc: Default::default(),
+        name: ScSymbol("reset".try_into().unwrap()),
+        inputs: Default::default(),
+        outputs: Default::default(),
+    })
+    .to_xdr(Limits::none())
+    .unwrap();
+
+    let v1 = fs::read(fixture_wasm("counter")).unwrap();
+    let mut v2 = v1[..8].to_vec();
+    let mut pos = 8;
+    while pos < v1.len() {
+        let id = v1[pos];
+        pos += 1;
+        let size = read_uleb(&v1, &mut pos);
+        let mut payload = v1[pos..pos + size].to_vec();
+        pos += size;
+
+        let spec_name = b"\x0econtractspecv0";
+        if id == 0 && payload.starts_with(spec_name) {
+            payload.extend_from_slice(&reset);
+        }
+        v2.push(id);
+        write_uleb(payload.len(), &mut v2);
+        v2.extend_from_slice(&payload);
+    }
+    v2
+}
+
+#[test]
+fn upgrade_check_json_lists_added_function() {
+    let old = fixture_wasm("counter");
+    let new = NamedTempFile::new().unwrap();
+    fs::write(new.path(), counter_v2_wasm()).unwrap();
+
+    let output = base_cmd()
+        .args([
+            "--quiet",
+            "upgrade-check",
+            "--old",
+            old.to_str().unwrap(),
+            "--new",
+            new.path().to_str().unwrap(),
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let report = &json["result"];
+    assert_eq!(report["classification"], "Caution");
+    assert_eq!(report["summary"]["non_breaking_changes"], 1);
+    assert_eq!(
+        report["non_breaking_changes"],
+        serde_json::json!([{"type": "FunctionAdded", "name": "reset"}])
+    );
+    assert_eq!(report["breaking_changes"], serde_json::json!([]));
+}
+
+#[test]
+fn migrate_check_runs_migration_over_old_storage() {
+    let output = base_cmd()
+        .args([
+            "--quiet",
+            "migrate-check",
+            "--old-storage",
+            r#"{"c": 41}"#,
+            "--new-contract",
+            fixture_wasm("counter").to_str().unwrap(),
+            "--function",
+            "increment",
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let report = &json["result"];
+    assert_eq!(report["success"], true);
+    assert_eq!(report["seeded_entries"], 1);
+    assert!(report["result"].as_str().unwrap().contains("I64(42)"));
+    let modified = report["storage_diff"]["modified"].as_object().unwrap();
+    assert_eq!(modified.len(), 1);
+    assert_eq!(
+        modified.values().next().unwrap(),
+        &serde_json::json!(["I64(41)", "I64(42)"])
+    );
+}
+
+#[test]
+fn migrate_check_exits_with_execution_code_when_migration_fails() {
+    base_cmd()
+        .args([
+            "migrate-check",
+            "--old-storage",
+            r#"{"c": 41}"#,
+            "--new-contract",
+            fixture_wasm("counter").to_str().unwrap(),
+        ])
+        .assert()
+        .code(soroban_debugger::exit_code::EXECUTION_FAILED)
+        .stdout(predicate::str::contains("Migration failed"));
+}
+
+#[test]
+fn run_continues_from_saved_network_snapshot() {
+    let wasm = fixture_wasm("counter");
+    let dir = tempfile::tempdir().unwrap();
+    let step1 = dir.path().join("step1.json");
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--storage",
+            r#"{"c": 41}"#,
+            "--save-snapshot",
+            step1.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--network-snapshot",
+            step1.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("I64(43)"));
+}
+
+#[test]
+fn run_json_redacts_meta_inputs_and_ledger_entries() {
+    let wasm = fixture_wasm("counter");
+    if !wasm.exists() {
+        eprintln!("Skipping test: fixture not found at {}", wasm.display());
+        return;
+    }
+
+    let output = base_cmd()
+        .args([
+            "--quiet",
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--storage",
+            r#"{"c": 41}"#,
+            "--show-ledger",
+            "--redact",
+            "contract_data:*",
+            "--output",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let start = if stdout.starts_with('{') {
+        0
+    } else {
+        stdout.find("\n{").expect("JSON object in stdout") + 1
+    };
+    let json: serde_json::Value = serde_json::from_str(&stdout[start..]).unwrap();
+
+    assert_eq!(json["meta"]["storage"], "***");
+    assert_eq!(json["meta"]["function"], "increment");
+
+    let entries = json["result"]["ledger_entries"]["entries"]
+        .as_array()
+        .expect("ledger entries");
+    let instance = entries
+        .iter()
+        .find(|entry| entry["storage_type"] == "Instance")
+        .expect("contract instance entry");
+    assert_eq!(instance["value"], "***");
+    for entry in entries {
+        assert!(!entry["value"].as_str().unwrap().contains("I64(41)"));
+    }
+
+    for (_, values) in json["result"]["storage_diff"]["modified"]
+        .as_object()
+        .into_iter()
+        .flatten()
+    {
+        assert_eq!(values, &serde_json::json!(["***", "***"]));
+    }
+}
+
+#[test]
+fn run_json_stream_streams_instruction_steps_and_exports_storage() {
+    let wasm = fixture_wasm("counter");
+    let dir = tempfile::tempdir().unwrap();
+    let export = dir.path().join("storage.json");
+
+    let output = base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--step-instructions",
+            "--json-stream",
+            "--export-storage",
+            export.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let records: Vec<serde_json::Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("every stdout line is JSON"))
+        .collect();
+    let first_step = records
+        .iter()
+        .position(|record| record["event"]["BeforeInstruction"]["pc"].is_u64())
+        .expect("stepped instructions are streamed");
+    let first_write = records
+        .iter()
+        .position(|record| record["event"]["StorageAccess"]["operation"] == "Write")
+        .expect("storage writes are streamed");
+    assert!(first_step < first_write);
+    assert_eq!(records.last().unwrap()["status"], "success");
+    assert!(export.exists());
+}
@@ -0,0 +1,117 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use serde_json::{json, Value};
+use soroban_debugger::server::DapServer;
+
+fn request(seq: i64, command: &str, arguments: Value) -> String {
+    let body = json!({
+        "seq": seq,
+        "type": "request",
+        "command": command,
+        "arguments": arguments,
+    })
+    .to_string();
+    format!("Content-Length: {}\r\n\r\n{}", body.len(), body)
+}
+
+/// Run a session over in-memory streams and return every message the
+/// server wrote, in order.
+fn run_session(requests: &[String]) -> Vec<Value> {
+    let input = requests.concat();
+    let mut output = Vec::new();
+    DapServer::new(input.as_bytes(), &mut output).run().unwrap();
+
+    let text = String::from_utf8(output).unwrap();
+    text.split("Content-Length: ")
+        .filter(|chunk| !chunk.is_empty())
+        .map(|chunk| {
+            let (_, body) = chunk.split_once("\r\n\r\n").unwrap();
+            serde_json::from_str(body).unwrap()
+        })
+        .collect()
+}
+
+fn response<'a>(messages: &'a [Value], command: &str) -> &'a Value {
+    messages
+        .iter()
+        .find(|m| m["type"] == "response" && m["command"] == command)
+        .unwrap_or_else(|| panic!("no {} response", command))
+}
+
+fn events<'a>(messages: &'a [Value], name: &str) -> Vec<&'a Value> {
+    messages
+        .iter()
+        .filter(|m| m["type"] == "event" && m["event"] == name)
+        .collect()
+}
+
+fn launch(seq: i64, function: &str) -> String {
+    let contract = fixtures::get_fixture_path("counter");
+    request(
+        seq,
+        "launch",
+        json!({ "contract": contract, "function": function }),
+    )
+}
+
+#[test]
+fn function_breakpoint_stops_and_reports_call_stack() {
+    let messages = run_session(&[
+        request(1, "initialize", json!({ "adapterID": "soroban" })),
+        launch(2, "increment"),
+        request(
+            3,
+            "setFunctionBreakpoints",
+            json!({ "breakpoints": [{ "name": "increment" }, { "name": "missing" }] }),
+        ),
+        request(4, "configurationDone", Value::Null),
+        request(5, "stackTrace", json!({ "threadId": 1 })),
+        request(6, "continue", json!({ "threadId": 1 })),
+        request(7, "disconnect", Value::Null),
+    ]);
+
+    assert_eq!(events(&messages, "initialized").len(), 1);
+    let breakpoints = &response(&messages, "setFunctionBreakpoints")["body"]["breakpoints"];
+    assert_eq!(breakpoints[0]["verified"], true);
+    assert_eq!(breakpoints[1]["verified"], false);
+
+    let stopped = events(&messages, "stopped");
+    assert_eq!(stopped.len(), 1);
+    assert_eq!(stopped[0]["body"]["reason"], "breakpoint");
+
+    let frames = &response(&messages, "stackTrace")["body"]["stackFrames"];
+    assert!(frames[0]["name"].as_str().unwrap().starts_with("increment"));
+
+    assert_eq!(events(&messages, "exited")[0]["body"]["exitCode"], 0);
+    assert_eq!(events(&messages, "terminated").len(), 1);
+}
+
+#[test]
+fn launch_without_breakpoints_runs_to_completion() {
+    let messages = run_session(&[
+        request(1, "initialize", Value::Null),
+        launch(2, "increment"),
+        request(3, "configurationDone", Value::Null),
+        request(4, "disconnect", Value::Null),
+    ]);
+
+    assert!(events(&messages, "stopped").is_empty());
+    let output = events(&messages, "output");
+    assert!(output
+        .iter()
+        .any(|e| e["body"]["category"] == "stdout" && e["body"]["output"] == "I64(1)\n"));
+    // Instruction stepping was enabled, so launch reported no problem.
+    assert!(!output.iter().any(|e| e["body"]["category"] == "stderr"));
+    assert_eq!(events(&messages, "exited")[0]["body"]["exitCode"], 0);
+}
+
+#[test]
+fn launch_rejects_unknown_function() {
+    let messages = run_session(&[launch(1, "nope"), request(2, "disconnect", Value::Null)]);
+
+    let launch = response(&messages, "launch");
+    assert_eq!(launch["success"], false);
+    assert!(launch["message"].as_str().unwrap().contains("nope"));
+    assert!(events(&messages, "initialized").is_empty());
+}
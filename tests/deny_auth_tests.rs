@@ -0,0 +1,64 @@
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+const FROM: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+
+/// The auth example is built separately:
+/// `cd examples/contracts/auth-example && cargo build --target wasm32-unknown-unknown --release`
+fn auth_example_wasm() -> Option<Vec<u8>> {
+    let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("examples/contracts/auth-example/target/wasm32-unknown-unknown/release")
+        .join("soroban_auth_example.wasm");
+    if !path.exists() {
+        eprintln!(
+            "Skipping test: auth example not built at {}",
+            path.display()
+        );
+        return None;
+    }
+    Some(std::fs::read(path).expect("read auth example wasm"))
+}
+
+fn withdraw_args() -> String {
+    format!(r#"["{FROM}", 0]"#)
+}
+
+#[test]
+fn withdraw_succeeds_when_auth_is_mocked() {
+    let Some(wasm) = auth_example_wasm() else {
+        return;
+    };
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    executor.enable_mock_all_auths();
+
+    assert!(executor.execute("withdraw", Some(&withdraw_args())).is_ok());
+}
+
+#[test]
+fn withdraw_fails_when_from_is_denied() {
+    let Some(wasm) = auth_example_wasm() else {
+        return;
+    };
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    executor.deny_auth(&[FROM.to_string()]).unwrap();
+
+    let err = executor
+        .execute("withdraw", Some(&withdraw_args()))
+        .unwrap_err();
+    assert_eq!(
+        soroban_debugger::exit_code::for_error(&err),
+        soroban_debugger::exit_code::EXECUTION_FAILED
+    );
+    // The host itself rejected the call, as it would on the network.
+    let events = format!("{:?}", executor.get_diagnostic_events().unwrap());
+    assert!(events.contains("Auth(InvalidAction)"), "{events}");
+
+    let log = executor.get_mock_call_log();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].auth_denied.as_deref(), Some(FROM));
+    assert_eq!(log[0].function, "withdraw");
+
+    // The buggy path never asks for auth, so the deny list cannot stop it.
+    assert!(executor
+        .execute("withdraw_buggy", Some(&withdraw_args()))
+        .is_ok());
+}
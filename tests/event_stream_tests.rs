@@ -0,0 +1,70 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::debugger::engine::DebuggerEngine;
+use soroban_debugger::plugin::{ExecutionEvent, StorageOperation};
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+fn counter_engine() -> DebuggerEngine {
+    let wasm_bytes = std::fs::read(fixtures::get_fixture_path("counter")).unwrap();
+    let executor = ContractExecutor::new(wasm_bytes).unwrap();
+    DebuggerEngine::new(executor, vec![])
+}
+
+#[test]
+fn event_stream_yields_calls_and_storage_writes_in_order() {
+    let mut engine = counter_engine();
+    let stream = engine.event_stream();
+
+    engine.execute("increment", None).unwrap();
+
+    let events: Vec<ExecutionEvent> = stream.try_iter().collect();
+    assert!(matches!(
+        events.first(),
+        Some(ExecutionEvent::BeforeFunctionCall { function, .. }) if function == "increment"
+    ));
+    assert!(matches!(
+        events.last(),
+        Some(ExecutionEvent::AfterFunctionCall { result: Ok(_), .. })
+    ));
+    assert!(events.iter().any(|event| matches!(
+        event,
+        ExecutionEvent::StorageAccess {
+            operation: StorageOperation::Write,
+            ..
+        }
+    )));
+
+    // Storage accesses are replayed together once the call returns.
+    let is_storage = |event: &ExecutionEvent| matches!(event, ExecutionEvent::StorageAccess { .. });
+    let first = events.iter().position(is_storage).unwrap();
+    let last = events.iter().rposition(is_storage).unwrap();
+    assert!(events[first..=last].iter().all(is_storage));
+    assert_eq!(last, events.len() - 2);
+}
+
+#[test]
+fn event_stream_reports_breakpoint_hits() {
+    let mut engine = counter_engine();
+    engine.add_breakpoint_spec("increment").unwrap();
+    let stream = engine.event_stream();
+
+    engine.execute("increment", None).unwrap();
+
+    assert!(stream.try_iter().any(|event| matches!(
+        event,
+        ExecutionEvent::BreakpointHit { function, .. } if function == "increment"
+    )));
+}
+
+#[test]
+fn event_stream_ends_when_engine_is_dropped() {
+    let mut engine = counter_engine();
+    let stream = engine.event_stream();
+    let consumer = std::thread::spawn(move || stream.count());
+
+    engine.execute("get", None).unwrap();
+    drop(engine);
+
+    assert!(consumer.join().unwrap() >= 2);
+}
@@ -15,9 +15,11 @@ function Get-FixtureExports {
     switch ($Name) {
         "always_panic" { return @("panic") }
         "budget_heavy" { return @("heavy") }
+        "conditional_panic" { return @("check") }
         "counter" { return @("get", "increment") }
         "cross_contract" { return @("call") }
         "echo" { return @("echo") }
+        "overflow_math" { return @("div", "mul") }
         "same_return" { return @("same") }
         default { throw "Unknown fixture export set for '$Name'" }
     }
@@ -14,9 +14,11 @@ fixture_exports_json() {
     case "$1" in
         always_panic) printf '["panic"]' ;;
         budget_heavy) printf '["heavy"]' ;;
+        conditional_panic) printf '["check"]' ;;
         counter) printf '["get","increment"]' ;;
         cross_contract) printf '["call"]' ;;
         echo) printf '["echo"]' ;;
+        overflow_math) printf '["div","mul"]' ;;
         same_return) printf '["same"]' ;;
         *)
             echo "Unknown fixture export set for '$1'" >&2
@@ -0,0 +1,17 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contract]
+pub struct ConditionalPanic;
+
+#[contractimpl]
+impl ConditionalPanic {
+    // Panics only on one branch, so the symbolic analyzer has to find an
+    // input past the bound before the generated scenario step can replay it.
+    pub fn check(_env: Env, x: u32) -> u32 {
+        if x > 100 {
+            panic!("x out of range");
+        }
+        x
+    }
+}
@@ -1,12 +1,18 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Val, Vec};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol, Val, Vec};
 
 #[contract]
 pub struct Caller;
 
 #[contractimpl]
 impl Caller {
+    /// Forwards the call and records its result afterwards, a state write
+    /// after an external call.
     pub fn call(env: Env, c: Address, f: Symbol, a: Vec<Val>) -> Val {
-        env.invoke_contract(&c, &f, a)
+        let result: Val = env.invoke_contract(&c, &f, a);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("last"), &result);
+        result
     }
 }
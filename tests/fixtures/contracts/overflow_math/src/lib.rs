@@ -0,0 +1,19 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contract]
+pub struct OverflowMath;
+
+#[contractimpl]
+impl OverflowMath {
+    // Plain i128 arithmetic. The SDK passes wide values as host objects and
+    // overflow checks turn a fault into a panic, so the symbolic analyzer has
+    // to trace the trap back to the operation.
+    pub fn mul(_env: Env, a: i128, b: i128) -> i128 {
+        a * b
+    }
+
+    pub fn div(_env: Env, a: i128, b: i128) -> i128 {
+        a / b
+    }
+}
@@ -146,4 +146,6 @@ pub mod names {
     pub const BUDGET_HEAVY: &str = "budget_heavy";
     pub const CROSS_CONTRACT: &str = "cross_contract";
     pub const SAME_RETURN: &str = "same_return";
+    pub const CONDITIONAL_PANIC: &str = "conditional_panic";
+    pub const OVERFLOW_MATH: &str = "overflow_math";
 }
@@ -0,0 +1,275 @@
+//! A hand-assembled module shaped like the auth example, shared by the
+//! missing-auth analyzer tests and the `analyze` CLI tests.
+
+use soroban_env_host::xdr::{
+    Limits, ScSpecEntry, ScSpecFunctionInputV0, ScSpecFunctionV0, ScSpecTypeDef, ScSymbol, WriteXdr,
+};
+
+fn uleb128(mut value: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let mut byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+    out
+}
+
+fn encode_string(bytes: &mut Vec<u8>, value: &str) {
+    bytes.extend_from_slice(&uleb128(value.len()));
+    bytes.extend_from_slice(value.as_bytes());
+}
+
+fn append_section(module: &mut Vec<u8>, id: u8, section: &[u8]) {
+    module.push(id);
+    module.extend_from_slice(&uleb128(section.len()));
+    module.extend_from_slice(section);
+}
+
+fn sleb128(mut value: i64) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        out.push(if done { byte } else { byte | 0x80 });
+        if done {
+            break;
+        }
+    }
+    out
+}
+
+fn i64_const(value: i64) -> Vec<u8> {
+    [vec![0x42], sleb128(value)].concat()
+}
+
+/// A `SymbolSmall` `Val`: six bits per character above the tag.
+fn symbol(name: &str) -> i64 {
+    let body = name.bytes().fold(0i64, |acc, c| {
+        let code = match c {
+            b'_' => 1,
+            b'0'..=b'9' => 2 + (c - b'0'),
+            b'A'..=b'Z' => 12 + (c - b'A'),
+            _ => 38 + (c - b'a'),
+        };
+        (acc << 6) | code as i64
+    });
+    (body << 8) | 14
+}
+
+fn fn_spec(name: &str, inputs: &[(&str, ScSpecTypeDef)]) -> Vec<u8> {
+    ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+        doc: Default::default(),
+        name: ScSymbol(name.try_into().unwrap()),
+        inputs: inputs
+            .iter()
+            .map(|(input, type_)| ScSpecFunctionInputV0 {
+                doc: Default::default(),
+                name: (*input).try_into().unwrap(),
+                type_: type_.clone(),
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+        outputs: Default::default(),
+    })
+    .to_xdr(Limits::none())
+    .unwrap()
+}
+
+/// Module laid out the way the SDK builds the auth example, using the
+/// soroban-sdk short import names (`a`/`0` is `require_auth`, `l`/`_`
+/// `put_contract_data`, `l`/`1` `get_contract_data`, `v`/`g`
+/// `vec_new_from_linear_memory`). `DataKey::Balance(address)` is built in a
+/// stack frame and turned into a `Vec`, and the admin is checked to be an
+/// `Address` after it is read:
+///
+/// - `withdraw_buggy` debits `from` without authorizing it;
+/// - `withdraw` calls `require_auth(from)` first;
+/// - `deposit` only credits `to`;
+/// - `admin_mint_buggy` loads the admin and credits `to` without authorizing;
+/// - `admin_mint` calls `require_auth(admin)` first;
+/// - `get_balance` does not write;
+/// - `initialize` authorizes the admin it stores.
+pub fn wallet_wasm() -> Vec<u8> {
+    const I64: u8 = 0x7E;
+    let balance_key = symbol("Balance");
+    let admin_key = symbol("Admin");
+
+    let mut module = vec![0x00, 0x61, 0x73, 0x6D, 0x01, 0x00, 0x00, 0x00];
+
+    // Types: 0 = (i64) -> i64, 1 = (i64, i64, i64) -> i64,
+    // 2 = (i64, i64) -> i64, 3 = (i64, i64) -> (), 4 = (i64) -> ().
+    let types: [(&[u8], &[u8]); 5] = [
+        (&[I64], &[I64]),
+        (&[I64, I64, I64], &[I64]),
+        (&[I64, I64], &[I64]),
+        (&[I64, I64], &[]),
+        (&[I64], &[]),
+    ];
+    let mut type_section = uleb128(types.len());
+    for (params, results) in types {
+        type_section.push(0x60);
+        type_section.extend_from_slice(&uleb128(params.len()));
+        type_section.extend_from_slice(params);
+        type_section.extend_from_slice(&uleb128(results.len()));
+        type_section.extend_from_slice(results);
+    }
+    append_section(&mut module, 1, &type_section);
+
+    // Imports: 0 = require_auth, 1 = put, 2 = get, 3 = vec_new_from_linear_memory.
+    let imports = [("a", "0", 0u8), ("l", "_", 1), ("l", "1", 2), ("v", "g", 2)];
+    let mut import = uleb128(imports.len());
+    for (module_name, name, type_index) in imports {
+        encode_string(&mut import, module_name);
+        encode_string(&mut import, name);
+        import.push(0x00);
+        import.push(type_index);
+    }
+    append_section(&mut module, 2, &import);
+
+    // Locals: 4 = key, 5 = balance, 6 = withdraw_buggy, 7 = withdraw,
+    // 8 = deposit, 9 = admin_mint_buggy, 10 = admin_mint, 11 = get_balance,
+    // 12 = initialize.
+    append_section(
+        &mut module,
+        3,
+        &[0x09, 0x00, 0x00, 0x03, 0x03, 0x03, 0x03, 0x03, 0x00, 0x04],
+    );
+
+    // Seventeen pages of memory; the stack pointer starts at 1 MiB.
+    append_section(&mut module, 5, &[0x01, 0x00, 0x11]);
+    append_section(
+        &mut module,
+        6,
+        &[0x01, 0x7F, 0x01, 0x41, 0x80, 0x80, 0xC0, 0x00, 0x0B],
+    );
+
+    let exported = [
+        ("withdraw_buggy", 6u8),
+        ("withdraw", 7),
+        ("deposit", 8),
+        ("admin_mint_buggy", 9),
+        ("admin_mint", 10),
+        ("get_balance", 11),
+        ("initialize", 12),
+    ];
+    let mut exports = uleb128(exported.len());
+    for (name, index) in exported {
+        encode_string(&mut exports, name);
+        exports.push(0x00);
+        exports.push(index);
+    }
+    append_section(&mut module, 7, &exports);
+
+    // key(address): [Balance, address] laid out in a frame, passed to
+    // vec_new_from_linear_memory as a `U32Val` pointer and length.
+    let key = [
+        &[0x01, 0x01, 0x7F][..],
+        &[0x23, 0x00, 0x41, 0x10, 0x6B, 0x22, 0x01, 0x24, 0x00], // sp -= 16
+        &[0x20, 0x01],
+        &i64_const(balance_key),
+        &[0x37, 0x03, 0x00],                         // frame[0] = Balance
+        &[0x20, 0x01, 0x20, 0x00, 0x37, 0x03, 0x08], // frame[8] = address
+        &[0x20, 0x01, 0xAD, 0x42, 0x20, 0x86, 0x42, 0x04, 0x84], // frame << 32 | 4
+        &i64_const((2 << 32) | 4),
+        &[0x10, 0x03],
+        &[0x20, 0x01, 0x41, 0x10, 0x6A, 0x24, 0x00], // sp += 16
+        &[0x0B],
+    ]
+    .concat();
+    // balance(address) = get(key(address), persistent)
+    let balance = [0x00, 0x20, 0x00, 0x10, 0x04, 0x42, 0x01, 0x10, 0x02, 0x0B].to_vec();
+    // put(key(local 0), balance(local 0) <op> local 1, persistent)
+    let set_balance = |op: u8| -> Vec<u8> {
+        vec![
+            0x20, 0x00, 0x10, 0x04, 0x20, 0x00, 0x10, 0x05, 0x20, 0x01, op, 0x42, 0x01, 0x10, 0x01,
+            0x1A,
+        ]
+    };
+    let read_admin = [i64_const(admin_key), vec![0x42, 0x02, 0x10, 0x02]].concat();
+    // Traps unless the value on the stack is tagged as an `Address`.
+    let check_address = [
+        0x42, 0xFF, 0x01, 0x83, 0x42, 0xCD, 0x00, 0x52, 0x04, 0x40, 0x00, 0x0B,
+    ];
+
+    let withdraw_buggy = [&[0x00][..], &set_balance(0x7D), &[0x0B]].concat();
+    let withdraw = [
+        &[0x00, 0x20, 0x00, 0x10, 0x00, 0x1A][..],
+        &set_balance(0x7D),
+        &[0x0B],
+    ]
+    .concat();
+    let deposit = [&[0x00][..], &set_balance(0x7C), &[0x0B]].concat();
+    let admin_mint_buggy = [
+        &[0x00][..],
+        &read_admin,
+        &check_address,
+        &set_balance(0x7C),
+        &[0x0B],
+    ]
+    .concat();
+    let admin_mint = [
+        &[0x01, 0x01, I64][..],
+        &read_admin,
+        &[0x22, 0x02],
+        &check_address,
+        &[0x20, 0x02, 0x10, 0x00, 0x1A],
+        &set_balance(0x7C),
+        &[0x0B],
+    ]
+    .concat();
+    let get_balance = vec![0x00, 0x20, 0x00, 0x10, 0x05, 0x0B];
+    let initialize = [
+        &[0x00, 0x20, 0x00, 0x10, 0x00, 0x1A][..],
+        &i64_const(admin_key),
+        &[0x20, 0x00, 0x42, 0x02, 0x10, 0x01, 0x1A, 0x0B],
+    ]
+    .concat();
+
+    let bodies = [
+        key,
+        balance,
+        withdraw_buggy,
+        withdraw,
+        deposit,
+        admin_mint_buggy,
+        admin_mint,
+        get_balance,
+        initialize,
+    ];
+    let mut code = uleb128(bodies.len());
+    for body in bodies {
+        code.extend_from_slice(&uleb128(body.len()));
+        code.extend_from_slice(&body);
+    }
+    append_section(&mut module, 10, &code);
+
+    let address = || ScSpecTypeDef::Address;
+    let mut spec = Vec::new();
+    encode_string(&mut spec, "contractspecv0");
+    for (name, inputs) in [
+        ("withdraw_buggy", "from"),
+        ("withdraw", "from"),
+        ("deposit", "to"),
+        ("admin_mint_buggy", "to"),
+        ("admin_mint", "to"),
+    ] {
+        spec.extend_from_slice(&fn_spec(
+            name,
+            &[(inputs, address()), ("amount", ScSpecTypeDef::I128)],
+        ));
+    }
+    spec.extend_from_slice(&fn_spec("get_balance", &[("account", address())]));
+    spec.extend_from_slice(&fn_spec("initialize", &[("admin", address())]));
+    append_section(&mut module, 0, &spec);
+
+    module
+}
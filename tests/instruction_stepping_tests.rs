@@ -277,6 +277,176 @@ fn test_stepping_help_formatting() {
     assert!(help.contains("step"));
     assert!(help.contains("over"));
     assert!(help.contains("continue"));
+    assert!(help.contains("goto <pc>"));
+}
+
+#[test]
+fn test_run_to_pc_steps_through_calls_and_stops_at_breakpoints() {
+    use soroban_debugger::debugger::instruction_pointer::StepMode;
+    use soroban_debugger::debugger::{DebugState, RunToPc, Stepper};
+    use wasmparser::Operator;
+
+    // Function 0 calls function 7, which returns straight away.
+    let state_with_call = || {
+        let mut debug_state = DebugState::new();
+        debug_state.set_instructions(vec![
+            Instruction::new(0x10, Operator::Call { function_index: 7 }, 0, 0),
+            Instruction::new(0x12, Operator::End, 0, 1),
+            Instruction::new(0x20, Operator::Nop, 7, 0),
+            Instruction::new(0x21, Operator::End, 7, 1),
+        ]);
+        debug_state.enable_instruction_debug();
+        debug_state
+    };
+
+    let mut stepper = Stepper::new();
+    let mut debug_state = state_with_call();
+    stepper.start(StepMode::StepInto, &mut debug_state);
+    assert_eq!(
+        stepper.run_to_pc(&mut debug_state, 0x12, |_| false),
+        RunToPc::Reached
+    );
+    assert_eq!(debug_state.current_instruction().unwrap().offset, 0x12);
+
+    let mut debug_state = state_with_call();
+    stepper.start(StepMode::StepInto, &mut debug_state);
+    assert_eq!(
+        stepper.run_to_pc(&mut debug_state, 0x12, |index| index == 7),
+        RunToPc::Breakpoint(7)
+    );
+    assert_eq!(debug_state.current_instruction().unwrap().offset, 0x20);
+
+    let mut debug_state = state_with_call();
+    stepper.start(StepMode::StepInto, &mut debug_state);
+    assert_eq!(
+        stepper.run_to_pc(&mut debug_state, 0x99, |_| false),
+        RunToPc::NotReached
+    );
+}
+
+#[test]
+fn test_engine_run_to_pc_on_budget_heavy() {
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::debugger::instruction_pointer::StepMode;
+    use soroban_debugger::debugger::state::PauseReason;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+
+    let wasm_bytes = std::fs::read(fixtures::get_fixture_path("budget_heavy")).unwrap();
+    let executor = ContractExecutor::new(wasm_bytes.clone()).unwrap();
+    let mut engine = DebuggerEngine::new(executor, vec![]);
+    engine.enable_instruction_debug(&wasm_bytes).unwrap();
+    engine
+        .start_instruction_stepping(StepMode::StepInto)
+        .unwrap();
+
+    let target = {
+        let state = engine.state();
+        let state = state.lock().unwrap();
+        let instructions = state.instructions();
+        assert!(instructions.len() > 20);
+        instructions[20].offset
+    };
+
+    assert!(engine.run_to_pc(target).unwrap());
+    assert!(engine.is_paused());
+    assert_eq!(engine.pause_reason(), Some(PauseReason::StepBoundary));
+    assert_eq!(
+        engine
+            .state()
+            .lock()
+            .unwrap()
+            .current_instruction()
+            .unwrap()
+            .offset,
+        target
+    );
+
+    assert!(!engine.run_to_pc(usize::MAX).unwrap());
+    assert_eq!(engine.pause_reason(), Some(PauseReason::EndOfExecution));
+}
+
+#[test]
+fn test_engine_max_steps_stops_instruction_stepping() {
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::debugger::instruction_pointer::StepMode;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+
+    let wasm_bytes = std::fs::read(fixtures::get_fixture_path("budget_heavy")).unwrap();
+    let executor = ContractExecutor::new(wasm_bytes.clone()).unwrap();
+    let mut engine = DebuggerEngine::new(executor, vec![]);
+    engine.enable_instruction_debug(&wasm_bytes).unwrap();
+    engine.set_max_steps(Some(3));
+    engine
+        .start_instruction_stepping(StepMode::StepInto)
+        .unwrap();
+
+    for _ in 0..3 {
+        assert!(engine.step_into().unwrap());
+    }
+    let err = engine.step_into().unwrap_err();
+    assert!(err.to_string().contains("step limit exceeded"), "{err}");
+    assert!(engine.step_limit_exceeded());
+
+    // A run to an unreachable offset stops at the limit instead of scanning on.
+    assert!(engine.run_to_pc(usize::MAX).is_err());
+}
+
+#[test]
+fn test_engine_max_steps_fails_long_running_call() {
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+
+    let wasm_bytes = std::fs::read(fixtures::get_fixture_path("budget_heavy")).unwrap();
+    let executor = ContractExecutor::new(wasm_bytes).unwrap();
+    let mut engine = DebuggerEngine::new(executor, vec![]);
+
+    engine.execute("heavy", Some("[200]")).unwrap();
+
+    engine.set_max_steps(Some(100));
+    let err = engine.execute("heavy", Some("[200]")).unwrap_err();
+    assert!(err.to_string().contains("step limit exceeded"), "{err}");
+}
+
+#[test]
+fn test_engine_max_steps_stops_runaway_call_early() {
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::inspector::budget::BudgetInspector;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+
+    let wasm_bytes = std::fs::read(fixtures::get_fixture_path("budget_heavy")).unwrap();
+    let executor = ContractExecutor::new(wasm_bytes).unwrap();
+    let mut engine = DebuggerEngine::new(executor, vec![]);
+    engine.set_max_steps(Some(100));
+
+    // Enough iterations to exhaust the full budget; the cap stops it well before.
+    let err = engine.execute("heavy", Some("[10000000]")).unwrap_err();
+    assert!(err.to_string().contains("step limit exceeded"), "{err}");
+    let budget = BudgetInspector::get_cpu_usage(engine.executor().host());
+    assert!(budget.cpu_instructions < 20_000_000, "{budget:?}");
+}
+
+#[test]
+fn test_engine_max_steps_caps_every_call_and_restores_limit() {
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::inspector::budget::BudgetInspector;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+
+    let wasm_bytes = std::fs::read(fixtures::get_fixture_path("budget_heavy")).unwrap();
+    let executor = ContractExecutor::new(wasm_bytes).unwrap();
+    let mut engine = DebuggerEngine::new(executor, vec![]);
+    let cpu_limit = BudgetInspector::get_cpu_usage(engine.executor().host()).cpu_limit;
+    engine.set_max_steps(Some(100));
+
+    // A REPL session keeps the engine, so the second call must be capped too.
+    for _ in 0..2 {
+        let err = engine.execute("heavy", Some("[10000000]")).unwrap_err();
+        assert!(err.to_string().contains("step limit exceeded"), "{err}");
+    }
+
+    engine.set_max_steps(None);
+    engine.execute("heavy", Some("[200]")).unwrap();
+    let budget = BudgetInspector::get_cpu_usage(engine.executor().host());
+    assert_eq!(budget.cpu_limit, cpu_limit);
 }
 
 // Integration test for full stepping workflow
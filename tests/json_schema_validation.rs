@@ -121,6 +121,39 @@ fn upgrade_check_json_output_matches_versioned_schema() {
     assert_schema_valid(&schema, &json_val, "Upgrade-check JSON");
 }
 
+#[test]
+fn profile_json_export_matches_versioned_schema() {
+    let wasm_path = "tests/fixtures/wasm/counter.wasm";
+    let dir = tempfile::tempdir().unwrap();
+    let report = dir.path().join("profile.json");
+    #[allow(deprecated)]
+    let output = Command::cargo_bin("soroban-debug")
+        .unwrap()
+        .arg("--quiet")
+        .arg("profile")
+        .arg("--contract")
+        .arg(wasm_path)
+        .arg("--function")
+        .arg("increment")
+        .arg("--export-format")
+        .arg("json")
+        .arg("--output")
+        .arg(&report)
+        .output()
+        .expect("Failed to execute profile command");
+    assert!(
+        output.status.success(),
+        "Command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json_val: Value = serde_json::from_str(&fs::read_to_string(&report).unwrap()).unwrap();
+    let schema = compile_schema("tests/schemas/profile_output.json");
+
+    assert_schema_valid(&schema, &json_val, "Profile JSON");
+    assert!(json_val["meta"]["contract_sha256"].is_string());
+}
+
 #[test]
 fn schema_rejects_missing_schema_version() {
     let schema = compile_schema("tests/schemas/execution_output.json");
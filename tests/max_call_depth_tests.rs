@@ -0,0 +1,48 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::inspector::StorageInspector;
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+const CALLEE: &str = "CADQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQP5KR";
+
+fn caller_with_mocked_callee() -> ContractExecutor {
+    let wasm = std::fs::read(fixtures::get_fixture_path("cross_contract")).unwrap();
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    executor
+        .set_mock_specs(&[format!("{CALLEE}.ping=true")])
+        .unwrap();
+    executor
+}
+
+fn call_args() -> String {
+    format!(
+        r#"[{{"type": "address", "value": "{CALLEE}"}}, {{"type": "symbol", "value": "ping"}}, {{"type": "vec", "value": []}}]"#
+    )
+}
+
+#[test]
+fn call_past_max_call_depth_is_reported_as_stack_overflow() {
+    let mut executor = caller_with_mocked_callee();
+    executor.set_max_call_depth(1).unwrap();
+
+    let err = executor.execute("call", Some(&call_args())).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("WASM stack overflow at depth 2 in function ping"),
+        "{err}"
+    );
+    // The write made after the nested call is rolled back.
+    let entries = StorageInspector::capture_instance_entries(executor.host());
+    assert!(!entries.contains_key("last"), "{entries:?}");
+}
+
+#[test]
+fn call_within_max_call_depth_runs() {
+    let mut executor = caller_with_mocked_callee();
+    executor.set_max_call_depth(2).unwrap();
+
+    executor.execute("call", Some(&call_args())).unwrap();
+    let entries = StorageInspector::capture_instance_entries(executor.host());
+    assert!(entries.contains_key("last"), "{entries:?}");
+}
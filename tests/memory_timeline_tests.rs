@@ -0,0 +1,45 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::profiler::MemoryTimeline;
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+const CALLEE: &str = "CADQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQP5KR";
+
+#[test]
+fn timeline_samples_inside_the_call() {
+    let wasm = fixtures::load_fixture(fixtures::names::CROSS_CONTRACT);
+
+    let mut executor = ContractExecutor::new(wasm).expect("executor");
+    executor
+        .set_mock_specs(&[format!("{CALLEE}.ping=true")])
+        .expect("mock spec");
+    let args = format!(
+        r#"[{{"type": "address", "value": "{CALLEE}"}}, {{"type": "symbol", "value": "ping"}}, {{"type": "vec", "value": []}}]"#
+    );
+
+    executor.checkpoints().start(executor.host(), "Before call");
+    executor.execute("call", Some(&args)).expect("execute call");
+    let checkpoints = executor.checkpoints().finish(executor.host(), "After call");
+    let timeline = MemoryTimeline::from_checkpoints("call", &checkpoints);
+
+    let locations: Vec<&str> = timeline
+        .samples
+        .iter()
+        .map(|s| s.location.as_str())
+        .collect();
+    let position = |location: &str| {
+        locations
+            .iter()
+            .position(|l| *l == location)
+            .unwrap_or_else(|| panic!("no sample at {location}: {locations:?}"))
+    };
+    let entry = position("Contract entry");
+    let mocked_call = position(&format!("Call {CALLEE}.ping"));
+    let mocked_return = position(&format!("Return {CALLEE}.ping"));
+    let exit = position("Contract exit");
+    assert!(position("Before call") < entry);
+    assert!(entry < mocked_call && mocked_call < mocked_return && mocked_return < exit);
+    assert!(exit < position("After call"));
+    assert!(!executor.checkpoints().is_recording());
+}
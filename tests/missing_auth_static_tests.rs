@@ -0,0 +1,77 @@
+#[path = "fixtures/wallet.rs"]
+mod wallet;
+
+use soroban_debugger::analyzer::security::{AnalyzerFilter, SecurityAnalyzer, Severity};
+use wallet::wallet_wasm;
+
+fn missing_auth_locations(wasm: &[u8]) -> Vec<String> {
+    let report = SecurityAnalyzer::new()
+        .analyze(wasm, None, None, &AnalyzerFilter::default(), "wallet.wasm")
+        .expect("analysis failed");
+    report
+        .findings
+        .into_iter()
+        .filter(|f| f.rule_id == "missing-auth")
+        .inspect(|f| assert_eq!(f.severity, Severity::High))
+        .map(|f| f.location)
+        .collect()
+}
+
+#[test]
+fn flags_exports_that_write_without_require_auth() {
+    assert_eq!(
+        missing_auth_locations(&wallet_wasm()),
+        vec![
+            "function 'withdraw_buggy'".to_string(),
+            "function 'admin_mint_buggy'".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn names_the_unauthorized_address_parameter() {
+    let report = SecurityAnalyzer::new()
+        .analyze(
+            &wallet_wasm(),
+            None,
+            None,
+            &AnalyzerFilter::default(),
+            "wallet.wasm",
+        )
+        .expect("analysis failed");
+    let finding = report
+        .findings
+        .iter()
+        .find(|f| f.location == "function 'withdraw_buggy'")
+        .expect("withdraw_buggy is flagged");
+    assert!(
+        finding.description.contains("'from'"),
+        "{}",
+        finding.description
+    );
+}
+
+/// The auth example is built separately:
+/// `cd examples/contracts/auth-example && cargo build --target wasm32-unknown-unknown --release`
+#[test]
+#[ignore = "needs examples/contracts/auth-example built for wasm32-unknown-unknown"]
+fn flags_buggy_functions_of_auth_example() {
+    let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("examples/contracts/auth-example/target/wasm32-unknown-unknown/release")
+        .join("soroban_auth_example.wasm");
+    let wasm = std::fs::read(&path)
+        .unwrap_or_else(|e| panic!("auth example not built at {}: {e}", path.display()));
+    let locations = missing_auth_locations(&wasm);
+
+    assert!(locations.contains(&"function 'withdraw_buggy'".to_string()));
+    assert!(locations.contains(&"function 'admin_mint_buggy'".to_string()));
+    for fixed in [
+        "initialize",
+        "deposit",
+        "withdraw",
+        "admin_mint",
+        "get_balance",
+    ] {
+        assert!(!locations.contains(&format!("function '{fixed}'")));
+    }
+}
@@ -0,0 +1,28 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+const CALLEE: &str = "CADQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQP5KR";
+
+#[test]
+fn mocked_callee_returns_typed_json_value() {
+    let wasm = fixtures::load_fixture(fixtures::names::CROSS_CONTRACT);
+
+    let mut executor = ContractExecutor::new(wasm).expect("executor");
+    executor
+        .set_mock_specs(&[format!(
+            r#"{CALLEE}.get_price -> {{"type":"i128","value":500}}"#
+        )])
+        .expect("mock spec");
+    let args = format!(
+        r#"[{{"type": "address", "value": "{CALLEE}"}}, {{"type": "symbol", "value": "get_price"}}, {{"type": "vec", "value": []}}]"#
+    );
+    let result = executor.execute("call", Some(&args)).expect("call");
+
+    assert_eq!(result, "I128(500)");
+    let log = executor.get_mock_call_log();
+    assert_eq!(log.len(), 1);
+    assert!(log[0].mocked);
+    assert_eq!(log[0].returned.as_deref(), Some("I128(500)"));
+}
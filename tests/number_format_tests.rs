@@ -0,0 +1,29 @@
+/// Tests for the global `--number-format` setting used for budget values.
+use soroban_debugger::cli::args::NumberFormat;
+use soroban_debugger::ui::formatter::Formatter;
+
+#[test]
+fn test_grouped_u64_inserts_separators() {
+    assert_eq!(Formatter::format_grouped_u64(0), "0");
+    assert_eq!(Formatter::format_grouped_u64(999), "999");
+    assert_eq!(Formatter::format_grouped_u64(1_000), "1,000");
+    assert_eq!(Formatter::format_grouped_u64(123_456_789), "123,456,789");
+}
+
+// The format is process-global, so every mode is checked in one test.
+#[test]
+fn test_format_number_follows_global_setting() {
+    Formatter::set_number_format(NumberFormat::Plain);
+    assert_eq!(Formatter::format_number(123_456_789), "123456789");
+
+    Formatter::set_number_format(NumberFormat::Si);
+    assert_eq!(Formatter::format_number(123_456_789), "123.46M");
+    assert_eq!(Formatter::format_number(512), "512");
+
+    Formatter::set_number_format(NumberFormat::Grouped);
+    assert_eq!(Formatter::format_number(123_456_789), "123,456,789");
+    assert_eq!(
+        Formatter::format_budget(1_500_000, 100_000_000, 2_048, 40_000_000),
+        "CPU: 1,500,000/100,000,000 (1.5%) | Memory: 2,048/40,000,000 bytes (0.0%)"
+    );
+}
@@ -0,0 +1,43 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::profiler::GasOptimizer;
+use soroban_debugger::runtime::executor::ContractExecutor;
+use std::collections::HashMap;
+
+const CALLEE: &str = "CADQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQOBYHA4DQP5KR";
+
+#[test]
+fn flamegraph_nests_cross_contract_call_under_entry_point() {
+    let wasm = fixtures::load_fixture(fixtures::names::CROSS_CONTRACT);
+
+    let mut executor = ContractExecutor::new(wasm).expect("executor");
+    executor
+        .set_mock_specs(&[format!("{CALLEE}.ping=true|cpu=50000")])
+        .expect("mock spec");
+    let mut optimizer = GasOptimizer::new(executor);
+    let args = format!(
+        r#"[{{"type": "address", "value": "{CALLEE}"}}, {{"type": "symbol", "value": "ping"}}, {{"type": "vec", "value": []}}]"#
+    );
+    let profile = optimizer
+        .analyze_function("call", Some(&args))
+        .expect("profile call");
+    let report = optimizer.generate_report("cross_contract.wasm");
+
+    let folded = GasOptimizer::generate_flamegraph(&report);
+    let counts: HashMap<&str, u64> = folded
+        .lines()
+        .map(|line| {
+            let (stack, count) = line.rsplit_once(' ').expect("stack and count");
+            (stack, count.parse().expect("numeric count"))
+        })
+        .collect();
+
+    let child = format!("call;{CALLEE}.ping");
+    assert_eq!(counts.get(child.as_str()), Some(&50000));
+    // The mocked charge is part of the measured total; the entry point keeps
+    // the rest.
+    assert!(profile.total_cpu > 50000);
+    assert_eq!(counts.get("call"), Some(&(profile.total_cpu - 50000)));
+    assert_eq!(counts.len(), 2);
+}
@@ -0,0 +1,52 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::compare::ExecutionTrace;
+use soroban_debugger::repl::executor::ReplExecutor;
+use soroban_debugger::repl::ReplConfig;
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+#[tokio::test]
+async fn save_trace_round_trips_session_calls() {
+    let config = ReplConfig {
+        contract_path: fixtures::get_fixture_path("counter"),
+        network_snapshot: None,
+        storage: None,
+        watch_keys: vec![],
+    };
+    let mut executor = ReplExecutor::new(&config).unwrap();
+    assert!(executor.session_trace().is_err());
+
+    executor.call_function("increment", vec![]).await.unwrap();
+    executor.call_function("increment", vec![]).await.unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.json");
+    executor.save_trace(&path).unwrap();
+    let trace = ExecutionTrace::from_file(&path).unwrap();
+
+    let functions: Vec<&str> = trace
+        .call_sequence
+        .iter()
+        .map(|call| call.function.as_str())
+        .collect();
+    assert_eq!(functions, ["increment", "increment"]);
+    assert_eq!(trace.function.as_deref(), Some("increment"));
+    // The last call started from the counter left by the first one.
+    assert_eq!(
+        trace.storage.get("c"),
+        Some(&serde_json::json!({ "type": "i64", "value": 1 }))
+    );
+    assert!(trace.budget.unwrap().cpu_instructions > 0);
+
+    // Replaying the last call from the recorded storage reproduces its result.
+    let wasm = std::fs::read(fixtures::get_fixture_path("counter")).unwrap();
+    let mut replay = ContractExecutor::new(wasm).unwrap();
+    replay
+        .set_initial_storage(serde_json::to_string(&trace.storage).unwrap())
+        .unwrap();
+    let replayed = replay.execute("increment", None).unwrap();
+    let replayed = serde_json::from_str(&replayed).unwrap_or(serde_json::Value::String(replayed));
+    assert_eq!(replayed, serde_json::json!(2));
+    assert_eq!(trace.return_value, Some(replayed));
+}
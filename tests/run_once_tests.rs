@@ -0,0 +1,66 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::inspector::StorageInspector;
+use soroban_debugger::{ContractExecutor, DebuggerEngine};
+
+fn counter_engine() -> DebuggerEngine {
+    let wasm = std::fs::read(fixtures::get_fixture_path("counter")).unwrap();
+    DebuggerEngine::new(ContractExecutor::new(wasm).unwrap(), vec![])
+}
+
+#[test]
+fn run_once_summarizes_counter_increment() {
+    let mut engine = counter_engine();
+
+    let summary = engine
+        .run_once("increment", None, Some(r#"{"c": 41}"#))
+        .expect("run_once");
+
+    assert_eq!(summary.function, "increment");
+    assert!(summary.result.contains("I64(42)"), "{}", summary.result);
+    let (before, after) = &summary.storage_diff.modified["c"];
+    assert_eq!((before.as_str(), after.as_str()), ("I64(41)", "I64(42)"));
+    assert_eq!(
+        StorageInspector::capture_instance_entries(engine.executor().host())["c"],
+        "I64(42)"
+    );
+    assert!(summary.storage_diff.added.is_empty());
+    assert!(summary.storage_diff.deleted.is_empty());
+    assert!(summary.events.is_empty());
+    assert!(summary.auth_tree.is_empty());
+    assert!(summary.budget.cpu_instructions > 0);
+    assert!(summary.budget.cpu_instructions <= summary.budget.cpu_limit);
+}
+
+#[test]
+fn run_once_reports_only_the_latest_call() {
+    let mut engine = counter_engine();
+
+    engine.run_once("increment", None, None).unwrap();
+    let summary = engine.run_once("get", None, None).unwrap();
+
+    assert!(summary.result.contains("I64(1)"), "{}", summary.result);
+    assert!(summary.storage_diff.is_empty());
+}
+
+#[test]
+fn run_once_reports_the_budget_of_each_call_alone() {
+    let mut engine = counter_engine();
+
+    let first = engine.run_once("get", None, None).unwrap();
+    let second = engine.run_once("get", None, None).unwrap();
+
+    assert!(first.budget.cpu_instructions > 0);
+    assert_eq!(
+        first.budget.cpu_instructions,
+        second.budget.cpu_instructions
+    );
+    assert_eq!(first.budget.memory_bytes, second.budget.memory_bytes);
+}
+
+#[test]
+fn run_once_propagates_execution_errors() {
+    let mut engine = counter_engine();
+    assert!(engine.run_once("no_such_function", None, None).is_err());
+}
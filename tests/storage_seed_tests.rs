@@ -62,3 +62,133 @@ fn storage_seed_rejects_malformed_json() {
         .to_string();
     assert!(err.contains("Failed to parse initial storage JSON"));
 }
+
+#[test]
+fn saved_snapshot_round_trips_through_loader() {
+    use soroban_debugger::simulator::{SnapshotLoader, SnapshotManager};
+
+    let wasm_path = fixture_wasm("counter");
+    if !wasm_path.exists() {
+        return;
+    }
+
+    let wasm = std::fs::read(&wasm_path).unwrap();
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    executor
+        .set_initial_storage(r#"{"c": 41}"#.to_string())
+        .unwrap();
+    executor.execute("increment", None).unwrap();
+
+    let tmpdir = tempfile::TempDir::new().unwrap();
+    let path = tmpdir.path().join("after_increment.json");
+    let saved = SnapshotManager::save_current(&executor, &path).expect("save snapshot");
+
+    let loader = SnapshotLoader::from_file(&path).expect("reload snapshot");
+    let reloaded = loader.snapshot();
+    assert_eq!(reloaded.ledger.sequence, saved.ledger.sequence);
+    assert_eq!(reloaded.contracts.len(), saved.contracts.len());
+    assert_eq!(
+        reloaded.ledger.network_passphrase,
+        executor.network_passphrase()
+    );
+    assert!(
+        reloaded
+            .contracts
+            .iter()
+            .flat_map(|c| c.storage.values())
+            .any(|v| v["value"] == "I64(42)"),
+        "expected incremented counter in saved snapshot, got: {:?}",
+        reloaded.contracts
+    );
+}
+
+fn seeded_tiers(storage_json: &str) -> (Option<i64>, Option<i64>, Option<i64>) {
+    use soroban_sdk::Symbol;
+
+    let wasm_path = fixture_wasm("counter");
+    let wasm = std::fs::read(&wasm_path).unwrap();
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    executor
+        .set_initial_storage(storage_json.to_string())
+        .expect("seed storage");
+
+    let env = executor.env();
+    env.as_contract(executor.contract_address(), || {
+        let key = Symbol::new(env, "k");
+        (
+            env.storage().instance().get(&key),
+            env.storage().persistent().get(&key),
+            env.storage().temporary().get(&key),
+        )
+    })
+}
+
+#[test]
+#[ignore = "needs the counter fixture built by tests/fixtures/build.sh"]
+fn storage_seed_places_entries_in_requested_tier() {
+    assert_eq!(
+        seeded_tiers(r#"[{"key": "k", "value": 1, "durability": "instance"}]"#),
+        (Some(1), None, None)
+    );
+    assert_eq!(
+        seeded_tiers(r#"[{"key": "k", "value": 2, "durability": "persistent"}]"#),
+        (None, Some(2), None)
+    );
+    assert_eq!(
+        seeded_tiers(r#"[{"key": "k", "value": 3, "durability": "temporary"}]"#),
+        (None, None, Some(3))
+    );
+}
+
+#[test]
+#[ignore = "needs the counter fixture built by tests/fixtures/build.sh"]
+fn storage_seed_defaults_to_instance_tier() {
+    assert_eq!(seeded_tiers(r#"{"k": 4}"#), (Some(4), None, None));
+    assert_eq!(
+        seeded_tiers(r#"[{"key": "k", "value": 5}]"#),
+        (Some(5), None, None)
+    );
+}
+
+#[test]
+#[ignore = "needs the counter fixture built by tests/fixtures/build.sh"]
+fn storage_seed_rejects_unknown_durability() {
+    let wasm_path = fixture_wasm("counter");
+    let wasm = std::fs::read(&wasm_path).unwrap();
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    let err = executor
+        .set_initial_storage(r#"[{"key": "k", "value": 1, "durability": "ledger"}]"#.to_string())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Unsupported durability 'ledger'"), "{err}");
+}
+
+#[test]
+fn saved_snapshot_continues_in_a_new_executor() {
+    use soroban_debugger::simulator::{SnapshotLoader, SnapshotManager};
+
+    let wasm_path = fixture_wasm("counter");
+    if !wasm_path.exists() {
+        return;
+    }
+
+    let wasm = std::fs::read(&wasm_path).unwrap();
+    let mut first = ContractExecutor::new(wasm.clone()).unwrap();
+    first
+        .set_initial_storage(r#"{"c": 41}"#.to_string())
+        .unwrap();
+    first.execute("increment", None).unwrap();
+    let tmpdir = tempfile::TempDir::new().unwrap();
+    let path = tmpdir.path().join("step1.json");
+    SnapshotManager::save_current(&first, &path).expect("save snapshot");
+
+    let loaded = SnapshotLoader::from_file(&path)
+        .and_then(|loader| loader.apply_to_environment())
+        .expect("load snapshot");
+    let mut second = ContractExecutor::new(wasm).unwrap();
+    second.apply_snapshot_ledger(&loaded).unwrap();
+    assert!(second.apply_snapshot_storage(&loaded).unwrap() >= 1);
+
+    let result = second.execute("increment", None).unwrap();
+    assert!(result.contains("I64(43)"), "got: {result}");
+}
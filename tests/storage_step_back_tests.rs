@@ -0,0 +1,107 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::debugger::engine::DebuggerEngine;
+use soroban_debugger::debugger::instruction_pointer::StepMode;
+use soroban_debugger::inspector::storage::StorageInspector;
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+fn counter_engine() -> DebuggerEngine {
+    let wasm_bytes = std::fs::read(fixtures::get_fixture_path("counter")).unwrap();
+    let executor = ContractExecutor::new(wasm_bytes.clone()).unwrap();
+    let mut engine = DebuggerEngine::new(executor, vec![]);
+    engine.enable_instruction_debug(&wasm_bytes).unwrap();
+    engine
+}
+
+fn counter_value(engine: &DebuggerEngine) -> Option<String> {
+    StorageInspector::capture_instance_entries(engine.executor().host())
+        .get("c")
+        .cloned()
+}
+
+#[test]
+fn step_back_reverts_counter_storage() {
+    let mut engine = counter_engine();
+
+    engine.execute("increment", None).unwrap();
+    engine.execute("increment", None).unwrap();
+    assert_eq!(counter_value(&engine).as_deref(), Some("I64(2)"));
+
+    assert!(engine.step_back().unwrap());
+    assert_eq!(counter_value(&engine).as_deref(), Some("I64(1)"));
+    assert_eq!(engine.state().lock().unwrap().snapshot_index(), 1);
+
+    assert!(engine.step_back().unwrap());
+    assert_eq!(counter_value(&engine), None);
+}
+
+#[test]
+fn storage_history_capacity_bounds_rewind_depth() {
+    let mut engine = counter_engine();
+    engine.set_storage_history_capacity(1);
+
+    engine.execute("increment", None).unwrap();
+    engine.execute("increment", None).unwrap();
+    engine.execute("increment", None).unwrap();
+
+    engine.step_back().unwrap();
+    assert_eq!(counter_value(&engine).as_deref(), Some("I64(2)"));
+
+    // The older snapshots were dropped, so storage stays put.
+    engine.step_back().unwrap();
+    assert_eq!(counter_value(&engine).as_deref(), Some("I64(2)"));
+}
+
+fn current_offset(engine: &DebuggerEngine) -> usize {
+    engine
+        .state()
+        .lock()
+        .unwrap()
+        .current_instruction()
+        .unwrap()
+        .offset
+}
+
+#[test]
+fn step_back_after_instruction_step_rewinds_one_instruction() {
+    let mut engine = counter_engine();
+    engine.execute("increment", None).unwrap();
+    engine
+        .start_instruction_stepping(StepMode::StepInto)
+        .unwrap();
+
+    assert!(engine.step_into().unwrap());
+    let first = current_offset(&engine);
+    assert!(engine.step_into().unwrap());
+    assert_ne!(current_offset(&engine), first);
+    assert_eq!(engine.state().lock().unwrap().snapshot_index(), 3);
+
+    assert!(engine.step_back().unwrap());
+    assert_eq!(current_offset(&engine), first);
+    assert_eq!(counter_value(&engine).as_deref(), Some("I64(1)"));
+    assert_eq!(engine.state().lock().unwrap().snapshot_index(), 2);
+
+    // Stepping back past the instruction steps undoes the call itself.
+    assert!(engine.step_back().unwrap());
+    assert!(engine.step_back().unwrap());
+    assert_eq!(counter_value(&engine), None);
+}
+
+#[test]
+fn run_to_pc_records_storage_for_step_back() {
+    let mut engine = counter_engine();
+    engine.execute("increment", None).unwrap();
+    engine
+        .start_instruction_stepping(StepMode::StepInto)
+        .unwrap();
+    let target = engine.state().lock().unwrap().instructions()[2].offset;
+
+    assert!(engine.run_to_pc(target).unwrap());
+    assert_eq!(current_offset(&engine), target);
+    assert_eq!(engine.state().lock().unwrap().snapshot_index(), 2);
+
+    assert!(engine.step_back().unwrap());
+    assert_eq!(counter_value(&engine).as_deref(), Some("I64(1)"));
+    assert_eq!(engine.state().lock().unwrap().snapshot_index(), 1);
+}
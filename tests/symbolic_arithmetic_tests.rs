@@ -0,0 +1,46 @@
+use soroban_debugger::analyzer::arithmetic::ArithmeticFaultKind;
+use soroban_debugger::analyzer::symbolic::{SymbolicAnalyzer, SymbolicConfig};
+
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+fn overflow_math_wasm() -> Vec<u8> {
+    std::fs::read(fixtures::get_fixture_path(fixtures::names::OVERFLOW_MATH)).unwrap()
+}
+
+#[test]
+fn sdk_built_i128_multiply_overflow_is_found() {
+    let bytes = overflow_math_wasm();
+    // The deep preset seeds u64::MAX, whose square does not fit in i128.
+    let report = SymbolicAnalyzer::new()
+        .analyze_with_config(&bytes, "mul", &SymbolicConfig::deep())
+        .expect("analysis failed");
+
+    let finding = report
+        .arithmetic_findings
+        .iter()
+        .find(|f| f.kind == ArithmeticFaultKind::MulOverflow)
+        .unwrap_or_else(|| panic!("no multiply overflow in {:?}", report.arithmetic_findings));
+    assert!(finding.offset.is_some(), "{finding:?}");
+    assert!(
+        finding.error.is_some(),
+        "overflow checks panic: {finding:?}"
+    );
+}
+
+#[test]
+fn sdk_built_i128_division_by_zero_is_found() {
+    let bytes = overflow_math_wasm();
+    let report = SymbolicAnalyzer::new()
+        .analyze(&bytes, "div")
+        .expect("analysis failed");
+
+    assert!(
+        report
+            .arithmetic_findings
+            .iter()
+            .any(|f| f.kind == ArithmeticFaultKind::DivisionByZero && f.inputs.ends_with(", 0]")),
+        "{:?}",
+        report.arithmetic_findings
+    );
+}
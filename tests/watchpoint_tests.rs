@@ -0,0 +1,53 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::debugger::engine::DebuggerEngine;
+use soroban_debugger::debugger::state::PauseReason;
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+fn counter_engine() -> DebuggerEngine {
+    let wasm_bytes = std::fs::read(fixtures::get_fixture_path("counter")).unwrap();
+    let executor = ContractExecutor::new(wasm_bytes).unwrap();
+    DebuggerEngine::new(executor, vec![])
+}
+
+#[test]
+fn writing_counter_key_triggers_prefix_watchpoint() {
+    let mut engine = counter_engine();
+    engine.add_watchpoint("c*").unwrap();
+
+    engine.execute("increment", None).unwrap();
+
+    assert!(engine.is_paused());
+    assert_eq!(engine.pause_reason(), Some(PauseReason::Watchpoint));
+    let hit = engine
+        .watchpoint_hits()
+        .iter()
+        .find(|hit| hit.key == "c")
+        .expect("watchpoint on c* should fire for key c");
+    assert_eq!(hit.before, None);
+    assert_eq!(hit.after.as_deref(), Some("I64(1)"));
+
+    engine.execute("increment", None).unwrap();
+    let hit = engine.watchpoint_hits().iter().find(|hit| hit.key == "c");
+    assert_eq!(hit.and_then(|h| h.before.as_deref()), Some("I64(1)"));
+    assert_eq!(hit.and_then(|h| h.after.as_deref()), Some("I64(2)"));
+}
+
+#[test]
+fn read_only_call_does_not_trigger_watchpoint() {
+    let mut engine = counter_engine();
+    engine.add_watchpoint("c").unwrap();
+
+    engine.execute("get", None).unwrap();
+
+    assert!(engine.watchpoint_hits().is_empty());
+    assert!(!engine.is_paused());
+}
+
+#[test]
+fn invalid_watchpoint_pattern_is_rejected() {
+    let mut engine = counter_engine();
+    assert!(engine.add_watchpoint("re:(").is_err());
+    assert!(engine.watchpoints().is_empty());
+}
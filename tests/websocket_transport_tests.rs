@@ -0,0 +1,92 @@
+#![cfg(feature = "network-tests")]
+
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::client::RemoteClient;
+use std::time::Duration;
+
+// Note: Requires the soroban-debug binary to be built (cargo build --bins)
+
+fn get_free_port() -> Option<u16> {
+    match std::net::TcpListener::bind("127.0.0.1:0") {
+        Ok(listener) => Some(
+            listener
+                .local_addr()
+                .expect("Failed to read local address")
+                .port(),
+        ),
+        Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+            eprintln!("Skipping network test: loopback bind is not permitted in this environment");
+            None
+        }
+        Err(err) => panic!("Failed to bind local loopback socket: {err}"),
+    }
+}
+
+struct ServerGuard(std::process::Child);
+
+impl Drop for ServerGuard {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn spawn_ws_server(port: u16, token: &str) -> ServerGuard {
+    let child = std::process::Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .args([
+            "server",
+            "--ws",
+            "--port",
+            &port.to_string(),
+            "--token",
+            token,
+        ])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .expect("Failed to spawn soroban-debug server");
+    std::thread::sleep(Duration::from_millis(500));
+    ServerGuard(child)
+}
+
+#[test]
+fn load_and_execute_over_websocket() {
+    let Some(port) = get_free_port() else {
+        return;
+    };
+    let token = "websocket-test-token-0123456789";
+    let _server = spawn_ws_server(port, token);
+
+    let url = format!("ws://127.0.0.1:{}", port);
+    let mut client =
+        RemoteClient::connect(&url, Some(token.to_string())).expect("WebSocket connect failed");
+
+    let contract = fixtures::get_fixture_path("counter");
+    let size = client
+        .load_contract(contract.to_str().unwrap())
+        .expect("LoadContract over WebSocket failed");
+    assert!(size > 0);
+
+    let output = client
+        .execute("increment", None)
+        .expect("Execute over WebSocket failed");
+    assert!(output.contains('1'), "unexpected result: {}", output);
+}
+
+#[test]
+fn websocket_handshake_rejects_wrong_token() {
+    let Some(port) = get_free_port() else {
+        return;
+    };
+    let _server = spawn_ws_server(port, "websocket-test-token-0123456789");
+
+    let url = format!("ws://127.0.0.1:{}", port);
+    let err = RemoteClient::connect(&url, Some("wrong-token".to_string())).unwrap_err();
+    assert!(
+        err.to_string().to_lowercase().contains("token"),
+        "unexpected error: {}",
+        err
+    );
+}